@@ -0,0 +1,466 @@
+//! Async connections, transactions and connection pools
+//!
+//! Note: This module requires enabling the `async` feature
+//!
+//! This module is a spawn-blocking adapter, not a non-blocking database
+//! driver. An [`AsyncConnection`] owns a regular, blocking diesel connection
+//! on a dedicated thread and hands each query to that thread, similar to
+//! calling `spawn_blocking` for every query. The returned futures wait for
+//! the result without blocking the executor. This works for every backend
+//! supported by diesel and does not depend on a specific async runtime.
+//!
+//! As a consequence:
+//!
+//! * Each connection occupies an operating system thread for its whole
+//!   lifetime.
+//! * Dropping a future does not cancel the query it started. The query
+//!   runs to completion on the connection thread and its result is
+//!   discarded.
+//! * Dropping a [`AsyncConnection::transaction`] future before the
+//!   transaction finished queues a rollback on the connection thread. The
+//!   rollback is executed after the query which is still running, the drop
+//!   itself does not wait for either of them.
+//!
+//! Queries are executed via the methods of [`AsyncRunQueryDsl`], which
+//! mirror the methods of [`RunQueryDsl`]. As a query is moved to the thread
+//! owning the connection it needs to be `Send + 'static`, so bind values
+//! need to be owned values instead of references.
+//! [`AsyncConnection::transaction`] runs an async closure inside of
+//! a transaction and [`AsyncPool`] hands out the connections of a
+//! [`Pool`](crate::pool::Pool) as async connections.
+//!
+//! # Example
+//!
+//! ```rust
+//! # include!("../doctest_setup.rs");
+//! # use std::future::Future;
+//! # use std::pin::pin;
+//! # use std::sync::Arc;
+//! # use std::task::{Context, Poll, Wake, Waker};
+//! # use std::thread::{self, Thread};
+//! #
+//! # // a minimal executor, use the one provided by your async runtime instead
+//! # fn block_on<F: Future>(future: F) -> F::Output {
+//! #     struct ThreadWaker(Thread);
+//! #     impl Wake for ThreadWaker {
+//! #         fn wake(self: Arc<Self>) {
+//! #             self.0.unpark();
+//! #         }
+//! #     }
+//! #     let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+//! #     let mut cx = Context::from_waker(&waker);
+//! #     let mut future = pin!(future);
+//! #     loop {
+//! #         match future.as_mut().poll(&mut cx) {
+//! #             Poll::Ready(output) => return output,
+//! #             Poll::Pending => thread::park(),
+//! #         }
+//! #     }
+//! # }
+//! #
+//! use diesel::async_connection::{AsyncConnection, AsyncRunQueryDsl};
+//!
+//! async fn add_user(conn: &mut AsyncConnection<DbConnection>, user_name: &str) -> QueryResult<Vec<String>> {
+//!     use schema::users::dsl::*;
+//!
+//!     let user_name = user_name.to_owned();
+//!     conn.transaction(|conn| {
+//!         Box::pin(async move {
+//!             diesel::insert_into(users)
+//!                 .values(name.eq(user_name))
+//!                 .execute_async(conn)
+//!                 .await?;
+//!             users.select(name).order(id).load_async(conn).await
+//!         })
+//!     })
+//!     .await
+//! }
+//!
+//! # fn main() {
+//! let mut conn = block_on(AsyncConnection::<DbConnection>::establish(&database_url_for_env()))
+//!     .expect("Could not establish the connection");
+//! # block_on(conn.run(|conn| setup_database(conn)));
+//! let names = block_on(add_user(&mut conn, "Jim")).unwrap();
+//! assert_eq!(vec!["Sean", "Tess", "Jim"], names);
+//! # }
+//! ```
+//!
+//! [`RunQueryDsl`]: crate::RunQueryDsl
+
+mod pool;
+mod run_query_dsl;
+
+use alloc::boxed::Box;
+use core::future::Future;
+use core::pin::Pin;
+
+use crate::blocking::JobQueue;
+use crate::connection::{Connection, TransactionManager};
+use crate::result::{ConnectionResult, Error};
+
+pub use self::pool::{AsyncPool, AsyncPooledConnection};
+pub use self::run_query_dsl::AsyncRunQueryDsl;
+
+/// A blocking connection owned by a dedicated thread, which is used from
+/// async code
+///
+/// The thread executes closures in the same way as the threads of a
+/// [`BlockingPool`](crate::blocking::BlockingPool), but it keeps the
+/// connection if a closure leaves a transaction open, so that
+/// transactions can span several closures.
+///
+/// Dropping the connection lets the thread finish all queued
+/// closures before it closes the underlying connection.
+///
+/// See the [module documentation](self) for an example.
+#[allow(missing_debug_implementations)]
+pub struct AsyncConnection<C> {
+    queue: JobQueue<C>,
+}
+
+impl<C> AsyncConnection<C>
+where
+    C: Connection + 'static,
+{
+    /// Establish a connection to the given database on a new thread
+    pub async fn establish(database_url: &str) -> ConnectionResult<Self> {
+        let database_url = database_url.to_owned();
+        Self::spawn(move || C::establish(&database_url)).await
+    }
+
+    /// Start a new thread owning the connection returned by `connect`
+    ///
+    /// If the returned future is dropped before the connection
+    /// was acquired, the connection is closed right away.
+    async fn spawn<F, E>(connect: F) -> Result<Self, E>
+    where
+        F: FnOnce() -> Result<C, E> + Send + 'static,
+        E: Send + 'static,
+    {
+        // the queue only holds the closures of a single
+        // task as `run` requires a mutable reference
+        let conn = Self {
+            queue: JobQueue::new(None, usize::MAX),
+        };
+        conn.queue.spawn_worker_with(connect).await?;
+        Ok(conn)
+    }
+
+    /// Execute the given closure with the underlying connection
+    ///
+    /// The closure is executed on the thread owning the connection.
+    /// If the closure panics, the panic is resumed in the calling task.
+    ///
+    /// Dropping the returned future does not cancel the execution
+    /// of the closure.
+    pub async fn run<F, R>(&mut self, f: F) -> R
+    where
+        F: FnOnce(&mut C) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.queue.run(f).await
+    }
+
+    /// Executes the given async closure inside of a database transaction
+    ///
+    /// This function behaves like [`Connection::transaction`]: The
+    /// transaction is committed if the closure returns `Ok` and rolled
+    /// back if it returns `Err`. Nested calls create savepoints.
+    ///
+    /// The closure returns a boxed future borrowing the connection, which
+    /// is usually created with `Box::pin(async move { ... })`. If the
+    /// returned future is dropped before the transaction finished,
+    /// the transaction is rolled back. The rollback is queued behind a
+    /// query that is still executing, as queries cannot be cancelled.
+    pub async fn transaction<R, E, F>(&mut self, f: F) -> Result<R, E>
+    where
+        F: for<'r> FnOnce(&'r mut Self) -> Pin<Box<dyn Future<Output = Result<R, E>> + Send + 'r>>,
+        E: From<Error>,
+    {
+        // the guard is created before the transaction is started
+        // so that a transaction started by a cancelled future
+        // is rolled back as well
+        let mut guard = RollbackGuard {
+            queue: Some(self.queue.clone()),
+        };
+        if let Err(e) = self.run(C::TransactionManager::begin_transaction).await {
+            guard.disarm();
+            return Err(e.into());
+        }
+        let result = f(self).await;
+        guard.disarm();
+        match result {
+            Ok(value) => {
+                self.run(C::TransactionManager::commit_transaction).await?;
+                Ok(value)
+            }
+            Err(user_error) => match self.run(C::TransactionManager::rollback_transaction).await {
+                Ok(()) => Err(user_error),
+                Err(Error::BrokenTransactionManager) => {
+                    // In this case we are probably more interested by the
+                    // original error, which likely caused this
+                    Err(user_error)
+                }
+                Err(rollback_error) => Err(rollback_error.into()),
+            },
+        }
+    }
+}
+
+impl<C> Drop for AsyncConnection<C> {
+    fn drop(&mut self) {
+        self.queue.shutdown();
+    }
+}
+
+struct RollbackGuard<C>
+where
+    C: Connection + 'static,
+{
+    queue: Option<JobQueue<C>>,
+}
+
+impl<C> RollbackGuard<C>
+where
+    C: Connection + 'static,
+{
+    fn disarm(&mut self) {
+        self.queue = None;
+    }
+}
+
+impl<C> Drop for RollbackGuard<C>
+where
+    C: Connection + 'static,
+{
+    fn drop(&mut self) {
+        if let Some(queue) = self.queue.take() {
+            queue.push(Box::new(|conn: &mut C| {
+                let status = C::TransactionManager::transaction_manager_status_mut(conn);
+                if let Ok(Some(_)) = status.transaction_depth() {
+                    // the error is ignored as the task is already cancelled
+                    let _ = C::TransactionManager::rollback_transaction(conn);
+                }
+            }));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::sync::Arc;
+    use core::panic::AssertUnwindSafe;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+    use std::task::Wake;
+    use std::thread::{self, Thread};
+
+    use super::*;
+    use crate::connection::SimpleConnection;
+    use crate::dsl::sql;
+    use crate::prelude::*;
+    use crate::sql_types::{BigInt, Integer};
+    use crate::test_helpers::*;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    pub(super) fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    fn assert_send<T: Send>(t: T) -> T {
+        t
+    }
+
+    fn connection_with_table() -> AsyncConnection<TestConnection> {
+        let mut conn =
+            block_on(AsyncConnection::<TestConnection>::establish(&database_url())).unwrap();
+        block_on(conn.run(|conn| {
+            conn.batch_execute("CREATE TEMPORARY TABLE async_test (id INTEGER PRIMARY KEY)")
+        }))
+        .unwrap();
+        conn
+    }
+
+    fn count(conn: &mut AsyncConnection<TestConnection>) -> i64 {
+        block_on(
+            conn.run(|conn| {
+                sql::<BigInt>("SELECT COUNT(*) FROM async_test").get_result::<i64>(conn)
+            }),
+        )
+        .unwrap()
+    }
+
+    fn is_broken(conn: &mut AsyncConnection<TestConnection>) -> bool {
+        block_on(conn.run(|conn| {
+            <TestConnection as Connection>::TransactionManager::is_broken_transaction_manager(conn)
+        }))
+    }
+
+    fn insert(
+        conn: &mut AsyncConnection<TestConnection>,
+        id: i32,
+    ) -> impl Future<Output = QueryResult<usize>> + Send + '_ {
+        conn.run(move |conn| {
+            crate::sql_query(format!("INSERT INTO async_test (id) VALUES ({id})")).execute(conn)
+        })
+    }
+
+    /// Polls `future` until a message is received from `receiver`
+    fn poll_until<F: Future + ?Sized>(
+        mut future: Pin<&mut F>,
+        receiver: &std::sync::mpsc::Receiver<()>,
+    ) {
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        while receiver.try_recv().is_err() {
+            assert!(future.as_mut().poll(&mut cx).is_pending());
+            thread::park_timeout(core::time::Duration::from_millis(10));
+        }
+    }
+
+    #[diesel_test_helper::test]
+    fn run_executes_closures_on_the_connection() {
+        let mut conn =
+            block_on(AsyncConnection::<TestConnection>::establish(&database_url())).unwrap();
+
+        let result =
+            block_on(assert_send(conn.run(|conn| {
+                crate::select(sql::<Integer>("1 + 1")).get_result::<i32>(conn)
+            })));
+        assert_eq!(Ok(2), result);
+    }
+
+    #[diesel_test_helper::test]
+    fn establish_reports_connection_errors() {
+        let result = block_on(AsyncConnection::<TestConnection>::establish(
+            "invalid://localhost/database",
+        ));
+        assert!(result.is_err());
+    }
+
+    #[diesel_test_helper::test]
+    fn panics_are_resumed_in_the_calling_task() {
+        let mut conn =
+            block_on(AsyncConnection::<TestConnection>::establish(&database_url())).unwrap();
+
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            block_on(conn.run(|_| panic!("closure panicked")))
+        }));
+        assert!(result.is_err());
+
+        // the connection is still available
+        assert_eq!(42, block_on(conn.run(|_| 42)));
+    }
+
+    #[diesel_test_helper::test]
+    fn transaction_commits_on_success() {
+        let mut conn = connection_with_table();
+
+        let result = block_on(assert_send(conn.transaction(|conn| {
+            Box::pin(async move {
+                insert(conn, 1).await?;
+                insert(conn, 2).await
+            })
+        })));
+        assert_eq!(Ok(1), result);
+        assert_eq!(2, count(&mut conn));
+        assert!(!is_broken(&mut conn));
+    }
+
+    #[diesel_test_helper::test]
+    fn transaction_rolls_back_on_error() {
+        let mut conn = connection_with_table();
+
+        let result = block_on(conn.transaction::<(), _, _>(|conn| {
+            Box::pin(async move {
+                insert(conn, 1).await?;
+                Err(Error::RollbackTransaction)
+            })
+        }));
+        assert_eq!(Err(Error::RollbackTransaction), result);
+        assert_eq!(0, count(&mut conn));
+        assert!(!is_broken(&mut conn));
+    }
+
+    #[diesel_test_helper::test]
+    fn nested_transactions_use_savepoints() {
+        let mut conn = connection_with_table();
+
+        let result = block_on(conn.transaction(|conn| {
+            Box::pin(async move {
+                insert(conn, 1).await?;
+                let nested = conn
+                    .transaction::<(), _, _>(|conn| {
+                        Box::pin(async move {
+                            insert(conn, 2).await?;
+                            Err(Error::RollbackTransaction)
+                        })
+                    })
+                    .await;
+                assert_eq!(Err(Error::RollbackTransaction), nested);
+                QueryResult::Ok(())
+            })
+        }));
+        assert_eq!(Ok(()), result);
+        assert_eq!(1, count(&mut conn));
+    }
+
+    #[diesel_test_helper::test]
+    fn dropping_a_transaction_future_rolls_back_the_transaction() {
+        let mut conn = connection_with_table();
+
+        let (inserted_sender, inserted) = std::sync::mpsc::channel();
+        let mut transaction = Box::pin(conn.transaction::<(), Error, _>(|conn| {
+            Box::pin(async move {
+                insert(conn, 1).await?;
+                inserted_sender.send(()).unwrap();
+                core::future::pending().await
+            })
+        }));
+        poll_until(transaction.as_mut(), &inserted);
+        drop(transaction);
+
+        assert_eq!(0, count(&mut conn));
+        assert!(!is_broken(&mut conn));
+    }
+
+    #[diesel_test_helper::test]
+    fn dropping_a_transaction_future_during_a_query_rolls_back_the_transaction() {
+        let mut conn = connection_with_table();
+
+        let (started_sender, started) = std::sync::mpsc::channel();
+        let (finish, finish_receiver) = std::sync::mpsc::channel::<()>();
+        let mut transaction = Box::pin(conn.transaction::<usize, Error, _>(|conn| {
+            Box::pin(conn.run(move |conn| {
+                let inserted =
+                    crate::sql_query("INSERT INTO async_test (id) VALUES (1)").execute(conn);
+                started_sender.send(()).unwrap();
+                finish_receiver.recv().unwrap();
+                inserted
+            }))
+        }));
+        poll_until(transaction.as_mut(), &started);
+        // the query is still running, so this would dead lock
+        // if dropping the future waited for the rollback
+        drop(transaction);
+        finish.send(()).unwrap();
+
+        assert_eq!(0, count(&mut conn));
+        assert!(!is_broken(&mut conn));
+    }
+}
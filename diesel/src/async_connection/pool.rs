@@ -0,0 +1,158 @@
+use super::AsyncConnection;
+use crate::connection::Connection;
+use crate::pool::{Pool, PoolError, PoolState, PooledConnection};
+
+/// A pool of [`AsyncConnection`]s
+///
+/// This type wraps a [`Pool`], so connections are checked out with the
+/// health checks, lifetimes and timeouts configured for that pool. Each
+/// checked out connection is handed to a dedicated thread, therefore
+/// neither waiting for a connection nor establishing a new one blocks
+/// the executor.
+///
+/// A connection is returned to the pool once the
+/// [`AsyncPooledConnection`] is dropped and all queued closures were
+/// executed. Connections returned with an open transaction or a broken
+/// transaction manager are closed instead of being reused.
+#[allow(missing_debug_implementations)]
+pub struct AsyncPool<C> {
+    pool: Pool<C>,
+}
+
+/// A connection checked out from an [`AsyncPool`]
+pub type AsyncPooledConnection<C> = AsyncConnection<PooledConnection<C>>;
+
+impl<C> Clone for AsyncPool<C> {
+    fn clone(&self) -> Self {
+        Self {
+            pool: self.pool.clone(),
+        }
+    }
+}
+
+impl<C> From<Pool<C>> for AsyncPool<C> {
+    fn from(pool: Pool<C>) -> Self {
+        Self { pool }
+    }
+}
+
+impl<C> AsyncPool<C>
+where
+    C: Connection + 'static,
+{
+    /// Create a new pool for the given database with at most
+    /// `max_size` connections
+    ///
+    /// No connection is established by this function. Use
+    /// [`Pool::builder`] and [`AsyncPool::from`] to configure
+    /// the underlying pool.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `max_size` is zero
+    pub fn new(database_url: &str, max_size: usize) -> Self {
+        let pool = Pool::builder()
+            .max_size(max_size)
+            .build(database_url)
+            .expect("Building a pool without initial connections does not fail");
+        Self { pool }
+    }
+
+    /// Check out a connection from the pool
+    ///
+    /// This reuses an idle connection, establishes a new one if the pool
+    /// is not full yet, or waits until another connection is returned.
+    /// Waiting times out after the
+    /// [connection timeout](crate::pool::Builder::connection_timeout)
+    /// of the underlying pool.
+    pub async fn get(&self) -> Result<AsyncPooledConnection<C>, PoolError> {
+        let pool = self.pool.clone();
+        AsyncConnection::spawn(move || pool.get()).await
+    }
+
+    /// The current number of connections held by this pool
+    pub fn state(&self) -> PoolState {
+        self.pool.state()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::pin::pin;
+    use core::task::{Context, Waker};
+    use core::time::Duration;
+    use std::time::Instant;
+
+    use super::super::tests::block_on;
+    use super::*;
+    use crate::connection::{AnsiTransactionManager, TransactionManager};
+    use crate::test_helpers::*;
+
+    // connections are returned by the thread owning them,
+    // which happens asynchronously to dropping them
+    fn wait_for(mut condition: impl FnMut() -> bool) {
+        let start = Instant::now();
+        while !condition() {
+            assert!(
+                start.elapsed() < Duration::from_secs(10),
+                "Condition not met in time"
+            );
+            std::thread::sleep(Duration::from_millis(1));
+        }
+    }
+
+    #[diesel_test_helper::test]
+    fn connections_are_established_lazily_and_reused() {
+        let pool = AsyncPool::<TestConnection>::new(&database_url(), 2);
+        assert_eq!(0, pool.state().connections);
+
+        let conn = block_on(pool.get()).unwrap();
+        assert_eq!(1, pool.state().connections);
+        drop(conn);
+        wait_for(|| pool.state().idle_connections == 1);
+
+        let first = block_on(pool.get()).unwrap();
+        let second = block_on(pool.get()).unwrap();
+        assert_eq!(2, pool.state().connections);
+        assert_eq!(0, pool.state().idle_connections);
+        drop((first, second));
+        wait_for(|| pool.state().idle_connections == 2);
+    }
+
+    #[diesel_test_helper::test]
+    fn get_waits_for_a_returned_connection() {
+        let pool = AsyncPool::<TestConnection>::new(&database_url(), 1);
+        let conn = block_on(pool.get()).unwrap();
+
+        let waker = Waker::noop();
+        let mut cx = Context::from_waker(waker);
+        let mut waiting = pin!(pool.get());
+        assert!(waiting.as_mut().poll(&mut cx).is_pending());
+
+        drop(conn);
+        assert!(block_on(waiting).is_ok());
+        assert_eq!(1, pool.state().connections);
+    }
+
+    #[diesel_test_helper::test]
+    fn connections_with_open_transactions_are_closed() {
+        let pool = AsyncPool::<TestConnection>::new(&database_url(), 1);
+        let mut conn = block_on(pool.get()).unwrap();
+        block_on(conn.run(|conn| AnsiTransactionManager::begin_transaction(&mut **conn))).unwrap();
+        drop(conn);
+        wait_for(|| pool.state().connections == 0);
+
+        let mut conn = block_on(pool.get()).unwrap();
+        let broken = block_on(
+            conn.run(|conn| AnsiTransactionManager::is_broken_transaction_manager(&mut **conn)),
+        );
+        assert!(!broken);
+    }
+
+    #[diesel_test_helper::test]
+    fn failing_connections_release_their_slot() {
+        let pool = AsyncPool::<TestConnection>::new("invalid://localhost/database", 1);
+        assert!(block_on(pool.get()).is_err());
+        assert_eq!(0, pool.state().connections);
+    }
+}
@@ -0,0 +1,98 @@
+use core::future::Future;
+
+use super::AsyncConnection;
+use crate::connection::Connection;
+use crate::dsl::Limit;
+use crate::query_dsl::RunQueryDsl;
+use crate::query_dsl::load_dsl::{ExecuteDsl, LoadQuery};
+use crate::query_dsl::methods::LimitDsl;
+use crate::result::QueryResult;
+
+/// Methods used to execute queries with an [`AsyncConnection`]
+///
+/// Each method mirrors the method of [`RunQueryDsl`] with the same name
+/// without the `_async` suffix. The query is moved to the thread owning
+/// the connection, therefore it needs to be `Send + 'static`.
+///
+/// This trait is implemented for every type implementing [`RunQueryDsl`].
+pub trait AsyncRunQueryDsl<Conn>: RunQueryDsl<Conn> + Send + Sized + 'static
+where
+    Conn: Connection + 'static,
+{
+    /// Executes the given command, returning the number of rows affected
+    ///
+    /// See [`RunQueryDsl::execute`] for details.
+    fn execute_async(
+        self,
+        conn: &mut AsyncConnection<Conn>,
+    ) -> impl Future<Output = QueryResult<usize>> + Send + '_
+    where
+        Self: ExecuteDsl<Conn>,
+    {
+        conn.run(move |conn| ExecuteDsl::execute(self, conn))
+    }
+
+    /// Executes the given query, returning a `Vec` with the returned rows
+    ///
+    /// See [`RunQueryDsl::load`] for details.
+    fn load_async<U>(
+        self,
+        conn: &mut AsyncConnection<Conn>,
+    ) -> impl Future<Output = QueryResult<Vec<U>>> + Send + '_
+    where
+        U: Send + 'static,
+        Self: LoadQuery<'static, Conn, U>,
+    {
+        conn.run(move |conn| RunQueryDsl::load(self, conn))
+    }
+
+    /// Runs the command, and returns the affected row
+    ///
+    /// See [`RunQueryDsl::get_result`] for details.
+    fn get_result_async<U>(
+        self,
+        conn: &mut AsyncConnection<Conn>,
+    ) -> impl Future<Output = QueryResult<U>> + Send + '_
+    where
+        U: Send + 'static,
+        Self: LoadQuery<'static, Conn, U>,
+    {
+        conn.run(move |conn| RunQueryDsl::get_result(self, conn))
+    }
+
+    /// Runs the command, returning a `Vec` with the affected rows
+    ///
+    /// See [`RunQueryDsl::get_results`] for details.
+    fn get_results_async<U>(
+        self,
+        conn: &mut AsyncConnection<Conn>,
+    ) -> impl Future<Output = QueryResult<Vec<U>>> + Send + '_
+    where
+        U: Send + 'static,
+        Self: LoadQuery<'static, Conn, U>,
+    {
+        conn.run(move |conn| RunQueryDsl::get_results(self, conn))
+    }
+
+    /// Attempts to load a single record
+    ///
+    /// See [`RunQueryDsl::first`] for details.
+    fn first_async<U>(
+        self,
+        conn: &mut AsyncConnection<Conn>,
+    ) -> impl Future<Output = QueryResult<U>> + Send + '_
+    where
+        U: Send + 'static,
+        Self: LimitDsl,
+        Limit<Self>: LoadQuery<'static, Conn, U>,
+    {
+        conn.run(move |conn| RunQueryDsl::first(self, conn))
+    }
+}
+
+impl<T, Conn> AsyncRunQueryDsl<Conn> for T
+where
+    T: RunQueryDsl<Conn> + Send + 'static,
+    Conn: Connection + 'static,
+{
+}
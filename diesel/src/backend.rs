@@ -341,6 +341,17 @@ pub trait SqlDialect: self::private::TrustedBackend {
         doc = "See [`sql_dialect::built_in_window_function_require_order`] for provided default implementations"
     )]
     type BuiltInWindowFunctionRequireOrder;
+
+    /// Configures how this backend adds intervals to or subtracts them from
+    /// date and time values
+    ///
+    /// This allows backends to provide custom [`QueryFragment`](crate::query_builder::QueryFragment)
+    /// implementations for the `+` and `-` operators
+    #[cfg_attr(
+        feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes",
+        doc = "See [`sql_dialect::interval_arithmetic`] for provided default implementations"
+    )]
+    type IntervalArithmetic;
 }
 
 /// This module contains all options provided by diesel to configure the [`SqlDialect`] trait.
@@ -404,6 +415,13 @@ pub(crate) mod sql_dialect {
         /// implementing this trait opts in supporting `RETURNING` clause syntax
         pub trait SupportsReturningClause {}
 
+        /// A marker trait indicating if a `RETURNING` clause is supported
+        /// for `UPDATE` statements
+        ///
+        /// Some backends, like MariaDB, only support `RETURNING` clauses
+        /// for `INSERT` and `DELETE` statements.
+        pub trait SupportsReturningClauseForUpdate: SupportsReturningClause {}
+
         /// Indicates that a backend provides support for `RETURNING` clauses
         /// using the postgresql `RETURNING` syntax
         #[derive(Debug, Copy, Clone)]
@@ -414,6 +432,7 @@ pub(crate) mod sql_dialect {
         pub struct DoesNotSupportReturningClause;
 
         impl SupportsReturningClause for PgLikeReturningClause {}
+        impl SupportsReturningClauseForUpdate for PgLikeReturningClause {}
     }
 
     /// This module contains all reusable options to configure
@@ -617,6 +636,17 @@ pub(crate) mod sql_dialect {
         #[derive(Debug, Copy, Clone)]
         pub struct NoOrderRequired;
     }
+
+    /// This module contains all reusable options to configure [`SqlDialect::IntervalArithmetic`]
+    #[diesel_derives::__diesel_public_if(
+        feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+    )]
+    pub mod interval_arithmetic {
+        /// Indicates that this backend adds intervals to date and time values
+        /// with the `+` and `-` operators, as specified by the SQL standard
+        #[derive(Debug, Copy, Clone)]
+        pub struct AnsiSqlIntervalArithmetic;
+    }
 }
 
 // These traits are not part of the public API
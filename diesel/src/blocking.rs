@@ -0,0 +1,516 @@
+//! Run diesel queries from async code on dedicated threads
+//!
+//! Note: This module requires enabling the `blocking` feature
+//!
+//! Diesel connections are blocking, so executing queries directly inside of
+//! an async task blocks the executor thread. [`BlockingPool`] owns a fixed
+//! number of connections, each on its own thread, and exposes an
+//! `async fn run` that hands a closure to one of these threads and waits
+//! for its result without blocking the executor. This module does not depend
+//! on a specific async runtime, so it can be used with `tokio` or any other
+//! executor.
+//!
+//! # Example
+//!
+//! ```rust
+//! # include!("doctest_setup.rs");
+//! # use std::future::Future;
+//! # use std::pin::pin;
+//! # use std::sync::Arc;
+//! # use std::task::{Context, Poll, Wake, Waker};
+//! # use std::thread::{self, Thread};
+//! #
+//! # // a minimal executor, use the one provided by your async runtime instead
+//! # fn block_on<F: Future>(future: F) -> F::Output {
+//! #     struct ThreadWaker(Thread);
+//! #     impl Wake for ThreadWaker {
+//! #         fn wake(self: Arc<Self>) {
+//! #             self.0.unpark();
+//! #         }
+//! #     }
+//! #     let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+//! #     let mut cx = Context::from_waker(&waker);
+//! #     let mut future = pin!(future);
+//! #     loop {
+//! #         match future.as_mut().poll(&mut cx) {
+//! #             Poll::Ready(output) => return output,
+//! #             Poll::Pending => thread::park(),
+//! #         }
+//! #     }
+//! # }
+//! #
+//! use diesel::blocking::BlockingPool;
+//!
+//! async fn user_names(pool: &BlockingPool<DbConnection>) -> QueryResult<Vec<String>> {
+//!     use schema::users::dsl::*;
+//!
+//!     pool.run(|conn| users.select(name).order(id).load(conn))
+//!         .await
+//! }
+//!
+//! # fn main() {
+//! let pool = BlockingPool::<DbConnection>::establish(&database_url_for_env(), 1)
+//!     .expect("Could not establish the connections of the pool");
+//! # block_on(pool.run(|conn| setup_database(conn)));
+//! let names = block_on(user_names(&pool)).unwrap();
+//! assert_eq!(vec!["Sean", "Tess"], names);
+//! # }
+//! ```
+
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use core::future::poll_fn;
+use core::panic::AssertUnwindSafe;
+use core::task::{Context, Poll, Waker};
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, JoinHandle};
+
+use crate::connection::{Connection, TransactionManager};
+use crate::result::ConnectionResult;
+
+pub(crate) type Job<C> = Box<dyn FnOnce(&mut C) + Send>;
+
+/// A fixed size pool of connections, each owned by a dedicated thread
+///
+/// Closures passed to [`BlockingPool::run`] are executed on the next free
+/// connection. At most `pool_size` closures wait for a free connection, further
+/// calls to [`BlockingPool::run`] wait asynchronously for a free slot in that
+/// queue. This limits the amount of work accepted by the pool if the database
+/// cannot keep up with the application.
+///
+/// Connections that are left with an open transaction or a broken
+/// transaction manager by a closure are replaced by a new connection.
+///
+/// Dropping the pool lets the worker threads finish all queued closures
+/// before they close their connections.
+///
+/// See the [module documentation](self) for an example.
+#[allow(missing_debug_implementations)]
+pub struct BlockingPool<C> {
+    queue: JobQueue<C>,
+    workers: Vec<JoinHandle<()>>,
+}
+
+impl<C> BlockingPool<C>
+where
+    C: Connection + 'static,
+{
+    /// Establish `pool_size` connections to the given database and
+    /// start a thread for each of them
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `pool_size` is zero
+    pub fn establish(database_url: &str, pool_size: usize) -> ConnectionResult<Self> {
+        assert!(
+            pool_size > 0,
+            "A blocking pool needs at least one connection"
+        );
+        let connections = (0..pool_size)
+            .map(|_| C::establish(database_url))
+            .collect::<ConnectionResult<Vec<_>>>()?;
+        let queue = JobQueue::new(Some(database_url), pool_size);
+        let workers = connections
+            .into_iter()
+            .map(|conn| queue.spawn_worker(conn))
+            .collect();
+        Ok(Self { queue, workers })
+    }
+
+    /// Execute the given closure with a connection of this pool
+    ///
+    /// The closure is executed on the thread owning the connection.
+    /// The returned future waits for a free slot in the queue of the
+    /// pool and afterwards for the result of the closure. If the closure
+    /// panics, the panic is resumed in the calling task.
+    ///
+    /// Dropping the returned future after the closure was queued does
+    /// not cancel the execution of the closure.
+    pub async fn run<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut C) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.queue.run(f).await
+    }
+
+    /// The number of connections owned by this pool
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+}
+
+impl<C> Drop for BlockingPool<C> {
+    fn drop(&mut self) {
+        self.queue.shutdown();
+    }
+}
+
+/// The queue of closures executed by the threads owning the connections
+/// of a [`BlockingPool`] or an `AsyncConnection`
+///
+/// The owner of the queue needs to call [`JobQueue::shutdown`] once it
+/// is dropped, which lets the threads exit after all queued closures
+/// were executed.
+pub(crate) struct JobQueue<C> {
+    shared: Arc<Shared<C>>,
+}
+
+struct Shared<C> {
+    // used to replace connections that are left with an open transaction
+    // or a broken transaction manager, `None` keeps these connections
+    database_url: Option<String>,
+    queue_size: usize,
+    state: Mutex<State<C>>,
+    job_available: Condvar,
+}
+
+struct State<C> {
+    queue: VecDeque<Job<C>>,
+    waiting_for_slot: Vec<Waker>,
+    shutdown: bool,
+}
+
+pub(crate) fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // panics of user code are caught before touching shared state,
+    // so the protected data is always consistent
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<C> Clone for JobQueue<C> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<C> JobQueue<C>
+where
+    C: Connection + 'static,
+{
+    /// Create a queue accepting at most `queue_size` closures
+    /// at the same time via [`JobQueue::run`]
+    pub(crate) fn new(database_url: Option<&str>, queue_size: usize) -> Self {
+        Self {
+            shared: Arc::new(Shared {
+                database_url: database_url.map(ToOwned::to_owned),
+                queue_size,
+                state: Mutex::new(State {
+                    queue: VecDeque::new(),
+                    waiting_for_slot: Vec::new(),
+                    shutdown: false,
+                }),
+                job_available: Condvar::new(),
+            }),
+        }
+    }
+
+    /// Start a thread executing the queued closures with the given connection
+    pub(crate) fn spawn_worker(&self, conn: C) -> JoinHandle<()> {
+        let shared = Arc::clone(&self.shared);
+        thread::spawn(move || shared.work(conn))
+    }
+
+    /// Execute the given closure on a thread owning a connection
+    ///
+    /// See [`BlockingPool::run`] for details.
+    pub(crate) async fn run<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut C) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (sender, result) = oneshot();
+        let mut job: Option<Job<C>> = Some(Box::new(move |conn: &mut C| {
+            sender.send(std::panic::catch_unwind(AssertUnwindSafe(|| f(conn))));
+        }));
+
+        poll_fn(|cx| self.shared.try_enqueue(&mut job, cx)).await;
+        result
+            .recv()
+            .await
+            .expect("Queued closures are executed before the threads exit")
+            .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+    }
+}
+
+#[cfg(feature = "async")]
+impl<C> JobQueue<C>
+where
+    C: Connection + 'static,
+{
+    /// Start a thread that acquires its connection via `connect` before
+    /// it executes the queued closures
+    ///
+    /// The returned future resolves once the connection was acquired, this
+    /// does not block the calling task. If `connect` panics, the panic
+    /// is resumed in the calling task.
+    pub(crate) async fn spawn_worker_with<F, E>(&self, connect: F) -> Result<(), E>
+    where
+        F: FnOnce() -> Result<C, E> + Send + 'static,
+        E: Send + 'static,
+    {
+        let (sender, connected) = oneshot();
+        let shared = Arc::clone(&self.shared);
+        thread::spawn(
+            move || match std::panic::catch_unwind(AssertUnwindSafe(connect)) {
+                Ok(Ok(conn)) => {
+                    sender.send(Ok(Ok(())));
+                    shared.work(conn);
+                }
+                Ok(Err(e)) => sender.send(Ok(Err(e))),
+                Err(payload) => sender.send(Err(payload)),
+            },
+        );
+        connected
+            .recv()
+            .await
+            .expect("The result is always sent")
+            .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+    }
+
+    /// Queue the given closure without waiting for a free slot
+    /// or for its execution
+    pub(crate) fn push(&self, job: Job<C>) {
+        lock(&self.shared.state).queue.push_back(job);
+        self.shared.job_available.notify_one();
+    }
+}
+
+impl<C> JobQueue<C> {
+    /// Let the threads exit after all queued closures were executed
+    pub(crate) fn shutdown(&self) {
+        lock(&self.shared.state).shutdown = true;
+        self.shared.job_available.notify_all();
+    }
+}
+
+impl<C> Shared<C>
+where
+    C: Connection + 'static,
+{
+    fn try_enqueue(&self, job: &mut Option<Job<C>>, cx: &mut Context<'_>) -> Poll<()> {
+        let mut state = lock(&self.state);
+        if state.queue.len() < self.queue_size {
+            let job = job.take().expect("The job is only enqueued once");
+            state.queue.push_back(job);
+            drop(state);
+            self.job_available.notify_one();
+            Poll::Ready(())
+        } else {
+            state.waiting_for_slot.push(cx.waker().clone());
+            Poll::Pending
+        }
+    }
+
+    fn next_job(&self) -> Option<Job<C>> {
+        let mut state = lock(&self.state);
+        loop {
+            if let Some(job) = state.queue.pop_front() {
+                // wake all waiting tasks as some of them might
+                // have been dropped in the meantime
+                let waiting = core::mem::take(&mut state.waiting_for_slot);
+                drop(state);
+                waiting.into_iter().for_each(Waker::wake);
+                return Some(job);
+            }
+            if state.shutdown {
+                return None;
+            }
+            state = self
+                .job_available
+                .wait(state)
+                .unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    fn work(&self, mut conn: C) {
+        while let Some(job) = self.next_job() {
+            job(&mut conn);
+            if let Some(database_url) = &self.database_url
+                && C::TransactionManager::is_broken_transaction_manager(&mut conn)
+            {
+                // keep the broken connection if we cannot replace it,
+                // so that the next closure receives an error from it
+                if let Ok(new_conn) = C::establish(database_url) {
+                    conn = new_conn;
+                }
+            }
+        }
+    }
+}
+
+struct OneshotState<T> {
+    value: Option<T>,
+    closed: bool,
+    waker: Option<Waker>,
+}
+
+struct OneshotSender<T>(Arc<Mutex<OneshotState<T>>>);
+
+struct OneshotReceiver<T>(Arc<Mutex<OneshotState<T>>>);
+
+fn oneshot<T>() -> (OneshotSender<T>, OneshotReceiver<T>) {
+    let state = Arc::new(Mutex::new(OneshotState {
+        value: None,
+        closed: false,
+        waker: None,
+    }));
+    (OneshotSender(Arc::clone(&state)), OneshotReceiver(state))
+}
+
+impl<T> OneshotSender<T> {
+    fn send(self, value: T) {
+        lock(&self.0).value = Some(value);
+    }
+}
+
+impl<T> Drop for OneshotSender<T> {
+    fn drop(&mut self) {
+        let mut state = lock(&self.0);
+        state.closed = true;
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> OneshotReceiver<T> {
+    /// Waits for the value, returns `None` if the sender
+    /// was dropped without sending a value
+    async fn recv(self) -> Option<T> {
+        poll_fn(|cx| {
+            let mut state = lock(&self.0);
+            match state.value.take() {
+                Some(value) => Poll::Ready(Some(value)),
+                None if state.closed => Poll::Ready(None),
+                None => {
+                    state.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::future::Future;
+    use core::pin::pin;
+    use std::sync::mpsc;
+    use std::task::Wake;
+    use std::thread::Thread;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::connection::AnsiTransactionManager;
+    use crate::prelude::*;
+    use crate::test_helpers::*;
+
+    struct ThreadWaker(Thread);
+
+    impl Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    fn assert_send<T: Send>(t: T) -> T {
+        t
+    }
+
+    #[diesel_test_helper::test]
+    fn run_executes_closures_on_the_pool() {
+        use crate::dsl::sql;
+        use crate::sql_types::Integer;
+
+        let pool = BlockingPool::<TestConnection>::establish(&database_url(), 2).unwrap();
+        assert_eq!(2, pool.size());
+
+        let result =
+            block_on(assert_send(pool.run(|conn| {
+                crate::select(sql::<Integer>("1 + 1")).get_result::<i32>(conn)
+            })));
+        assert_eq!(Ok(2), result);
+    }
+
+    #[diesel_test_helper::test]
+    fn run_waits_for_a_free_slot_in_the_queue() {
+        let pool = BlockingPool::<TestConnection>::establish(&database_url(), 1).unwrap();
+        let (blocked_sender, blocked) = mpsc::channel::<()>();
+        let (release, released) = mpsc::channel::<()>();
+
+        // occupy the only connection and the only slot in the queue
+        let mut running = pin!(pool.run(move |_| {
+            blocked_sender.send(()).unwrap();
+            released.recv().unwrap();
+        }));
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        assert!(running.as_mut().poll(&mut cx).is_pending());
+        blocked.recv().unwrap();
+        let mut queued = pin!(pool.run(|_| 1));
+        assert!(queued.as_mut().poll(&mut cx).is_pending());
+
+        let mut waiting = pin!(pool.run(|_| 2));
+        assert!(waiting.as_mut().poll(&mut cx).is_pending());
+        assert_eq!(1, lock(&pool.queue.shared.state).queue.len());
+
+        release.send(()).unwrap();
+        block_on(running);
+        assert_eq!(1, block_on(queued));
+        assert_eq!(2, block_on(waiting));
+    }
+
+    #[diesel_test_helper::test]
+    fn panics_are_resumed_in_the_calling_task() {
+        let pool = BlockingPool::<TestConnection>::establish(&database_url(), 1).unwrap();
+
+        let result = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            block_on(pool.run(|_| panic!("closure panicked")))
+        }));
+        assert!(result.is_err());
+
+        // the worker thread is still available
+        assert_eq!(42, block_on(pool.run(|_| 42)));
+    }
+
+    #[diesel_test_helper::test]
+    fn connections_with_open_transactions_are_replaced() {
+        let pool = BlockingPool::<TestConnection>::establish(&database_url(), 1).unwrap();
+
+        block_on(pool.run(|conn| {
+            AnsiTransactionManager::begin_transaction(conn).unwrap();
+        }));
+        let broken = block_on(pool.run(AnsiTransactionManager::is_broken_transaction_manager));
+        assert!(!broken);
+    }
+
+    #[diesel_test_helper::test]
+    fn dropping_the_pool_finishes_queued_closures() {
+        let pool = BlockingPool::<TestConnection>::establish(&database_url(), 1).unwrap();
+        let (sender, receiver) = mpsc::channel();
+
+        let waker = Waker::from(Arc::new(ThreadWaker(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        let mut queued = Box::pin(pool.run(move |_| sender.send(()).unwrap()));
+        assert!(queued.as_mut().poll(&mut cx).is_pending());
+        drop(queued);
+        drop(pool);
+
+        receiver.recv_timeout(Duration::from_secs(10)).unwrap();
+    }
+}
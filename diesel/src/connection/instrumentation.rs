@@ -1,7 +1,10 @@
 use alloc::boxed::Box;
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt::{Debug, Display};
 use core::num::NonZeroU32;
 use core::ops::DerefMut;
+use core::time::Duration;
 use downcast_rs::Downcast;
 
 #[cfg(feature = "std")]
@@ -12,9 +15,120 @@ static GLOBAL_INSTRUMENTATION: std::sync::RwLock<fn() -> Option<Box<dyn Instrume
 /// which allows to get a `Display` and `Debug`
 /// representation of the underlying type without
 /// exposing type specific details
-pub trait DebugQuery: Debug + Display {}
+pub trait DebugQuery: Debug + Display {
+    /// Structured information about the query
+    ///
+    /// The information is collected from the query AST
+    /// when this function is called. It returns `None` for
+    /// queries that are only available as SQL string, for example
+    /// queries executed via
+    /// [`SimpleConnection::batch_execute`](crate::connection::SimpleConnection::batch_execute)
+    fn statement_info(&self) -> Option<StatementInfo> {
+        None
+    }
+}
+
+impl<T, DB> DebugQuery for crate::query_builder::DebugQuery<'_, T, DB>
+where
+    Self: Debug + Display,
+    DB: crate::backend::Backend + Default,
+    T: crate::query_builder::QueryFragment<DB>,
+{
+    fn statement_info(&self) -> Option<StatementInfo> {
+        crate::query_builder::debug_query::statement_info(self.query).ok()
+    }
+}
+
+/// The kind of a SQL statement
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StatementKind {
+    /// A `SELECT` statement, including `UNION`, `INTERSECT`
+    /// and `EXCEPT` queries
+    Select,
+    /// An `INSERT` statement, including `REPLACE` and upserts
+    Insert,
+    /// An `UPDATE` statement
+    Update,
+    /// A `DELETE` statement
+    Delete,
+    /// Any other statement, for example a query
+    /// constructed via [`sql_query`](crate::sql_query())
+    Other,
+}
+
+/// Structured information about a query
+///
+/// This information is returned by [`DebugQuery::statement_info`].
+/// It is collected from the query AST instead of the generated SQL.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct StatementInfo {
+    kind: Option<StatementKind>,
+    tables: Vec<String>,
+    bind_count: usize,
+}
+
+impl StatementInfo {
+    /// The kind of the statement
+    ///
+    /// For statements containing subqueries this is
+    /// the kind of the outermost statement.
+    pub fn kind(&self) -> StatementKind {
+        self.kind.unwrap_or(StatementKind::Other)
+    }
+
+    /// The names of all tables referenced by the statement
+    ///
+    /// Each table is listed once, in the order of its first appearance
+    /// in the query. Tables in a schema other than the default schema are
+    /// qualified with the schema name, e.g. `"schema.table"`.
+    pub fn tables(&self) -> &[String] {
+        &self.tables
+    }
+
+    /// The number of bind parameters sent with the statement
+    pub fn bind_count(&self) -> usize {
+        self.bind_count
+    }
+
+    pub(crate) fn set_kind(&mut self, kind: StatementKind) {
+        // subqueries are walked after the outer statement
+        self.kind.get_or_insert(kind);
+    }
 
-impl<T, DB> DebugQuery for crate::query_builder::DebugQuery<'_, T, DB> where Self: Debug + Display {}
+    pub(crate) fn clear_kind(&mut self) {
+        self.kind = None;
+    }
+
+    pub(crate) fn has_kind(&self) -> bool {
+        self.kind.is_some()
+    }
+
+    pub(crate) fn add_table(&mut self, table: String) {
+        if !self.tables.contains(&table) {
+            self.tables.push(table);
+        }
+    }
+
+    pub(crate) fn set_bind_count(&mut self, bind_count: usize) {
+        self.bind_count = bind_count;
+    }
+}
+
+/// Whether the prepared statement of a query was
+/// taken from the prepared statement cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum StatementCacheStatus {
+    /// The statement was already cached
+    Hit,
+    /// The statement was prepared and stored in the cache
+    Miss,
+    /// The statement was prepared without storing it in the cache,
+    /// either because it is not safe to cache or because the
+    /// cache does not have capacity for it
+    Uncached,
+}
 
 /// A helper type that allows printing out str slices
 ///
@@ -34,12 +148,6 @@ impl<'query> StrQueryHelper<'query> {
     #[diesel_derives::__diesel_public_if(
         feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
     )]
-    #[cfg(any(
-        feature = "postgres",
-        feature = "__sqlite-shared",
-        feature = "mysql",
-        feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
-    ))]
     pub(crate) fn new(s: &'query str) -> Self {
         Self { s }
     }
@@ -81,7 +189,7 @@ impl DebugQuery for StrQueryHelper<'_> {}
 // type, as that makes it easier to reuse
 // `Instrumentation` implementations in
 // a different context
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 #[non_exhaustive]
 pub enum InstrumentationEvent<'a> {
     /// An event emitted by before starting
@@ -145,6 +253,37 @@ pub enum InstrumentationEvent<'a> {
         query: &'a dyn DebugQuery,
         /// An optional error if the connection failed
         error: Option<&'a crate::result::Error>,
+        /// The time elapsed since the corresponding
+        /// [`InstrumentationEvent::StartQuery`] event
+        ///
+        /// This is `None` if the connection does not measure
+        /// the duration, e.g. for targets without a clock.
+        duration: Option<Duration>,
+        /// Whether the prepared statement of this query was
+        /// taken from the prepared statement cache
+        ///
+        /// This is `None` if no prepared statement was used.
+        statement_cache: Option<StatementCacheStatus>,
+    },
+    /// An event that is emitted after executing a query
+    /// for which the database reported warnings
+    ///
+    /// This event is only emitted by
+    /// [`Connection::execute_returning_count_with_warnings`](super::Connection::execute_returning_count_with_warnings)
+    /// and follows the corresponding [`InstrumentationEvent::FinishQuery`] event.
+    #[non_exhaustive]
+    QueryWarnings {
+        /// A opaque representation of the query
+        ///
+        /// This type implements [`Debug`] and [`Display`],
+        /// but should be considered otherwise as opaque.
+        ///
+        /// The exact output of the [`Debug`] and [`Display`]
+        /// implementation is not considered as part of the
+        /// stable API.
+        query: &'a dyn DebugQuery,
+        /// The warnings reported by the database
+        warnings: &'a [super::DatabaseWarning],
     },
     /// An event that is emitted while
     /// starting a new transaction
@@ -204,13 +343,13 @@ impl<'a> InstrumentationEvent<'a> {
         Self::CacheQuery { sql }
     }
 
-    /// Create a new `InstrumentationEvent::FinishQuery` event
+    /// Create a new `InstrumentationEvent::QueryWarnings` event
     #[cfg(feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes")]
-    pub fn finish_query(
+    pub fn query_warnings(
         query: &'a dyn DebugQuery,
-        error: Option<&'a crate::result::Error>,
+        warnings: &'a [super::DatabaseWarning],
     ) -> Self {
-        Self::FinishQuery { query, error }
+        Self::QueryWarnings { query, warnings }
     }
 
     /// Create a new `InstrumentationEvent::BeginTransaction` event
@@ -232,6 +371,36 @@ impl<'a> InstrumentationEvent<'a> {
     }
 }
 
+impl<'a> InstrumentationEvent<'a> {
+    /// Create a new `InstrumentationEvent::FinishQuery` event
+    ///
+    /// The duration and the statement cache status are filled in
+    /// by [`DynInstrumentation`]
+    #[diesel_derives::__diesel_public_if(
+        feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+    )]
+    #[cfg_attr(
+        not(any(
+            feature = "postgres",
+            feature = "__sqlite-shared",
+            feature = "mysql",
+            feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+        )),
+        expect(dead_code)
+    )]
+    pub(crate) fn finish_query(
+        query: &'a dyn DebugQuery,
+        error: Option<&'a crate::result::Error>,
+    ) -> Self {
+        Self::FinishQuery {
+            query,
+            error,
+            duration: None,
+            statement_cache: None,
+        }
+    }
+}
+
 /// A type that provides an connection `Instrumentation`
 ///
 /// This trait is the basic building block for logging or
@@ -245,6 +414,38 @@ impl<'a> InstrumentationEvent<'a> {
 /// More complex usages and integrations with frameworks like
 /// `tracing` and `log` are supposed to be part of their own
 /// crates.
+///
+/// Several instrumentations can be registered at once by passing
+/// a `Vec` of them. Each event is passed to all of them in order.
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// use diesel::connection::{Instrumentation, InstrumentationEvent};
+///
+/// # fn main() {
+/// let mut conn = establish_connection();
+/// let logger = |event: InstrumentationEvent<'_>| println!("{event:?}");
+/// let metrics = |event: InstrumentationEvent<'_>| {
+///     if let InstrumentationEvent::FinishQuery {
+///         query, duration, ..
+///     } = event
+///     {
+///         let info = query.statement_info();
+///         // record `duration` for `info.kind()` and `info.tables()` here
+///     }
+/// };
+/// conn.set_instrumentation(vec![
+///     Box::new(logger) as Box<dyn Instrumentation>,
+///     Box::new(metrics),
+/// ]);
+///
+/// // more instrumentations can be added later on
+/// conn.instrumentation()
+///     .downcast_mut::<Vec<Box<dyn Instrumentation>>>()
+///     .expect("A list of instrumentations is registered")
+///     .push(Box::new(|event: InstrumentationEvent<'_>| println!("{event:?}")));
+/// # }
+/// ```
 pub trait Instrumentation: Downcast + Send + 'static {
     /// The function that is invoked for each event
     fn on_connection_event(&mut self, event: InstrumentationEvent<'_>);
@@ -324,6 +525,17 @@ where
     }
 }
 
+impl<T> Instrumentation for Vec<T>
+where
+    T: Instrumentation,
+{
+    fn on_connection_event(&mut self, event: InstrumentationEvent<'_>) {
+        for i in self {
+            i.on_connection_event(event)
+        }
+    }
+}
+
 #[diesel_derives::__diesel_public_if(
     feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
 )]
@@ -344,7 +556,161 @@ where
 pub(crate) struct DynInstrumentation {
     /// zst
     no_instrumentation: NoInstrumentation,
+    tracked: TrackedInstrumentation,
+}
+
+/// Forwards all events to the wrapped instrumentation and fills in
+/// the duration and the statement cache status of
+/// [`InstrumentationEvent::FinishQuery`] events
+#[cfg(any(
+    feature = "postgres",
+    feature = "__sqlite-shared",
+    feature = "mysql",
+    feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+))]
+struct TrackedInstrumentation {
     inner: Option<Box<dyn Instrumentation>>,
+    running_queries: Vec<RunningQuery>,
+}
+
+/// Queries might be nested, e.g. while looking up type metadata
+/// for the binds of another query. This limit guards against
+/// connections that do not emit a `FinishQuery` event for each
+/// `StartQuery` event.
+#[cfg(any(
+    feature = "postgres",
+    feature = "__sqlite-shared",
+    feature = "mysql",
+    feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+))]
+const MAX_RUNNING_QUERIES: usize = 16;
+
+#[cfg(any(
+    feature = "postgres",
+    feature = "__sqlite-shared",
+    feature = "mysql",
+    feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+))]
+struct RunningQuery {
+    #[cfg(all(
+        feature = "std",
+        not(all(target_family = "wasm", target_os = "unknown"))
+    ))]
+    start: std::time::Instant,
+    statement_cache: Option<StatementCacheStatus>,
+}
+
+#[cfg(any(
+    feature = "postgres",
+    feature = "__sqlite-shared",
+    feature = "mysql",
+    feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+))]
+impl RunningQuery {
+    fn start() -> Self {
+        Self {
+            #[cfg(all(
+                feature = "std",
+                not(all(target_family = "wasm", target_os = "unknown"))
+            ))]
+            start: std::time::Instant::now(),
+            statement_cache: None,
+        }
+    }
+
+    fn elapsed(&self) -> Option<Duration> {
+        #[cfg(all(
+            feature = "std",
+            not(all(target_family = "wasm", target_os = "unknown"))
+        ))]
+        {
+            Some(self.start.elapsed())
+        }
+        #[cfg(not(all(
+            feature = "std",
+            not(all(target_family = "wasm", target_os = "unknown"))
+        )))]
+        {
+            None
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "postgres",
+    feature = "__sqlite-shared",
+    feature = "mysql",
+    feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+))]
+impl TrackedInstrumentation {
+    fn new(inner: Option<Box<dyn Instrumentation>>) -> Self {
+        Self {
+            inner,
+            running_queries: Vec::new(),
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "postgres",
+    feature = "__sqlite-shared",
+    feature = "mysql",
+    feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+))]
+impl Instrumentation for TrackedInstrumentation {
+    fn on_connection_event(&mut self, event: InstrumentationEvent<'_>) {
+        let Self {
+            inner,
+            running_queries,
+        } = self;
+        // don't track anything if nobody is interested in the events
+        let Some(inner) = inner.as_deref_mut() else {
+            return;
+        };
+        let event = match event {
+            InstrumentationEvent::StartQuery { .. } => {
+                if running_queries.len() >= MAX_RUNNING_QUERIES {
+                    running_queries.remove(0);
+                }
+                running_queries.push(RunningQuery::start());
+                event
+            }
+            InstrumentationEvent::FinishQuery { query, error, .. } => match running_queries.pop() {
+                Some(running) => InstrumentationEvent::FinishQuery {
+                    query,
+                    error,
+                    duration: running.elapsed(),
+                    statement_cache: running.statement_cache,
+                },
+                None => event,
+            },
+            event => event,
+        };
+        inner.on_connection_event(event)
+    }
+}
+
+/// Records the statement cache status of the currently running query
+///
+/// This only has an effect if `instrumentation` is the instrumentation
+/// returned by [`DynInstrumentation::tracked`]
+#[cfg(any(
+    feature = "postgres",
+    feature = "__sqlite-shared",
+    feature = "mysql",
+    feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+))]
+pub(crate) fn record_statement_cache_status(
+    instrumentation: &mut dyn Instrumentation,
+    status: StatementCacheStatus,
+) {
+    if let Some(tracked) = instrumentation
+        .as_any_mut()
+        .downcast_mut::<TrackedInstrumentation>()
+        && let Some(query) = tracked.running_queries.last_mut()
+    {
+        query.statement_cache = Some(status);
+    }
 }
 
 #[cfg(any(
@@ -357,7 +723,10 @@ impl core::ops::Deref for DynInstrumentation {
     type Target = dyn Instrumentation;
 
     fn deref(&self) -> &Self::Target {
-        self.inner.as_deref().unwrap_or(&self.no_instrumentation)
+        self.tracked
+            .inner
+            .as_deref()
+            .unwrap_or(&self.no_instrumentation)
     }
 }
 
@@ -369,7 +738,8 @@ impl core::ops::Deref for DynInstrumentation {
 ))]
 impl DerefMut for DynInstrumentation {
     fn deref_mut(&mut self) -> &mut Self::Target {
-        self.inner
+        self.tracked
+            .inner
             .as_deref_mut()
             .unwrap_or(&mut self.no_instrumentation)
     }
@@ -394,7 +764,7 @@ impl DynInstrumentation {
     ))]
     pub(crate) fn default_instrumentation() -> Self {
         Self {
-            inner: get_default_instrumentation(),
+            tracked: TrackedInstrumentation::new(get_default_instrumentation()),
             no_instrumentation: NoInstrumentation,
         }
     }
@@ -411,7 +781,7 @@ impl DynInstrumentation {
     ))]
     pub(crate) fn none() -> Self {
         Self {
-            inner: None,
+            tracked: TrackedInstrumentation::new(None),
             no_instrumentation: NoInstrumentation,
         }
     }
@@ -430,9 +800,27 @@ impl DynInstrumentation {
         // This implementation is not necessary to be able to call this method on this object
         // because of the already existing Deref impl.
         // However it allows avoiding the dynamic dispatch to the stub value
-        if let Some(inner) = self.inner.as_deref_mut() {
-            inner.on_connection_event(event)
-        }
+        // and it fills in the duration of finished queries
+        self.tracked.on_connection_event(event)
+    }
+
+    /// The instrumentation that should be passed to functions
+    /// emitting events for this connection
+    ///
+    /// In contrast to dereferencing this type, which returns the
+    /// wrapped instrumentation, this fills in the duration and the
+    /// statement cache status of finished queries.
+    #[diesel_derives::__diesel_public_if(
+        feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+    )]
+    #[cfg(any(
+        feature = "postgres",
+        feature = "__sqlite-shared",
+        feature = "mysql",
+        feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+    ))]
+    pub(crate) fn tracked(&mut self) -> &mut dyn Instrumentation {
+        &mut self.tracked
     }
 }
 
@@ -445,7 +833,9 @@ impl DynInstrumentation {
 impl<I: Instrumentation> From<I> for DynInstrumentation {
     fn from(instrumentation: I) -> Self {
         Self {
-            inner: Some(unpack_instrumentation(Box::new(instrumentation))),
+            tracked: TrackedInstrumentation::new(Some(unpack_instrumentation(Box::new(
+                instrumentation,
+            )))),
             no_instrumentation: NoInstrumentation,
         }
     }
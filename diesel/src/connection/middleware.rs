@@ -0,0 +1,514 @@
+//! Helpers to wrap a [`Connection`] and to intercept the queries executed on it
+//!
+//! The main entry point of this module is [`MiddlewareConnection`]. It wraps
+//! any connection type and forwards all queries to the wrapped connection,
+//! after passing them through a [`ConnectionMiddleware`] implementation. A
+//! middleware can inspect, reject or retry queries, for example to log them,
+//! to collect metrics or to retry statements after serialization failures.
+//! [`LoggingConnection`] is a ready to use example, which logs each query
+//! together with the time it took to execute.
+//!
+//! A `MiddlewareConnection` can be used everywhere a connection of the wrapped
+//! type can be used, including transactions, migrations and connection pools.
+//! The wrapped connection is accessible via
+//! [`ConnectionWrapper::inner_connection`] for connection specific methods.
+//!
+//! If you implement your own wrapper connection type instead, which
+//! requires the `i-implement-a-third-party-backend-and-opt-into-breaking-changes`
+//! feature, you can implement [`ConnectionWrapper`] for it and use the
+//! [`DelegatingTransactionManager`] as its transaction manager to forward
+//! all transaction handling to the wrapped connection.
+//!
+//! # Example
+//!
+//! A middleware that retries statements failing with a
+//! [`SerializationFailure`](crate::result::DatabaseErrorKind::SerializationFailure):
+//!
+//! ```rust
+//! # include!("../doctest_setup.rs");
+//! use diesel::connection::middleware::{ConnectionMiddleware, MiddlewareConnection};
+//! use diesel::connection::{Connection, DebugQuery};
+//! use diesel::result::{DatabaseErrorKind, Error};
+//!
+//! #[derive(Default)]
+//! struct RetrySerializationFailures;
+//!
+//! impl<C: Connection> ConnectionMiddleware<C> for RetrySerializationFailures {
+//!     fn execute(
+//!         &mut self,
+//!         conn: &mut C,
+//!         _query: &dyn DebugQuery,
+//!         execute: &mut dyn FnMut(&mut C) -> QueryResult<usize>,
+//!     ) -> QueryResult<usize> {
+//!         let mut attempts = 0;
+//!         loop {
+//!             match execute(conn) {
+//!                 Err(Error::DatabaseError(DatabaseErrorKind::SerializationFailure, _))
+//!                     if attempts < 3 =>
+//!                 {
+//!                     attempts += 1;
+//!                 }
+//!                 result => return result,
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! # fn main() -> QueryResult<()> {
+//! #     use schema::users;
+//! // use `MiddlewareConnection::<DbConnection, RetrySerializationFailures>::establish`
+//! // to establish a new connection instead
+//! let connection = &mut MiddlewareConnection::new(
+//!     establish_connection(),
+//!     RetrySerializationFailures,
+//! );
+//!
+//! diesel::insert_into(users::table)
+//!     .values(users::name.eq("Jim"))
+//!     .execute(connection)?;
+//! #     Ok(())
+//! # }
+//! ```
+
+use super::{
+    Access, CacheSize, Connection, ConnectionSealed, DatabaseWarning, DebugQuery, Instrumentation,
+    Isolation, LoadConnection, SimpleConnection, TransactionManager, TransactionManagerStatus,
+    WithMetadataLookup,
+};
+use crate::backend::Backend;
+use crate::expression::QueryMetadata;
+use crate::query_builder::{Query, QueryFragment, QueryId};
+use crate::result::{ConnectionResult, Error, QueryResult};
+use crate::sql_types::TypeMetadata;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// A type that wraps another [`Connection`]
+///
+/// This trait gives access to the wrapped connection, which is used by
+/// [`DelegatingTransactionManager`] to forward the transaction handling.
+pub trait ConnectionWrapper {
+    /// The wrapped connection type
+    type Inner: Connection;
+
+    /// Returns the wrapped connection
+    fn inner_connection(&mut self) -> &mut Self::Inner;
+}
+
+/// A [`TransactionManager`] for [`ConnectionWrapper`] types that forwards
+/// all calls to the transaction manager `T` of the wrapped connection
+///
+/// The transaction state is therefore stored in the wrapped connection,
+/// so that the wrapper and the wrapped connection always agree
+/// on the current transaction depth.
+#[allow(missing_debug_implementations)]
+pub struct DelegatingTransactionManager<T>(PhantomData<T>);
+
+impl<W, T> TransactionManager<W> for DelegatingTransactionManager<T>
+where
+    W: ConnectionWrapper + Connection,
+    W::Inner: Connection<TransactionManager = T>,
+    T: TransactionManager<W::Inner>,
+{
+    type TransactionStateData = T::TransactionStateData;
+
+    fn begin_transaction(conn: &mut W) -> QueryResult<()> {
+        T::begin_transaction(conn.inner_connection())
+    }
+
+    fn begin_transaction_with(
+        conn: &mut W,
+        isolation: Isolation,
+        access: Access,
+    ) -> QueryResult<()> {
+        T::begin_transaction_with(conn.inner_connection(), isolation, access)
+    }
+
+    fn rollback_transaction(conn: &mut W) -> QueryResult<()> {
+        T::rollback_transaction(conn.inner_connection())
+    }
+
+    fn commit_transaction(conn: &mut W) -> QueryResult<()> {
+        T::commit_transaction(conn.inner_connection())
+    }
+
+    fn begin_savepoint(conn: &mut W, name: &str) -> QueryResult<()> {
+        T::begin_savepoint(conn.inner_connection(), name)
+    }
+
+    fn release_savepoint(conn: &mut W, name: &str) -> QueryResult<()> {
+        T::release_savepoint(conn.inner_connection(), name)
+    }
+
+    fn rollback_to_savepoint(conn: &mut W, name: &str) -> QueryResult<()> {
+        T::rollback_to_savepoint(conn.inner_connection(), name)
+    }
+
+    fn transaction_manager_status_mut(conn: &mut W) -> &mut TransactionManagerStatus {
+        T::transaction_manager_status_mut(conn.inner_connection())
+    }
+
+    fn is_broken_transaction_manager(conn: &mut W) -> bool {
+        T::is_broken_transaction_manager(conn.inner_connection())
+    }
+}
+
+/// Intercepts the queries executed via a [`MiddlewareConnection`]
+///
+/// All methods have default implementations which just forward the query
+/// to the wrapped connection `C`, so that implementations only need to
+/// overwrite the methods they are interested in.
+///
+/// Statements executed by the transaction manager to begin, commit or
+/// rollback transactions are executed directly on the wrapped connection
+/// and do not pass through the middleware.
+///
+/// As [`Connection::establish`] has to create the middleware, it needs to
+/// implement [`Default`].
+pub trait ConnectionMiddleware<C: Connection>: Default + Send {
+    /// Executes a query that does not return any rows
+    ///
+    /// Calling `execute` runs the query on the given connection and returns
+    /// the number of affected rows. It can be called more than once, for
+    /// example to retry a failed query.
+    fn execute(
+        &mut self,
+        conn: &mut C,
+        _query: &dyn DebugQuery,
+        execute: &mut dyn FnMut(&mut C) -> QueryResult<usize>,
+    ) -> QueryResult<usize> {
+        execute(conn)
+    }
+
+    /// Executes the given SQL via [`SimpleConnection::batch_execute`]
+    fn batch_execute(&mut self, conn: &mut C, query: &str) -> QueryResult<()> {
+        conn.batch_execute(query)
+    }
+
+    /// Called before a query returning rows is loaded
+    ///
+    /// Returning an error aborts loading the query.
+    fn before_load(&mut self, _conn: &mut C, _query: &dyn DebugQuery) -> QueryResult<()> {
+        Ok(())
+    }
+
+    /// Called after the wrapped connection started to load a query
+    ///
+    /// `error` contains the error returned by the wrapped connection, if any.
+    /// Depending on the loading mode, the rows may still be received from the
+    /// database afterwards while iterating over them.
+    fn after_load(&mut self, _query: &dyn DebugQuery, _error: Option<&Error>) {}
+}
+
+/// A connection passing all queries through the [`ConnectionMiddleware`] `M`
+/// before executing them on the wrapped connection `C`
+///
+/// See the [module documentation](self) for details
+#[allow(missing_debug_implementations)]
+pub struct MiddlewareConnection<C, M> {
+    inner: C,
+    middleware: M,
+}
+
+impl<C, M> MiddlewareConnection<C, M> {
+    /// Wraps an already established connection
+    pub fn new(inner: C, middleware: M) -> Self {
+        Self { inner, middleware }
+    }
+
+    /// Returns the middleware used by this connection
+    pub fn middleware(&mut self) -> &mut M {
+        &mut self.middleware
+    }
+
+    /// Unwraps the wrapped connection
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+}
+
+impl<C, M> ConnectionWrapper for MiddlewareConnection<C, M>
+where
+    C: Connection,
+{
+    type Inner = C;
+
+    fn inner_connection(&mut self) -> &mut C {
+        &mut self.inner
+    }
+}
+
+impl<C, M> ConnectionSealed for MiddlewareConnection<C, M> where C: ConnectionSealed {}
+
+impl<C, M> SimpleConnection for MiddlewareConnection<C, M>
+where
+    C: Connection,
+    M: ConnectionMiddleware<C>,
+{
+    fn batch_execute(&mut self, query: &str) -> QueryResult<()> {
+        self.middleware.batch_execute(&mut self.inner, query)
+    }
+}
+
+impl<C, M> Connection for MiddlewareConnection<C, M>
+where
+    C: Connection,
+    M: ConnectionMiddleware<C>,
+    C::Backend: Default,
+    <C::Backend as Backend>::QueryBuilder: Default,
+{
+    type Backend = C::Backend;
+    type TransactionManager = DelegatingTransactionManager<C::TransactionManager>;
+
+    fn establish(database_url: &str) -> ConnectionResult<Self> {
+        Ok(Self::new(C::establish(database_url)?, M::default()))
+    }
+
+    fn begin_test_transaction(&mut self) -> QueryResult<()> {
+        self.inner.begin_test_transaction()
+    }
+
+    fn execute_returning_count<T>(&mut self, source: &T) -> QueryResult<usize>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        let query = crate::debug_query::<Self::Backend, _>(source);
+        self.middleware
+            .execute(&mut self.inner, &query, &mut |conn| {
+                conn.execute_returning_count(source)
+            })
+    }
+
+    fn execute_returning_count_with_warnings<T>(
+        &mut self,
+        source: &T,
+    ) -> QueryResult<(usize, Vec<DatabaseWarning>)>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        let query = crate::debug_query::<Self::Backend, _>(source);
+        let mut warnings = Vec::new();
+        let count = self
+            .middleware
+            .execute(&mut self.inner, &query, &mut |conn| {
+                let (count, query_warnings) = conn.execute_returning_count_with_warnings(source)?;
+                warnings = query_warnings;
+                Ok(count)
+            })?;
+        Ok((count, warnings))
+    }
+
+    fn transaction_state(
+        &mut self,
+    ) -> &mut <Self::TransactionManager as TransactionManager<Self>>::TransactionStateData {
+        self.inner.transaction_state()
+    }
+
+    fn instrumentation(&mut self) -> &mut dyn Instrumentation {
+        self.inner.instrumentation()
+    }
+
+    fn set_instrumentation(&mut self, instrumentation: impl Instrumentation) {
+        self.inner.set_instrumentation(instrumentation)
+    }
+
+    fn set_prepared_statement_cache_size(&mut self, size: CacheSize) {
+        self.inner.set_prepared_statement_cache_size(size)
+    }
+
+    fn clear_prepared_statement_cache(&mut self) {
+        self.inner.clear_prepared_statement_cache()
+    }
+
+    fn cached_statements(&self) -> Vec<String> {
+        self.inner.cached_statements()
+    }
+}
+
+impl<B, C, M> LoadConnection<B> for MiddlewareConnection<C, M>
+where
+    C: LoadConnection<B>,
+    M: ConnectionMiddleware<C>,
+    C::Backend: Default,
+    <C::Backend as Backend>::QueryBuilder: Default,
+{
+    type Cursor<'conn, 'query>
+        = <C as LoadConnection<B>>::Cursor<'conn, 'query>
+    where
+        Self: 'conn;
+    type Row<'conn, 'query>
+        = <C as LoadConnection<B>>::Row<'conn, 'query>
+    where
+        Self: 'conn;
+
+    fn load<'conn, 'query, T>(
+        &'conn mut self,
+        source: T,
+    ) -> QueryResult<Self::Cursor<'conn, 'query>>
+    where
+        T: Query + QueryFragment<Self::Backend> + QueryId + 'query,
+        Self::Backend: QueryMetadata<T::SqlType>,
+    {
+        // `source` is moved into `load`, so we need to render
+        // the query upfront to pass it to `after_load`
+        let rendered = alloc::format!("{}", crate::debug_query::<Self::Backend, _>(&source));
+        let query = super::instrumentation::StrQueryHelper::new(&rendered);
+        self.middleware.before_load(&mut self.inner, &query)?;
+        let cursor = self.inner.load(source);
+        self.middleware.after_load(&query, cursor.as_ref().err());
+        cursor
+    }
+}
+
+impl<C, M> WithMetadataLookup for MiddlewareConnection<C, M>
+where
+    C: WithMetadataLookup,
+    Self: Connection<Backend = C::Backend>,
+{
+    fn metadata_lookup(&mut self) -> &mut <Self::Backend as TypeMetadata>::MetadataLookup {
+        self.inner.metadata_lookup()
+    }
+}
+
+impl<C, M> crate::migration::MigrationConnection for MiddlewareConnection<C, M>
+where
+    C: crate::migration::MigrationConnection,
+    Self: Connection,
+{
+    fn setup(&mut self) -> QueryResult<usize> {
+        self.inner.setup()
+    }
+}
+
+#[cfg(feature = "r2d2")]
+impl<C, M> crate::r2d2::R2D2Connection for MiddlewareConnection<C, M>
+where
+    C: crate::r2d2::R2D2Connection,
+    Self: Connection,
+{
+    fn ping(&mut self) -> QueryResult<()> {
+        self.inner.ping()
+    }
+
+    fn is_broken(&mut self) -> bool {
+        self.inner.is_broken()
+    }
+}
+
+/// A connection logging each query together with the time it took to execute
+///
+/// See [`LoggingMiddleware`] for details
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// use diesel::connection::middleware::{LoggingConnection, LoggingMiddleware};
+///
+/// # fn main() -> QueryResult<()> {
+/// #     use schema::users;
+/// // logs to stderr
+/// let mut connection = LoggingConnection::new(establish_connection(), LoggingMiddleware::default());
+/// let names = users::table.select(users::name).load::<String>(&mut connection)?;
+///
+/// // or use a custom logger
+/// let mut connection = LoggingConnection::new(
+///     connection.into_inner(),
+///     LoggingMiddleware::new(|message| println!("{message}")),
+/// );
+/// let names = users::table.select(users::name).load::<String>(&mut connection)?;
+/// #     Ok(())
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub type LoggingConnection<C> = MiddlewareConnection<C, LoggingMiddleware>;
+
+/// A [`ConnectionMiddleware`] logging each query together with the time it took to execute
+///
+/// The default implementation logs to stderr, a custom logger can be provided via
+/// [`LoggingMiddleware::new`]. For queries returning rows the logged time only
+/// contains the time until the wrapped connection returned the first row.
+#[cfg(feature = "std")]
+pub struct LoggingMiddleware {
+    logger: alloc::boxed::Box<dyn FnMut(&str) + Send>,
+    load_started: Option<std::time::Instant>,
+}
+
+#[cfg(feature = "std")]
+impl LoggingMiddleware {
+    /// Creates a middleware passing the log messages to `logger`
+    pub fn new(logger: impl FnMut(&str) + Send + 'static) -> Self {
+        Self {
+            logger: alloc::boxed::Box::new(logger),
+            load_started: None,
+        }
+    }
+
+    fn log(
+        &mut self,
+        query: &dyn DebugQuery,
+        elapsed: core::time::Duration,
+        error: Option<&Error>,
+    ) {
+        let message = match error {
+            Some(error) => alloc::format!("[{elapsed:?}] {query} failed: {error}"),
+            None => alloc::format!("[{elapsed:?}] {query}"),
+        };
+        (self.logger)(&message);
+    }
+}
+
+#[cfg(feature = "std")]
+impl Default for LoggingMiddleware {
+    fn default() -> Self {
+        Self::new(|message| eprintln!("{message}"))
+    }
+}
+
+#[cfg(feature = "std")]
+impl core::fmt::Debug for LoggingMiddleware {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("LoggingMiddleware").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "std")]
+impl<C: Connection> ConnectionMiddleware<C> for LoggingMiddleware {
+    fn execute(
+        &mut self,
+        conn: &mut C,
+        query: &dyn DebugQuery,
+        execute: &mut dyn FnMut(&mut C) -> QueryResult<usize>,
+    ) -> QueryResult<usize> {
+        let start = std::time::Instant::now();
+        let result = execute(conn);
+        self.log(query, start.elapsed(), result.as_ref().err());
+        result
+    }
+
+    fn batch_execute(&mut self, conn: &mut C, query: &str) -> QueryResult<()> {
+        let start = std::time::Instant::now();
+        let result = conn.batch_execute(query);
+        self.log(
+            &super::instrumentation::StrQueryHelper::new(query),
+            start.elapsed(),
+            result.as_ref().err(),
+        );
+        result
+    }
+
+    fn before_load(&mut self, _conn: &mut C, _query: &dyn DebugQuery) -> QueryResult<()> {
+        self.load_started = Some(std::time::Instant::now());
+        Ok(())
+    }
+
+    fn after_load(&mut self, query: &dyn DebugQuery, error: Option<&Error>) {
+        let elapsed = self
+            .load_started
+            .take()
+            .map(|start| start.elapsed())
+            .unwrap_or_default();
+        self.log(query, elapsed, error);
+    }
+}
@@ -1,6 +1,18 @@
 //! Types related to database connections
 
 pub(crate) mod instrumentation;
+pub mod middleware;
+#[cfg(feature = "otel")]
+mod otel;
+#[cfg(feature = "std")]
+mod retry;
+#[cfg(any(
+    feature = "postgres",
+    feature = "__sqlite-shared",
+    feature = "mysql",
+    feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+))]
+mod sql_only;
 #[cfg(all(
     not(feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"),
     any(feature = "__sqlite-shared", feature = "postgres", feature = "mysql")
@@ -8,27 +20,57 @@ pub(crate) mod instrumentation;
 pub(crate) mod statement_cache;
 #[cfg(feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes")]
 pub mod statement_cache;
+#[cfg(feature = "std")]
+mod test_database;
 mod transaction_manager;
+mod warning;
 
 use crate::backend::Backend;
 use crate::expression::QueryMetadata;
 use crate::query_builder::{Query, QueryFragment, QueryId};
 use crate::result::*;
 use crate::sql_types::TypeMetadata;
+use alloc::borrow::Cow;
+use alloc::string::String;
+use alloc::vec::Vec;
 use core::fmt::Debug;
+use core::num::NonZeroUsize;
 
 #[cfg(feature = "std")]
 #[doc(inline)]
 pub use self::instrumentation::set_default_instrumentation;
 #[doc(inline)]
 pub use self::instrumentation::{
-    DebugQuery, Instrumentation, InstrumentationEvent, get_default_instrumentation,
+    DebugQuery, Instrumentation, InstrumentationEvent, StatementCacheStatus, StatementInfo,
+    StatementKind, get_default_instrumentation,
 };
+#[cfg(feature = "otel")]
+#[doc(inline)]
+pub use self::otel::TracingInstrumentation;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use self::retry::RetryPolicy;
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use self::test_database::{TestDatabase, TestDatabaseConnection};
+#[cfg(any(feature = "mysql_backend", feature = "__sqlite-shared"))]
+pub(crate) use self::transaction_manager::finish_transaction;
 #[doc(inline)]
 pub use self::transaction_manager::{
-    AnsiTransactionManager, InTransactionStatus, TransactionDepthChange, TransactionManager,
-    TransactionManagerStatus, ValidTransactionManagerStatus,
+    Access, AnsiTransactionManager, InTransactionStatus, Isolation, TransactionDepthChange,
+    TransactionManager, TransactionManagerStatus, ValidTransactionManagerStatus,
 };
+#[doc(inline)]
+pub use self::warning::DatabaseWarning;
+
+#[cfg(any(
+    feature = "postgres",
+    feature = "__sqlite-shared",
+    feature = "mysql",
+    feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+))]
+#[doc(inline)]
+pub use self::sql_only::{RecordedQuery, SqlOnlyConnection, SqlOnlyRow};
 
 #[diesel_derives::__diesel_public_if(
     feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
@@ -53,6 +95,11 @@ pub(crate) use self::private::MultiConnectionHelper;
 pub enum CacheSize {
     /// Caches all queries if possible
     Unbounded,
+    /// Caches at most the given number of queries
+    ///
+    /// If the cache is full, the least recently used
+    /// statement is removed from the cache
+    Bounded(NonZeroUsize),
     /// Disable statement cache
     Disabled,
 }
@@ -320,6 +367,235 @@ where
         Self::TransactionManager::transaction(self, f)
     }
 
+    /// Executes the given function inside of a database transaction with
+    /// the given isolation level and access mode
+    ///
+    /// This behaves like [`Connection::transaction`], except that it must
+    /// start a new top level transaction. Calling it while a transaction is
+    /// already open returns [`Error::AlreadyInTransaction`].
+    ///
+    /// The isolation level and access mode are mapped to the syntax of the
+    /// corresponding backend. Combinations the connection cannot provide
+    /// return [`Error::UnsupportedTransactionOptions`] without starting
+    /// a transaction:
+    ///
+    /// * PostgreSQL and MySQL support all combinations
+    /// * SQLite transactions are always serializable, so any isolation
+    ///   level is accepted, but [`Access::ReadOnly`] is not supported
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// use diesel::connection::{Access, Isolation};
+    /// use diesel::result::Error;
+    ///
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let conn = &mut DbConnection::establish(&database_url_for_env()).unwrap();
+    /// let result = conn.transaction_with(Isolation::Serializable, Access::ReadOnly, |conn| {
+    ///     users.count().get_result::<i64>(conn)
+    /// });
+    ///
+    /// match result {
+    ///     Ok(count) => assert!(count >= 0),
+    ///     // SQLite cannot start read only transactions
+    ///     Err(Error::UnsupportedTransactionOptions { access, .. }) => {
+    ///         assert_eq!(Access::ReadOnly, access)
+    ///     }
+    ///     Err(e) => return Err(e),
+    /// }
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn transaction_with<T, E, F>(
+        &mut self,
+        isolation: Isolation,
+        access: Access,
+        f: F,
+    ) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+        E: From<Error>,
+    {
+        Self::TransactionManager::transaction_with(self, isolation, access, f)
+    }
+
+    /// Executes the given function inside of a database transaction and
+    /// retries the whole transaction if it failed due to a concurrent
+    /// transaction
+    ///
+    /// This behaves like [`Connection::transaction`], except that a
+    /// transaction failing with an error accepted by
+    /// [`RetryPolicy::is_retryable`] is rolled back and executed again,
+    /// after the backoff configured by `policy`. This covers serialization
+    /// failures and deadlocks on PostgreSQL, deadlocks on MySQL and busy
+    /// databases on SQLite. Once the retries are exhausted, the last error
+    /// is returned.
+    ///
+    /// The function `f` is therefore possibly called several times and
+    /// should not have side effects outside of the database. Retrying only
+    /// part of a transaction is not possible, so this function must start
+    /// a new top level transaction. Calling it while a transaction is
+    /// already open returns [`Error::AlreadyInTransaction`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// use diesel::connection::RetryPolicy;
+    /// use diesel::sql_types::Integer;
+    /// use std::time::Duration;
+    ///
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut DbConnection::establish(&database_url_for_env()).unwrap();
+    /// let policy = RetryPolicy::default()
+    ///     .max_retries(5)
+    ///     .initial_backoff(Duration::from_millis(20));
+    ///
+    /// let answer = conn.transaction_with_retries(&policy, |conn| {
+    ///     diesel::select(42.into_sql::<Integer>()).get_result::<i32>(conn)
+    /// })?;
+    /// assert_eq!(42, answer);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    fn transaction_with_retries<T, F>(&mut self, policy: &RetryPolicy, mut f: F) -> QueryResult<T>
+    where
+        F: FnMut(&mut Self) -> QueryResult<T>,
+    {
+        if Self::TransactionManager::transaction_manager_status_mut(self)
+            .transaction_depth()?
+            .is_some()
+        {
+            return Err(Error::AlreadyInTransaction);
+        }
+        policy.run(|| self.transaction(&mut f))
+    }
+
+    /// Executes the given function inside of a savepoint with the given name
+    ///
+    /// This must be called inside of a transaction, otherwise
+    /// [`Error::NotInTransaction`] is returned. The savepoint is released
+    /// if the closure returns `Ok(_)`. If it returns `Err(_)`, all changes
+    /// made inside of the savepoint are rolled back before it is released,
+    /// while the changes made before are kept.
+    ///
+    /// Contrary to nesting [`Connection::transaction`] calls, the name of
+    /// the savepoint is known, so that the closure can roll back to it via
+    /// [`Connection::rollback_to_savepoint`] and continue afterwards.
+    /// Savepoint names must be plain identifiers, other names return an
+    /// [`Error::QueryBuilderError`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// use diesel::result::Error;
+    ///
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// conn.transaction::<_, Error, _>(|conn| {
+    ///     conn.savepoint("import", |conn| {
+    ///         for new_name in ["Ruby", "Jim"] {
+    ///             diesel::insert_into(users)
+    ///                 .values(name.eq(new_name))
+    ///                 .execute(conn)?;
+    ///             if new_name == "Jim" {
+    ///                 // undo all inserts of this savepoint, but keep it open
+    ///                 conn.rollback_to_savepoint("import")?;
+    ///             }
+    ///         }
+    ///         diesel::insert_into(users)
+    ///             .values(name.eq("Pascal"))
+    ///             .execute(conn)
+    ///     })?;
+    ///
+    ///     let all_names = users.select(name).load::<String>(conn)?;
+    ///     assert_eq!(vec!["Sean", "Tess", "Pascal"], all_names);
+    ///     Ok(())
+    /// })?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn savepoint<T, E, F>(&mut self, name: &str, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Self) -> Result<T, E>,
+        E: From<Error>,
+    {
+        Self::TransactionManager::begin_savepoint(self, name)?;
+        match f(self) {
+            Ok(value) => {
+                Self::TransactionManager::release_savepoint(self, name)?;
+                Ok(value)
+            }
+            Err(user_error) => {
+                Self::TransactionManager::rollback_to_savepoint(self, name)
+                    .and_then(|()| Self::TransactionManager::release_savepoint(self, name))?;
+                Err(user_error)
+            }
+        }
+    }
+
+    /// Rolls back all changes made since the savepoint with the given name
+    /// was created by [`Connection::savepoint`]
+    ///
+    /// The savepoint stays open, so further changes can be made inside of
+    /// it. Rolling back to a savepoint also removes all savepoints created
+    /// after it, therefore `name` should refer to the innermost savepoint.
+    ///
+    /// See [`Connection::savepoint`] for an example.
+    fn rollback_to_savepoint(&mut self, name: &str) -> QueryResult<()> {
+        Self::TransactionManager::rollback_to_savepoint(self, name)
+    }
+
+    /// Returns the number of open transactions and savepoints
+    ///
+    /// This is `None` outside of a transaction, `1` inside of a transaction
+    /// and increases by one for each nested transaction or savepoint.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// use diesel::result::Error;
+    /// use std::num::NonZeroU32;
+    ///
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut DbConnection::establish(&database_url_for_env()).unwrap();
+    /// assert_eq!(None, conn.transaction_depth()?);
+    /// conn.transaction::<_, Error, _>(|conn| {
+    ///     assert_eq!(NonZeroU32::new(1), conn.transaction_depth()?);
+    ///     conn.savepoint("nested", |conn| {
+    ///         assert_eq!(NonZeroU32::new(2), conn.transaction_depth()?);
+    ///         Ok(())
+    ///     })
+    /// })?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn transaction_depth(&mut self) -> QueryResult<Option<core::num::NonZeroU32>> {
+        Self::TransactionManager::transaction_manager_status_mut(self).transaction_depth()
+    }
+
     /// Creates a transaction that will never be committed. This is useful for
     /// tests. Panics if called while inside of a transaction or
     /// if called with a connection containing a broken transaction
@@ -396,6 +672,81 @@ where
     where
         T: QueryFragment<Self::Backend> + QueryId;
 
+    /// Execute a single SQL statement given by a query and return the
+    /// number of affected rows together with all warnings the database
+    /// reported for it
+    ///
+    /// Warnings are collected as follows:
+    ///
+    /// * PostgreSQL: All notices and warnings sent by the server while
+    ///   executing the statement, e.g. by `RAISE NOTICE` or
+    ///   `DROP TABLE IF EXISTS` for a missing table
+    /// * MySQL: The output of `SHOW WARNINGS` if the statement produced
+    ///   any warnings, e.g. for silently truncated values
+    /// * SQLite: SQLite does not report warnings for statements, so the
+    ///   list is always empty
+    ///
+    /// If the list is not empty the warnings are also passed to the connection
+    /// [`Instrumentation`] as [`InstrumentationEvent::QueryWarnings`].
+    ///
+    /// The default implementation executes the query via
+    /// [`Connection::execute_returning_count`] and returns no warnings.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut establish_connection();
+    /// let (_count, warnings) = conn.execute_returning_count_with_warnings(
+    ///     &diesel::sql_query("DROP TABLE IF EXISTS does_not_exist"),
+    /// )?;
+    /// # #[cfg(feature = "postgres")]
+    /// assert_eq!(warnings[0].level(), "NOTICE");
+    /// # #[cfg(feature = "postgres")]
+    /// assert_eq!(
+    ///     warnings[0].message(),
+    ///     r#"table "does_not_exist" does not exist, skipping"#
+    /// );
+    /// # #[cfg(feature = "mysql")]
+    /// # assert_eq!(warnings[0].code(), Some("1051"));
+    /// # #[cfg(feature = "sqlite")]
+    /// # assert!(warnings.is_empty());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn execute_returning_count_with_warnings<T>(
+        &mut self,
+        source: &T,
+    ) -> QueryResult<(usize, Vec<DatabaseWarning>)>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        self.execute_returning_count(source)
+            .map(|count| (count, Vec::new()))
+    }
+
+    /// The SQL used by [`AnsiTransactionManager`] to begin a transaction
+    /// with the given isolation level and access mode
+    ///
+    /// The default implementation returns
+    /// [`Error::UnsupportedTransactionOptions`]
+    #[diesel_derives::__diesel_public_if(
+        feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+    )]
+    fn begin_transaction_with_sql(
+        &mut self,
+        isolation: Isolation,
+        access: Access,
+    ) -> QueryResult<Cow<'static, str>> {
+        Err(Error::UnsupportedTransactionOptions { isolation, access })
+    }
+
     /// Get access to the current transaction state of this connection
     ///
     /// This function should be used from [`TransactionManager`] to access
@@ -418,6 +769,44 @@ where
 
     /// Set the prepared statement cache size to [`CacheSize`] for this connection
     fn set_prepared_statement_cache_size(&mut self, size: CacheSize);
+
+    /// Remove all statements from the prepared statement cache of this connection
+    ///
+    /// Subsequent queries are prepared again. This does nothing for
+    /// connections without a prepared statement cache.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// users.select(name).load::<String>(connection)?;
+    /// let cached = connection.cached_statements();
+    /// assert!(cached.iter().any(|sql| sql.starts_with("SELECT")));
+    ///
+    /// connection.clear_prepared_statement_cache();
+    /// assert!(connection.cached_statements().is_empty());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn clear_prepared_statement_cache(&mut self) {}
+
+    /// The SQL of all statements in the prepared statement cache of this connection
+    ///
+    /// For [`CacheSize::Bounded`] the statements are ordered from the least
+    /// recently used to the most recently used statement, otherwise the order
+    /// is unspecified. Connections without a prepared statement cache
+    /// return an empty list.
+    fn cached_statements(&self) -> Vec<String> {
+        Vec::new()
+    }
 }
 
 /// The specific part of a [`Connection`] which actually loads data from the database
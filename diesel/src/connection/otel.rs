@@ -0,0 +1,410 @@
+//! An [`Instrumentation`] emitting `tracing` spans
+//!
+//! Note: This module requires enabling the `otel` feature
+
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use tracing::Span;
+use tracing::field::Empty;
+
+use super::instrumentation::{DebugQuery, Instrumentation, InstrumentationEvent, StatementKind};
+
+const DEFAULT_MAX_STATEMENT_LENGTH: usize = 2048;
+
+/// An [`Instrumentation`] that emits a [`tracing`] span for establishing
+/// a connection, for each query and for each transaction
+///
+/// The spans use the [OpenTelemetry semantic conventions for database
+/// clients](https://opentelemetry.io/docs/specs/semconv/database/), so
+/// that they can be exported as OpenTelemetry traces via
+/// `tracing-opentelemetry`. All spans are emitted with the `diesel` target
+/// at the `INFO` level and record the following fields:
+///
+/// * `db.system`: The database system passed to [`TracingInstrumentation::new`]
+/// * `db.statement`: The SQL of a query, without bind values and truncated
+///   to [`TracingInstrumentation::with_max_statement_length`] bytes
+/// * `db.operation` and `db.sql.table`: The kind of a query and the first
+///   table used by it, if diesel constructed the query
+/// * `db.transaction.depth` and `db.transaction.outcome`: The nesting level
+///   of a transaction and whether it was committed or rolled back
+/// * `otel.kind`, `otel.status_code` and `otel.status_message`: The span
+///   kind and the error of failed queries or connections
+///
+/// Warnings reported by the database for
+/// [`Connection::execute_returning_count_with_warnings`](crate::Connection::execute_returning_count_with_warnings)
+/// are emitted as `WARN` events with the fields `db.warning.level` and
+/// `db.warning.code`.
+///
+/// Query spans are children of the span of the innermost open transaction,
+/// or of the current span outside of transactions. The database url
+/// is never recorded, as it might contain a password.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// use diesel::connection::TracingInstrumentation;
+///
+/// # fn main() {
+/// let mut conn = establish_connection();
+/// # #[cfg(feature = "postgres")]
+/// conn.set_instrumentation(TracingInstrumentation::new("postgresql"));
+/// # #[cfg(feature = "sqlite")]
+/// # conn.set_instrumentation(TracingInstrumentation::new("sqlite"));
+/// # #[cfg(feature = "mysql")]
+/// # conn.set_instrumentation(TracingInstrumentation::new("mysql"));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct TracingInstrumentation {
+    db_system: &'static str,
+    max_statement_length: usize,
+    connection: Option<Span>,
+    transactions: Vec<(usize, Span)>,
+    finishing_transaction: Option<Span>,
+    queries: Vec<Span>,
+}
+
+impl TracingInstrumentation {
+    /// Create a new instrumentation for the given database system
+    ///
+    /// `db_system` is recorded as `db.system` field. The OpenTelemetry
+    /// semantic conventions use `"postgresql"`, `"mysql"` and `"sqlite"`
+    /// for the backends supported by diesel.
+    pub fn new(db_system: &'static str) -> Self {
+        Self {
+            db_system,
+            max_statement_length: DEFAULT_MAX_STATEMENT_LENGTH,
+            connection: None,
+            transactions: Vec::new(),
+            finishing_transaction: None,
+            queries: Vec::new(),
+        }
+    }
+
+    /// Set the maximal length of the recorded `db.statement` field in bytes
+    ///
+    /// Longer statements are truncated. The default is 2048 bytes.
+    pub fn with_max_statement_length(mut self, max_statement_length: usize) -> Self {
+        self.max_statement_length = max_statement_length;
+        self
+    }
+
+    fn parent(&self) -> Option<tracing::Id> {
+        self.finishing_transaction
+            .as_ref()
+            .or(self.transactions.last().map(|(_, span)| span))
+            .map_or_else(|| Span::current().id(), Span::id)
+    }
+
+    fn start_query(&mut self, query: &dyn DebugQuery) {
+        let span = tracing::info_span!(
+            target: "diesel",
+            parent: self.parent(),
+            "query",
+            otel.name = Empty,
+            otel.kind = "client",
+            otel.status_code = Empty,
+            otel.status_message = Empty,
+            db.system = self.db_system,
+            db.statement = Empty,
+            db.operation = Empty,
+            db.sql.table = Empty,
+        );
+        if !span.is_disabled() {
+            span.record(
+                "db.statement",
+                statement(query, self.max_statement_length).as_str(),
+            );
+        }
+        self.queries.push(span);
+    }
+
+    fn finish_query(&mut self, query: &dyn DebugQuery, error: Option<&crate::result::Error>) {
+        if let Some(span) = self.queries.pop()
+            && !span.is_disabled()
+        {
+            if let Some(info) = query.statement_info() {
+                let operation = match info.kind() {
+                    StatementKind::Select => "SELECT",
+                    StatementKind::Insert => "INSERT",
+                    StatementKind::Update => "UPDATE",
+                    StatementKind::Delete => "DELETE",
+                    StatementKind::Other => "",
+                };
+                if !operation.is_empty() {
+                    span.record("db.operation", operation);
+                    match info.tables().first() {
+                        Some(table) => {
+                            span.record("otel.name", format!("{operation} {table}").as_str());
+                            span.record("db.sql.table", table.as_str());
+                        }
+                        None => {
+                            span.record("otel.name", operation);
+                        }
+                    }
+                }
+            }
+            if let Some(error) = error {
+                record_error(&span, error);
+            }
+        }
+        if self.queries.is_empty() {
+            // the commit or rollback statement is finished
+            self.finishing_transaction = None;
+        }
+    }
+
+    fn begin_transaction(&mut self, depth: usize) {
+        self.transactions.retain(|(d, _)| *d < depth);
+        let parent = self
+            .transactions
+            .last()
+            .map_or_else(|| Span::current().id(), |(_, span)| span.id());
+        let span = tracing::info_span!(
+            target: "diesel",
+            parent: parent,
+            "transaction",
+            otel.kind = "client",
+            db.system = self.db_system,
+            db.transaction.depth = depth,
+            db.transaction.outcome = Empty,
+        );
+        self.transactions.push((depth, span));
+    }
+
+    fn finish_transaction(&mut self, depth: usize, outcome: &'static str) {
+        // transactions opened before this instrumentation
+        // was registered have no span
+        if let Some(index) = self.transactions.iter().position(|(d, _)| *d == depth) {
+            // the span is kept open until the commit or
+            // rollback statement is executed
+            let (_, span) = self.transactions.split_off(index).swap_remove(0);
+            span.record("db.transaction.outcome", outcome);
+            self.finishing_transaction = Some(span);
+        }
+    }
+}
+
+impl Instrumentation for TracingInstrumentation {
+    fn on_connection_event(&mut self, event: InstrumentationEvent<'_>) {
+        match event {
+            InstrumentationEvent::StartEstablishConnection { .. } => {
+                self.connection = Some(tracing::info_span!(
+                    target: "diesel",
+                    "establish_connection",
+                    otel.kind = "client",
+                    otel.status_code = Empty,
+                    otel.status_message = Empty,
+                    db.system = self.db_system,
+                ));
+            }
+            InstrumentationEvent::FinishEstablishConnection { error, .. } => {
+                if let Some(span) = self.connection.take()
+                    && let Some(error) = error
+                {
+                    record_error(&span, error);
+                }
+            }
+            InstrumentationEvent::StartQuery { query, .. } => self.start_query(query),
+            InstrumentationEvent::FinishQuery { query, error, .. } => {
+                self.finish_query(query, error)
+            }
+            InstrumentationEvent::BeginTransaction { depth, .. } => {
+                self.begin_transaction(depth.get() as usize)
+            }
+            InstrumentationEvent::CommitTransaction { depth, .. } => {
+                self.finish_transaction(depth.get() as usize, "commit")
+            }
+            InstrumentationEvent::RollbackTransaction { depth, .. } => {
+                self.finish_transaction(depth.get() as usize, "rollback")
+            }
+            InstrumentationEvent::QueryWarnings { warnings, .. } => {
+                let parent = self.parent();
+                for warning in warnings {
+                    tracing::event!(
+                        target: "diesel",
+                        parent: parent.clone(),
+                        tracing::Level::WARN,
+                        db.system = self.db_system,
+                        db.warning.level = warning.level(),
+                        db.warning.code = warning.code(),
+                        "{}",
+                        warning.message(),
+                    );
+                }
+            }
+            InstrumentationEvent::CacheQuery { .. } => {}
+        }
+    }
+}
+
+fn record_error(span: &Span, error: &dyn core::fmt::Display) {
+    span.record("otel.status_code", "ERROR");
+    span.record("otel.status_message", error.to_string().as_str());
+}
+
+fn statement(query: &dyn DebugQuery, max_length: usize) -> String {
+    let mut statement = query.to_string();
+    // the bind values might contain sensitive information
+    // and are not part of `db.statement`
+    if let Some(binds) = statement.rfind(" -- binds: ") {
+        statement.truncate(binds);
+    }
+    if statement.len() > max_length {
+        let mut end = max_length;
+        while !statement.is_char_boundary(end) {
+            end -= 1;
+        }
+        statement.truncate(end);
+    }
+    statement
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    use super::*;
+    use crate::connection::Connection;
+    use crate::connection::instrumentation::StrQueryHelper;
+    use crate::prelude::*;
+    use crate::test_helpers::*;
+
+    table! {
+        otel_users {
+            id -> Integer,
+            name -> Text,
+        }
+    }
+
+    #[derive(Debug)]
+    struct RecordedSpan {
+        name: &'static str,
+        parent: Option<u64>,
+        fields: Vec<(&'static str, String)>,
+    }
+
+    impl RecordedSpan {
+        fn field(&self, name: &str) -> Option<&str> {
+            self.fields
+                .iter()
+                .rev()
+                .find(|(n, _)| *n == name)
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    impl Visit for RecordedSpan {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.fields.push((field.name(), value.to_owned()));
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn core::fmt::Debug) {
+            self.fields.push((field.name(), format!("{value:?}")));
+        }
+    }
+
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<RecordedSpan>>>);
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attrs: &Attributes<'_>) -> Id {
+            let mut span = RecordedSpan {
+                name: attrs.metadata().name(),
+                parent: attrs.parent().map(Id::into_u64),
+                fields: Vec::new(),
+            };
+            attrs.record(&mut span);
+            let mut spans = self.0.lock().unwrap();
+            spans.push(span);
+            Id::from_u64(spans.len() as u64)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let mut spans = self.0.lock().unwrap();
+            let index = usize::try_from(span.into_u64()).unwrap() - 1;
+            values.record(&mut spans[index]);
+        }
+
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+        fn event(&self, _: &Event<'_>) {}
+
+        fn enter(&self, _: &Id) {}
+
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[diesel_test_helper::test]
+    fn spans_are_emitted_for_queries_and_transactions() {
+        let recorder = Recorder::default();
+        let conn = &mut connection();
+        crate::sql_query(
+            "CREATE TEMPORARY TABLE otel_users (id INTEGER PRIMARY KEY, name TEXT NOT NULL)",
+        )
+        .execute(conn)
+        .unwrap();
+        conn.set_instrumentation(TracingInstrumentation::new("test"));
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            conn.transaction(|conn| {
+                crate::insert_into(otel_users::table)
+                    .values((otel_users::id.eq(1), otel_users::name.eq("Sean")))
+                    .execute(conn)?;
+                otel_users::table
+                    .select(otel_users::name)
+                    .filter(otel_users::id.eq(1))
+                    .load::<String>(conn)
+            })
+            .unwrap();
+            crate::sql_query("invalid").execute(conn).unwrap_err();
+        });
+
+        let spans = recorder.0.lock().unwrap();
+        let names = spans.iter().map(|s| s.name).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            ["transaction", "query", "query", "query", "query", "query"]
+        );
+        let transaction = &spans[0];
+        assert_eq!(Some("commit"), transaction.field("db.transaction.outcome"));
+        assert_eq!(Some("test"), transaction.field("db.system"));
+        // begin, insert, select and commit are part of the transaction
+        assert!(spans[1..5].iter().all(|s| s.parent == Some(1)), "{spans:?}");
+
+        let insert = &spans[2];
+        assert_eq!(Some("INSERT"), insert.field("db.operation"));
+        assert_eq!(Some("INSERT otel_users"), insert.field("otel.name"));
+        assert_eq!(Some("otel_users"), insert.field("db.sql.table"));
+        let statement = insert.field("db.statement").unwrap();
+        assert!(statement.starts_with("INSERT INTO"), "{statement}");
+        assert!(!statement.contains("Sean"), "{statement}");
+        assert_eq!(None, insert.field("otel.status_code"));
+
+        assert_eq!(Some("SELECT otel_users"), spans[3].field("otel.name"));
+
+        let invalid = &spans[5];
+        assert_eq!(None, invalid.parent);
+        assert_eq!(Some("invalid"), invalid.field("db.statement"));
+        assert_eq!(None, invalid.field("db.operation"));
+        assert_eq!(Some("ERROR"), invalid.field("otel.status_code"));
+        assert!(invalid.field("otel.status_message").is_some());
+    }
+
+    #[diesel_test_helper::test]
+    fn statements_are_truncated_at_char_boundaries() {
+        let query = StrQueryHelper::new("SELECT '\u{e4}\u{f6}'");
+        assert_eq!("SELECT '\u{e4}\u{f6}'", statement(&query, 100));
+        assert_eq!("SELECT '\u{e4}", statement(&query, 11));
+        assert_eq!("SELECT '", statement(&query, 9));
+    }
+}
@@ -0,0 +1,203 @@
+use core::hash::{BuildHasher, Hasher};
+use core::time::Duration;
+
+use crate::result::{DatabaseErrorKind, Error};
+
+/// Configures how [`Connection::transaction_with_retries`] retries
+/// transactions that failed due to concurrent transactions
+///
+/// A failed transaction is retried up to [`max_retries`] times. Before each
+/// retry the current thread sleeps for an exponentially growing backoff,
+/// starting at [`initial_backoff`] and capped at [`max_backoff`]. The actual
+/// sleep is chosen randomly between half of and the full backoff, so that
+/// transactions that conflicted with each other don't retry in lockstep.
+///
+/// By default a transaction is retried 3 times, with a backoff
+/// starting at 10 milliseconds and capped at 1 second.
+///
+/// [`Connection::transaction_with_retries`]: super::Connection::transaction_with_retries()
+/// [`max_retries`]: RetryPolicy::max_retries()
+/// [`initial_backoff`]: RetryPolicy::initial_backoff()
+/// [`max_backoff`]: RetryPolicy::max_backoff()
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_secs(1),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Sets how often a failed transaction is retried
+    ///
+    /// The transaction is executed at most `max_retries + 1` times.
+    pub fn max_retries(mut self, max_retries: u32) -> Self {
+        self.max_retries = max_retries;
+        self
+    }
+
+    /// Sets the backoff before the first retry
+    ///
+    /// The backoff is doubled for each further retry.
+    pub fn initial_backoff(mut self, initial_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self
+    }
+
+    /// Sets the upper limit of the backoff between two retries
+    pub fn max_backoff(mut self, max_backoff: Duration) -> Self {
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Returns whether a transaction that failed with `error`
+    /// is retried by [`Connection::transaction_with_retries`]
+    ///
+    /// These are errors caused by concurrent transactions, which
+    /// diesel reports as [`DatabaseErrorKind::SerializationFailure`]:
+    ///
+    /// * PostgreSQL serialization failures (`40001`) and deadlocks (`40P01`)
+    /// * MySQL deadlocks (`1213`)
+    /// * SQLite `SQLITE_BUSY` errors
+    ///
+    /// [`Connection::transaction_with_retries`]: super::Connection::transaction_with_retries()
+    pub fn is_retryable(error: &Error) -> bool {
+        matches!(
+            error,
+            Error::DatabaseError(DatabaseErrorKind::SerializationFailure, _)
+        )
+    }
+
+    pub(crate) fn run<T>(&self, mut f: impl FnMut() -> Result<T, Error>) -> Result<T, Error> {
+        let mut retries = 0;
+        loop {
+            match f() {
+                Err(e) if retries < self.max_retries && Self::is_retryable(&e) => {
+                    std::thread::sleep(self.backoff(retries));
+                    retries += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn backoff(&self, retries: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .checked_mul(1 << retries.min(31))
+            .map_or(self.max_backoff, |b| b.min(self.max_backoff));
+        // each `RandomState` is seeded differently,
+        // which is good enough for jitter
+        let random = std::collections::hash_map::RandomState::new()
+            .build_hasher()
+            .finish();
+        let half = backoff / 2;
+        half + half.mul_f64(random as f64 / u64::MAX as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::boxed::Box;
+    use alloc::string::String;
+
+    fn serialization_failure() -> Error {
+        Error::DatabaseError(
+            DatabaseErrorKind::SerializationFailure,
+            Box::new(String::from("could not serialize access")),
+        )
+    }
+
+    #[diesel_test_helper::test]
+    fn backoff_grows_exponentially_up_to_the_limit() {
+        let policy = RetryPolicy::default()
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(300));
+
+        for (retries, full) in [(0, 100), (1, 200), (2, 300), (40, 300)] {
+            let backoff = policy.backoff(retries);
+            assert!(backoff >= Duration::from_millis(full / 2), "{backoff:?}");
+            assert!(backoff <= Duration::from_millis(full), "{backoff:?}");
+        }
+    }
+
+    #[diesel_test_helper::test]
+    fn retries_only_retryable_errors_up_to_the_limit() {
+        let policy = RetryPolicy::default()
+            .max_retries(2)
+            .initial_backoff(Duration::ZERO);
+
+        let mut attempts = 0;
+        let result = policy.run(|| -> Result<(), _> {
+            attempts += 1;
+            Err(serialization_failure())
+        });
+        assert_eq!(Err(serialization_failure()), result);
+        assert_eq!(3, attempts);
+
+        let mut attempts = 0;
+        let result = policy.run(|| {
+            attempts += 1;
+            if attempts == 1 {
+                Err(serialization_failure())
+            } else {
+                Ok(attempts)
+            }
+        });
+        assert_eq!(Ok(2), result);
+
+        let mut attempts = 0;
+        let result = policy.run(|| -> Result<(), _> {
+            attempts += 1;
+            Err(Error::NotFound)
+        });
+        assert_eq!(Err(Error::NotFound), result);
+        assert_eq!(1, attempts);
+    }
+
+    // Gated out on WASM: it needs two connections to a shared file-backed
+    // database, and `tempfile::tempdir()` panics on WASM
+    #[cfg(all(
+        feature = "sqlite",
+        not(all(target_family = "wasm", target_os = "unknown"))
+    ))]
+    #[diesel_test_helper::test]
+    fn sqlite_busy_transactions_are_retried() {
+        use crate::connection::SimpleConnection;
+        use crate::prelude::*;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("retry.db");
+        let url = path.to_str().unwrap();
+        let conn = &mut SqliteConnection::establish(url).unwrap();
+        let other_conn = &mut SqliteConnection::establish(url).unwrap();
+        conn.batch_execute("CREATE TABLE retry_test (id INTEGER)")
+            .unwrap();
+        other_conn.batch_execute("BEGIN IMMEDIATE").unwrap();
+
+        let policy = RetryPolicy::default().initial_backoff(Duration::ZERO);
+        let mut attempts = 0;
+        let result = conn.transaction_with_retries(&policy, |conn| {
+            attempts += 1;
+            if attempts == 2 {
+                other_conn.batch_execute("COMMIT")?;
+            }
+            crate::sql_query("INSERT INTO retry_test VALUES (1)").execute(conn)
+        });
+        assert_eq!(Ok(1), result);
+        assert_eq!(2, attempts);
+
+        let result = conn.transaction(|conn| conn.transaction_with_retries(&policy, |_| Ok(())));
+        assert_eq!(Err(Error::AlreadyInTransaction), result);
+    }
+}
@@ -0,0 +1,323 @@
+use super::instrumentation::{DynInstrumentation, StrQueryHelper};
+use super::{
+    AnsiTransactionManager, CacheSize, Connection, ConnectionSealed, DefaultLoadingMode,
+    Instrumentation, InstrumentationEvent, LoadConnection, SimpleConnection, TransactionManager,
+};
+use crate::backend::Backend;
+use crate::expression::QueryMetadata;
+use crate::migration::{CREATE_MIGRATIONS_TABLE, MigrationConnection};
+use crate::query_builder::{AstPass, Query, QueryBuilder, QueryFragment, QueryId};
+use crate::result::{ConnectionResult, QueryResult};
+use crate::row::{Field, PartialRow, Row, RowIndex, RowSealed};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ops::Range;
+
+/// A query recorded by a [`SqlOnlyConnection`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedQuery {
+    /// The SQL of the query, exactly as it would be sent to the database
+    pub sql: String,
+    /// The [`Debug`](fmt::Debug) representation of each bind parameter of the query
+    pub binds: Vec<String>,
+}
+
+/// A connection that records the SQL of each query instead of sending it to a database
+///
+/// This connection never touches a database server. It renders every query
+/// executed on it for the backend `DB` and records the resulting SQL and bind
+/// parameters, which can be inspected via [`SqlOnlyConnection::queries`]
+/// afterwards. This allows to reuse the normal query building code, for example
+/// to snapshot test the generated SQL or to render migration scripts offline.
+///
+/// As there is no database, executing a query always reports `0` affected rows and
+/// loading a query never returns any row, so methods like
+/// [`RunQueryDsl::get_result`](crate::RunQueryDsl::get_result()) fail with
+/// [`NotFound`](crate::result::Error::NotFound). Transactions are recorded as
+/// the statements that open and close them.
+///
+/// [`Connection::establish`] accepts any string, as there is nothing to connect to.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// use diesel::connection::SqlOnlyConnection;
+/// #
+/// # fn main() -> QueryResult<()> {
+/// #     use schema::users;
+/// let mut connection = SqlOnlyConnection::<DB>::new();
+///
+/// diesel::insert_into(users::table)
+///     .values(users::name.eq("Sean"))
+///     .execute(&mut connection)?;
+/// let names = users::table
+///     .select(users::name)
+///     .filter(users::id.eq(42))
+///     .load::<String>(&mut connection)?;
+/// assert!(names.is_empty());
+///
+/// let queries = connection.take_queries();
+/// assert_eq!(2, queries.len());
+/// assert_eq!(vec!["\"Sean\""], queries[0].binds);
+/// assert_eq!(vec!["42"], queries[1].binds);
+/// #     Ok(())
+/// # }
+/// ```
+pub struct SqlOnlyConnection<DB> {
+    queries: Vec<RecordedQuery>,
+    transaction_state: AnsiTransactionManager,
+    instrumentation: DynInstrumentation,
+    _backend: PhantomData<fn() -> DB>,
+}
+
+impl<DB> SqlOnlyConnection<DB> {
+    /// Creates a new connection without any recorded queries
+    pub fn new() -> Self {
+        Self {
+            queries: Vec::new(),
+            transaction_state: AnsiTransactionManager::default(),
+            instrumentation: DynInstrumentation::none(),
+            _backend: PhantomData,
+        }
+    }
+
+    /// The queries recorded by this connection, in the order they were executed
+    pub fn queries(&self) -> &[RecordedQuery] {
+        &self.queries
+    }
+
+    /// Returns the queries recorded by this connection and clears them
+    pub fn take_queries(&mut self) -> Vec<RecordedQuery> {
+        core::mem::take(&mut self.queries)
+    }
+}
+
+impl<DB> SqlOnlyConnection<DB>
+where
+    DB: Backend + Default,
+    DB::QueryBuilder: Default,
+{
+    fn record<T>(&mut self, source: &T) -> QueryResult<()>
+    where
+        T: QueryFragment<DB>,
+    {
+        let query = crate::debug_query::<DB, _>(source);
+        self.instrumentation
+            .on_connection_event(InstrumentationEvent::StartQuery { query: &query });
+        let recorded = render_query(source);
+        self.instrumentation
+            .on_connection_event(InstrumentationEvent::finish_query(
+                &query,
+                recorded.as_ref().err(),
+            ));
+        self.queries.push(recorded?);
+        Ok(())
+    }
+}
+
+fn render_query<DB, T>(source: &T) -> QueryResult<RecordedQuery>
+where
+    DB: Backend + Default,
+    DB::QueryBuilder: Default,
+    T: QueryFragment<DB> + ?Sized,
+{
+    let backend = DB::default();
+    let mut query_builder = DB::QueryBuilder::default();
+    source.to_sql(&mut query_builder, &backend)?;
+    let mut binds = Vec::new();
+    source.walk_ast(AstPass::debug_binds(&mut binds, &backend))?;
+    Ok(RecordedQuery {
+        sql: query_builder.finish(),
+        binds: binds.iter().map(|bind| format!("{bind:?}")).collect(),
+    })
+}
+
+impl<DB> Default for SqlOnlyConnection<DB> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<DB> fmt::Debug for SqlOnlyConnection<DB> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SqlOnlyConnection")
+            .field("queries", &self.queries)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<DB> ConnectionSealed for SqlOnlyConnection<DB> {}
+
+impl<DB> SimpleConnection for SqlOnlyConnection<DB> {
+    fn batch_execute(&mut self, query: &str) -> QueryResult<()> {
+        self.instrumentation
+            .on_connection_event(InstrumentationEvent::StartQuery {
+                query: &StrQueryHelper::new(query),
+            });
+        self.queries.push(RecordedQuery {
+            sql: query.into(),
+            binds: Vec::new(),
+        });
+        self.instrumentation
+            .on_connection_event(InstrumentationEvent::finish_query(
+                &StrQueryHelper::new(query),
+                None,
+            ));
+        Ok(())
+    }
+}
+
+impl<DB> Connection for SqlOnlyConnection<DB>
+where
+    DB: Backend + Default,
+    DB::QueryBuilder: Default,
+{
+    type Backend = DB;
+    type TransactionManager = AnsiTransactionManager;
+
+    fn establish(database_url: &str) -> ConnectionResult<Self> {
+        let mut instrumentation = DynInstrumentation::default_instrumentation();
+        instrumentation.on_connection_event(InstrumentationEvent::StartEstablishConnection {
+            url: database_url,
+        });
+        instrumentation.on_connection_event(InstrumentationEvent::FinishEstablishConnection {
+            url: database_url,
+            error: None,
+        });
+        Ok(Self {
+            instrumentation,
+            ..Self::new()
+        })
+    }
+
+    fn execute_returning_count<T>(&mut self, source: &T) -> QueryResult<usize>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        self.record(source)?;
+        Ok(0)
+    }
+
+    fn transaction_state(
+        &mut self,
+    ) -> &mut <Self::TransactionManager as TransactionManager<Self>>::TransactionStateData {
+        &mut self.transaction_state
+    }
+
+    fn instrumentation(&mut self) -> &mut dyn Instrumentation {
+        &mut *self.instrumentation
+    }
+
+    fn set_instrumentation(&mut self, instrumentation: impl Instrumentation) {
+        self.instrumentation = instrumentation.into();
+    }
+
+    fn set_prepared_statement_cache_size(&mut self, _size: CacheSize) {}
+}
+
+impl<DB> LoadConnection<DefaultLoadingMode> for SqlOnlyConnection<DB>
+where
+    DB: Backend + Default,
+    DB::QueryBuilder: Default,
+{
+    type Cursor<'conn, 'query>
+        = core::iter::Empty<QueryResult<SqlOnlyRow<DB>>>
+    where
+        Self: 'conn;
+
+    type Row<'conn, 'query>
+        = SqlOnlyRow<DB>
+    where
+        Self: 'conn;
+
+    fn load<'conn, 'query, T>(
+        &'conn mut self,
+        source: T,
+    ) -> QueryResult<Self::Cursor<'conn, 'query>>
+    where
+        T: Query + QueryFragment<Self::Backend> + QueryId + 'query,
+        Self::Backend: QueryMetadata<T::SqlType>,
+    {
+        self.record(&source)?;
+        Ok(core::iter::empty())
+    }
+}
+
+impl<DB> MigrationConnection for SqlOnlyConnection<DB>
+where
+    DB: Backend + Default,
+    DB::QueryBuilder: Default,
+{
+    fn setup(&mut self) -> QueryResult<usize> {
+        self.batch_execute(CREATE_MIGRATIONS_TABLE)?;
+        Ok(0)
+    }
+}
+
+/// The row type of [`SqlOnlyConnection`]
+///
+/// As a [`SqlOnlyConnection`] never returns any rows, this type cannot be constructed.
+pub struct SqlOnlyRow<DB> {
+    never: Infallible,
+    _backend: PhantomData<fn() -> DB>,
+}
+
+impl<DB> fmt::Debug for SqlOnlyRow<DB> {
+    fn fmt(&self, _f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.never {}
+    }
+}
+
+impl<DB> RowSealed for SqlOnlyRow<DB> {}
+
+impl<'a, DB: Backend> Row<'a, DB> for SqlOnlyRow<DB> {
+    type Field<'f>
+        = &'f Self
+    where
+        'a: 'f,
+        Self: 'f;
+    type InnerPartialRow = Self;
+
+    fn field_count(&self) -> usize {
+        match self.never {}
+    }
+
+    fn get<'b, I>(&'b self, _idx: I) -> Option<Self::Field<'b>>
+    where
+        'a: 'b,
+        Self: RowIndex<I>,
+    {
+        match self.never {}
+    }
+
+    fn partial_row(&self, _range: Range<usize>) -> PartialRow<'_, Self::InnerPartialRow> {
+        match self.never {}
+    }
+}
+
+impl<'a, DB: Backend> Field<'a, DB> for &'a SqlOnlyRow<DB> {
+    fn field_name(&self) -> Option<&str> {
+        match self.never {}
+    }
+
+    fn value(&self) -> Option<DB::RawValue<'_>> {
+        match self.never {}
+    }
+}
+
+impl<DB> RowIndex<usize> for SqlOnlyRow<DB> {
+    fn idx(&self, _idx: usize) -> Option<usize> {
+        match self.never {}
+    }
+}
+
+impl<'a, DB> RowIndex<&'a str> for SqlOnlyRow<DB> {
+    fn idx(&self, _idx: &'a str) -> Option<usize> {
+        match self.never {}
+    }
+}
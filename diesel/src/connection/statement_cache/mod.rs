@@ -50,6 +50,14 @@
 //! them. They will be prepared and immediately released after use (or in the
 //! case of PG they will use the unnamed prepared statement).
 //!
+//! Whether a single query is cached can be overridden via
+//! [`StatementCacheDsl`](crate::query_dsl::StatementCacheDsl). Queries marked
+//! with [`never_cache`](crate::query_dsl::StatementCacheDsl::never_cache) are
+//! treated as unsafe to cache, while queries marked with
+//! [`always_prepare`](crate::query_dsl::StatementCacheDsl::always_prepare) are
+//! cached even if the connection uses [`CacheSize::Disabled`]. The latter does
+//! not apply to queries that are unsafe to cache.
+//!
 //! For statements which are able to be cached, we then have to determine what
 //! to use as the cache key. The standard method that virtually all ORMs or
 //! database access layers use in the wild is to store the statements in a
@@ -95,7 +103,7 @@
 //! nanoseconds on an operation that will take microseconds or even
 //! milliseconds.
 
-use crate::util::std_compat::Entry;
+use crate::util::std_compat::{Entry, HashMap};
 use alloc::borrow::Cow;
 use alloc::boxed::Box;
 use alloc::string::String;
@@ -105,7 +113,8 @@ use core::hash::Hash;
 use core::ops::{Deref, DerefMut};
 
 use strategy::{
-    LookupStatementResult, StatementCacheStrategy, WithCacheStrategy, WithoutCacheStrategy,
+    LookupStatementResult, LruCacheStrategy, StatementCacheStrategy, WithCacheStrategy,
+    WithoutCacheStrategy,
 };
 
 use crate::backend::Backend;
@@ -113,6 +122,7 @@ use crate::connection::InstrumentationEvent;
 use crate::query_builder::*;
 use crate::result::QueryResult;
 
+use super::instrumentation::{StatementCacheStatus, record_statement_cache_status};
 use super::{CacheSize, Instrumentation};
 
 /// Various interfaces and implementations to control connection statement caching.
@@ -127,6 +137,12 @@ pub mod strategy;
 )]
 pub struct StatementCache<DB: Backend, Statement> {
     cache: Box<dyn StatementCacheStrategy<DB, Statement>>,
+    // statements of queries forced into the cache via
+    // `StatementCacheMode::PrepareForCache` while the
+    // configured strategy does not cache them
+    forced_cache: WithCacheStrategy<DB, Statement>,
+    // the sql of cached statements identified by their type
+    type_sql: HashMap<TypeId, String>,
     // increment every time a query is cached
     // some backends might use it to create unique prepared statement names
     cache_counter: u64,
@@ -169,6 +185,8 @@ where
     pub fn new() -> Self {
         StatementCache {
             cache: Box::new(WithCacheStrategy::default()),
+            forced_cache: WithCacheStrategy::default(),
+            type_sql: HashMap::default(),
             cache_counter: 0,
         }
     }
@@ -178,6 +196,7 @@ where
         if self.cache.cache_size() != size {
             self.cache = match size {
                 CacheSize::Unbounded => Box::new(WithCacheStrategy::default()),
+                CacheSize::Bounded(max_size) => Box::new(LruCacheStrategy::new(max_size)),
                 CacheSize::Disabled => Box::new(WithoutCacheStrategy::default()),
             }
         }
@@ -185,11 +204,26 @@ where
 
     /// Removes all cached statements so subsequent queries are re-prepared,
     /// while keeping the configured caching strategy.
-    // Currently used only by the SQLite authorizer, so it is compiled only for
-    // backends that provide that caller.
-    #[cfg(feature = "__sqlite-shared")]
-    pub(crate) fn clear(&mut self) {
+    pub fn clear(&mut self) {
         self.cache.clear();
+        self.forced_cache.clear();
+        self.type_sql.clear();
+    }
+
+    /// The SQL of all cached statements
+    ///
+    /// See [`Connection::cached_statements`](super::Connection::cached_statements)
+    /// for details about the order of the returned statements.
+    pub fn cached_sql(&self) -> Vec<&str> {
+        self.cache
+            .cached_keys()
+            .into_iter()
+            .chain(self.forced_cache.cached_keys())
+            .filter_map(|key| match key {
+                StatementCacheKey::Type(id) => self.type_sql.get(id).map(String::as_str),
+                StatementCacheKey::Sql { sql, .. } => Some(sql.as_str()),
+            })
+            .collect()
     }
 
     /// Setting custom caching strategy. It is used in tests, to verify caching logic
@@ -273,12 +307,15 @@ where
     {
         Self::cached_statement_non_generic_impl(
             self.cache.as_mut(),
+            &mut self.forced_cache,
+            &mut self.type_sql,
             maybe_type_id,
             source,
             backend,
             bind_types,
             conn,
-            |conn, sql, is_cached| {
+            instrumentation,
+            |conn, sql, is_cached, instrumentation| {
                 if is_cached {
                     instrumentation.on_connection_event(InstrumentationEvent::CacheQuery { sql });
                     self.cache_counter += 1;
@@ -302,14 +339,18 @@ where
     /// There will be only a instance per connection type `C` for each connection that
     /// uses this prepared statement impl, this closely correlates to the types `DB` and `Statement`
     /// for the overall statement cache impl
+    #[allow(clippy::too_many_arguments)] // we need all of them
     fn cached_statement_non_generic_impl<'a, R, C>(
         cache: &'a mut dyn StatementCacheStrategy<DB, Statement>,
+        forced_cache: &'a mut WithCacheStrategy<DB, Statement>,
+        type_sql: &mut HashMap<TypeId, String>,
         maybe_type_id: Option<TypeId>,
         source: &dyn QueryFragmentForCachedStatement<DB>,
         backend: &DB,
         bind_types: &[DB::TypeMetadata],
         conn: C,
-        prepare_fn: impl FnOnce(C, &str, bool) -> R,
+        instrumentation: &mut dyn Instrumentation,
+        prepare_fn: impl FnOnce(C, &str, bool, &mut dyn Instrumentation) -> R,
     ) -> R::Return<'a>
     where
         R: StatementCallbackReturnType<Statement, C> + 'a,
@@ -337,12 +378,25 @@ where
                 Ok(sql) => sql,
                 Err(e) => return R::from_error(e),
             };
-            return prepare_fn(conn, &sql, false).map_to_no_cache();
+            record_statement_cache_status(instrumentation, StatementCacheStatus::Uncached);
+            return prepare_fn(conn, &sql, false, instrumentation).map_to_no_cache();
         }
-        let entry = cache.lookup_statement(cache_key);
+        let entry = match cache.lookup_statement(cache_key) {
+            // queries forced into the cache are cached separately
+            // if the configured strategy does not cache them
+            LookupStatementResult::NoCache(cache_key) => {
+                match source.is_forced_to_cache_prepared(backend) {
+                    Ok(true) => forced_cache.lookup_statement(cache_key),
+                    Ok(false) => LookupStatementResult::NoCache(cache_key),
+                    Err(e) => return R::from_error(e),
+                }
+            }
+            entry => entry,
+        };
         match entry {
             // The statement is already cached
             LookupStatementResult::CacheEntry(Entry::Occupied(e)) => {
+                record_statement_cache_status(instrumentation, StatementCacheStatus::Hit);
                 R::map_to_cache(e.into_mut(), conn)
             }
             // The statement is not cached but there is capacity to cache it
@@ -351,7 +405,13 @@ where
                     Ok(sql) => sql,
                     Err(e) => return R::from_error(e),
                 };
-                let st = prepare_fn(conn, &sql, true);
+                if let StatementCacheKey::Type(id) = *e.key() {
+                    type_sql
+                        .entry(id)
+                        .or_insert_with(|| sql.clone().into_owned());
+                }
+                record_statement_cache_status(instrumentation, StatementCacheStatus::Miss);
+                let st = prepare_fn(conn, &sql, true, instrumentation);
                 st.register_cache(|stmt| e.insert(stmt))
             }
             // The statement is not cached and there is no capacity to cache it
@@ -360,7 +420,8 @@ where
                     Ok(sql) => sql,
                     Err(e) => return R::from_error(e),
                 };
-                prepare_fn(conn, &sql, false).map_to_no_cache()
+                record_statement_cache_status(instrumentation, StatementCacheStatus::Uncached);
+                prepare_fn(conn, &sql, false, instrumentation).map_to_no_cache()
             }
         }
     }
@@ -386,6 +447,13 @@ pub trait QueryFragmentForCachedStatement<DB> {
 
     /// Check whether it's safe to cache the query
     fn is_safe_to_cache_prepared(&self, backend: &DB) -> QueryResult<bool>;
+
+    /// Check whether the query should be cached even if the
+    /// configured caching strategy does not cache it
+    fn is_forced_to_cache_prepared(&self, backend: &DB) -> QueryResult<bool> {
+        let _ = backend;
+        Ok(false)
+    }
 }
 
 impl<T, DB> QueryFragmentForCachedStatement<DB> for T
@@ -403,6 +471,12 @@ where
     fn is_safe_to_cache_prepared(&self, backend: &DB) -> QueryResult<bool> {
         <T as QueryFragment<DB>>::is_safe_to_cache_prepared(self, backend)
     }
+
+    fn is_forced_to_cache_prepared(&self, backend: &DB) -> QueryResult<bool> {
+        let mut result = false;
+        self.walk_ast(AstPass::is_forced_to_cache_prepared(&mut result, backend))?;
+        Ok(result)
+    }
 }
 
 /// Wraps a possibly cached prepared statement
@@ -537,6 +611,22 @@ pub enum StatementCacheKey<DB: Backend> {
     },
 }
 
+impl<DB> Clone for StatementCacheKey<DB>
+where
+    DB: Backend,
+    DB::TypeMetadata: Clone,
+{
+    fn clone(&self) -> Self {
+        match self {
+            StatementCacheKey::Type(id) => StatementCacheKey::Type(*id),
+            StatementCacheKey::Sql { sql, bind_types } => StatementCacheKey::Sql {
+                sql: sql.clone(),
+                bind_types: bind_types.clone(),
+            },
+        }
+    }
+}
+
 impl<DB> StatementCacheKey<DB>
 where
     DB: Backend,
@@ -1,7 +1,10 @@
 use crate::backend::Backend;
 use crate::util::std_compat::Entry;
 use crate::util::std_compat::HashMap;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
 use core::hash::Hash;
+use core::num::NonZeroUsize;
 
 use super::{CacheSize, StatementCacheKey};
 
@@ -44,11 +47,10 @@ where
     ) -> LookupStatementResult<'_, DB, Statement>;
 
     /// Removes all cached statements so that subsequent queries are re-prepared.
-    // Only reached through `StatementCache::clear`, which is currently used only
-    // by the SQLite authorizer, so it is dead code for backends compiled without
-    // that caller.
-    #[cfg(feature = "__sqlite-shared")]
     fn clear(&mut self);
+
+    /// Returns the keys of all cached statements
+    fn cached_keys(&self) -> Vec<&StatementCacheKey<DB>>;
 }
 
 /// Cache all (safe) statements for as long as connection is alive.
@@ -90,10 +92,90 @@ where
         CacheSize::Unbounded
     }
 
-    #[cfg(feature = "__sqlite-shared")]
     fn clear(&mut self) {
         self.cache.clear();
     }
+
+    fn cached_keys(&self) -> Vec<&StatementCacheKey<DB>> {
+        self.cache.keys().collect()
+    }
+}
+
+/// Cache up to a fixed number of statements, removing
+/// the least recently used statement if the cache is full.
+#[allow(missing_debug_implementations, unreachable_pub)]
+pub struct LruCacheStrategy<DB, Statement>
+where
+    DB: Backend,
+{
+    cache: HashMap<StatementCacheKey<DB>, Statement>,
+    // ordered from the least recently used to the most recently used key
+    //
+    // A linear search is fine here as bounded caches are expected
+    // to be small and cache keys are mostly type ids
+    usage: VecDeque<StatementCacheKey<DB>>,
+    max_size: NonZeroUsize,
+}
+
+impl<DB, Statement> LruCacheStrategy<DB, Statement>
+where
+    DB: Backend,
+{
+    /// Create a new cache holding at most `max_size` statements
+    pub fn new(max_size: NonZeroUsize) -> Self {
+        Self {
+            cache: Default::default(),
+            usage: VecDeque::new(),
+            max_size,
+        }
+    }
+}
+
+impl<DB, Statement> StatementCacheStrategy<DB, Statement> for LruCacheStrategy<DB, Statement>
+where
+    DB: Backend + 'static,
+    StatementCacheKey<DB>: Hash + Eq,
+    DB::TypeMetadata: Send + Clone,
+    DB::QueryBuilder: Default,
+    Statement: Send + 'static,
+{
+    fn lookup_statement(
+        &mut self,
+        key: StatementCacheKey<DB>,
+    ) -> LookupStatementResult<'_, DB, Statement> {
+        if let Some(index) = self.usage.iter().position(|k| *k == key) {
+            if let Some(key) = self.usage.remove(index) {
+                self.usage.push_back(key);
+            }
+        } else {
+            // `usage` might contain keys of statements that failed
+            // to prepare, so it is used to bound the cache instead
+            // of the number of cached statements
+            if self.usage.len() >= self.max_size.get()
+                && let Some(evicted) = self.usage.pop_front()
+            {
+                self.cache.remove(&evicted);
+            }
+            self.usage.push_back(key.clone());
+        }
+        LookupStatementResult::CacheEntry(self.cache.entry(key))
+    }
+
+    fn cache_size(&self) -> CacheSize {
+        CacheSize::Bounded(self.max_size)
+    }
+
+    fn clear(&mut self) {
+        self.cache.clear();
+        self.usage.clear();
+    }
+
+    fn cached_keys(&self) -> Vec<&StatementCacheKey<DB>> {
+        self.usage
+            .iter()
+            .filter(|key| self.cache.contains_key(*key))
+            .collect()
+    }
 }
 
 /// No statements will be cached,
@@ -120,8 +202,11 @@ where
         CacheSize::Disabled
     }
 
-    #[cfg(feature = "__sqlite-shared")]
     fn clear(&mut self) {}
+
+    fn cached_keys(&self) -> Vec<&StatementCacheKey<DB>> {
+        Vec::new()
+    }
 }
 
 #[allow(dead_code)]
@@ -169,7 +254,11 @@ mod tests_pg {
     use crate::sql_types::{Integer, VarChar};
     use crate::table;
     use crate::test_helpers::pg_database_url;
-    use crate::{Connection, ExpressionMethods, IntoSql, PgConnection, QueryDsl, RunQueryDsl};
+    use crate::{
+        Connection, ExpressionMethods, IntoSql, PgConnection, QueryDsl, RunQueryDsl,
+        StatementCacheDsl,
+    };
+    use core::num::NonZeroUsize;
 
     use super::testing_utils::{RecordCacheEvents, count_cache_calls};
 
@@ -354,6 +443,76 @@ mod tests_pg {
         assert_eq!(Ok(1), query.get_result(connection));
         assert_eq!(0, count_cache_calls(connection));
     }
+
+    #[diesel_test_helper::test]
+    fn queries_marked_as_never_cache_are_not_cached() {
+        let connection = &mut connection();
+
+        let query = crate::select(1.into_sql::<Integer>()).never_cache();
+
+        assert_eq!(Ok(1), query.get_result(connection));
+        assert_eq!(0, count_cache_calls(connection));
+    }
+
+    #[diesel_test_helper::test]
+    fn queries_marked_as_always_prepare_are_cached_with_disabled_cache() {
+        let connection = &mut connection();
+        connection.set_prepared_statement_cache_size(CacheSize::Disabled);
+
+        let query = crate::select(1.into_sql::<Integer>()).always_prepare();
+
+        assert_eq!(Ok(1), query.get_result(connection));
+        assert_eq!(1, count_cache_calls(connection));
+        assert_eq!(Ok(1), query.get_result(connection));
+        assert_eq!(1, count_cache_calls(connection));
+
+        let query = crate::select(sql::<Integer>("1")).always_prepare();
+        assert_eq!(Ok(1), query.get_result(connection));
+        assert_eq!(1, count_cache_calls(connection));
+    }
+
+    #[diesel_test_helper::test]
+    fn bounded_cache_deallocates_evicted_statements() {
+        use crate::dsl::count_star;
+
+        let connection = &mut connection();
+        connection.set_prepared_statement_cache_size(CacheSize::Bounded(NonZeroUsize::MIN));
+
+        let prepared_statements = |connection: &mut PgConnection| {
+            crate::sql_query(
+                "SELECT COUNT(*) AS count FROM pg_prepared_statements \
+                 WHERE name LIKE '__diesel_stmt_%'",
+            )
+            .get_result::<PreparedStatementCount>(connection)
+            .unwrap()
+            .count
+        };
+
+        assert_eq!(
+            Ok(1),
+            crate::select(1.into_sql::<Integer>()).get_result(connection)
+        );
+        assert_eq!(1, prepared_statements(connection));
+        assert_eq!(
+            Ok(0),
+            users::table
+                .select(count_star())
+                .get_result::<i64>(connection)
+        );
+        assert_eq!(2, count_cache_calls(connection));
+        assert_eq!(1, connection.cached_statements().len());
+        assert_eq!(1, prepared_statements(connection));
+
+        connection.clear_prepared_statement_cache();
+        assert!(connection.cached_statements().is_empty());
+        assert_eq!(0, prepared_statements(connection));
+    }
+
+    #[derive(crate::QueryableByName)]
+    struct PreparedStatementCount {
+        #[diesel(sql_type = crate::sql_types::BigInt)]
+        count: i64,
+    }
 }
 
 #[cfg(test)]
@@ -364,7 +523,8 @@ mod tests_sqlite {
     use crate::dsl::sql;
     use crate::query_dsl::RunQueryDsl;
     use crate::sql_types::Integer;
-    use crate::{Connection, ExpressionMethods, IntoSql, SqliteConnection};
+    use crate::{Connection, ExpressionMethods, IntoSql, SqliteConnection, StatementCacheDsl};
+    use core::num::NonZeroUsize;
 
     use super::testing_utils::{RecordCacheEvents, count_cache_calls};
 
@@ -436,4 +596,77 @@ mod tests_sqlite {
         assert_eq!(Ok(1), query.get_result(connection));
         assert_eq!(0, count_cache_calls(connection));
     }
+
+    #[diesel_test_helper::test]
+    fn queries_marked_as_never_cache_are_not_cached() {
+        let connection = &mut connection();
+
+        let query = crate::select(1.into_sql::<Integer>()).never_cache();
+
+        assert_eq!(Ok(1), query.get_result(connection));
+        assert_eq!(0, count_cache_calls(connection));
+    }
+
+    #[diesel_test_helper::test]
+    fn queries_marked_as_always_prepare_are_cached_with_disabled_cache() {
+        let connection = &mut connection();
+        connection.set_prepared_statement_cache_size(CacheSize::Disabled);
+
+        let query = crate::select(1.into_sql::<Integer>()).always_prepare();
+
+        assert_eq!(Ok(1), query.get_result(connection));
+        assert_eq!(1, count_cache_calls(connection));
+        assert_eq!(Ok(1), query.get_result(connection));
+        assert_eq!(1, count_cache_calls(connection));
+
+        let query = crate::select(sql::<Integer>("1")).always_prepare();
+        assert_eq!(Ok(1), query.get_result(connection));
+        assert_eq!(1, count_cache_calls(connection));
+    }
+
+    #[diesel_test_helper::test]
+    fn bounded_cache_evicts_the_least_recently_used_statement() {
+        let connection = &mut connection();
+        connection
+            .set_prepared_statement_cache_size(CacheSize::Bounded(NonZeroUsize::new(2).unwrap()));
+
+        let one = crate::select(1.into_sql::<Integer>());
+        let two = crate::select(2.into_sql::<Integer>().eq(2));
+        let three = crate::select(3.into_sql::<Integer>().eq(3).eq(true));
+
+        assert_eq!(Ok(1), one.get_result(connection));
+        assert_eq!(Ok(true), two.get_result(connection));
+        assert_eq!(Ok(1), one.get_result(connection));
+        assert_eq!(2, count_cache_calls(connection));
+        assert_eq!(
+            vec!["SELECT (? = ?)", "SELECT ?"],
+            connection.cached_statements()
+        );
+
+        // evicts `two` as `one` was used more recently
+        assert_eq!(Ok(true), three.get_result(connection));
+        assert_eq!(3, count_cache_calls(connection));
+        assert_eq!(
+            vec!["SELECT ?", "SELECT ((? = ?) = ?)"],
+            connection.cached_statements()
+        );
+        assert_eq!(Ok(1), one.get_result(connection));
+        assert_eq!(3, count_cache_calls(connection));
+        assert_eq!(Ok(true), two.get_result(connection));
+        assert_eq!(4, count_cache_calls(connection));
+    }
+
+    #[diesel_test_helper::test]
+    fn clearing_the_cache_prepares_statements_again() {
+        let connection = &mut connection();
+        let query = crate::select(1.into_sql::<Integer>());
+
+        assert_eq!(Ok(1), query.get_result(connection));
+        assert_eq!(vec!["SELECT ?"], connection.cached_statements());
+        connection.clear_prepared_statement_cache();
+        assert!(connection.cached_statements().is_empty());
+
+        assert_eq!(Ok(1), query.get_result(connection));
+        assert_eq!(2, count_cache_calls(connection));
+    }
 }
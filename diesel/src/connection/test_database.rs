@@ -0,0 +1,166 @@
+use alloc::boxed::Box;
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+use alloc::string::String;
+
+use super::Connection;
+use crate::migration::Result;
+
+/// A connection type which is able to provide an isolated database for each test
+///
+/// This trait is used by [`#[diesel_test]`](crate::diesel_test) to set up a fresh
+/// database before a test runs. It is implemented for `SqliteConnection` and
+/// `PgConnection` if the corresponding backend feature is enabled. You can implement
+/// it for your own connection types to use them with `#[diesel_test]`.
+pub trait TestDatabaseConnection: Connection + Sized {
+    /// Create a new empty database for the test with the given name
+    /// and establish a connection to it
+    ///
+    /// The test name is unique for each test function, but the same test
+    /// might be run concurrently by several processes.
+    fn create_test_database(test_name: &str) -> Result<TestDatabase<Self>>;
+}
+
+/// A connection to a database that only exists for the duration of a single test
+///
+/// Dropping this type closes the connection and removes the database afterwards.
+#[allow(missing_debug_implementations)]
+pub struct TestDatabase<C> {
+    connection: Option<C>,
+    teardown: Option<Box<dyn FnOnce()>>,
+}
+
+impl<C> TestDatabase<C> {
+    /// Wrap a connection to a newly created test database
+    ///
+    /// The `teardown` callback is executed after the connection is closed
+    /// and is expected to remove the database again.
+    pub fn new(connection: C, teardown: impl FnOnce() + 'static) -> Self {
+        Self {
+            connection: Some(connection),
+            teardown: Some(Box::new(teardown)),
+        }
+    }
+
+    /// The connection to the test database
+    pub fn connection(&mut self) -> &mut C {
+        self.connection
+            .as_mut()
+            .expect("The connection is only removed on drop")
+    }
+}
+
+impl<C> Drop for TestDatabase<C> {
+    fn drop(&mut self) {
+        // the connection needs to be closed before the database can be removed
+        drop(self.connection.take());
+        if let Some(teardown) = self.teardown.take() {
+            teardown();
+        }
+    }
+}
+
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+fn unique_suffix() -> String {
+    use core::sync::atomic::{AtomicUsize, Ordering};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    format!(
+        "{}_{}",
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+#[cfg(feature = "sqlite")]
+impl TestDatabaseConnection for crate::SqliteConnection {
+    fn create_test_database(test_name: &str) -> Result<TestDatabase<Self>> {
+        let test_name = test_name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect::<String>();
+        let path = std::env::temp_dir().join(format!(
+            "diesel_test_{test_name}_{}.sqlite",
+            unique_suffix()
+        ));
+        // a file might be left over from a previous run that was aborted
+        let _ = std::fs::remove_file(&path);
+        let url = path
+            .to_str()
+            .ok_or("The temporary directory path is not valid UTF-8")?;
+        let connection = Self::establish(url)?;
+        Ok(TestDatabase::new(connection, move || {
+            let _ = std::fs::remove_file(&path);
+        }))
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl TestDatabaseConnection for crate::PgConnection {
+    fn create_test_database(_test_name: &str) -> Result<TestDatabase<Self>> {
+        use crate::connection::SimpleConnection;
+
+        let base_url = std::env::var("PG_DATABASE_URL")
+            .or_else(|_| std::env::var("DATABASE_URL"))
+            .map_err(|_| "Neither `PG_DATABASE_URL` nor `DATABASE_URL` is set")?;
+        // the test name might exceed the maximal identifier length of postgres,
+        // so it is not included here
+        let database = format!("diesel_test_{}", unique_suffix());
+        let create = match std::env::var("DIESEL_TEST_PG_TEMPLATE") {
+            Ok(template) => format!(
+                r#"CREATE DATABASE "{database}" TEMPLATE "{}""#,
+                template.replace('"', "\"\"")
+            ),
+            Err(_) => format!(r#"CREATE DATABASE "{database}""#),
+        };
+
+        let mut admin_connection = Self::establish(&base_url)?;
+        admin_connection.batch_execute(&format!(r#"DROP DATABASE IF EXISTS "{database}""#))?;
+        admin_connection.batch_execute(&create)?;
+        drop(admin_connection);
+
+        let connection = Self::establish(&replace_database_in_url(&base_url, &database))?;
+        Ok(TestDatabase::new(connection, move || {
+            if let Ok(mut conn) = Self::establish(&base_url) {
+                let _ = conn.batch_execute(&format!(r#"DROP DATABASE IF EXISTS "{database}""#));
+            }
+        }))
+    }
+}
+
+#[cfg(feature = "postgres")]
+fn replace_database_in_url(url: &str, database: &str) -> String {
+    let (base, query) = match url.split_once('?') {
+        Some((base, query)) => (base, Some(query)),
+        None => (url, None),
+    };
+    let authority_start = base.find("://").map(|i| i + 3).unwrap_or(0);
+    let base = match base[authority_start..].find('/') {
+        Some(path_start) => &base[..authority_start + path_start],
+        None => base,
+    };
+    match query {
+        Some(query) => format!("{base}/{database}?{query}"),
+        None => format!("{base}/{database}"),
+    }
+}
+
+#[cfg(all(test, feature = "postgres"))]
+mod tests {
+    use super::replace_database_in_url;
+
+    #[test]
+    fn replace_database_in_url_keeps_authority_and_query() {
+        assert_eq!(
+            replace_database_in_url("postgres://user:pw@localhost:5432/app", "other"),
+            "postgres://user:pw@localhost:5432/other"
+        );
+        assert_eq!(
+            replace_database_in_url("postgres://localhost", "other"),
+            "postgres://localhost/other"
+        );
+        assert_eq!(
+            replace_database_in_url("postgres://localhost/app?sslmode=disable", "other"),
+            "postgres://localhost/other?sslmode=disable"
+        );
+    }
+}
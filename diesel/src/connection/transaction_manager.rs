@@ -54,21 +54,98 @@ pub trait TransactionManager<Conn: Connection> {
         E: From<Error>,
     {
         Self::begin_transaction(conn)?;
-        match callback(&mut *conn) {
-            Ok(value) => {
-                Self::commit_transaction(conn)?;
-                Ok(value)
-            }
-            Err(user_error) => match Self::rollback_transaction(conn) {
-                Ok(()) => Err(user_error),
-                Err(Error::BrokenTransactionManager) => {
-                    // In this case we are probably more interested by the
-                    // original error, which likely caused this
-                    Err(user_error)
-                }
-                Err(rollback_error) => Err(rollback_error.into()),
+        finish_transaction::<Self, _, _, _, _>(conn, callback)
+    }
+
+    /// Begin a new transaction with the given isolation level and access mode
+    ///
+    /// This function is expected to return [`Error::AlreadyInTransaction`]
+    /// if a transaction is already open. The default implementation returns
+    /// [`Error::UnsupportedTransactionOptions`].
+    fn begin_transaction_with(
+        _conn: &mut Conn,
+        isolation: Isolation,
+        access: Access,
+    ) -> QueryResult<()> {
+        Err(Error::UnsupportedTransactionOptions { isolation, access })
+    }
+
+    /// Executes the given function inside of a database transaction
+    /// with the given isolation level and access mode
+    ///
+    /// Each implementation of this function needs to fulfill the documented
+    /// behaviour of [`Connection::transaction_with`]
+    fn transaction_with<F, R, E>(
+        conn: &mut Conn,
+        isolation: Isolation,
+        access: Access,
+        callback: F,
+    ) -> Result<R, E>
+    where
+        F: FnOnce(&mut Conn) -> Result<R, E>,
+        E: From<Error>,
+    {
+        Self::begin_transaction_with(conn, isolation, access)?;
+        finish_transaction::<Self, _, _, _, _>(conn, callback)
+    }
+
+    /// Creates a savepoint with the given name inside of the current transaction
+    ///
+    /// This function is expected to return [`Error::NotInTransaction`]
+    /// if no transaction is open and to increment the transaction depth
+    /// by 1 otherwise. The default implementation uses
+    /// `SAVEPOINT name`, which is supported by all built-in backends.
+    fn begin_savepoint(conn: &mut Conn, name: &str) -> QueryResult<()> {
+        let sql = savepoint_sql("SAVEPOINT", name)?;
+        let depth = Self::transaction_manager_status_mut(conn)
+            .transaction_depth()?
+            .ok_or(Error::NotInTransaction)?;
+        conn.instrumentation().on_connection_event(
+            super::instrumentation::InstrumentationEvent::BeginTransaction {
+                depth: depth.saturating_add(1),
             },
-        }
+        );
+        conn.batch_execute(&sql)?;
+        Self::transaction_manager_status_mut(conn)
+            .transaction_state()?
+            .change_transaction_depth(TransactionDepthChange::IncreaseDepth)
+    }
+
+    /// Releases the savepoint with the given name
+    ///
+    /// This function is expected to decrement the transaction depth by 1,
+    /// even if releasing the savepoint fails, so that the depth stays in sync
+    /// with the number of calls to [`TransactionManager::begin_savepoint`].
+    /// The default implementation uses `RELEASE SAVEPOINT name`.
+    fn release_savepoint(conn: &mut Conn, name: &str) -> QueryResult<()> {
+        let sql = savepoint_sql("RELEASE SAVEPOINT", name)?;
+        let depth = Self::transaction_manager_status_mut(conn)
+            .transaction_depth()?
+            .ok_or(Error::NotInTransaction)?;
+        conn.instrumentation().on_connection_event(
+            super::instrumentation::InstrumentationEvent::CommitTransaction { depth },
+        );
+        let result = conn.batch_execute(&sql);
+        Self::transaction_manager_status_mut(conn)
+            .transaction_state()?
+            .change_transaction_depth(TransactionDepthChange::DecreaseDepth)?;
+        result
+    }
+
+    /// Rolls back all changes made since the savepoint with the given
+    /// name was created
+    ///
+    /// The savepoint itself stays open, so the transaction depth is not
+    /// changed. The default implementation uses `ROLLBACK TO SAVEPOINT name`.
+    fn rollback_to_savepoint(conn: &mut Conn, name: &str) -> QueryResult<()> {
+        let sql = savepoint_sql("ROLLBACK TO SAVEPOINT", name)?;
+        let depth = Self::transaction_manager_status_mut(conn)
+            .transaction_depth()?
+            .ok_or(Error::NotInTransaction)?;
+        conn.instrumentation().on_connection_event(
+            super::instrumentation::InstrumentationEvent::RollbackTransaction { depth },
+        );
+        conn.batch_execute(&sql)
     }
 
     /// This methods checks if the connection manager is considered to be broken
@@ -101,6 +178,96 @@ pub trait TransactionManager<Conn: Connection> {
     }
 }
 
+/// Runs `callback` inside of the already started transaction and commits
+/// or rolls back the transaction depending on its result
+pub(crate) fn finish_transaction<TM, Conn, F, R, E>(conn: &mut Conn, callback: F) -> Result<R, E>
+where
+    TM: TransactionManager<Conn> + ?Sized,
+    Conn: Connection,
+    F: FnOnce(&mut Conn) -> Result<R, E>,
+    E: From<Error>,
+{
+    match callback(&mut *conn) {
+        Ok(value) => {
+            TM::commit_transaction(conn)?;
+            Ok(value)
+        }
+        Err(user_error) => match TM::rollback_transaction(conn) {
+            Ok(()) => Err(user_error),
+            Err(Error::BrokenTransactionManager) => {
+                // In this case we are probably more interested by the
+                // original error, which likely caused this
+                Err(user_error)
+            }
+            Err(rollback_error) => Err(rollback_error.into()),
+        },
+    }
+}
+
+/// Builds the SQL for a savepoint statement
+///
+/// Savepoint names are interpolated into the statement, therefore
+/// only plain identifiers are accepted.
+fn savepoint_sql(statement: &str, name: &str) -> QueryResult<alloc::string::String> {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if !is_identifier {
+        return Err(Error::QueryBuilderError(
+            alloc::format!("`{name}` is not a valid savepoint name").into(),
+        ));
+    }
+    Ok(alloc::format!("{statement} {name}"))
+}
+
+/// The isolation level of a transaction started via
+/// [`Connection::transaction_with`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Isolation {
+    /// `READ UNCOMMITTED`
+    ReadUncommitted,
+    /// `READ COMMITTED`
+    ReadCommitted,
+    /// `REPEATABLE READ`
+    RepeatableRead,
+    /// `SERIALIZABLE`
+    Serializable,
+}
+
+impl Isolation {
+    #[cfg(any(feature = "postgres_backend", feature = "mysql_backend"))]
+    pub(crate) fn to_sql(self) -> &'static str {
+        match self {
+            Isolation::ReadUncommitted => "READ UNCOMMITTED",
+            Isolation::ReadCommitted => "READ COMMITTED",
+            Isolation::RepeatableRead => "REPEATABLE READ",
+            Isolation::Serializable => "SERIALIZABLE",
+        }
+    }
+}
+
+/// The access mode of a transaction started via
+/// [`Connection::transaction_with`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Access {
+    /// `READ WRITE`
+    ReadWrite,
+    /// `READ ONLY`
+    ReadOnly,
+}
+
+impl Access {
+    #[cfg(any(feature = "postgres_backend", feature = "mysql_backend"))]
+    pub(crate) fn to_sql(self) -> &'static str {
+        match self {
+            Access::ReadWrite => "READ WRITE",
+            Access::ReadOnly => "READ ONLY",
+        }
+    }
+}
+
 /// An implementation of `TransactionManager` which can be used for backends
 /// which use ANSI standard syntax for savepoints such as SQLite and PostgreSQL.
 #[derive(Default, Debug)]
@@ -368,6 +535,15 @@ where
         Ok(())
     }
 
+    fn begin_transaction_with(
+        conn: &mut Conn,
+        isolation: Isolation,
+        access: Access,
+    ) -> QueryResult<()> {
+        let sql = conn.begin_transaction_with_sql(isolation, access)?;
+        Self::begin_transaction_sql(conn, &sql)
+    }
+
     fn rollback_transaction(conn: &mut Conn) -> QueryResult<()> {
         let transaction_state = Self::get_transaction_state(conn)?;
 
@@ -1262,4 +1438,137 @@ mod test {
         t1.join().unwrap();
         t2.join().unwrap();
     }
+
+    #[diesel_test_helper::test]
+    fn named_savepoints_roll_back_partially() {
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::result::Error;
+        use crate::sql_types::Integer;
+        use crate::test_helpers::*;
+        use std::num::NonZeroU32;
+
+        fn ids(conn: &mut TestConnection) -> QueryResult<Vec<i32>> {
+            sql::<Integer>("SELECT id FROM savepoint_test ORDER BY id").load(conn)
+        }
+
+        let conn = &mut TestConnection::establish(&database_url()).unwrap();
+        crate::sql_query("CREATE TEMPORARY TABLE savepoint_test (id INTEGER)")
+            .execute(conn)
+            .unwrap();
+
+        let result = conn.savepoint("outside", |_| Ok(()));
+        assert!(matches!(result, Err(Error::NotInTransaction)));
+
+        conn.transaction(|conn| {
+            crate::sql_query("INSERT INTO savepoint_test VALUES (1)").execute(conn)?;
+            let result = conn.savepoint("failing", |conn| {
+                assert_eq!(NonZeroU32::new(2), conn.transaction_depth()?);
+                crate::sql_query("INSERT INTO savepoint_test VALUES (2)").execute(conn)?;
+                Err::<(), _>(Error::RollbackTransaction)
+            });
+            assert_eq!(Err(Error::RollbackTransaction), result);
+            assert_eq!(NonZeroU32::new(1), conn.transaction_depth()?);
+            assert_eq!(vec![1], ids(conn)?);
+
+            conn.savepoint("partial", |conn| {
+                crate::sql_query("INSERT INTO savepoint_test VALUES (3)").execute(conn)?;
+                conn.rollback_to_savepoint("partial")?;
+                crate::sql_query("INSERT INTO savepoint_test VALUES (4)").execute(conn)
+            })?;
+            assert_eq!(vec![1, 4], ids(conn)?);
+
+            let result = conn.savepoint("not a name; DROP TABLE savepoint_test", |_| Ok(()));
+            assert!(matches!(result, Err(Error::QueryBuilderError(_))));
+            assert_eq!(NonZeroU32::new(1), conn.transaction_depth()?);
+            QueryResult::Ok(())
+        })
+        .unwrap();
+        assert_eq!(None, conn.transaction_depth().unwrap());
+        assert_eq!(Ok(vec![1, 4]), ids(conn));
+    }
+
+    #[diesel_test_helper::test]
+    fn transaction_with_cannot_be_nested() {
+        use crate::connection::{Access, Isolation};
+        use crate::prelude::*;
+        use crate::result::Error;
+        use crate::test_helpers::*;
+
+        let conn = &mut TestConnection::establish(&database_url()).unwrap();
+        let result = conn.transaction(|conn| {
+            conn.transaction_with(Isolation::Serializable, Access::ReadWrite, |_| Ok(()))
+        });
+        assert_eq!(Err(Error::AlreadyInTransaction), result);
+
+        conn.transaction_with(Isolation::Serializable, Access::ReadWrite, |conn| {
+            conn.transaction(|_| Ok::<_, Error>(()))
+        })
+        .unwrap();
+    }
+
+    #[diesel_test_helper::test]
+    #[cfg(feature = "postgres")]
+    fn pg_transaction_with_sets_isolation_level_and_access_mode() {
+        use crate::connection::{Access, AnsiTransactionManager, Isolation, TransactionManager};
+        use crate::dsl::sql;
+        use crate::prelude::*;
+        use crate::result::{DatabaseErrorKind, Error};
+        use crate::sql_types::Text;
+
+        let conn = &mut crate::test_helpers::pg_connection_no_transaction();
+        let isolation = conn
+            .transaction_with(Isolation::RepeatableRead, Access::ReadOnly, |conn| {
+                crate::select(sql::<Text>("current_setting('transaction_isolation')"))
+                    .get_result::<String>(conn)
+            })
+            .unwrap();
+        assert_eq!("repeatable read", isolation);
+
+        let result = conn.transaction_with(Isolation::ReadCommitted, Access::ReadOnly, |conn| {
+            crate::sql_query("CREATE TEMPORARY TABLE read_only_test (id INT)").execute(conn)
+        });
+        assert!(matches!(
+            result,
+            Err(Error::DatabaseError(
+                DatabaseErrorKind::ReadOnlyTransaction,
+                _
+            ))
+        ));
+        assert_eq!(
+            None,
+            <AnsiTransactionManager as TransactionManager<crate::PgConnection>>::transaction_manager_status_mut(
+                conn
+            ).transaction_depth().expect("Transaction depth")
+        );
+    }
+
+    #[diesel_test_helper::test]
+    #[cfg(feature = "__sqlite-shared")]
+    fn sqlite_transaction_with_rejects_read_only_transactions() {
+        use crate::connection::{Access, AnsiTransactionManager, Isolation, TransactionManager};
+        use crate::prelude::*;
+        use crate::result::Error;
+
+        let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+        let result = conn.transaction_with(Isolation::Serializable, Access::ReadOnly, |_| Ok(()));
+        assert_eq!(
+            Err(Error::UnsupportedTransactionOptions {
+                isolation: Isolation::Serializable,
+                access: Access::ReadOnly,
+            }),
+            result
+        );
+        assert_eq!(
+            None,
+            <AnsiTransactionManager as TransactionManager<SqliteConnection>>::transaction_manager_status_mut(
+                conn
+            ).transaction_depth().expect("Transaction depth")
+        );
+
+        conn.transaction_with(Isolation::ReadCommitted, Access::ReadWrite, |conn| {
+            crate::sql_query("CREATE TABLE read_write_test (id INTEGER)").execute(conn)
+        })
+        .unwrap();
+    }
 }
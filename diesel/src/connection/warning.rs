@@ -0,0 +1,69 @@
+use alloc::string::String;
+use core::fmt;
+
+/// A warning or notice reported by the database server while
+/// executing a statement
+///
+/// Warnings are returned by
+/// [`Connection::execute_returning_count_with_warnings`](super::Connection::execute_returning_count_with_warnings)
+/// and passed to the connection [`Instrumentation`](super::Instrumentation) as
+/// [`InstrumentationEvent::QueryWarnings`](super::InstrumentationEvent::QueryWarnings).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DatabaseWarning {
+    level: String,
+    code: Option<String>,
+    message: String,
+}
+
+impl DatabaseWarning {
+    /// Create a new warning
+    #[diesel_derives::__diesel_public_if(
+        feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+    )]
+    #[cfg_attr(
+        not(any(
+            feature = "postgres",
+            feature = "mysql",
+            feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+        )),
+        expect(dead_code)
+    )]
+    pub(crate) fn new(level: String, code: Option<String>, message: String) -> Self {
+        Self {
+            level,
+            code,
+            message,
+        }
+    }
+
+    /// The severity of the warning as reported by the database
+    ///
+    /// For PostgreSQL this is the severity of the notice, e.g. `NOTICE`
+    /// or `WARNING`. For MySQL this is the `Level` column of
+    /// `SHOW WARNINGS`, e.g. `Note` or `Warning`.
+    pub fn level(&self) -> &str {
+        &self.level
+    }
+
+    /// The database specific code of the warning, if any
+    ///
+    /// For PostgreSQL this is the SQLSTATE code, for MySQL
+    /// the numeric error code.
+    pub fn code(&self) -> Option<&str> {
+        self.code.as_deref()
+    }
+
+    /// The message of the warning
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for DatabaseWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.code {
+            Some(code) => write!(f, "{} ({}): {}", self.level, code, self.message),
+            None => write!(f, "{}: {}", self.level, self.message),
+        }
+    }
+}
@@ -3,8 +3,37 @@
 //! confusing (such as date and time types). This module will re-export
 //! all backend specific data structures when compiled against that
 //! backend.
+#[cfg(feature = "std")]
+mod binary_stream;
+#[cfg(all(
+    feature = "serde",
+    any(
+        feature = "postgres_backend",
+        feature = "mysql_backend",
+        feature = "__sqlite-shared"
+    )
+))]
+mod json;
+mod numeric_string;
+
+#[cfg(feature = "std")]
+pub use self::binary_stream::BinaryStream;
+#[cfg(all(
+    feature = "serde",
+    any(
+        feature = "postgres_backend",
+        feature = "mysql_backend",
+        feature = "__sqlite-shared"
+    )
+))]
+pub use self::json::Json;
+pub use self::numeric_string::NumericString;
+
 #[cfg(feature = "postgres_backend")]
 pub use crate::pg::data_types::*;
 
 #[cfg(feature = "mysql_backend")]
 pub use crate::mysql::data_types::*;
+
+#[cfg(feature = "__sqlite-shared")]
+pub use crate::sqlite::data_types::*;
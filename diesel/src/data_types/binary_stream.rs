@@ -0,0 +1,112 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::cell::RefCell;
+use std::io::{self, Read};
+
+use crate::backend::Backend;
+use crate::deserialize::{self, FromSql, FromSqlRow};
+use crate::expression::AsExpression;
+use crate::query_builder::bind_collector::RawBytesBindCollector;
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types::Binary;
+
+/// A [`Read`] adapter to send and receive [`Binary`] values
+///
+/// When a `BinaryStream` is serialized, the wrapped reader is copied into
+/// the bind parameter chunk by chunk. Large values (for example files)
+/// can therefore be inserted without reading them into a `Vec<u8>` first.
+/// Together with the connection level streaming APIs, at most one copy of
+/// the value is held in memory:
+///
+/// * MySQL sends large parameters to the server in chunks, instead of
+///   copying them into the packet executing the statement
+/// * SQLite can write into a BLOB incrementally with
+///   `SqliteConnection::get_blob` after inserting a `zeroblob`
+/// * PostgreSQL can stream values stored as large objects with
+///   `PgConnection::open_large_object`
+///
+/// The reader is consumed by serializing the value, so a `BinaryStream`
+/// can only be sent once. Values loaded as `BinaryStream<Cursor<Vec<u8>>>`
+/// implement [`Read`] as well, which allows passing them to APIs expecting
+/// a reader.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// use diesel::data_types::BinaryStream;
+/// use diesel::sql_types::Binary;
+/// use std::io::{Cursor, Read};
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// #     let connection = &mut establish_connection();
+/// let attachment = Cursor::new(vec![0xde, 0xad, 0xbe, 0xef]);
+/// let mut result = diesel::select(BinaryStream::new(attachment).into_sql::<Binary>())
+///     .get_result::<BinaryStream<Cursor<Vec<u8>>>>(connection)?;
+///
+/// let mut bytes = Vec::new();
+/// result.read_to_end(&mut bytes)?;
+/// assert_eq!(vec![0xde, 0xad, 0xbe, 0xef], bytes);
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Binary)]
+pub struct BinaryStream<R>(RefCell<R>);
+
+impl<R> BinaryStream<R> {
+    /// Wraps the given reader
+    pub fn new(reader: R) -> Self {
+        Self(RefCell::new(reader))
+    }
+
+    /// Returns the wrapped reader
+    pub fn into_inner(self) -> R {
+        self.0.into_inner()
+    }
+}
+
+impl<R: Read> Read for BinaryStream<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.0.get_mut().read(buf)
+    }
+}
+
+impl<DB> FromSql<Binary, DB> for BinaryStream<io::Cursor<Vec<u8>>>
+where
+    DB: Backend,
+    Vec<u8>: FromSql<Binary, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        Vec::from_sql(bytes).map(|bytes| Self::new(io::Cursor::new(bytes)))
+    }
+}
+
+impl<R, DB> ToSql<Binary, DB> for BinaryStream<R>
+where
+    R: Read + core::fmt::Debug,
+    for<'a> DB: Backend<BindCollector<'a> = RawBytesBindCollector<DB>>,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        let mut reader = self.0.try_borrow_mut()?;
+        io::copy(&mut *reader, out)
+            .map(|_| IsNull::No)
+            .map_err(|e| Box::new(e) as Box<dyn core::error::Error + Send + Sync>)
+    }
+}
+
+#[cfg(feature = "__sqlite-shared")]
+impl<R> ToSql<Binary, crate::sqlite::Sqlite> for BinaryStream<R>
+where
+    R: Read + core::fmt::Debug,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, crate::sqlite::Sqlite>) -> serialize::Result {
+        // SQLite binds own their values, so the reader is copied into a
+        // single buffer here. Use `SqliteConnection::get_blob` to write
+        // large values incrementally instead.
+        let mut bytes = Vec::new();
+        self.0.try_borrow_mut()?.read_to_end(&mut bytes)?;
+        out.set_value(bytes);
+        Ok(IsNull::No)
+    }
+}
@@ -0,0 +1,86 @@
+use core::ops::{Deref, DerefMut};
+
+use crate::deserialize::FromSqlRow;
+use crate::expression::AsExpression;
+use crate::sql_types;
+
+/// A wrapper to store any serde compatible type in a JSON column
+///
+/// Values of this type are serialized with `serde_json` before they are
+/// sent to the database and deserialized into `T` when they are loaded,
+/// so a custom type can be used directly in `Queryable`, `Selectable` and
+/// `Insertable` structs without going through [`serde_json::Value`].
+///
+/// This type can be used with the [`Json`](sql_types::Json) SQL type on
+/// all backends. On PostgreSQL and SQLite it can additionally be used with
+/// the [`Jsonb`](sql_types::Jsonb) SQL type. SQLite stores `Json` values as
+/// `TEXT`.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// use diesel::data_types::Json;
+/// use diesel::sql_types;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Debug, PartialEq, Serialize, Deserialize)]
+/// struct Address {
+///     street: String,
+///     city: String,
+/// }
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// #     let connection = &mut establish_connection();
+/// let address = Address {
+///     street: "Article Circle Expressway 1".into(),
+///     city: "North Pole".into(),
+/// };
+/// let result = diesel::select(Json(&address).into_sql::<sql_types::Json>())
+///     .get_result::<Json<Address>>(connection)?;
+/// assert_eq!(address, result.into_inner());
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, AsExpression, FromSqlRow,
+)]
+#[diesel(sql_type = sql_types::Json)]
+#[cfg_attr(
+    any(feature = "postgres_backend", feature = "__sqlite-shared"),
+    diesel(sql_type = sql_types::Jsonb)
+)]
+pub struct Json<T>(pub T);
+
+impl<T> Json<T> {
+    /// Returns the wrapped value
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Json<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> Deref for Json<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Json<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> AsRef<T> for Json<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
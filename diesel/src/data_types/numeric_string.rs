@@ -0,0 +1,164 @@
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+
+use crate::deserialize::FromSqlRow;
+use crate::expression::AsExpression;
+use crate::sql_types::Numeric;
+
+/// A lossless, string based representation of a `NUMERIC`/`DECIMAL` value
+///
+/// This type can be used with the [`Numeric`] SQL type on all backends
+/// without enabling the `numeric` feature. Values are transferred as their
+/// decimal text representation, so no binary floating point conversion is
+/// involved. This makes it a good fit for applications that only pass
+/// decimal values through (e.g. to a decimal crate of their choice) and
+/// cannot tolerate rounding errors.
+///
+/// The contained string is validated to be a plain decimal number, that is
+/// an optional sign, followed by digits with an optional decimal point.
+/// Additionally the special value `NaN` is accepted, which is only
+/// supported by PostgreSQL.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// use diesel::data_types::NumericString;
+/// use diesel::sql_types::Numeric;
+///
+/// # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+/// #     let connection = &mut establish_connection();
+/// let value = "12345678901234567890.0123456789".parse::<NumericString>()?;
+/// let result = diesel::select(value.clone().into_sql::<Numeric>())
+///     .get_result::<NumericString>(connection)?;
+/// assert_eq!(value, result);
+/// #     Ok(())
+/// # }
+/// ```
+///
+/// Note that SQLite stores values in columns with `NUMERIC` affinity as
+/// `INTEGER` or `REAL` if they can be converted losslessly (according to
+/// SQLite's own rules), so the returned value might differ in its textual
+/// representation from the inserted one there. Use a column with `TEXT`
+/// affinity if you need to preserve the exact representation on SQLite.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Numeric)]
+pub struct NumericString(String);
+
+impl NumericString {
+    /// Returns the decimal representation of this value
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns `true` if this value represents `NaN`
+    pub fn is_nan(&self) -> bool {
+        self.0.eq_ignore_ascii_case("nan")
+    }
+
+    /// Constructs a new value without checking that the given string
+    /// is a valid decimal number
+    ///
+    /// This is used for values received from the database
+    #[cfg(any(feature = "postgres_backend", feature = "__sqlite-shared"))]
+    pub(crate) fn new_unchecked(value: String) -> Self {
+        Self(value)
+    }
+}
+
+fn validate(value: &str) -> Result<(), Box<dyn Error + Send + Sync>> {
+    if value.eq_ignore_ascii_case("nan") {
+        return Ok(());
+    }
+    let unsigned = value.strip_prefix(['+', '-']).unwrap_or(value);
+    let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+    let is_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if (integer.is_empty() && fraction.is_empty()) || !is_digits(integer) || !is_digits(fraction) {
+        return Err(alloc::format!("`{value}` is not a valid decimal number").into());
+    }
+    Ok(())
+}
+
+impl FromStr for NumericString {
+    type Err = Box<dyn Error + Send + Sync>;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        validate(s)?;
+        Ok(Self(s.to_string()))
+    }
+}
+
+impl TryFrom<String> for NumericString {
+    type Error = Box<dyn Error + Send + Sync>;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        validate(&value)?;
+        Ok(Self(value))
+    }
+}
+
+impl From<NumericString> for String {
+    fn from(value: NumericString) -> Self {
+        value.0
+    }
+}
+
+impl AsRef<str> for NumericString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for NumericString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+macro_rules! from_integer {
+    ($($ty:ty),*) => {
+        $(
+            impl From<$ty> for NumericString {
+                fn from(value: $ty) -> Self {
+                    Self(value.to_string())
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+
+#[cfg(test)]
+mod tests {
+    use super::NumericString;
+
+    #[diesel_test_helper::test]
+    fn parse_valid_numeric_strings() {
+        for value in [
+            "0",
+            "-1",
+            "+1",
+            "1.",
+            ".5",
+            "-0.000001",
+            "123456789012345678901234567890.5",
+            "NaN",
+        ] {
+            let parsed = value.parse::<NumericString>().unwrap();
+            assert_eq!(value, parsed.as_str());
+        }
+    }
+
+    #[diesel_test_helper::test]
+    fn parse_invalid_numeric_strings() {
+        for value in [
+            "", "-", ".", "1e5", "1.2.3", "abc", " 1", "1,5", "--1", "inf",
+        ] {
+            assert!(value.parse::<NumericString>().is_err(), "{value}");
+        }
+    }
+}
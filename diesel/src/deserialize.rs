@@ -297,6 +297,72 @@ where
 #[doc(inline)]
 pub use diesel_derives::Queryable;
 
+/// Converts rows into domain types that are separate from the types
+/// used to load them
+///
+/// Implementing this trait allows to load a domain type via
+/// [`RunQueryDsl::load_into`](crate::RunQueryDsl::load_into). Each row is
+/// loaded as [`QueryableInto::Row`] and converted via the [`TryFrom`]
+/// implementation of the domain type. Conversion errors are returned as
+/// [`Error::DeserializationError`](crate::result::Error::DeserializationError).
+///
+/// # Example
+///
+/// ```rust
+/// # include!("doctest_setup.rs");
+/// # use schema::users;
+/// #
+/// #[derive(Queryable)]
+/// struct UserRow {
+///     id: i32,
+///     name: String,
+/// }
+///
+/// #[derive(Debug, PartialEq)]
+/// struct User {
+///     id: u32,
+///     name: String,
+/// }
+///
+/// impl TryFrom<UserRow> for User {
+///     type Error = core::num::TryFromIntError;
+///
+///     fn try_from(row: UserRow) -> Result<Self, Self::Error> {
+///         Ok(User {
+///             id: row.id.try_into()?,
+///             name: row.name,
+///         })
+///     }
+/// }
+///
+/// impl QueryableInto for User {
+///     type Row = UserRow;
+/// }
+///
+/// # fn main() {
+/// #     run_test();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     let connection = &mut establish_connection();
+/// let users = users::table
+///     .order(users::id)
+///     .load_into::<User>(connection)?;
+/// assert_eq!(
+///     User {
+///         id: 1,
+///         name: "Sean".into()
+///     },
+///     users[0]
+/// );
+/// #     Ok(())
+/// # }
+/// ```
+pub trait QueryableInto: TryFrom<Self::Row, Error: Into<Box<dyn Error + Send + Sync>>> {
+    /// The type each row is loaded as before it is converted
+    type Row;
+}
+
 /// Deserializes the result of a query constructed with [`sql_query`].
 ///
 /// This trait can be [derived](derive@QueryableByName)
@@ -273,8 +273,8 @@ where
     }
 }
 
-impl<ST, Combinator, Rule, Source, Rhs> AsInExpression<ST>
-    for CombinationClause<Combinator, Rule, Source, Rhs>
+impl<ST, Combinator, Rule, Source, Rhs, O, LOf> AsInExpression<ST>
+    for CombinationClause<Combinator, Rule, Source, Rhs, O, LOf>
 where
     ST: SqlType,
     Self: SelectQuery<SqlType = ST>,
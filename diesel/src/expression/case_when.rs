@@ -1,9 +1,11 @@
 use crate::expression::grouped::Grouped;
-use crate::expression::{Expression, helper_types};
+use crate::expression::{Expression, MixedAggregates, ValidGrouping, helper_types};
+use crate::query_builder::{AstPass, QueryFragment};
 use crate::sql_types::{BoolOrNullableBool, SqlType};
-use diesel_derives::{DieselNumericOps, QueryId, ValidGrouping};
+use diesel_derives::{DieselNumericOps, QueryId};
 
 use super::{AsExpression, TypedExpressionType};
+use crate::dsl::AsExprOf;
 
 /// Creates a SQL `CASE WHEN ... END` expression
 ///
@@ -67,6 +69,43 @@ use super::{AsExpression, TypedExpressionType};
 /// assert_eq!(&[(1, Some(1)), (2, Some(2))], users_with_name.as_slice());
 /// # }
 /// ```
+///
+/// # Literal values
+///
+/// If the first `THEN` value is a rust value instead of an expression, its
+/// SQL type cannot be inferred and needs to be specified explicitly. The
+/// following branches and the `ELSE` branch use the same SQL type.
+///
+/// `CASE` expressions can be used everywhere other expressions can be
+/// used, for example in an `ORDER BY` clause or as argument of an
+/// aggregate function:
+///
+/// ```
+/// # include!("../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     use schema::users::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// use diesel::dsl::{case_when, max};
+/// use diesel::sql_types::Integer;
+///
+/// let score = case_when::<_, _, Integer>(name.eq("Tess"), 10)
+///     .when(name.like("S%").and(id.gt(0)), 5)
+///     .otherwise(0);
+///
+/// let ordered_names: Vec<String> = users
+///     .select(name)
+///     .order((score.desc(), id))
+///     .load(connection)
+///     .unwrap();
+/// assert_eq!(vec!["Tess", "Sean"], ordered_names);
+///
+/// let best_score: Option<i32> = users.select(max(score)).get_result(connection).unwrap();
+/// assert_eq!(Some(10), best_score);
+/// # }
+/// ```
+///
+/// To compare a single expression with several values use [`case`] instead.
 pub fn case_when<C, T, ST>(condition: C, if_true: T) -> helper_types::case_when<C, T, ST>
 where
     C: Expression,
@@ -80,14 +119,138 @@ where
             then: Grouped(if_true.as_expression()),
         },
         else_expr: NoElseExpression,
+        operand: NoCaseOperand,
     }
 }
 
-/// A SQL `CASE WHEN ... END` expression
-#[derive(Debug, Clone, Copy, QueryId, DieselNumericOps, ValidGrouping)]
-pub struct CaseWhen<Whens, E> {
+/// Creates a SQL `CASE operand WHEN ... END` expression
+///
+/// Each `WHEN` branch compares the operand with a value of the same SQL
+/// type. The branches are added via [`CaseOperand::when`] and
+/// [`CaseWhen::when`], an `ELSE` branch via [`CaseWhen::otherwise`].
+///
+/// # Example
+///
+/// ```
+/// # include!("../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     use schema::users::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// use diesel::dsl::case;
+/// use diesel::sql_types::Text;
+///
+/// let greetings: Vec<String> = users
+///     .select(
+///         case(name)
+///             .when::<_, _, Text>("Sean", "Hi Sean")
+///             .when("Tess", "Hello Tess")
+///             .otherwise("Who are you?"),
+///     )
+///     .order(id)
+///     .load(connection)
+///     .unwrap();
+///
+/// assert_eq!(vec!["Hi Sean", "Hello Tess"], greetings);
+/// # }
+/// ```
+///
+/// Without an `ELSE` branch the expression returns `NULL`
+/// if no value matches:
+///
+/// ```
+/// # include!("../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     use schema::users::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// use diesel::dsl::case;
+///
+/// let ids: Vec<Option<i32>> = users
+///     .select(case(name).when("Tess", id))
+///     .order(id)
+///     .load(connection)
+///     .unwrap();
+///
+/// assert_eq!(vec![None, Some(2)], ids);
+/// # }
+/// ```
+pub fn case<O>(operand: O) -> helper_types::case<O>
+where
+    O: Expression,
+{
+    CaseOperand {
+        operand: Grouped(operand),
+    }
+}
+
+/// The operand of a SQL `CASE operand WHEN ... END` expression
+///
+/// This type is constructed by [`case`]. It only becomes an expression
+/// once the first branch is added via [`CaseOperand::when`].
+#[derive(Debug, Clone, Copy, QueryId, diesel_derives::ValidGrouping)]
+pub struct CaseOperand<O> {
+    operand: O,
+}
+
+impl<O> CaseOperand<O>
+where
+    O: Expression,
+    O::SqlType: SqlType + TypedExpressionType,
+{
+    /// Add the first `WHEN ... THEN ...` branch to the `CASE` expression
+    ///
+    /// See the [`case`] documentation for more details.
+    pub fn when<V, T, ST>(self, value: V, if_equal: T) -> helper_types::CaseOperandWhen<O, V, T, ST>
+    where
+        V: AsExpression<O::SqlType>,
+        T: AsExpression<ST>,
+        ST: SqlType + TypedExpressionType,
+    {
+        CaseWhen {
+            whens: CaseWhenConditionsLeaf {
+                when: Grouped(value.as_expression()),
+                then: Grouped(if_equal.as_expression()),
+            },
+            else_expr: NoElseExpression,
+            operand: self,
+        }
+    }
+}
+
+impl<O, DB> QueryFragment<DB> for CaseOperand<O>
+where
+    DB: crate::backend::Backend,
+    O: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> crate::QueryResult<()> {
+        out.push_sql(" ");
+        self.operand.walk_ast(out.reborrow())
+    }
+}
+
+/// A SQL `CASE WHEN ... END` or `CASE operand WHEN ... END` expression
+#[derive(Debug, Clone, Copy, QueryId, DieselNumericOps)]
+pub struct CaseWhen<Whens, E, Operand = NoCaseOperand> {
     whens: Whens,
     else_expr: E,
+    operand: Operand,
+}
+
+// `#[derive(ValidGrouping)]` does not support defaults for type parameters
+impl<Whens, E, Operand, GB> ValidGrouping<GB> for CaseWhen<Whens, E, Operand>
+where
+    Whens: ValidGrouping<GB>,
+    E: ValidGrouping<GB>,
+    Operand: ValidGrouping<GB>,
+    Whens::IsAggregate: MixedAggregates<E::IsAggregate>,
+    <Whens::IsAggregate as MixedAggregates<E::IsAggregate>>::Output:
+        MixedAggregates<Operand::IsAggregate>,
+{
+    type IsAggregate =
+        <<Whens::IsAggregate as MixedAggregates<E::IsAggregate>>::Output as MixedAggregates<
+            Operand::IsAggregate,
+        >>::Output;
 }
 
 impl<Whens, E> CaseWhen<Whens, E> {
@@ -96,7 +259,7 @@ impl<Whens, E> CaseWhen<Whens, E> {
     /// See the [`case_when`] documentation for more details.
     pub fn when<C, T>(self, condition: C, if_true: T) -> helper_types::When<Self, C, T>
     where
-        Self: CaseWhenTypesExtractor<Whens = Whens, Else = E>,
+        Self: CaseWhenTypesExtractor<Whens = Whens, Else = E, Operand = NoCaseOperand>,
         C: Expression,
         <C as Expression>::SqlType: BoolOrNullableBool,
         T: AsExpression<<Self as CaseWhenTypesExtractor>::OutputExpressionSpecifiedSqlType>,
@@ -110,19 +273,53 @@ impl<Whens, E> CaseWhen<Whens, E> {
                 },
             },
             else_expr: self.else_expr,
+            operand: self.operand,
         }
     }
 }
 
-impl<Whens> CaseWhen<Whens, NoElseExpression> {
+impl<Whens, E, O> CaseWhen<Whens, E, CaseOperand<O>>
+where
+    O: Expression,
+    O::SqlType: SqlType + TypedExpressionType,
+{
+    /// Add an additional `WHEN ... THEN ...` branch to the `CASE operand`
+    /// expression
+    ///
+    /// See the [`case`] documentation for more details.
+    pub fn when<V, T>(
+        self,
+        value: V,
+        if_equal: T,
+    ) -> helper_types::When<Self, AsExprOf<V, O::SqlType>, T>
+    where
+        Self: CaseWhenTypesExtractor<Whens = Whens, Else = E, Operand = CaseOperand<O>>,
+        V: AsExpression<O::SqlType>,
+        T: AsExpression<<Self as CaseWhenTypesExtractor>::OutputExpressionSpecifiedSqlType>,
+    {
+        CaseWhen {
+            whens: CaseWhenConditionsIntermediateNode {
+                first_whens: self.whens,
+                last_when: CaseWhenConditionsLeaf {
+                    when: Grouped(value.as_expression()),
+                    then: Grouped(if_equal.as_expression()),
+                },
+            },
+            else_expr: self.else_expr,
+            operand: self.operand,
+        }
+    }
+}
+
+impl<Whens, Operand> CaseWhen<Whens, NoElseExpression, Operand> {
     /// Sets the `ELSE` branch of the `CASE` expression
     ///
     /// It is named this way because `else` is a reserved keyword in Rust
     ///
-    /// See the [`case_when`] documentation for more details.
+    /// See the [`case_when`] and [`case`] documentation for more details.
     pub fn otherwise<E>(self, if_no_other_branch_matched: E) -> helper_types::Otherwise<Self, E>
     where
-        Self: CaseWhenTypesExtractor<Whens = Whens, Else = NoElseExpression>,
+        Self: CaseWhenTypesExtractor<Whens = Whens, Else = NoElseExpression, Operand = Operand>,
         E: AsExpression<<Self as CaseWhenTypesExtractor>::OutputExpressionSpecifiedSqlType>,
     {
         CaseWhen {
@@ -130,13 +327,14 @@ impl<Whens> CaseWhen<Whens, NoElseExpression> {
             else_expr: ElseExpression {
                 expr: Grouped(if_no_other_branch_matched.as_expression()),
             },
+            operand: self.operand,
         }
     }
 }
 
 pub(crate) use non_public_types::*;
 mod non_public_types {
-    use super::CaseWhen;
+    use super::{CaseOperand, CaseWhen};
 
     use diesel_derives::{QueryId, ValidGrouping};
 
@@ -179,6 +377,39 @@ mod non_public_types {
         type OutputExpressionSpecifiedSqlType = T::SqlType;
     }
 
+    #[derive(Debug, Clone, Copy, QueryId, ValidGrouping)]
+    pub struct NoCaseOperand;
+
+    /// Checks the `WHEN` conditions of a `CASE` expression
+    ///
+    /// Without an operand each condition is a boolean expression,
+    /// otherwise each condition is a value of the SQL type of the operand.
+    pub trait CaseWhenCondition<W> {}
+    impl<W> CaseWhenCondition<W> for NoCaseOperand
+    where
+        W: Expression,
+        <W as Expression>::SqlType: BoolOrNullableBool,
+    {
+    }
+    impl<O, W> CaseWhenCondition<W> for CaseOperand<O>
+    where
+        O: Expression,
+        W: Expression<SqlType = O::SqlType>,
+    {
+    }
+
+    /// Checks that the operand of a `CASE` expression is valid for the
+    /// query source `QS`
+    pub trait CaseOperandAppearsOnTable<QS> {}
+    impl<QS> CaseOperandAppearsOnTable<QS> for NoCaseOperand {}
+    impl<O, QS> CaseOperandAppearsOnTable<QS> for CaseOperand<O> where O: AppearsOnTable<QS> {}
+
+    /// Checks that the operand of a `CASE` expression is selectable from
+    /// the query source `QS`
+    pub trait CaseOperandSelectableExpression<QS>: CaseOperandAppearsOnTable<QS> {}
+    impl<QS> CaseOperandSelectableExpression<QS> for NoCaseOperand {}
+    impl<O, QS> CaseOperandSelectableExpression<QS> for CaseOperand<O> where O: SelectableExpression<QS> {}
+
     #[derive(Debug, Clone, Copy, QueryId, ValidGrouping)]
     pub struct NoElseExpression;
     #[derive(Debug, Clone, Copy, QueryId, ValidGrouping)]
@@ -198,107 +429,117 @@ mod non_public_types {
         type OutputExpressionSpecifiedSqlType: SqlType + TypedExpressionType;
         type Whens;
         type Else;
+        type Operand;
     }
-    impl<Whens, E> CaseWhenTypesExtractor for CaseWhen<Whens, E>
+    impl<Whens, E, Operand> CaseWhenTypesExtractor for CaseWhen<Whens, E, Operand>
     where
         Whens: CaseWhenConditions,
     {
         type OutputExpressionSpecifiedSqlType = Whens::OutputExpressionSpecifiedSqlType;
         type Whens = Whens;
         type Else = E;
+        type Operand = Operand;
     }
 
-    impl<W, T, QS> SelectableExpression<QS> for CaseWhen<CaseWhenConditionsLeaf<W, T>, NoElseExpression>
+    impl<W, T, Op, QS> SelectableExpression<QS>
+        for CaseWhen<CaseWhenConditionsLeaf<W, T>, NoElseExpression, Op>
     where
-        CaseWhen<CaseWhenConditionsLeaf<W, T>, NoElseExpression>: AppearsOnTable<QS>,
+        CaseWhen<CaseWhenConditionsLeaf<W, T>, NoElseExpression, Op>: AppearsOnTable<QS>,
         W: SelectableExpression<QS>,
         T: SelectableExpression<QS>,
+        Op: CaseOperandSelectableExpression<QS>,
     {
     }
 
-    impl<W, T, E, QS> SelectableExpression<QS>
-        for CaseWhen<CaseWhenConditionsLeaf<W, T>, ElseExpression<E>>
+    impl<W, T, E, Op, QS> SelectableExpression<QS>
+        for CaseWhen<CaseWhenConditionsLeaf<W, T>, ElseExpression<E>, Op>
     where
-        CaseWhen<CaseWhenConditionsLeaf<W, T>, ElseExpression<E>>: AppearsOnTable<QS>,
+        CaseWhen<CaseWhenConditionsLeaf<W, T>, ElseExpression<E>, Op>: AppearsOnTable<QS>,
         W: SelectableExpression<QS>,
         T: SelectableExpression<QS>,
         E: SelectableExpression<QS>,
+        Op: CaseOperandSelectableExpression<QS>,
     {
     }
 
-    impl<W, T, Whens, E, QS> SelectableExpression<QS>
-        for CaseWhen<CaseWhenConditionsIntermediateNode<W, T, Whens>, E>
+    impl<W, T, Whens, E, Op, QS> SelectableExpression<QS>
+        for CaseWhen<CaseWhenConditionsIntermediateNode<W, T, Whens>, E, Op>
     where
         Self: AppearsOnTable<QS>,
         W: SelectableExpression<QS>,
         T: SelectableExpression<QS>,
-        CaseWhen<Whens, E>: SelectableExpression<QS>,
+        CaseWhen<Whens, E, Op>: SelectableExpression<QS>,
     {
     }
 
-    impl<W, T, QS> AppearsOnTable<QS> for CaseWhen<CaseWhenConditionsLeaf<W, T>, NoElseExpression>
+    impl<W, T, Op, QS> AppearsOnTable<QS>
+        for CaseWhen<CaseWhenConditionsLeaf<W, T>, NoElseExpression, Op>
     where
-        CaseWhen<CaseWhenConditionsLeaf<W, T>, NoElseExpression>: Expression,
+        CaseWhen<CaseWhenConditionsLeaf<W, T>, NoElseExpression, Op>: Expression,
         W: AppearsOnTable<QS>,
         T: AppearsOnTable<QS>,
+        Op: CaseOperandAppearsOnTable<QS>,
     {
     }
 
-    impl<W, T, E, QS> AppearsOnTable<QS> for CaseWhen<CaseWhenConditionsLeaf<W, T>, ElseExpression<E>>
+    impl<W, T, E, Op, QS> AppearsOnTable<QS>
+        for CaseWhen<CaseWhenConditionsLeaf<W, T>, ElseExpression<E>, Op>
     where
-        CaseWhen<CaseWhenConditionsLeaf<W, T>, ElseExpression<E>>: Expression,
+        CaseWhen<CaseWhenConditionsLeaf<W, T>, ElseExpression<E>, Op>: Expression,
         W: AppearsOnTable<QS>,
         T: AppearsOnTable<QS>,
         E: AppearsOnTable<QS>,
+        Op: CaseOperandAppearsOnTable<QS>,
     {
     }
 
-    impl<W, T, Whens, E, QS> AppearsOnTable<QS>
-        for CaseWhen<CaseWhenConditionsIntermediateNode<W, T, Whens>, E>
+    impl<W, T, Whens, E, Op, QS> AppearsOnTable<QS>
+        for CaseWhen<CaseWhenConditionsIntermediateNode<W, T, Whens>, E, Op>
     where
         Self: Expression,
         W: AppearsOnTable<QS>,
         T: AppearsOnTable<QS>,
-        CaseWhen<Whens, E>: AppearsOnTable<QS>,
+        CaseWhen<Whens, E, Op>: AppearsOnTable<QS>,
     {
     }
 
-    impl<W, T> Expression for CaseWhen<CaseWhenConditionsLeaf<W, T>, NoElseExpression>
+    impl<W, T, Op> Expression for CaseWhen<CaseWhenConditionsLeaf<W, T>, NoElseExpression, Op>
     where
-        W: Expression,
-        <W as Expression>::SqlType: BoolOrNullableBool,
+        Op: CaseWhenCondition<W>,
         T: Expression,
         <T as Expression>::SqlType: IntoNullable,
         <<T as Expression>::SqlType as IntoNullable>::Nullable: SqlType + TypedExpressionType,
     {
         type SqlType = <<T as Expression>::SqlType as IntoNullable>::Nullable;
     }
-    impl<W, T, E> Expression for CaseWhen<CaseWhenConditionsLeaf<W, T>, ElseExpression<E>>
+    impl<W, T, E, Op> Expression for CaseWhen<CaseWhenConditionsLeaf<W, T>, ElseExpression<E>, Op>
     where
-        W: Expression,
-        <W as Expression>::SqlType: BoolOrNullableBool,
+        Op: CaseWhenCondition<W>,
         T: Expression,
     {
         type SqlType = T::SqlType;
     }
-    impl<W, T, Whens, E> Expression for CaseWhen<CaseWhenConditionsIntermediateNode<W, T, Whens>, E>
+    impl<W, T, Whens, E, Op> Expression
+        for CaseWhen<CaseWhenConditionsIntermediateNode<W, T, Whens>, E, Op>
     where
-        CaseWhen<CaseWhenConditionsLeaf<W, T>, E>: Expression,
-        CaseWhen<Whens, E>: Expression<
-            SqlType = <CaseWhen<CaseWhenConditionsLeaf<W, T>, E> as Expression>::SqlType,
+        CaseWhen<CaseWhenConditionsLeaf<W, T>, E, Op>: Expression,
+        CaseWhen<Whens, E, Op>: Expression<
+            SqlType = <CaseWhen<CaseWhenConditionsLeaf<W, T>, E, Op> as Expression>::SqlType,
         >,
     {
-        type SqlType = <CaseWhen<CaseWhenConditionsLeaf<W, T>, E> as Expression>::SqlType;
+        type SqlType = <CaseWhen<CaseWhenConditionsLeaf<W, T>, E, Op> as Expression>::SqlType;
     }
 
-    impl<Whens, E, DB> QueryFragment<DB> for CaseWhen<Whens, E>
+    impl<Whens, E, Op, DB> QueryFragment<DB> for CaseWhen<Whens, E, Op>
     where
         DB: crate::backend::Backend,
         Whens: QueryFragment<DB>,
         E: QueryFragment<DB>,
+        Op: QueryFragment<DB>,
     {
         fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> crate::QueryResult<()> {
             out.push_sql("CASE");
+            self.operand.walk_ast(out.reborrow())?;
             self.whens.walk_ast(out.reborrow())?;
             self.else_expr.walk_ast(out.reborrow())?;
             out.push_sql(" END");
@@ -335,6 +576,16 @@ mod non_public_types {
         }
     }
 
+    impl<DB> QueryFragment<DB> for NoCaseOperand
+    where
+        DB: crate::backend::Backend,
+    {
+        fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, DB>) -> crate::result::QueryResult<()> {
+            let _ = out;
+            Ok(())
+        }
+    }
+
     impl<DB> QueryFragment<DB> for NoElseExpression
     where
         DB: crate::backend::Backend,
@@ -356,20 +607,69 @@ mod non_public_types {
         }
     }
 
-    impl<S, Conditions, E> aliasing::FieldAliasMapper<S> for CaseWhen<Conditions, E>
+    impl<S, Conditions, E, Op> aliasing::FieldAliasMapper<S> for CaseWhen<Conditions, E, Op>
     where
         S: aliasing::AliasSource,
         Conditions: aliasing::FieldAliasMapper<S>,
         E: aliasing::FieldAliasMapper<S>,
+        Op: aliasing::FieldAliasMapper<S>,
     {
         type Out = CaseWhen<
             <Conditions as aliasing::FieldAliasMapper<S>>::Out,
             <E as aliasing::FieldAliasMapper<S>>::Out,
+            <Op as aliasing::FieldAliasMapper<S>>::Out,
         >;
         fn map(self, alias: &aliasing::Alias<S>) -> Self::Out {
             CaseWhen {
                 whens: self.whens.map(alias),
                 else_expr: self.else_expr.map(alias),
+                operand: self.operand.map(alias),
+            }
+        }
+    }
+
+    impl<S> aliasing::FieldAliasMapper<S> for NoCaseOperand
+    where
+        S: aliasing::AliasSource,
+    {
+        type Out = NoCaseOperand;
+        fn map(self, _alias: &aliasing::Alias<S>) -> Self::Out {
+            self
+        }
+    }
+
+    impl<S, O> aliasing::FieldAliasMapper<S> for CaseOperand<O>
+    where
+        S: aliasing::AliasSource,
+        O: aliasing::FieldAliasMapper<S>,
+    {
+        type Out = CaseOperand<<O as aliasing::FieldAliasMapper<S>>::Out>;
+        fn map(self, alias: &aliasing::Alias<S>) -> Self::Out {
+            CaseOperand {
+                operand: self.operand.map(alias),
+            }
+        }
+    }
+
+    impl<S> aliasing::FieldAliasMapper<S> for NoElseExpression
+    where
+        S: aliasing::AliasSource,
+    {
+        type Out = NoElseExpression;
+        fn map(self, _alias: &aliasing::Alias<S>) -> Self::Out {
+            self
+        }
+    }
+
+    impl<S, E> aliasing::FieldAliasMapper<S> for ElseExpression<E>
+    where
+        S: aliasing::AliasSource,
+        E: aliasing::FieldAliasMapper<S>,
+    {
+        type Out = ElseExpression<<E as aliasing::FieldAliasMapper<S>>::Out>;
+        fn map(self, alias: &aliasing::Alias<S>) -> Self::Out {
+            ElseExpression {
+                expr: self.expr.map(alias),
             }
         }
     }
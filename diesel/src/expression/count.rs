@@ -125,6 +125,20 @@ impl<DB: Backend> FunctionFragment<DB> for CountStar {
         out.push_sql("*");
         Ok(())
     }
+
+    fn walk_filtered_arguments<'b, F>(
+        &'b self,
+        filter: &'b F,
+        mut out: AstPass<'_, 'b, DB>,
+    ) -> QueryResult<()>
+    where
+        F: QueryFragment<DB>,
+    {
+        out.push_sql("CASE WHEN ");
+        filter.walk_ast(out.reborrow())?;
+        out.push_sql(" THEN 1 END");
+        Ok(())
+    }
 }
 
 impl<DB: Backend> QueryFragment<DB> for CountStar {
@@ -30,6 +30,27 @@ use crate::sql_types::Bool;
 /// assert_eq!(Ok(false), jim_exists);
 /// # }
 /// ```
+///
+/// A subquery referencing the outer table:
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     use schema::{posts, users};
+/// #     use diesel::dsl::exists;
+/// #     let connection = &mut establish_connection();
+/// let users_with_posts_about_rust = users::table
+///     .select(users::name)
+///     .filter(exists(
+///         posts::table
+///             .filter(posts::user_id.eq(users::id))
+///             .filter(posts::title.like("%Rust%")),
+///     ))
+///     .load::<String>(connection);
+/// assert_eq!(Ok(vec!["Sean".to_string()]), users_with_posts_about_rust);
+/// # }
+/// ```
 pub fn exists<T>(query: T) -> helper_types::exists<T> {
     Exists {
         subselect: Subselect::new(query),
@@ -30,7 +30,7 @@ mod over_clause;
 mod partition_by;
 mod prefix;
 
-use self::aggregate_filter::{FilterDsl, NoFilter};
+use self::aggregate_filter::{FilterArguments, FilterDsl, NoFilter};
 pub use self::aggregate_order::Order;
 use self::aggregate_order::{NoOrder, OrderAggregateDsl, OrderWindowDsl};
 use self::frame_clause::{FrameDsl, NoFrame};
@@ -61,14 +61,15 @@ where
     Fn: FunctionFragment<DB>,
     Prefix: QueryFragment<DB>,
     Order: QueryFragment<DB>,
-    Filter: QueryFragment<DB>,
+    Filter: QueryFragment<DB> + FilterArguments<Fn, DB>,
     Window: QueryFragment<DB> + WindowFunctionFragment<Fn, DB>,
 {
     fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
         pass.push_sql(Fn::FUNCTION_NAME);
         pass.push_sql("(");
         self.prefix.walk_ast(pass.reborrow())?;
-        self.function.walk_arguments(pass.reborrow())?;
+        self.filter
+            .walk_arguments(&self.function, pass.reborrow())?;
         self.order.walk_ast(pass.reborrow())?;
         pass.push_sql(")");
         self.filter.walk_ast(pass.reborrow())?;
@@ -156,6 +157,28 @@ pub trait FunctionFragment<DB: Backend> {
 
     /// Walk the function argument part (everything between ())
     fn walk_arguments<'b>(&'b self, pass: AstPass<'_, 'b, DB>) -> QueryResult<()>;
+
+    /// Walk the function argument part, only passing the arguments
+    /// for rows matching the given filter
+    ///
+    /// This is used to emulate aggregate `FILTER (WHERE …)` clauses
+    /// via `CASE WHEN filter THEN argument END` on backends that do
+    /// not support them.
+    fn walk_filtered_arguments<'b, F>(
+        &'b self,
+        filter: &'b F,
+        mut pass: AstPass<'_, 'b, DB>,
+    ) -> QueryResult<()>
+    where
+        F: QueryFragment<DB>,
+    {
+        pass.push_sql("CASE WHEN ");
+        filter.walk_ast(pass.reborrow())?;
+        pass.push_sql(" THEN ");
+        self.walk_arguments(pass.reborrow())?;
+        pass.push_sql(" END");
+        Ok(())
+    }
 }
 
 /// Expression methods to build aggregate function expressions
@@ -240,17 +263,23 @@ pub trait AggregateExpressionMethods: Sized {
     /// call to use only items matching the provided
     /// filter
     ///
+    /// This generates a `FILTER (WHERE …)` clause on PostgreSQL
+    /// and SQLite. MySQL does not support this clause, so the filter
+    /// is emulated there by passing `CASE WHEN filter THEN argument END`
+    /// to the aggregate function instead.
+    ///
+    /// [`count_star()`](crate::dsl::count_star()) accepts a filter as well,
+    /// which makes it easy to write conditional counts.
+    ///
     /// # Example
     ///
     /// ```rust
     /// # include!("../../doctest_setup.rs");
     /// #
     /// # fn main() {
-    /// #     #[cfg(not(feature = "mysql"))]
     /// #     run_test().unwrap();
     /// # }
     /// #
-    /// # #[cfg(not(feature = "mysql"))]
     /// # fn run_test() -> QueryResult<()> {
     /// #     use schema::posts::dsl::*;
     /// #     use diesel::dsl;
@@ -262,8 +291,13 @@ pub trait AggregateExpressionMethods: Sized {
     ///     .select(dsl::count(user_id).aggregate_filter(title.like("%first post%")))
     ///     .get_result::<i64>(connection)?;
     ///
+    /// let first_posts = posts
+    ///     .select(dsl::count_star().aggregate_filter(title.like("%first post%")))
+    ///     .get_result::<i64>(connection)?;
+    ///
     /// assert_eq!(3, without_filter);
     /// assert_eq!(2, with_filter);
+    /// assert_eq!(2, first_posts);
     /// #     Ok(())
     /// # }
     /// ```
@@ -1,4 +1,5 @@
 use super::AggregateExpression;
+use super::FunctionFragment;
 use super::IsAggregateFunction;
 use super::NoWindow;
 use super::aggregate_order::NoOrder;
@@ -10,7 +11,8 @@ use crate::backend::{Backend, SqlDialect, sql_dialect};
 use crate::query_builder::QueryFragment;
 use crate::query_builder::where_clause::NoWhereClause;
 use crate::query_builder::where_clause::WhereAnd;
-use crate::query_builder::{AstPass, QueryId};
+use crate::query_builder::where_clause::WhereClause;
+use crate::query_builder::{AstPass, NotSpecialized, QueryId};
 use crate::sql_types::BoolOrNullableBool;
 
 empty_clause!(NoFilter);
@@ -45,6 +47,104 @@ where
     }
 }
 
+impl<P, DB>
+    QueryFragment<DB, sql_dialect::aggregate_function_expressions::NoAggregateFunctionExpressions>
+    for Filter<P>
+where
+    DB: Backend
+        + SqlDialect<
+            AggregateFunctionExpressions = sql_dialect::aggregate_function_expressions::NoAggregateFunctionExpressions,
+        >,
+{
+    // the filter is applied to the function arguments instead,
+    // see `FilterArguments`
+    fn walk_ast<'b>(&'b self, _pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+/// Walks the arguments of an aggregate function call
+///
+/// Backends without support for `FILTER (WHERE …)` clauses
+/// emulate the filter by wrapping the arguments into
+/// `CASE WHEN … THEN … END` expressions.
+pub trait FilterArguments<Fn, DB: Backend, SP = NotSpecialized> {
+    fn walk_arguments<'b>(&'b self, function: &'b Fn, pass: AstPass<'_, 'b, DB>)
+    -> QueryResult<()>;
+}
+
+impl<Fn, DB> FilterArguments<Fn, DB> for NoFilter
+where
+    Fn: FunctionFragment<DB>,
+    DB: Backend,
+{
+    fn walk_arguments<'b>(
+        &'b self,
+        function: &'b Fn,
+        pass: AstPass<'_, 'b, DB>,
+    ) -> QueryResult<()> {
+        function.walk_arguments(pass)
+    }
+}
+
+impl<Fn, P, DB> FilterArguments<Fn, DB> for Filter<P>
+where
+    Self: FilterArguments<Fn, DB, DB::AggregateFunctionExpressions>,
+    DB: Backend,
+{
+    fn walk_arguments<'b>(
+        &'b self,
+        function: &'b Fn,
+        pass: AstPass<'_, 'b, DB>,
+    ) -> QueryResult<()> {
+        <Self as FilterArguments<Fn, DB, DB::AggregateFunctionExpressions>>::walk_arguments(
+            self, function, pass,
+        )
+    }
+}
+
+impl<Fn, P, DB>
+    FilterArguments<
+        Fn,
+        DB,
+        sql_dialect::aggregate_function_expressions::PostgresLikeAggregateFunctionExpressions,
+    > for Filter<P>
+where
+    Fn: FunctionFragment<DB>,
+    DB: Backend + SqlDialect<AggregateFunctionExpressions = sql_dialect::aggregate_function_expressions::PostgresLikeAggregateFunctionExpressions>,
+{
+    fn walk_arguments<'b>(
+        &'b self,
+        function: &'b Fn,
+        pass: AstPass<'_, 'b, DB>,
+    ) -> QueryResult<()> {
+        function.walk_arguments(pass)
+    }
+}
+
+impl<Fn, P, DB>
+    FilterArguments<
+        Fn,
+        DB,
+        sql_dialect::aggregate_function_expressions::NoAggregateFunctionExpressions,
+    > for Filter<WhereClause<P>>
+where
+    Fn: FunctionFragment<DB>,
+    P: QueryFragment<DB>,
+    DB: Backend
+        + SqlDialect<
+            AggregateFunctionExpressions = sql_dialect::aggregate_function_expressions::NoAggregateFunctionExpressions,
+        >,
+{
+    fn walk_arguments<'b>(
+        &'b self,
+        function: &'b Fn,
+        pass: AstPass<'_, 'b, DB>,
+    ) -> QueryResult<()> {
+        function.walk_filtered_arguments(self.0.predicate(), pass)
+    }
+}
+
 pub trait FilterDsl<P> {
     type Output;
 
@@ -71,6 +171,30 @@ where
     }
 }
 
+// `COUNT(*)` only accepts a filter, not any other aggregate expression modifier
+impl<P, ST> FilterDsl<P> for crate::expression::count::CountStar
+where
+    P: Expression<SqlType = ST>,
+    ST: BoolOrNullableBool,
+{
+    type Output = AggregateExpression<
+        Self,
+        NoPrefix,
+        NoOrder,
+        Filter<<NoWhereClause as WhereAnd<P>>::Output>,
+    >;
+
+    fn filter(self, f: P) -> Self::Output {
+        AggregateExpression {
+            prefix: NoPrefix,
+            function: self,
+            order: NoOrder,
+            filter: Filter(NoWhereClause.and(f)),
+            window: NoWindow,
+        }
+    }
+}
+
 impl<Fn, P, Prefix, Order, F, Window, ST> FilterDsl<P>
     for AggregateExpression<Fn, Prefix, Order, Filter<F>, Window>
 where
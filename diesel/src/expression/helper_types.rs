@@ -3,6 +3,7 @@
 //! a boxed trait object, these can be useful for writing concise return types.
 use super::array_comparison::{AsInExpression, In, NotIn};
 use super::grouped::Grouped;
+use super::quantified_subselect::{AllSubselect, AnySubselect};
 use super::select_by::SelectBy;
 use super::{AsExpression, Expression};
 use crate::expression;
@@ -74,6 +75,98 @@ pub type LtEq<Lhs, Rhs> = Grouped<super::operators::LtEq<Lhs, AsExpr<Rhs, Lhs>>>
 #[doc(hidden)] // required for `#[auto_type]`
 pub type Le<Lhs, Rhs> = LtEq<Lhs, Rhs>;
 
+/// The return type of
+/// [`lhs.gt_any(rhs)`](crate::expression_methods::ExpressionMethods::gt_any())
+pub type GtAny<Lhs, Rhs> = Grouped<super::operators::Gt<Lhs, AnySubselect<Rhs, SqlTypeOf<Lhs>>>>;
+
+/// The return type of
+/// [`lhs.gt_all(rhs)`](crate::expression_methods::ExpressionMethods::gt_all())
+pub type GtAll<Lhs, Rhs> = Grouped<super::operators::Gt<Lhs, AllSubselect<Rhs, SqlTypeOf<Lhs>>>>;
+
+/// The return type of
+/// [`lhs.ge_any(rhs)`](crate::expression_methods::ExpressionMethods::ge_any())
+pub type GtEqAny<Lhs, Rhs> =
+    Grouped<super::operators::GtEq<Lhs, AnySubselect<Rhs, SqlTypeOf<Lhs>>>>;
+
+#[doc(hidden)] // required for `#[auto_type]`
+pub type GeAny<Lhs, Rhs> = GtEqAny<Lhs, Rhs>;
+
+/// The return type of
+/// [`lhs.ge_all(rhs)`](crate::expression_methods::ExpressionMethods::ge_all())
+pub type GtEqAll<Lhs, Rhs> =
+    Grouped<super::operators::GtEq<Lhs, AllSubselect<Rhs, SqlTypeOf<Lhs>>>>;
+
+#[doc(hidden)] // required for `#[auto_type]`
+pub type GeAll<Lhs, Rhs> = GtEqAll<Lhs, Rhs>;
+
+/// The return type of
+/// [`lhs.lt_any(rhs)`](crate::expression_methods::ExpressionMethods::lt_any())
+pub type LtAny<Lhs, Rhs> = Grouped<super::operators::Lt<Lhs, AnySubselect<Rhs, SqlTypeOf<Lhs>>>>;
+
+/// The return type of
+/// [`lhs.lt_all(rhs)`](crate::expression_methods::ExpressionMethods::lt_all())
+pub type LtAll<Lhs, Rhs> = Grouped<super::operators::Lt<Lhs, AllSubselect<Rhs, SqlTypeOf<Lhs>>>>;
+
+/// The return type of
+/// [`lhs.le_any(rhs)`](crate::expression_methods::ExpressionMethods::le_any())
+pub type LtEqAny<Lhs, Rhs> =
+    Grouped<super::operators::LtEq<Lhs, AnySubselect<Rhs, SqlTypeOf<Lhs>>>>;
+
+#[doc(hidden)] // required for `#[auto_type]`
+pub type LeAny<Lhs, Rhs> = LtEqAny<Lhs, Rhs>;
+
+/// The return type of
+/// [`lhs.le_all(rhs)`](crate::expression_methods::ExpressionMethods::le_all())
+pub type LtEqAll<Lhs, Rhs> =
+    Grouped<super::operators::LtEq<Lhs, AllSubselect<Rhs, SqlTypeOf<Lhs>>>>;
+
+#[doc(hidden)] // required for `#[auto_type]`
+pub type LeAll<Lhs, Rhs> = LtEqAll<Lhs, Rhs>;
+
+/// The type of `Item` when converted to a row value with the same type as `TargetExpr`
+pub type AsRowValueExpr<Item, TargetExpr> = super::row_value::RowValue<
+    <Item as super::row_value::AsRowValue<SqlTypeOf<TargetExpr>>>::Expression,
+>;
+
+/// The return type of
+/// [`lhs.row_eq(rhs)`](crate::expression_methods::RowValueExpressionMethods::row_eq())
+pub type RowEq<Lhs, Rhs> =
+    Grouped<super::operators::Eq<AsRowValueExpr<Lhs, Lhs>, AsRowValueExpr<Rhs, Lhs>>>;
+
+/// The return type of
+/// [`lhs.row_ne(rhs)`](crate::expression_methods::RowValueExpressionMethods::row_ne())
+pub type RowNe<Lhs, Rhs> =
+    Grouped<super::operators::NotEq<AsRowValueExpr<Lhs, Lhs>, AsRowValueExpr<Rhs, Lhs>>>;
+
+/// The return type of
+/// [`lhs.row_gt(rhs)`](crate::expression_methods::RowValueExpressionMethods::row_gt())
+pub type RowGt<Lhs, Rhs> =
+    Grouped<super::operators::Gt<AsRowValueExpr<Lhs, Lhs>, AsRowValueExpr<Rhs, Lhs>>>;
+
+/// The return type of
+/// [`lhs.row_ge(rhs)`](crate::expression_methods::RowValueExpressionMethods::row_ge())
+pub type RowGe<Lhs, Rhs> =
+    Grouped<super::operators::GtEq<AsRowValueExpr<Lhs, Lhs>, AsRowValueExpr<Rhs, Lhs>>>;
+
+/// The return type of
+/// [`lhs.row_lt(rhs)`](crate::expression_methods::RowValueExpressionMethods::row_lt())
+pub type RowLt<Lhs, Rhs> =
+    Grouped<super::operators::Lt<AsRowValueExpr<Lhs, Lhs>, AsRowValueExpr<Rhs, Lhs>>>;
+
+/// The return type of
+/// [`lhs.row_le(rhs)`](crate::expression_methods::RowValueExpressionMethods::row_le())
+pub type RowLe<Lhs, Rhs> =
+    Grouped<super::operators::LtEq<AsRowValueExpr<Lhs, Lhs>, AsRowValueExpr<Rhs, Lhs>>>;
+
+/// The return type of
+/// [`lhs.row_eq_any(rhs)`](crate::expression_methods::RowValueExpressionMethods::row_eq_any())
+pub type RowEqAny<Lhs, Rhs> = Grouped<
+    super::row_value::RowValueIn<
+        <Lhs as super::row_value::AsRowValue<SqlTypeOf<Lhs>>>::Expression,
+        <<Rhs as IntoIterator>::Item as super::row_value::AsRowValue<SqlTypeOf<Lhs>>>::Expression,
+    >,
+>;
+
 /// The return type of
 /// [`lhs.between(lower, upper)`](crate::expression_methods::ExpressionMethods::between())
 pub type Between<Lhs, Lower, Upper> = Grouped<
@@ -140,13 +233,39 @@ pub type Like<Lhs, Rhs> = Grouped<super::operators::Like<Lhs, AsExprOf<Rhs, SqlT
 /// [`lhs.not_like(rhs)`](crate::expression_methods::TextExpressionMethods::not_like())
 pub type NotLike<Lhs, Rhs> = Grouped<super::operators::NotLike<Lhs, AsExprOf<Rhs, SqlTypeOf<Lhs>>>>;
 
+/// The return type of
+/// [`lhs.eq_ignore_case(rhs)`](crate::expression_methods::TextExpressionMethods::eq_ignore_case())
+pub type EqIgnoreCase<Lhs, Rhs> =
+    Grouped<super::operators::EqIgnoreCase<Lhs, AsExprOf<Rhs, SqlTypeOf<Lhs>>>>;
+
+/// The return type of
+/// [`lhs.like_ignore_case(rhs)`](crate::expression_methods::TextExpressionMethods::like_ignore_case())
+pub type LikeIgnoreCase<Lhs, Rhs> =
+    Grouped<super::operators::LikeIgnoreCase<Lhs, AsExprOf<Rhs, SqlTypeOf<Lhs>>>>;
+
 /// The return type of [`case_when()`](expression::case_when::case_when)
 #[allow(non_camel_case_types)] // required for `#[auto_type]`
 pub type case_when<C, T, ST = <T as Expression>::SqlType> = expression::case_when::CaseWhen<
     expression::case_when::CaseWhenConditionsLeaf<Grouped<C>, Grouped<AsExprOf<T, ST>>>,
     expression::case_when::NoElseExpression,
 >;
+/// The return type of [`case(operand)`](expression::case_when::case)
+#[allow(non_camel_case_types)] // required for `#[auto_type]`
+pub type case<O> = expression::case_when::CaseOperand<Grouped<O>>;
+/// The return type of [`case(operand).when(...)`](expression::case_when::CaseOperand::when)
+pub type CaseOperandWhen<O, V, T, ST = <T as Expression>::SqlType> =
+    expression::case_when::CaseWhen<
+        expression::case_when::CaseWhenConditionsLeaf<
+            Grouped<AsExprOf<V, SqlTypeOf<O>>>,
+            Grouped<AsExprOf<T, ST>>,
+        >,
+        expression::case_when::NoElseExpression,
+        expression::case_when::CaseOperand<O>,
+    >;
 /// The return type of [`case_when(...).when(...)`](expression::CaseWhen::when)
+///
+/// For a `CASE operand` expression `C` is the type of the compared value
+/// after converting it to an expression of the SQL type of the operand.
 pub type When<W, C, T> = expression::case_when::CaseWhen<
     expression::case_when::CaseWhenConditionsIntermediateNode<
         Grouped<C>,
@@ -154,11 +273,13 @@ pub type When<W, C, T> = expression::case_when::CaseWhen<
         <W as expression::case_when::CaseWhenTypesExtractor>::Whens,
     >,
     <W as expression::case_when::CaseWhenTypesExtractor>::Else,
+    <W as expression::case_when::CaseWhenTypesExtractor>::Operand,
 >;
 /// The return type of [`case_when(...).otherwise(...)`](expression::case_when::CaseWhen::otherwise)
 pub type Otherwise<W, E> = expression::case_when::CaseWhen<
     <W as expression::case_when::CaseWhenTypesExtractor>::Whens,
     expression::case_when::ElseExpression<Grouped<AsExprOf<E, <W as expression::case_when::CaseWhenTypesExtractor>::OutputExpressionSpecifiedSqlType>>>,
+    <W as expression::case_when::CaseWhenTypesExtractor>::Operand,
 >;
 
 /// Represents the return type of [`.as_select()`](crate::prelude::SelectableHelper::as_select)
@@ -219,6 +340,11 @@ pub use crate::sqlite::expression::helper_types::*;
 #[allow(unreachable_pub)]
 pub use crate::sqlite::expression::helper_types::*;
 
+#[doc(inline)]
+#[cfg(feature = "mysql_backend")]
+#[allow(unreachable_pub)]
+pub use crate::mysql::expression::helper_types::*;
+
 /// The return type of [`lhs.retrieve_as_text(rhs)`](crate::expression_methods::AnyJsonExpressionMethods::retrieve_as_text)
 #[cfg(any(feature = "postgres_backend", feature = "__sqlite-shared"))]
 pub type RetrieveAsText<Lhs, Rhs> = Grouped<
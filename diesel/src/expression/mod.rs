@@ -38,6 +38,8 @@ pub(crate) mod nullable;
 pub(crate) mod operators;
 mod case_when;
 pub(crate) mod cast;
+pub(crate) mod quantified_subselect;
+pub mod row_value;
 pub(crate) mod select_by;
 mod sql_literal;
 pub(crate) mod subselect;
@@ -56,7 +58,7 @@ pub(crate) mod dsl {
     use crate::dsl::SqlTypeOf;
 
     #[doc(inline)]
-    pub use super::case_when::case_when;
+    pub use super::case_when::{case, case_when};
     #[doc(inline)]
     pub use super::count::*;
     #[doc(inline)]
@@ -81,6 +83,9 @@ pub(crate) mod dsl {
     #[cfg(feature = "__sqlite-shared")]
     pub use crate::sqlite::expression::dsl::*;
 
+    #[cfg(feature = "mysql_backend")]
+    pub use crate::mysql::expression::dsl::*;
+
     /// The return type of [`count(expr)`](crate::dsl::count())
     pub type count<Expr> = super::count::count<SqlTypeOf<Expr>, Expr>;
 
@@ -112,7 +117,7 @@ pub(crate) mod dsl {
 }
 
 #[doc(inline)]
-pub use self::case_when::CaseWhen;
+pub use self::case_when::{CaseOperand, CaseWhen};
 #[doc(inline)]
 pub use self::cast::{CastsTo, FallibleCastsTo, KnownCastSqlTypeName};
 #[doc(inline)]
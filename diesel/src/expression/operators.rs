@@ -940,3 +940,228 @@ where
         }
     }
 }
+
+// need an explicit impl here as this operator wraps both sides
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    crate::query_builder::QueryId,
+    crate::sql_types::DieselNumericOps,
+    crate::expression::ValidGrouping,
+)]
+#[doc(hidden)]
+pub struct EqIgnoreCase<T, U> {
+    pub(crate) left: T,
+    pub(crate) right: U,
+}
+
+impl<T, U> EqIgnoreCase<T, U> {
+    pub(crate) fn new(left: T, right: U) -> Self {
+        EqIgnoreCase { left, right }
+    }
+}
+
+impl<T, U, QS> crate::expression::SelectableExpression<QS> for EqIgnoreCase<T, U>
+where
+    EqIgnoreCase<T, U>: crate::expression::AppearsOnTable<QS>,
+    T: crate::expression::SelectableExpression<QS>,
+    U: crate::expression::SelectableExpression<QS>,
+{
+}
+
+impl<T, U, QS> crate::expression::AppearsOnTable<QS> for EqIgnoreCase<T, U>
+where
+    EqIgnoreCase<T, U>: crate::expression::Expression,
+    T: crate::expression::AppearsOnTable<QS>,
+    U: crate::expression::AppearsOnTable<QS>,
+{
+}
+
+impl<T, U> crate::expression::Expression for EqIgnoreCase<T, U>
+where
+    T: crate::expression::Expression,
+    U: crate::expression::Expression,
+    <T as crate::expression::Expression>::SqlType: crate::sql_types::SqlType,
+    <U as crate::expression::Expression>::SqlType: crate::sql_types::SqlType,
+    crate::sql_types::is_nullable::IsSqlTypeNullable<<T as crate::expression::Expression>::SqlType>:
+        crate::sql_types::OneIsNullable<
+                crate::sql_types::is_nullable::IsSqlTypeNullable<
+                    <U as crate::expression::Expression>::SqlType,
+                >,
+            >,
+    crate::sql_types::is_nullable::IsOneNullable<
+        <T as crate::expression::Expression>::SqlType,
+        <U as crate::expression::Expression>::SqlType,
+    >: crate::sql_types::MaybeNullableType<crate::sql_types::Bool>,
+{
+    type SqlType = crate::sql_types::is_nullable::MaybeNullable<
+        crate::sql_types::is_nullable::IsOneNullable<
+            <T as crate::expression::Expression>::SqlType,
+            <U as crate::expression::Expression>::SqlType,
+        >,
+        crate::sql_types::Bool,
+    >;
+}
+
+impl<T, U, DB> crate::query_builder::QueryFragment<DB> for EqIgnoreCase<T, U>
+where
+    T: crate::query_builder::QueryFragment<DB>,
+    U: crate::query_builder::QueryFragment<DB>,
+    DB: crate::backend::Backend,
+{
+    fn walk_ast<'b>(
+        &'b self,
+        mut out: crate::query_builder::AstPass<'_, 'b, DB>,
+    ) -> crate::result::QueryResult<()> {
+        out.push_sql("LOWER(");
+        self.left.walk_ast(out.reborrow())?;
+        out.push_sql(") = LOWER(");
+        self.right.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<S, T, U> crate::internal::operators_macro::FieldAliasMapper<S> for EqIgnoreCase<T, U>
+where
+    S: crate::query_source::AliasSource,
+    T: crate::internal::operators_macro::FieldAliasMapper<S>,
+    U: crate::internal::operators_macro::FieldAliasMapper<S>,
+{
+    type Out = EqIgnoreCase<
+        <T as crate::internal::operators_macro::FieldAliasMapper<S>>::Out,
+        <U as crate::internal::operators_macro::FieldAliasMapper<S>>::Out,
+    >;
+    fn map(self, alias: &crate::query_source::Alias<S>) -> Self::Out {
+        EqIgnoreCase {
+            left: self.left.map(alias),
+            right: self.right.map(alias),
+        }
+    }
+}
+
+// need an explicit impl here as the generated SQL depends on the backend
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    crate::query_builder::QueryId,
+    crate::sql_types::DieselNumericOps,
+    crate::expression::ValidGrouping,
+)]
+#[doc(hidden)]
+pub struct LikeIgnoreCase<T, U> {
+    pub(crate) left: T,
+    pub(crate) right: U,
+}
+
+impl<T, U> LikeIgnoreCase<T, U> {
+    pub(crate) fn new(left: T, right: U) -> Self {
+        LikeIgnoreCase { left, right }
+    }
+
+    #[cfg(any(feature = "sqlite", feature = "mysql_backend"))]
+    fn walk_lowered<'b, DB>(
+        &'b self,
+        mut out: crate::query_builder::AstPass<'_, 'b, DB>,
+    ) -> crate::result::QueryResult<()>
+    where
+        DB: crate::backend::Backend,
+        T: crate::query_builder::QueryFragment<DB>,
+        U: crate::query_builder::QueryFragment<DB>,
+    {
+        out.push_sql("LOWER(");
+        self.left.walk_ast(out.reborrow())?;
+        out.push_sql(") LIKE LOWER(");
+        self.right.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<T, U, QS> crate::expression::SelectableExpression<QS> for LikeIgnoreCase<T, U>
+where
+    LikeIgnoreCase<T, U>: crate::expression::AppearsOnTable<QS>,
+    T: crate::expression::SelectableExpression<QS>,
+    U: crate::expression::SelectableExpression<QS>,
+{
+}
+
+impl<T, U, QS> crate::expression::AppearsOnTable<QS> for LikeIgnoreCase<T, U>
+where
+    LikeIgnoreCase<T, U>: crate::expression::Expression,
+    T: crate::expression::AppearsOnTable<QS>,
+    U: crate::expression::AppearsOnTable<QS>,
+{
+}
+
+impl<T, U> crate::expression::Expression for LikeIgnoreCase<T, U>
+where
+    EqIgnoreCase<T, U>: crate::expression::Expression,
+{
+    type SqlType = <EqIgnoreCase<T, U> as crate::expression::Expression>::SqlType;
+}
+
+#[cfg(feature = "postgres_backend")]
+impl<T, U> crate::query_builder::QueryFragment<crate::pg::Pg> for LikeIgnoreCase<T, U>
+where
+    T: crate::query_builder::QueryFragment<crate::pg::Pg>,
+    U: crate::query_builder::QueryFragment<crate::pg::Pg>,
+{
+    fn walk_ast<'b>(
+        &'b self,
+        mut out: crate::query_builder::AstPass<'_, 'b, crate::pg::Pg>,
+    ) -> crate::result::QueryResult<()> {
+        self.left.walk_ast(out.reborrow())?;
+        out.push_sql(" ILIKE ");
+        self.right.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl<T, U> crate::query_builder::QueryFragment<crate::sqlite::Sqlite> for LikeIgnoreCase<T, U>
+where
+    T: crate::query_builder::QueryFragment<crate::sqlite::Sqlite>,
+    U: crate::query_builder::QueryFragment<crate::sqlite::Sqlite>,
+{
+    fn walk_ast<'b>(
+        &'b self,
+        out: crate::query_builder::AstPass<'_, 'b, crate::sqlite::Sqlite>,
+    ) -> crate::result::QueryResult<()> {
+        self.walk_lowered(out)
+    }
+}
+
+#[cfg(feature = "mysql_backend")]
+impl<T, U> crate::query_builder::QueryFragment<crate::mysql::Mysql> for LikeIgnoreCase<T, U>
+where
+    T: crate::query_builder::QueryFragment<crate::mysql::Mysql>,
+    U: crate::query_builder::QueryFragment<crate::mysql::Mysql>,
+{
+    fn walk_ast<'b>(
+        &'b self,
+        out: crate::query_builder::AstPass<'_, 'b, crate::mysql::Mysql>,
+    ) -> crate::result::QueryResult<()> {
+        self.walk_lowered(out)
+    }
+}
+
+impl<S, T, U> crate::internal::operators_macro::FieldAliasMapper<S> for LikeIgnoreCase<T, U>
+where
+    S: crate::query_source::AliasSource,
+    T: crate::internal::operators_macro::FieldAliasMapper<S>,
+    U: crate::internal::operators_macro::FieldAliasMapper<S>,
+{
+    type Out = LikeIgnoreCase<
+        <T as crate::internal::operators_macro::FieldAliasMapper<S>>::Out,
+        <U as crate::internal::operators_macro::FieldAliasMapper<S>>::Out,
+    >;
+    fn map(self, alias: &crate::query_source::Alias<S>) -> Self::Out {
+        LikeIgnoreCase {
+            left: self.left.map(alias),
+            right: self.right.map(alias),
+        }
+    }
+}
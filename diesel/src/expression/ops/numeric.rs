@@ -1,4 +1,4 @@
-use crate::backend::Backend;
+use crate::backend::{Backend, SqlDialect, sql_dialect};
 use crate::expression::{Expression, TypedExpressionType, ValidGrouping};
 use crate::query_builder::*;
 use crate::result::QueryResult;
@@ -6,11 +6,56 @@ use crate::sql_types;
 
 macro_rules! numeric_operation {
     ($name:ident, $op:expr_2021) => {
+        numeric_operation!(@common $name);
+
+        impl<Lhs, Rhs, DB> QueryFragment<DB> for $name<Lhs, Rhs>
+        where
+            DB: Backend,
+            Lhs: QueryFragment<DB>,
+            Rhs: QueryFragment<DB>,
+        {
+            fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, DB>) -> QueryResult<()>
+            {
+                self.walk_operator(out, $op)
+            }
+        }
+    };
+    ($name:ident, $op:expr_2021, interval_arithmetic) => {
+        numeric_operation!(@common $name);
+
+        impl<Lhs, Rhs, DB> QueryFragment<DB> for $name<Lhs, Rhs>
+        where
+            DB: Backend,
+            Self: QueryFragment<DB, DB::IntervalArithmetic>,
+        {
+            fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, DB>) -> QueryResult<()>
+            {
+                <Self as QueryFragment<DB, DB::IntervalArithmetic>>::walk_ast(self, out)
+            }
+        }
+
+        impl<Lhs, Rhs, DB> QueryFragment<DB, sql_dialect::interval_arithmetic::AnsiSqlIntervalArithmetic>
+            for $name<Lhs, Rhs>
+        where
+            DB: Backend
+                + SqlDialect<
+                    IntervalArithmetic = sql_dialect::interval_arithmetic::AnsiSqlIntervalArithmetic,
+                >,
+            Lhs: QueryFragment<DB>,
+            Rhs: QueryFragment<DB>,
+        {
+            fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, DB>) -> QueryResult<()>
+            {
+                self.walk_operator(out, $op)
+            }
+        }
+    };
+    (@common $name:ident) => {
         #[doc(hidden)]
         #[derive(Debug, Copy, Clone, QueryId, ValidGrouping)]
         pub struct $name<Lhs, Rhs> {
-            lhs: Lhs,
-            rhs: Rhs,
+            pub(crate) lhs: Lhs,
+            pub(crate) rhs: Rhs,
         }
 
         impl<Lhs, Rhs> $name<Lhs, Rhs> {
@@ -36,17 +81,20 @@ macro_rules! numeric_operation {
             type SqlType = <Lhs::SqlType as sql_types::ops::$name>::Output;
         }
 
-        impl<Lhs, Rhs, DB> QueryFragment<DB> for $name<Lhs, Rhs>
-        where
-            DB: Backend,
-            Lhs: QueryFragment<DB>,
-            Rhs: QueryFragment<DB>,
-        {
-            fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()>
+        impl<Lhs, Rhs> $name<Lhs, Rhs> {
+            pub(crate) fn walk_operator<'b, DB>(
+                &'b self,
+                mut out: AstPass<'_, 'b, DB>,
+                operator: &str,
+            ) -> QueryResult<()>
+            where
+                DB: Backend,
+                Lhs: QueryFragment<DB>,
+                Rhs: QueryFragment<DB>,
             {
                 out.push_sql("(");
                 self.lhs.walk_ast(out.reborrow())?;
-                out.push_sql($op);
+                out.push_sql(operator);
                 self.rhs.walk_ast(out.reborrow())?;
                 out.push_sql(")");
                 Ok(())
@@ -58,7 +106,7 @@ macro_rules! numeric_operation {
     };
 }
 
-numeric_operation!(Add, " + ");
-numeric_operation!(Sub, " - ");
+numeric_operation!(Add, " + ", interval_arithmetic);
+numeric_operation!(Sub, " - ", interval_arithmetic);
 numeric_operation!(Mul, " * ");
 numeric_operation!(Div, " / ");
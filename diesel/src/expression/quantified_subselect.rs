@@ -0,0 +1,115 @@
+//! This module contains the query dsl nodes for the `ANY (subselect)`
+//! and `ALL (subselect)` operands of comparisons
+
+#[cfg(any(feature = "postgres_backend", feature = "mysql_backend"))]
+use crate::backend::Backend;
+use crate::expression::subselect::Subselect;
+use crate::expression::{
+    AppearsOnTable, Expression, SelectableExpression, TypedExpressionType, ValidGrouping,
+};
+use crate::query_builder::*;
+#[cfg(any(feature = "postgres_backend", feature = "mysql_backend"))]
+use crate::result::QueryResult;
+use crate::sql_types::SqlType;
+
+macro_rules! quantified_subselect {
+    ($(#[$meta:meta])* $name:ident => $keyword:literal) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, QueryId)]
+        pub struct $name<T, ST> {
+            #[cfg_attr(
+                not(any(feature = "postgres_backend", feature = "mysql_backend")),
+                allow(dead_code)
+            )]
+            subselect: Subselect<T, ST>,
+        }
+
+        impl<T, ST> $name<T, ST> {
+            pub(crate) fn new(query: T) -> Self {
+                Self {
+                    subselect: Subselect::new(query),
+                }
+            }
+
+            #[cfg(any(feature = "postgres_backend", feature = "mysql_backend"))]
+            fn walk_quantified<'b, DB>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()>
+            where
+                DB: Backend,
+                T: QueryFragment<DB>,
+            {
+                out.push_sql(concat!($keyword, " ("));
+                self.subselect.walk_ast(out.reborrow())?;
+                out.push_sql(")");
+                Ok(())
+            }
+        }
+
+        impl<T, ST> Expression for $name<T, ST>
+        where
+            Subselect<T, ST>: Expression<SqlType = ST>,
+            ST: SqlType + TypedExpressionType,
+        {
+            type SqlType = ST;
+        }
+
+        impl<T, ST, QS> AppearsOnTable<QS> for $name<T, ST>
+        where
+            Self: Expression,
+            Subselect<T, ST>: AppearsOnTable<QS>,
+        {
+        }
+
+        impl<T, ST, QS> SelectableExpression<QS> for $name<T, ST>
+        where
+            Self: AppearsOnTable<QS>,
+            Subselect<T, ST>: SelectableExpression<QS>,
+        {
+        }
+
+        impl<T, ST, GB> ValidGrouping<GB> for $name<T, ST>
+        where
+            Subselect<T, ST>: ValidGrouping<GB>,
+        {
+            type IsAggregate = <Subselect<T, ST> as ValidGrouping<GB>>::IsAggregate;
+        }
+
+        // SQLite does not support quantified comparisons
+        #[cfg(feature = "postgres_backend")]
+        impl<T, ST> QueryFragment<crate::pg::Pg> for $name<T, ST>
+        where
+            T: QueryFragment<crate::pg::Pg>,
+        {
+            fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, crate::pg::Pg>) -> QueryResult<()> {
+                self.walk_quantified(out)
+            }
+        }
+
+        #[cfg(feature = "mysql_backend")]
+        impl<T, ST> QueryFragment<crate::mysql::Mysql> for $name<T, ST>
+        where
+            T: QueryFragment<crate::mysql::Mysql>,
+        {
+            fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, crate::mysql::Mysql>) -> QueryResult<()> {
+                self.walk_quantified(out)
+            }
+        }
+    };
+}
+
+quantified_subselect!(
+    /// The `ANY (subselect)` operand of a comparison
+    ///
+    /// This is constructed by the `*_any` methods of
+    /// [`ExpressionMethods`](crate::ExpressionMethods), for example
+    /// [`gt_any`](crate::ExpressionMethods::gt_any()).
+    AnySubselect => "ANY"
+);
+
+quantified_subselect!(
+    /// The `ALL (subselect)` operand of a comparison
+    ///
+    /// This is constructed by the `*_all` methods of
+    /// [`ExpressionMethods`](crate::ExpressionMethods), for example
+    /// [`gt_all`](crate::ExpressionMethods::gt_all()).
+    AllSubselect => "ALL"
+);
@@ -0,0 +1,167 @@
+//! This module contains the query dsl node definitions
+//! for row value expressions like `(a, b) > (x, y)`
+
+use crate::backend::Backend;
+use crate::expression::{
+    AppearsOnTable, AsExpression, Expression, MixedAggregates, SelectableExpression,
+    TypedExpressionType, ValidGrouping,
+};
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+use crate::sql_types::{self, SqlType};
+use alloc::vec::Vec;
+
+/// Query dsl node that represents a row value `(a, b, ...)`
+///
+/// Row values are constructed from tuples via [`AsRowValue`] and are
+/// compared as a whole via
+/// [`RowValueExpressionMethods`](crate::expression_methods::RowValueExpressionMethods).
+#[derive(Debug, Clone, Copy, QueryId, ValidGrouping)]
+pub struct RowValue<T>(T);
+
+impl<T: Expression> Expression for RowValue<T> {
+    type SqlType = T::SqlType;
+}
+
+impl<T, DB> QueryFragment<DB> for RowValue<T>
+where
+    T: QueryFragment<DB>,
+    DB: Backend,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        out.push_sql("(");
+        self.0.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl_selectable_expression!(RowValue<T>);
+
+/// Converts a tuple into a [`RowValue`] of the given tuple sql type
+///
+/// This is implemented for tuples whose elements each implement
+/// [`AsExpression`] for the corresponding element of the sql type,
+/// so tuples of columns and tuples of rust values can be used as row values.
+pub trait AsRowValue<ST> {
+    /// The expression wrapped by the row value
+    type Expression: Expression<SqlType = ST>;
+
+    /// Construct the row value for `self`
+    #[allow(clippy::wrong_self_convention)]
+    // That's a public api, we cannot just change it to
+    // appease clippy
+    fn as_row_value(self) -> RowValue<Self::Expression>;
+}
+
+macro_rules! row_value_impls {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST:ident, $TT:ident,)+
+        }
+    )+) => {
+        $(
+            #[cfg_attr(diesel_docsrs, doc(hidden))]
+            impl<$($T,)+ $($ST,)+> AsRowValue<($($ST,)+)> for ($($T,)+)
+            where
+                $($T: AsExpression<$ST>,)+
+                $($ST: SqlType + TypedExpressionType,)+
+            {
+                type Expression = ($($T::Expression,)+);
+
+                fn as_row_value(self) -> RowValue<Self::Expression> {
+                    RowValue(($(self.$idx.as_expression(),)+))
+                }
+            }
+        )+
+    }
+}
+
+crate::for_each_tuple!(row_value_impls);
+
+/// Query dsl node that represents a `(a, b) IN ((x1, y1), (x2, y2))`
+/// expression
+///
+/// An empty list of values is rendered as `1=0`.
+#[derive(Debug, Clone)]
+pub struct RowValueIn<T, U> {
+    left: RowValue<T>,
+    values: Vec<RowValue<U>>,
+}
+
+impl<T, U> RowValueIn<T, U> {
+    pub(crate) fn new(left: RowValue<T>, values: Vec<RowValue<U>>) -> Self {
+        Self { left, values }
+    }
+}
+
+impl<T, U> Expression for RowValueIn<T, U>
+where
+    T: Expression,
+    U: Expression<SqlType = T::SqlType>,
+    T::SqlType: SqlType,
+    sql_types::is_nullable::IsSqlTypeNullable<T::SqlType>:
+        sql_types::MaybeNullableType<sql_types::Bool>,
+{
+    type SqlType = sql_types::is_nullable::MaybeNullable<
+        sql_types::is_nullable::IsSqlTypeNullable<T::SqlType>,
+        sql_types::Bool,
+    >;
+}
+
+impl<T, U, GB> ValidGrouping<GB> for RowValueIn<T, U>
+where
+    T: ValidGrouping<GB>,
+    U: ValidGrouping<GB>,
+    T::IsAggregate: MixedAggregates<U::IsAggregate>,
+{
+    type IsAggregate = <T::IsAggregate as MixedAggregates<U::IsAggregate>>::Output;
+}
+
+impl<T, U, QS> SelectableExpression<QS> for RowValueIn<T, U>
+where
+    Self: AppearsOnTable<QS>,
+    T: SelectableExpression<QS>,
+    U: SelectableExpression<QS>,
+{
+}
+
+impl<T, U, QS> AppearsOnTable<QS> for RowValueIn<T, U>
+where
+    Self: Expression,
+    T: AppearsOnTable<QS>,
+    U: AppearsOnTable<QS>,
+{
+}
+
+impl<T, U, DB> QueryFragment<DB> for RowValueIn<T, U>
+where
+    DB: Backend,
+    T: QueryFragment<DB>,
+    U: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        if self.values.is_empty() {
+            out.push_sql("1=0");
+            return Ok(());
+        }
+        // the number of bind values depends on the number of rows
+        out.unsafe_to_cache_prepared();
+        self.left.walk_ast(out.reborrow())?;
+        out.push_sql(" IN (");
+        for (idx, value) in self.values.iter().enumerate() {
+            if idx != 0 {
+                out.push_sql(", ");
+            }
+            value.walk_ast(out.reborrow())?;
+        }
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<T, U> QueryId for RowValueIn<T, U> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
@@ -2,7 +2,9 @@ use crate::dsl;
 use crate::expression::array_comparison::{AsInExpression, In, NotIn};
 use crate::expression::grouped::Grouped;
 use crate::expression::operators::*;
+use crate::expression::quantified_subselect::{AllSubselect, AnySubselect};
 use crate::expression::{AsExpression, Expression, assume_not_null, cast, nullable};
+use crate::query_builder::SelectQuery;
 use crate::sql_types::{SingleValue, SqlType, Untyped};
 
 /// Methods present on all expressions, except tuples
@@ -382,6 +384,358 @@ pub trait ExpressionMethods: Expression + Sized {
         Grouped(LtEq::new(self, other.as_expression()))
     }
 
+    /// Creates a SQL `> ANY (subquery)` expression.
+    ///
+    /// The comparison is true if it holds for at least one row returned by the subquery.
+    /// The subquery may reference columns of the outer query.
+    /// This is only supported on PostgreSQL and MySQL.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "sqlite"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::{posts, users};
+    /// #     let connection = &mut establish_connection();
+    /// let data = users::table
+    ///     .select(users::name)
+    ///     .filter(users::id.gt_any(posts::table.select(posts::user_id)))
+    ///     .order(users::id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Tess"], data);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(feature = "sqlite")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = ">")]
+    fn gt_any<T>(self, subquery: T) -> dsl::GtAny<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: SelectQuery<SqlType = Self::SqlType>,
+    {
+        Grouped(Gt::new(self, AnySubselect::new(subquery)))
+    }
+
+    /// Creates a SQL `> ALL (subquery)` expression.
+    ///
+    /// The comparison is true if it holds for all rows returned by the subquery.
+    /// The subquery may reference columns of the outer query.
+    /// This is only supported on PostgreSQL and MySQL.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "sqlite"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::{posts, users};
+    /// #     let connection = &mut establish_connection();
+    /// let data = users::table
+    ///     .select(users::name)
+    ///     .filter(users::id.gt_all(
+    ///         posts::table
+    ///             .select(posts::user_id)
+    ///             .filter(posts::title.eq("About Rust")),
+    ///     ))
+    ///     .order(users::id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Tess"], data);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(feature = "sqlite")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = ">")]
+    fn gt_all<T>(self, subquery: T) -> dsl::GtAll<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: SelectQuery<SqlType = Self::SqlType>,
+    {
+        Grouped(Gt::new(self, AllSubselect::new(subquery)))
+    }
+
+    /// Creates a SQL `>= ANY (subquery)` expression.
+    ///
+    /// The comparison is true if it holds for at least one row returned by the subquery.
+    /// The subquery may reference columns of the outer query.
+    /// This is only supported on PostgreSQL and MySQL.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "sqlite"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::{posts, users};
+    /// #     let connection = &mut establish_connection();
+    /// let data = users::table
+    ///     .select(users::name)
+    ///     .filter(users::id.ge_any(posts::table.select(posts::user_id)))
+    ///     .order(users::id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Sean", "Tess"], data);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(feature = "sqlite")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = ">=")]
+    fn ge_any<T>(self, subquery: T) -> dsl::GtEqAny<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: SelectQuery<SqlType = Self::SqlType>,
+    {
+        Grouped(GtEq::new(self, AnySubselect::new(subquery)))
+    }
+
+    /// Creates a SQL `>= ALL (subquery)` expression.
+    ///
+    /// The comparison is true if it holds for all rows returned by the subquery.
+    /// The subquery may reference columns of the outer query.
+    /// This is only supported on PostgreSQL and MySQL.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "sqlite"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::{posts, users};
+    /// #     let connection = &mut establish_connection();
+    /// let data = users::table
+    ///     .select(users::name)
+    ///     .filter(users::id.ge_all(posts::table.select(posts::user_id)))
+    ///     .order(users::id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Tess"], data);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(feature = "sqlite")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = ">=")]
+    fn ge_all<T>(self, subquery: T) -> dsl::GtEqAll<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: SelectQuery<SqlType = Self::SqlType>,
+    {
+        Grouped(GtEq::new(self, AllSubselect::new(subquery)))
+    }
+
+    /// Creates a SQL `< ANY (subquery)` expression.
+    ///
+    /// The comparison is true if it holds for at least one row returned by the subquery.
+    /// The subquery may reference columns of the outer query.
+    /// This is only supported on PostgreSQL and MySQL.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "sqlite"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::{posts, users};
+    /// #     let connection = &mut establish_connection();
+    /// let data = users::table
+    ///     .select(users::name)
+    ///     .filter(users::id.lt_any(posts::table.select(posts::user_id)))
+    ///     .order(users::id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Sean"], data);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(feature = "sqlite")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "<")]
+    fn lt_any<T>(self, subquery: T) -> dsl::LtAny<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: SelectQuery<SqlType = Self::SqlType>,
+    {
+        Grouped(Lt::new(self, AnySubselect::new(subquery)))
+    }
+
+    /// Creates a SQL `< ALL (subquery)` expression.
+    ///
+    /// The comparison is true if it holds for all rows returned by the subquery.
+    /// The subquery may reference columns of the outer query.
+    /// This is only supported on PostgreSQL and MySQL.
+    ///
+    /// The subquery in this example references the outer `users` table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "sqlite"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::{posts, users};
+    /// #     let connection = &mut establish_connection();
+    /// let data = users::table
+    ///     .select(users::name)
+    ///     .filter(users::id.lt_all(
+    ///         posts::table
+    ///             .select(posts::id)
+    ///             .filter(posts::user_id.eq(users::id)),
+    ///     ))
+    ///     .order(users::id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Tess"], data);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(feature = "sqlite")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "<")]
+    fn lt_all<T>(self, subquery: T) -> dsl::LtAll<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: SelectQuery<SqlType = Self::SqlType>,
+    {
+        Grouped(Lt::new(self, AllSubselect::new(subquery)))
+    }
+
+    /// Creates a SQL `<= ANY (subquery)` expression.
+    ///
+    /// The comparison is true if it holds for at least one row returned by the subquery.
+    /// The subquery may reference columns of the outer query.
+    /// This is only supported on PostgreSQL and MySQL.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "sqlite"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::{posts, users};
+    /// #     let connection = &mut establish_connection();
+    /// let data = users::table
+    ///     .select(users::name)
+    ///     .filter(users::id.le_any(posts::table.select(posts::user_id)))
+    ///     .order(users::id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Sean", "Tess"], data);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(feature = "sqlite")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "<=")]
+    fn le_any<T>(self, subquery: T) -> dsl::LtEqAny<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: SelectQuery<SqlType = Self::SqlType>,
+    {
+        Grouped(LtEq::new(self, AnySubselect::new(subquery)))
+    }
+
+    /// Creates a SQL `<= ALL (subquery)` expression.
+    ///
+    /// The comparison is true if it holds for all rows returned by the subquery.
+    /// The subquery may reference columns of the outer query.
+    /// This is only supported on PostgreSQL and MySQL.
+    ///
+    /// The subquery in this example references the outer `users` table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "sqlite"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::{posts, users};
+    /// #     let connection = &mut establish_connection();
+    /// let data = users::table
+    ///     .select(users::name)
+    ///     .filter(users::id.le_all(
+    ///         posts::table
+    ///             .select(posts::id)
+    ///             .filter(posts::user_id.eq(users::id)),
+    ///     ))
+    ///     .order(users::id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Sean", "Tess"], data);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(feature = "sqlite")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[doc(alias = "<=")]
+    fn le_all<T>(self, subquery: T) -> dsl::LtEqAll<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: SelectQuery<SqlType = Self::SqlType>,
+    {
+        Grouped(LtEq::new(self, AllSubselect::new(subquery)))
+    }
+
     /// Creates a SQL `BETWEEN` expression using the given lower and upper
     /// bounds.
     ///
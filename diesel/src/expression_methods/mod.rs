@@ -10,6 +10,7 @@ mod escape_expression_methods;
 mod global_expression_methods;
 #[cfg(any(feature = "__sqlite-shared", feature = "postgres_backend"))]
 pub(crate) mod json_expression_methods;
+mod row_value_expression_methods;
 mod text_expression_methods;
 
 #[doc(inline)]
@@ -26,6 +27,8 @@ pub use self::global_expression_methods::{
 #[cfg(any(feature = "__sqlite-shared", feature = "postgres_backend"))]
 pub use self::json_expression_methods::{AnyJsonExpressionMethods, JsonIndex};
 #[doc(inline)]
+pub use self::row_value_expression_methods::RowValueExpressionMethods;
+#[doc(inline)]
 pub use self::text_expression_methods::TextExpressionMethods;
 #[doc(inline)]
 pub use crate::expression::functions::aggregate_expressions::AggregateExpressionMethods;
@@ -0,0 +1,235 @@
+use crate::dsl;
+use crate::expression::Expression;
+use crate::expression::grouped::Grouped;
+use crate::expression::operators::{Eq, Gt, GtEq, Lt, LtEq, NotEq};
+use crate::expression::row_value::{AsRowValue, RowValueIn};
+
+/// Methods present on tuples of expressions, comparing them as row values
+///
+/// Row values are compared element by element from left to right, so
+/// `(a, b) > (x, y)` is true if `a > x`, or if `a = x` and `b > y`.
+/// This is useful for keyset pagination over a composite sort key.
+///
+/// Row value comparisons are supported by PostgreSQL, MySQL and
+/// SQLite 3.15 or newer.
+pub trait RowValueExpressionMethods:
+    Expression + AsRowValue<<Self as Expression>::SqlType> + Sized
+{
+    /// Creates a SQL `(a, b) = (x, y)` expression.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let data = users
+    ///     .select(name)
+    ///     .filter((id, name).row_eq((2, "Tess")))
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Tess"], data);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn row_eq<T>(self, other: T) -> dsl::RowEq<Self, T>
+    where
+        T: AsRowValue<Self::SqlType>,
+    {
+        Grouped(Eq::new(self.as_row_value(), other.as_row_value()))
+    }
+
+    /// Creates a SQL `(a, b) != (x, y)` expression.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let data = users
+    ///     .select(name)
+    ///     .filter((id, name).row_ne((2, "Tess")))
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Sean"], data);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn row_ne<T>(self, other: T) -> dsl::RowNe<Self, T>
+    where
+        T: AsRowValue<Self::SqlType>,
+    {
+        Grouped(NotEq::new(self.as_row_value(), other.as_row_value()))
+    }
+
+    /// Creates a SQL `(a, b) > (x, y)` expression.
+    ///
+    /// # Example
+    ///
+    /// Fetching the page after the last seen row, sorted by name and id:
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let last_seen = ("Sean", 1);
+    /// let next_page = users
+    ///     .select((id, name))
+    ///     .filter((name, id).row_gt(last_seen))
+    ///     .order((name, id))
+    ///     .limit(10)
+    ///     .load::<(i32, String)>(connection)?;
+    /// assert_eq!(vec![(2, "Tess".to_string())], next_page);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn row_gt<T>(self, other: T) -> dsl::RowGt<Self, T>
+    where
+        T: AsRowValue<Self::SqlType>,
+    {
+        Grouped(Gt::new(self.as_row_value(), other.as_row_value()))
+    }
+
+    /// Creates a SQL `(a, b) >= (x, y)` expression.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let data = users
+    ///     .select(name)
+    ///     .filter((name, id).row_ge(("Sean", 1)))
+    ///     .order(id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Sean", "Tess"], data);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn row_ge<T>(self, other: T) -> dsl::RowGe<Self, T>
+    where
+        T: AsRowValue<Self::SqlType>,
+    {
+        Grouped(GtEq::new(self.as_row_value(), other.as_row_value()))
+    }
+
+    /// Creates a SQL `(a, b) < (x, y)` expression.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let data = users
+    ///     .select(name)
+    ///     .filter((name, id).row_lt(("Tess", 2)))
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Sean"], data);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn row_lt<T>(self, other: T) -> dsl::RowLt<Self, T>
+    where
+        T: AsRowValue<Self::SqlType>,
+    {
+        Grouped(Lt::new(self.as_row_value(), other.as_row_value()))
+    }
+
+    /// Creates a SQL `(a, b) <= (x, y)` expression.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let data = users
+    ///     .select(name)
+    ///     .filter((name, id).row_le(("Tess", 2)))
+    ///     .order(id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Sean", "Tess"], data);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn row_le<T>(self, other: T) -> dsl::RowLe<Self, T>
+    where
+        T: AsRowValue<Self::SqlType>,
+    {
+        Grouped(LtEq::new(self.as_row_value(), other.as_row_value()))
+    }
+
+    /// Creates a SQL `(a, b) IN ((x1, y1), (x2, y2))` expression.
+    ///
+    /// An empty list of rows never matches.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let data = users
+    ///     .select(name)
+    ///     .filter((id, name).row_eq_any(vec![(1, "Sean"), (2, "Jim")]))
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Sean"], data);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn row_eq_any<I, T>(self, values: I) -> dsl::RowEqAny<Self, I>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRowValue<Self::SqlType>,
+    {
+        Grouped(RowValueIn::new(
+            self.as_row_value(),
+            values.into_iter().map(AsRowValue::as_row_value).collect(),
+        ))
+    }
+}
+
+impl<T> RowValueExpressionMethods for T where T: Expression + AsRowValue<T::SqlType> {}
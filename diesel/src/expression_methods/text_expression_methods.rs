@@ -1,7 +1,7 @@
 use self::private::TextOrNullableText;
 use crate::dsl;
 use crate::expression::grouped::Grouped;
-use crate::expression::operators::{Collate, Concat, Like, NotLike};
+use crate::expression::operators::{Collate, Concat, EqIgnoreCase, Like, LikeIgnoreCase, NotLike};
 use crate::expression::{AsExpression, Expression};
 
 use crate::sql_types::SqlType;
@@ -173,6 +173,89 @@ pub trait TextExpressionMethods: Expression + Sized {
     {
         Grouped(NotLike::new(self, other.as_expression()))
     }
+
+    /// Compares two strings case insensitively
+    ///
+    /// This is rendered as `LOWER(lhs) = LOWER(rhs)` on all backends.
+    /// In contrast to `ILIKE` the right hand side is not treated as a
+    /// pattern, so `%` and `_` only match themselves. Note that `LOWER`
+    /// only handles ASCII characters on SQLite. On PostgreSQL columns of
+    /// the [`Citext`](../sql_types/struct.Citext.html) type already compare case
+    /// insensitively with [`eq`](crate::ExpressionMethods::eq()).
+    ///
+    /// An index on the lowercased column, e.g. `CREATE INDEX ON users (LOWER(name))`,
+    /// can be used to speed up such queries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// #
+    /// let sean = users
+    ///     .select(id)
+    ///     .filter(name.eq_ignore_case("SEAN"))
+    ///     .load::<i32>(connection)?;
+    /// assert_eq!(vec![1], sean);
+    ///
+    /// let no_pattern = users
+    ///     .select(id)
+    ///     .filter(name.eq_ignore_case("S%"))
+    ///     .load::<i32>(connection)?;
+    /// assert!(no_pattern.is_empty());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn eq_ignore_case<T>(self, other: T) -> dsl::EqIgnoreCase<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(EqIgnoreCase::new(self, other.as_expression()))
+    }
+
+    /// Returns a SQL `ILIKE` expression, matching a pattern case insensitively
+    ///
+    /// This is rendered as `lhs ILIKE rhs` on PostgreSQL and as
+    /// `LOWER(lhs) LIKE LOWER(rhs)` on SQLite and MySQL. Use
+    /// [`PgTextExpressionMethods::ilike`](crate::PgTextExpressionMethods::ilike())
+    /// instead if your queries only target PostgreSQL.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// #
+    /// let starts_with_s = users
+    ///     .select(name)
+    ///     .filter(name.like_ignore_case("s%"))
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Sean"], starts_with_s);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn like_ignore_case<T>(self, other: T) -> dsl::LikeIgnoreCase<Self, T>
+    where
+        Self::SqlType: SqlType,
+        T: AsExpression<Self::SqlType>,
+    {
+        Grouped(LikeIgnoreCase::new(self, other.as_expression()))
+    }
 }
 
 impl<T> TextExpressionMethods for T
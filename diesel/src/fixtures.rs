@@ -0,0 +1,656 @@
+//! Load test data from fixture files
+//!
+//! Note: This module requires enabling the `fixtures` feature
+//!
+//! Fixture files are TOML documents containing an array of rows for each
+//! table. Each row maps column names to values, columns missing from a row
+//! use their default value. Before anything is inserted, each table and
+//! column is checked against the tables registered with [`Fixtures::table`],
+//! so that a typo in a fixture file results in an error instead of a
+//! confusing SQL error. All rows are inserted inside of a single
+//! transaction, tables referenced via [`Fixtures::foreign_key`] are
+//! inserted before the tables referencing them.
+//!
+//! Strings, integers, floats, booleans and date/time values are supported
+//! as values. Strings and date/time values are passed to the database as
+//! string literals, so they can be used for any column type accepting
+//! such literals, like timestamps or enums.
+//!
+//! # Example
+//!
+//! ```rust
+//! # include!("doctest_setup.rs");
+//! use diesel::fixtures::Fixtures;
+//! use schema::{posts, users};
+//!
+//! # fn main() {
+//! #     run_test().unwrap();
+//! # }
+//! #
+//! # fn run_test() -> Result<(), Box<dyn std::error::Error>> {
+//! let connection = &mut establish_connection();
+//! let fixtures = Fixtures::new()
+//!     .table(users::table)
+//!     .table(posts::table)
+//!     .foreign_key(posts::table, users::table);
+//!
+//! // `users` are inserted first, as `posts` reference them
+//! let inserted = fixtures.load(
+//!     connection,
+//!     r#"
+//!     [[posts]]
+//!     id = 10
+//!     user_id = 3
+//!     title = "Loaded from a fixture"
+//!
+//!     [[users]]
+//!     id = 3
+//!     name = "Jim"
+//!     "#,
+//! )?;
+//! assert_eq!(2, inserted);
+//!
+//! let title = posts::table
+//!     .inner_join(users::table)
+//!     .filter(users::name.eq("Jim"))
+//!     .select(posts::title)
+//!     .get_result::<String>(connection)?;
+//! assert_eq!("Loaded from a fixture", title);
+//! # Ok(())
+//! # }
+//! ```
+
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+use std::path::Path;
+
+use crate::backend::Backend;
+use crate::connection::Connection;
+use crate::query_builder::locking_clause::TableNameComponent;
+use crate::query_builder::nodes::StaticQueryFragment;
+use crate::query_builder::{AstPass, ColumnList, QueryFragment, QueryId};
+use crate::query_source::{JoinTo, Table};
+use crate::result::{Error, QueryResult};
+
+/// A table that can be loaded from fixture files
+///
+/// This trait is implemented for all tables generated by [`table!`](crate::table!)
+pub trait FixtureTable<DB: Backend>: Table {
+    #[doc(hidden)]
+    fn table_name() -> &'static str;
+
+    #[doc(hidden)]
+    fn column_names() -> Vec<&'static str>;
+
+    #[doc(hidden)]
+    fn walk_table(pass: AstPass<'_, '_, DB>) -> QueryResult<()>;
+}
+
+impl<T, DB> FixtureTable<DB> for T
+where
+    DB: Backend,
+    T: Table + StaticQueryFragment,
+    T::AllColumns: ColumnList,
+    T::Component: TableNameComponent + QueryFragment<DB>,
+{
+    fn table_name() -> &'static str {
+        T::STATIC_COMPONENT.table_name()
+    }
+
+    fn column_names() -> Vec<&'static str> {
+        let mut names = Vec::new();
+        <T::AllColumns as ColumnList>::column_names(&mut names);
+        names
+    }
+
+    fn walk_table(pass: AstPass<'_, '_, DB>) -> QueryResult<()> {
+        T::STATIC_COMPONENT.walk_ast(pass)
+    }
+}
+
+/// A backend supported by [`Fixtures`]
+///
+/// This trait is implemented for all backends provided by diesel.
+pub trait FixtureBackend: Backend {
+    #[doc(hidden)]
+    fn walk_string<'b>(value: &'b str, pass: AstPass<'_, 'b, Self>) -> QueryResult<()>;
+}
+
+/// Pushes `value` as string literal, which is valid for backends
+/// that do not treat backslashes as escape characters
+#[cfg(any(feature = "postgres_backend", feature = "__sqlite-shared"))]
+fn push_string_literal<DB: Backend>(value: &str, mut pass: AstPass<'_, '_, DB>) {
+    pass.push_sql("'");
+    pass.push_sql(&value.replace('\'', "''"));
+    pass.push_sql("'");
+}
+
+#[cfg(feature = "postgres_backend")]
+impl FixtureBackend for crate::pg::Pg {
+    fn walk_string<'b>(value: &'b str, pass: AstPass<'_, 'b, Self>) -> QueryResult<()> {
+        // a literal has no type, so PostgreSQL infers the column type
+        // while a bind parameter would be sent as `text`
+        push_string_literal(value, pass);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "__sqlite-shared")]
+impl FixtureBackend for crate::sqlite::Sqlite {
+    fn walk_string<'b>(value: &'b str, pass: AstPass<'_, 'b, Self>) -> QueryResult<()> {
+        push_string_literal(value, pass);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mysql_backend")]
+impl FixtureBackend for crate::mysql::Mysql {
+    fn walk_string<'b>(value: &'b str, mut pass: AstPass<'_, 'b, Self>) -> QueryResult<()> {
+        // MySQL converts string parameters to the column type, a literal
+        // would depend on the `NO_BACKSLASH_ESCAPES` SQL mode
+        pass.push_bind_param::<crate::sql_types::Text, str>(value)
+    }
+}
+
+/// Errors returned while loading fixtures
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum FixtureError {
+    /// The fixture file could not be read
+    Io(std::io::Error),
+    /// The fixture file is not a valid TOML document
+    Parse(Box<dyn core::error::Error + Send + Sync>),
+    /// The fixture file contains a table which was not registered
+    /// via [`Fixtures::table`]
+    UnknownTable(String),
+    /// A row contains a column, which does not exist on its table
+    UnknownColumn {
+        /// The name of the table
+        table: String,
+        /// The name of the unknown column
+        column: String,
+    },
+    /// The rows of a table are not an array of tables
+    /// or a row does not contain any column
+    InvalidRows(String),
+    /// A row contains a value, which is not supported, like an array
+    UnsupportedValue {
+        /// The name of the table
+        table: String,
+        /// The name of the column
+        column: String,
+    },
+    /// The foreign keys between the given tables form a cycle
+    CyclicForeignKeys(Vec<String>),
+    /// Inserting the rows failed
+    Query(Error),
+}
+
+impl fmt::Display for FixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FixtureError::Io(e) => write!(f, "Failed to read the fixture file: {e}"),
+            FixtureError::Parse(e) => write!(f, "Failed to parse the fixture file: {e}"),
+            FixtureError::UnknownTable(table) => write!(
+                f,
+                "The table `{table}` is not registered for loading fixtures"
+            ),
+            FixtureError::UnknownColumn { table, column } => {
+                write!(f, "The table `{table}` has no column `{column}`")
+            }
+            FixtureError::InvalidRows(table) => write!(
+                f,
+                "The rows of `{table}` need to be an array of tables with at least one column"
+            ),
+            FixtureError::UnsupportedValue { table, column } => write!(
+                f,
+                "The value of `{table}.{column}` is not supported in fixtures"
+            ),
+            FixtureError::CyclicForeignKeys(tables) => write!(
+                f,
+                "The foreign keys between `{}` form a cycle",
+                tables.join("`, `")
+            ),
+            FixtureError::Query(e) => write!(f, "Failed to insert the fixtures: {e}"),
+        }
+    }
+}
+
+impl core::error::Error for FixtureError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            FixtureError::Io(e) => Some(e),
+            FixtureError::Parse(e) => Some(&**e),
+            FixtureError::Query(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for FixtureError {
+    fn from(e: Error) -> Self {
+        FixtureError::Query(e)
+    }
+}
+
+struct TableInfo<DB: Backend> {
+    name: &'static str,
+    columns: Vec<&'static str>,
+    walk_table: fn(AstPass<'_, '_, DB>) -> QueryResult<()>,
+}
+
+/// Loads fixture files into the tables registered with [`Fixtures::table`]
+///
+/// See the [module documentation](self) for an example.
+pub struct Fixtures<DB: Backend> {
+    tables: Vec<TableInfo<DB>>,
+    // pairs of (referencing table, referenced table)
+    foreign_keys: Vec<(&'static str, &'static str)>,
+}
+
+impl<DB: Backend> fmt::Debug for Fixtures<DB> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Fixtures")
+            .field(
+                "tables",
+                &self.tables.iter().map(|t| t.name).collect::<Vec<_>>(),
+            )
+            .field("foreign_keys", &self.foreign_keys)
+            .finish()
+    }
+}
+
+impl<DB: FixtureBackend> Default for Fixtures<DB> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<DB: FixtureBackend> Fixtures<DB> {
+    /// Create a loader without any registered table
+    pub fn new() -> Self {
+        Self {
+            tables: Vec::new(),
+            foreign_keys: Vec::new(),
+        }
+    }
+
+    /// Allow loading rows into the given table
+    ///
+    /// The rows of this table are stored under the unqualified name of
+    /// the table in fixture files.
+    pub fn table<T: FixtureTable<DB>>(mut self, _table: T) -> Self {
+        if !self.tables.iter().any(|t| t.name == T::table_name()) {
+            self.tables.push(TableInfo {
+                name: T::table_name(),
+                columns: T::column_names(),
+                walk_table: T::walk_table,
+            });
+        }
+        self
+    }
+
+    /// Declare that `child` references `parent`, so that rows of `parent`
+    /// are inserted before the rows of `child`
+    ///
+    /// This requires a [`joinable!`](crate::joinable!) declaration
+    /// between both tables.
+    pub fn foreign_key<Child, Parent>(mut self, _child: Child, _parent: Parent) -> Self
+    where
+        Child: FixtureTable<DB> + JoinTo<Parent>,
+        Parent: FixtureTable<DB>,
+    {
+        self.foreign_keys
+            .push((Child::table_name(), Parent::table_name()));
+        self
+    }
+
+    /// Insert all rows of the given TOML document inside of a transaction
+    ///
+    /// Returns the number of inserted rows.
+    pub fn load<C>(&self, conn: &mut C, fixtures: &str) -> Result<usize, FixtureError>
+    where
+        C: Connection<Backend = DB>,
+    {
+        let document = fixtures
+            .parse::<toml::Table>()
+            .map_err(|e| FixtureError::Parse(Box::new(e)))?;
+        let mut inserts = Vec::new();
+        for table in self.insertion_order(&document)? {
+            let rows = document[table.name]
+                .as_array()
+                .ok_or_else(|| FixtureError::InvalidRows(table.name.to_string()))?;
+            for row in rows {
+                inserts.push(FixtureInsert::new(table, row)?);
+            }
+        }
+        conn.transaction(|conn| {
+            for insert in &inserts {
+                conn.execute_returning_count(insert)?;
+            }
+            Ok(inserts.len())
+        })
+    }
+
+    /// Insert all rows of the given TOML file inside of a transaction
+    ///
+    /// Returns the number of inserted rows.
+    pub fn load_file<C>(&self, conn: &mut C, path: impl AsRef<Path>) -> Result<usize, FixtureError>
+    where
+        C: Connection<Backend = DB>,
+    {
+        let fixtures = std::fs::read_to_string(path).map_err(FixtureError::Io)?;
+        self.load(conn, &fixtures)
+    }
+
+    /// Orders the tables of `document` so that referenced tables
+    /// come before the tables referencing them
+    fn insertion_order(&self, document: &toml::Table) -> Result<Vec<&TableInfo<DB>>, FixtureError> {
+        let mut pending = Vec::new();
+        for name in document.keys() {
+            let table = self
+                .tables
+                .iter()
+                .find(|t| t.name == name)
+                .ok_or_else(|| FixtureError::UnknownTable(name.clone()))?;
+            pending.push(table);
+        }
+        // keep the order in which the tables were registered
+        // for tables that do not depend on each other
+        pending.sort_by_key(|table| {
+            self.tables
+                .iter()
+                .position(|t| t.name == table.name)
+                .unwrap_or(usize::MAX)
+        });
+
+        let mut ordered = Vec::with_capacity(pending.len());
+        while !pending.is_empty() {
+            let next = pending.iter().position(|table| {
+                !self.foreign_keys.iter().any(|(child, parent)| {
+                    *child == table.name
+                        && child != parent
+                        && pending.iter().any(|t| t.name == *parent)
+                })
+            });
+            match next {
+                Some(index) => ordered.push(pending.remove(index)),
+                None => {
+                    return Err(FixtureError::CyclicForeignKeys(
+                        pending.iter().map(|t| t.name.to_string()).collect(),
+                    ));
+                }
+            }
+        }
+        Ok(ordered)
+    }
+}
+
+enum FixtureValue {
+    String(String),
+    Literal(String),
+}
+
+struct FixtureInsert<'a, DB: Backend> {
+    table: &'a TableInfo<DB>,
+    columns: Vec<&'static str>,
+    values: Vec<FixtureValue>,
+}
+
+impl<'a, DB: Backend> FixtureInsert<'a, DB> {
+    fn new(table: &'a TableInfo<DB>, row: &toml::Value) -> Result<Self, FixtureError> {
+        let row = row
+            .as_table()
+            .filter(|row| !row.is_empty())
+            .ok_or_else(|| FixtureError::InvalidRows(table.name.to_string()))?;
+        let mut columns = Vec::with_capacity(row.len());
+        let mut values = Vec::with_capacity(row.len());
+        for (column, value) in row {
+            let column = table.columns.iter().find(|c| *c == column).ok_or_else(|| {
+                FixtureError::UnknownColumn {
+                    table: table.name.to_string(),
+                    column: column.clone(),
+                }
+            })?;
+            let value = match value {
+                toml::Value::String(s) => FixtureValue::String(s.clone()),
+                toml::Value::Datetime(d) => FixtureValue::String(d.to_string()),
+                toml::Value::Integer(i) => FixtureValue::Literal(i.to_string()),
+                toml::Value::Float(f) if f.is_finite() => FixtureValue::Literal(f.to_string()),
+                toml::Value::Boolean(b) => {
+                    FixtureValue::Literal(if *b { "TRUE" } else { "FALSE" }.to_string())
+                }
+                _ => {
+                    return Err(FixtureError::UnsupportedValue {
+                        table: table.name.to_string(),
+                        column: column.to_string(),
+                    });
+                }
+            };
+            columns.push(*column);
+            values.push(value);
+        }
+        Ok(Self {
+            table,
+            columns,
+            values,
+        })
+    }
+}
+
+impl<DB: FixtureBackend> QueryFragment<DB> for FixtureInsert<'_, DB> {
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        // the literals differ between rows
+        out.unsafe_to_cache_prepared();
+        out.push_sql("INSERT INTO ");
+        (self.table.walk_table)(out.reborrow())?;
+        out.push_sql(" (");
+        for (i, column) in self.columns.iter().enumerate() {
+            if i != 0 {
+                out.push_sql(", ");
+            }
+            out.push_identifier(column)?;
+        }
+        out.push_sql(") VALUES (");
+        for (i, value) in self.values.iter().enumerate() {
+            if i != 0 {
+                out.push_sql(", ");
+            }
+            match value {
+                FixtureValue::String(s) => DB::walk_string(s, out.reborrow())?,
+                FixtureValue::Literal(l) => out.push_sql(l),
+            }
+        }
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<DB: Backend> QueryId for FixtureInsert<'_, DB> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::connection::SimpleConnection;
+    use crate::prelude::*;
+    use crate::test_helpers::*;
+
+    crate::table! {
+        fixture_users {
+            id -> Integer,
+            name -> Text,
+        }
+    }
+
+    crate::table! {
+        fixture_posts {
+            id -> Integer,
+            user_id -> Integer,
+            title -> Text,
+            published -> Bool,
+        }
+    }
+
+    crate::joinable!(fixture_posts -> fixture_users (user_id));
+    crate::allow_tables_to_appear_in_same_query!(fixture_users, fixture_posts);
+
+    fn connection_with_tables() -> TestConnection {
+        let mut conn = connection();
+        conn.batch_execute(
+            "CREATE TEMPORARY TABLE fixture_users (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL
+            );
+            CREATE TEMPORARY TABLE fixture_posts (
+                id INTEGER PRIMARY KEY,
+                user_id INTEGER NOT NULL REFERENCES fixture_users (id),
+                title TEXT NOT NULL,
+                published BOOLEAN NOT NULL DEFAULT FALSE
+            );",
+        )
+        .unwrap();
+        conn
+    }
+
+    fn fixtures() -> Fixtures<<TestConnection as Connection>::Backend> {
+        Fixtures::new()
+            .table(fixture_posts::table)
+            .table(fixture_users::table)
+            .foreign_key(fixture_posts::table, fixture_users::table)
+    }
+
+    fn user_count(conn: &mut TestConnection) -> i64 {
+        fixture_users::table.count().get_result(conn).unwrap()
+    }
+
+    #[diesel_test_helper::test]
+    fn referenced_tables_are_inserted_first() {
+        let conn = &mut connection_with_tables();
+
+        let inserted = fixtures()
+            .load(
+                conn,
+                r#"
+                [[fixture_posts]]
+                id = 1
+                user_id = 1
+                title = "It's a post"
+                published = true
+
+                [[fixture_posts]]
+                id = 2
+                user_id = 2
+                title = "Draft"
+
+                [[fixture_users]]
+                id = 1
+                name = "Sean"
+
+                [[fixture_users]]
+                id = 2
+                name = "Tess"
+                "#,
+            )
+            .unwrap();
+        assert_eq!(4, inserted);
+
+        let posts = fixture_posts::table
+            .inner_join(fixture_users::table)
+            .select((
+                fixture_users::name,
+                fixture_posts::title,
+                fixture_posts::published,
+            ))
+            .order(fixture_posts::id)
+            .load::<(String, String, bool)>(conn)
+            .unwrap();
+        assert_eq!(
+            vec![
+                ("Sean".to_string(), "It's a post".to_string(), true),
+                ("Tess".to_string(), "Draft".to_string(), false),
+            ],
+            posts
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn unknown_tables_and_columns_are_rejected() {
+        let conn = &mut connection_with_tables();
+
+        let result = fixtures().load(conn, "[[fixture_comments]]\nid = 1");
+        assert!(
+            matches!(result, Err(FixtureError::UnknownTable(ref t)) if t == "fixture_comments"),
+            "{result:?}"
+        );
+
+        let result = fixtures().load(
+            conn,
+            "[[fixture_users]]\nid = 1\nname = \"Sean\"\nemail = \"sean@example.com\"",
+        );
+        assert!(
+            matches!(result, Err(FixtureError::UnknownColumn { ref column, .. }) if column == "email"),
+            "{result:?}"
+        );
+        assert_eq!(0, user_count(conn));
+    }
+
+    #[diesel_test_helper::test]
+    fn unsupported_values_are_rejected() {
+        let conn = &mut connection_with_tables();
+
+        let result = fixtures().load(conn, "[[fixture_users]]\nid = 1\nname = [\"Sean\"]");
+        assert!(
+            matches!(result, Err(FixtureError::UnsupportedValue { ref column, .. }) if column == "name"),
+            "{result:?}"
+        );
+
+        let result = fixtures().load(conn, "fixture_users = 1");
+        assert!(
+            matches!(result, Err(FixtureError::InvalidRows(_))),
+            "{result:?}"
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn failing_inserts_roll_back_all_rows() {
+        let conn = &mut connection_with_tables();
+
+        let result = fixtures().load(
+            conn,
+            r#"
+            [[fixture_users]]
+            id = 1
+            name = "Sean"
+
+            [[fixture_users]]
+            id = 1
+            name = "Tess"
+            "#,
+        );
+        assert!(matches!(result, Err(FixtureError::Query(_))), "{result:?}");
+        assert_eq!(0, user_count(conn));
+    }
+
+    #[diesel_test_helper::test]
+    fn cyclic_foreign_keys_are_rejected() {
+        let conn = &mut connection_with_tables();
+
+        let result = fixtures()
+            .foreign_key(fixture_users::table, fixture_posts::table)
+            .load(
+                conn,
+                "[[fixture_users]]\nid = 1\nname = \"Sean\"\n\n\
+                 [[fixture_posts]]\nid = 1\nuser_id = 1\ntitle = \"Post\"",
+            );
+        assert!(
+            matches!(result, Err(FixtureError::CyclicForeignKeys(ref t)) if t.len() == 2),
+            "{result:?}"
+        );
+    }
+}
@@ -181,12 +181,18 @@ where
     }
 }
 
-impl<Expr, DB> QueryFragment<DB, sql_dialect::default_keyword_for_insert::IsoSqlDefaultKeyword> for DefaultableColumnInsertValue<Expr>
+impl<Col, Expr, DB> QueryFragment<DB, sql_dialect::default_keyword_for_insert::IsoSqlDefaultKeyword> for DefaultableColumnInsertValue<ColumnInsertValue<Col, Expr>>
 where
     DB: Backend + SqlDialect<InsertWithDefaultKeyword = sql_dialect::default_keyword_for_insert::IsoSqlDefaultKeyword>,
-    Expr: QueryFragment<DB>,
+    Col: Column,
+    ColumnInsertValue<Col, Expr>: QueryFragment<DB>,
 {
     fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        // Generated columns are omitted entirely, even if no value is
+        // given, so that all rows of a batch insert have the same columns
+        if Col::GENERATED {
+            return Ok(());
+        }
         out.unsafe_to_cache_prepared();
         if let Self::Expression(ref inner) = *self {
             inner.walk_ast(out.reborrow())?;
@@ -200,9 +206,16 @@ where
 impl<Col, Expr, DB> QueryFragment<DB> for ColumnInsertValue<Col, Expr>
 where
     DB: Backend + DieselReserveSpecialization,
+    Col: Column,
     Expr: QueryFragment<DB>,
 {
-    fn walk_ast<'b>(&'b self, pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+    fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        // Values for database generated columns are skipped, which turns
+        // this into a noop that is left out of the column and value lists
+        if Col::GENERATED {
+            return Ok(());
+        }
+        pass.redact_following_binds(Col::SENSITIVE);
         self.expr.walk_ast(pass)
     }
 }
@@ -230,6 +243,7 @@ impl<Col, Expr>
         crate::backend::sql_dialect::default_keyword_for_insert::DoesNotSupportDefaultKeyword,
     > for DefaultableColumnInsertValue<ColumnInsertValue<Col, Expr>>
 where
+    Col: Column,
     Expr: QueryFragment<crate::sqlite::Sqlite>,
 {
     fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, crate::sqlite::Sqlite>) -> QueryResult<()> {
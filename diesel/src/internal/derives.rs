@@ -4,6 +4,12 @@ pub mod insertable {
     pub use crate::query_builder::insert_statement::UndecoratedInsertRecord;
 }
 
+#[doc(hidden)]
+pub mod empty_string {
+    #[doc(hidden)]
+    pub use crate::types::empty_string::{EmptyStringAsNull, NullAsEmptyString};
+}
+
 #[doc(hidden)]
 pub mod as_expression {
     #[doc(hidden)]
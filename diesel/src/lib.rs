@@ -189,6 +189,15 @@
 //!   mysql backend
 //! - `returning_clauses_for_sqlite_3_35`: This feature enables support for `RETURNING` clauses in the sqlite backend.
 //!   Enabling this feature requires sqlite 3.35.0 or newer.
+//! - `sqlite_load_extension`: This feature enables `SqliteConnection::load_extension` to load run-time
+//!   loadable sqlite extensions. Enabling this feature requires a sqlite build that was not compiled with
+//!   `SQLITE_OMIT_LOAD_EXTENSION`, otherwise linking fails.
+//! - `returning_clauses_for_mariadb`: This feature enables support for `RETURNING` clauses for `INSERT`
+//!   and `DELETE` statements in the mysql backend. Enabling this feature requires MariaDB 10.5 or newer,
+//!   as MySQL does not support `RETURNING` clauses. MariaDB does not support `RETURNING` clauses for
+//!   `UPDATE` statements.
+//! - `distinct_on_for_mysql_8`: This feature enables support for `DISTINCT ON` clauses in the mysql backend.
+//!   The clause is emulated using the `ROW_NUMBER()` window function, which requires MySQL 8.0 or newer.
 //! - `32-column-tables`: This feature enables support for tables with up to 32 columns.
 //!   This feature is enabled by default. Consider disabling this feature if you write a library crate
 //!   providing general extensions for diesel or if you do not need to support tables with more than 16 columns
@@ -222,7 +231,25 @@
 //!   values from the database using types provided by `ipnet`.
 //! - `numeric`: This feature flag enables support for (de)serializing numeric values from the database using types
 //!   provided by `bigdecimal`
+//! - `pgvector`: This feature flag enables support for the `vector` type and the distance operators of the
+//!   PostgreSQL [pgvector](https://github.com/pgvector/pgvector) extension.
+//! - `postgis`: This feature flag enables support for the `geometry` and `geography` types and some spatial
+//!   functions of the PostgreSQL [PostGIS](https://postgis.net/) extension.
 //! - `r2d2`: This feature flag enables support for the `r2d2` connection pool implementation.
+//! - `blocking`: This feature flag enables the `diesel::blocking` module, which allows to run queries
+//!   from async code on threads owning a connection.
+//! - `pool`: This feature flag enables the `diesel::pool` module, a built-in connection pool with
+//!   health checks, connection lifetimes and metrics hooks.
+//! - `async`: This feature flag enables the `diesel::async_connection` module, a spawn-blocking adapter
+//!   which provides async connections, transactions and connection pools for all backends by running
+//!   blocking connections on dedicated threads.
+//! - `fixtures`: This feature flag enables the `diesel::fixtures` module, which loads test data
+//!   from TOML fixture files.
+//! - `otel`: This feature flag enables `diesel::connection::TracingInstrumentation`, which emits
+//!   `tracing` spans following the OpenTelemetry semantic conventions for connections, queries
+//!   and transactions.
+//! - `proptest`: This feature flag enables the `diesel::test_helpers` module, which checks that
+//!   `ToSql`/`FromSql` implementations round trip for values generated by `proptest` strategies.
 //! - `extras`: This feature enables the feature flagged support for any third party crate. This implies the
 //!   following feature flags: `serde_json`, `chrono`, `uuid`, `network-address`, `numeric`, `r2d2`
 //! - `with-deprecated`: This feature enables items marked as `#[deprecated]`. It is enabled by default.
@@ -241,6 +268,12 @@
 //! - `with-deprecated`
 //! - `32-column-tables`
 //! - `std`
+//!
+//! Diesel can be used without enabling any backend feature. This is useful for crates that
+//! only share the [`table!`] definitions, derived types and custom type definitions of an
+//! application, for example to reuse them in a frontend compiled to `wasm32-unknown-unknown`.
+//! Such crates do not link any native database driver. The backend features should then be
+//! enabled by the crates that actually connect to the database.
 
 #![cfg_attr(feature = "unstable", feature(trait_alias))]
 #![cfg_attr(feature = "unstable", feature(strict_provenance_lints))]
@@ -307,11 +340,15 @@ pub mod internal;
 #[macro_use]
 extern crate cfg_if;
 
-#[cfg(test)]
+#[cfg(any(test, feature = "proptest"))]
 pub mod test_helpers;
 
 pub mod associations;
+#[cfg(feature = "async")]
+pub mod async_connection;
 pub mod backend;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod collation;
 pub mod connection;
 pub mod data_types;
@@ -319,6 +356,8 @@ pub mod deserialize;
 #[macro_use]
 pub mod expression;
 pub mod expression_methods;
+#[cfg(feature = "fixtures")]
+pub mod fixtures;
 #[doc(hidden)]
 pub mod insertable;
 pub mod query_builder;
@@ -332,6 +371,8 @@ pub mod upsert;
 #[macro_use]
 pub mod sql_types;
 pub mod migration;
+#[cfg(feature = "pool")]
+pub mod pool;
 pub mod row;
 
 #[cfg(feature = "mysql_backend")]
@@ -357,6 +398,9 @@ pub use diesel_derives::{
 
 pub use diesel_derives::MultiConnection;
 
+#[doc(inline)]
+pub use diesel_derives::diesel_test;
+
 pub mod dsl {
     //! Includes various helper types and bare functions which are named too
     //! generically to be included in prelude, but are often used when using Diesel.
@@ -372,11 +416,12 @@ pub mod dsl {
     #[doc(inline)]
     pub use crate::query_builder::functions::{
         delete, insert_into, insert_or_ignore_into, replace_into, select, sql_query, update,
+        values, with, with_recursive,
     };
 
     #[doc(inline)]
     #[cfg(feature = "postgres_backend")]
-    pub use crate::query_builder::functions::{copy_from, copy_to};
+    pub use crate::query_builder::functions::{copy_from, copy_to, notify};
 
     #[doc(inline)]
     pub use diesel_derives::auto_type;
@@ -453,6 +498,9 @@ pub mod helper_types {
     /// Represents the return type of [`.no_wait()`](crate::prelude::QueryDsl::no_wait)
     pub type NoWait<Source> = <Source as ModifyLockDsl<lock::NoWait>>::Output;
 
+    /// Represents the return type of [`.of(tables)`](crate::prelude::QueryDsl::of)
+    pub type Of<Source, Tables> = <Source as LockTargetDsl<Tables>>::Output;
+
     /// Represents the return type of [`.find(pk)`](crate::prelude::QueryDsl::find)
     pub type Find<Source, PK> = <Source as FindDsl<PK>>::Output;
 
@@ -514,7 +562,7 @@ pub mod helper_types {
     pub type Distinct<Source> = <Source as DistinctDsl>::Output;
 
     /// Represents the return type of [`.distinct_on(expr)`](crate::prelude::QueryDsl::distinct_on)
-    #[cfg(feature = "postgres_backend")]
+    #[cfg(any(feature = "postgres_backend", feature = "distinct_on_for_mysql_8"))]
     pub type DistinctOn<Source, Expr> = <Source as DistinctOnDsl<Expr>>::Output;
 
     /// Represents the return type of [`.single_value()`](SingleValueDsl::single_value)
@@ -700,6 +748,22 @@ pub mod helper_types {
     #[allow(non_camel_case_types)] // required for `#[auto_type]`
     pub type insert_into<T> = crate::query_builder::IncompleteInsertStatement<T>;
 
+    /// Represents the return type of [`diesel::dsl::with`](crate::dsl::with())
+    #[allow(non_camel_case_types)] // required for `#[auto_type]`
+    pub type with<R, Q> = crate::query_builder::IncompleteWithQuery<
+        crate::query_builder::CommonTableExpression<R, <Q as AsQuery>::Query>,
+    >;
+
+    /// Represents the return type of [`diesel::dsl::with_recursive`](crate::dsl::with_recursive())
+    #[allow(non_camel_case_types)] // required for `#[auto_type]`
+    pub type with_recursive<R, Q> =
+        crate::query_builder::IncompleteRecursiveCte<R, <Q as AsQuery>::Query>;
+
+    /// Represents the return type of [`diesel::dsl::values`](crate::dsl::values())
+    #[allow(non_camel_case_types)] // required for `#[auto_type]`
+    pub type values<ST, T> =
+        crate::query_builder::ValuesList<ST, <T as crate::query_builder::IntoValuesRows<ST>>::Rows>;
+
     /// Represents the return type of [`diesel::update`]
     #[allow(non_camel_case_types)] // required for `#[auto_type]`
     pub type update<T> =
@@ -801,7 +865,7 @@ pub mod prelude {
     #[doc(inline)]
     pub use crate::connection::Connection;
     #[doc(inline)]
-    pub use crate::deserialize::{Queryable, QueryableByName};
+    pub use crate::deserialize::{Queryable, QueryableByName, QueryableInto};
     #[doc(inline)]
     pub use crate::expression::{
         AppearsOnTable, BoxableExpression, Expression, IntoSql, Selectable, SelectableExpression,
@@ -836,6 +900,7 @@ pub mod prelude {
     #[doc(inline)]
     pub use crate::query_dsl::{
         BelongingToDsl, CombineDsl, JoinOnDsl, QueryDsl, RunQueryDsl, SaveChangesDsl,
+        StatementCacheDsl,
     };
     pub use crate::query_source::SizeRestrictedColumn as _;
     #[doc(inline)]
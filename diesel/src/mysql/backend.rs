@@ -74,7 +74,10 @@ impl TypeMetadata for Mysql {
 }
 
 impl SqlDialect for Mysql {
+    #[cfg(not(feature = "returning_clauses_for_mariadb"))]
     type ReturningClause = sql_dialect::returning_clause::DoesNotSupportReturningClause;
+    #[cfg(feature = "returning_clauses_for_mariadb")]
+    type ReturningClause = MariaDbReturningClause;
 
     type OnConflictClause = MysqlOnConflictClause;
 
@@ -101,6 +104,8 @@ impl SqlDialect for Mysql {
         sql_dialect::aggregate_function_expressions::NoAggregateFunctionExpressions;
 
     type BuiltInWindowFunctionRequireOrder = MysqlRequiresOrderForWindowFunctions;
+
+    type IntervalArithmetic = MysqlIntervalArithmetic;
 }
 
 impl DieselReserveSpecialization for Mysql {}
@@ -109,6 +114,13 @@ impl TrustedBackend for Mysql {}
 #[derive(Debug, Clone, Copy)]
 pub struct MysqlStyleDefaultValueClause;
 
+/// MariaDB supports `RETURNING` clauses for `INSERT` and `DELETE`
+/// statements, but not for `UPDATE` statements
+#[derive(Debug, Clone, Copy)]
+pub struct MariaDbReturningClause;
+
+impl sql_dialect::returning_clause::SupportsReturningClause for MariaDbReturningClause {}
+
 #[derive(Debug, Clone, Copy)]
 pub struct MysqlConcatClause;
 
@@ -118,4 +130,7 @@ pub struct MysqlOnConflictClause;
 #[derive(Debug, Clone, Copy)]
 pub struct MysqlRequiresOrderForWindowFunctions;
 
+#[derive(Debug, Clone, Copy)]
+pub struct MysqlIntervalArithmetic;
+
 impl SupportsOnConflictClause for MysqlOnConflictClause {}
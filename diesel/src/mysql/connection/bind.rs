@@ -22,6 +22,10 @@ fn bind_buffer(data: Vec<u8>) -> (Option<NonNull<u8>>, libc::c_ulong, usize) {
     )
 }
 
+/// Text and binary parameters larger than this are sent to the server
+/// in chunks of this size before the statement is executed
+pub(super) const LONG_DATA_CHUNK_SIZE: usize = 1024 * 1024;
+
 pub(super) struct PreparedStatementBinds(Binds);
 
 pub(super) struct OutputBinds(Binds);
@@ -57,6 +61,16 @@ impl PreparedStatementBinds {
     {
         self.0.with_mysql_binds(f)
     }
+
+    /// Returns the index and the data of each parameter that is too
+    /// large to be copied into the packet executing the statement
+    pub(super) fn long_data(&self) -> impl Iterator<Item = (usize, &[u8])> {
+        self.0
+            .data
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, bind)| Some((idx, bind.long_data()?)))
+    }
 }
 
 impl OutputBinds {
@@ -488,6 +502,28 @@ impl BindData {
         }
     }
 
+    fn long_data(&self) -> Option<&[u8]> {
+        use ffi::enum_field_types as t;
+
+        let is_byte_string = matches!(
+            self.tpe,
+            t::MYSQL_TYPE_TINY_BLOB
+                | t::MYSQL_TYPE_MEDIUM_BLOB
+                | t::MYSQL_TYPE_LONG_BLOB
+                | t::MYSQL_TYPE_BLOB
+                | t::MYSQL_TYPE_VAR_STRING
+                | t::MYSQL_TYPE_STRING
+        );
+        let length = usize::try_from(self.length).ok()?;
+        if self.is_null() || !is_byte_string || length <= LONG_DATA_CHUNK_SIZE {
+            return None;
+        }
+        let bytes = self.bytes?;
+        // We know that this points to an input buffer of `length` bytes,
+        // as input binds are never resized
+        Some(unsafe { core::slice::from_raw_parts(bytes.as_ptr(), length) })
+    }
+
     pub(super) fn is_null(&self) -> bool {
         self.is_null != ffi::my_bool::default()
     }
@@ -950,7 +986,7 @@ mod tests {
             ),
             &mut conn.statement_cache,
             &mut conn.raw_connection,
-            &mut *conn.instrumentation,
+            conn.instrumentation.tracked(),
         ).unwrap();
 
         let metadata = stmt.metadata().unwrap();
@@ -1885,4 +1921,41 @@ mod tests {
             set_col_as_text.value().unwrap().as_bytes()
         );
     }
+
+    #[diesel_test_helper::test]
+    fn large_binds_are_sent_in_chunks() {
+        #[derive(QueryableByName)]
+        struct LongData {
+            #[diesel(sql_type = Binary)]
+            data: Vec<u8>,
+        }
+
+        let conn = &mut crate::test_helpers::connection();
+
+        crate::sql_query("DROP TABLE IF EXISTS long_data_test CASCADE")
+            .execute(conn)
+            .unwrap();
+        crate::sql_query(
+            "CREATE TABLE long_data_test(id INTEGER PRIMARY KEY, data LONGBLOB NOT NULL)",
+        )
+        .execute(conn)
+        .unwrap();
+
+        let data = (0..LONG_DATA_CHUNK_SIZE * 2 + 42)
+            .map(|i| u8::try_from(i % 251).unwrap())
+            .collect::<Vec<_>>();
+        for id in 0..2 {
+            crate::sql_query("INSERT INTO long_data_test(id, data) VALUES (?, ?)")
+                .bind::<Integer, _>(id)
+                .bind::<Binary, _>(&data)
+                .execute(conn)
+                .unwrap();
+        }
+
+        let loaded = crate::sql_query("SELECT data FROM long_data_test ORDER BY id")
+            .load::<LongData>(conn)
+            .unwrap();
+        assert_eq!(loaded.len(), 2);
+        assert!(loaded.iter().all(|row| row.data == data));
+    }
 }
@@ -0,0 +1,242 @@
+use super::{MysqlConnection, update_transaction_manager_status};
+use crate::connection::InstrumentationEvent;
+use crate::mysql::{Mysql, MysqlQueryBuilder};
+use crate::query_builder::nodes::StaticQueryFragment;
+use crate::query_builder::{AstPass, ColumnList, QueryBuilder, QueryFragment};
+use crate::result::QueryResult;
+use crate::{Column, Table};
+
+/// A table or a tuple of columns that can be used as target for
+/// [`MysqlConnection::load_data_local_infile`]
+///
+/// This trait is implemented for any table type and for tuples of columns from the same table
+pub trait LoadDataTarget {
+    /// The table targeted by the statement
+    type Table: Table;
+
+    #[doc(hidden)]
+    fn walk_table(pass: AstPass<'_, '_, Mysql>) -> QueryResult<()>;
+
+    #[doc(hidden)]
+    fn walk_columns(pass: AstPass<'_, '_, Mysql>) -> QueryResult<()>;
+}
+
+impl<T> LoadDataTarget for T
+where
+    T: Table + StaticQueryFragment,
+    T::AllColumns: ColumnList,
+    T::Component: QueryFragment<Mysql>,
+{
+    type Table = Self;
+
+    fn walk_table(pass: AstPass<'_, '_, Mysql>) -> QueryResult<()> {
+        T::STATIC_COMPONENT.walk_ast(pass)
+    }
+
+    fn walk_columns(pass: AstPass<'_, '_, Mysql>) -> QueryResult<()> {
+        T::all_columns().walk_ast(pass)
+    }
+}
+
+macro_rules! load_data_target_for_columns {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST:ident, $TT:ident,)+
+        }
+    )+) => {
+        $(
+            impl<T, $($ST,)*> LoadDataTarget for ($($ST,)*)
+            where
+                $($ST: Column<Table = T> + Default,)*
+                T: Table + StaticQueryFragment,
+                T::Component: QueryFragment<Mysql>,
+                Self: ColumnList,
+            {
+                type Table = T;
+
+                fn walk_table(pass: AstPass<'_, '_, Mysql>) -> QueryResult<()> {
+                    T::STATIC_COMPONENT.walk_ast(pass)
+                }
+
+                fn walk_columns(pass: AstPass<'_, '_, Mysql>) -> QueryResult<()> {
+                    <Self as ColumnList>::walk_ast(&($($ST::default(),)*), pass)
+                }
+            }
+        )*
+    }
+}
+
+crate::for_each_tuple!(load_data_target_for_columns);
+
+/// The `LOAD DATA LOCAL INFILE` statement executed by
+/// [`MysqlLoadDataLocalInfile::execute`]
+#[derive(Debug)]
+struct LoadDataQuery<T> {
+    fields_terminated_by: Option<String>,
+    fields_enclosed_by: Option<char>,
+    fields_escaped_by: Option<char>,
+    lines_starting_by: Option<String>,
+    lines_terminated_by: Option<String>,
+    ignore_lines: Option<u64>,
+    target: core::marker::PhantomData<T>,
+}
+
+impl<T> QueryFragment<Mysql> for LoadDataQuery<T>
+where
+    T: LoadDataTarget,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Mysql>) -> QueryResult<()> {
+        // the file name is never opened, as the data
+        // is provided by our own local infile handler
+        out.push_sql("LOAD DATA LOCAL INFILE 'diesel' INTO TABLE ");
+        T::walk_table(out.reborrow())?;
+        if self.fields_terminated_by.is_some()
+            || self.fields_enclosed_by.is_some()
+            || self.fields_escaped_by.is_some()
+        {
+            out.push_sql(" FIELDS");
+            if let Some(ref terminated_by) = self.fields_terminated_by {
+                out.push_sql(" TERMINATED BY ");
+                push_literal(&mut out, terminated_by);
+            }
+            if let Some(enclosed_by) = self.fields_enclosed_by {
+                out.push_sql(" ENCLOSED BY ");
+                push_literal(&mut out, enclosed_by.encode_utf8(&mut [0; 4]));
+            }
+            if let Some(escaped_by) = self.fields_escaped_by {
+                out.push_sql(" ESCAPED BY ");
+                push_literal(&mut out, escaped_by.encode_utf8(&mut [0; 4]));
+            }
+        }
+        if self.lines_starting_by.is_some() || self.lines_terminated_by.is_some() {
+            out.push_sql(" LINES");
+            if let Some(ref starting_by) = self.lines_starting_by {
+                out.push_sql(" STARTING BY ");
+                push_literal(&mut out, starting_by);
+            }
+            if let Some(ref terminated_by) = self.lines_terminated_by {
+                out.push_sql(" TERMINATED BY ");
+                push_literal(&mut out, terminated_by);
+            }
+        }
+        if let Some(ignore_lines) = self.ignore_lines {
+            out.push_sql(&format!(" IGNORE {ignore_lines} LINES"));
+        }
+        out.push_sql(" (");
+        T::walk_columns(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+// Strings are written as hexadecimal literals, so that we do not need to care
+// about escaping them, which depends on the `NO_BACKSLASH_ESCAPES` sql mode
+fn push_literal(out: &mut AstPass<'_, '_, Mysql>, value: &str) {
+    let mut literal = String::from("X'");
+    for b in value.bytes() {
+        literal.push_str(&format!("{b:02x}"));
+    }
+    literal.push('\'');
+    out.push_sql(&literal);
+}
+
+/// A `LOAD DATA LOCAL INFILE` statement that reads its data from a
+/// [`std::io::Read`] source
+///
+/// The field and line options correspond to the clauses with the same name
+/// described in the
+/// [MySQL documentation](https://dev.mysql.com/doc/refman/8.4/en/load-data.html).
+/// Options which are not set use the server side defaults, which means fields
+/// are terminated by tabs and lines by `\n`.
+///
+/// You can use [`MysqlConnection::load_data_local_infile`] to get a new
+/// instance of this type.
+#[allow(missing_debug_implementations)] // `MysqlConnection` is not debug
+#[must_use = "Queries are only executed when calling `execute`"]
+pub struct MysqlLoadDataLocalInfile<'conn, T> {
+    conn: &'conn mut MysqlConnection,
+    query: LoadDataQuery<T>,
+}
+
+impl<'conn, T> MysqlLoadDataLocalInfile<'conn, T>
+where
+    T: LoadDataTarget,
+{
+    pub(super) fn new(conn: &'conn mut MysqlConnection) -> Self {
+        Self {
+            conn,
+            query: LoadDataQuery {
+                fields_terminated_by: None,
+                fields_enclosed_by: None,
+                fields_escaped_by: None,
+                lines_starting_by: None,
+                lines_terminated_by: None,
+                ignore_lines: None,
+                target: core::marker::PhantomData,
+            },
+        }
+    }
+
+    /// The string separating the fields of a row
+    pub fn fields_terminated_by(mut self, terminated_by: &str) -> Self {
+        self.query.fields_terminated_by = Some(terminated_by.into());
+        self
+    }
+
+    /// The character used to quote fields
+    pub fn fields_enclosed_by(mut self, enclosed_by: char) -> Self {
+        self.query.fields_enclosed_by = Some(enclosed_by);
+        self
+    }
+
+    /// The character used to escape special characters in fields
+    pub fn fields_escaped_by(mut self, escaped_by: char) -> Self {
+        self.query.fields_escaped_by = Some(escaped_by);
+        self
+    }
+
+    /// The prefix which is skipped at the start of each line
+    pub fn lines_starting_by(mut self, starting_by: &str) -> Self {
+        self.query.lines_starting_by = Some(starting_by.into());
+        self
+    }
+
+    /// The string separating the rows
+    pub fn lines_terminated_by(mut self, terminated_by: &str) -> Self {
+        self.query.lines_terminated_by = Some(terminated_by.into());
+        self
+    }
+
+    /// The number of lines to skip at the start of the data, for example
+    /// to skip a header line
+    pub fn ignore_lines(mut self, lines: u64) -> Self {
+        self.query.ignore_lines = Some(lines);
+        self
+    }
+
+    /// Executes the statement with the data read from `reader`
+    ///
+    /// Returns the number of inserted rows. Errors returned by `reader` abort
+    /// the statement and are returned as
+    /// [`Error::SerializationError`](crate::result::Error::SerializationError).
+    pub fn execute<R>(self, mut reader: R) -> QueryResult<usize>
+    where
+        R: std::io::Read,
+    {
+        let Self { conn, query } = self;
+        let mut query_builder = MysqlQueryBuilder::new();
+        query.to_sql(&mut query_builder, &Mysql)?;
+        let sql = query_builder.finish();
+
+        conn.instrumentation
+            .on_connection_event(InstrumentationEvent::StartQuery {
+                query: &crate::debug_query(&query),
+            });
+        update_transaction_manager_status(
+            conn.raw_connection.load_local_infile(&sql, &mut reader),
+            &mut conn.transaction_state,
+            &mut conn.instrumentation,
+            &crate::debug_query(&query),
+        )
+    }
+}
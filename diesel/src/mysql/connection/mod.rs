@@ -1,17 +1,25 @@
 mod bind;
+mod load_data;
+mod procedure;
 mod raw;
 mod stmt;
+mod upsert;
 mod url;
 
+pub use self::load_data::{LoadDataTarget, MysqlLoadDataLocalInfile};
+use self::procedure::CallProcedure;
+pub use self::procedure::MysqlProcedureResults;
 use self::raw::RawConnection;
 use self::stmt::Statement;
-use self::stmt::iterator::StatementIterator;
+use self::stmt::iterator::{RowByRowStatementIterator, StatementIterator};
+pub use self::upsert::MysqlUpsertOutcome;
 use self::url::ConnectionOptions;
-use super::backend::Mysql;
+use super::backend::{Mysql, MysqlType};
 use crate::RunQueryDsl;
 use crate::connection::instrumentation::{DebugQuery, DynInstrumentation, StrQueryHelper};
 use crate::connection::statement_cache::{MaybeCached, StatementCache};
 use crate::connection::*;
+use crate::deserialize::FromSqlRow;
 use crate::expression::QueryMetadata;
 use crate::query_builder::bind_collector::RawBytesBindCollector;
 use crate::query_builder::*;
@@ -42,8 +50,8 @@ use crate::result::*;
 ///
 /// ## DefaultLoadingMode
 ///
-/// `MysqlConnection` only supports a single loading mode, which loads
-/// values row by row from the result set.
+/// `MysqlConnection` only supports a single loading mode, which receives
+/// the complete result set at once and loads values row by row from it.
 ///
 /// ```rust
 /// # include!("../../doctest_setup.rs");
@@ -77,8 +85,10 @@ use crate::result::*;
 /// # }
 /// ```
 ///
-/// This mode does **not support** creating
-/// multiple iterators using the same connection.
+/// Huge result sets can be received row by row from the server via
+/// [`MysqlConnection::load_iter_row_by_row`] instead.
+///
+/// Neither way supports creating multiple iterators using the same connection.
 ///
 /// ```compile_fail
 /// # include!("../../doctest_setup.rs");
@@ -128,10 +138,10 @@ impl SimpleConnection for MysqlConnection {
             .raw_connection
             .enable_multi_statements(|| self.raw_connection.execute(query));
         self.instrumentation
-            .on_connection_event(InstrumentationEvent::FinishQuery {
-                query: &StrQueryHelper::new(query),
-                error: r.as_ref().err(),
-            });
+            .on_connection_event(InstrumentationEvent::finish_query(
+                &StrQueryHelper::new(query),
+                r.as_ref().err(),
+            ));
         r
     }
 }
@@ -182,7 +192,7 @@ impl Connection for MysqlConnection {
                 &source,
                 &mut self.statement_cache,
                 &mut self.raw_connection,
-                &mut *self.instrumentation,
+                self.instrumentation.tracked(),
             )
             .and_then(|stmt| {
                 // we have not called result yet, so calling `execute` is
@@ -196,6 +206,40 @@ impl Connection for MysqlConnection {
         )
     }
 
+    fn execute_returning_count_with_warnings<T>(
+        &mut self,
+        source: &T,
+    ) -> QueryResult<(usize, Vec<DatabaseWarning>)>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        let count = self.execute_returning_count(source)?;
+        let warnings = self.raw_connection.warnings()?;
+        if !warnings.is_empty() {
+            self.instrumentation
+                .on_connection_event(InstrumentationEvent::QueryWarnings {
+                    query: &crate::debug_query(source),
+                    warnings: &warnings,
+                });
+        }
+        Ok((count, warnings))
+    }
+
+    fn begin_transaction_with_sql(
+        &mut self,
+        isolation: Isolation,
+        access: Access,
+    ) -> QueryResult<alloc::borrow::Cow<'static, str>> {
+        // `SET TRANSACTION` only applies to the next transaction
+        // started by this session
+        Ok(alloc::format!(
+            "SET TRANSACTION ISOLATION LEVEL {}; START TRANSACTION {}",
+            isolation.to_sql(),
+            access.to_sql()
+        )
+        .into())
+    }
+
     fn transaction_state(&mut self) -> &mut AnsiTransactionManager {
         &mut self.transaction_state
     }
@@ -211,6 +255,18 @@ impl Connection for MysqlConnection {
     fn set_prepared_statement_cache_size(&mut self, size: CacheSize) {
         self.statement_cache.set_cache_size(size);
     }
+
+    fn clear_prepared_statement_cache(&mut self) {
+        self.statement_cache.clear();
+    }
+
+    fn cached_statements(&self) -> Vec<String> {
+        self.statement_cache
+            .cached_sql()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
 }
 
 #[inline(always)]
@@ -232,10 +288,10 @@ fn update_transaction_manager_status<T>(
                 .status
                 .set_requires_rollback_maybe_up_to_top_level(true)
         }
-        instrumentation.on_connection_event(InstrumentationEvent::FinishQuery {
+        instrumentation.on_connection_event(InstrumentationEvent::finish_query(
             query,
-            error: query_result.err(),
-        });
+            query_result.err(),
+        ));
     }
 
     non_generic_inner(
@@ -259,22 +315,7 @@ impl LoadConnection<DefaultLoadingMode> for MysqlConnection {
         T: Query + QueryFragment<Self::Backend> + QueryId + 'query,
         Self::Backend: QueryMetadata<T::SqlType>,
     {
-        update_transaction_manager_status(
-            prepared_query(
-                &source,
-                &mut self.statement_cache,
-                &mut self.raw_connection,
-                &mut *self.instrumentation,
-            )
-            .and_then(|stmt| {
-                let mut metadata = Vec::new();
-                Mysql::row_metadata(&mut (), &mut metadata);
-                StatementIterator::from_stmt(stmt, &metadata)
-            }),
-            &mut self.transaction_state,
-            &mut self.instrumentation,
-            &crate::debug_query(&source),
-        )
+        self.load_with(source, StatementIterator::from_stmt)
     }
 }
 
@@ -332,6 +373,295 @@ fn prepared_query<'a, T: QueryFragment<Mysql> + QueryId>(
 }
 
 impl MysqlConnection {
+    /// Build a transaction, specifying additional details such as isolation level
+    ///
+    /// See [`TransactionBuilder`] for more examples.
+    ///
+    /// [`TransactionBuilder`]: crate::mysql::TransactionBuilder
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_data();
+    /// conn.build_transaction()
+    ///     .read_only()
+    ///     .serializable()
+    ///     .run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn build_transaction(&mut self) -> crate::mysql::TransactionBuilder<'_, Self> {
+        crate::mysql::TransactionBuilder::new(self)
+    }
+
+    /// Calls the stored procedure with the given name
+    ///
+    /// `params` is a tuple of the values passed to the procedure, usually
+    /// created via [`IntoSql::into_sql`](crate::IntoSql::into_sql()). `OUT`
+    /// and `INOUT` parameters need a value as well, their values are
+    /// returned as last result set. Use `()` for procedures without parameters.
+    ///
+    /// The returned [`MysqlProcedureResults`] allows to load the result sets
+    /// returned by the procedure one after another.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let connection = &mut establish_connection();
+    /// use diesel::connection::SimpleConnection;
+    /// use diesel::sql_types::{Integer, Nullable, Text};
+    ///
+    /// #[derive(QueryableByName, Debug, PartialEq)]
+    /// struct User {
+    ///     #[diesel(sql_type = Text)]
+    ///     name: String,
+    /// }
+    ///
+    /// #[derive(QueryableByName, Debug, PartialEq)]
+    /// struct UserCount {
+    ///     #[diesel(sql_type = Integer)]
+    ///     user_count: i32,
+    /// }
+    ///
+    /// connection.batch_execute(
+    ///     "DROP PROCEDURE IF EXISTS users_from;
+    ///      CREATE PROCEDURE users_from(IN min_id INTEGER, OUT user_count INTEGER)
+    ///      BEGIN
+    ///          SELECT name FROM users WHERE id >= min_id ORDER BY id;
+    ///          SELECT COUNT(*) INTO user_count FROM users WHERE id >= min_id;
+    ///      END",
+    /// )?;
+    ///
+    /// let mut results = connection.call_procedure(
+    ///     "users_from",
+    ///     (1.into_sql::<Integer>(), None::<i32>.into_sql::<Nullable<Integer>>()),
+    /// )?;
+    ///
+    /// let users = results.next_result_set::<User>()?;
+    /// let expected_users = vec![
+    ///     User { name: "Sean".into() },
+    ///     User { name: "Tess".into() },
+    /// ];
+    /// assert_eq!(Some(expected_users), users);
+    ///
+    /// let out_params = results.next_result_set::<UserCount>()?;
+    /// assert_eq!(Some(vec![UserCount { user_count: 2 }]), out_params);
+    ///
+    /// assert_eq!(None, results.next_result_set::<UserCount>()?);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn call_procedure<P>(
+        &mut self,
+        procedure: &str,
+        params: P,
+    ) -> QueryResult<MysqlProcedureResults<'_>>
+    where
+        P: QueryFragment<Mysql>,
+    {
+        let call = CallProcedure { procedure, params };
+        #[allow(unsafe_code)] // call to unsafe function
+        update_transaction_manager_status(
+            prepared_query(
+                &call,
+                &mut self.statement_cache,
+                &mut self.raw_connection,
+                self.instrumentation.tracked(),
+            )
+            .and_then(|stmt| {
+                // we have not called result yet, so calling `execute` is
+                // fine
+                let stmt_use = unsafe { stmt.execute() }?;
+                Ok(MysqlProcedureResults::new(stmt_use))
+            }),
+            &mut self.transaction_state,
+            &mut self.instrumentation,
+            &crate::debug_query(&call),
+        )
+    }
+
+    /// Executes the given `INSERT … ON DUPLICATE KEY UPDATE` statement
+    ///
+    /// In contrast to [`RunQueryDsl::execute`] this returns a
+    /// [`MysqlUpsertOutcome`], which allows to distinguish inserted rows from
+    /// updated ones. See the documentation of [`MysqlUpsertOutcome`] for
+    /// details about how these values are determined.
+    ///
+    /// ## Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let new_users = vec![(id.eq(1), name.eq("Sean")), (id.eq(3), name.eq("Jim"))];
+    /// let upsert = diesel::insert_into(users)
+    ///     .values(&new_users)
+    ///     .on_conflict(diesel::dsl::DuplicatedKeys)
+    ///     .do_update()
+    ///     .set(name.eq("Sean"));
+    /// let outcome = connection.execute_upsert(upsert)?;
+    ///
+    /// assert_eq!(1, outcome.inserted());
+    /// assert_eq!(0, outcome.updated());
+    /// assert_eq!(1, outcome.unchanged());
+    ///
+    /// let upsert = diesel::insert_into(users)
+    ///     .values((id.eq(2), name.eq("Tess")))
+    ///     .on_conflict(diesel::dsl::DuplicatedKeys)
+    ///     .do_update()
+    ///     .set(name.eq("Ruby"));
+    /// let outcome = connection.execute_upsert(upsert)?;
+    ///
+    /// assert_eq!(0, outcome.inserted());
+    /// assert_eq!(1, outcome.updated());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn execute_upsert<T>(&mut self, query: T) -> QueryResult<MysqlUpsertOutcome>
+    where
+        T: QueryFragment<Mysql> + QueryId,
+    {
+        let affected_rows = self.execute_returning_count(&query)?;
+        let info = self.raw_connection.info();
+        Ok(MysqlUpsertOutcome::new(affected_rows, info.as_deref()))
+    }
+
+    /// Loads data from the given [`std::io::Read`] source into `target` via
+    /// `LOAD DATA LOCAL INFILE`
+    ///
+    /// `target` is either a table or a tuple of columns of the same table,
+    /// which are filled with the fields of each row in the given order.
+    /// The returned [`MysqlLoadDataLocalInfile`] allows to configure how fields
+    /// and lines are separated before executing the statement with the actual
+    /// data. This is considerably faster than inserting a large number of rows
+    /// via [`insert_into`](crate::insert_into()).
+    ///
+    /// This requires that the server allows loading local data by setting the
+    /// `local_infile` system variable and that the connection was established
+    /// with `local_infile=true`.
+    ///
+    /// ## Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users;
+    /// #     let connection = &mut establish_connection();
+    /// let data = "3,Jim\n4,Ruby\n";
+    ///
+    /// let count = connection
+    ///     .load_data_local_infile((users::id, users::name))
+    ///     .fields_terminated_by(",")
+    ///     .lines_terminated_by("\n")
+    ///     .execute(data.as_bytes())?;
+    /// assert_eq!(2, count);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn load_data_local_infile<T>(&mut self, target: T) -> MysqlLoadDataLocalInfile<'_, T>
+    where
+        T: LoadDataTarget,
+    {
+        let _ = target;
+        MysqlLoadDataLocalInfile::new(self)
+    }
+
+    /// Executes the given query and receives the resulting rows one by one
+    /// from the server while iterating
+    ///
+    /// Contrary to [`RunQueryDsl::load_iter`], which receives the complete
+    /// result set at once and buffers it on the client side, this keeps only a
+    /// single row in memory. This might be desired for huge result sets. As long
+    /// as the iterator is not exhausted, the server keeps the rows of the result
+    /// set around, so you should process the rows without too much delay. Rows
+    /// that were not received yet are discarded once the iterator is dropped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users;
+    /// #     let connection = &mut establish_connection();
+    /// let iter = connection.load_iter_row_by_row::<_, (i32, String)>(users::table)?;
+    ///
+    /// for r in iter {
+    ///     let (id, name) = r?;
+    ///     println!("Id: {} Name: {}", id, name);
+    /// }
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn load_iter_row_by_row<'conn, Q, U>(
+        &'conn mut self,
+        query: Q,
+    ) -> QueryResult<impl Iterator<Item = QueryResult<U>> + 'conn>
+    where
+        Q: AsQuery,
+        Q::Query: QueryFragment<Mysql> + QueryId,
+        Mysql: QueryMetadata<Q::SqlType>,
+        U: FromSqlRow<Q::SqlType, Mysql> + 'conn,
+    {
+        let rows = self.load_with(query.as_query(), RowByRowStatementIterator::from_stmt)?;
+        Ok(rows.map(|row| {
+            U::build_from_row(&row?).map_err(crate::result::Error::DeserializationError)
+        }))
+    }
+
+    fn load_with<'conn, T, C>(
+        &'conn mut self,
+        source: T,
+        cursor: impl FnOnce(MaybeCached<'conn, Statement>, &[Option<MysqlType>]) -> QueryResult<C>,
+    ) -> QueryResult<C>
+    where
+        T: Query + QueryFragment<Mysql> + QueryId,
+        Mysql: QueryMetadata<T::SqlType>,
+    {
+        update_transaction_manager_status(
+            prepared_query(
+                &source,
+                &mut self.statement_cache,
+                &mut self.raw_connection,
+                self.instrumentation.tracked(),
+            )
+            .and_then(|stmt| {
+                let mut metadata = Vec::new();
+                Mysql::row_metadata(&mut (), &mut metadata);
+                cursor(stmt, &metadata)
+            }),
+            &mut self.transaction_state,
+            &mut self.instrumentation,
+            &crate::debug_query(&source),
+        )
+    }
+
     fn set_config_options(&mut self) -> QueryResult<()> {
         crate::sql_query("SET time_zone = '+00:00';").execute(self)?;
         crate::sql_query("SET character_set_client = 'utf8mb4'").execute(self)?;
@@ -396,6 +726,154 @@ mod tests {
         assert!(crate::sql_query("SELECT 1").execute(connection).is_ok());
     }
 
+    #[diesel_test_helper::test]
+    fn call_procedure_returns_all_result_sets() {
+        use crate::sql_types::{Integer, Nullable};
+        use crate::{IntoSql, QueryableByName};
+
+        #[derive(QueryableByName, Debug, PartialEq)]
+        #[diesel(check_for_backend(Mysql))]
+        struct Value {
+            #[diesel(sql_type = Integer)]
+            value: i32,
+        }
+
+        let connection = &mut connection();
+        connection
+            .batch_execute(
+                "DROP PROCEDURE IF EXISTS multiple_results;
+                 CREATE PROCEDURE multiple_results(IN a INTEGER, INOUT value INTEGER)
+                 BEGIN
+                     SELECT a AS value UNION ALL SELECT a + 1;
+                     SELECT value AS value;
+                     SET value = a * 10;
+                 END",
+            )
+            .unwrap();
+
+        let mut results = connection
+            .call_procedure(
+                "multiple_results",
+                (
+                    1.into_sql::<Integer>(),
+                    Some(5).into_sql::<Nullable<Integer>>(),
+                ),
+            )
+            .unwrap();
+        assert_eq!(
+            Some(vec![Value { value: 1 }, Value { value: 2 }]),
+            results.next_result_set().unwrap()
+        );
+        assert_eq!(
+            Some(vec![Value { value: 5 }]),
+            results.next_result_set().unwrap()
+        );
+        assert_eq!(
+            Some(vec![Value { value: 10 }]),
+            results.next_result_set().unwrap()
+        );
+        assert_eq!(None, results.next_result_set::<Value>().unwrap());
+        drop(results);
+
+        // results that are not loaded are discarded
+        let results = connection
+            .call_procedure(
+                "multiple_results",
+                (
+                    1.into_sql::<Integer>(),
+                    None::<i32>.into_sql::<Nullable<Integer>>(),
+                ),
+            )
+            .unwrap();
+        drop(results);
+        assert!(crate::sql_query("SELECT 1").execute(connection).is_ok());
+    }
+
+    #[diesel_test_helper::test]
+    fn load_data_local_infile_inserts_all_rows() {
+        use crate::{ExpressionMethods, QueryDsl};
+
+        struct FailingReader;
+
+        impl std::io::Read for FailingReader {
+            fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+                Err(std::io::Error::other("reader failed"))
+            }
+        }
+
+        crate::table! {
+            load_data_test {
+                id -> Integer,
+                name -> Text,
+                note -> Nullable<Text>,
+            }
+        }
+
+        dotenvy::dotenv().ok();
+        let database_url = env::var("MYSQL_UNIT_TEST_DATABASE_URL")
+            .or_else(|_| env::var("MYSQL_DATABASE_URL"))
+            .or_else(|_| env::var("DATABASE_URL"))
+            .expect("DATABASE_URL must be set in order to run unit tests");
+        let separator = if database_url.contains('?') { '&' } else { '?' };
+        let connection =
+            &mut MysqlConnection::establish(&format!("{database_url}{separator}local_infile=true"))
+                .unwrap();
+        connection
+            .batch_execute(
+                "SET GLOBAL local_infile = 1;
+                 DROP TABLE IF EXISTS load_data_test;
+                 CREATE TABLE load_data_test(
+                     id INTEGER PRIMARY KEY,
+                     name TEXT NOT NULL,
+                     note TEXT
+                 );",
+            )
+            .unwrap();
+
+        let data = "id;name\r\n1;\"Sean; Jr\"\r\n2;Tess\r\n";
+        let count = connection
+            .load_data_local_infile((load_data_test::id, load_data_test::name))
+            .fields_terminated_by(";")
+            .fields_enclosed_by('"')
+            .lines_terminated_by("\r\n")
+            .ignore_lines(1)
+            .execute(data.as_bytes())
+            .unwrap();
+        assert_eq!(2, count);
+
+        let count = connection
+            .load_data_local_infile(load_data_test::table)
+            .execute("3\tJim\tnew\n".as_bytes())
+            .unwrap();
+        assert_eq!(1, count);
+
+        let rows = load_data_test::table
+            .order(load_data_test::id)
+            .load::<(i32, String, Option<String>)>(connection)
+            .unwrap();
+        assert_eq!(
+            vec![
+                (1, "Sean; Jr".to_owned(), None),
+                (2, "Tess".to_owned(), None),
+                (3, "Jim".to_owned(), Some("new".to_owned())),
+            ],
+            rows
+        );
+
+        let result = connection
+            .load_data_local_infile(load_data_test::table)
+            .execute(FailingReader);
+        assert!(matches!(result, Err(Error::SerializationError(_))));
+
+        // the connection is still usable afterwards
+        let count = load_data_test::table
+            .filter(load_data_test::id.gt(1))
+            .count()
+            .get_result::<i64>(connection)
+            .unwrap();
+        assert_eq!(2, count);
+    }
+
     #[diesel_test_helper::test]
     fn check_client_found_rows_flag() {
         let conn = &mut crate::test_helpers::connection();
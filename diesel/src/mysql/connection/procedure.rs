@@ -0,0 +1,96 @@
+use super::stmt::StatementUse;
+use super::stmt::iterator::load_current_result_set;
+use crate::deserialize::QueryableByName;
+use crate::mysql::Mysql;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+
+/// The ``CALL `procedure`(params)`` statement executed by
+/// [`MysqlConnection::call_procedure`](super::MysqlConnection::call_procedure)
+#[derive(Debug, Clone, Copy)]
+pub(super) struct CallProcedure<'a, P> {
+    pub(super) procedure: &'a str,
+    pub(super) params: P,
+}
+
+impl<P> QueryId for CallProcedure<'_, P> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<P> QueryFragment<Mysql> for CallProcedure<'_, P>
+where
+    P: QueryFragment<Mysql>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Mysql>) -> QueryResult<()> {
+        out.push_sql("CALL ");
+        out.push_identifier(self.procedure)?;
+        out.push_sql("(");
+        self.params.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// The results of a stored procedure call
+///
+/// A stored procedure can return any number of result sets, each with its own
+/// set of columns. Use [`MysqlProcedureResults::next_result_set`] to load
+/// them one after another.
+///
+/// Any result sets which were not loaded are discarded as soon as this value
+/// is dropped.
+///
+/// You can use [`MysqlConnection::call_procedure`](super::MysqlConnection::call_procedure)
+/// to get a new instance of this type.
+#[allow(missing_debug_implementations)] // `MysqlConnection` is not debug
+pub struct MysqlProcedureResults<'conn> {
+    stmt: StatementUse<'conn>,
+    // whether the current result was not returned to the user yet
+    pending: bool,
+}
+
+impl<'conn> MysqlProcedureResults<'conn> {
+    pub(super) fn new(stmt: StatementUse<'conn>) -> Self {
+        Self {
+            stmt,
+            pending: true,
+        }
+    }
+
+    /// Loads the next result set returned by the stored procedure
+    ///
+    /// As the columns of a result set are only known at runtime, rows are
+    /// loaded via [`QueryableByName`], the same way as for
+    /// [`sql_query`](crate::sql_query()).
+    ///
+    /// If the procedure has `OUT` or `INOUT` parameters, their values are
+    /// returned as an additional, final result set containing a single row.
+    ///
+    /// Returns `None` if there are no more result sets.
+    pub fn next_result_set<U>(&mut self) -> QueryResult<Option<Vec<U>>>
+    where
+        U: QueryableByName<Mysql>,
+    {
+        loop {
+            if core::mem::replace(&mut self.pending, false) && self.stmt.field_count() > 0 {
+                return load_current_result_set(&self.stmt).map(Some);
+            }
+            // results without columns, like the final status of the call,
+            // are skipped
+            if !self.stmt.next_result()? {
+                return Ok(None);
+            }
+            self.pending = true;
+        }
+    }
+}
+
+impl Drop for MysqlProcedureResults<'_> {
+    fn drop(&mut self) {
+        // all results need to be consumed before the connection
+        // can be used for the next statement
+        while let Ok(true) = self.stmt.next_result() {}
+    }
+}
@@ -8,6 +8,7 @@ use std::sync::Once;
 use super::statement_cache::PrepareForCache;
 use super::stmt::Statement;
 use super::url::ConnectionOptions;
+use crate::connection::DatabaseWarning;
 use crate::mysql::MysqlType;
 use crate::result::{ConnectionError, ConnectionResult, QueryResult};
 
@@ -108,6 +109,83 @@ impl RawConnection {
             .into_owned()
     }
 
+    /// Returns the additional information about the last executed statement
+    ///
+    /// This is only available for some statements, like inserts with
+    /// multiple rows
+    pub(super) fn info(&self) -> Option<String> {
+        let info = unsafe { ffi::mysql_info(self.0.as_ptr()) };
+        if info.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(info) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    }
+
+    /// Returns the warnings reported for the last executed statement
+    ///
+    /// This executes `SHOW WARNINGS` if the server reported
+    /// any warnings, which resets the warnings afterwards
+    pub(super) fn warnings(&self) -> QueryResult<Vec<DatabaseWarning>> {
+        let warning_count = unsafe { ffi::mysql_warning_count(self.0.as_ptr()) };
+        if warning_count == 0 {
+            return Ok(Vec::new());
+        }
+        let query = "SHOW WARNINGS";
+        unsafe {
+            ffi::mysql_real_query(
+                self.0.as_ptr(),
+                query.as_ptr() as *const libc::c_char,
+                query.len() as libc::c_ulong,
+            );
+        }
+        self.did_an_error_occur()?;
+        let result = unsafe { ffi::mysql_store_result(self.0.as_ptr()) };
+        if result.is_null() {
+            self.did_an_error_occur()?;
+            return Ok(Vec::new());
+        }
+        let mut warnings = Vec::with_capacity(warning_count as usize);
+        unsafe {
+            // the columns are `Level`, `Code` and `Message`
+            if ffi::mysql_num_fields(result) >= 3 {
+                loop {
+                    let row = ffi::mysql_fetch_row(result);
+                    if row.is_null() {
+                        break;
+                    }
+                    let lengths = ffi::mysql_fetch_lengths(result);
+                    let column = |idx: usize| {
+                        let value = *row.add(idx);
+                        if value.is_null() {
+                            None
+                        } else {
+                            let bytes = core::slice::from_raw_parts(
+                                value as *const u8,
+                                (*lengths.add(idx))
+                                    .try_into()
+                                    .expect("Diesel expects at least a 32 bit operating system"),
+                            );
+                            Some(String::from_utf8_lossy(bytes).into_owned())
+                        }
+                    };
+                    warnings.push(DatabaseWarning::new(
+                        column(0).unwrap_or_default(),
+                        column(1),
+                        column(2).unwrap_or_default(),
+                    ));
+                }
+            }
+            ffi::mysql_free_result(result);
+        }
+        self.did_an_error_occur()?;
+        Ok(warnings)
+    }
+
     pub(super) fn execute(&self, query: &str) -> QueryResult<()> {
         unsafe {
             // Make sure you don't use the fake one!
@@ -122,6 +200,54 @@ impl RawConnection {
         Ok(())
     }
 
+    /// Executes the given `LOAD DATA LOCAL INFILE` statement while
+    /// providing the file content from `reader`
+    ///
+    /// Returns the number of affected rows
+    pub(super) fn load_local_infile(
+        &self,
+        query: &str,
+        reader: &mut dyn std::io::Read,
+    ) -> QueryResult<usize> {
+        let mut state = LocalInfileState {
+            reader,
+            error: None,
+            panic: None,
+        };
+        unsafe {
+            ffi::mysql_set_local_infile_handler(
+                self.0.as_ptr(),
+                Some(local_infile_init),
+                Some(local_infile_read),
+                Some(local_infile_end),
+                Some(local_infile_error),
+                &mut state as *mut LocalInfileState<'_> as *mut libc::c_void,
+            );
+            ffi::mysql_real_query(
+                self.0.as_ptr(),
+                query.as_ptr() as *const libc::c_char,
+                query.len() as libc::c_ulong,
+            );
+            // the handler must not outlive `state`
+            ffi::mysql_set_local_infile_default(self.0.as_ptr());
+        }
+        if let Some(panic) = state.panic {
+            std::panic::resume_unwind(panic);
+        }
+        if let Some(error) = state.error {
+            // the statement failed, but the connection
+            // is still usable for further queries
+            let _ = self.flush_pending_results();
+            return Err(crate::result::Error::SerializationError(Box::new(error)));
+        }
+        self.did_an_error_occur()?;
+        let affected_rows = unsafe { ffi::mysql_affected_rows(self.0.as_ptr()) };
+        self.flush_pending_results()?;
+        affected_rows
+            .try_into()
+            .map_err(|e| crate::result::Error::DeserializationError(Box::new(e)))
+    }
+
     pub(super) fn enable_multi_statements<T, F>(&self, f: F) -> QueryResult<T>
     where
         F: FnOnce() -> QueryResult<T>,
@@ -264,6 +390,81 @@ impl RawConnection {
     }
 }
 
+/// The state passed to the local infile callbacks
+/// by [`RawConnection::load_local_infile`]
+struct LocalInfileState<'a> {
+    reader: &'a mut dyn std::io::Read,
+    error: Option<std::io::Error>,
+    panic: Option<Box<dyn core::any::Any + Send + 'static>>,
+}
+
+// error code used by libmysqlclient for `CR_UNKNOWN_ERROR`
+const CR_UNKNOWN_ERROR: libc::c_int = 2000;
+
+unsafe extern "C" fn local_infile_init(
+    ptr: *mut *mut libc::c_void,
+    _filename: *const libc::c_char,
+    userdata: *mut libc::c_void,
+) -> libc::c_int {
+    // the state is used for all following callbacks
+    unsafe {
+        *ptr = userdata;
+    }
+    0
+}
+
+unsafe extern "C" fn local_infile_read(
+    ptr: *mut libc::c_void,
+    buf: *mut libc::c_char,
+    buf_len: libc::c_uint,
+) -> libc::c_int {
+    let state = unsafe { &mut *(ptr as *mut LocalInfileState<'_>) };
+    let buf_len = buf_len.min(libc::c_int::MAX as libc::c_uint) as usize;
+    let buf = unsafe { core::slice::from_raw_parts_mut(buf as *mut u8, buf_len) };
+    let reader = &mut state.reader;
+    // we must not unwind into libmysqlclient, so any panic is
+    // stored and resumed after the statement returned
+    let result = std::panic::catch_unwind(core::panic::AssertUnwindSafe(|| reader.read(buf)));
+    match result {
+        // `read` is at most `buf_len`, which fits into `c_int`
+        Ok(Ok(read)) => libc::c_int::try_from(read).unwrap_or(libc::c_int::MAX),
+        Ok(Err(e)) => {
+            state.error = Some(e);
+            -1
+        }
+        Err(panic) => {
+            state.panic = Some(panic);
+            -1
+        }
+    }
+}
+
+unsafe extern "C" fn local_infile_end(_ptr: *mut libc::c_void) {
+    // the state is owned by `RawConnection::load_local_infile`
+}
+
+unsafe extern "C" fn local_infile_error(
+    ptr: *mut libc::c_void,
+    error_msg: *mut libc::c_char,
+    error_msg_len: libc::c_uint,
+) -> libc::c_int {
+    let state = unsafe { &*(ptr as *const LocalInfileState<'_>) };
+    let msg = match state.error {
+        Some(ref e) => e.to_string(),
+        None => String::from("Failed to read the local infile data"),
+    };
+    if error_msg_len > 0 {
+        // copy as much as fits into the buffer, while leaving
+        // space for the null terminator
+        let len = msg.len().min(error_msg_len as usize - 1);
+        unsafe {
+            ptr::copy_nonoverlapping(msg.as_ptr(), error_msg as *mut u8, len);
+            *error_msg.add(len) = 0;
+        }
+    }
+    CR_UNKNOWN_ERROR
+}
+
 impl Drop for RawConnection {
     fn drop(&mut self) {
         unsafe {
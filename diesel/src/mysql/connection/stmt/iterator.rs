@@ -5,6 +5,7 @@ use core::cell::{Ref, RefCell};
 use super::{OutputBinds, Statement, StatementMetadata, StatementUse};
 use crate::backend::Backend;
 use crate::connection::statement_cache::MaybeCached;
+use crate::deserialize::QueryableByName;
 use crate::mysql::{Mysql, MysqlType};
 use crate::result::QueryResult;
 use crate::row::*;
@@ -21,14 +22,28 @@ impl<'a> StatementIterator<'a> {
     pub fn from_stmt(
         stmt: MaybeCached<'a, Statement>,
         types: &[Option<MysqlType>],
+    ) -> QueryResult<Self> {
+        Self::new(stmt, types, true)
+    }
+
+    fn new(
+        stmt: MaybeCached<'a, Statement>,
+        types: &[Option<MysqlType>],
+        buffered: bool,
     ) -> QueryResult<Self> {
         let metadata = stmt.metadata()?;
 
         let mut output_binds = OutputBinds::from_output_types(types, &metadata)
             .map_err(crate::result::Error::DeserializationError)?;
 
-        let mut stmt = stmt.execute_statement(&mut output_binds)?;
-        let size = unsafe { stmt.result_size() }?;
+        let (stmt, size) = if buffered {
+            let mut stmt = stmt.execute_statement(&mut output_binds)?;
+            let size = unsafe { stmt.result_size() }?;
+            (stmt, size)
+        } else {
+            // the number of rows is unknown until all rows are fetched
+            (stmt.execute_statement_unbuffered(&mut output_binds)?, 0)
+        };
 
         Ok(StatementIterator {
             metadata: Rc::new(metadata),
@@ -39,6 +54,41 @@ impl<'a> StatementIterator<'a> {
     }
 }
 
+/// Loads all rows of the current result set of an already executed statement
+///
+/// This is used for statements returning more than one result set,
+/// where the shape of each result set is only known after it was received.
+pub(in crate::mysql::connection) fn load_current_result_set<U>(
+    stmt: &StatementUse<'_>,
+) -> QueryResult<Vec<U>>
+where
+    U: QueryableByName<Mysql>,
+{
+    let metadata = Rc::new(stmt.metadata()?);
+    let mut output_binds = OutputBinds::from_output_types(&[], &metadata)
+        .map_err(crate::result::Error::DeserializationError)?;
+    unsafe { output_binds.with_mysql_binds(|bind_ptr| stmt.bind_result(bind_ptr)) }?;
+
+    let row = Rc::new(RefCell::new(PrivateMysqlRow::Direct(output_binds)));
+    let mut results = Vec::new();
+    loop {
+        let next_row = match &mut *row.borrow_mut() {
+            PrivateMysqlRow::Direct(binds) | PrivateMysqlRow::Copied(binds) => {
+                stmt.populate_row_buffers(binds)?
+            }
+        };
+        if next_row.is_none() {
+            break;
+        }
+        let current_row = MysqlRow {
+            row: row.clone(),
+            metadata: metadata.clone(),
+        };
+        results.push(U::build(&current_row).map_err(crate::result::Error::DeserializationError)?);
+    }
+    Ok(results)
+}
+
 impl Iterator for StatementIterator<'_> {
     type Item = QueryResult<MysqlRow>;
 
@@ -132,6 +182,30 @@ impl ExactSizeIterator for StatementIterator<'_> {
     }
 }
 
+/// The cursor used by [`MysqlConnection::load_iter_row_by_row`](crate::mysql::MysqlConnection::load_iter_row_by_row)
+///
+/// Contrary to [`StatementIterator`] the rows are received from the
+/// server while iterating, so the size of the result set is unknown
+#[allow(missing_debug_implementations)]
+pub(crate) struct RowByRowStatementIterator<'a>(StatementIterator<'a>);
+
+impl<'a> RowByRowStatementIterator<'a> {
+    pub(crate) fn from_stmt(
+        stmt: MaybeCached<'a, Statement>,
+        types: &[Option<MysqlType>],
+    ) -> QueryResult<Self> {
+        StatementIterator::new(stmt, types, false).map(Self)
+    }
+}
+
+impl Iterator for RowByRowStatementIterator<'_> {
+    type Item = QueryResult<MysqlRow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
 #[derive(Clone)]
 #[allow(missing_debug_implementations)]
 pub struct MysqlRow {
@@ -241,7 +315,7 @@ fn fun_with_row_iters() {
         }
     }
 
-    use crate::connection::LoadConnection;
+    use crate::connection::{DefaultLoadingMode, LoadConnection};
     use crate::deserialize::{FromSql, FromSqlRow};
     use crate::prelude::*;
     use crate::row::{Field, Row};
@@ -268,7 +342,7 @@ fn fun_with_row_iters() {
     let expected = vec![(1, String::from("Sean")), (2, String::from("Tess"))];
 
     {
-        let row_iter = conn.load(query).unwrap();
+        let row_iter = LoadConnection::<DefaultLoadingMode>::load(conn, query).unwrap();
         for (row, expected) in row_iter.zip(&expected) {
             let row = row.unwrap();
 
@@ -283,7 +357,9 @@ fn fun_with_row_iters() {
     }
 
     {
-        let collected_rows = conn.load(query).unwrap().collect::<Vec<_>>();
+        let collected_rows = LoadConnection::<DefaultLoadingMode>::load(conn, query)
+            .unwrap()
+            .collect::<Vec<_>>();
         assert_eq!(collected_rows.len(), 2);
         for (row, expected) in collected_rows.iter().zip(&expected) {
             let deserialized = row
@@ -299,7 +375,7 @@ fn fun_with_row_iters() {
         }
     }
 
-    let mut row_iter = conn.load(query).unwrap();
+    let mut row_iter = LoadConnection::<DefaultLoadingMode>::load(conn, query).unwrap();
 
     let first_row = row_iter.next().unwrap().unwrap();
     let first_fields = (
@@ -372,3 +448,64 @@ fn fun_with_row_iters() {
         expected[0].1
     );
 }
+
+#[cfg(test)]
+#[diesel_test_helper::test]
+fn load_iter_row_by_row_receives_all_rows() {
+    crate::table! {
+        row_by_row_users(id) {
+            id -> Integer,
+            name -> Text,
+        }
+    }
+
+    use crate::prelude::*;
+
+    let conn = &mut crate::test_helpers::connection();
+
+    crate::sql_query(
+        "CREATE TEMPORARY TABLE IF NOT EXISTS row_by_row_users(id INTEGER PRIMARY KEY, name TEXT NOT NULL);",
+    )
+    .execute(conn)
+    .unwrap();
+
+    let users = (1..=100)
+        .map(|id| {
+            (
+                row_by_row_users::id.eq(id),
+                row_by_row_users::name.eq(format!("User {id}")),
+            )
+        })
+        .collect::<Vec<_>>();
+    crate::insert_into(row_by_row_users::table)
+        .values(users)
+        .execute(conn)
+        .unwrap();
+
+    let query = row_by_row_users::table.order(row_by_row_users::id);
+
+    let loaded = conn
+        .load_iter_row_by_row::<_, (i32, String)>(query)
+        .unwrap()
+        .collect::<QueryResult<Vec<_>>>()
+        .unwrap();
+    let expected = (1..=100)
+        .map(|id| (id, format!("User {id}")))
+        .collect::<Vec<_>>();
+    assert_eq!(expected, loaded);
+
+    // the remaining rows are discarded if the iterator is dropped early
+    let first = conn
+        .load_iter_row_by_row::<_, (i32, String)>(query)
+        .unwrap()
+        .next()
+        .unwrap()
+        .unwrap();
+    assert_eq!((1, String::from("User 1")), first);
+
+    let count = row_by_row_users::table
+        .count()
+        .get_result::<i64>(conn)
+        .unwrap();
+    assert_eq!(100, count);
+}
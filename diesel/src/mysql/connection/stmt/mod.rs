@@ -4,7 +4,7 @@ use core::ffi::CStr;
 use core::ptr::NonNull;
 use mysqlclient_sys as ffi;
 
-use super::bind::{OutputBinds, PreparedStatementBinds};
+use super::bind::{LONG_DATA_CHUNK_SIZE, OutputBinds, PreparedStatementBinds};
 use crate::connection::statement_cache::MaybeCached;
 use crate::mysql::MysqlType;
 use crate::result::{DatabaseErrorKind, Error, QueryResult};
@@ -64,7 +64,36 @@ impl Statement {
             }
         });
         self.input_binds = Some(input_binds);
-        self.did_an_error_occur()
+        self.did_an_error_occur()?;
+        self.send_long_data()
+    }
+
+    /// Sends large text and binary parameters to the server in chunks.
+    ///
+    /// The client library would otherwise copy them into the single packet
+    /// executing the statement, which doubles the memory needed for them.
+    /// The data sent here is only used for the next execution, so this
+    /// needs to happen each time the parameters are bound.
+    fn send_long_data(&self) -> QueryResult<()> {
+        let Some(input_binds) = &self.input_binds else {
+            return Ok(());
+        };
+        for (idx, data) in input_binds.long_data() {
+            let idx =
+                libc::c_uint::try_from(idx).expect("Statements have less than 2^32 parameters");
+            for chunk in data.chunks(LONG_DATA_CHUNK_SIZE) {
+                unsafe {
+                    ffi::mysql_stmt_send_long_data(
+                        self.stmt.as_ptr(),
+                        idx,
+                        chunk.as_ptr() as *const libc::c_char,
+                        chunk.len() as libc::c_ulong,
+                    );
+                }
+                self.did_an_error_occur()?;
+            }
+        }
+        Ok(())
     }
 
     fn last_error_message(&self) -> String {
@@ -134,16 +163,38 @@ impl<'a> MaybeCached<'a, Statement> {
         }
     }
 
+    /// Like `execute_statement`, but the result set is not buffered
+    /// on the client side. Instead each row is received from the server
+    /// while fetching it.
+    ///
+    /// No other statement can be executed on the connection until all
+    /// rows are fetched or the returned `StatementUse` is dropped.
+    pub(super) fn execute_statement_unbuffered(
+        self,
+        binds: &mut OutputBinds,
+    ) -> QueryResult<StatementUse<'a>> {
+        unsafe {
+            binds.with_mysql_binds(|bind_ptr| self.bind_result(bind_ptr))?;
+            self.execute_inner(false)
+        }
+    }
+
     /// This function should be called instead of `results` on queries which
     /// have no return value. It should never be called on a statement on
     /// which `results` has previously been called?
     pub(super) unsafe fn execute(self) -> QueryResult<StatementUse<'a>> {
+        unsafe { self.execute_inner(true) }
+    }
+
+    unsafe fn execute_inner(self, store_result: bool) -> QueryResult<StatementUse<'a>> {
         unsafe {
             ffi::mysql_stmt_execute(self.stmt.as_ptr());
         }
         self.did_an_error_occur()?;
-        unsafe {
-            ffi::mysql_stmt_store_result(self.stmt.as_ptr());
+        if store_result {
+            unsafe {
+                ffi::mysql_stmt_store_result(self.stmt.as_ptr());
+            }
         }
         let ret = StatementUse { inner: self };
         ret.inner.did_an_error_occur()?;
@@ -170,6 +221,40 @@ impl StatementUse<'_> {
             .map_err(|e| Error::DeserializationError(Box::new(e)))
     }
 
+    pub(in crate::mysql::connection) fn metadata(&self) -> QueryResult<StatementMetadata> {
+        self.inner.metadata()
+    }
+
+    /// Returns the number of columns of the current result set
+    ///
+    /// This is `0` for results that do not contain any rows,
+    /// like the final status result of a `CALL` statement
+    pub(in crate::mysql::connection) fn field_count(&self) -> u32 {
+        unsafe { ffi::mysql_stmt_field_count(self.inner.stmt.as_ptr()) }
+    }
+
+    /// Advances to the next result of a statement returning
+    /// multiple results, like a `CALL` statement
+    ///
+    /// Returns `false` if there are no more results
+    pub(in crate::mysql::connection) fn next_result(&mut self) -> QueryResult<bool> {
+        let stmt = self.inner.stmt.as_ptr();
+        unsafe {
+            ffi::mysql_stmt_free_result(stmt);
+        }
+        match unsafe { ffi::mysql_stmt_next_result(stmt) } {
+            0 => {
+                unsafe {
+                    ffi::mysql_stmt_store_result(stmt);
+                }
+                self.inner.did_an_error_occur()?;
+                Ok(true)
+            }
+            -1 => Ok(false),
+            _ => self.inner.did_an_error_occur().map(|()| false),
+        }
+    }
+
     /// This function should be called after `execute` only
     /// otherwise it's not guaranteed to return a valid result
     pub(in crate::mysql::connection) unsafe fn result_size(&mut self) -> QueryResult<usize> {
@@ -224,6 +309,8 @@ impl StatementUse<'_> {
 }
 
 impl Drop for StatementUse<'_> {
+    // this also discards all rows of an unbuffered
+    // result set that were not fetched yet
     fn drop(&mut self) {
         unsafe {
             ffi::mysql_stmt_free_result(self.inner.stmt.as_ptr());
@@ -0,0 +1,105 @@
+/// The outcome of an `INSERT … ON DUPLICATE KEY UPDATE` statement
+///
+/// This type is returned by
+/// [`MysqlConnection::execute_upsert`](super::MysqlConnection::execute_upsert)
+/// and reports how many rows were inserted, updated or left unchanged.
+///
+/// The values are derived from the number of affected rows reported by MySQL.
+/// As diesel connects to MySQL with the `CLIENT_FOUND_ROWS` flag, MySQL reports
+/// rows which already contained the new values as found rows. For statements
+/// inserting multiple rows these rows can be told apart via the
+/// number of duplicates reported by MySQL and are counted as
+/// [`unchanged`](MysqlUpsertOutcome::unchanged()). For statements inserting
+/// a single row such a row cannot be distinguished from an inserted row
+/// and is counted as [`inserted`](MysqlUpsertOutcome::inserted()).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MysqlUpsertOutcome {
+    inserted: usize,
+    updated: usize,
+    unchanged: usize,
+}
+
+impl MysqlUpsertOutcome {
+    pub(super) fn new(affected_rows: usize, info: Option<&str>) -> Self {
+        match info.and_then(parse_records_and_duplicates) {
+            Some((records, duplicates)) => {
+                // each inserted or unchanged row counts as one affected row,
+                // each updated row counts as two affected rows
+                let updated = affected_rows.saturating_sub(records);
+                Self {
+                    inserted: records.saturating_sub(duplicates),
+                    updated,
+                    unchanged: duplicates.saturating_sub(updated),
+                }
+            }
+            None if affected_rows == 2 => Self {
+                updated: 1,
+                ..Self::default()
+            },
+            None => Self {
+                inserted: affected_rows,
+                ..Self::default()
+            },
+        }
+    }
+
+    /// The number of inserted rows
+    pub fn inserted(&self) -> usize {
+        self.inserted
+    }
+
+    /// The number of existing rows which were updated
+    pub fn updated(&self) -> usize {
+        self.updated
+    }
+
+    /// The number of existing rows which already contained the new values
+    pub fn unchanged(&self) -> usize {
+        self.unchanged
+    }
+}
+
+// parses an info string like `Records: 3  Duplicates: 1  Warnings: 0`
+fn parse_records_and_duplicates(info: &str) -> Option<(usize, usize)> {
+    let mut records = None;
+    let mut duplicates = None;
+    let mut parts = info.split_whitespace();
+    while let Some(key) = parts.next() {
+        let value = parts.next()?.parse().ok()?;
+        match key {
+            "Records:" => records = Some(value),
+            "Duplicates:" => duplicates = Some(value),
+            _ => {}
+        }
+    }
+    Some((records?, duplicates?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MysqlUpsertOutcome;
+
+    fn outcome(inserted: usize, updated: usize, unchanged: usize) -> MysqlUpsertOutcome {
+        MysqlUpsertOutcome {
+            inserted,
+            updated,
+            unchanged,
+        }
+    }
+
+    #[diesel_test_helper::test]
+    fn single_row_outcome() {
+        assert_eq!(outcome(1, 0, 0), MysqlUpsertOutcome::new(1, None));
+        assert_eq!(outcome(0, 1, 0), MysqlUpsertOutcome::new(2, None));
+    }
+
+    #[diesel_test_helper::test]
+    fn multi_row_outcome() {
+        let info = "Records: 4  Duplicates: 3  Warnings: 0";
+        // one inserted row, two updated rows and one unchanged row
+        assert_eq!(outcome(1, 2, 1), MysqlUpsertOutcome::new(6, Some(info)));
+
+        let info = "Records: 2  Duplicates: 0  Warnings: 0";
+        assert_eq!(outcome(2, 0, 0), MysqlUpsertOutcome::new(2, Some(info)));
+    }
+}
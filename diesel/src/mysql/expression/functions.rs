@@ -0,0 +1,160 @@
+//! MySQL specific functions
+use crate::expression::functions::declare_sql_function;
+use crate::mysql::expression::private::DateOrDatetime;
+use crate::mysql::expression::private::JsonOrNullableJson;
+use crate::mysql::expression::private::MaybeNullableValue;
+use crate::sql_types::*;
+
+#[declare_sql_function(generate_return_type_helpers = true)]
+#[backends(crate::mysql::Mysql)]
+extern "SQL" {
+    /// Returns the part of a JSON document selected by the given path.
+    ///
+    /// This is the MySQL equivalent of the PostgreSQL `->` and `#>` operators.
+    /// The path uses the MySQL JSON path syntax, e.g. `$.address.city` or `$[1]`.
+    /// It returns `NULL` if the path does not select a value.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_extract;
+    /// #     use diesel::sql_types::Json;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// #
+    /// let address = json!({"city": "North Pole", "lines": ["Article Circle Expressway 1"]});
+    ///
+    /// let result = diesel::select(json_extract::<Json, _, _>(&address, "$.city"))
+    ///     .get_result::<Option<serde_json::Value>>(connection)?;
+    /// assert_eq!(Some(json!("North Pole")), result);
+    ///
+    /// let result = diesel::select(json_extract::<Json, _, _>(&address, "$.lines[0]"))
+    ///     .get_result::<Option<serde_json::Value>>(connection)?;
+    /// assert_eq!(Some(json!("Article Circle Expressway 1")), result);
+    ///
+    /// let result = diesel::select(json_extract::<Json, _, _>(&address, "$.postcode"))
+    ///     .get_result::<Option<serde_json::Value>>(connection)?;
+    /// assert_eq!(None, result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn json_extract<J: JsonOrNullableJson + SingleValue>(json: J, path: Text) -> Nullable<Json>;
+
+    /// Unquotes a JSON value and returns it as text.
+    ///
+    /// Combined with [`json_extract()`] this is the MySQL equivalent of
+    /// the PostgreSQL `->>` operator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{json_extract, json_unquote};
+    /// #     use diesel::sql_types::{Json, Nullable};
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// #
+    /// let address = json!({"city": "North Pole"});
+    ///
+    /// let result = diesel::select(json_unquote::<Nullable<Json>, _>(
+    ///     json_extract::<Json, _, _>(&address, "$.city"),
+    /// ))
+    /// .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(Some("North Pole".to_owned()), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn json_unquote<J: JsonOrNullableJson + MaybeNullableValue<Text>>(json: J) -> J::Out;
+
+    /// Checks whether the first JSON document contains the second one.
+    ///
+    /// This is the MySQL equivalent of the PostgreSQL `@>` operator.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_contains;
+    /// #     use diesel::sql_types::Json;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// #
+    /// let tags = json!({"tags": ["rust", "sql"]});
+    ///
+    /// let result = diesel::select(json_contains::<Json, _, _>(&tags, json!({"tags": ["rust"]})))
+    ///     .get_result::<Option<bool>>(connection)?;
+    /// assert_eq!(Some(true), result);
+    ///
+    /// let result = diesel::select(json_contains::<Json, _, _>(&tags, json!({"tags": ["go"]})))
+    ///     .get_result::<Option<bool>>(connection)?;
+    /// assert_eq!(Some(false), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn json_contains<J: JsonOrNullableJson + SingleValue>(
+        target: J,
+        candidate: Json,
+    ) -> Nullable<Bool>;
+
+    /// Formats a date or timestamp as text according to the given format
+    ///
+    /// This is the MySQL equivalent of the PostgreSQL `to_char` function.
+    /// See the [MySQL documentation](https://dev.mysql.com/doc/refman/8.4/en/date-and-time-functions.html#function_date-format)
+    /// for the supported format specifiers. To group rows by day use
+    /// [`date`](crate::dsl::date()) instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{date_format, sql};
+    /// #     use diesel::sql_types::Datetime;
+    /// #     let connection = &mut establish_connection();
+    /// let formatted = diesel::select(date_format(
+    ///     sql::<Datetime>("TIMESTAMP('2024-03-15 13:45:12')"),
+    ///     "%d.%m.%Y %H:%i",
+    /// ))
+    /// .get_result::<String>(connection)?;
+    /// assert_eq!("15.03.2024 13:45", formatted);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn date_format<D: DateOrDatetime + MaybeNullableValue<Text>>(date: D, format: Text) -> D::Out;
+}
+
+pub(super) mod return_type_helpers_reexported {
+    #[allow(unused_imports)]
+    #[doc(inline)]
+    pub use super::return_type_helpers::*;
+}
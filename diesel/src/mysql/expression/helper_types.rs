@@ -0,0 +1,2 @@
+#[doc(inline)]
+pub use super::return_type_helpers::*;
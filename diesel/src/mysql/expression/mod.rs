@@ -0,0 +1,64 @@
+//! MySQL related query builder extensions.
+//!
+//! Everything in this module is re-exported from database agnostic locations.
+//! You should rely on the re-exports rather than this module directly. It is
+//! kept separate purely for documentation purposes.
+
+pub mod functions;
+pub(crate) mod helper_types;
+
+mod return_type_helpers {
+    #[allow(unused_imports)]
+    #[doc(inline)]
+    pub use super::functions::return_type_helpers_reexported::*;
+}
+
+/// MySQL specific expression DSL methods.
+///
+/// This module will be glob imported by
+/// [`diesel::dsl`](crate::dsl) when compiled with the `feature =
+/// "mysql_backend"` flag.
+pub mod dsl {
+    #[doc(inline)]
+    pub use super::functions::*;
+}
+
+pub(in crate::mysql) mod private {
+    use crate::sql_types::{
+        Date, Datetime, Json, MaybeNullableType, Nullable, SingleValue, Timestamp,
+    };
+
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::Json` nor `diesel::sql_types::Nullable<Json>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait JsonOrNullableJson {}
+    impl JsonOrNullableJson for Json {}
+    impl JsonOrNullableJson for Nullable<Json> {}
+
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::Date`, `diesel::sql_types::Datetime`, \
+                   `diesel::sql_types::Timestamp` nor the `Nullable` variant of one of these types",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait DateOrDatetime {}
+    impl DateOrDatetime for Date {}
+    impl DateOrDatetime for Datetime {}
+    impl DateOrDatetime for Timestamp {}
+    impl DateOrDatetime for Nullable<Date> {}
+    impl DateOrDatetime for Nullable<Datetime> {}
+    impl DateOrDatetime for Nullable<Timestamp> {}
+
+    pub trait MaybeNullableValue<T>: SingleValue {
+        type Out: SingleValue;
+    }
+
+    impl<T, O> MaybeNullableValue<O> for T
+    where
+        T: SingleValue,
+        T::IsNull: MaybeNullableType<O>,
+        <T::IsNull as MaybeNullableType<O>>::Out: SingleValue,
+    {
+        type Out = <T::IsNull as MaybeNullableType<O>>::Out;
+    }
+}
@@ -7,15 +7,24 @@
 pub(crate) mod backend;
 #[cfg(feature = "mysql")]
 mod connection;
+pub mod expression;
 mod value;
 
 pub(crate) mod query_builder;
+mod transaction;
 mod types;
 
 pub use self::backend::{Mysql, MysqlType};
 #[cfg(feature = "mysql")]
-pub use self::connection::MysqlConnection;
+pub use self::connection::{
+    LoadDataTarget, MysqlConnection, MysqlLoadDataLocalInfile, MysqlProcedureResults,
+    MysqlUpsertOutcome,
+};
 pub use self::query_builder::MysqlQueryBuilder;
+#[cfg(feature = "distinct_on_for_mysql_8")]
+#[doc(inline)]
+pub use self::query_builder::{DistinctOnClause, OrderDecorator};
+pub use self::transaction::TransactionBuilder;
 pub use self::value::{MysqlValue, NumericRepresentation};
 
 /// Data structures for MySQL types which have no corresponding Rust type
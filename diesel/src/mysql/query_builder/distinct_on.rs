@@ -0,0 +1,75 @@
+use crate::backend::sql_dialect::select_statement_syntax::AnsiSqlSelectStatement;
+use crate::mysql::Mysql;
+use crate::query_builder::distinct_on::DistinctOnClause;
+use crate::query_builder::group_by_clause::NoGroupByClause;
+use crate::query_builder::having_clause::NoHavingClause;
+use crate::query_builder::locking_clause::NoLockingClause;
+use crate::query_builder::{AstPass, FromClause, QueryFragment, SelectStatement};
+use crate::query_source::Table;
+use crate::result::QueryResult;
+
+const ROW_NUMBER_COLUMN: &str = "__diesel_distinct_on_row_number";
+const DERIVED_TABLE: &str = "__diesel_distinct_on";
+
+/// Emulates `DISTINCT ON` via the `ROW_NUMBER()` window function
+///
+/// The query is rendered as
+///
+/// ```sql
+/// SELECT <select> FROM (
+///     SELECT table.*, ROW_NUMBER() OVER (PARTITION BY <distinct on> <order by>) AS row_number
+///     FROM table WHERE <where>
+/// ) AS derived_table WHERE row_number = 1 <order by> <limit offset>
+/// ```
+///
+/// The outer query refers to the columns of the derived table by their
+/// unqualified names, which is why the emulation is only provided for
+/// queries on a single table without `GROUP BY`, `HAVING` or locking clauses.
+impl<F, S, T, W, O, LOf> QueryFragment<Mysql, AnsiSqlSelectStatement>
+    for SelectStatement<
+        FromClause<F>,
+        S,
+        DistinctOnClause<T>,
+        W,
+        O,
+        LOf,
+        NoGroupByClause,
+        NoHavingClause,
+        NoLockingClause,
+    >
+where
+    F: Table + QueryFragment<Mysql>,
+    F::FromClause: QueryFragment<Mysql>,
+    S: QueryFragment<Mysql>,
+    T: QueryFragment<Mysql>,
+    W: QueryFragment<Mysql>,
+    O: QueryFragment<Mysql>,
+    LOf: QueryFragment<Mysql>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Mysql>) -> QueryResult<()> {
+        let skip_from = out.should_skip_from();
+        out.push_sql("SELECT ");
+        out.skip_from(true);
+        self.select.walk_ast(out.reborrow())?;
+        out.skip_from(false);
+        out.push_sql(" FROM (SELECT ");
+        self.from.source.walk_ast(out.reborrow())?;
+        out.push_sql(".*, ROW_NUMBER() OVER (PARTITION BY ");
+        self.distinct.0.walk_ast(out.reborrow())?;
+        self.order.walk_ast(out.reborrow())?;
+        out.push_sql(") AS ");
+        out.push_identifier(ROW_NUMBER_COLUMN)?;
+        self.from.walk_ast(out.reborrow())?;
+        self.where_clause.walk_ast(out.reborrow())?;
+        out.push_sql(") AS ");
+        out.push_identifier(DERIVED_TABLE)?;
+        out.push_sql(" WHERE ");
+        out.push_identifier(ROW_NUMBER_COLUMN)?;
+        out.push_sql(" = 1");
+        out.skip_from(true);
+        self.order.walk_ast(out.reborrow())?;
+        out.skip_from(skip_from);
+        self.limit_offset.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
@@ -4,8 +4,15 @@ use crate::result::QueryResult;
 
 #[doc(inline)]
 pub use self::query_fragment_impls::DuplicatedKeys;
+#[cfg(feature = "distinct_on_for_mysql_8")]
+pub use crate::query_builder::distinct_on::DistinctOnClause;
+#[cfg(feature = "distinct_on_for_mysql_8")]
+pub use crate::query_builder::distinct_on::OrderDecorator;
 
+#[cfg(feature = "distinct_on_for_mysql_8")]
+mod distinct_on;
 mod limit_offset;
+pub(crate) mod new_row;
 mod query_fragment_impls;
 
 /// The MySQL query builder
@@ -0,0 +1,53 @@
+use crate::expression::{AppearsOnTable, Expression, ValidGrouping, is_aggregate};
+use crate::mysql::Mysql;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::query_source::Column;
+use crate::result::QueryResult;
+
+/// Represents `VALUES(column)` in an `ON DUPLICATE KEY UPDATE` clause,
+/// which refers to the value that would have been inserted into `column`.
+///
+/// See [`diesel::upsert::new_row`](crate::upsert::new_row()) for details.
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct NewRow<T>(T);
+
+impl<T> NewRow<T> {
+    pub(crate) fn new(column: T) -> Self {
+        NewRow(column)
+    }
+}
+
+impl<T> Expression for NewRow<T>
+where
+    T: Expression,
+{
+    type SqlType = T::SqlType;
+}
+
+impl<T> ValidGrouping<()> for NewRow<T> {
+    type IsAggregate = is_aggregate::Never;
+}
+
+// only meaningful in the `ON DUPLICATE KEY UPDATE` clause
+// of an insert statement targeting the column's table
+impl<T> AppearsOnTable<T::Table> for NewRow<T>
+where
+    T: Column,
+    NewRow<T>: Expression,
+{
+}
+
+impl<T> QueryFragment<Mysql> for NewRow<T>
+where
+    T: Column,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Mysql>) -> QueryResult<()> {
+        // the row alias syntax (`INSERT … AS new … new.column`) is not
+        // supported by MariaDB, while `VALUES(column)` is accepted by all
+        // supported MySQL and MariaDB versions
+        out.push_sql("VALUES(");
+        out.push_identifier(T::NAME)?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
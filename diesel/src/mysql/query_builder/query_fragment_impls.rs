@@ -1,9 +1,12 @@
+use crate::expression::Expression;
 use crate::expression::operators::Concat;
+use crate::expression::ops::{Add, Sub};
 use crate::mysql::Mysql;
 use crate::mysql::backend::MysqlOnConflictClause;
 use crate::query_builder::insert_statement::DefaultValues;
 use crate::query_builder::locking_clause::{ForShare, ForUpdate, NoModifier, NoWait, SkipLocked};
 use crate::query_builder::nodes::StaticQueryFragment;
+use crate::query_builder::returning::ReturningClause;
 use crate::query_builder::upsert::into_conflict_clause::OnConflictSelectWrapper;
 use crate::query_builder::upsert::on_conflict_actions::{DoNothing, DoUpdate};
 use crate::query_builder::upsert::on_conflict_clause::OnConflictValues;
@@ -11,6 +14,7 @@ use crate::query_builder::upsert::on_conflict_target::{ConflictTarget, OnConflic
 use crate::query_builder::where_clause::NoWhereClause;
 use crate::query_builder::{AstPass, QueryFragment};
 use crate::result::QueryResult;
+use crate::sql_types::ops::OperationKind;
 use crate::{Column, Table};
 
 impl QueryFragment<Mysql> for ForUpdate {
@@ -47,6 +51,20 @@ impl QueryFragment<Mysql> for NoWait {
     }
 }
 
+impl<Expr> QueryFragment<Mysql, crate::mysql::backend::MariaDbReturningClause>
+    for ReturningClause<Expr>
+where
+    Expr: QueryFragment<Mysql>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Mysql>) -> QueryResult<()> {
+        // MariaDB does not accept qualified column names in `RETURNING` clauses
+        out.skip_from(true);
+        out.push_sql(" RETURNING ");
+        self.0.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
 impl QueryFragment<Mysql, crate::mysql::backend::MysqlStyleDefaultValueClause> for DefaultValues {
     fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Mysql>) -> QueryResult<()> {
         out.push_sql("() VALUES ()");
@@ -218,3 +236,34 @@ macro_rules! do_nothing_for_composite_keys {
 }
 
 crate::for_each_tuple!(do_nothing_for_composite_keys);
+
+macro_rules! mysql_interval_arithmetic {
+    ($name:ident, $op:expr_2021, $function:expr_2021) => {
+        impl<L, R> QueryFragment<Mysql, crate::mysql::backend::MysqlIntervalArithmetic>
+            for $name<L, R>
+        where
+            L: Expression + QueryFragment<Mysql>,
+            L::SqlType: crate::sql_types::ops::$name,
+            R: QueryFragment<Mysql>,
+        {
+            fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Mysql>) -> QueryResult<()> {
+                match <L::SqlType as crate::sql_types::ops::$name>::KIND {
+                    OperationKind::Numeric => self.walk_operator(out, $op),
+                    // Intervals are sent as a number of microseconds
+                    OperationKind::TimeInterval | OperationKind::DateTimeInterval => {
+                        out.push_sql($function);
+                        out.push_sql("(");
+                        self.lhs.walk_ast(out.reborrow())?;
+                        out.push_sql(", INTERVAL ");
+                        self.rhs.walk_ast(out.reborrow())?;
+                        out.push_sql(" MICROSECOND)");
+                        Ok(())
+                    }
+                }
+            }
+        }
+    };
+}
+
+mysql_interval_arithmetic!(Add, " + ", "DATE_ADD");
+mysql_interval_arithmetic!(Sub, " - ", "DATE_SUB");
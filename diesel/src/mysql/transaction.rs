@@ -0,0 +1,248 @@
+use alloc::string::String;
+
+use crate::connection::{Access, AnsiTransactionManager, Isolation, finish_transaction};
+use crate::mysql::Mysql;
+use crate::prelude::*;
+use crate::result::Error;
+
+/// Used to build a transaction, specifying additional details.
+///
+/// This struct is returned by [`.build_transaction`].
+/// See the documentation for methods on this struct for usage examples.
+/// See [the MySQL documentation for `SET TRANSACTION`][mysql-docs]
+/// for details on the behavior of each option.
+///
+/// [`.build_transaction`]: crate::MysqlConnection::build_transaction()
+/// [mysql-docs]: https://dev.mysql.com/doc/refman/8.4/en/set-transaction.html
+#[allow(missing_debug_implementations)] // False positive. Connection isn't Debug.
+#[must_use = "Transaction builder does nothing unless you call `run` on it"]
+pub struct TransactionBuilder<'a, C> {
+    connection: &'a mut C,
+    isolation: Option<Isolation>,
+    access: Option<Access>,
+}
+
+impl<'a, C> TransactionBuilder<'a, C>
+where
+    C: Connection<Backend = Mysql, TransactionManager = AnsiTransactionManager>,
+{
+    /// Creates a new TransactionBuilder.
+    #[diesel_derives::__diesel_public_if(
+        feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+    )]
+    pub(crate) fn new(connection: &'a mut C) -> Self {
+        Self {
+            connection,
+            isolation: None,
+            access: None,
+        }
+    }
+
+    /// Makes the transaction `READ ONLY`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let conn = &mut connection_no_data();
+    /// #     create_tables_with_data(conn);
+    /// conn.build_transaction()
+    ///     .read_only()
+    ///     .run::<_, diesel::result::Error, _>(|conn| {
+    ///         let read_attempt = users.select(name).load::<String>(conn);
+    ///         assert!(read_attempt.is_ok());
+    ///
+    ///         let write_attempt = diesel::insert_into(users)
+    ///             .values(name.eq("Ruby"))
+    ///             .execute(conn);
+    ///         assert!(write_attempt.is_err());
+    ///
+    ///         Ok(())
+    ///     })
+    /// # }
+    /// ```
+    pub fn read_only(mut self) -> Self {
+        self.access = Some(Access::ReadOnly);
+        self
+    }
+
+    /// Makes the transaction `READ WRITE`
+    ///
+    /// This is the default, unless you've changed the
+    /// `transaction_read_only` system variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_data();
+    /// conn.build_transaction().read_write().run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn read_write(mut self) -> Self {
+        self.access = Some(Access::ReadWrite);
+        self
+    }
+
+    /// Makes the transaction `ISOLATION LEVEL READ UNCOMMITTED`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_data();
+    /// conn.build_transaction()
+    ///     .read_uncommitted()
+    ///     .run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn read_uncommitted(mut self) -> Self {
+        self.isolation = Some(Isolation::ReadUncommitted);
+        self
+    }
+
+    /// Makes the transaction `ISOLATION LEVEL READ COMMITTED`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_data();
+    /// conn.build_transaction().read_committed().run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn read_committed(mut self) -> Self {
+        self.isolation = Some(Isolation::ReadCommitted);
+        self
+    }
+
+    /// Makes the transaction `ISOLATION LEVEL REPEATABLE READ`
+    ///
+    /// This is the default, unless you've changed the
+    /// `transaction_isolation` system variable.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_data();
+    /// conn.build_transaction()
+    ///     .repeatable_read()
+    ///     .run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn repeatable_read(mut self) -> Self {
+        self.isolation = Some(Isolation::RepeatableRead);
+        self
+    }
+
+    /// Makes the transaction `ISOLATION LEVEL SERIALIZABLE`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut connection_no_data();
+    /// conn.build_transaction().serializable().run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn serializable(mut self) -> Self {
+        self.isolation = Some(Isolation::Serializable);
+        self
+    }
+
+    /// Runs the given function inside of the transaction
+    /// with the parameters given to this builder.
+    ///
+    /// This function executes the provided closure `f` inside a database
+    /// transaction. If there is already an open transaction for the current
+    /// connection it will return an error. The connection is committed if
+    /// the closure returns `Ok(_)`, it will be rolled back if it returns `Err(_)`.
+    /// For both cases the original result value will be returned from this function.
+    pub fn run<T, E, F>(&mut self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut C) -> Result<T, E>,
+        E: From<Error>,
+    {
+        let sql = self.to_sql();
+        AnsiTransactionManager::begin_transaction_sql(&mut *self.connection, &sql)?;
+        finish_transaction::<AnsiTransactionManager, _, _, _, _>(&mut *self.connection, f)
+    }
+
+    fn to_sql(&self) -> String {
+        let mut sql = String::new();
+        // `SET TRANSACTION` only applies to the next transaction
+        // started by this session
+        if let Some(isolation) = self.isolation {
+            sql += "SET TRANSACTION ISOLATION LEVEL ";
+            sql += isolation.to_sql();
+            sql += "; ";
+        }
+        sql += "START TRANSACTION";
+        if let Some(access) = self.access {
+            sql += " ";
+            sql += access.to_sql();
+        }
+        sql
+    }
+}
+
+#[cfg(all(test, feature = "mysql"))]
+#[diesel_test_helper::test]
+fn test_transaction_builder_generates_correct_sql() {
+    let conn = &mut crate::test_helpers::connection_no_transaction();
+
+    assert_eq!("START TRANSACTION", conn.build_transaction().to_sql());
+    assert_eq!(
+        "START TRANSACTION READ ONLY",
+        conn.build_transaction().read_only().to_sql()
+    );
+    assert_eq!(
+        "SET TRANSACTION ISOLATION LEVEL SERIALIZABLE; START TRANSACTION READ WRITE",
+        conn.build_transaction()
+            .serializable()
+            .read_write()
+            .to_sql()
+    );
+    assert_eq!(
+        "SET TRANSACTION ISOLATION LEVEL READ UNCOMMITTED; START TRANSACTION",
+        conn.build_transaction().read_uncommitted().to_sql()
+    );
+}
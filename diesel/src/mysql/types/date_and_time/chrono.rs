@@ -1,10 +1,10 @@
-use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use std::os::raw as libc;
 
 use crate::deserialize::{self, FromSql};
 use crate::mysql::{Mysql, MysqlValue};
 use crate::serialize::{self, Output, ToSql};
-use crate::sql_types::{Date, Datetime, Time, Timestamp};
+use crate::sql_types::{BigInt, Date, Datetime, Interval, Time, Timestamp};
 
 use super::{MysqlTime, MysqlTimestampType};
 
@@ -123,6 +123,16 @@ impl FromSql<Date, Mysql> for NaiveDate {
     }
 }
 
+#[cfg(all(feature = "chrono", feature = "mysql_backend"))]
+impl ToSql<Interval, Mysql> for Duration {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+        let microseconds = self
+            .num_microseconds()
+            .ok_or("Failed to create microseconds by overflow")?;
+        ToSql::<BigInt, Mysql>::to_sql(&microseconds, &mut out.reborrow())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate chrono;
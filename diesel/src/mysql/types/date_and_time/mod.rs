@@ -6,7 +6,7 @@ use crate::deserialize::{self, FromSql, FromSqlRow};
 use crate::expression::AsExpression;
 use crate::mysql::{Mysql, MysqlValue};
 use crate::serialize::{self, IsNull, Output, ToSql};
-use crate::sql_types::{Date, Datetime, Time, Timestamp};
+use crate::sql_types::{BigInt, Date, Datetime, Interval, Time, Timestamp};
 
 #[cfg(feature = "chrono")]
 mod chrono;
@@ -207,3 +207,13 @@ mysql_time_impls!(Datetime);
 mysql_time_impls!(Timestamp);
 mysql_time_impls!(Time);
 mysql_time_impls!(Date);
+
+// MySQL has no interval type, intervals are sent as a number of microseconds
+// and added with `DATE_ADD(_, INTERVAL _ MICROSECOND)` instead
+#[cfg(feature = "mysql_backend")]
+impl ToSql<Interval, Mysql> for core::time::Duration {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+        let microseconds = i64::try_from(self.as_micros())?;
+        ToSql::<BigInt, Mysql>::to_sql(&microseconds, &mut out.reborrow())
+    }
+}
@@ -1,12 +1,13 @@
 use std::os::raw as libc;
 use time::{
-    Date as NaiveDate, Month, OffsetDateTime, PrimitiveDateTime, Time as NaiveTime, UtcOffset,
+    Date as NaiveDate, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time as NaiveTime,
+    UtcOffset,
 };
 
 use crate::deserialize::{self, FromSql};
 use crate::mysql::{Mysql, MysqlValue};
 use crate::serialize::{self, Output, ToSql};
-use crate::sql_types::{Date, Datetime, Time, Timestamp};
+use crate::sql_types::{BigInt, Date, Datetime, Interval, Time, Timestamp};
 
 use super::{MysqlTime, MysqlTimestampType};
 
@@ -205,6 +206,14 @@ impl FromSql<Date, Mysql> for NaiveDate {
     }
 }
 
+#[cfg(all(feature = "time", feature = "mysql_backend"))]
+impl ToSql<Interval, Mysql> for Duration {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+        let microseconds = i64::try_from(self.whole_microseconds())?;
+        ToSql::<BigInt, Mysql>::to_sql(&microseconds, &mut out.reborrow())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate dotenvy;
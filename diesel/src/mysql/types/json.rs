@@ -1,3 +1,5 @@
+#[cfg(feature = "serde")]
+use crate::data_types::Json;
 use crate::deserialize::{self, FromSql};
 use crate::mysql::{Mysql, MysqlValue};
 use crate::serialize::{self, IsNull, Output, ToSql};
@@ -19,6 +21,30 @@ impl ToSql<sql_types::Json, Mysql> for serde_json::Value {
     }
 }
 
+#[cfg(all(feature = "mysql_backend", feature = "serde"))]
+impl<T> FromSql<sql_types::Json, Mysql> for Json<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+        serde_json::from_slice(value.as_bytes())
+            .map(Json)
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(all(feature = "mysql_backend", feature = "serde"))]
+impl<T> ToSql<sql_types::Json, Mysql> for Json<T>
+where
+    T: serde::Serialize + core::fmt::Debug,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+        serde_json::to_writer(out, &self.0)
+            .map(|_| IsNull::No)
+            .map_err(Into::into)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -25,8 +25,11 @@ impl ToSql<TinyInt, Mysql> for i8 {
 #[cfg(feature = "mysql_backend")]
 impl FromSql<TinyInt, Mysql> for i8 {
     fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
-        let bytes = value.as_bytes();
-        Ok(i8::from_be_bytes([bytes[0]]))
+        let byte = value
+            .as_bytes()
+            .first()
+            .ok_or("Received an empty buffer while trying to read a TinyInt value")?;
+        Ok(i8::from_be_bytes([*byte]))
     }
 }
 
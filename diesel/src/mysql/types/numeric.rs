@@ -35,3 +35,46 @@ mod bigdecimal {
         }
     }
 }
+
+mod numeric_string {
+    use alloc::string::{String, ToString};
+    use std::io::Write;
+
+    use crate::data_types::NumericString;
+    use crate::deserialize::{self, FromSql};
+    use crate::mysql::{Mysql, MysqlType, MysqlValue, NumericRepresentation};
+    use crate::serialize::{self, IsNull, Output, ToSql};
+    use crate::sql_types::Numeric;
+
+    #[cfg(feature = "mysql_backend")]
+    impl ToSql<Numeric, Mysql> for NumericString {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Mysql>) -> serialize::Result {
+            out.write_all(self.as_str().as_bytes())
+                .map(|_| IsNull::No)
+                .map_err(Into::into)
+        }
+    }
+
+    #[cfg(feature = "mysql_backend")]
+    impl FromSql<Numeric, Mysql> for NumericString {
+        fn from_sql(value: MysqlValue<'_>) -> deserialize::Result<Self> {
+            let value = match value.value_type() {
+                // bind parameters are sometimes returned as string
+                MysqlType::String => core::str::from_utf8(value.as_bytes())?.to_string(),
+                _ => match value.numeric_value()? {
+                    NumericRepresentation::Tiny(x) => x.to_string(),
+                    NumericRepresentation::Small(x) => x.to_string(),
+                    NumericRepresentation::Medium(x) => x.to_string(),
+                    NumericRepresentation::Big(x) => x.to_string(),
+                    NumericRepresentation::Float(x) if x.is_finite() => x.to_string(),
+                    NumericRepresentation::Double(x) if x.is_finite() => x.to_string(),
+                    NumericRepresentation::Float(_) | NumericRepresentation::Double(_) => {
+                        return Err("Infinite values are not valid decimal numbers".into());
+                    }
+                    NumericRepresentation::Decimal(bytes) => String::from_utf8(bytes.to_vec())?,
+                },
+            };
+            NumericString::try_from(value)
+        }
+    }
+}
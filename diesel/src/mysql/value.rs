@@ -103,9 +103,14 @@ impl<'a> MysqlValue<'a> {
 
     /// Returns the numeric representation of this value, based on the type code.
     /// Returns an error if the type code is not numeric.
-    pub(crate) fn numeric_value(&self) -> deserialize::Result<NumericRepresentation<'_>> {
+    ///
+    /// This gives raw access to numeric values, without any conversion
+    /// to a specific rust type. For `DECIMAL` values the textual
+    /// representation send by the server is returned as is.
+    pub fn numeric_value(&self) -> deserialize::Result<NumericRepresentation<'_>> {
         Ok(match self.tpe {
             MysqlType::UnsignedTiny | MysqlType::Tiny => {
+                self.too_short_buffer(1, "Tiny")?;
                 NumericRepresentation::Tiny(self.raw[0].try_into()?)
             }
             MysqlType::UnsignedShort | MysqlType::Short => {
@@ -150,6 +150,8 @@ impl SqlDialect for Pg {
 
     type BuiltInWindowFunctionRequireOrder =
         sql_dialect::built_in_window_function_require_order::NoOrderRequired;
+
+    type IntervalArithmetic = sql_dialect::interval_arithmetic::AnsiSqlIntervalArithmetic;
 }
 
 impl DieselReserveSpecialization for Pg {}
@@ -2,6 +2,9 @@ use super::private::QueryFragmentHelper;
 use super::raw::RawConnection;
 use super::result::PgResult;
 use super::row::PgRow;
+use crate::connection::{AnsiTransactionManager, TransactionManager};
+use crate::pg::Pg;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
 use alloc::rc::Rc;
 
 #[allow(missing_debug_implementations)]
@@ -134,10 +137,7 @@ impl Drop for RowByRowCursor<'_, '_> {
                 if res.is_ok() {
                     self.query.instrumentation(&mut |query| {
                         self.conn.instrumentation.on_connection_event(
-                            crate::connection::InstrumentationEvent::FinishQuery {
-                                query,
-                                error: None,
-                            },
+                            crate::connection::InstrumentationEvent::finish_query(query, None),
                         );
                     });
                 }
@@ -147,10 +147,156 @@ impl Drop for RowByRowCursor<'_, '_> {
     }
 }
 
+// There can only be one server side cursor per connection at a time,
+// as the iterator borrows the connection mutably
+const SERVER_SIDE_CURSOR_NAME: &str = "diesel_cursor";
+
+/// The `DECLARE … CURSOR` statement used to
+/// load the results of `T` in batches
+pub(super) struct DeclareCursor<T>(pub(super) T);
+
+impl<T> QueryId for DeclareCursor<T> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<T> QueryFragment<Pg> for DeclareCursor<T>
+where
+    T: QueryFragment<Pg>,
+{
+    fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, Pg>) -> crate::QueryResult<()> {
+        pass.unsafe_to_cache_prepared();
+        pass.push_sql("DECLARE ");
+        pass.push_identifier(SERVER_SIDE_CURSOR_NAME)?;
+        // diesel expects all values in the binary format
+        pass.push_sql(" BINARY NO SCROLL CURSOR FOR ");
+        self.0.walk_ast(pass.reborrow())
+    }
+}
+
+/// The cursor used by [`PgCursorLoadingMode`](super::PgCursorLoadingMode)
+///
+/// Fetches the rows of a server side cursor in batches
+/// and closes the cursor once all rows were returned or
+/// the iterator is dropped.
+#[allow(missing_debug_implementations)]
+pub struct ServerSideCursor<'conn> {
+    current_row: usize,
+    db_result: Rc<PgResult>,
+    batch_size: usize,
+    // whether the cursor runs in a transaction
+    // started by diesel, which is committed at the end
+    own_transaction: bool,
+    finished: bool,
+    conn: &'conn mut super::PgConnection,
+}
+
+impl<'conn> ServerSideCursor<'conn> {
+    pub(super) fn new(
+        conn: &'conn mut super::PgConnection,
+        batch_size: usize,
+        own_transaction: bool,
+    ) -> crate::QueryResult<Self> {
+        match conn.exec_simple_query(&fetch_query(batch_size)) {
+            Ok(res) => Ok(Self {
+                current_row: 0,
+                db_result: Rc::new(res),
+                batch_size,
+                own_transaction,
+                finished: false,
+                conn,
+            }),
+            Err(e) => {
+                let _ = close_cursor(conn, own_transaction);
+                Err(e)
+            }
+        }
+    }
+
+    fn fetch_next_batch(&mut self) -> crate::QueryResult<()> {
+        let res = self.conn.exec_simple_query(&fetch_query(self.batch_size))?;
+        // we try to reuse the existing allocation here
+        if let Some(old_res) = Rc::get_mut(&mut self.db_result) {
+            *old_res = res;
+        } else {
+            self.db_result = Rc::new(res);
+        }
+        self.current_row = 0;
+        Ok(())
+    }
+
+    fn finish(&mut self) -> crate::QueryResult<()> {
+        if core::mem::replace(&mut self.finished, true) {
+            return Ok(());
+        }
+        close_cursor(self.conn, self.own_transaction)
+    }
+}
+
+fn fetch_query(batch_size: usize) -> String {
+    format!("FETCH FORWARD {batch_size} FROM \"{SERVER_SIDE_CURSOR_NAME}\"")
+}
+
+/// Closes the server side cursor and commits the
+/// transaction if it was started for the cursor
+pub(super) fn close_cursor(
+    conn: &mut super::PgConnection,
+    own_transaction: bool,
+) -> crate::QueryResult<()> {
+    let res = conn
+        .exec_simple_query(&format!("CLOSE \"{SERVER_SIDE_CURSOR_NAME}\""))
+        .map(|_| ());
+    if !own_transaction {
+        return res;
+    }
+    match res {
+        Ok(()) => AnsiTransactionManager::commit_transaction(conn),
+        Err(e) => {
+            // the error of the `CLOSE` statement
+            // is more interesting for the caller
+            let _ = AnsiTransactionManager::rollback_transaction(conn);
+            Err(e)
+        }
+    }
+}
+
+impl Iterator for ServerSideCursor<'_> {
+    type Item = crate::QueryResult<PgRow>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.finished {
+            return None;
+        }
+        if self.current_row == self.db_result.num_rows() {
+            // a batch with less rows than requested is the last one
+            if self.db_result.num_rows() < self.batch_size {
+                return self.finish().err().map(Err);
+            }
+            if let Err(e) = self.fetch_next_batch() {
+                let _ = self.finish();
+                return Some(Err(e));
+            }
+            if self.db_result.num_rows() == 0 {
+                return self.finish().err().map(Err);
+            }
+        }
+        let row = self.db_result.clone().get_row(self.current_row);
+        self.current_row += 1;
+        Some(Ok(row))
+    }
+}
+
+impl Drop for ServerSideCursor<'_> {
+    fn drop(&mut self) {
+        let _ = self.finish();
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::connection::DefaultLoadingMode;
-    use crate::pg::PgRowByRowLoadingMode;
+    use crate::pg::{PgCursorLoadingMode, PgRowByRowLoadingMode};
 
     #[diesel_test_helper::test]
     fn fun_with_row_iters() {
@@ -421,4 +567,69 @@ mod tests {
             expected[0].1
         );
     }
+
+    #[diesel_test_helper::test]
+    fn cursor_loading_mode_fetches_all_batches() {
+        use crate::prelude::*;
+        use crate::sql_types::Integer;
+
+        let query = crate::dsl::sql::<Integer>("SELECT generate_series(1, 10)");
+        let expected = (1..=10).collect::<Vec<i32>>();
+
+        // inside of an existing transaction
+        let conn = &mut crate::test_helpers::pg_connection();
+        let rows = query
+            .clone()
+            .load_iter::<i32, PgCursorLoadingMode<3>>(conn)
+            .unwrap()
+            .collect::<QueryResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(expected, rows);
+        // the batch size divides the number of rows
+        let rows = query
+            .clone()
+            .load_iter::<i32, PgCursorLoadingMode<5>>(conn)
+            .unwrap()
+            .collect::<QueryResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(expected, rows);
+
+        // the cursor starts its own transaction if there is none
+        let conn = &mut crate::test_helpers::pg_connection_no_transaction();
+        let rows = query
+            .clone()
+            .load_iter::<i32, PgCursorLoadingMode>(conn)
+            .unwrap()
+            .collect::<QueryResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(expected, rows);
+        assert_eq!(
+            super::super::PgTransactionStatus::Idle,
+            conn.connection_and_transaction_manager
+                .raw_connection
+                .transaction_status()
+        );
+
+        // dropping the iterator early closes the cursor
+        let first_rows = query
+            .clone()
+            .load_iter::<i32, PgCursorLoadingMode<3>>(conn)
+            .unwrap()
+            .take(4)
+            .collect::<QueryResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(vec![1, 2, 3, 4], first_rows);
+        assert_eq!(
+            super::super::PgTransactionStatus::Idle,
+            conn.connection_and_transaction_manager
+                .raw_connection
+                .transaction_status()
+        );
+        let rows = query
+            .load_iter::<i32, PgCursorLoadingMode<3>>(conn)
+            .unwrap()
+            .collect::<QueryResult<Vec<_>>>()
+            .unwrap();
+        assert_eq!(expected, rows);
+    }
 }
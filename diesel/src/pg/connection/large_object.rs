@@ -0,0 +1,188 @@
+#![allow(unsafe_code)] // ffi code
+
+use core::ffi as libc;
+use std::io::{Read, Seek, SeekFrom, Write};
+
+use super::raw::RawConnection;
+use crate::QueryResult;
+use crate::pg::data_types::PgLargeObjectId;
+use crate::result::{DatabaseErrorKind, Error};
+
+// see `libpq/libpq-fs.h`
+const INV_WRITE: libc::c_int = 0x0002_0000;
+const INV_READ: libc::c_int = 0x0004_0000;
+
+/// The mode used to open a large object via
+/// [`PgConnection::open_large_object`](super::PgConnection::open_large_object)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PgLargeObjectMode {
+    /// Open the large object for reading only
+    ///
+    /// Reads observe the state of the large object as of the start of
+    /// the current transaction snapshot.
+    Read,
+    /// Open the large object for reading and writing
+    ReadWrite,
+}
+
+/// A handle to a PostgreSQL large object
+///
+/// This type allows to incrementally read and write large objects
+/// without buffering their whole content in memory.
+/// It implements [`std::io::Read`], [`std::io::Write`] and
+/// [`std::io::Seek`] to integrate with standard Rust IO mechanisms.
+///
+/// The handle is closed as soon as this value is dropped. Use
+/// [`PgLargeObject::close`] to observe errors while closing the handle.
+///
+/// You can use [`PgConnection::open_large_object`](super::PgConnection::open_large_object)
+/// to get a new instance of this type.
+///
+/// See the [PostgreSQL documentation](https://www.postgresql.org/docs/current/largeobjects.html)
+/// for more details
+#[allow(missing_debug_implementations)] // `PgConnection` is not debug
+pub struct PgLargeObject<'conn> {
+    conn: &'conn mut RawConnection,
+    fd: libc::c_int,
+}
+
+impl<'conn> PgLargeObject<'conn> {
+    pub(super) fn open(
+        conn: &'conn mut RawConnection,
+        oid: PgLargeObjectId,
+        mode: PgLargeObjectMode,
+    ) -> QueryResult<Self> {
+        let mode = match mode {
+            PgLargeObjectMode::Read => INV_READ,
+            PgLargeObjectMode::ReadWrite => INV_READ | INV_WRITE,
+        };
+        let fd = unsafe { pq_sys::lo_open(conn.internal_connection.as_ptr(), oid.0, mode) };
+        if fd < 0 {
+            return Err(last_error(conn));
+        }
+        Ok(Self { conn, fd })
+    }
+
+    /// Truncate (or extend with zero bytes) the large object to the given length
+    pub fn truncate(&mut self, len: u64) -> QueryResult<()> {
+        let len = i64::try_from(len).map_err(|e| Error::SerializationError(Box::new(e)))?;
+        let ret =
+            unsafe { pq_sys::lo_truncate64(self.conn.internal_connection.as_ptr(), self.fd, len) };
+        if ret < 0 {
+            return Err(last_error(self.conn));
+        }
+        Ok(())
+    }
+
+    /// Close the handle
+    pub fn close(mut self) -> QueryResult<()> {
+        let ret = self.close_inner();
+        // the handle is closed even if an error occurred
+        self.fd = -1;
+        ret
+    }
+
+    fn close_inner(&mut self) -> QueryResult<()> {
+        let ret = unsafe { pq_sys::lo_close(self.conn.internal_connection.as_ptr(), self.fd) };
+        if ret < 0 {
+            return Err(last_error(self.conn));
+        }
+        Ok(())
+    }
+}
+
+impl Drop for PgLargeObject<'_> {
+    fn drop(&mut self) {
+        if self.fd >= 0 {
+            // errors are ignored here, as the handle is closed
+            // by the server at the end of the transaction anyway
+            let _ = self.close_inner();
+        }
+    }
+}
+
+impl Read for PgLargeObject<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        // the return value of `lo_read` is an int
+        // so we cannot read more than `i32::MAX` bytes at once
+        let len = buf.len().min(i32::MAX as usize);
+        let ret = unsafe {
+            pq_sys::lo_read(
+                self.conn.internal_connection.as_ptr(),
+                self.fd,
+                buf.as_mut_ptr() as *mut libc::c_char,
+                len,
+            )
+        };
+        usize::try_from(ret).map_err(|_| std::io::Error::other(last_error(self.conn)))
+    }
+}
+
+impl Write for PgLargeObject<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let len = buf.len().min(i32::MAX as usize);
+        let ret = unsafe {
+            pq_sys::lo_write(
+                self.conn.internal_connection.as_ptr(),
+                self.fd,
+                buf.as_ptr() as *const libc::c_char,
+                len,
+            )
+        };
+        usize::try_from(ret).map_err(|_| std::io::Error::other(last_error(self.conn)))
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for PgLargeObject<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        // see `SEEK_SET`, `SEEK_CUR` and `SEEK_END` in `stdio.h`
+        let (offset, whence) = match pos {
+            SeekFrom::Start(n) => (i64::try_from(n).map_err(to_io_error)?, 0),
+            SeekFrom::Current(n) => (n, 1),
+            SeekFrom::End(n) => (n, 2),
+        };
+        let ret = unsafe {
+            pq_sys::lo_lseek64(
+                self.conn.internal_connection.as_ptr(),
+                self.fd,
+                offset,
+                whence,
+            )
+        };
+        u64::try_from(ret).map_err(|_| std::io::Error::other(last_error(self.conn)))
+    }
+}
+
+#[allow(clippy::std_instead_of_core)] // needs a newer rust version
+fn to_io_error(error: core::num::TryFromIntError) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidInput, Box::new(error))
+}
+
+pub(super) fn create(conn: &mut RawConnection) -> QueryResult<PgLargeObjectId> {
+    // passing `InvalidOid` lets the server assign an unused oid
+    let oid = unsafe { pq_sys::lo_create(conn.internal_connection.as_ptr(), 0) };
+    if oid == 0 {
+        return Err(last_error(conn));
+    }
+    Ok(PgLargeObjectId(oid))
+}
+
+pub(super) fn unlink(conn: &mut RawConnection, oid: PgLargeObjectId) -> QueryResult<()> {
+    let ret = unsafe { pq_sys::lo_unlink(conn.internal_connection.as_ptr(), oid.0) };
+    if ret < 0 {
+        return Err(last_error(conn));
+    }
+    Ok(())
+}
+
+fn last_error(conn: &RawConnection) -> Error {
+    Error::DatabaseError(
+        DatabaseErrorKind::Unknown,
+        Box::new(conn.last_error_message()),
+    )
+}
@@ -1,5 +1,8 @@
 pub(super) mod copy;
 pub(crate) mod cursor;
+mod large_object;
+#[cfg(feature = "pipeline_mode_for_libpq_14")]
+pub mod pipeline;
 mod raw;
 mod result;
 mod row;
@@ -7,24 +10,24 @@ mod stmt;
 
 use self::copy::{CopyFromSink, CopyToBuffer};
 use self::cursor::*;
+pub use self::large_object::{PgLargeObject, PgLargeObjectMode};
 use self::private::{ConnectionAndTransactionManager, CopyFromWrapper, QueryFragmentHelper};
 use self::raw::{PgTransactionStatus, RawConnection};
 use self::stmt::Statement;
 use crate::RunQueryDsl;
 use crate::connection::instrumentation::{DynInstrumentation, Instrumentation, StrQueryHelper};
-use crate::connection::statement_cache::{MaybeCached, StatementCache};
+use crate::connection::statement_cache::{MaybeCached, PrepareForCache, StatementCache};
 use crate::connection::*;
 use crate::expression::QueryMetadata;
 use crate::pg::backend::PgNotification;
 use crate::pg::metadata_lookup::{GetPgMetadataCache, PgMetadataCache};
 use crate::pg::query_builder::copy::InternalCopyFromQuery;
-use crate::pg::{Pg, TransactionBuilder};
+use crate::pg::{Pg, PgQueryBuilder, TransactionBuilder};
 use crate::query_builder::bind_collector::RawBytesBindCollector;
 use crate::query_builder::*;
 use crate::result::ConnectionError::CouldntSetupConfiguration;
 use crate::result::*;
 use alloc::ffi::CString;
-use core::ffi as libc;
 use core::fmt::Debug;
 
 use super::query_builder::copy::{CopyFromExpression, CopyTarget, CopyToCommand};
@@ -35,10 +38,32 @@ pub(super) use self::result::PgResult;
 /// should be a PostgreSQL connection string, as documented at
 /// <https://www.postgresql.org/docs/9.4/static/libpq-connect.html#LIBPQ-CONNSTRING>
 ///
+/// Connection URLs may list several comma separated hosts, which are tried in
+/// order until a connection succeeds. Combined with the `target_session_attrs`
+/// parameter this allows a cluster with a primary and several standbys to be
+/// addressed with a single URL, as only the server accepting writes is chosen
+/// for `postgresql://primary:5432,standby:5432/app?target_session_attrs=read-write`.
+/// See the [libpq documentation](https://www.postgresql.org/docs/current/libpq-connect.html#LIBPQ-MULTIPLE-HOSTS)
+/// for details.
+///
+/// # Transaction pooling
+///
+/// Connection poolers like PgBouncer running in transaction pooling mode
+/// might hand out a different server connection for each transaction, which
+/// causes "prepared statement does not exist" errors for statements prepared
+/// by diesel in an earlier transaction. Adding `prepared_statements=false` to
+/// the connection URL (e.g. `postgresql://localhost/app?prepared_statements=false`)
+/// or the keyword/value connection string makes diesel execute all queries as
+/// unnamed statements via the extended query protocol instead. These statements
+/// are never stored in the prepared statement cache, which also applies to
+/// queries using [`always_prepare`](crate::query_dsl::StatementCacheDsl::always_prepare()).
+/// This option is handled by diesel and is not passed to libpq.
+///
 /// # Supported loading model implementations
 ///
 /// * [`DefaultLoadingMode`]
 /// * [`PgRowByRowLoadingMode`]
+/// * [`PgCursorLoadingMode`]
 ///
 /// If you are unsure which loading mode is the correct one for your application,
 /// you likely want to use the `DefaultLoadingMode` as it's simpler and more intuitive.
@@ -120,12 +145,49 @@ pub(super) use self::result::PgResult;
 /// #   Ok(())
 /// # }
 /// ```
+///
+/// ## `PgCursorLoadingMode`
+///
+/// By using this mode `PgConnection` declares a server side cursor for the query
+/// and fetches `BATCH_SIZE` rows at once from it, which defaults to 1000 rows.
+/// This keeps the memory usage bounded for huge result sets, while requiring
+/// fewer round trips than the `PgRowByRowLoadingMode`.
+///
+/// As cursors can only be used inside of a transaction, a transaction is started
+/// if the connection is not already inside of one. This transaction is committed
+/// as soon as all rows were loaded or the iterator is dropped.
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::users;
+/// #     let connection = &mut establish_connection();
+/// use diesel::pg::PgCursorLoadingMode;
+///
+/// let iter = users::table.load_iter::<(i32, String), PgCursorLoadingMode<100>>(connection)?;
+///
+/// for r in iter {
+///     let (id, name) = r?;
+///     println!("Id: {} Name: {}", id, name);
+/// }
+/// #   Ok(())
+/// # }
+/// ```
+///
+/// Like the `PgRowByRowLoadingMode` this loading mode **prevents** creating more
+/// than one iterator at once using the same connection.
 #[allow(missing_debug_implementations)]
 #[cfg(feature = "postgres")]
 pub struct PgConnection {
     statement_cache: StatementCache<Pg, Statement>,
     metadata_cache: PgMetadataCache,
     connection_and_transaction_manager: ConnectionAndTransactionManager,
+    prepared_statements: bool,
 }
 
 // according to libpq documentation a connection can be transferred to other threads
@@ -133,30 +195,8 @@ pub struct PgConnection {
 unsafe impl Send for PgConnection {}
 
 impl SimpleConnection for PgConnection {
-    #[allow(unsafe_code)] // use of unsafe function
     fn batch_execute(&mut self, query: &str) -> QueryResult<()> {
-        self.connection_and_transaction_manager
-            .instrumentation
-            .on_connection_event(InstrumentationEvent::StartQuery {
-                query: &StrQueryHelper::new(query),
-            });
-        let c_query = CString::new(query)?;
-        let inner_result = unsafe {
-            self.connection_and_transaction_manager
-                .raw_connection
-                .exec(c_query.as_ptr())
-        };
-        update_transaction_manager_status(
-            inner_result.and_then(|raw_result| {
-                PgResult::new(
-                    raw_result,
-                    &self.connection_and_transaction_manager.raw_connection,
-                )
-            }),
-            &mut self.connection_and_transaction_manager,
-            &|callback| callback(&StrQueryHelper::new(query)),
-            true,
-        )?;
+        self.exec_simple_query(query)?;
         Ok(())
     }
 }
@@ -167,6 +207,13 @@ impl SimpleConnection for PgConnection {
 #[derive(Debug, Copy, Clone)]
 pub struct PgRowByRowLoadingMode;
 
+/// A [`PgConnection`] specific loading mode to load rows in batches
+/// of `BATCH_SIZE` rows via a server side cursor
+///
+/// See the documentation of [`PgConnection`] for details
+#[derive(Debug, Copy, Clone)]
+pub struct PgCursorLoadingMode<const BATCH_SIZE: usize = 1000>;
+
 impl ConnectionSealed for PgConnection {}
 
 impl Connection for PgConnection {
@@ -178,20 +225,11 @@ impl Connection for PgConnection {
         instrumentation.on_connection_event(InstrumentationEvent::StartEstablishConnection {
             url: database_url,
         });
-        let r = RawConnection::establish(database_url).and_then(|raw_conn| {
-            let mut conn = PgConnection {
-                connection_and_transaction_manager: ConnectionAndTransactionManager {
-                    raw_connection: raw_conn,
-                    transaction_state: AnsiTransactionManager::default(),
-                    instrumentation: DynInstrumentation::none(),
-                },
-                statement_cache: StatementCache::new(),
-                metadata_cache: PgMetadataCache::new(),
-            };
-            conn.set_config_options()
-                .map_err(CouldntSetupConfiguration)?;
-            Ok(conn)
-        });
+        let r = extract_prepared_statements_option(database_url).and_then(
+            |(database_url, prepared_statements)| {
+                Self::establish_with_options(&database_url, prepared_statements)
+            },
+        );
         instrumentation.on_connection_event(InstrumentationEvent::FinishEstablishConnection {
             url: database_url,
             error: r.as_ref().err(),
@@ -225,6 +263,46 @@ impl Connection for PgConnection {
         )
     }
 
+    fn execute_returning_count_with_warnings<T>(
+        &mut self,
+        source: &T,
+    ) -> QueryResult<(usize, Vec<DatabaseWarning>)>
+    where
+        T: QueryFragment<Pg> + QueryId,
+    {
+        self.connection_and_transaction_manager
+            .raw_connection
+            .start_collecting_warnings();
+        let count = self.execute_returning_count(source);
+        let warnings = self
+            .connection_and_transaction_manager
+            .raw_connection
+            .finish_collecting_warnings();
+        let count = count?;
+        if !warnings.is_empty() {
+            self.connection_and_transaction_manager
+                .instrumentation
+                .on_connection_event(InstrumentationEvent::QueryWarnings {
+                    query: &crate::debug_query(source),
+                    warnings: &warnings,
+                });
+        }
+        Ok((count, warnings))
+    }
+
+    fn begin_transaction_with_sql(
+        &mut self,
+        isolation: Isolation,
+        access: Access,
+    ) -> QueryResult<alloc::borrow::Cow<'static, str>> {
+        Ok(alloc::format!(
+            "BEGIN TRANSACTION ISOLATION LEVEL {} {}",
+            isolation.to_sql(),
+            access.to_sql()
+        )
+        .into())
+    }
+
     fn transaction_state(&mut self) -> &mut AnsiTransactionManager
     where
         Self: Sized,
@@ -243,6 +321,21 @@ impl Connection for PgConnection {
     fn set_prepared_statement_cache_size(&mut self, size: CacheSize) {
         self.statement_cache.set_cache_size(size);
     }
+
+    fn clear_prepared_statement_cache(&mut self) {
+        self.statement_cache.clear();
+        self.connection_and_transaction_manager
+            .raw_connection
+            .deallocate_dropped_statements();
+    }
+
+    fn cached_statements(&self) -> Vec<String> {
+        self.statement_cache
+            .cached_sql()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
 }
 
 impl<B> LoadConnection<B> for PgConnection
@@ -272,6 +365,56 @@ where
     }
 }
 
+impl<const BATCH_SIZE: usize> LoadConnection<PgCursorLoadingMode<BATCH_SIZE>> for PgConnection {
+    type Cursor<'conn, 'query> = ServerSideCursor<'conn>;
+    type Row<'conn, 'query> = self::row::PgRow;
+
+    fn load<'conn, 'query, T>(
+        &'conn mut self,
+        source: T,
+    ) -> QueryResult<Self::Cursor<'conn, 'query>>
+    where
+        T: Query + QueryFragment<Self::Backend> + QueryId + 'query,
+        Self::Backend: QueryMetadata<T::SqlType>,
+    {
+        const {
+            assert!(
+                BATCH_SIZE > 0,
+                "The batch size of a cursor must not be zero"
+            );
+        }
+        // a cursor can only be declared inside of a transaction
+        let own_transaction = self
+            .connection_and_transaction_manager
+            .raw_connection
+            .transaction_status()
+            == PgTransactionStatus::Idle;
+        if own_transaction {
+            AnsiTransactionManager::begin_transaction(self)?;
+        }
+        let declare = self.with_prepared_query(
+            Box::new(DeclareCursor(source)),
+            false,
+            &mut |stmt, params, conn, source| {
+                let result = inner_load(stmt, params, conn, &*source, false);
+                update_transaction_manager_status(
+                    result.map(|_| ()),
+                    conn,
+                    &|callback| source.instrumentation(callback),
+                    true,
+                )
+            },
+        );
+        if let Err(e) = declare {
+            if own_transaction {
+                let _ = AnsiTransactionManager::rollback_transaction(self);
+            }
+            return Err(e);
+        }
+        ServerSideCursor::new(self, BATCH_SIZE, own_transaction)
+    }
+}
+
 fn inner_load(
     stmt: MaybeCached<'_, Statement>,
     params: Vec<Option<Vec<u8>>>,
@@ -362,15 +505,12 @@ fn update_transaction_manager_status<T>(
         if let Err(e) = query_result {
             instrumentation_callback(&mut |query| {
                 conn.instrumentation
-                    .on_connection_event(InstrumentationEvent::FinishQuery {
-                        query,
-                        error: Some(e),
-                    })
+                    .on_connection_event(InstrumentationEvent::finish_query(query, Some(e)))
             });
         } else if final_call {
             instrumentation_callback(&mut |query| {
                 conn.instrumentation
-                    .on_connection_event(InstrumentationEvent::FinishQuery { query, error: None });
+                    .on_connection_event(InstrumentationEvent::finish_query(query, None));
             });
         }
     }
@@ -479,17 +619,13 @@ impl PgConnection {
                     );
                     source.instrumentation(&mut |query| {
                         conn.instrumentation.on_connection_event(
-                            InstrumentationEvent::FinishQuery {
-                                query,
-                                error: Some(&database_error),
-                            },
+                            InstrumentationEvent::finish_query(query, Some(&database_error)),
                         );
                     });
                 } else {
                     source.instrumentation(&mut |query| {
-                        conn.instrumentation.on_connection_event(
-                            InstrumentationEvent::FinishQuery { query, error: None },
-                        );
+                        conn.instrumentation
+                            .on_connection_event(InstrumentationEvent::finish_query(query, None));
                     });
                 }
 
@@ -503,21 +639,20 @@ impl PgConnection {
     where
         T: CopyTarget,
     {
-        let res = self.with_prepared_query::<_, Error>(
-            Box::new(command),
-            false,
-            &mut |stmt, binds, conn, source| {
-                let res = stmt.execute(&mut conn.raw_connection, &binds, false);
-                source.instrumentation(&mut |query| {
-                    conn.instrumentation
-                        .on_connection_event(InstrumentationEvent::FinishQuery {
-                            query,
-                            error: res.as_ref().err(),
-                        });
-                });
-                Ok(CopyToBuffer::new(&mut conn.raw_connection, res?))
-            },
-        )?;
+        let res =
+            self.with_prepared_query::<_, Error>(
+                Box::new(command),
+                false,
+                &mut |stmt, binds, conn, source| {
+                    let res = stmt.execute(&mut conn.raw_connection, &binds, false);
+                    source.instrumentation(&mut |query| {
+                        conn.instrumentation.on_connection_event(
+                            InstrumentationEvent::finish_query(query, res.as_ref().err()),
+                        );
+                    });
+                    Ok(CopyToBuffer::new(&mut conn.raw_connection, res?))
+                },
+            )?;
         Ok(res)
     }
 
@@ -538,6 +673,7 @@ impl PgConnection {
         fn prepare_query_non_generic_inner<'a, E>(
             connection_and_transaction_manager: &mut ConnectionAndTransactionManager,
             cache: &'a mut StatementCache<Pg, Statement>,
+            prepared_statements: bool,
             source: &dyn QueryFragmentHelper<E>,
             execute_returning_count: bool,
             bind_collector: RawBytesBindCollector<Pg>,
@@ -547,23 +683,33 @@ impl PgConnection {
         )> {
             let binds = bind_collector.binds;
             let metadata = bind_collector.metadata;
-            let query = cache.cached_statement_non_generic(
-                source.query_id(),
-                source,
-                &Pg,
-                &metadata,
-                &mut connection_and_transaction_manager.raw_connection,
-                Statement::prepare,
-                &mut *connection_and_transaction_manager.instrumentation,
-            );
+            let query = if prepared_statements {
+                cache.cached_statement_non_generic(
+                    source.query_id(),
+                    source,
+                    &Pg,
+                    &metadata,
+                    &mut connection_and_transaction_manager.raw_connection,
+                    Statement::prepare,
+                    connection_and_transaction_manager.instrumentation.tracked(),
+                )
+            } else {
+                // skip the cache so that no named statement is ever prepared
+                source.construct_sql(&Pg).and_then(|sql| {
+                    Statement::prepare(
+                        &mut connection_and_transaction_manager.raw_connection,
+                        &sql,
+                        PrepareForCache::No,
+                        &metadata,
+                    )
+                    .map(MaybeCached::CannotCache)
+                })
+            };
             if !execute_returning_count && let Err(ref e) = query {
                 source.instrumentation(&mut |query| {
                     connection_and_transaction_manager
                         .instrumentation
-                        .on_connection_event(InstrumentationEvent::FinishQuery {
-                            query,
-                            error: Some(e),
-                        });
+                        .on_connection_event(InstrumentationEvent::finish_query(query, Some(e)));
                 });
             }
             Ok((binds, query))
@@ -573,6 +719,7 @@ impl PgConnection {
         let (binds, query) = prepare_query_non_generic_inner(
             &mut self.connection_and_transaction_manager,
             &mut self.statement_cache,
+            self.prepared_statements,
             &*source,
             execute_returning_count,
             bind_collector,
@@ -600,12 +747,29 @@ impl PgConnection {
         Ok(bind_collector)
     }
 
+    fn establish_with_options(
+        database_url: &str,
+        prepared_statements: bool,
+    ) -> ConnectionResult<Self> {
+        let raw_conn = RawConnection::establish(database_url)?;
+        let mut conn = PgConnection {
+            connection_and_transaction_manager: ConnectionAndTransactionManager {
+                raw_connection: raw_conn,
+                transaction_state: AnsiTransactionManager::default(),
+                instrumentation: DynInstrumentation::none(),
+            },
+            statement_cache: StatementCache::new(),
+            metadata_cache: PgMetadataCache::new(),
+            prepared_statements,
+        };
+        conn.set_config_options()
+            .map_err(CouldntSetupConfiguration)?;
+        Ok(conn)
+    }
+
     fn set_config_options(&mut self) -> QueryResult<()> {
         crate::sql_query("SET TIME ZONE 'UTC'").execute(self)?;
         crate::sql_query("SET CLIENT_ENCODING TO 'UTF8'").execute(self)?;
-        self.connection_and_transaction_manager
-            .raw_connection
-            .set_notice_processor(noop_notice_processor);
         Ok(())
     }
 
@@ -653,9 +817,198 @@ impl PgConnection {
         let conn = &self.connection_and_transaction_manager.raw_connection;
         core::iter::from_fn(move || conn.pq_notifies().transpose())
     }
-}
 
-extern "C" fn noop_notice_processor(_: *mut libc::c_void, _message: *const libc::c_char) {}
+    /// Starts listening for notifications on the given channel
+    ///
+    /// The channel name is quoted, so it is matched case sensitively, the
+    /// same way as the channel passed to [`diesel::dsl::notify`]. Listening
+    /// on a channel inside of a transaction only takes effect once the
+    /// transaction commits. Received notifications are returned by
+    /// [`PgConnection::notifications`].
+    ///
+    /// See the [PostgreSQL documentation](https://www.postgresql.org/docs/current/sql-listen.html)
+    /// for more details
+    ///
+    /// [`diesel::dsl::notify`]: crate::dsl::notify()
+    pub fn listen(&mut self, channel: &str) -> QueryResult<()> {
+        let mut query = PgQueryBuilder::new();
+        query.push_sql("LISTEN ");
+        query.push_identifier(channel)?;
+        self.batch_execute(&query.finish())
+    }
+
+    /// Returns a blocking iterator over the notifications received
+    /// on the channels this connection [listens](PgConnection::listen) on
+    ///
+    /// Each call to `next` returns an already received notification
+    /// right away, or otherwise waits for the next notification to
+    /// arrive. The iterator returns `None` if no notification arrived
+    /// within `timeout`. It can be polled again afterwards to continue
+    /// waiting for further notifications.
+    ///
+    /// Use [`PgConnection::notifications_iter`] to only check for already
+    /// received notifications without waiting.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # include!("../../doctest_setup.rs");
+    /// # use std::time::Duration;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let connection = &mut connection_no_transaction();
+    /// connection.listen("cache_invalidation")?;
+    ///
+    /// std::thread::spawn(|| -> QueryResult<()> {
+    ///     // notifications are usually sent from a different application
+    ///     let other_connection = &mut PgConnection::establish(&database_url_for_env()).unwrap();
+    ///     diesel::dsl::notify("cache_invalidation", "users").execute(other_connection)?;
+    ///     Ok(())
+    /// });
+    ///
+    /// let notification = connection
+    ///     .notifications(Duration::from_secs(5))
+    ///     .next()
+    ///     .unwrap()?;
+    /// assert_eq!("cache_invalidation", notification.channel);
+    /// assert_eq!("users", notification.payload);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn notifications(
+        &mut self,
+        timeout: core::time::Duration,
+    ) -> impl Iterator<Item = QueryResult<PgNotification>> + '_ {
+        let conn = &self.connection_and_transaction_manager.raw_connection;
+        core::iter::from_fn(move || conn.wait_for_notification(timeout).transpose())
+    }
+
+    /// Creates a new, empty large object and returns its oid
+    ///
+    /// Large objects allow to store binary data which is too large to be
+    /// handled as a single `bytea` value. Their content can be streamed via
+    /// [`PgConnection::open_large_object`]. The returned
+    /// [`PgLargeObjectId`](crate::pg::data_types::PgLargeObjectId) is usually
+    /// stored in a column of the [`Oid`](crate::sql_types::Oid) type.
+    ///
+    /// This function needs to be called inside of a transaction,
+    /// otherwise [`Error::NotInTransaction`] is returned.
+    ///
+    /// See the [PostgreSQL documentation](https://www.postgresql.org/docs/current/largeobjects.html)
+    /// for more details
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let connection = &mut establish_connection();
+    /// use diesel::pg::PgLargeObjectMode;
+    /// use std::io::{Read, Seek, SeekFrom, Write};
+    ///
+    /// connection.transaction(|connection| {
+    ///     let oid = connection.create_large_object()?;
+    ///
+    ///     let mut object = connection.open_large_object(oid, PgLargeObjectMode::ReadWrite)?;
+    ///     // data is written in chunks, without buffering the whole object in memory
+    ///     for chunk in [&b"Hello"[..], b", ", b"World"] {
+    ///         object.write_all(chunk).unwrap();
+    ///     }
+    ///
+    ///     let mut content = String::new();
+    ///     object.seek(SeekFrom::Start(0)).unwrap();
+    ///     object.read_to_string(&mut content).unwrap();
+    ///     assert_eq!("Hello, World", content);
+    ///     object.close()?;
+    ///
+    ///     connection.unlink_large_object(oid)
+    /// })
+    /// # }
+    /// ```
+    pub fn create_large_object(&mut self) -> QueryResult<crate::pg::data_types::PgLargeObjectId> {
+        let raw_connection = self.raw_connection_in_transaction()?;
+        large_object::create(raw_connection)
+    }
+
+    /// Opens the large object with the given oid
+    ///
+    /// The oid is usually loaded from a column of the
+    /// [`Oid`](crate::sql_types::Oid) type as
+    /// [`PgLargeObjectId`](crate::pg::data_types::PgLargeObjectId).
+    ///
+    /// The returned handle implements [`std::io::Read`], [`std::io::Write`] and
+    /// [`std::io::Seek`], which allows to stream the content of the large object.
+    /// See [`PgConnection::create_large_object`] for an example.
+    ///
+    /// This function needs to be called inside of a transaction,
+    /// otherwise [`Error::NotInTransaction`] is returned. The handle
+    /// cannot be used after the transaction has finished.
+    pub fn open_large_object(
+        &mut self,
+        oid: crate::pg::data_types::PgLargeObjectId,
+        mode: PgLargeObjectMode,
+    ) -> QueryResult<PgLargeObject<'_>> {
+        let raw_connection = self.raw_connection_in_transaction()?;
+        PgLargeObject::open(raw_connection, oid, mode)
+    }
+
+    /// Removes the large object with the given oid from the database
+    ///
+    /// This function needs to be called inside of a transaction,
+    /// otherwise [`Error::NotInTransaction`] is returned.
+    pub fn unlink_large_object(
+        &mut self,
+        oid: crate::pg::data_types::PgLargeObjectId,
+    ) -> QueryResult<()> {
+        let raw_connection = self.raw_connection_in_transaction()?;
+        large_object::unlink(raw_connection, oid)
+    }
+
+    /// Executes the given query via the simple query protocol
+    /// and returns the result of the last statement
+    #[allow(unsafe_code)] // use of unsafe function
+    fn exec_simple_query(&mut self, query: &str) -> QueryResult<PgResult> {
+        self.connection_and_transaction_manager
+            .instrumentation
+            .on_connection_event(InstrumentationEvent::StartQuery {
+                query: &StrQueryHelper::new(query),
+            });
+        let c_query = CString::new(query)?;
+        let inner_result = unsafe {
+            self.connection_and_transaction_manager
+                .raw_connection
+                .exec(c_query.as_ptr())
+        };
+        update_transaction_manager_status(
+            inner_result.and_then(|raw_result| {
+                PgResult::new(
+                    raw_result,
+                    &self.connection_and_transaction_manager.raw_connection,
+                )
+            }),
+            &mut self.connection_and_transaction_manager,
+            &|callback| callback(&StrQueryHelper::new(query)),
+            true,
+        )
+    }
+
+    fn raw_connection_in_transaction(&mut self) -> QueryResult<&mut RawConnection> {
+        let raw_connection = &mut self.connection_and_transaction_manager.raw_connection;
+        if raw_connection.transaction_status() == PgTransactionStatus::Idle {
+            return Err(Error::NotInTransaction);
+        }
+        Ok(raw_connection)
+    }
+}
 
 mod private {
     use super::*;
@@ -784,6 +1137,10 @@ mod private {
         fn is_safe_to_cache_prepared(&self, backend: &Pg) -> QueryResult<bool> {
             self.0.borrow().is_safe_to_cache_prepared(backend)
         }
+
+        fn is_forced_to_cache_prepared(&self, backend: &Pg) -> QueryResult<bool> {
+            self.0.borrow().is_forced_to_cache_prepared(backend)
+        }
     }
 
     impl<S, T> QueryFragmentHelper<S::Error> for CopyFromWrapper<S, T>
@@ -821,6 +1178,65 @@ mod private {
     }
 }
 
+/// Removes the `prepared_statements` option from the connection string,
+/// as libpq rejects options it does not know, and returns its value
+fn extract_prepared_statements_option(database_url: &str) -> ConnectionResult<(String, bool)> {
+    const OPTION: &str = "prepared_statements";
+
+    let parse_value = |value: &str| match value {
+        "true" | "on" | "1" => Ok(true),
+        "false" | "off" | "0" => Ok(false),
+        _ => Err(ConnectionError::InvalidConnectionUrl(format!(
+            "Invalid value `{value}` for the `{OPTION}` option, expected `true` or `false`"
+        ))),
+    };
+
+    let mut prepared_statements = true;
+    if database_url.starts_with("postgresql://") || database_url.starts_with("postgres://") {
+        let Some((base, params)) = database_url.split_once('?') else {
+            return Ok((database_url.to_owned(), prepared_statements));
+        };
+        let mut remaining = Vec::new();
+        for param in params.split('&') {
+            match param.split_once('=') {
+                Some((OPTION, value)) => prepared_statements = parse_value(value)?,
+                _ => remaining.push(param),
+            }
+        }
+        let database_url = if remaining.is_empty() {
+            base.to_owned()
+        } else {
+            format!("{base}?{}", remaining.join("&"))
+        };
+        Ok((database_url, prepared_statements))
+    } else {
+        let mut database_url = database_url.to_owned();
+        while let Some((start, end, value)) = find_keyword_option(&database_url, OPTION) {
+            prepared_statements = parse_value(value)?;
+            database_url.replace_range(start..end, "");
+        }
+        Ok((database_url, prepared_statements))
+    }
+}
+
+/// Finds a `keyword = value` pair in a keyword/value connection string
+/// and returns its byte range together with the value
+fn find_keyword_option<'a>(conninfo: &'a str, keyword: &str) -> Option<(usize, usize, &'a str)> {
+    conninfo.match_indices(keyword).find_map(|(start, _)| {
+        let at_boundary = conninfo[..start]
+            .chars()
+            .next_back()
+            .is_none_or(char::is_whitespace);
+        let value = conninfo[start + keyword.len()..]
+            .trim_start()
+            .strip_prefix('=')?
+            .trim_start();
+        let value_len = value.find(char::is_whitespace).unwrap_or(value.len());
+        let end = conninfo.len() - value.len() + value_len;
+        at_boundary.then(|| (start, end, &value[..value_len]))
+    })
+}
+
 #[cfg(test)]
 // that's a false positive for `panic!`/`assert!` on rust 2018
 #[allow(clippy::uninlined_format_args)]
@@ -836,6 +1252,91 @@ mod tests {
         crate::test_helpers::pg_connection_no_transaction()
     }
 
+    #[diesel_test_helper::test]
+    fn establish_tries_hosts_in_order() {
+        use crate::dsl::sql;
+        use crate::sql_types::Bool;
+
+        let database_url = crate::test_helpers::pg_database_url();
+        let (scheme, rest) = database_url
+            .split_once("://")
+            .expect("The test database url is an URL");
+        let (user_info, rest) = match rest.rsplit_once('@') {
+            Some((user_info, rest)) => (format!("{user_info}@"), rest),
+            None => (String::new(), rest),
+        };
+        let separator = if rest.contains('?') { '&' } else { '?' };
+        // nothing listens on port 1, so libpq falls back to the second host
+        let multi_host_url = format!(
+            "{scheme}://{user_info}localhost:1,{rest}{separator}target_session_attrs=read-write"
+        );
+
+        let conn = &mut PgConnection::establish(&multi_host_url).unwrap();
+        let in_recovery = crate::select(sql::<Bool>("pg_is_in_recovery()"))
+            .get_result::<bool>(conn)
+            .unwrap();
+        assert!(!in_recovery);
+    }
+
+    #[diesel_test_helper::test]
+    fn prepared_statements_option_is_removed_from_connection_string() {
+        let (url, prepared_statements) = extract_prepared_statements_option(
+            "postgres://localhost/app?sslmode=disable&prepared_statements=false",
+        )
+        .unwrap();
+        assert_eq!("postgres://localhost/app?sslmode=disable", url);
+        assert!(!prepared_statements);
+
+        let (url, prepared_statements) = extract_prepared_statements_option(
+            "postgresql://localhost/app?prepared_statements=true",
+        )
+        .unwrap();
+        assert_eq!("postgresql://localhost/app", url);
+        assert!(prepared_statements);
+
+        let (url, prepared_statements) = extract_prepared_statements_option(
+            "host=localhost prepared_statements = false dbname=app",
+        )
+        .unwrap();
+        assert_eq!("host=localhost  dbname=app", url);
+        assert!(!prepared_statements);
+
+        let (url, prepared_statements) =
+            extract_prepared_statements_option("host=localhost dbname=app").unwrap();
+        assert_eq!("host=localhost dbname=app", url);
+        assert!(prepared_statements);
+
+        assert!(matches!(
+            extract_prepared_statements_option("postgres://localhost/app?prepared_statements=no"),
+            Err(ConnectionError::InvalidConnectionUrl(_))
+        ));
+    }
+
+    #[diesel_test_helper::test]
+    fn queries_are_not_prepared_with_prepared_statements_disabled() {
+        use crate::dsl::sql;
+        use crate::query_dsl::StatementCacheDsl;
+        use crate::sql_types::{BigInt, Integer};
+
+        let database_url = crate::test_helpers::pg_database_url();
+        let separator = if database_url.contains('?') { '&' } else { '?' };
+        let conn = &mut PgConnection::establish(&format!(
+            "{database_url}{separator}prepared_statements=false"
+        ))
+        .unwrap();
+
+        let query = crate::select(1.into_sql::<Integer>());
+        assert_eq!(Ok(1), query.get_result(conn));
+        assert_eq!(Ok(1), query.always_prepare().get_result(conn));
+
+        let prepared_statements = crate::select(sql::<BigInt>(
+            "(SELECT COUNT(*) FROM pg_prepared_statements)",
+        ))
+        .get_result::<i64>(conn)
+        .unwrap();
+        assert_eq!(0, prepared_statements);
+    }
+
     #[diesel_test_helper::test]
     fn notifications_arrive() {
         use crate::sql_query;
@@ -877,6 +1378,234 @@ mod tests {
         );
     }
 
+    #[diesel_test_helper::test]
+    fn notifications_wait_for_the_next_notification() {
+        use crate::dsl::notify;
+        use core::time::Duration;
+        use std::time::Instant;
+
+        let conn = &mut connection();
+        conn.listen("Mixed Case \"channel\"").unwrap();
+
+        let sender = std::thread::spawn(|| {
+            std::thread::sleep(Duration::from_millis(100));
+            let other_conn = &mut connection();
+            notify("Mixed Case \"channel\"", "first")
+                .execute(other_conn)
+                .unwrap();
+            // notifications are only delivered on commit
+            other_conn
+                .transaction(|conn| {
+                    notify("Mixed Case \"channel\"", "rolled back").execute(conn)?;
+                    Err::<(), _>(Error::RollbackTransaction)
+                })
+                .unwrap_err();
+            notify("other_channel", "ignored")
+                .execute(other_conn)
+                .unwrap();
+        });
+
+        let notification = conn
+            .notifications(Duration::from_secs(10))
+            .next()
+            .unwrap()
+            .unwrap();
+        assert_eq!("Mixed Case \"channel\"", notification.channel);
+        assert_eq!("first", notification.payload);
+        sender.join().unwrap();
+
+        let start = Instant::now();
+        let next_notification = conn.notifications(Duration::from_millis(200)).next();
+        assert!(
+            next_notification.is_none(),
+            "Got a next notification, while not expecting one: {next_notification:?}"
+        );
+        assert!(start.elapsed() >= Duration::from_millis(200));
+    }
+
+    #[cfg(feature = "pipeline_mode_for_libpq_14")]
+    table! {
+        pipeline_items {
+            id -> Integer,
+            name -> Text,
+        }
+    }
+
+    #[cfg(feature = "pipeline_mode_for_libpq_14")]
+    #[diesel_test_helper::test]
+    fn pipeline_returns_the_result_of_each_query() {
+        use self::pipeline_items::dsl::*;
+        use crate::pg::pipeline;
+
+        let conn = &mut connection();
+        crate::sql_query(
+            "CREATE TEMPORARY TABLE pipeline_items(id INTEGER PRIMARY KEY, name TEXT)",
+        )
+        .execute(conn)
+        .unwrap();
+
+        let (inserted, names, count, updated) = conn
+            .pipeline((
+                pipeline::execute(
+                    crate::insert_into(pipeline_items)
+                        .values(&vec![(id.eq(1), name.eq("a")), (id.eq(2), name.eq("b"))]),
+                ),
+                pipeline::load::<String, _>(pipeline_items.select(name).order(id)),
+                pipeline::get_result::<i64, _>(pipeline_items.count()),
+                pipeline::execute(crate::update(pipeline_items).set(name.eq("c"))),
+            ))
+            .unwrap();
+        assert_eq!(2, inserted);
+        assert_eq!(vec!["a", "b"], names);
+        assert_eq!(2, count);
+        assert_eq!(2, updated);
+
+        // the connection leaves pipeline mode and can be used as usual
+        let names = pipeline_items.select(name).load::<String>(conn).unwrap();
+        assert_eq!(vec!["c", "c"], names);
+
+        let result = conn.pipeline((pipeline::get_result::<String, _>(
+            pipeline_items.select(name).filter(id.eq(42)),
+        ),));
+        assert_eq!(Err(Error::NotFound), result);
+
+        conn.transaction(|conn| {
+            let (deleted,) = conn.pipeline((pipeline::execute(crate::delete(pipeline_items)),))?;
+            assert_eq!(2, deleted);
+            Err::<(), _>(Error::RollbackTransaction)
+        })
+        .unwrap_err();
+        assert_eq!(Ok(2), pipeline_items.count().get_result::<i64>(conn));
+    }
+
+    #[cfg(feature = "pipeline_mode_for_libpq_14")]
+    #[diesel_test_helper::test]
+    fn pipeline_stops_at_the_first_failing_query() {
+        use self::pipeline_items::dsl::*;
+        use crate::pg::pipeline;
+
+        let conn = &mut connection();
+        crate::sql_query(
+            "CREATE TEMPORARY TABLE pipeline_items(id INTEGER PRIMARY KEY, name TEXT)",
+        )
+        .execute(conn)
+        .unwrap();
+
+        let result = conn.pipeline((
+            pipeline::execute(crate::insert_into(pipeline_items).values((id.eq(1), name.eq("a")))),
+            pipeline::execute(crate::insert_into(pipeline_items).values((id.eq(1), name.eq("b")))),
+            pipeline::execute(crate::insert_into(pipeline_items).values((id.eq(2), name.eq("c")))),
+        ));
+        assert!(
+            matches!(
+                result,
+                Err(DatabaseError(DatabaseErrorKind::UniqueViolation, _))
+            ),
+            "Expected a unique violation, got {result:?}"
+        );
+
+        // queries of the pipeline run in an implicit transaction
+        // outside of a transaction block
+        assert_eq!(Ok(0), pipeline_items.count().get_result::<i64>(conn));
+        assert_eq!(Ok(None), conn.transaction_depth());
+    }
+
+    #[diesel_test_helper::test]
+    fn large_object_roundtrip() {
+        use crate::pg::PgLargeObjectMode;
+        use std::io::{Read, Seek, SeekFrom, Write};
+
+        let connection = &mut connection();
+        connection
+            .transaction(|connection| {
+                let oid = connection.create_large_object()?;
+                {
+                    let mut object =
+                        connection.open_large_object(oid, PgLargeObjectMode::ReadWrite)?;
+                    let data = vec![42_u8; 100_000];
+                    object.write_all(&data).unwrap();
+                    assert_eq!(object.stream_position().unwrap(), 100_000);
+                    object.truncate(10)?;
+                    assert_eq!(object.seek(SeekFrom::End(0)).unwrap(), 10);
+                }
+
+                let mut object = connection.open_large_object(oid, PgLargeObjectMode::Read)?;
+                let mut buf = Vec::new();
+                object.read_to_end(&mut buf).unwrap();
+                assert_eq!(buf, vec![42_u8; 10]);
+                object.close()?;
+
+                connection.unlink_large_object(oid)
+            })
+            .unwrap();
+    }
+
+    #[diesel_test_helper::test]
+    fn large_objects_require_a_transaction() {
+        let connection = &mut connection();
+        assert!(matches!(
+            connection.create_large_object(),
+            Err(Error::NotInTransaction)
+        ));
+    }
+
+    #[diesel_test_helper::test]
+    fn large_object_referenced_from_oid_column() {
+        use crate::pg::PgLargeObjectMode;
+        use crate::pg::expression::dsl::{lo_from_bytea, lo_get, lo_unlink};
+        use std::io::Read;
+
+        table! {
+            documents {
+                id -> Integer,
+                content -> Oid,
+            }
+        }
+
+        let connection = &mut connection();
+        crate::sql_query("CREATE TEMPORARY TABLE documents(id INTEGER PRIMARY KEY, content OID)")
+            .execute(connection)
+            .unwrap();
+        crate::insert_into(documents::table)
+            .values((
+                documents::id.eq(1),
+                documents::content.eq(lo_from_bytea(0, b"Hello, World".to_vec())),
+            ))
+            .execute(connection)
+            .unwrap();
+
+        let oid = documents::table
+            .select(documents::content)
+            .get_result::<crate::pg::data_types::PgLargeObjectId>(connection)
+            .unwrap();
+        let count = documents::table
+            .filter(documents::content.eq(oid))
+            .count()
+            .get_result::<i64>(connection);
+        assert_eq!(Ok(1), count);
+        connection
+            .transaction(|connection| {
+                let mut object = connection.open_large_object(oid, PgLargeObjectMode::Read)?;
+                let mut content = String::new();
+                object.read_to_string(&mut content).unwrap();
+                assert_eq!("Hello, World", content);
+                QueryResult::Ok(())
+            })
+            .unwrap();
+
+        let content = documents::table
+            .select(lo_get(documents::content))
+            .get_result::<Vec<u8>>(connection)
+            .unwrap();
+        assert_eq!(b"Hello, World".to_vec(), content);
+
+        let removed = documents::table
+            .select(lo_unlink(documents::content))
+            .get_result::<i32>(connection)
+            .unwrap();
+        assert_eq!(1, removed);
+    }
+
     #[diesel_test_helper::test]
     fn malformed_sql_query() {
         let connection = &mut connection();
@@ -1142,6 +1871,89 @@ mod tests {
         );
     }
 
+    #[diesel_test_helper::test]
+    // This function uses collect with an side effect (spawning threads)
+    // so this is a false positive from clippy
+    #[allow(clippy::needless_collect)]
+    fn deadlocked_transactions_are_retried() {
+        use crate::connection::RetryPolicy;
+        use crate::*;
+        use std::sync::{Arc, Barrier};
+        use std::thread;
+
+        table! {
+            #[sql_name = "pg_deadlocked_transactions_are_retried"]
+            deadlock_example {
+                id -> Integer,
+                value -> Integer,
+            }
+        }
+
+        let conn = &mut crate::test_helpers::pg_connection_no_transaction();
+
+        sql_query("DROP TABLE IF EXISTS pg_deadlocked_transactions_are_retried;")
+            .execute(conn)
+            .unwrap();
+        sql_query(
+            r#"
+            CREATE TABLE pg_deadlocked_transactions_are_retried (
+                id INTEGER PRIMARY KEY,
+                value INTEGER NOT NULL
+            )
+        "#,
+        )
+        .execute(conn)
+        .unwrap();
+
+        insert_into(deadlock_example::table)
+            .values(&vec![
+                (deadlock_example::id.eq(1), deadlock_example::value.eq(0)),
+                (deadlock_example::id.eq(2), deadlock_example::value.eq(0)),
+            ])
+            .execute(conn)
+            .unwrap();
+
+        let barrier = Arc::new(Barrier::new(2));
+        let threads = (1..3)
+            .map(|i| {
+                let barrier = barrier.clone();
+                thread::spawn(move || {
+                    let conn = &mut crate::test_helpers::pg_connection_no_transaction();
+                    let policy = RetryPolicy::default();
+                    let mut attempts = 0;
+                    // both transactions lock their own row first and then
+                    // the row of the other one, so that one of them deadlocks
+                    let result = conn.transaction_with_retries(&policy, |conn| {
+                        attempts += 1;
+                        let other_i = if i == 1 { 2 } else { 1 };
+                        for id in [i, other_i] {
+                            update(deadlock_example::table.find(id))
+                                .set(deadlock_example::value.eq(deadlock_example::value + 1))
+                                .execute(conn)?;
+                            if attempts == 1 && id == i {
+                                barrier.wait();
+                            }
+                        }
+                        Ok(())
+                    });
+                    (result, attempts)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let results = threads
+            .into_iter()
+            .map(|t| t.join().unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(results.iter().all(|(r, _)| r.is_ok()), "Got {results:?}");
+        assert_eq!(3, results.iter().map(|(_, a)| a).sum::<i32>());
+        let values = deadlock_example::table
+            .select(deadlock_example::value)
+            .load::<i32>(conn);
+        assert_eq!(Ok(vec![2, 2]), values);
+    }
+
     #[diesel_test_helper::test]
     // This function uses collect with an side effect (spawning threads)
     // so this is a false positive from clippy
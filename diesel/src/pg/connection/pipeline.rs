@@ -0,0 +1,353 @@
+//! Send several queries to the database server in a single round trip
+//!
+//! See [`PgConnection::pipeline`] for details.
+
+use super::PgConnection;
+use super::private::{ConnectionAndTransactionManager, QueryFragmentHelper};
+use super::result::PgResult;
+use super::stmt::Statement;
+use super::update_transaction_manager_status;
+use crate::connection::InstrumentationEvent;
+use crate::connection::statement_cache::PrepareForCache;
+use crate::deserialize::FromSqlRow;
+use crate::pg::Pg;
+use crate::query_builder::{AsQuery, QueryFragment, QueryId};
+use crate::query_dsl::load_dsl::CompatibleType;
+use crate::result::{DatabaseErrorKind, Error, QueryResult};
+use alloc::rc::Rc;
+use core::marker::PhantomData;
+
+/// A query that loads all rows returned by the wrapped query
+///
+/// Constructed by [`load`]
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when passed to `PgConnection::pipeline`"]
+pub struct Load<Q, U> {
+    query: Q,
+    _marker: PhantomData<U>,
+}
+
+/// A query that loads the first row returned by the wrapped query
+///
+/// Constructed by [`get_result`]
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when passed to `PgConnection::pipeline`"]
+pub struct GetResult<Q, U> {
+    query: Q,
+    _marker: PhantomData<U>,
+}
+
+/// A query that returns the number of affected rows
+///
+/// Constructed by [`execute`]
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when passed to `PgConnection::pipeline`"]
+pub struct Execute<Q> {
+    query: Q,
+}
+
+/// Loads all rows returned by `query` as part of a pipeline
+///
+/// This is the pipeline equivalent of [`RunQueryDsl::load`](crate::RunQueryDsl::load).
+/// The pipeline returns a `Vec<U>` for this query.
+pub fn load<U, Q>(query: Q) -> Load<Q::Query, U>
+where
+    Q: AsQuery,
+{
+    Load {
+        query: query.as_query(),
+        _marker: PhantomData,
+    }
+}
+
+/// Loads the first row returned by `query` as part of a pipeline
+///
+/// This is the pipeline equivalent of
+/// [`RunQueryDsl::get_result`](crate::RunQueryDsl::get_result).
+/// The pipeline returns an `U` for this query, or fails with
+/// [`Error::NotFound`] if the query returned no rows.
+pub fn get_result<U, Q>(query: Q) -> GetResult<Q::Query, U>
+where
+    Q: AsQuery,
+{
+    GetResult {
+        query: query.as_query(),
+        _marker: PhantomData,
+    }
+}
+
+/// Executes `query` as part of a pipeline
+///
+/// This is the pipeline equivalent of
+/// [`RunQueryDsl::execute`](crate::RunQueryDsl::execute).
+/// The pipeline returns the number of affected rows for this query.
+pub fn execute<Q>(query: Q) -> Execute<Q> {
+    Execute { query }
+}
+
+/// A set of queries that can be sent to the database server
+/// with [`PgConnection::pipeline`]
+///
+/// This trait is implemented for the query types of this module,
+/// and for tuples of them.
+pub trait PipelineQueries {
+    /// The type returned by executing these queries
+    type Output;
+
+    #[doc(hidden)]
+    fn collect_queries<'a>(&'a self, queries: &mut Vec<&'a dyn QueryFragmentHelper<Error>>);
+
+    #[doc(hidden)]
+    fn build_output(results: &mut PipelineResults) -> QueryResult<Self::Output>;
+}
+
+/// The results of the queries of a pipeline, in the order of the queries
+#[allow(missing_debug_implementations)]
+#[doc(hidden)]
+pub struct PipelineResults(alloc::vec::IntoIter<PgResult>);
+
+impl PipelineResults {
+    fn next_result(&mut self) -> PgResult {
+        self.0
+            .next()
+            .expect("There is one result for each query of the pipeline")
+    }
+}
+
+impl<Q, U> PipelineQueries for Load<Q, U>
+where
+    Q: crate::query_builder::Query + QueryFragment<Pg> + QueryId,
+    Q::SqlType: CompatibleType<U, Pg>,
+    U: FromSqlRow<<Q::SqlType as CompatibleType<U, Pg>>::SqlType, Pg>,
+{
+    type Output = Vec<U>;
+
+    fn collect_queries<'a>(&'a self, queries: &mut Vec<&'a dyn QueryFragmentHelper<Error>>) {
+        queries.push(&self.query);
+    }
+
+    fn build_output(results: &mut PipelineResults) -> QueryResult<Self::Output> {
+        let result = Rc::new(results.next_result());
+        (0..result.num_rows())
+            .map(|idx| {
+                let row = result.clone().get_row(idx);
+                U::build_from_row(&row).map_err(Error::DeserializationError)
+            })
+            .collect()
+    }
+}
+
+impl<Q, U> PipelineQueries for GetResult<Q, U>
+where
+    Q: crate::query_builder::Query + QueryFragment<Pg> + QueryId,
+    Q::SqlType: CompatibleType<U, Pg>,
+    U: FromSqlRow<<Q::SqlType as CompatibleType<U, Pg>>::SqlType, Pg>,
+{
+    type Output = U;
+
+    fn collect_queries<'a>(&'a self, queries: &mut Vec<&'a dyn QueryFragmentHelper<Error>>) {
+        queries.push(&self.query);
+    }
+
+    fn build_output(results: &mut PipelineResults) -> QueryResult<Self::Output> {
+        let result = Rc::new(results.next_result());
+        if result.num_rows() == 0 {
+            return Err(Error::NotFound);
+        }
+        let row = result.get_row(0);
+        U::build_from_row(&row).map_err(Error::DeserializationError)
+    }
+}
+
+impl<Q> PipelineQueries for Execute<Q>
+where
+    Q: QueryFragment<Pg> + QueryId,
+{
+    type Output = usize;
+
+    fn collect_queries<'a>(&'a self, queries: &mut Vec<&'a dyn QueryFragmentHelper<Error>>) {
+        queries.push(&self.query);
+    }
+
+    fn build_output(results: &mut PipelineResults) -> QueryResult<Self::Output> {
+        results.next_result().rows_affected()
+    }
+}
+
+macro_rules! pipeline_queries_for_tuples {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST:ident, $TT:ident,)+
+        }
+    )+) => {
+        $(
+            impl<$($T,)+> PipelineQueries for ($($T,)+)
+            where
+                $($T: PipelineQueries,)+
+            {
+                type Output = ($($T::Output,)+);
+
+                fn collect_queries<'a>(
+                    &'a self,
+                    queries: &mut Vec<&'a dyn QueryFragmentHelper<Error>>,
+                ) {
+                    $(self.$idx.collect_queries(queries);)+
+                }
+
+                fn build_output(results: &mut PipelineResults) -> QueryResult<Self::Output> {
+                    Ok(($($T::build_output(results)?,)+))
+                }
+            }
+        )+
+    }
+}
+
+crate::for_each_tuple!(pipeline_queries_for_tuples, 16);
+
+impl PgConnection {
+    /// Sends several independent queries to the database server in a
+    /// single round trip, and returns the typed result of each query
+    ///
+    /// Queries are constructed with [`pipeline::load`](load),
+    /// [`pipeline::get_result`](get_result) and [`pipeline::execute`](execute),
+    /// and passed as tuple. This uses the
+    /// [pipeline mode](https://www.postgresql.org/docs/current/libpq-pipeline-mode.html)
+    /// of libpq, which requires libpq 14 or newer.
+    ///
+    /// All queries are sent before the first result is awaited, which
+    /// removes one network round trip per query compared to executing them one
+    /// after another. Outside of a transaction the queries are executed in a
+    /// single implicit transaction, so either all of them succeed or none of
+    /// their changes are persisted. If one query fails, the server skips the
+    /// remaining queries and the error of the failing query is returned.
+    ///
+    /// Pipelines are meant for a small number of queries. The connection
+    /// stays in blocking mode, so a large number of queries with big result
+    /// sets might exhaust the network buffers of both sides.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// use diesel::pg::pipeline;
+    ///
+    /// let (inserted, names, count): (usize, Vec<String>, i64) = connection.pipeline((
+    ///     pipeline::execute(diesel::insert_into(users).values(name.eq("Jim"))),
+    ///     pipeline::load(users.select(name).order(id)),
+    ///     pipeline::get_result(users.count()),
+    /// ))?;
+    ///
+    /// assert_eq!(1, inserted);
+    /// assert_eq!(vec!["Sean", "Tess", "Jim"], names);
+    /// assert_eq!(3, count);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg_attr(diesel_docsrs, doc(cfg(feature = "pipeline_mode_for_libpq_14")))]
+    pub fn pipeline<Q>(&mut self, queries: Q) -> QueryResult<Q::Output>
+    where
+        Q: PipelineQueries,
+    {
+        let mut sources = Vec::new();
+        queries.collect_queries(&mut sources);
+        let results = self.run_pipeline(&sources)?;
+        Q::build_output(&mut PipelineResults(results.into_iter()))
+    }
+
+    fn run_pipeline(
+        &mut self,
+        sources: &[&dyn QueryFragmentHelper<Error>],
+    ) -> QueryResult<Vec<PgResult>> {
+        // Looking up the oids of custom types might require to execute
+        // queries, so all statements are prepared before entering pipeline mode
+        let mut statements = Vec::with_capacity(sources.len());
+        for source in sources {
+            let bind_collector = self.collect_binds(*source)?;
+            let conn = &mut self.connection_and_transaction_manager;
+            let statement = source.construct_sql(&Pg).and_then(|sql| {
+                Statement::prepare(
+                    &mut conn.raw_connection,
+                    &sql,
+                    PrepareForCache::No,
+                    &bind_collector.metadata,
+                )
+            });
+            match statement {
+                Ok(statement) => statements.push((statement, bind_collector.binds)),
+                Err(e) => {
+                    finish_query(conn, *source, Some(&e));
+                    return Err(e);
+                }
+            }
+        }
+
+        let conn = &mut self.connection_and_transaction_manager;
+        conn.raw_connection.enter_pipeline_mode()?;
+        let mut send_result = Ok(());
+        let mut sent = 0;
+        for (statement, binds) in &statements {
+            send_result = statement.send(&mut conn.raw_connection, binds);
+            if send_result.is_err() {
+                break;
+            }
+            sent += 1;
+        }
+        // the results of all sent queries need to be consumed
+        // before leaving pipeline mode, even if sending failed
+        let sync_result = conn.raw_connection.pipeline_sync();
+        let results = (0..sent)
+            .map(|_| {
+                conn.raw_connection.next_pipeline_result()?.ok_or_else(|| {
+                    Error::DatabaseError(
+                        DatabaseErrorKind::Unknown,
+                        Box::new(
+                            "The query was skipped as an earlier query of the pipeline failed"
+                                .to_owned(),
+                        ),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        let exit_result = conn.raw_connection.exit_pipeline_mode();
+
+        let results = sources
+            .iter()
+            .zip(results)
+            .map(|(source, result)| {
+                update_transaction_manager_status(
+                    result,
+                    conn,
+                    &|callback| source.instrumentation(callback),
+                    true,
+                )
+            })
+            .collect::<Vec<_>>();
+        if let Err(e) = &send_result {
+            finish_query(conn, sources[sent], Some(e));
+        }
+        let results = results.into_iter().collect::<QueryResult<Vec<_>>>()?;
+        send_result?;
+        sync_result?;
+        exit_result?;
+        Ok(results)
+    }
+}
+
+fn finish_query(
+    conn: &mut ConnectionAndTransactionManager,
+    source: &dyn QueryFragmentHelper<Error>,
+    error: Option<&Error>,
+) {
+    source.instrumentation(&mut |query| {
+        conn.instrumentation
+            .on_connection_event(InstrumentationEvent::finish_query(query, error));
+    });
+}
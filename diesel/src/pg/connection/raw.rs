@@ -5,11 +5,17 @@ extern crate pq_sys;
 
 use self::pq_sys::*;
 use alloc::ffi::CString;
+use alloc::sync::Arc;
+use core::cell::Cell;
 use core::ffi as libc;
 use core::ffi::CStr;
 use core::ptr::NonNull;
-use core::{ptr, str};
+use core::str;
+use core::time::Duration;
+use std::sync::Mutex;
+use std::time::Instant;
 
+use crate::connection::DatabaseWarning;
 use crate::result::*;
 
 use super::result::PgResult;
@@ -18,6 +24,18 @@ use crate::pg::PgNotification;
 #[allow(missing_debug_implementations, missing_copy_implementations)]
 pub(super) struct RawConnection {
     pub(super) internal_connection: NonNull<PGconn>,
+    // names of prepared statements removed from the statement
+    // cache, which are not deallocated on the server yet
+    dropped_statements: Arc<Mutex<Vec<CString>>>,
+    // boxed, as libpq keeps a pointer to it for the notice receiver
+    notices: Box<NoticeCollector>,
+}
+
+/// Collects the notices sent by the server, but only
+/// while `warnings` is `Some`
+#[derive(Default)]
+struct NoticeCollector {
+    warnings: Cell<Option<Vec<DatabaseWarning>>>,
 }
 
 impl RawConnection {
@@ -29,8 +47,20 @@ impl RawConnection {
         match connection_status {
             ConnStatusType::CONNECTION_OK => {
                 let connection_ptr = unsafe { NonNull::new_unchecked(connection_ptr) };
+                let notices = Box::<NoticeCollector>::default();
+                // replaces the default notice processor, which
+                // prints all notices to stderr
+                unsafe {
+                    PQsetNoticeReceiver(
+                        connection_ptr.as_ptr(),
+                        Some(notice_receiver),
+                        &*notices as *const NoticeCollector as *mut libc::c_void,
+                    );
+                }
                 Ok(RawConnection {
                     internal_connection: connection_ptr,
+                    dropped_statements: Arc::default(),
+                    notices,
                 })
             }
             _ => {
@@ -48,18 +78,55 @@ impl RawConnection {
         }
     }
 
+    /// A list that collects the names of dropped prepared statements
+    pub(super) fn dropped_statements(&self) -> Arc<Mutex<Vec<CString>>> {
+        Arc::clone(&self.dropped_statements)
+    }
+
+    /// Deallocate all prepared statements that were removed
+    /// from the statement cache
+    ///
+    /// This only happens outside of transactions, as a failing
+    /// `DEALLOCATE` would abort the current transaction. Statements
+    /// that cannot be deallocated yet are kept for the next call.
+    pub(super) fn deallocate_dropped_statements(&self) {
+        if !matches!(self.transaction_status(), PgTransactionStatus::Idle) {
+            return;
+        }
+        let mut names = match self.dropped_statements.lock() {
+            Ok(names) => names,
+            Err(poison) => poison.into_inner(),
+        };
+        while let Some(name) = names.pop() {
+            let Ok(query) = CString::new(format!("DEALLOCATE \"{}\"", name.to_string_lossy()))
+            else {
+                continue;
+            };
+            let result =
+                unsafe { self.exec(query.as_ptr()) }.and_then(|result| PgResult::new(result, self));
+            if result.is_err() {
+                // the connection is likely broken, so there
+                // is no need to keep the name around
+                break;
+            }
+        }
+    }
+
     pub(super) fn last_error_message(&self) -> String {
         last_error_message(self.internal_connection.as_ptr())
     }
 
-    pub(super) fn set_notice_processor(&self, notice_processor: NoticeProcessor) {
-        unsafe {
-            PQsetNoticeProcessor(
-                self.internal_connection.as_ptr(),
-                Some(notice_processor),
-                ptr::null_mut(),
-            );
-        }
+    /// Start collecting the notices sent by the server
+    ///
+    /// Notices are discarded if they are not collected
+    pub(super) fn start_collecting_warnings(&self) {
+        self.notices.warnings.set(Some(Vec::new()));
+    }
+
+    /// Stop collecting notices and return all notices
+    /// received since [`RawConnection::start_collecting_warnings`]
+    pub(super) fn finish_collecting_warnings(&self) -> Vec<DatabaseWarning> {
+        self.notices.warnings.take().unwrap_or_default()
     }
 
     pub(super) unsafe fn exec(&self, query: *const libc::c_char) -> QueryResult<RawResult> {
@@ -184,6 +251,72 @@ impl RawConnection {
         }
     }
 
+    #[cfg(feature = "pipeline_mode_for_libpq_14")]
+    pub(super) fn enter_pipeline_mode(&self) -> QueryResult<()> {
+        let res = unsafe { PQenterPipelineMode(self.internal_connection.as_ptr()) };
+        self.pipeline_call_result(res)
+    }
+
+    /// Marks the end of the queries sent in pipeline mode and flushes them to the server
+    #[cfg(feature = "pipeline_mode_for_libpq_14")]
+    pub(super) fn pipeline_sync(&self) -> QueryResult<()> {
+        let res = unsafe { PQpipelineSync(self.internal_connection.as_ptr()) };
+        self.pipeline_call_result(res)
+    }
+
+    /// Returns the result of the next query sent in pipeline mode
+    ///
+    /// Returns `Ok(None)` if the query was skipped by the server
+    /// as an earlier query of the pipeline failed
+    #[cfg(feature = "pipeline_mode_for_libpq_14")]
+    pub(super) fn next_pipeline_result(&self) -> QueryResult<Option<PgResult>> {
+        let res = unsafe { PQgetResult(self.internal_connection.as_ptr()) };
+        let raw = RawResult::new(res, self)?;
+        if matches!(raw.result_status(), ExecStatusType::PGRES_PIPELINE_ABORTED) {
+            // each result is followed by a null pointer
+            self.get_next_result()?;
+            return Ok(None);
+        }
+        // for errors the trailing null pointer is already consumed
+        // by `PgResult::new`
+        let is_error = !matches!(
+            raw.result_status(),
+            ExecStatusType::PGRES_COMMAND_OK | ExecStatusType::PGRES_TUPLES_OK
+        );
+        let result = PgResult::new(raw, self)?;
+        if !is_error {
+            self.get_next_result()?;
+        }
+        Ok(Some(result))
+    }
+
+    /// Consumes the result of the pipeline sync and leaves pipeline mode
+    #[cfg(feature = "pipeline_mode_for_libpq_14")]
+    pub(super) fn exit_pipeline_mode(&self) -> QueryResult<()> {
+        let res = unsafe { PQgetResult(self.internal_connection.as_ptr()) };
+        let raw = RawResult::new(res, self)?;
+        if !matches!(raw.result_status(), ExecStatusType::PGRES_PIPELINE_SYNC) {
+            return Err(Error::DatabaseError(
+                DatabaseErrorKind::Unknown,
+                Box::new("Received an unexpected result while leaving pipeline mode".to_owned()),
+            ));
+        }
+        let res = unsafe { PQexitPipelineMode(self.internal_connection.as_ptr()) };
+        self.pipeline_call_result(res)
+    }
+
+    #[cfg(feature = "pipeline_mode_for_libpq_14")]
+    fn pipeline_call_result(&self, res: libc::c_int) -> QueryResult<()> {
+        if res == 1 {
+            Ok(())
+        } else {
+            Err(Error::DatabaseError(
+                DatabaseErrorKind::Unknown,
+                Box::new(self.last_error_message()),
+            ))
+        }
+    }
+
     pub(super) fn put_copy_data(&mut self, buf: &[u8]) -> QueryResult<()> {
         for c in buf.chunks(i32::MAX as usize) {
             let res = unsafe {
@@ -297,6 +430,48 @@ impl RawConnection {
             Ok(Some(ret))
         }
     }
+
+    /// Waits until a notification arrives or `timeout` has elapsed
+    pub(super) fn wait_for_notification(
+        &self,
+        timeout: Duration,
+    ) -> Result<Option<PgNotification>, Error> {
+        let deadline = Instant::now() + timeout;
+        loop {
+            if let Some(notification) = self.pq_notifies()? {
+                return Ok(Some(notification));
+            }
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+            self.wait_until_readable(remaining);
+        }
+    }
+
+    #[cfg(unix)]
+    fn wait_until_readable(&self, timeout: Duration) {
+        let mut fd = ::libc::pollfd {
+            fd: unsafe { PQsocket(self.internal_connection.as_ptr()) },
+            events: ::libc::POLLIN,
+            revents: 0,
+        };
+        let timeout = timeout
+            .as_micros()
+            .div_ceil(1000)
+            .try_into()
+            .unwrap_or(libc::c_int::MAX);
+        // errors (including interrupts) are ignored here, as the
+        // caller checks for notifications again in any case
+        unsafe { ::libc::poll(&mut fd, 1, timeout) };
+    }
+
+    // there is no portable way to wait on the socket,
+    // so we poll for notifications in short intervals instead
+    #[cfg(not(unix))]
+    fn wait_until_readable(&self, timeout: Duration) {
+        std::thread::sleep(timeout.min(Duration::from_millis(10)));
+    }
 }
 
 /// Represents the current in-transaction status of the connection
@@ -326,8 +501,41 @@ impl From<PGTransactionStatusType> for PgTransactionStatus {
     }
 }
 
-pub(super) type NoticeProcessor =
-    extern "C" fn(arg: *mut libc::c_void, message: *const libc::c_char);
+unsafe extern "C" fn notice_receiver(arg: *mut libc::c_void, result: *const PGresult) {
+    if arg.is_null() || result.is_null() {
+        return;
+    }
+    // SAFETY:
+    // `arg` points to the boxed `NoticeCollector` of the connection,
+    // which outlives the connection as it is dropped after `PQfinish`
+    let collector = unsafe { &*(arg as *const NoticeCollector) };
+    let Some(mut warnings) = collector.warnings.take() else {
+        return;
+    };
+    let field = |field: ResultField| {
+        // SAFETY:
+        // libpq passes a valid result, that lives till
+        // the end of this function
+        let ptr = unsafe { PQresultErrorField(result, field as libc::c_int) };
+        if ptr.is_null() {
+            None
+        } else {
+            Some(
+                unsafe { CStr::from_ptr(ptr) }
+                    .to_string_lossy()
+                    .into_owned(),
+            )
+        }
+    };
+    warnings.push(DatabaseWarning::new(
+        field(ResultField::SeverityNonlocalized)
+            .or_else(|| field(ResultField::Severity))
+            .unwrap_or_else(|| String::from("NOTICE")),
+        field(ResultField::SqlState),
+        field(ResultField::MessagePrimary).unwrap_or_default(),
+    ));
+    collector.warnings.set(Some(warnings));
+}
 
 impl Drop for RawConnection {
     fn drop(&mut self) {
@@ -489,6 +697,8 @@ impl Drop for RawResult {
 /// Their values can be found in `postgres_ext.h`
 #[repr(i32)]
 pub(super) enum ResultField {
+    Severity = 'S' as i32,
+    SeverityNonlocalized = 'V' as i32,
     SqlState = 'C' as i32,
     MessagePrimary = 'M' as i32,
     MessageDetail = 'D' as i32,
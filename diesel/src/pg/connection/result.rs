@@ -60,7 +60,8 @@ impl PgResult {
                     Some(error_codes::FOREIGN_KEY_VIOLATION) => {
                         DatabaseErrorKind::ForeignKeyViolation
                     }
-                    Some(error_codes::SERIALIZATION_FAILURE) => {
+                    Some(error_codes::SERIALIZATION_FAILURE)
+                    | Some(error_codes::DEADLOCK_DETECTED) => {
                         DatabaseErrorKind::SerializationFailure
                     }
                     Some(error_codes::READ_ONLY_TRANSACTION) => {
@@ -238,4 +239,5 @@ mod error_codes {
     pub(in crate::pg::connection) const EXCLUSION_VIOLATION: &str = "23P01";
     pub(in crate::pg::connection) const READ_ONLY_TRANSACTION: &str = "25006";
     pub(in crate::pg::connection) const SERIALIZATION_FAILURE: &str = "40001";
+    pub(in crate::pg::connection) const DEADLOCK_DETECTED: &str = "40P01";
 }
@@ -2,8 +2,10 @@
 extern crate pq_sys;
 
 use alloc::ffi::CString;
+use alloc::sync::Arc;
 use core::ffi as libc;
 use core::ptr;
+use std::sync::Mutex;
 
 use super::result::PgResult;
 use super::statement_cache::PrepareForCache;
@@ -13,8 +15,14 @@ use crate::result::QueryResult;
 use super::raw::RawConnection;
 
 enum StatementKind {
-    Unnamed { sql: CString, param_types: Vec<u32> },
-    Named { name: CString },
+    Unnamed {
+        sql: CString,
+        param_types: Vec<u32>,
+    },
+    Named {
+        name: CString,
+        dropped_statements: Arc<Mutex<Vec<CString>>>,
+    },
 }
 
 pub(crate) struct Statement {
@@ -29,6 +37,19 @@ impl Statement {
         param_data: &[Option<Vec<u8>>],
         row_by_row: bool,
     ) -> QueryResult<PgResult> {
+        self.send(raw_connection, param_data)?;
+        if row_by_row {
+            raw_connection.enable_row_by_row_mode()?;
+        }
+        Ok(raw_connection.get_next_result()?.expect("Is never none"))
+    }
+
+    /// Sends the statement to the server without waiting for its result
+    pub(super) fn send(
+        &self,
+        raw_connection: &mut RawConnection,
+        param_data: &[Option<Vec<u8>>],
+    ) -> QueryResult<()> {
         let params_pointer = param_data
             .iter()
             .map(|data| {
@@ -57,7 +78,7 @@ impl Statement {
                 })?;
 
         match &self.kind {
-            StatementKind::Named { name } => {
+            StatementKind::Named { name, .. } => {
                 unsafe {
                     // execute the previously prepared statement
                     // in autocommit mode, this will be a new transaction
@@ -88,11 +109,7 @@ impl Statement {
                 )
             }?,
         };
-
-        if row_by_row {
-            raw_connection.enable_row_by_row_mode()?;
-        }
-        Ok(raw_connection.get_next_result()?.expect("Is never none"))
+        Ok(())
     }
 
     pub(super) fn prepare(
@@ -112,6 +129,7 @@ impl Statement {
             PrepareForCache::Yes { counter } => {
                 // For named/cached statements, prepare as usual using a prepare phase and then
                 // an execute phase
+                raw_connection.deallocate_dropped_statements();
                 let name_cstr = CString::new(format!("__diesel_stmt_{counter}"))?;
                 let internal_result =
                     unsafe {
@@ -132,7 +150,10 @@ impl Statement {
                 PgResult::new(internal_result?, raw_connection)?;
 
                 Ok(Statement {
-                    kind: StatementKind::Named { name: name_cstr },
+                    kind: StatementKind::Named {
+                        name: name_cstr,
+                        dropped_statements: raw_connection.dropped_statements(),
+                    },
                     param_formats: vec![1; param_types.len()],
                 })
             }
@@ -154,3 +175,21 @@ impl Statement {
         }
     }
 }
+
+impl Drop for Statement {
+    fn drop(&mut self) {
+        // named statements are deallocated by the connection later on,
+        // as dropping a statement has no access to the connection
+        if let StatementKind::Named {
+            name,
+            dropped_statements,
+        } = &mut self.kind
+        {
+            let mut names = match dropped_statements.lock() {
+                Ok(names) => names,
+                Err(poison) => poison.into_inner(),
+            };
+            names.push(core::mem::take(name));
+        }
+    }
+}
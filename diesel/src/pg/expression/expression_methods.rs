@@ -1,10 +1,14 @@
 //! PostgreSQL specific expression methods
 
+#[cfg(feature = "pgvector")]
+use self::private::VectorOrNullableVector;
 pub(in crate::pg) use self::private::{
-    ArrayOrNullableArray, CombinedAllNullableValue, InetOrCidr, IntegerOrNullableInteger,
-    JsonOrNullableJson, JsonRemoveIndex, JsonbOrNullableJsonb, MaybeNullableValue,
-    MultirangeOrNullableMultirange, MultirangeOrRangeMaybeNullable, RangeOrMultirange,
-    RangeOrNullableRange, RecordOrNullableRecord, TextArrayOrNullableTextArray, TextOrNullableText,
+    ArrayOrNullableArray, CombinedAllNullableValue, GeometryOrGeography,
+    GeometryOrNullableGeometry, InetOrCidr, IntegerOrNullableInteger, JsonOrNullableJson,
+    JsonRemoveIndex, JsonbOrNullableJsonb, MaybeNullableValue, MultirangeOrNullableMultirange,
+    MultirangeOrRangeMaybeNullable, RangeOrMultirange, RangeOrNullableRange,
+    RecordOrNullableRecord, TextArrayOrNullableTextArray, TextOrNullableText,
+    TimestampOrTimestamptz, TimestampOrTimestamptzOrInterval,
 };
 use super::date_and_time::{AtTimeZone, DateTimeLike};
 use super::operators::*;
@@ -19,7 +23,9 @@ use crate::expression_methods::json_expression_methods::private::JsonOrNullableJ
 use crate::pg::expression::expression_methods::private::BinaryOrNullableBinary;
 use crate::pg::expression::operators::RetrieveAsObjectJson;
 use crate::sql_types::Nullable;
-use crate::sql_types::{Array, Inet, Integer, Range, SqlType, Text, VarChar};
+#[cfg(feature = "pgvector")]
+use crate::sql_types::Vector;
+use crate::sql_types::{Array, BigInt, Inet, Integer, Range, SqlType, Text, VarChar};
 
 /// PostgreSQL specific methods which are present on all expressions.
 #[cfg(feature = "postgres_backend")]
@@ -3060,6 +3066,110 @@ pub trait PgNetExpressionMethods: Expression + Sized {
     {
         Grouped(DifferenceNet::new(self, other.as_expression()))
     }
+
+    /// Creates a PostgreSQL `+` expression.
+    ///
+    /// This operator adds an integer offset to an address, which is useful to
+    /// iterate over the addresses of a network.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     hosts {
+    /// #         id -> Integer,
+    /// #         address -> Inet,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "ipnetwork")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::hosts::dsl::*;
+    /// #     use ipnetwork::IpNetwork;
+    /// #     use std::str::FromStr;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS hosts").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE hosts (id SERIAL PRIMARY KEY, address INET NOT NULL)").execute(conn).unwrap();
+    /// diesel::insert_into(hosts)
+    ///     .values(vec![address.eq(IpNetwork::from_str("10.0.2.3").unwrap())])
+    ///     .execute(conn)?;
+    ///
+    /// let addr = hosts
+    ///     .select(address.add_offset(7))
+    ///     .first::<IpNetwork>(conn)?;
+    /// assert_eq!(addr, IpNetwork::from_str("10.0.2.10").unwrap());
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "ipnetwork"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn add_offset<T>(self, offset: T) -> dsl::AddOffset<Self, T>
+    where
+        T: AsExpression<BigInt>,
+    {
+        Grouped(AddNet::new(self, offset.as_expression()))
+    }
+
+    /// Creates a PostgreSQL `-` expression with an integer offset.
+    ///
+    /// This operator subtracts an integer offset from an address. Use
+    /// [`diff`](Self::diff) to compute the distance between two addresses.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     hosts {
+    /// #         id -> Integer,
+    /// #         address -> Inet,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "ipnetwork")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use self::hosts::dsl::*;
+    /// #     use ipnetwork::IpNetwork;
+    /// #     use std::str::FromStr;
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("DROP TABLE IF EXISTS hosts").execute(conn).unwrap();
+    /// #     diesel::sql_query("CREATE TABLE hosts (id SERIAL PRIMARY KEY, address INET NOT NULL)").execute(conn).unwrap();
+    /// diesel::insert_into(hosts)
+    ///     .values(vec![address.eq(IpNetwork::from_str("10.0.2.2").unwrap())])
+    ///     .execute(conn)?;
+    ///
+    /// let addr = hosts
+    ///     .select(address.sub_offset(3))
+    ///     .first::<IpNetwork>(conn)?;
+    /// assert_eq!(addr, IpNetwork::from_str("10.0.1.255").unwrap());
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "ipnetwork"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn sub_offset<T>(self, offset: T) -> dsl::SubOffset<Self, T>
+    where
+        T: AsExpression<BigInt>,
+    {
+        Grouped(SubtractNet::new(self, offset.as_expression()))
+    }
 }
 
 impl<T> PgNetExpressionMethods for T
@@ -4127,10 +4237,167 @@ where
 {
 }
 
+/// PostgreSQL specific methods present on `vector` expressions of the
+/// pgvector extension
+///
+/// All distances are returned as `double precision` values. Pass them to
+/// `.order()` together with `.limit()` to query the nearest neighbors of a
+/// vector. Such a query can use an index created with the matching operator
+/// class, e.g. `vector_l2_ops` for [`l2_distance`](Self::l2_distance).
+#[cfg(all(feature = "postgres_backend", feature = "pgvector"))]
+pub trait PgVectorExpressionMethods: Expression + Sized {
+    /// Calculates the euclidean (L2) distance between two vectors using the
+    /// `<->` operator.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     items {
+    /// #         id -> Integer,
+    /// #         embedding -> diesel::sql_types::Vector,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> QueryResult<()> {
+    /// #     use self::items::dsl::*;
+    /// #     let connection = &mut connection_no_data();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS vector").execute(connection)?;
+    /// #     diesel::sql_query("CREATE TABLE items (id SERIAL PRIMARY KEY, embedding vector(3) NOT NULL)")
+    /// #         .execute(connection)?;
+    /// diesel::insert_into(items)
+    ///     .values(&vec![
+    ///         embedding.eq(vec![1.0, 1.0, 1.0]),
+    ///         embedding.eq(vec![2.0, 3.0, 0.0]),
+    ///         embedding.eq(vec![-1.0, -1.0, 0.0]),
+    ///     ])
+    ///     .execute(connection)?;
+    ///
+    /// let nearest_neighbors = items
+    ///     .select(id)
+    ///     .order(embedding.l2_distance([1.0, 1.0, 2.0]))
+    ///     .limit(2)
+    ///     .load::<i32>(connection)?;
+    /// assert_eq!(vec![1, 2], nearest_neighbors);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn l2_distance<T>(self, other: T) -> dsl::L2Distance<Self, T>
+    where
+        T: AsExpression<Vector>,
+    {
+        Grouped(L2Distance::new(self, other.as_expression()))
+    }
+
+    /// Calculates the negative inner product of two vectors using the `<#>`
+    /// operator.
+    ///
+    /// The result is negated, so that ordering by it ascending returns the
+    /// vectors with the largest inner product first.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     items {
+    /// #         id -> Integer,
+    /// #         embedding -> diesel::sql_types::Vector,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> QueryResult<()> {
+    /// #     use self::items::dsl::*;
+    /// #     let connection = &mut connection_no_data();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS vector").execute(connection)?;
+    /// #     diesel::sql_query("CREATE TABLE items (id SERIAL PRIMARY KEY, embedding vector(3) NOT NULL)")
+    /// #         .execute(connection)?;
+    /// diesel::insert_into(items)
+    ///     .values(&vec![
+    ///         embedding.eq(vec![1.0, 1.0, 1.0]),
+    ///         embedding.eq(vec![2.0, 3.0, 0.0]),
+    ///         embedding.eq(vec![-1.0, -1.0, 0.0]),
+    ///     ])
+    ///     .execute(connection)?;
+    ///
+    /// let nearest_neighbors = items
+    ///     .select(id)
+    ///     .order(embedding.max_inner_product([1.0, 1.0, 2.0]))
+    ///     .limit(2)
+    ///     .load::<i32>(connection)?;
+    /// assert_eq!(vec![2, 1], nearest_neighbors);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn max_inner_product<T>(self, other: T) -> dsl::MaxInnerProduct<Self, T>
+    where
+        T: AsExpression<Vector>,
+    {
+        Grouped(NegativeInnerProduct::new(self, other.as_expression()))
+    }
+
+    /// Calculates the cosine distance between two vectors using the `<=>`
+    /// operator.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     items {
+    /// #         id -> Integer,
+    /// #         embedding -> diesel::sql_types::Vector,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> QueryResult<()> {
+    /// #     use self::items::dsl::*;
+    /// #     let connection = &mut connection_no_data();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS vector").execute(connection)?;
+    /// #     diesel::sql_query("CREATE TABLE items (id SERIAL PRIMARY KEY, embedding vector(3) NOT NULL)")
+    /// #         .execute(connection)?;
+    /// diesel::insert_into(items)
+    ///     .values(&vec![
+    ///         embedding.eq(vec![1.0, 1.0, 1.0]),
+    ///         embedding.eq(vec![2.0, 3.0, 0.0]),
+    ///         embedding.eq(vec![-1.0, -1.0, 0.0]),
+    ///     ])
+    ///     .execute(connection)?;
+    ///
+    /// let nearest_neighbors = items
+    ///     .select(id)
+    ///     .order(embedding.cosine_distance([1.0, 1.0, 2.0]))
+    ///     .limit(2)
+    ///     .load::<i32>(connection)?;
+    /// assert_eq!(vec![1, 2], nearest_neighbors);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn cosine_distance<T>(self, other: T) -> dsl::CosineDistance<Self, T>
+    where
+        T: AsExpression<Vector>,
+    {
+        Grouped(CosineDistance::new(self, other.as_expression()))
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "pgvector"))]
+impl<T> PgVectorExpressionMethods for T
+where
+    T: Expression,
+    T::SqlType: VectorOrNullableVector,
+{
+}
+
 pub(in crate::pg) mod private {
     use crate::sql_types::{
-        AllAreNullable, Array, Binary, Cidr, Inet, Integer, Json, Jsonb, MaybeNullableType,
-        Multirange, Nullable, Range, Record, SingleValue, SqlType, Text,
+        AllAreNullable, Array, Binary, Cidr, Inet, Integer, Interval, Json, Jsonb,
+        MaybeNullableType, Multirange, Nullable, Range, Record, SingleValue, SqlType, Text,
+        Timestamp, Timestamptz,
     };
     use crate::{Expression, IntoSql};
 
@@ -4219,13 +4486,16 @@ pub(in crate::pg) mod private {
     )]
     pub trait RangeOrNullableRange {
         type Inner: SingleValue;
+        type Multirange: SingleValue;
     }
 
     impl<ST: SingleValue> RangeOrNullableRange for Range<ST> {
         type Inner = ST;
+        type Multirange = Multirange<ST>;
     }
     impl<ST: SingleValue> RangeOrNullableRange for Nullable<Range<ST>> {
         type Inner = ST;
+        type Multirange = Nullable<Multirange<ST>>;
     }
 
     /// Marker trait used to implement `PgRangeExpressionMethods` on the appropriate
@@ -4380,6 +4650,45 @@ pub(in crate::pg) mod private {
     impl BinaryOrNullableBinary for Binary {}
     impl BinaryOrNullableBinary for Nullable<Binary> {}
 
+    #[cfg(feature = "pgvector")]
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::Vector` nor `diesel::sql_types::Nullable<Vector>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait VectorOrNullableVector {}
+
+    #[cfg(feature = "pgvector")]
+    impl VectorOrNullableVector for crate::sql_types::Vector {}
+    #[cfg(feature = "pgvector")]
+    impl VectorOrNullableVector for Nullable<crate::sql_types::Vector> {}
+
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::Geometry`, `diesel::sql_types::Geography`, \
+                   `diesel::sql_types::Nullable<Geometry>` nor `diesel::sql_types::Nullable<Geography>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait GeometryOrGeography {}
+
+    #[cfg(feature = "postgis")]
+    impl GeometryOrGeography for crate::sql_types::Geometry {}
+    #[cfg(feature = "postgis")]
+    impl GeometryOrGeography for crate::sql_types::Geography {}
+    #[cfg(feature = "postgis")]
+    impl GeometryOrGeography for Nullable<crate::sql_types::Geometry> {}
+    #[cfg(feature = "postgis")]
+    impl GeometryOrGeography for Nullable<crate::sql_types::Geography> {}
+
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::Geometry` nor `diesel::sql_types::Nullable<Geometry>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait GeometryOrNullableGeometry {}
+
+    #[cfg(feature = "postgis")]
+    impl GeometryOrNullableGeometry for crate::sql_types::Geometry {}
+    #[cfg(feature = "postgis")]
+    impl GeometryOrNullableGeometry for Nullable<crate::sql_types::Geometry> {}
+
     pub trait MaybeNullableValue<T>: SingleValue {
         type Out: SingleValue;
     }
@@ -4414,6 +4723,32 @@ pub(in crate::pg) mod private {
     impl<T> RecordOrNullableRecord for Record<T> {}
     impl<T> RecordOrNullableRecord for Nullable<Record<T>> {}
 
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::Timestamp`, `diesel::sql_types::Timestamptz`, \
+                   `diesel::sql_types::Nullable<Timestamp>` nor `diesel::sql_types::Nullable<Timestamptz>`",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait TimestampOrTimestamptz {}
+
+    impl TimestampOrTimestamptz for Timestamp {}
+    impl TimestampOrTimestamptz for Timestamptz {}
+    impl TimestampOrTimestamptz for Nullable<Timestamp> {}
+    impl TimestampOrTimestamptz for Nullable<Timestamptz> {}
+
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::Timestamp`, `diesel::sql_types::Timestamptz`, \
+                   `diesel::sql_types::Interval` nor the `Nullable` variant of one of these types",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait TimestampOrTimestamptzOrInterval {}
+
+    impl TimestampOrTimestamptzOrInterval for Timestamp {}
+    impl TimestampOrTimestamptzOrInterval for Timestamptz {}
+    impl TimestampOrTimestamptzOrInterval for Interval {}
+    impl TimestampOrTimestamptzOrInterval for Nullable<Timestamp> {}
+    impl TimestampOrTimestamptzOrInterval for Nullable<Timestamptz> {}
+    impl TimestampOrTimestamptzOrInterval for Nullable<Interval> {}
+
     pub trait CombinedAllNullableValue<O, Out>: SingleValue {
         type Out: SingleValue;
     }
@@ -1,9 +1,13 @@
 //! PostgreSQL specific functions
 
 use super::expression_methods::InetOrCidr;
+#[cfg(doc)]
+use crate::expression::functions::aggregate_expressions::AggregateExpressionMethods;
 use crate::expression::functions::declare_sql_function;
 use crate::pg::expression::expression_methods::ArrayOrNullableArray;
 use crate::pg::expression::expression_methods::CombinedAllNullableValue;
+use crate::pg::expression::expression_methods::GeometryOrGeography;
+use crate::pg::expression::expression_methods::GeometryOrNullableGeometry;
 use crate::pg::expression::expression_methods::JsonOrNullableJson;
 use crate::pg::expression::expression_methods::JsonbOrNullableJsonb;
 use crate::pg::expression::expression_methods::MaybeNullableValue;
@@ -13,6 +17,8 @@ use crate::pg::expression::expression_methods::RangeOrNullableRange;
 use crate::pg::expression::expression_methods::RecordOrNullableRecord;
 use crate::pg::expression::expression_methods::TextArrayOrNullableTextArray;
 use crate::pg::expression::expression_methods::TextOrNullableText;
+use crate::pg::expression::expression_methods::TimestampOrTimestamptz;
+use crate::pg::expression::expression_methods::TimestampOrTimestamptzOrInterval;
 use crate::sql_types::helper::CombinedNullableValue;
 use crate::sql_types::*;
 
@@ -69,6 +75,140 @@ extern "SQL" {
     #[cfg(feature = "postgres_backend")]
     fn set_masklen<T: InetOrCidr + SingleValue>(addr: T, len: Integer) -> T;
 
+    /// Sets the 7th bit of a macaddr8 value to one, creating what is known as modified EUI-64,
+    /// for inclusion in an IPv6 address.
+    #[cfg(feature = "postgres_backend")]
+    fn macaddr8_set7bit(addr: MacAddr8) -> MacAddr8;
+
+    /// Truncates a timestamp or interval to the given precision
+    ///
+    /// Valid values for `field` are `microseconds`, `milliseconds`, `second`,
+    /// `minute`, `hour`, `day`, `week`, `month`, `quarter`, `year`, `decade`,
+    /// `century` and `millennium`. This is commonly used to group rows by day
+    /// or month.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{date_trunc, sql, to_char};
+    /// #     use diesel::sql_types::Timestamp;
+    /// #     let connection = &mut establish_connection();
+    /// let timestamp = sql::<Timestamp>("TIMESTAMP '2024-03-15 13:45:12'");
+    /// let day = diesel::select(to_char(
+    ///     date_trunc("day", timestamp),
+    ///     "YYYY-MM-DD HH24:MI:SS",
+    /// ))
+    /// .get_result::<String>(connection)?;
+    /// assert_eq!("2024-03-15 00:00:00", day);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn date_trunc<T: TimestampOrTimestamptzOrInterval + SingleValue>(field: Text, source: T) -> T;
+
+    /// Retrieves a subfield like the year or the hour from a timestamp or interval
+    ///
+    /// This is the function equivalent of `EXTRACT(field FROM source)`. Valid
+    /// values for `field` include `year`, `month`, `day`, `hour`, `minute`,
+    /// `second`, `dow` (day of the week), `doy` (day of the year) and `epoch`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{date_part, sql};
+    /// #     use diesel::sql_types::Timestamp;
+    /// #     let connection = &mut establish_connection();
+    /// let timestamp = sql::<Timestamp>("TIMESTAMP '2024-03-15 13:45:12'");
+    /// let year = diesel::select(date_part("year", timestamp.clone())).get_result::<f64>(connection)?;
+    /// assert_eq!(2024.0, year);
+    ///
+    /// let hour = diesel::select(date_part("hour", timestamp)).get_result::<f64>(connection)?;
+    /// assert_eq!(13.0, hour);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn date_part<T: TimestampOrTimestamptzOrInterval + MaybeNullableValue<Double>>(
+        field: Text,
+        source: T,
+    ) -> T::Out;
+
+    /// Subtracts `start` from `end`, producing a symbolic result that uses years and months,
+    /// rather than just days
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{age, sql};
+    /// #     use diesel::pg::data_types::PgInterval;
+    /// #     use diesel::sql_types::Timestamp;
+    /// #     let connection = &mut establish_connection();
+    /// let interval = diesel::select(age(
+    ///     sql::<Timestamp>("TIMESTAMP '2024-04-16 12:00:00'"),
+    ///     sql::<Timestamp>("TIMESTAMP '2024-03-15 00:00:00'"),
+    /// ))
+    /// .get_result::<PgInterval>(connection)?;
+    /// assert_eq!(PgInterval::new(12 * 60 * 60 * 1_000_000, 1, 1), interval);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn age<T: TimestampOrTimestamptz + MaybeNullableValue<Interval>>(end: T, start: T) -> T::Out;
+
+    /// Formats a timestamp or interval as text according to the given format
+    ///
+    /// See the [PostgreSQL documentation](https://www.postgresql.org/docs/current/functions-formatting.html)
+    /// for the supported template patterns.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{sql, to_char};
+    /// #     use diesel::sql_types::Timestamp;
+    /// #     let connection = &mut establish_connection();
+    /// let formatted = diesel::select(to_char(
+    ///     sql::<Timestamp>("TIMESTAMP '2024-03-15 13:45:12'"),
+    ///     "DD.MM.YYYY HH24:MI",
+    /// ))
+    /// .get_result::<String>(connection)?;
+    /// assert_eq!("15.03.2024 13:45", formatted);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn to_char<T: TimestampOrTimestamptzOrInterval + MaybeNullableValue<Text>>(
+        value: T,
+        format: Text,
+    ) -> T::Out;
+
     /// Returns the lower bound of the range
     ///
     /// If the range is empty or has no lower bound, it returns NULL.
@@ -439,6 +579,78 @@ extern "SQL" {
     fn multirange_merge<R: MultirangeOrNullableMultirange + SingleValue>(multirange: R)
     -> R::Range;
 
+    /// Returns a multirange containing just the given range
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// # use diesel::pg::sql_types::Range;
+    /// # use diesel::dsl::multirange;
+    /// #     use std::collections::Bound;
+    /// #     use diesel::sql_types::{Nullable, Integer};
+    /// #     let connection = &mut establish_connection();
+    /// let int = diesel::select(multirange::<Range<Integer>, _>(1..5))
+    ///     .get_result::<Vec<(Bound<i32>, Bound<i32>)>>(connection)?;
+    /// assert_eq!(vec![(Bound::Included(1), Bound::Excluded(5))], int);
+    ///
+    /// let int = diesel::select(multirange::<Nullable<Range<Integer>>, _>(
+    ///     None::<std::ops::Range<i32>>,
+    /// ))
+    /// .get_result::<Option<Vec<(Bound<i32>, Bound<i32>)>>>(connection)?;
+    /// assert_eq!(None, int);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn multirange<R: RangeOrNullableRange + SingleValue>(range: R) -> R::Multirange;
+
+    /// Computes the union of the non-null input ranges
+    ///
+    /// Returns `NULL` if there are no non-null input values.
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See [`AggregateExpressionMethods`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() -> QueryResult<()> {
+    /// #     use diesel::dsl::{int4range, range_agg};
+    /// #     use diesel::pg::sql_types::RangeBound;
+    /// #     use schema::animals::dsl::*;
+    /// #     use std::collections::Bound;
+    /// #     let connection = &mut establish_connection();
+    /// let leg_ranges = animals
+    ///     .select(range_agg(int4range(
+    ///         legs.nullable(),
+    ///         (legs + 2).nullable(),
+    ///         RangeBound::LowerBoundInclusiveUpperBoundExclusive,
+    ///     )))
+    ///     .get_result::<Option<Vec<(Bound<i32>, Bound<i32>)>>>(connection)?;
+    /// assert_eq!(
+    ///     Some(vec![
+    ///         (Bound::Included(4), Bound::Excluded(6)),
+    ///         (Bound::Included(8), Bound::Excluded(10)),
+    ///     ]),
+    ///     leg_ranges
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[aggregate]
+    fn range_agg<R: RangeOrNullableRange + SingleValue>(expr: R) -> Nullable<Multirange<R::Inner>>;
+
     /// Returns range of integer
     ///
     /// # Example
@@ -3007,6 +3219,190 @@ extern "SQL" {
     #[sql_name = "jsonb_build_array"]
     #[variadic(1)]
     fn jsonb_build_array<V: SingleValue>(value: V) -> Jsonb;
+
+    /// Builds a JSON object out of a variadic argument list of alternating keys and values.
+    ///
+    /// Keys are converted to text and values are converted as per `to_json`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{json_build_object_0, json_build_object_1, json_build_object_2};
+    /// #     use diesel::sql_types::{Array, Integer, Nullable, Text};
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// #
+    /// let result = diesel::select(json_build_object_0())
+    ///     .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!({}), result);
+    ///
+    /// let result = diesel::select(json_build_object_1::<Text, Integer, _, _>("a", 1))
+    ///     .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!({"a": 1}), result);
+    ///
+    /// let result = diesel::select(
+    ///     json_build_object_2::<Text, Nullable<Text>, Text, Array<Integer>, _, _, _, _>(
+    ///         "a",
+    ///         None::<String>,
+    ///         "b",
+    ///         vec![1, 2],
+    ///     ),
+    /// )
+    /// .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!({"a": null, "b": [1, 2]}), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[sql_name = "json_build_object"]
+    #[variadic(2)]
+    fn json_build_object<K: SingleValue, V: SingleValue>(key: K, value: V) -> Json;
+
+    /// Builds a JSONB object out of a variadic argument list of alternating keys and values.
+    ///
+    /// Keys are converted to text and values are converted as per `to_jsonb`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{jsonb_build_object_0, jsonb_build_object_1, jsonb_build_object_2};
+    /// #     use diesel::sql_types::{Integer, Jsonb, Text};
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// #
+    /// let result = diesel::select(jsonb_build_object_0())
+    ///     .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!({}), result);
+    ///
+    /// let result = diesel::select(jsonb_build_object_1::<Text, Text, _, _>("name", "Sean"))
+    ///     .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!({"name": "Sean"}), result);
+    ///
+    /// let result = diesel::select(
+    ///     jsonb_build_object_2::<Text, Integer, Text, Jsonb, _, _, _, _>(
+    ///         "id",
+    ///         1,
+    ///         "tags",
+    ///         json!(["a", "b"]),
+    ///     ),
+    /// )
+    /// .get_result::<serde_json::Value>(connection)?;
+    /// assert_eq!(json!({"id": 1, "tags": ["a", "b"]}), result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[sql_name = "jsonb_build_object"]
+    #[variadic(2)]
+    fn jsonb_build_object<K: SingleValue, V: SingleValue>(key: K, value: V) -> Jsonb;
+
+    /// Expands the top-level JSON array into a set of JSON values.
+    ///
+    /// This is a set-returning function. Used in a select clause
+    /// it produces one row for each element of the array, while
+    /// rows with an empty array or a `NULL` value produce no rows at all.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::json_array_elements;
+    /// #     use diesel::sql_types::Json;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// #
+    /// let result = diesel::select(json_array_elements::<Json, _>(json!([1, "a", {"b": 2}])))
+    ///     .load::<serde_json::Value>(connection)?;
+    /// assert_eq!(vec![json!(1), json!("a"), json!({"b": 2})], result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn json_array_elements<E: JsonOrNullableJson + SingleValue>(json: E) -> Json;
+
+    /// Expands the top-level JSONB array into a set of JSONB values.
+    ///
+    /// This is a set-returning function. Used in a select clause
+    /// it produces one row for each element of the array, while
+    /// rows with an empty array or a `NULL` value produce no rows at all.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #    posts {
+    /// #        id -> Integer,
+    /// #        tags -> Jsonb,
+    /// #    }
+    /// # }
+    /// #
+    /// # fn main() {
+    /// #     #[cfg(feature = "serde_json")]
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(feature = "serde_json")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::jsonb_array_elements;
+    /// #     use diesel::sql_types::Jsonb;
+    /// #     use self::posts::dsl::*;
+    /// #     use serde_json::json;
+    /// #     let connection = &mut establish_connection();
+    /// #     diesel::sql_query("CREATE TEMPORARY TABLE posts (id INTEGER PRIMARY KEY, tags JSONB NOT NULL)")
+    /// #         .execute(connection)?;
+    /// diesel::insert_into(posts)
+    ///     .values(&vec![
+    ///         (id.eq(1), tags.eq(json!(["rust", "sql"]))),
+    ///         (id.eq(2), tags.eq(json!([]))),
+    ///         (id.eq(3), tags.eq(json!(["diesel"]))),
+    ///     ])
+    ///     .execute(connection)?;
+    ///
+    /// let result = posts
+    ///     .select((id, jsonb_array_elements::<Jsonb, _>(tags)))
+    ///     .order_by(id)
+    ///     .load::<(i32, serde_json::Value)>(connection)?;
+    /// assert_eq!(
+    ///     vec![
+    ///         (1, json!("rust")),
+    ///         (1, json!("sql")),
+    ///         (3, json!("diesel")),
+    ///     ],
+    ///     result
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    fn jsonb_array_elements<E: JsonbOrNullableJsonb + SingleValue>(jsonb: E) -> Jsonb;
     /// Extracts JSON sub-object at the specified path. (This is functionally equivalent to the #> operator, but writing the path out as a variadic list can be more convenient in some cases.)
     ///
     /// # Example
@@ -3254,6 +3650,263 @@ extern "SQL" {
         json: J,
         text: T,
     ) -> Nullable<Text>;
+
+    /// Creates a new large object with the given content and returns its oid
+    ///
+    /// Passing `0` as `loid` lets the server assign an unused oid. The returned
+    /// oid is usually stored in a column of the `oid` type, the content can
+    /// later be streamed via
+    /// [`PgConnection::open_large_object`](crate::pg::PgConnection::open_large_object).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{lo_from_bytea, lo_get, lo_unlink};
+    /// #     let connection = &mut establish_connection();
+    /// use diesel::pg::data_types::PgLargeObjectId;
+    ///
+    /// let oid = diesel::select(lo_from_bytea(0, b"Hello".to_vec()))
+    ///     .get_result::<PgLargeObjectId>(connection)?;
+    ///
+    /// let content = diesel::select(lo_get(oid)).get_result::<Vec<u8>>(connection)?;
+    /// assert_eq!(b"Hello".to_vec(), content);
+    ///
+    /// let removed = diesel::select(lo_unlink(oid)).get_result::<i32>(connection)?;
+    /// assert_eq!(1, removed);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[skip_return_type_helper]
+    fn lo_from_bytea(loid: Oid, data: Binary) -> Oid;
+
+    /// Returns the content of the large object with the given oid
+    ///
+    /// See [`lo_from_bytea`](lo_from_bytea()) for an example.
+    #[cfg(feature = "postgres_backend")]
+    #[skip_return_type_helper]
+    fn lo_get(loid: Oid) -> Binary;
+
+    /// Removes the large object with the given oid, returns `1` on success
+    ///
+    /// See [`lo_from_bytea`](lo_from_bytea()) for an example.
+    #[cfg(feature = "postgres_backend")]
+    #[skip_return_type_helper]
+    fn lo_unlink(loid: Oid) -> Integer;
+
+    /// Collects all input values, including nulls, into an array
+    ///
+    /// Returns `NULL` if there are no input rows. The order of the
+    /// elements is unspecified unless an order is given via
+    /// [`aggregate_order`](AggregateExpressionMethods::aggregate_order).
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See [`AggregateExpressionMethods`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() -> QueryResult<()> {
+    /// #     use diesel::dsl::array_agg;
+    /// #     use schema::animals::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let all_legs = animals
+    ///     .select(array_agg(legs).aggregate_order(legs.desc()))
+    ///     .get_result::<Option<Vec<i32>>>(connection)?;
+    /// assert_eq!(Some(vec![8, 4]), all_legs);
+    ///
+    /// let no_legs = animals
+    ///     .filter(legs.gt(8))
+    ///     .select(array_agg(legs))
+    ///     .get_result::<Option<Vec<i32>>>(connection)?;
+    /// assert_eq!(None, no_legs);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[aggregate]
+    fn array_agg<T: SingleValue>(expr: T) -> Nullable<Array<T>>;
+
+    /// Concatenates the non-null input values into a string, separated by `delimiter`
+    ///
+    /// Returns `NULL` if there are no non-null input values. The order of the
+    /// values is unspecified unless an order is given via
+    /// [`aggregate_order`](AggregateExpressionMethods::aggregate_order).
+    ///
+    /// ## Aggregate Function Expression
+    ///
+    /// This function can be used as aggregate expression. See [`AggregateExpressionMethods`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() -> QueryResult<()> {
+    /// #     use diesel::dsl::string_agg;
+    /// #     use schema::animals::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let all_species = animals
+    ///     .select(string_agg(species, ", ").aggregate_order(species.desc()))
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(Some("spider, dog".to_owned()), all_species);
+    ///
+    /// let all_names = animals
+    ///     .select(string_agg(name, ", ").aggregate_order(name))
+    ///     .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(Some("Jack".to_owned()), all_names);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "postgres_backend")]
+    #[aggregate]
+    fn string_agg<T: TextOrNullableText + SingleValue>(expr: T, delimiter: Text) -> Nullable<Text>;
+
+    /// Returns the minimum distance between two geometries or geographies
+    ///
+    /// For [`Geometry`] values the distance is measured in the units of the
+    /// spatial reference system, for [`Geography`] values in meters.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     places {
+    /// #         id -> Integer,
+    /// #         location -> diesel::sql_types::Geometry,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> QueryResult<()> {
+    /// #     use self::places::dsl::*;
+    /// #     use diesel::dsl::st_distance;
+    /// #     let connection = &mut connection_no_data();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS postgis").execute(connection)?;
+    /// #     diesel::sql_query("CREATE TABLE places (id SERIAL PRIMARY KEY, location geometry NOT NULL)")
+    /// #         .execute(connection)?;
+    /// #     diesel::sql_query("INSERT INTO places (location) VALUES ('POINT(3 4)')")
+    /// #         .execute(connection)?;
+    /// // `POINT(0 0)` encoded as well-known binary
+    /// let origin: Vec<u8> = vec![
+    ///     1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    /// ];
+    /// let distance = places
+    ///     .select(st_distance(location, origin))
+    ///     .get_result::<f64>(connection)?;
+    /// assert_eq!(5.0, distance);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(all(feature = "postgres_backend", feature = "postgis"))]
+    #[sql_name = "ST_Distance"]
+    fn st_distance<G: GeometryOrGeography + SingleValue + MaybeNullableValue<Double>>(
+        geom1: G,
+        geom2: G,
+    ) -> G::Out;
+
+    /// Returns true if two geometries or geographies are within the given distance
+    ///
+    /// In contrast to comparing the result of [`st_distance`](st_distance())
+    /// this function can use a spatial index.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     places {
+    /// #         id -> Integer,
+    /// #         location -> diesel::sql_types::Geometry,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> QueryResult<()> {
+    /// #     use self::places::dsl::*;
+    /// #     use diesel::dsl::st_dwithin;
+    /// #     let connection = &mut connection_no_data();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS postgis").execute(connection)?;
+    /// #     diesel::sql_query("CREATE TABLE places (id SERIAL PRIMARY KEY, location geometry NOT NULL)")
+    /// #         .execute(connection)?;
+    /// #     diesel::sql_query("INSERT INTO places (location) VALUES ('POINT(3 4)'), ('POINT(10 10)')")
+    /// #         .execute(connection)?;
+    /// // `POINT(0 0)` encoded as well-known binary
+    /// let origin: Vec<u8> = vec![
+    ///     1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    /// ];
+    /// let nearby = places
+    ///     .select(id)
+    ///     .filter(st_dwithin(location, origin, 6.0))
+    ///     .load::<i32>(connection)?;
+    /// assert_eq!(vec![1], nearby);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(all(feature = "postgres_backend", feature = "postgis"))]
+    #[sql_name = "ST_DWithin"]
+    fn st_dwithin<G: GeometryOrGeography + SingleValue + MaybeNullableValue<Bool>>(
+        geom1: G,
+        geom2: G,
+        distance: Double,
+    ) -> G::Out;
+
+    /// Returns true if no point of `geom_b` lies outside of `geom_a` and
+    /// their interiors have at least one point in common
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # table! {
+    /// #     areas {
+    /// #         id -> Integer,
+    /// #         boundary -> diesel::sql_types::Geometry,
+    /// #     }
+    /// # }
+    /// #
+    /// # fn main() -> QueryResult<()> {
+    /// #     use self::areas::dsl::*;
+    /// #     use diesel::dsl::st_contains;
+    /// #     let connection = &mut connection_no_data();
+    /// #     diesel::sql_query("CREATE EXTENSION IF NOT EXISTS postgis").execute(connection)?;
+    /// #     diesel::sql_query("CREATE TABLE areas (id SERIAL PRIMARY KEY, boundary geometry NOT NULL)")
+    /// #         .execute(connection)?;
+    /// #     diesel::sql_query(
+    /// #         "INSERT INTO areas (boundary) VALUES \
+    /// #          ('POLYGON((0 0, 0 2, 2 2, 2 0, 0 0))'), ('POLYGON((5 5, 5 6, 6 6, 6 5, 5 5))')",
+    /// #     )
+    /// #     .execute(connection)?;
+    /// // `POINT(1 1)` encoded as well-known binary
+    /// let point: Vec<u8> = vec![
+    ///     1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f, 0, 0, 0, 0, 0, 0, 0xf0, 0x3f,
+    /// ];
+    /// let containing_areas = areas
+    ///     .select(id)
+    ///     .filter(st_contains(boundary, point))
+    ///     .load::<i32>(connection)?;
+    /// assert_eq!(vec![1], containing_areas);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(all(feature = "postgres_backend", feature = "postgis"))]
+    #[sql_name = "ST_Contains"]
+    fn st_contains<G: GeometryOrNullableGeometry + SingleValue + MaybeNullableValue<Bool>>(
+        geom_a: G,
+        geom_b: G,
+    ) -> G::Out;
 }
 
 pub(super) mod return_type_helpers_reexported {
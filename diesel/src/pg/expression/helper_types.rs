@@ -4,7 +4,7 @@ use crate::expression::grouped::Grouped;
 use crate::expression_methods::JsonIndex;
 use crate::pg::expression::expression_methods::private::JsonRemoveIndex;
 use crate::pg::types::sql_types::Array;
-use crate::sql_types::{Inet, Integer, Nullable, VarChar};
+use crate::sql_types::{BigInt, Inet, Integer, Nullable, VarChar};
 
 /// The return type of [`lhs.ilike(rhs)`](super::expression_methods::PgTextExpressionMethods::ilike)
 #[cfg(feature = "postgres_backend")]
@@ -221,6 +221,29 @@ pub type DifferenceNet<Lhs, Rhs> =
 #[doc(hidden)] // used by `#[auto_type]`
 pub type Diff<Lhs, Rhs> = DifferenceNet<Lhs, Rhs>;
 
+/// The return type of [`lsh.add_offset(rhs)`](super::expression_methods::PgNetExpressionMethods::add_offset)
+#[cfg(feature = "postgres_backend")]
+pub type AddOffset<Lhs, Rhs> = Grouped<super::operators::AddNet<Lhs, AsExprOf<Rhs, BigInt>>>;
+
+/// The return type of [`lsh.sub_offset(rhs)`](super::expression_methods::PgNetExpressionMethods::sub_offset)
+#[cfg(feature = "postgres_backend")]
+pub type SubOffset<Lhs, Rhs> = Grouped<super::operators::SubtractNet<Lhs, AsExprOf<Rhs, BigInt>>>;
+
+/// The return type of [`lhs.l2_distance(rhs)`](super::expression_methods::PgVectorExpressionMethods::l2_distance)
+#[cfg(all(feature = "postgres_backend", feature = "pgvector"))]
+pub type L2Distance<Lhs, Rhs> =
+    Grouped<super::operators::L2Distance<Lhs, AsExprOf<Rhs, crate::sql_types::Vector>>>;
+
+/// The return type of [`lhs.max_inner_product(rhs)`](super::expression_methods::PgVectorExpressionMethods::max_inner_product)
+#[cfg(all(feature = "postgres_backend", feature = "pgvector"))]
+pub type MaxInnerProduct<Lhs, Rhs> =
+    Grouped<super::operators::NegativeInnerProduct<Lhs, AsExprOf<Rhs, crate::sql_types::Vector>>>;
+
+/// The return type of [`lhs.cosine_distance(rhs)`](super::expression_methods::PgVectorExpressionMethods::cosine_distance)
+#[cfg(all(feature = "postgres_backend", feature = "pgvector"))]
+pub type CosineDistance<Lhs, Rhs> =
+    Grouped<super::operators::CosineDistance<Lhs, AsExprOf<Rhs, crate::sql_types::Vector>>>;
+
 #[doc(hidden)]
 #[deprecated(note = "Use `dsl::Concat` instead")]
 pub type ConcatJsonb<Lhs, Rhs> = crate::dsl::Concat<Lhs, Rhs>;
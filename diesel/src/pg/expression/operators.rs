@@ -43,6 +43,14 @@ infix_operator!(IsContainedByNetLoose, " <<= ", backend: Pg);
 infix_operator!(AndNet, " & ", Inet, backend: Pg);
 infix_operator!(OrNet, " | ", Inet, backend: Pg);
 infix_operator!(DifferenceNet, " - ", Bigint, backend: Pg);
+infix_operator!(AddNet, " + ", Inet, backend: Pg);
+infix_operator!(SubtractNet, " - ", Inet, backend: Pg);
+#[cfg(feature = "pgvector")]
+infix_operator!(L2Distance, " <-> ", crate::sql_types::Double, backend: Pg);
+#[cfg(feature = "pgvector")]
+infix_operator!(NegativeInnerProduct, " <#> ", crate::sql_types::Double, backend: Pg);
+#[cfg(feature = "pgvector")]
+infix_operator!(CosineDistance, " <=> ", crate::sql_types::Double, backend: Pg);
 infix_operator!(HasKeyJsonb, " ? ", backend: Pg);
 infix_operator!(HasAnyKeyJsonb, " ?| ", backend: Pg);
 infix_operator!(HasAllKeysJsonb, " ?& ", backend: Pg);
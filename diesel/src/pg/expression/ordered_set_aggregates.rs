@@ -0,0 +1,341 @@
+//! PostgreSQL ordered-set aggregate functions
+//!
+//! Ordered-set aggregates compute their result from the input rows sorted by
+//! a `WITHIN GROUP (ORDER BY …)` clause. The functions in this module return
+//! an incomplete function call, which becomes an expression once the ordering
+//! is provided via `within_group`.
+
+use crate::expression::operators::{Asc, Desc};
+use crate::expression::{
+    AppearsOnTable, AsExpression, Expression, SelectableExpression, ValidGrouping, is_aggregate,
+};
+use crate::pg::Pg;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+use crate::sql_types::{
+    BigInt, Double, Float, Integer, Interval, IntoNullable, Nullable, Numeric, SingleValue,
+    SmallInt,
+};
+
+/// An expression that can be used in the `WITHIN GROUP (ORDER BY …)` clause
+/// of an ordered-set aggregate
+///
+/// This is implemented for any expression as well as for their
+/// [`asc`](crate::ExpressionMethods::asc()) and
+/// [`desc`](crate::ExpressionMethods::desc()) variants.
+pub trait WithinGroupOrder {
+    /// The SQL type of the sorted values
+    type SqlType;
+}
+
+impl<T> WithinGroupOrder for T
+where
+    T: Expression,
+    T::SqlType: SingleValue,
+{
+    type SqlType = T::SqlType;
+}
+
+impl<T> WithinGroupOrder for Asc<T>
+where
+    T: Expression,
+{
+    type SqlType = T::SqlType;
+}
+
+impl<T> WithinGroupOrder for Desc<T>
+where
+    T: Expression,
+{
+    type SqlType = T::SqlType;
+}
+
+/// An ordered-set aggregate accepting sorted values of the SQL type `ST`
+pub trait OrderedSetAggregate<ST> {
+    /// The SQL type returned by the aggregate
+    type SqlType: SingleValue;
+}
+
+/// An ordered-set aggregate function call with its `WITHIN GROUP (ORDER BY …)` clause
+///
+/// See [`percentile_cont`], [`percentile_disc`] and [`mode`] for details.
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct WithinGroup<F, O> {
+    function: F,
+    order: O,
+}
+
+impl<F, O> Expression for WithinGroup<F, O>
+where
+    O: WithinGroupOrder,
+    F: OrderedSetAggregate<O::SqlType>,
+{
+    type SqlType = F::SqlType;
+}
+
+impl<F, O, GB> ValidGrouping<GB> for WithinGroup<F, O> {
+    type IsAggregate = is_aggregate::Yes;
+}
+
+impl<F, O> QueryFragment<Pg> for WithinGroup<F, O>
+where
+    F: QueryFragment<Pg>,
+    O: QueryFragment<Pg>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        self.function.walk_ast(out.reborrow())?;
+        out.push_sql(" WITHIN GROUP (ORDER BY ");
+        self.order.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// The incomplete function call returned by [`percentile_cont`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct PercentileCont<F> {
+    fraction: F,
+}
+
+/// The incomplete function call returned by [`percentile_disc`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct PercentileDisc<F> {
+    fraction: F,
+}
+
+/// The incomplete function call returned by [`mode`]
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct Mode;
+
+macro_rules! within_group {
+    ($ty: ty $(, $param: ident)?) => {
+        impl$(<$param>)? $ty {
+            /// Adds the `WITHIN GROUP (ORDER BY order)` clause to this ordered-set aggregate
+            pub fn within_group<O>(self, order: O) -> WithinGroup<Self, O>
+            where
+                O: WithinGroupOrder,
+                Self: OrderedSetAggregate<O::SqlType>,
+            {
+                WithinGroup {
+                    function: self,
+                    order,
+                }
+            }
+        }
+    };
+}
+
+within_group!(PercentileCont<F>, F);
+within_group!(PercentileDisc<F>, F);
+within_group!(Mode);
+
+macro_rules! percentile_cont_types {
+    ($($ty: ty => $out: ty,)*) => {
+        $(
+            impl<F> OrderedSetAggregate<$ty> for PercentileCont<F> {
+                type SqlType = Nullable<$out>;
+            }
+
+            impl<F> OrderedSetAggregate<Nullable<$ty>> for PercentileCont<F> {
+                type SqlType = Nullable<$out>;
+            }
+        )*
+    };
+}
+
+percentile_cont_types! {
+    SmallInt => Double,
+    Integer => Double,
+    BigInt => Double,
+    Float => Double,
+    Double => Double,
+    Numeric => Double,
+    Interval => Interval,
+}
+
+impl<F, ST> OrderedSetAggregate<ST> for PercentileDisc<F>
+where
+    ST: IntoNullable,
+    ST::Nullable: SingleValue,
+{
+    type SqlType = ST::Nullable;
+}
+
+impl<ST> OrderedSetAggregate<ST> for Mode
+where
+    ST: IntoNullable,
+    ST::Nullable: SingleValue,
+{
+    type SqlType = ST::Nullable;
+}
+
+impl<F> QueryFragment<Pg> for PercentileCont<F>
+where
+    F: QueryFragment<Pg>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        out.push_sql("percentile_cont(");
+        self.fraction.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<F> QueryFragment<Pg> for PercentileDisc<F>
+where
+    F: QueryFragment<Pg>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        out.push_sql("percentile_disc(");
+        self.fraction.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl QueryFragment<Pg> for Mode {
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        out.push_sql("mode()");
+        Ok(())
+    }
+}
+
+impl<F, O, QS> AppearsOnTable<QS> for WithinGroup<PercentileCont<F>, O>
+where
+    Self: Expression,
+    F: AppearsOnTable<QS>,
+    O: AppearsOnTable<QS>,
+{
+}
+
+impl<F, O, QS> SelectableExpression<QS> for WithinGroup<PercentileCont<F>, O>
+where
+    Self: AppearsOnTable<QS>,
+    F: SelectableExpression<QS>,
+    O: SelectableExpression<QS>,
+{
+}
+
+impl<F, O, QS> AppearsOnTable<QS> for WithinGroup<PercentileDisc<F>, O>
+where
+    Self: Expression,
+    F: AppearsOnTable<QS>,
+    O: AppearsOnTable<QS>,
+{
+}
+
+impl<F, O, QS> SelectableExpression<QS> for WithinGroup<PercentileDisc<F>, O>
+where
+    Self: AppearsOnTable<QS>,
+    F: SelectableExpression<QS>,
+    O: SelectableExpression<QS>,
+{
+}
+
+impl<O, QS> AppearsOnTable<QS> for WithinGroup<Mode, O>
+where
+    Self: Expression,
+    O: AppearsOnTable<QS>,
+{
+}
+
+impl<O, QS> SelectableExpression<QS> for WithinGroup<Mode, O>
+where
+    Self: AppearsOnTable<QS>,
+    O: SelectableExpression<QS>,
+{
+}
+
+/// Computes a continuous percentile, interpolating between adjacent input values if needed
+///
+/// `fraction` must be between 0 and 1. The input values are provided by calling
+/// [`within_group`](PercentileCont::within_group()) on the result, which accepts
+/// numeric and interval expressions. Returns `NULL` if there are no input rows.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() -> QueryResult<()> {
+/// #     use diesel::dsl::percentile_cont;
+/// #     use schema::animals::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// let median_legs = animals
+///     .select(percentile_cont(0.5).within_group(legs))
+///     .get_result::<Option<f64>>(connection)?;
+/// assert_eq!(Some(6.0), median_legs);
+///
+/// let first_quartile = animals
+///     .select(percentile_cont(0.25).within_group(legs.desc()))
+///     .get_result::<Option<f64>>(connection)?;
+/// assert_eq!(Some(7.0), first_quartile);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn percentile_cont<F>(fraction: F) -> PercentileCont<F::Expression>
+where
+    F: AsExpression<Double>,
+{
+    PercentileCont {
+        fraction: fraction.as_expression(),
+    }
+}
+
+/// Computes a discrete percentile, the first input value whose position
+/// in the ordering equals or exceeds `fraction`
+///
+/// `fraction` must be between 0 and 1. The input values are provided by calling
+/// [`within_group`](PercentileDisc::within_group()) on the result, which accepts
+/// expressions of any sortable type. Returns `NULL` if there are no input rows.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() -> QueryResult<()> {
+/// #     use diesel::dsl::percentile_disc;
+/// #     use schema::animals::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// let median_species = animals
+///     .select(percentile_disc(0.5).within_group(species))
+///     .get_result::<Option<String>>(connection)?;
+/// assert_eq!(Some("dog".to_owned()), median_species);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn percentile_disc<F>(fraction: F) -> PercentileDisc<F::Expression>
+where
+    F: AsExpression<Double>,
+{
+    PercentileDisc {
+        fraction: fraction.as_expression(),
+    }
+}
+
+/// Computes the most frequent input value, choosing the first one
+/// in the ordering if there are multiple equally frequent values
+///
+/// The input values are provided by calling [`within_group`](Mode::within_group())
+/// on the result. Returns `NULL` if there are no input rows.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// #
+/// # fn main() -> QueryResult<()> {
+/// #     use diesel::dsl::mode;
+/// #     use schema::animals::dsl::*;
+/// #     let connection = &mut establish_connection();
+/// let most_common_legs = animals
+///     .select(mode().within_group(legs.desc()))
+///     .get_result::<Option<i32>>(connection)?;
+/// assert_eq!(Some(8), most_common_legs);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn mode() -> Mode {
+    Mode
+}
@@ -19,18 +19,26 @@ mod value;
 
 #[doc(inline)]
 pub use self::backend::{Pg, PgNotification, PgTypeMetadata};
+#[cfg(feature = "pipeline_mode_for_libpq_14")]
+pub use self::connection::pipeline;
 #[cfg(feature = "postgres")]
-pub use self::connection::{PgConnection, PgRowByRowLoadingMode};
+pub use self::connection::{
+    PgConnection, PgCursorLoadingMode, PgLargeObject, PgLargeObjectMode, PgRowByRowLoadingMode,
+};
 #[doc(inline)]
 pub use self::metadata_lookup::PgMetadataLookup;
 #[doc(inline)]
 pub use self::query_builder::DistinctOnClause;
 #[doc(inline)]
+pub use self::query_builder::NotifyQuery;
+#[doc(inline)]
 pub use self::query_builder::OrderDecorator;
 #[doc(inline)]
 pub use self::query_builder::PgQueryBuilder;
 #[doc(inline)]
-pub use self::query_builder::{CopyFormat, CopyFromQuery, CopyHeader, CopyTarget, CopyToQuery};
+pub use self::query_builder::{
+    CopyBinaryWriter, CopyFormat, CopyFromQuery, CopyHeader, CopyRow, CopyTarget, CopyToQuery,
+};
 #[doc(inline)]
 pub use self::transaction::TransactionBuilder;
 #[doc(inline)]
@@ -63,6 +71,8 @@ pub mod data_types {
     #[doc(inline)]
     pub use super::types::floats::PgNumeric;
     #[doc(inline)]
+    pub use super::types::large_object::PgLargeObjectId;
+    #[doc(inline)]
     pub use super::types::money::PgMoney;
     pub use super::types::money::PgMoney as Cents;
     #[doc(inline)]
@@ -182,6 +182,173 @@ impl PgMetadataLookup for Dummy {
     }
 }
 
+fn write_value<ST, T>(value: &T, out: &mut Vec<u8>) -> QueryResult<IsNull>
+where
+    T: ToSql<ST, Pg>,
+{
+    use crate::query_builder::ByteWrapper;
+    use crate::serialize::Output;
+
+    ToSql::<ST, Pg>::to_sql(value, &mut Output::new(ByteWrapper(out), &mut Dummy as _))
+        .map_err(crate::result::Error::SerializationError)
+}
+
+fn io_error(e: std::io::Error) -> crate::result::Error {
+    crate::result::Error::DeserializationError(Box::new(e))
+}
+
+/// Writes rows in the binary format expected by `COPY FROM` statements
+///
+/// This allows to stream rows into the database via
+/// [`CopyFromQuery::from_raw_data`] without collecting them up front,
+/// as required by [`CopyFromQuery::from_insertable`]. Values are serialized
+/// via their [`ToSql`] implementations, the SQL types of a row are given as tuple
+/// to [`CopyBinaryWriter::write_row`].
+///
+/// The query needs to use [`CopyFormat::Binary`]. Call [`CopyBinaryWriter::finish`]
+/// after the last row to write the end marker of the binary format.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../../doctest_setup.rs");
+/// # fn run_test() -> QueryResult<()> {
+/// # use crate::schema::users;
+/// use diesel::pg::{CopyBinaryWriter, CopyFormat};
+/// use diesel::sql_types::{Integer, Text};
+/// # let connection = &mut establish_connection();
+///
+/// let count = diesel::copy_from(users::table)
+///     .from_raw_data((users::id, users::name), |copy| {
+///         let mut writer = CopyBinaryWriter::new(copy)?;
+///         for id in 3..=1000 {
+///             writer.write_row::<(Integer, Text), _>(&(id, format!("User {id}")))?;
+///         }
+///         writer.finish()
+///     })
+///     .with_format(CopyFormat::Binary)
+///     .execute(connection)?;
+///
+/// assert_eq!(count, 998);
+/// # Ok(())
+/// # }
+/// # fn main() {
+/// #    run_test().unwrap();
+/// # }
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct CopyBinaryWriter<'a> {
+    out: &'a mut dyn std::io::Write,
+    // we reuse the same buffer here again and again
+    // as we expect the data to be "similar"
+    // this skips reallocating
+    buffer: Vec<u8>,
+}
+
+impl<'a> CopyBinaryWriter<'a> {
+    /// Create a new writer and write the header of the binary format
+    pub fn new(out: &'a mut dyn std::io::Write) -> QueryResult<Self> {
+        // see https://www.postgresql.org/docs/current/sql-copy.html for
+        // a description of the binary format
+        //
+        // We don't write oids
+        out.write_all(&super::COPY_MAGIC_HEADER).map_err(io_error)?;
+        out.write_i32::<NetworkEndian>(0).map_err(io_error)?;
+        out.write_i32::<NetworkEndian>(0).map_err(io_error)?;
+        Ok(Self {
+            out,
+            buffer: Vec::new(),
+        })
+    }
+
+    /// Write a single row
+    ///
+    /// `ST` is a tuple of the SQL types of the columns
+    /// given as target to [`CopyFromQuery::from_raw_data`]
+    pub fn write_row<ST, R>(&mut self, row: &R) -> QueryResult<()>
+    where
+        R: CopyRow<ST>,
+    {
+        self.write_fields(R::COLUMN_COUNT, |idx, out| row.write_field(idx, out))
+    }
+
+    /// Write the end marker of the binary format
+    pub fn finish(self) -> QueryResult<()> {
+        self.out.write_i16::<NetworkEndian>(-1).map_err(io_error)
+    }
+
+    fn write_fields(
+        &mut self,
+        column_count: u16,
+        mut write_field: impl FnMut(u16, &mut Vec<u8>) -> QueryResult<IsNull>,
+    ) -> QueryResult<()> {
+        let buffer = &mut self.buffer;
+        buffer
+            .write_u16::<NetworkEndian>(column_count)
+            .map_err(io_error)?;
+        for idx in 0..column_count {
+            // first write the null indicator as dummy value
+            buffer.write_i32::<NetworkEndian>(-1).map_err(io_error)?;
+            let len_before = buffer.len();
+            let is_null = write_field(idx, buffer)?;
+            if is_null == IsNull::No {
+                // fill in the length afterwards
+                let len_after = buffer.len();
+                let diff = (len_after - len_before)
+                    .try_into()
+                    .map_err(|e| crate::result::Error::SerializationError(Box::new(e)))?;
+                let bytes = i32::to_be_bytes(diff);
+                for (b, t) in bytes.into_iter().zip(&mut buffer[len_before - 4..]) {
+                    *t = b;
+                }
+            }
+        }
+        self.out.write_all(buffer).map_err(io_error)?;
+        buffer.clear();
+        Ok(())
+    }
+}
+
+/// A row that can be written via [`CopyBinaryWriter::write_row`]
+///
+/// This trait is implemented for tuples of values, where each value
+/// implements [`ToSql`] for the corresponding SQL type in `ST`.
+pub trait CopyRow<ST> {
+    #[doc(hidden)]
+    const COLUMN_COUNT: u16;
+
+    #[doc(hidden)]
+    fn write_field(&self, idx: u16, out: &mut Vec<u8>) -> QueryResult<IsNull>;
+}
+
+macro_rules! impl_copy_row_for_tuple {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST:ident, $TT:ident,)+
+        }
+    )+) => {
+        $(
+            impl<$($T,)* $($TT,)*> CopyRow<($($T,)*)> for ($($TT,)*)
+            where
+                $($TT: ToSql<$T, Pg>,)*
+            {
+                // statically known to always fit
+                // as we don't support more than 128 columns
+                const COLUMN_COUNT: u16 = $Tuple;
+
+                fn write_field(&self, idx: u16, out: &mut Vec<u8>) -> QueryResult<IsNull> {
+                    match idx {
+                        $($idx => write_value::<$T, _>(&self.$idx, out),)*
+                        _ => unreachable!(),
+                    }
+                }
+            }
+        )*
+    }
+}
+
+crate::for_each_tuple!(impl_copy_row_for_tuple);
+
 trait CopyFromInsertableHelper {
     type Target: CopyTarget;
     const COLUMN_COUNT: u16;
@@ -210,19 +377,9 @@ macro_rules! impl_copy_from_insertable_helper_for_values_clause {
                 const COLUMN_COUNT: u16 = $Tuple;
 
                 fn write_to_buffer(&self, idx: u16, out: &mut Vec<u8>) -> QueryResult<IsNull> {
-                    use crate::query_builder::ByteWrapper;
-                    use crate::serialize::Output;
-
                     let values = &self.values;
                     match idx {
-                        $($idx =>{
-                            let item = &values.$idx.expr.item;
-                            let is_null = ToSql::<$T, Pg>::to_sql(
-                                item,
-                                &mut Output::new( ByteWrapper(out), &mut Dummy as _)
-                            ).map_err(crate::result::Error::SerializationError)?;
-                            return Ok(is_null);
-                        })*
+                        $($idx => write_value::<$T, _>(&values.$idx.expr.item, out),)*
                         _ => unreachable!(),
                     }
                 }
@@ -244,19 +401,9 @@ macro_rules! impl_copy_from_insertable_helper_for_values_clause {
                 const COLUMN_COUNT: u16 = $Tuple;
 
                 fn write_to_buffer(&self, idx: u16, out: &mut Vec<u8>) -> QueryResult<IsNull> {
-                    use crate::query_builder::ByteWrapper;
-                    use crate::serialize::Output;
-
                     let values = &self.values;
                     match idx {
-                        $($idx =>{
-                            let item = &values.$idx.expr.item;
-                            let is_null = ToSql::<$T, Pg>::to_sql(
-                                item,
-                                &mut Output::new( ByteWrapper(out), &mut Dummy as _)
-                            ).map_err(crate::result::Error::SerializationError)?;
-                            return Ok(is_null);
-                        })*
+                        $($idx => write_value::<$T, _>(&values.$idx.expr.item, out),)*
                         _ => unreachable!(),
                     }
                 }
@@ -278,60 +425,16 @@ where
     type Error = crate::result::Error;
 
     fn callback(&mut self, copy: &mut impl std::io::Write) -> Result<(), Self::Error> {
-        let io_result_mapper = |e| crate::result::Error::DeserializationError(Box::new(e));
-        // see https://www.postgresql.org/docs/current/sql-copy.html for
-        // a description of the binary format
-        //
-        // We don't write oids
-
-        // write the header
-        copy.write_all(&super::COPY_MAGIC_HEADER)
-            .map_err(io_result_mapper)?;
-        copy.write_i32::<NetworkEndian>(0)
-            .map_err(io_result_mapper)?;
-        copy.write_i32::<NetworkEndian>(0)
-            .map_err(io_result_mapper)?;
-        // write the data
-        // we reuse the same buffer here again and again
-        // as we expect the data to be "similar"
-        // this skips reallocating
-        let mut buffer = Vec::<u8>::new();
+        let mut writer = CopyBinaryWriter::new(copy)?;
         let values = self
             .0
             .take()
             .expect("We only call this callback once")
             .values();
         for i in values.values {
-            // column count
-            buffer
-                .write_u16::<NetworkEndian>(V::COLUMN_COUNT)
-                .map_err(io_result_mapper)?;
-            for idx in 0..V::COLUMN_COUNT {
-                // first write the null indicator as dummy value
-                buffer
-                    .write_i32::<NetworkEndian>(-1)
-                    .map_err(io_result_mapper)?;
-                let len_before = buffer.len();
-                let is_null = i.write_to_buffer(idx, &mut buffer)?;
-                if is_null == IsNull::No {
-                    // fill in the length afterwards
-                    let len_after = buffer.len();
-                    let diff = (len_after - len_before)
-                        .try_into()
-                        .map_err(|e| crate::result::Error::SerializationError(Box::new(e)))?;
-                    let bytes = i32::to_be_bytes(diff);
-                    for (b, t) in bytes.into_iter().zip(&mut buffer[len_before - 4..]) {
-                        *t = b;
-                    }
-                }
-            }
-            copy.write_all(&buffer).map_err(io_result_mapper)?;
-            buffer.clear();
+            writer.write_fields(V::COLUMN_COUNT, |idx, out| i.write_to_buffer(idx, out))?;
         }
-        // write the trailer
-        copy.write_i16::<NetworkEndian>(-1)
-            .map_err(io_result_mapper)?;
-        Ok(())
+        writer.finish()
     }
 
     fn options(&self) -> &CopyFromOptions {
@@ -14,7 +14,9 @@ pub(crate) use self::copy_from::{CopyFromExpression, InternalCopyFromQuery};
 #[cfg(feature = "postgres")]
 pub(crate) use self::copy_to::CopyToCommand;
 
-pub use self::copy_from::{CopyFromQuery, CopyHeader, ExecuteCopyFromDsl};
+pub use self::copy_from::{
+    CopyBinaryWriter, CopyFromQuery, CopyHeader, CopyRow, ExecuteCopyFromDsl,
+};
 pub use self::copy_to::CopyToQuery;
 
 const COPY_MAGIC_HEADER: [u8; 11] = [
@@ -3,15 +3,19 @@ use crate::query_builder::QueryBuilder;
 use crate::result::QueryResult;
 
 pub(crate) mod copy;
-mod distinct_on;
 mod limit_offset;
+pub(crate) mod notify;
 pub(crate) mod on_constraint;
 pub(crate) mod only;
 mod query_fragment_impls;
 pub(crate) mod tablesample;
-pub use self::copy::{CopyFormat, CopyFromQuery, CopyHeader, CopyTarget, CopyToQuery};
-pub use self::distinct_on::DistinctOnClause;
-pub use self::distinct_on::OrderDecorator;
+pub(crate) mod was_inserted;
+pub use self::copy::{
+    CopyBinaryWriter, CopyFormat, CopyFromQuery, CopyHeader, CopyRow, CopyTarget, CopyToQuery,
+};
+pub use self::notify::NotifyQuery;
+pub use crate::query_builder::distinct_on::DistinctOnClause;
+pub use crate::query_builder::distinct_on::OrderDecorator;
 
 /// The PostgreSQL query builder
 #[allow(missing_debug_implementations)]
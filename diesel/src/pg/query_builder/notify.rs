@@ -0,0 +1,85 @@
+use crate::expression::AsExpression;
+use crate::pg::Pg;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::query_dsl::RunQueryDslSupport;
+use crate::result::QueryResult;
+use crate::sql_types::Text;
+
+/// The query returned by [`notify`]
+///
+/// Execute it with [`RunQueryDsl::execute`](crate::RunQueryDsl::execute).
+#[derive(Debug, Clone, Copy, QueryId)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct NotifyQuery<C, P> {
+    channel: C,
+    payload: P,
+}
+
+impl<C, P> QueryFragment<Pg> for NotifyQuery<C, P>
+where
+    C: QueryFragment<Pg>,
+    P: QueryFragment<Pg>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        out.push_sql("SELECT pg_notify(");
+        self.channel.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.payload.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+impl<C, P> RunQueryDslSupport for NotifyQuery<C, P> {}
+
+/// Sends a notification with the given payload to all connections
+/// listening on `channel`
+///
+/// This uses the `pg_notify` function instead of a `NOTIFY` statement,
+/// so both channel and payload are sent as bind parameters. As for
+/// `NOTIFY`, the notification is only delivered once the current
+/// transaction commits. Notifications are received with
+/// [`PgConnection::listen`] and [`PgConnection::notifications`].
+///
+/// See the [PostgreSQL documentation](https://www.postgresql.org/docs/current/sql-notify.html)
+/// for more details
+///
+/// [`PgConnection::listen`]: crate::pg::PgConnection::listen()
+/// [`PgConnection::notifications`]: crate::pg::PgConnection::notifications()
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// # use std::time::Duration;
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     let listener = &mut connection_no_transaction();
+/// #     let connection = &mut connection_no_transaction();
+/// listener.listen("user_changes")?;
+///
+/// diesel::dsl::notify("user_changes", "42").execute(connection)?;
+///
+/// let notification = listener
+///     .notifications(Duration::from_secs(5))
+///     .next()
+///     .unwrap()?;
+/// assert_eq!("user_changes", notification.channel);
+/// assert_eq!("42", notification.payload);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn notify<C, P>(channel: C, payload: P) -> NotifyQuery<C::Expression, P::Expression>
+where
+    C: AsExpression<Text>,
+    P: AsExpression<Text>,
+{
+    NotifyQuery {
+        channel: channel.as_expression(),
+        payload: payload.as_expression(),
+    }
+}
@@ -7,6 +7,12 @@ use crate::result::QueryResult;
 /// CONFLICT ON CONSTRAINT`. Note that `constraint_name` must be the name of a
 /// unique constraint, not the name of an index.
 ///
+/// A constraint target cannot be combined with
+/// [`filter_target`](crate::upsert::DecoratableTarget::filter_target()),
+/// as PostgreSQL only accepts a `WHERE` predicate for a list of columns.
+/// Use `.on_conflict(column).filter_target(predicate)` to target a partial
+/// unique index instead.
+///
 /// # Example
 ///
 /// ```rust
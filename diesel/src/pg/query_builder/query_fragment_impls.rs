@@ -2,6 +2,7 @@ use crate::expression::array_comparison::{In, InExpression, Many, NotIn};
 use crate::pg::Pg;
 use crate::pg::backend::PgStyleArrayComparison;
 use crate::pg::types::sql_types::Array;
+use crate::query_builder::distinct_on::DistinctOnClause;
 use crate::query_builder::locking_clause::{
     ForKeyShare, ForNoKeyUpdate, ForShare, ForUpdate, NoModifier, NoWait, SkipLocked,
 };
@@ -12,6 +13,18 @@ use crate::result::QueryResult;
 use crate::serialize::ToSql;
 use crate::sql_types::{HasSqlType, SingleValue};
 
+impl<T> QueryFragment<Pg> for DistinctOnClause<T>
+where
+    T: QueryFragment<Pg>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        out.push_sql("DISTINCT ON (");
+        self.0.walk_ast(out.reborrow())?;
+        out.push_sql(") ");
+        Ok(())
+    }
+}
+
 impl QueryFragment<Pg> for ForUpdate {
     fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
         out.push_sql(" FOR UPDATE");
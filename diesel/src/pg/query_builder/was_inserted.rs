@@ -0,0 +1,61 @@
+use core::marker::PhantomData;
+
+use crate::expression::{
+    AppearsOnTable, Expression, SelectableExpression, ValidGrouping, is_aggregate,
+};
+use crate::pg::Pg;
+use crate::query_builder::nodes::StaticQueryFragment;
+use crate::query_builder::returning::{InsertStmtWithOnConflictDoUpdate, ReturningQuerySource};
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::result::QueryResult;
+use crate::sql_types::Bool;
+
+/// Represents `"table".xmax = 0`, which is `true` for rows inserted by
+/// an `INSERT … ON CONFLICT DO UPDATE` statement and `false` for
+/// rows updated by it.
+///
+/// See [`diesel::upsert::was_inserted`](crate::upsert::was_inserted()) for details.
+#[derive(Debug, Clone, Copy, Default, QueryId)]
+pub struct WasInserted<T> {
+    table: PhantomData<T>,
+}
+
+impl<T> WasInserted<T> {
+    pub(crate) fn new(_table: T) -> Self {
+        WasInserted { table: PhantomData }
+    }
+}
+
+impl<T> Expression for WasInserted<T> {
+    type SqlType = Bool;
+}
+
+impl<T> ValidGrouping<()> for WasInserted<T> {
+    type IsAggregate = is_aggregate::No;
+}
+
+// only valid in the `RETURNING` clause of an `ON CONFLICT DO UPDATE` statement
+impl<T> AppearsOnTable<ReturningQuerySource<InsertStmtWithOnConflictDoUpdate, T>>
+    for WasInserted<T>
+{
+}
+
+impl<T> SelectableExpression<ReturningQuerySource<InsertStmtWithOnConflictDoUpdate, T>>
+    for WasInserted<T>
+{
+}
+
+impl<T> QueryFragment<Pg> for WasInserted<T>
+where
+    T: StaticQueryFragment,
+    T::Component: QueryFragment<Pg>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
+        out.push_sql("(");
+        T::STATIC_COMPONENT.walk_ast(out.reborrow())?;
+        // `xmax` is the system column containing the id of the deleting
+        // transaction, it is only set for rows updated by an upsert
+        out.push_sql(".xmax = 0)");
+        Ok(())
+    }
+}
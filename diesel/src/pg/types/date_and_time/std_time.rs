@@ -1,6 +1,7 @@
 use core::time::Duration;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use super::PgInterval;
 use crate::deserialize::{self, FromSql};
 use crate::pg::{Pg, PgValue};
 use crate::serialize::{self, Output, ToSql};
@@ -44,6 +45,47 @@ impl FromSql<sql_types::Timestamp, Pg> for SystemTime {
 
 const USEC_PER_SEC: u64 = 1_000_000;
 const NANO_PER_USEC: u32 = 1_000;
+const SECONDS_PER_DAY: u64 = 60 * 60 * 24;
+// Months have no fixed length, so reading an interval is lossy:
+// `1 month` and `30 days` are both read as 30 days
+const DAYS_PER_MONTH: i64 = 30;
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<sql_types::Interval, Pg> for Duration {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        // Whole days are stored separately, so that adding the interval
+        // to a timestamp behaves like adding a number of days.
+        // Months are never used, as their length is not fixed.
+        let days = i32::try_from(self.as_secs() / SECONDS_PER_DAY)?;
+        let remainder = Duration::new(self.as_secs() % SECONDS_PER_DAY, self.subsec_nanos());
+        let interval = PgInterval {
+            microseconds: duration_to_usecs(remainder).try_into()?,
+            days,
+            months: 0,
+        };
+        ToSql::<sql_types::Interval, Pg>::to_sql(&interval, &mut out.reborrow())
+    }
+}
+
+/// Reads an interval as [`Duration`]
+///
+/// A month is assumed to have 30 days and a day to have 24 hours,
+/// so `1 month` and `30 days` are read as the same duration.
+/// Negative intervals fail to deserialize.
+#[cfg(feature = "postgres_backend")]
+impl FromSql<sql_types::Interval, Pg> for Duration {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let interval: PgInterval = FromSql::<sql_types::Interval, Pg>::from_sql(bytes)?;
+        // Like `justify_interval` we assume that a month has 30 days
+        // and a day has 24 hours.
+        let days = i64::from(interval.months) * DAYS_PER_MONTH + i64::from(interval.days);
+        let microseconds = i128::from(days) * i128::from(SECONDS_PER_DAY * USEC_PER_SEC)
+            + i128::from(interval.microseconds);
+        let microseconds = u64::try_from(microseconds)
+            .map_err(|_| "Negative intervals cannot be represented as `std::time::Duration`")?;
+        Ok(usecs_to_duration(microseconds))
+    }
+}
 
 fn usecs_to_duration(usecs_passed: u64) -> Duration {
     let seconds = usecs_passed / USEC_PER_SEC;
@@ -67,7 +109,7 @@ mod tests {
     use crate::dsl::{now, sql};
     use crate::prelude::*;
     use crate::select;
-    use crate::sql_types::Timestamp;
+    use crate::sql_types::{Interval, Timestamp};
     use crate::test_helpers::pg_connection;
 
     #[diesel_test_helper::test]
@@ -96,4 +138,39 @@ mod tests {
         let query = select(now.at_time_zone("utc").gt(time));
         assert!(query.get_result::<bool>(connection).unwrap());
     }
+
+    #[diesel_test_helper::test]
+    fn durations_encode_correctly() {
+        let connection = &mut pg_connection();
+        let duration = Duration::from_secs(60 * 60 * 24 * 60 + 60) + Duration::from_micros(123456);
+        for literal in [
+            "60 days 1 minute 123456 microseconds",
+            "2 months 1 minute 123456 microseconds",
+            "5184060 seconds 123456 microseconds",
+        ] {
+            let query = select(sql::<Interval>(&format!("'{literal}'::interval")).eq(duration));
+            assert!(query.get_result::<bool>(connection).unwrap());
+        }
+    }
+
+    #[diesel_test_helper::test]
+    fn durations_decode_correctly() {
+        let connection = &mut pg_connection();
+        let duration = Duration::from_secs(60 * 60 * 24 * 60 + 60) + Duration::from_micros(123456);
+        for literal in [
+            "60 days 1 minute 123456 microseconds",
+            "2 months 1 minute 123456 microseconds",
+            "59 days 24 hours 60.123456 seconds",
+        ] {
+            let query = select(sql::<Interval>(&format!("'{literal}'::interval")));
+            assert_eq!(Ok(duration), query.get_result::<Duration>(connection));
+        }
+    }
+
+    #[diesel_test_helper::test]
+    fn negative_durations_fail_to_decode() {
+        let connection = &mut pg_connection();
+        let query = select(sql::<Interval>("'-1 second'::interval"));
+        assert!(query.get_result::<Duration>(connection).is_err());
+    }
 }
@@ -8,11 +8,11 @@ use self::time::{
     macros::{date, datetime},
 };
 
-use super::{PgDate, PgTime, PgTimestamp};
+use super::{PgDate, PgInterval, PgTime, PgTimestamp};
 use crate::deserialize::{self, Defaultable, FromSql};
 use crate::pg::{Pg, PgValue};
 use crate::serialize::{self, Output, ToSql};
-use crate::sql_types::{Date, Time, Timestamp, Timestamptz};
+use crate::sql_types::{Date, Interval, Time, Timestamp, Timestamptz};
 
 // Postgres timestamps start from January 1st 2000.
 const PG_EPOCH: PrimitiveDateTime = datetime!(2000-1-1 0:00:00);
@@ -140,6 +140,41 @@ impl Defaultable for NaiveDate {
     }
 }
 
+// Months have no fixed length, so reading an interval is lossy:
+// `1 month` and `30 days` are both read as 30 days
+const DAYS_PER_MONTH: i64 = 30;
+
+#[cfg(all(feature = "time", feature = "postgres_backend"))]
+impl ToSql<Interval, Pg> for Duration {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        // Like for `chrono::Duration` the interval only consists of
+        // days and microseconds, as the length of a month is not fixed.
+        let days = self.whole_days();
+        let microseconds = (*self - Duration::days(days)).whole_microseconds();
+        let interval = PgInterval {
+            microseconds: microseconds.try_into()?,
+            days: days.try_into()?,
+            months: 0,
+        };
+        ToSql::<Interval, Pg>::to_sql(&interval, &mut out.reborrow())
+    }
+}
+
+/// Reads an interval as [`Duration`]
+///
+/// A month is assumed to have 30 days and a day to have 24 hours,
+/// so `1 month` and `30 days` are read as the same duration.
+#[cfg(all(feature = "time", feature = "postgres_backend"))]
+impl FromSql<Interval, Pg> for Duration {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let interval: PgInterval = FromSql::<Interval, Pg>::from_sql(bytes)?;
+        // Like `justify_interval` we assume that a month has 30 days
+        // and a day has 24 hours.
+        let days = i64::from(interval.months) * DAYS_PER_MONTH + i64::from(interval.days);
+        Ok(Duration::days(days) + Duration::microseconds(interval.microseconds))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     extern crate dotenvy;
@@ -147,7 +182,7 @@ mod tests {
     use crate::dsl::{now, sql};
     use crate::prelude::*;
     use crate::select;
-    use crate::sql_types::{Date, Time, Timestamp, Timestamptz};
+    use crate::sql_types::{Date, Interval, Time, Timestamp, Timestamptz};
     use crate::test_helpers::connection;
 
     use time::{
@@ -323,4 +358,27 @@ mod tests {
             query.get_result::<NaiveDate>(connection)
         );
     }
+
+    #[diesel_test_helper::test]
+    fn durations_round_trip() {
+        let connection = &mut connection();
+        let duration = Duration::days(60) + Duration::minutes(1) + Duration::microseconds(123456);
+        for literal in [
+            "60 days 1 minute 123456 microseconds",
+            "2 months 1 minute 123456 microseconds",
+            "59 days 24 hours 60.123456 seconds",
+        ] {
+            let query = select(sql::<Interval>(&format!("'{literal}'::interval")).eq(duration));
+            assert!(query.get_result::<bool>(connection).unwrap());
+
+            let query = select(sql::<Interval>(&format!("'{literal}'::interval")));
+            assert_eq!(Ok(duration), query.get_result::<Duration>(connection));
+        }
+
+        let query = select(sql::<Interval>("'-1 day -1 second'::interval"));
+        assert_eq!(
+            Ok(-Duration::days(1) - Duration::seconds(1)),
+            query.get_result::<Duration>(connection)
+        );
+    }
 }
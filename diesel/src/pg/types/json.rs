@@ -4,6 +4,8 @@ extern crate serde_json;
 
 use std::io::prelude::*;
 
+#[cfg(feature = "serde")]
+use crate::data_types::Json;
 use crate::deserialize::{self, FromSql};
 use crate::pg::{Pg, PgValue};
 use crate::serialize::{self, IsNull, Output, ToSql};
@@ -52,6 +54,63 @@ impl ToSql<sql_types::Jsonb, Pg> for serde_json::Value {
     }
 }
 
+#[cfg(all(feature = "postgres_backend", feature = "serde"))]
+impl<T> FromSql<sql_types::Json, Pg> for Json<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        serde_json::from_slice(value.as_bytes())
+            .map(Json)
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "serde"))]
+impl<T> ToSql<sql_types::Json, Pg> for Json<T>
+where
+    T: serde::Serialize + core::fmt::Debug,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        serde_json::to_writer(out, &self.0)
+            .map(|_| IsNull::No)
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "serde"))]
+impl<T> FromSql<sql_types::Jsonb, Pg> for Json<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let bytes = value.as_bytes();
+        let first_byte = bytes
+            .first()
+            .ok_or("Received an empty response from the server")?;
+
+        if *first_byte != 1 {
+            return Err("Unsupported JSONB encoding version".into());
+        }
+        serde_json::from_slice(&bytes[1..])
+            .map(Json)
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "serde"))]
+impl<T> ToSql<sql_types::Jsonb, Pg> for Json<T>
+where
+    T: serde::Serialize + core::fmt::Debug,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(&[1])?;
+        serde_json::to_writer(out, &self.0)
+            .map(|_| IsNull::No)
+            .map_err(Into::into)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::deserialize::FromSql;
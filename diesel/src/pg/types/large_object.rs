@@ -0,0 +1,30 @@
+use crate::deserialize::{self, FromSql, FromSqlRow};
+use crate::expression::AsExpression;
+use crate::pg::{Pg, PgValue};
+use crate::serialize::{self, Output, ToSql};
+use crate::sql_types::Oid;
+
+/// The id of a PostgreSQL large object
+///
+/// Large objects are referenced by their oid, which is usually stored in
+/// a column of the [`Oid`] type. Values of this type can be loaded from and
+/// compared against such columns and are accepted by
+/// [`PgConnection::open_large_object`](crate::pg::PgConnection::open_large_object)
+/// to stream the content of the referenced large object.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, AsExpression, FromSqlRow)]
+#[diesel(sql_type = Oid)]
+pub struct PgLargeObjectId(pub u32);
+
+#[cfg(feature = "postgres_backend")]
+impl FromSql<Oid, Pg> for PgLargeObjectId {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        FromSql::<Oid, Pg>::from_sql(bytes).map(PgLargeObjectId)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl ToSql<Oid, Pg> for PgLargeObjectId {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<Oid, Pg>::to_sql(&self.0, out)
+    }
+}
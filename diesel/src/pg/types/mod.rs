@@ -13,6 +13,7 @@ mod ipnet_address;
 #[cfg(feature = "serde_json")]
 mod json;
 mod json_function_enum;
+pub(in crate::pg) mod large_object;
 mod mac_addr;
 mod mac_addr_8;
 #[doc(hidden)]
@@ -22,11 +23,15 @@ mod multirange;
 mod network_address;
 mod numeric;
 pub(in crate::pg) mod pg_lsn;
+#[cfg(feature = "postgis")]
+mod postgis;
 mod primitives;
 mod ranges;
 mod record;
 #[cfg(feature = "uuid")]
 mod uuid;
+#[cfg(feature = "pgvector")]
+mod vector;
 
 /// PostgreSQL specific SQL types
 ///
@@ -116,6 +121,12 @@ pub mod sql_types {
     /// This wraps another type to represent a SQL array of that type.
     /// Multidimensional arrays are not supported.
     ///
+    /// The element type can be a user defined enum, domain or composite type
+    /// declared with `#[diesel(postgres_type(name = "..."))]`. The array type
+    /// OID is resolved together with the element type OID on first use and
+    /// cached per connection. As with any bind parameter, the element type
+    /// needs to implement [`QueryId`] to use such arrays in a filter.
+    ///
     /// ### [`ToSql`] impls
     ///
     /// - [`Vec<T>`][Vec] for any `T` which implements `ToSql<ST>`
@@ -695,6 +706,88 @@ pub mod sql_types {
     #[diesel(postgres_type(oid = 3220, array_oid = 3221))]
     pub struct PgLsn;
 
+    /// The `vector` SQL type of the [pgvector] extension. This is a PostgreSQL
+    /// specific type, which requires the `pgvector` feature.
+    ///
+    /// The extension needs to be installed in the database by running
+    /// `CREATE EXTENSION vector`. Use the methods of
+    /// [`PgVectorExpressionMethods`] to calculate the distance between
+    /// vectors, for example to order by the nearest neighbors of a vector.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`Vec<f32>`]
+    /// - [`[f32]`][slice]
+    /// - [`[f32; N]`][array]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`Vec<f32>`]
+    /// - [`[f32; N]`][array]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [`PgVectorExpressionMethods`]: crate::expression_methods::PgVectorExpressionMethods
+    /// [pgvector]: https://github.com/pgvector/pgvector
+    #[cfg(all(feature = "postgres_backend", feature = "pgvector"))]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(name = "vector"))]
+    pub struct Vector;
+
+    /// The `geometry` SQL type of the [PostGIS] extension. This is a PostgreSQL
+    /// specific type, which requires the `postgis` feature.
+    ///
+    /// The extension needs to be installed in the database by running
+    /// `CREATE EXTENSION postgis`. Values are transferred in the
+    /// (extended) well-known binary format, which can be produced and consumed
+    /// by crates like [`geozero`] or [`wkb`]. See
+    /// [`st_contains`](crate::dsl::st_contains()),
+    /// [`st_distance`](crate::dsl::st_distance()) and
+    /// [`st_dwithin`](crate::dsl::st_dwithin()) for spatial functions.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`Vec<u8>`]
+    /// - [`&[u8]`][slice]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`Vec<u8>`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [PostGIS]: https://postgis.net/
+    /// [`geozero`]: https://docs.rs/geozero
+    /// [`wkb`]: https://docs.rs/wkb
+    #[cfg(all(feature = "postgres_backend", feature = "postgis"))]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(name = "geometry"))]
+    pub struct Geometry;
+
+    /// The `geography` SQL type of the [PostGIS] extension. This is a PostgreSQL
+    /// specific type, which requires the `postgis` feature.
+    ///
+    /// In contrast to [`Geometry`] coordinates are interpreted as longitude and
+    /// latitude on a spheroid, so distances are measured in meters.
+    /// Values are transferred in the (extended) well-known binary format.
+    ///
+    /// ### [`ToSql`] impls
+    ///
+    /// - [`Vec<u8>`]
+    /// - [`&[u8]`][slice]
+    ///
+    /// ### [`FromSql`] impls
+    ///
+    /// - [`Vec<u8>`]
+    ///
+    /// [`ToSql`]: crate::serialize::ToSql
+    /// [`FromSql`]: crate::deserialize::FromSql
+    /// [PostGIS]: https://postgis.net/
+    #[cfg(all(feature = "postgres_backend", feature = "postgis"))]
+    #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
+    #[diesel(postgres_type(name = "geography"))]
+    pub struct Geography;
+
     #[doc(inline)]
     pub use crate::sql_types::Jsonb;
 }
@@ -707,11 +800,15 @@ mod ops {
     impl Add for Timestamptz {
         type Rhs = Interval;
         type Output = Timestamptz;
+
+        const KIND: OperationKind = OperationKind::DateTimeInterval;
     }
 
     impl Sub for Timestamptz {
         type Rhs = Interval;
         type Output = Timestamptz;
+
+        const KIND: OperationKind = OperationKind::DateTimeInterval;
     }
 
     impl Add for Cidr {
@@ -389,3 +389,253 @@ mod bigdecimal {
         }
     }
 }
+
+mod numeric_string {
+    use alloc::boxed::Box;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use core::error::Error;
+    use core::fmt::Write;
+
+    use crate::data_types::NumericString;
+    use crate::deserialize::{self, FromSql};
+    use crate::pg::data_types::PgNumeric;
+    use crate::pg::{Pg, PgValue};
+    use crate::serialize::{self, Output, ToSql};
+    use crate::sql_types::Numeric;
+
+    impl<'a> TryFrom<&'a PgNumeric> for NumericString {
+        type Error = Box<dyn Error + Send + Sync>;
+
+        fn try_from(numeric: &'a PgNumeric) -> Result<Self, Self::Error> {
+            let (sign, weight, scale, digits) = match *numeric {
+                PgNumeric::Positive {
+                    weight,
+                    scale,
+                    ref digits,
+                } => ("", weight, scale, digits),
+                PgNumeric::Negative {
+                    weight,
+                    scale,
+                    ref digits,
+                } => ("-", weight, scale, digits),
+                PgNumeric::NaN => return Ok(NumericString::new_unchecked("NaN".into())),
+            };
+            if digits.iter().any(|d| !(0..10_000).contains(d)) {
+                return Err("Received an invalid base 10000 digit for a numeric value".into());
+            }
+            // Digit at position `idx` has the factor 10_000^(weight - idx)
+            let digit_at = |idx: i32| {
+                usize::try_from(idx)
+                    .ok()
+                    .and_then(|idx| digits.get(idx))
+                    .copied()
+                    .unwrap_or(0)
+            };
+
+            let mut integer = String::new();
+            for idx in 0..=i32::from(weight) {
+                write!(integer, "{:04}", digit_at(idx))?;
+            }
+            let integer = integer.trim_start_matches('0');
+            let integer = if integer.is_empty() { "0" } else { integer };
+
+            let mut fraction = String::new();
+            let mut idx = i32::from(weight) + 1;
+            while fraction.len() < usize::from(scale) {
+                write!(fraction, "{:04}", digit_at(idx))?;
+                idx += 1;
+            }
+            fraction.truncate(usize::from(scale));
+
+            let mut out = String::with_capacity(sign.len() + integer.len() + fraction.len() + 1);
+            out.push_str(sign);
+            out.push_str(integer);
+            if !fraction.is_empty() {
+                out.push('.');
+                out.push_str(&fraction);
+            }
+            Ok(NumericString::new_unchecked(out))
+        }
+    }
+
+    impl TryFrom<PgNumeric> for NumericString {
+        type Error = Box<dyn Error + Send + Sync>;
+
+        fn try_from(numeric: PgNumeric) -> Result<Self, Self::Error> {
+            (&numeric).try_into()
+        }
+    }
+
+    impl<'a> TryFrom<&'a NumericString> for PgNumeric {
+        type Error = Box<dyn Error + Send + Sync>;
+
+        fn try_from(value: &'a NumericString) -> Result<Self, Self::Error> {
+            if value.is_nan() {
+                return Ok(PgNumeric::NaN);
+            }
+            let value = value.as_str();
+            let (negative, unsigned) = match value.as_bytes().first() {
+                Some(b'-') => (true, &value[1..]),
+                Some(b'+') => (false, &value[1..]),
+                _ => (false, value),
+            };
+            let (integer, fraction) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+            let integer = integer.trim_start_matches('0');
+            let scale =
+                u16::try_from(fraction.len()).map_err(|_| "Scale is expected to be 16bit large")?;
+
+            // pad both parts so that they lie on a base 10000 digit boundary
+            let integer_padding = (4 - integer.len() % 4) % 4;
+            let fraction_padding = (4 - fraction.len() % 4) % 4;
+            let decimal_digits = core::iter::repeat_n(&b'0', integer_padding)
+                .chain(integer.as_bytes())
+                .chain(fraction.as_bytes())
+                .chain(core::iter::repeat_n(&b'0', fraction_padding))
+                .map(|b| i16::from(b - b'0'))
+                .collect::<Vec<_>>();
+            let mut digits = decimal_digits
+                .chunks(4)
+                .map(|c| c.iter().fold(0, |acc, d| acc * 10 + d))
+                .collect::<Vec<i16>>();
+
+            let integer_digits = (integer.len() + integer_padding) / 4;
+            let leading_zeros = digits.iter().take_while(|d| **d == 0).count();
+            let mut weight = i16::try_from(integer_digits)
+                .map_err(|_| "Max digit number is expected to fit into 16 bit")?
+                - i16::try_from(leading_zeros)
+                    .map_err(|_| "Max digit number is expected to fit into 16 bit")?
+                - 1;
+            digits.drain(..leading_zeros);
+            let trailing_zeros = digits.iter().rev().take_while(|d| **d == 0).count();
+            digits.truncate(digits.len() - trailing_zeros);
+            if digits.is_empty() {
+                weight = 0;
+            }
+
+            Ok(if negative && !digits.is_empty() {
+                PgNumeric::Negative {
+                    weight,
+                    scale,
+                    digits,
+                }
+            } else {
+                PgNumeric::Positive {
+                    weight,
+                    scale,
+                    digits,
+                }
+            })
+        }
+    }
+
+    impl TryFrom<NumericString> for PgNumeric {
+        type Error = Box<dyn Error + Send + Sync>;
+
+        fn try_from(value: NumericString) -> Result<Self, Self::Error> {
+            (&value).try_into()
+        }
+    }
+
+    #[cfg(feature = "postgres_backend")]
+    impl ToSql<Numeric, Pg> for NumericString {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+            let numeric = PgNumeric::try_from(self)?;
+            ToSql::<Numeric, Pg>::to_sql(&numeric, &mut out.reborrow())
+        }
+    }
+
+    #[cfg(feature = "postgres_backend")]
+    impl FromSql<Numeric, Pg> for NumericString {
+        fn from_sql(numeric: PgValue<'_>) -> deserialize::Result<Self> {
+            PgNumeric::from_sql(numeric)?.try_into()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[diesel_test_helper::test]
+        fn numeric_string_to_pg_numeric_converts_digits_to_base_10000() {
+            let cases = [
+                (
+                    "1.0",
+                    PgNumeric::Positive {
+                        weight: 0,
+                        scale: 1,
+                        digits: vec![1],
+                    },
+                ),
+                (
+                    "-31.0",
+                    PgNumeric::Negative {
+                        weight: 0,
+                        scale: 1,
+                        digits: vec![31],
+                    },
+                ),
+                (
+                    "10000",
+                    PgNumeric::Positive {
+                        weight: 1,
+                        scale: 0,
+                        digits: vec![1],
+                    },
+                ),
+                (
+                    "0.00001234",
+                    PgNumeric::Positive {
+                        weight: -2,
+                        scale: 8,
+                        digits: vec![1234],
+                    },
+                ),
+                (
+                    "120001.00001234",
+                    PgNumeric::Positive {
+                        weight: 1,
+                        scale: 8,
+                        digits: vec![12, 1, 0, 1234],
+                    },
+                ),
+                (
+                    "-0.00",
+                    PgNumeric::Positive {
+                        weight: 0,
+                        scale: 2,
+                        digits: vec![],
+                    },
+                ),
+                ("NaN", PgNumeric::NaN),
+            ];
+            for (value, expected) in cases {
+                let value = value.parse::<NumericString>().unwrap();
+                assert_eq!(expected, PgNumeric::try_from(&value).unwrap(), "{value}");
+            }
+        }
+
+        #[diesel_test_helper::test]
+        fn pg_numeric_to_numeric_string_roundtrip() {
+            let values = [
+                "0",
+                "0.1",
+                "1.0",
+                "-141.0",
+                "4.2000000",
+                "18446744073709551616",
+                "10000",
+                "100000000",
+                "10000.100001",
+                "0.00001234",
+                "-120001.00001234",
+                "NaN",
+            ];
+            for value in values {
+                let numeric = value.parse::<NumericString>().unwrap();
+                let pg_numeric = PgNumeric::try_from(&numeric).unwrap();
+                assert_eq!(numeric, NumericString::try_from(pg_numeric).unwrap());
+            }
+        }
+    }
+}
@@ -0,0 +1,103 @@
+//! Support for the `geometry` and `geography` types of the PostGIS extension
+
+use std::io::Write;
+
+use crate::deserialize::{self, FromSql};
+use crate::pg::{Pg, PgValue};
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types::{Geography, Geometry};
+
+#[allow(dead_code)]
+mod foreign_derives {
+    use super::*;
+    use crate::expression::AsExpression;
+
+    #[derive(AsExpression)]
+    #[diesel(foreign_derive)]
+    #[diesel(sql_type = Geometry)]
+    #[diesel(sql_type = Geography)]
+    struct VecProxy(Vec<u8>);
+
+    #[derive(AsExpression)]
+    #[diesel(foreign_derive, not_sized)]
+    #[diesel(sql_type = Geometry)]
+    #[diesel(sql_type = Geography)]
+    struct SliceProxy([u8]);
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "postgis"))]
+impl FromSql<Geometry, Pg> for Vec<u8> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        Ok(value.as_bytes().to_vec())
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "postgis"))]
+impl FromSql<Geography, Pg> for Vec<u8> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        FromSql::<Geometry, Pg>::from_sql(value)
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "postgis"))]
+impl ToSql<Geometry, Pg> for [u8] {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        out.write_all(self).map(|_| IsNull::No).map_err(Into::into)
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "postgis"))]
+impl ToSql<Geography, Pg> for [u8] {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<Geometry, Pg>::to_sql(self, out)
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "postgis"))]
+impl ToSql<Geometry, Pg> for Vec<u8> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<Geometry, Pg>::to_sql(self.as_slice(), out)
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "postgis"))]
+impl ToSql<Geography, Pg> for Vec<u8> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<Geometry, Pg>::to_sql(self.as_slice(), out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_builder::bind_collector::ByteWrapper;
+
+    // `POINT(1 2)` encoded as little endian WKB
+    const POINT_WKB: [u8; 21] = [
+        0x01, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xf0, 0x3f, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x40,
+    ];
+
+    #[diesel_test_helper::test]
+    fn geometry_to_sql() {
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        ToSql::<Geometry, Pg>::to_sql(&POINT_WKB.to_vec(), &mut bytes).unwrap();
+        assert_eq!(buffer, POINT_WKB.to_vec());
+
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        ToSql::<Geography, Pg>::to_sql(&POINT_WKB[..], &mut bytes).unwrap();
+        assert_eq!(buffer, POINT_WKB.to_vec());
+    }
+
+    #[diesel_test_helper::test]
+    fn geometry_from_sql() {
+        let geometry: Vec<u8> =
+            FromSql::<Geometry, Pg>::from_sql(PgValue::for_test(&POINT_WKB)).unwrap();
+        assert_eq!(geometry, POINT_WKB.to_vec());
+        let geography: Vec<u8> =
+            FromSql::<Geography, Pg>::from_sql(PgValue::for_test(&POINT_WKB)).unwrap();
+        assert_eq!(geography, POINT_WKB.to_vec());
+    }
+}
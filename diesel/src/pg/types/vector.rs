@@ -0,0 +1,146 @@
+//! Support for the `vector` type of the pgvector extension
+
+use byteorder::{NetworkEndian, ReadBytesExt, WriteBytesExt};
+
+use crate::deserialize::{self, FromSql};
+use crate::pg::{Pg, PgValue};
+use crate::serialize::{self, IsNull, Output, ToSql};
+use crate::sql_types::Vector;
+
+#[allow(dead_code)]
+mod foreign_derives {
+    use super::*;
+    use crate::deserialize::FromSqlRow;
+    use crate::expression::AsExpression;
+
+    #[derive(AsExpression)]
+    #[diesel(foreign_derive)]
+    #[diesel(sql_type = Vector)]
+    struct VecProxy(Vec<f32>);
+
+    #[derive(AsExpression)]
+    #[diesel(foreign_derive, not_sized)]
+    #[diesel(sql_type = Vector)]
+    struct SliceProxy([f32]);
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[diesel(sql_type = Vector)]
+    struct ArrayProxy<const N: usize>([f32; N]);
+}
+
+/// Reads the header of a binary encoded vector and returns its dimension
+fn read_dimensions(bytes: &mut &[u8]) -> deserialize::Result<usize> {
+    let dimensions = bytes.read_u16::<NetworkEndian>()?;
+    let unused = bytes.read_u16::<NetworkEndian>()?;
+    if unused != 0 {
+        return Err("Invalid vector header: expected the unused field to be 0".into());
+    }
+    if bytes.len() != usize::from(dimensions) * 4 {
+        return Err("Invalid vector: the number of elements doesn't match its dimension".into());
+    }
+    Ok(usize::from(dimensions))
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "pgvector"))]
+impl FromSql<Vector, Pg> for Vec<f32> {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let dimensions = read_dimensions(&mut bytes)?;
+        let mut vector = Vec::with_capacity(dimensions);
+        for _ in 0..dimensions {
+            vector.push(bytes.read_f32::<NetworkEndian>()?);
+        }
+        Ok(vector)
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "pgvector"))]
+impl<const N: usize> FromSql<Vector, Pg> for [f32; N] {
+    fn from_sql(value: PgValue<'_>) -> deserialize::Result<Self> {
+        let mut bytes = value.as_bytes();
+        let dimensions = read_dimensions(&mut bytes)?;
+        if dimensions != N {
+            return Err(format!(
+                "Received a vector with {dimensions} dimensions, but expected {N} dimensions"
+            )
+            .into());
+        }
+        let mut vector = [0.0; N];
+        bytes.read_f32_into::<NetworkEndian>(&mut vector)?;
+        Ok(vector)
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "pgvector"))]
+impl ToSql<Vector, Pg> for [f32] {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        let dimensions = u16::try_from(self.len())
+            .map_err(|_| "A vector must not have more than 65535 dimensions")?;
+        out.write_u16::<NetworkEndian>(dimensions)?;
+        // unused, must be 0
+        out.write_u16::<NetworkEndian>(0)?;
+        for element in self {
+            out.write_f32::<NetworkEndian>(*element)?;
+        }
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "pgvector"))]
+impl ToSql<Vector, Pg> for Vec<f32> {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<Vector, Pg>::to_sql(self.as_slice(), out)
+    }
+}
+
+#[cfg(all(feature = "postgres_backend", feature = "pgvector"))]
+impl<const N: usize> ToSql<Vector, Pg> for [f32; N] {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<Vector, Pg>::to_sql(self.as_slice(), out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::query_builder::bind_collector::ByteWrapper;
+
+    #[diesel_test_helper::test]
+    fn vector_to_sql() {
+        let mut buffer = Vec::new();
+        let mut bytes = Output::test(ByteWrapper(&mut buffer));
+        ToSql::<Vector, Pg>::to_sql(&vec![1.0_f32, -2.5], &mut bytes).unwrap();
+        assert_eq!(
+            buffer,
+            [0, 2, 0, 0, 0x3f, 0x80, 0, 0, 0xc0, 0x20, 0, 0].to_vec()
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn vector_from_sql() {
+        let input = [0, 2, 0, 0, 0x3f, 0x80, 0, 0, 0xc0, 0x20, 0, 0];
+        let vector: Vec<f32> = FromSql::<Vector, Pg>::from_sql(PgValue::for_test(&input)).unwrap();
+        assert_eq!(vector, vec![1.0, -2.5]);
+        let vector: [f32; 2] = FromSql::<Vector, Pg>::from_sql(PgValue::for_test(&input)).unwrap();
+        assert_eq!(vector, [1.0, -2.5]);
+    }
+
+    #[diesel_test_helper::test]
+    fn vector_with_wrong_dimensions_from_sql() {
+        let input = [0, 2, 0, 0, 0x3f, 0x80, 0, 0, 0xc0, 0x20, 0, 0];
+        let vector: deserialize::Result<[f32; 3]> =
+            FromSql::<Vector, Pg>::from_sql(PgValue::for_test(&input));
+        assert_eq!(
+            vector.unwrap_err().to_string(),
+            "Received a vector with 2 dimensions, but expected 3 dimensions"
+        );
+
+        let vector: deserialize::Result<Vec<f32>> =
+            FromSql::<Vector, Pg>::from_sql(PgValue::for_test(&input[..8]));
+        assert_eq!(
+            vector.unwrap_err().to_string(),
+            "Invalid vector: the number of elements doesn't match its dimension"
+        );
+    }
+}
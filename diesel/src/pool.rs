@@ -0,0 +1,968 @@
+//! A built-in connection pool with health checks
+//!
+//! Note: This module requires enabling the `pool` feature
+//!
+//! [`Pool`] keeps a set of established connections and hands them out
+//! to one caller at a time. Connections are checked with a `SELECT 1` query
+//! before they are handed out, connections left with an open transaction are
+//! closed instead of being reused and connections exceeding their configured
+//! lifetime or idle time are replaced by new ones. Both a blocking
+//! ([`Pool::get`]) and an async ([`Pool::get_async`]) checkout are provided.
+//! Metrics can be collected by registering a [`PoolEventHandler`].
+//!
+//! # Example
+//!
+//! ```rust
+//! # include!("doctest_setup.rs");
+//! use diesel::pool::Pool;
+//! use std::thread;
+//! use std::time::Duration;
+//!
+//! # fn main() {
+//! let pool = Pool::<DbConnection>::builder()
+//!     .max_size(4)
+//!     .max_lifetime(Some(Duration::from_secs(30 * 60)))
+//!     .build(&database_url_for_env())
+//!     .expect("Could not build connection pool");
+//!
+//! let threads = (0..4)
+//!     .map(|_| {
+//!         let pool = pool.clone();
+//!         thread::spawn(move || {
+//!             let conn = &mut pool.get().unwrap();
+//!             diesel::select(1.into_sql::<Integer>())
+//!                 .get_result::<i32>(conn)
+//!                 .unwrap()
+//!         })
+//!     })
+//!     .collect::<Vec<_>>();
+//!
+//! for thread in threads {
+//!     assert_eq!(1, thread.join().unwrap());
+//! }
+//! # }
+//! ```
+
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+use core::future::poll_fn;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+use core::task::{Poll, Waker};
+use core::time::Duration;
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::Instant;
+
+use crate::connection::middleware::{ConnectionWrapper, DelegatingTransactionManager};
+use crate::connection::{
+    ConnectionSealed, DatabaseWarning, Instrumentation, LoadConnection, SimpleConnection,
+    TransactionManager,
+};
+use crate::expression::QueryMetadata;
+use crate::prelude::*;
+use crate::query_builder::{Query, QueryFragment, QueryId};
+
+/// The error returned while checking out a connection from a [`Pool`]
+#[derive(Debug)]
+pub enum PoolError {
+    /// An error occurred establishing a new connection
+    ConnectionError(ConnectionError),
+
+    /// No connection became available before the timeout elapsed
+    Timeout,
+}
+
+impl core::fmt::Display for PoolError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            PoolError::ConnectionError(ref e) => e.fmt(f),
+            PoolError::Timeout => {
+                f.write_str("Timed out while waiting for a connection from the pool")
+            }
+        }
+    }
+}
+
+impl core::error::Error for PoolError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match *self {
+            PoolError::ConnectionError(ref e) => Some(e),
+            PoolError::Timeout => None,
+        }
+    }
+}
+
+impl From<ConnectionError> for PoolError {
+    fn from(other: ConnectionError) -> Self {
+        Self::ConnectionError(other)
+    }
+}
+
+/// The reason why a connection was closed by the pool
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ReleaseReason {
+    /// The connection was returned with an open or broken transaction,
+    /// or while the thread using it was panicking
+    Broken,
+    /// The connection failed the health check on checkout
+    HealthCheckFailed,
+    /// The connection exceeded the configured maximal lifetime
+    MaxLifetime,
+    /// The connection exceeded the configured idle timeout
+    IdleTimeout,
+}
+
+/// An event emitted by a [`Pool`]
+///
+/// See [`PoolEventHandler`] for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PoolEvent {
+    /// A new connection was established
+    Acquire,
+    /// A connection was closed
+    Release {
+        /// The reason for closing the connection
+        reason: ReleaseReason,
+    },
+    /// A connection was checked out
+    Checkout {
+        /// The time spent waiting for the connection
+        wait_time: Duration,
+    },
+    /// A connection was returned to the pool
+    Checkin {
+        /// The time the connection was checked out
+        usage_time: Duration,
+    },
+    /// A checkout timed out
+    Timeout {
+        /// The time spent waiting for a connection
+        wait_time: Duration,
+    },
+}
+
+/// A hook to collect metrics about a [`Pool`]
+///
+/// The handler is invoked for each [`PoolEvent`] emitted by the pool.
+/// It is implemented for closures accepting a [`PoolEvent`] as argument.
+/// Handlers are called from the thread triggering the event,
+/// so they should return quickly.
+pub trait PoolEventHandler: Send + Sync + 'static {
+    /// The function that is invoked for each event
+    fn on_pool_event(&self, event: PoolEvent);
+}
+
+impl<F> PoolEventHandler for F
+where
+    F: Fn(PoolEvent) + Send + Sync + 'static,
+{
+    fn on_pool_event(&self, event: PoolEvent) {
+        (self)(event)
+    }
+}
+
+/// A snapshot of the number of connections held by a [`Pool`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PoolState {
+    /// The number of connections currently owned by the pool,
+    /// including checked out connections
+    pub connections: usize,
+    /// The number of connections that are waiting to be checked out
+    pub idle_connections: usize,
+}
+
+/// A builder to configure a [`Pool`]
+///
+/// Use [`Pool::builder`] to construct a new instance.
+#[must_use = "A builder does nothing until `build` is called"]
+pub struct Builder<C> {
+    config: Config,
+    event_handler: Box<dyn PoolEventHandler>,
+    _marker: PhantomData<fn() -> C>,
+}
+
+impl<C> core::fmt::Debug for Builder<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Builder")
+            .field("config", &self.config)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Config {
+    max_size: usize,
+    initial_size: usize,
+    connection_timeout: Duration,
+    max_lifetime: Option<Duration>,
+    idle_timeout: Option<Duration>,
+    test_on_check_out: bool,
+}
+
+impl<C> Builder<C>
+where
+    C: Connection + 'static,
+{
+    fn new() -> Self {
+        Self {
+            config: Config {
+                max_size: 10,
+                initial_size: 0,
+                connection_timeout: Duration::from_secs(30),
+                max_lifetime: Some(Duration::from_secs(30 * 60)),
+                idle_timeout: Some(Duration::from_secs(10 * 60)),
+                test_on_check_out: true,
+            },
+            event_handler: Box::new(|_| {}),
+            _marker: PhantomData,
+        }
+    }
+
+    /// The maximal number of connections owned by the pool
+    ///
+    /// Defaults to 10.
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `max_size` is zero
+    pub fn max_size(mut self, max_size: usize) -> Self {
+        assert!(max_size > 0, "A pool needs at least one connection");
+        self.config.max_size = max_size;
+        self
+    }
+
+    /// The number of connections established while building the pool
+    ///
+    /// Further connections are established on demand.
+    /// Defaults to 0.
+    pub fn initial_size(mut self, initial_size: usize) -> Self {
+        self.config.initial_size = initial_size;
+        self
+    }
+
+    /// The time [`Pool::get`] waits for a connection before
+    /// returning [`PoolError::Timeout`]
+    ///
+    /// Defaults to 30 seconds.
+    pub fn connection_timeout(mut self, connection_timeout: Duration) -> Self {
+        self.config.connection_timeout = connection_timeout;
+        self
+    }
+
+    /// The maximal lifetime of a connection
+    ///
+    /// Connections older than this are closed when they are returned to
+    /// the pool or before they are checked out. `None` keeps connections
+    /// open until they break. Defaults to 30 minutes.
+    pub fn max_lifetime(mut self, max_lifetime: Option<Duration>) -> Self {
+        self.config.max_lifetime = max_lifetime;
+        self
+    }
+
+    /// The maximal time a connection stays unused in the pool
+    ///
+    /// Connections that have been idle for longer than this are closed
+    /// instead of being checked out. `None` disables the timeout.
+    /// Defaults to 10 minutes.
+    pub fn idle_timeout(mut self, idle_timeout: Option<Duration>) -> Self {
+        self.config.idle_timeout = idle_timeout;
+        self
+    }
+
+    /// Whether connections are checked with a `SELECT 1` query before
+    /// they are checked out
+    ///
+    /// Defaults to `true`.
+    pub fn test_on_check_out(mut self, test_on_check_out: bool) -> Self {
+        self.config.test_on_check_out = test_on_check_out;
+        self
+    }
+
+    /// Register a handler that is invoked for each [`PoolEvent`]
+    pub fn event_handler(mut self, event_handler: impl PoolEventHandler) -> Self {
+        self.event_handler = Box::new(event_handler);
+        self
+    }
+
+    /// Build the pool for the given database
+    ///
+    /// This establishes the number of connections configured via
+    /// [`Builder::initial_size`] and returns an error if any
+    /// of them cannot be established.
+    pub fn build(self, database_url: &str) -> Result<Pool<C>, PoolError> {
+        let initial_size = self.config.initial_size.min(self.config.max_size);
+        let shared = Arc::new(Shared {
+            database_url: database_url.to_owned(),
+            config: self.config,
+            event_handler: self.event_handler,
+            state: Mutex::new(State {
+                idle: VecDeque::with_capacity(initial_size),
+                connections: 0,
+                waiting: Vec::new(),
+            }),
+            connection_available: Condvar::new(),
+        });
+        for _ in 0..initial_size {
+            let conn = shared.establish()?;
+            let mut state = lock(&shared.state);
+            state.connections += 1;
+            state.idle.push_back(IdleConnection {
+                conn,
+                idle_since: Instant::now(),
+            });
+        }
+        Ok(Pool { shared })
+    }
+}
+
+/// A pool of connections
+///
+/// Cloning the pool is cheap and returns a handle to the same set
+/// of connections. See the [module documentation](self) for an example.
+pub struct Pool<C> {
+    shared: Arc<Shared<C>>,
+}
+
+impl<C> Clone for Pool<C> {
+    fn clone(&self) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+        }
+    }
+}
+
+impl<C> core::fmt::Debug for Pool<C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Pool")
+            .field("config", &self.shared.config)
+            .field("state", &self.shared.pool_state())
+            .finish_non_exhaustive()
+    }
+}
+
+struct Shared<C> {
+    database_url: String,
+    config: Config,
+    event_handler: Box<dyn PoolEventHandler>,
+    state: Mutex<State<C>>,
+    connection_available: Condvar,
+}
+
+struct State<C> {
+    idle: VecDeque<IdleConnection<C>>,
+    // includes checked out connections and connections
+    // that are currently being established
+    connections: usize,
+    waiting: Vec<Waker>,
+}
+
+struct IdleConnection<C> {
+    conn: Conn<C>,
+    idle_since: Instant,
+}
+
+struct Conn<C> {
+    conn: C,
+    created_at: Instant,
+}
+
+enum Slot<C> {
+    Idle(IdleConnection<C>),
+    New,
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    // the state is only modified by code that cannot panic,
+    // so the protected data is always consistent
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<C> Pool<C>
+where
+    C: Connection + 'static,
+{
+    /// Build a pool with the default configuration for the given database
+    pub fn new(database_url: &str) -> Result<Self, PoolError> {
+        Self::builder().build(database_url)
+    }
+
+    /// Construct a [`Builder`] to configure a new pool
+    pub fn builder() -> Builder<C> {
+        Builder::new()
+    }
+
+    /// Check out a connection, waiting at most for the configured
+    /// [connection timeout](Builder::connection_timeout)
+    ///
+    /// This blocks the current thread. Use [`Pool::get_async`]
+    /// to check out connections from async code.
+    pub fn get(&self) -> Result<PooledConnection<C>, PoolError> {
+        self.get_timeout(self.shared.config.connection_timeout)
+    }
+
+    /// Check out a connection, waiting at most for the given timeout
+    pub fn get_timeout(&self, timeout: Duration) -> Result<PooledConnection<C>, PoolError> {
+        let start = Instant::now();
+        loop {
+            let mut state = lock(&self.shared.state);
+            let slot = loop {
+                if let Some(slot) = self.shared.reserve(&mut state) {
+                    break slot;
+                }
+                let wait_time = start.elapsed();
+                if wait_time >= timeout {
+                    drop(state);
+                    self.shared
+                        .event_handler
+                        .on_pool_event(PoolEvent::Timeout { wait_time });
+                    return Err(PoolError::Timeout);
+                }
+                state = self
+                    .shared
+                    .connection_available
+                    .wait_timeout(state, timeout - wait_time)
+                    .unwrap_or_else(PoisonError::into_inner)
+                    .0;
+            };
+            drop(state);
+            if let Some(conn) = self.shared.prepare(slot)? {
+                return Ok(self.checkout(conn, start));
+            }
+        }
+    }
+
+    /// Check out a connection from async code
+    ///
+    /// The returned future waits for a free connection without blocking
+    /// the executor. It does not time out on its own, use the timeout
+    /// mechanism of your async runtime instead. The health check and
+    /// establishing new connections still block the current thread, use
+    /// [`Builder::initial_size`] to establish connections upfront.
+    pub async fn get_async(&self) -> Result<PooledConnection<C>, PoolError> {
+        let start = Instant::now();
+        loop {
+            let slot = poll_fn(|cx| {
+                let mut state = lock(&self.shared.state);
+                match self.shared.reserve(&mut state) {
+                    Some(slot) => Poll::Ready(slot),
+                    None => {
+                        state.waiting.push(cx.waker().clone());
+                        Poll::Pending
+                    }
+                }
+            })
+            .await;
+            if let Some(conn) = self.shared.prepare(slot)? {
+                return Ok(self.checkout(conn, start));
+            }
+        }
+    }
+
+    /// The current number of connections held by this pool
+    pub fn state(&self) -> PoolState {
+        self.shared.pool_state()
+    }
+
+    fn checkout(&self, conn: Conn<C>, start: Instant) -> PooledConnection<C> {
+        self.shared
+            .event_handler
+            .on_pool_event(PoolEvent::Checkout {
+                wait_time: start.elapsed(),
+            });
+        PooledConnection {
+            pool: Arc::clone(&self.shared),
+            conn: Some(conn),
+            checked_out_at: Instant::now(),
+        }
+    }
+}
+
+impl<C> Shared<C> {
+    fn pool_state(&self) -> PoolState {
+        let state = lock(&self.state);
+        PoolState {
+            connections: state.connections,
+            idle_connections: state.idle.len(),
+        }
+    }
+
+    fn reserve(&self, state: &mut State<C>) -> Option<Slot<C>> {
+        if let Some(idle) = state.idle.pop_front() {
+            Some(Slot::Idle(idle))
+        } else if state.connections < self.config.max_size {
+            state.connections += 1;
+            Some(Slot::New)
+        } else {
+            None
+        }
+    }
+
+    fn notify(&self, mut state: MutexGuard<'_, State<C>>) {
+        // wake all waiting tasks as some of them might
+        // have been dropped in the meantime
+        let waiting = core::mem::take(&mut state.waiting);
+        drop(state);
+        self.connection_available.notify_one();
+        waiting.into_iter().for_each(Waker::wake);
+    }
+
+    fn release(&self, conn: Conn<C>, reason: ReleaseReason) {
+        drop(conn);
+        let mut state = lock(&self.state);
+        state.connections -= 1;
+        self.notify(state);
+        self.event_handler
+            .on_pool_event(PoolEvent::Release { reason });
+    }
+
+    fn exceeds_max_lifetime(&self, conn: &Conn<C>) -> bool {
+        self.config
+            .max_lifetime
+            .is_some_and(|max_lifetime| conn.created_at.elapsed() >= max_lifetime)
+    }
+}
+
+impl<C> Shared<C>
+where
+    C: Connection,
+{
+    fn establish(&self) -> Result<Conn<C>, PoolError> {
+        let conn = C::establish(&self.database_url)?;
+        self.event_handler.on_pool_event(PoolEvent::Acquire);
+        Ok(Conn {
+            conn,
+            created_at: Instant::now(),
+        })
+    }
+
+    // returns `None` if an idle connection was closed
+    // and the caller needs to reserve another slot
+    fn prepare(&self, slot: Slot<C>) -> Result<Option<Conn<C>>, PoolError> {
+        match slot {
+            Slot::Idle(IdleConnection {
+                mut conn,
+                idle_since,
+            }) => {
+                let reason = if self.exceeds_max_lifetime(&conn) {
+                    Some(ReleaseReason::MaxLifetime)
+                } else if self
+                    .config
+                    .idle_timeout
+                    .is_some_and(|idle_timeout| idle_since.elapsed() >= idle_timeout)
+                {
+                    Some(ReleaseReason::IdleTimeout)
+                } else if self.config.test_on_check_out
+                    && conn.conn.batch_execute("SELECT 1").is_err()
+                {
+                    Some(ReleaseReason::HealthCheckFailed)
+                } else {
+                    None
+                };
+                match reason {
+                    Some(reason) => {
+                        self.release(conn, reason);
+                        Ok(None)
+                    }
+                    None => Ok(Some(conn)),
+                }
+            }
+            Slot::New => match self.establish() {
+                Ok(conn) => Ok(Some(conn)),
+                Err(e) => {
+                    let mut state = lock(&self.state);
+                    state.connections -= 1;
+                    self.notify(state);
+                    Err(e)
+                }
+            },
+        }
+    }
+
+    fn checkin(&self, mut conn: Conn<C>) {
+        if std::thread::panicking()
+            || C::TransactionManager::is_broken_transaction_manager(&mut conn.conn)
+        {
+            self.release(conn, ReleaseReason::Broken);
+        } else if self.exceeds_max_lifetime(&conn) {
+            self.release(conn, ReleaseReason::MaxLifetime);
+        } else {
+            let mut state = lock(&self.state);
+            state.idle.push_back(IdleConnection {
+                conn,
+                idle_since: Instant::now(),
+            });
+            self.notify(state);
+        }
+    }
+}
+
+/// A connection checked out from a [`Pool`]
+///
+/// The connection is returned to the pool when this value is dropped.
+/// It dereferences to the underlying connection and can be used
+/// directly to execute queries.
+pub struct PooledConnection<C>
+where
+    C: Connection,
+{
+    pool: Arc<Shared<C>>,
+    // only `None` while dropping
+    conn: Option<Conn<C>>,
+    checked_out_at: Instant,
+}
+
+impl<C> core::fmt::Debug for PooledConnection<C>
+where
+    C: Connection,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PooledConnection")
+            .field("checked_out_at", &self.checked_out_at)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C> Deref for PooledConnection<C>
+where
+    C: Connection,
+{
+    type Target = C;
+
+    fn deref(&self) -> &C {
+        match self.conn {
+            Some(ref conn) => &conn.conn,
+            None => unreachable!("The connection is only taken while dropping"),
+        }
+    }
+}
+
+impl<C> DerefMut for PooledConnection<C>
+where
+    C: Connection,
+{
+    fn deref_mut(&mut self) -> &mut C {
+        match self.conn {
+            Some(ref mut conn) => &mut conn.conn,
+            None => unreachable!("The connection is only taken while dropping"),
+        }
+    }
+}
+
+impl<C> Drop for PooledConnection<C>
+where
+    C: Connection,
+{
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            self.pool.event_handler.on_pool_event(PoolEvent::Checkin {
+                usage_time: self.checked_out_at.elapsed(),
+            });
+            self.pool.checkin(conn);
+        }
+    }
+}
+
+impl<C> SimpleConnection for PooledConnection<C>
+where
+    C: Connection,
+{
+    fn batch_execute(&mut self, query: &str) -> QueryResult<()> {
+        (**self).batch_execute(query)
+    }
+}
+
+impl<C> ConnectionSealed for PooledConnection<C> where C: Connection + ConnectionSealed {}
+
+impl<C> ConnectionWrapper for PooledConnection<C>
+where
+    C: Connection,
+{
+    type Inner = C;
+
+    fn inner_connection(&mut self) -> &mut C {
+        self
+    }
+}
+
+impl<C> Connection for PooledConnection<C>
+where
+    C: Connection + 'static,
+{
+    type Backend = C::Backend;
+    type TransactionManager = DelegatingTransactionManager<C::TransactionManager>;
+
+    fn establish(_: &str) -> ConnectionResult<Self> {
+        Err(ConnectionError::BadConnection(String::from(
+            "Cannot directly establish a pooled connection",
+        )))
+    }
+
+    fn begin_test_transaction(&mut self) -> QueryResult<()> {
+        (**self).begin_test_transaction()
+    }
+
+    fn execute_returning_count<T>(&mut self, source: &T) -> QueryResult<usize>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        (**self).execute_returning_count(source)
+    }
+
+    fn execute_returning_count_with_warnings<T>(
+        &mut self,
+        source: &T,
+    ) -> QueryResult<(usize, Vec<DatabaseWarning>)>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        (**self).execute_returning_count_with_warnings(source)
+    }
+
+    fn transaction_state(
+        &mut self,
+    ) -> &mut <Self::TransactionManager as TransactionManager<Self>>::TransactionStateData {
+        (**self).transaction_state()
+    }
+
+    fn instrumentation(&mut self) -> &mut dyn Instrumentation {
+        (**self).instrumentation()
+    }
+
+    fn set_instrumentation(&mut self, instrumentation: impl Instrumentation) {
+        (**self).set_instrumentation(instrumentation)
+    }
+
+    fn set_prepared_statement_cache_size(&mut self, size: crate::connection::CacheSize) {
+        (**self).set_prepared_statement_cache_size(size)
+    }
+
+    fn clear_prepared_statement_cache(&mut self) {
+        (**self).clear_prepared_statement_cache()
+    }
+
+    fn cached_statements(&self) -> alloc::vec::Vec<alloc::string::String> {
+        (**self).cached_statements()
+    }
+}
+
+impl<B, C> LoadConnection<B> for PooledConnection<C>
+where
+    C: LoadConnection<B> + 'static,
+{
+    type Cursor<'conn, 'query> = <C as LoadConnection<B>>::Cursor<'conn, 'query>;
+    type Row<'conn, 'query> = <C as LoadConnection<B>>::Row<'conn, 'query>;
+
+    fn load<'conn, 'query, T>(
+        &'conn mut self,
+        source: T,
+    ) -> QueryResult<Self::Cursor<'conn, 'query>>
+    where
+        T: Query + QueryFragment<Self::Backend> + QueryId + 'query,
+        Self::Backend: QueryMetadata<T::SqlType>,
+    {
+        (**self).load(source)
+    }
+}
+
+impl<C> crate::migration::MigrationConnection for PooledConnection<C>
+where
+    C: crate::migration::MigrationConnection + 'static,
+{
+    fn setup(&mut self) -> QueryResult<usize> {
+        (**self).setup()
+    }
+}
+
+impl<Changes, Output, C> crate::query_dsl::UpdateAndFetchResults<Changes, Output>
+    for PooledConnection<C>
+where
+    C: crate::query_dsl::UpdateAndFetchResults<Changes, Output> + 'static,
+{
+    fn update_and_fetch(&mut self, changeset: Changes) -> QueryResult<Output> {
+        (**self).update_and_fetch(changeset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::mpsc;
+    use std::thread;
+
+    use super::*;
+    use crate::connection::AnsiTransactionManager;
+    use crate::test_helpers::*;
+
+    #[derive(Default)]
+    struct EventCounter {
+        acquire: AtomicUsize,
+        release: AtomicUsize,
+        checkout: AtomicUsize,
+        checkin: AtomicUsize,
+        timeout: AtomicUsize,
+    }
+
+    impl EventCounter {
+        fn handler(self: &Arc<Self>) -> impl PoolEventHandler + use<> {
+            let counter = Arc::clone(self);
+            move |event| {
+                let count = match event {
+                    PoolEvent::Acquire => &counter.acquire,
+                    PoolEvent::Release { .. } => &counter.release,
+                    PoolEvent::Checkout { .. } => &counter.checkout,
+                    PoolEvent::Checkin { .. } => &counter.checkin,
+                    PoolEvent::Timeout { .. } => &counter.timeout,
+                };
+                count.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        fn counts(&self) -> [usize; 5] {
+            [
+                self.acquire.load(Ordering::Relaxed),
+                self.release.load(Ordering::Relaxed),
+                self.checkout.load(Ordering::Relaxed),
+                self.checkin.load(Ordering::Relaxed),
+                self.timeout.load(Ordering::Relaxed),
+            ]
+        }
+    }
+
+    #[diesel_test_helper::test]
+    fn pooled_connection_impls_connection() {
+        use crate::select;
+        use crate::sql_types::Text;
+
+        let pool = Pool::<TestConnection>::new(&database_url()).unwrap();
+        let mut conn = pool.get().unwrap();
+
+        let query = select("foo".into_sql::<Text>());
+        assert_eq!("foo", query.get_result::<String>(&mut conn).unwrap());
+    }
+
+    #[diesel_test_helper::test]
+    fn connections_are_reused() {
+        let counter = Arc::new(EventCounter::default());
+        let pool = Pool::<TestConnection>::builder()
+            .max_size(2)
+            .initial_size(1)
+            .event_handler(counter.handler())
+            .build(&database_url())
+            .unwrap();
+        assert_eq!([1, 0, 0, 0, 0], counter.counts());
+
+        drop(pool.get().unwrap());
+        drop(pool.get().unwrap());
+        assert_eq!([1, 0, 2, 2, 0], counter.counts());
+
+        let first = pool.get().unwrap();
+        let second = pool.get().unwrap();
+        assert_eq!([2, 0, 4, 2, 0], counter.counts());
+        assert_eq!(
+            PoolState {
+                connections: 2,
+                idle_connections: 0
+            },
+            pool.state()
+        );
+        drop((first, second));
+        assert_eq!(
+            PoolState {
+                connections: 2,
+                idle_connections: 2
+            },
+            pool.state()
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn checkout_times_out_if_all_connections_are_in_use() {
+        let counter = Arc::new(EventCounter::default());
+        let pool = Pool::<TestConnection>::builder()
+            .max_size(1)
+            .event_handler(counter.handler())
+            .build(&database_url())
+            .unwrap();
+
+        let conn = pool.get().unwrap();
+        let result = pool.get_timeout(Duration::from_millis(10));
+        assert!(matches!(result, Err(PoolError::Timeout)));
+        assert_eq!([1, 0, 1, 0, 1], counter.counts());
+
+        let (sender, receiver) = mpsc::channel();
+        let waiting_pool = pool.clone();
+        let waiting = thread::spawn(move || {
+            sender.send(()).unwrap();
+            waiting_pool.get().map(drop)
+        });
+        receiver.recv().unwrap();
+        drop(conn);
+        waiting.join().unwrap().unwrap();
+    }
+
+    #[diesel_test_helper::test]
+    fn async_checkout_waits_for_a_free_connection() {
+        use core::future::Future;
+        use core::pin::pin;
+        use core::task::Context;
+
+        let pool = Pool::<TestConnection>::builder()
+            .max_size(1)
+            .build(&database_url())
+            .unwrap();
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let conn = pool.get().unwrap();
+        let mut waiting = pin!(pool.get_async());
+        assert!(waiting.as_mut().poll(&mut cx).is_pending());
+        drop(conn);
+        assert!(matches!(waiting.as_mut().poll(&mut cx), Poll::Ready(Ok(_))));
+    }
+
+    #[diesel_test_helper::test]
+    fn connections_with_open_transactions_are_closed() {
+        let counter = Arc::new(EventCounter::default());
+        let pool = Pool::<TestConnection>::builder()
+            .max_size(1)
+            .event_handler(counter.handler())
+            .build(&database_url())
+            .unwrap();
+
+        let mut conn = pool.get().unwrap();
+        AnsiTransactionManager::begin_transaction(&mut *conn).unwrap();
+        drop(conn);
+        assert_eq!([1, 1, 1, 1, 0], counter.counts());
+        assert_eq!(0, pool.state().connections);
+
+        let mut conn = pool.get().unwrap();
+        assert!(!AnsiTransactionManager::is_broken_transaction_manager(
+            &mut *conn
+        ));
+    }
+
+    #[diesel_test_helper::test]
+    fn expired_connections_are_replaced() {
+        let counter = Arc::new(EventCounter::default());
+        let pool = Pool::<TestConnection>::builder()
+            .initial_size(1)
+            .idle_timeout(Some(Duration::ZERO))
+            .event_handler(counter.handler())
+            .build(&database_url())
+            .unwrap();
+
+        drop(pool.get().unwrap());
+        assert_eq!([2, 1, 1, 1, 0], counter.counts());
+
+        let pool = Pool::<TestConnection>::builder()
+            .max_lifetime(Some(Duration::ZERO))
+            .event_handler(counter.handler())
+            .build(&database_url())
+            .unwrap();
+        drop(pool.get().unwrap());
+        assert_eq!([3, 2, 2, 2, 0], counter.counts());
+        assert_eq!(0, pool.state().connections);
+    }
+}
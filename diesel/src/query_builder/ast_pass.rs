@@ -1,10 +1,12 @@
 use crate::backend::Backend;
+use crate::connection::{StatementInfo, StatementKind};
 use crate::query_builder::{BindCollector, MoveableBindCollector, QueryBuilder};
 use crate::result::QueryResult;
 use crate::serialize::ToSql;
 use crate::sql_types::HasSqlType;
 use alloc::boxed::Box;
 use alloc::fmt;
+use alloc::string::String;
 use alloc::vec::Vec;
 
 #[allow(missing_debug_implementations)]
@@ -70,12 +72,36 @@ where
         }
     }
 
+    // only used by the statement cache of the built-in connections
+    #[cfg_attr(
+        not(any(
+            feature = "postgres",
+            feature = "__sqlite-shared",
+            feature = "mysql",
+            feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+        )),
+        allow(dead_code)
+    )]
+    pub(crate) fn is_forced_to_cache_prepared(result: &'a mut bool, backend: &'b DB) -> Self {
+        AstPass {
+            internals: AstPassInternals::IsForcedToCachePrepared(result),
+            backend,
+        }
+    }
+
     pub(crate) fn debug_binds(
         formatter: &'a mut Vec<Box<dyn fmt::Debug + 'b>>,
         backend: &'b DB,
     ) -> Self {
         AstPass {
-            internals: AstPassInternals::DebugBinds(formatter),
+            internals: AstPassInternals::DebugBinds(formatter, RedactBinds::Owned(false)),
+            backend,
+        }
+    }
+
+    pub(crate) fn statement_info(info: &'a mut StatementInfo, backend: &'b DB) -> Self {
+        AstPass {
+            internals: AstPassInternals::StatementInfo(info),
             backend,
         }
     }
@@ -98,6 +124,7 @@ where
     )]
     #[cfg(any(
         feature = "__sqlite-shared",
+        feature = "mysql_backend",
         feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
     ))]
     pub(crate) fn skip_from(&mut self, value: bool) {
@@ -106,6 +133,16 @@ where
         }
     }
 
+    /// Register an internal flag whether or not diesel should
+    /// generate qualified column names for the targets
+    /// of a `SET` clause
+    #[cfg(feature = "mysql_backend")]
+    pub(crate) fn qualify_update_targets(&mut self, value: bool) {
+        if let AstPassInternals::ToSql(_, ref mut options) = self.internals {
+            options.qualify_update_targets = value
+        }
+    }
+
     /// Call this method whenever you pass an instance of `AstPass` by value.
     ///
     /// Effectively copies `self`, with a narrower lifetime. When passing a
@@ -131,8 +168,15 @@ where
             AstPassInternals::IsSafeToCachePrepared(ref mut result) => {
                 AstPassInternals::IsSafeToCachePrepared(result)
             }
-            AstPassInternals::DebugBinds(ref mut f) => AstPassInternals::DebugBinds(f),
+            AstPassInternals::IsForcedToCachePrepared(ref mut result) => {
+                AstPassInternals::IsForcedToCachePrepared(result)
+            }
+            AstPassInternals::DebugBinds(ref mut f, ref mut redact) => {
+                AstPassInternals::DebugBinds(f, redact.reborrow())
+            }
             AstPassInternals::IsNoop(ref mut result) => AstPassInternals::IsNoop(result),
+            AstPassInternals::StatementInfo(ref mut info) => AstPassInternals::StatementInfo(info),
+            AstPassInternals::TableName(ref mut name) => AstPassInternals::TableName(name),
         };
         AstPass {
             internals,
@@ -166,6 +210,19 @@ where
         }
     }
 
+    /// Mark the current query being constructed as one that should be
+    /// stored in the prepared statement cache even if caching is disabled
+    /// for the connection
+    ///
+    /// This does not affect queries marked via
+    /// [`unsafe_to_cache_prepared`](AstPass::unsafe_to_cache_prepared()),
+    /// those are never cached.
+    pub(crate) fn force_cache_prepared(&mut self) {
+        if let AstPassInternals::IsForcedToCachePrepared(ref mut result) = self.internals {
+            **result = true
+        }
+    }
+
     /// Push the given SQL string on the end of the query being constructed.
     ///
     /// # Example
@@ -194,6 +251,7 @@ where
         match self.internals {
             AstPassInternals::ToSql(ref mut builder, _) => builder.push_sql(sql),
             AstPassInternals::IsNoop(ref mut result) => **result = false,
+            AstPassInternals::TableName(ref mut name) => name.push_str(sql),
             _ => {}
         }
     }
@@ -206,6 +264,7 @@ where
         match self.internals {
             AstPassInternals::ToSql(ref mut builder, _) => builder.push_identifier(identifier)?,
             AstPassInternals::IsNoop(ref mut result) => **result = false,
+            AstPassInternals::TableName(ref mut name) => name.push_str(identifier),
             _ => {}
         }
         Ok(())
@@ -227,8 +286,12 @@ where
                 ref mut collector,
                 ref mut metadata_lookup,
             } => collector.push_bound_value(bind, metadata_lookup)?,
-            AstPassInternals::DebugBinds(ref mut f) => {
-                f.push(Box::new(bind));
+            AstPassInternals::DebugBinds(ref mut f, ref redact) => {
+                if redact.get() {
+                    f.push(Box::new(RedactedBind));
+                } else {
+                    f.push(Box::new(bind));
+                }
             }
             AstPassInternals::IsNoop(ref mut result) => **result = false,
             _ => {}
@@ -282,7 +345,7 @@ where
                 ref mut collector,
                 metadata_lookup: _,
             } => collector.append_bind_data(bind_collector_data),
-            AstPassInternals::DebugBinds(ref mut f) => {
+            AstPassInternals::DebugBinds(ref mut f, _) => {
                 DB::BindCollector::push_debug_binds(bind_collector_data, f);
             }
             _ => {}
@@ -290,6 +353,69 @@ where
         Ok(())
     }
 
+    /// Register whether the bind values following this call are
+    /// redacted in debug output
+    ///
+    /// Columns declared with `#[diesel(sensitive)]` in [`table!`](crate::table!)
+    /// enable the redaction, all other columns and the start of a clause
+    /// disable it again. Redacted values are printed as `<redacted>` by
+    /// [`debug_query`](crate::debug_query()), which includes the queries
+    /// passed to an [`Instrumentation`](crate::connection::Instrumentation).
+    #[doc(hidden)] // This is used by the `table!` macro
+    pub fn redact_following_binds(&mut self, redact: bool) {
+        if let AstPassInternals::DebugBinds(_, ref mut state) = self.internals {
+            state.set(redact);
+        }
+    }
+
+    /// Register the kind of the statement being walked
+    ///
+    /// Only the kind of the outermost statement is kept.
+    pub(crate) fn mark_statement_kind(&mut self, kind: StatementKind) {
+        if let AstPassInternals::StatementInfo(ref mut info) = self.internals {
+            info.set_kind(kind);
+        }
+    }
+
+    /// Walks a statement nested in front of the main statement,
+    /// like a common table expression, without using its kind
+    /// as kind of the whole statement
+    pub(crate) fn walk_nested_statement<F>(&mut self, walk: F) -> QueryResult<()>
+    where
+        F: FnOnce(AstPass<'_, 'b, DB>) -> QueryResult<()>,
+    {
+        let has_kind = match self.internals {
+            AstPassInternals::StatementInfo(ref info) => info.has_kind(),
+            _ => return walk(self.reborrow()),
+        };
+        walk(self.reborrow())?;
+        if let AstPassInternals::StatementInfo(ref mut info) = self.internals
+            && !has_kind
+        {
+            info.clear_kind();
+        }
+        Ok(())
+    }
+
+    /// Walks the name of a table and registers it as referenced
+    /// by the statement
+    pub(crate) fn walk_table_name<F>(&mut self, walk: F) -> QueryResult<()>
+    where
+        F: FnOnce(AstPass<'_, 'b, DB>) -> QueryResult<()>,
+    {
+        if let AstPassInternals::StatementInfo(ref mut info) = self.internals {
+            let mut name = String::new();
+            walk(AstPass {
+                internals: AstPassInternals::TableName(&mut name),
+                backend: self.backend,
+            })?;
+            info.add_table(name);
+            Ok(())
+        } else {
+            walk(self.reborrow())
+        }
+    }
+
     /// Get information about the backend that will consume this query
     #[cfg_attr(
         not(feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"),
@@ -303,6 +429,16 @@ where
         self.backend
     }
 
+    /// Get if the targets of a `SET` clause should be rendered
+    /// as qualified column names
+    pub(crate) fn should_qualify_update_targets(&self) -> bool {
+        if let AstPassInternals::ToSql(_, ref options) = self.internals {
+            options.qualify_update_targets
+        } else {
+            false
+        }
+    }
+
     /// Get if the query should be rendered with from clauses or not
     #[cfg_attr(
         not(feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"),
@@ -339,8 +475,52 @@ where
         metadata_lookup: &'a mut DB::MetadataLookup,
     },
     IsSafeToCachePrepared(&'a mut bool),
-    DebugBinds(&'a mut Vec<Box<dyn fmt::Debug + 'b>>),
+    IsForcedToCachePrepared(&'a mut bool),
+    DebugBinds(&'a mut Vec<Box<dyn fmt::Debug + 'b>>, RedactBinds<'a>),
     IsNoop(&'a mut bool),
+    StatementInfo(&'a mut StatementInfo),
+    TableName(&'a mut String),
+}
+
+/// Whether bind values are currently redacted while collecting debug binds
+///
+/// The state is owned by the pass walking the whole query and shared
+/// with all passes reborrowed from it.
+enum RedactBinds<'a> {
+    Owned(bool),
+    Borrowed(&'a mut bool),
+}
+
+impl RedactBinds<'_> {
+    fn get(&self) -> bool {
+        match self {
+            RedactBinds::Owned(redact) => *redact,
+            RedactBinds::Borrowed(redact) => **redact,
+        }
+    }
+
+    fn set(&mut self, value: bool) {
+        match self {
+            RedactBinds::Owned(redact) => *redact = value,
+            RedactBinds::Borrowed(redact) => **redact = value,
+        }
+    }
+
+    fn reborrow(&mut self) -> RedactBinds<'_> {
+        match self {
+            RedactBinds::Owned(redact) => RedactBinds::Borrowed(redact),
+            RedactBinds::Borrowed(redact) => RedactBinds::Borrowed(redact),
+        }
+    }
+}
+
+/// Printed instead of the value of a bind following a sensitive column
+struct RedactedBind;
+
+impl fmt::Debug for RedactedBind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
 }
 
 #[diesel_derives::__diesel_public_if(
@@ -353,6 +533,7 @@ where
 /// when rendering the sql string.
 pub(crate) struct AstPassToSqlOptions {
     skip_from: bool,
+    qualify_update_targets: bool,
 }
 
 /// This is an internal extension trait with methods required for
@@ -431,8 +612,13 @@ where
             AstPassInternals::IsSafeToCachePrepared(b) => {
                 AstPassInternals::IsSafeToCachePrepared(b)
             }
-            AstPassInternals::DebugBinds(b) => AstPassInternals::DebugBinds(b),
+            AstPassInternals::IsForcedToCachePrepared(b) => {
+                AstPassInternals::IsForcedToCachePrepared(b)
+            }
+            AstPassInternals::DebugBinds(b, r) => AstPassInternals::DebugBinds(b, r),
             AstPassInternals::IsNoop(b) => AstPassInternals::IsNoop(b),
+            AstPassInternals::StatementInfo(i) => AstPassInternals::StatementInfo(i),
+            AstPassInternals::TableName(n) => AstPassInternals::TableName(n),
         };
 
         AstPass {
@@ -454,7 +640,7 @@ where
     }
 
     fn debug_binds(&mut self) -> Option<(&mut Vec<Box<dyn fmt::Debug + 'b>>, &'b DB)> {
-        if let AstPassInternals::DebugBinds(formatter) = &mut self.internals {
+        if let AstPassInternals::DebugBinds(formatter, _) = &mut self.internals {
             Some((formatter, self.backend))
         } else {
             None
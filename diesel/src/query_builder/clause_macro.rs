@@ -53,6 +53,7 @@ macro_rules! simple_clause {
             fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()>
             {
                 out.push_sql($sql);
+                out.redact_following_binds(false);
                 self.0.walk_ast(out.reborrow())?;
                 Ok(())
             }
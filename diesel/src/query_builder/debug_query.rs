@@ -1,5 +1,8 @@
 use super::{AstPass, QueryBuilder, QueryFragment};
 use crate::backend::Backend;
+use crate::connection::StatementInfo;
+use crate::result::QueryResult;
+use crate::serialize::{self, Output, ToSql};
 use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -59,6 +62,20 @@ where
     formatter(query, &debug_binds, f)
 }
 
+/// Collects the [`StatementInfo`] of `query` from its AST
+pub(crate) fn statement_info<DB>(query: &dyn QueryFragment<DB>) -> QueryResult<StatementInfo>
+where
+    DB: Backend + Default,
+{
+    let backend = DB::default();
+    let mut info = StatementInfo::default();
+    query.walk_ast(AstPass::statement_info(&mut info, &backend))?;
+    let mut binds = Vec::new();
+    query.walk_ast(AstPass::debug_binds(&mut binds, &backend))?;
+    info.set_bind_count(binds.len());
+    Ok(info)
+}
+
 pub(crate) fn display(
     query: String,
     debug_binds: &DebugBinds<'_>,
@@ -125,3 +142,46 @@ fn format_list<'b>(f: &mut fmt::Formatter<'_>, entries: &[Box<dyn Debug + 'b>])
     }
     list.finish()
 }
+
+/// A bind value printed as `<redacted>` by [`debug_query`] and in
+/// the queries passed to an [`Instrumentation`]
+///
+/// Values compared with or assigned to columns declared with
+/// `#[diesel(sensitive)]` in [`table!`](crate::table!) are redacted
+/// without this wrapper. Use it for binds which don't follow such a
+/// column, like the binds of [`sql_query`](crate::sql_query()).
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// use diesel::query_builder::{Sensitive, debug_query};
+/// use diesel::sql_types::{Integer, Text};
+///
+/// # fn main() {
+/// let query = diesel::sql_query("UPDATE users SET name = $1 WHERE id = $2")
+///     .bind::<Text, _>(Sensitive("Jane"))
+///     .bind::<Integer, _>(1);
+/// let sql = debug_query::<DB, _>(&query).to_string();
+/// assert!(sql.ends_with("-- binds: [<redacted>, 1]"));
+/// # }
+/// ```
+///
+/// [`debug_query`]: crate::query_builder::debug_query()
+/// [`Instrumentation`]: crate::connection::Instrumentation
+#[derive(Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Sensitive<T>(pub T);
+
+impl<T> Debug for Sensitive<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+impl<T, ST, DB> ToSql<ST, DB> for Sensitive<T>
+where
+    T: ToSql<ST, DB>,
+    DB: Backend,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        self.0.to_sql(out)
+    }
+}
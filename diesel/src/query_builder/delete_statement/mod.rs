@@ -1,14 +1,18 @@
 use crate::backend::DieselReserveSpecialization;
+use crate::connection::StatementKind;
 use crate::dsl::{Filter, IntoBoxed, OrFilter};
 use crate::expression::{AppearsOnTable, Expression, SelectableExpression};
 use crate::query_builder::returning::{
     DeleteStmt, NoReturningClause, ReturningClause, ReturningQuerySource,
 };
+use crate::query_builder::using_clause::{
+    DeleteUsingClauseFragment, NoUsingClause, UsingClause, UsingClauseQuerySource,
+};
 use crate::query_builder::where_clause::*;
 use crate::query_builder::*;
 use crate::query_dsl::RunQueryDslSupport;
 use crate::query_dsl::methods::{BoxedDsl, FilterDsl, OrFilterDsl};
-use crate::query_source::{QuerySource, Table};
+use crate::query_source::{AppearsInFromClause, Never, QuerySource, Table};
 
 #[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
 /// Represents a SQL `DELETE` statement.
@@ -21,59 +25,70 @@ use crate::query_source::{QuerySource, Table};
 /// - `Ret`: The `RETURNING` clause of this query. The exact types used to
 ///   represent this are private. You can safely rely on the default type
 ///   representing the lack of a `RETURNING` clause.
-pub struct DeleteStatement<T: QuerySource, U, Ret = NoReturningClause> {
+/// - `F`: The `USING` clause of this query. The exact types used to
+///   represent this are private. You can safely rely on the default type
+///   representing the lack of a `USING` clause.
+pub struct DeleteStatement<T: QuerySource, U, Ret = NoReturningClause, F = NoUsingClause> {
     from_clause: FromClause<T>,
     where_clause: U,
     returning: Ret,
+    using_clause: F,
 }
 
-impl<T, U, Ret> Clone for DeleteStatement<T, U, Ret>
+impl<T, U, Ret, F> Clone for DeleteStatement<T, U, Ret, F>
 where
     T: QuerySource,
     FromClause<T>: Clone,
     U: Clone,
     Ret: Clone,
+    F: Clone,
 {
     fn clone(&self) -> Self {
         Self {
             from_clause: self.from_clause.clone(),
             where_clause: self.where_clause.clone(),
             returning: self.returning.clone(),
+            using_clause: self.using_clause.clone(),
         }
     }
 }
 
-impl<T, U, Ret> core::fmt::Debug for DeleteStatement<T, U, Ret>
+impl<T, U, Ret, F> core::fmt::Debug for DeleteStatement<T, U, Ret, F>
 where
     T: QuerySource,
     FromClause<T>: core::fmt::Debug,
     U: core::fmt::Debug,
     Ret: core::fmt::Debug,
+    F: core::fmt::Debug,
 {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("DeleteStatement")
             .field("from_clause", &self.from_clause)
             .field("where_clause", &self.where_clause)
             .field("returning", &self.returning)
+            .field("using_clause", &self.using_clause)
             .finish()
     }
 }
 
-impl<T, U, Ret> QueryId for DeleteStatement<T, U, Ret>
+impl<T, U, Ret, F> QueryId for DeleteStatement<T, U, Ret, F>
 where
     T: QuerySource + QueryId + 'static,
     U: QueryId,
     Ret: QueryId,
+    F: QueryId,
 {
-    type QueryId = DeleteStatement<T, U::QueryId, Ret::QueryId>;
+    type QueryId = DeleteStatement<T, U::QueryId, Ret::QueryId, F::QueryId>;
 
-    const HAS_STATIC_QUERY_ID: bool =
-        T::HAS_STATIC_QUERY_ID && U::HAS_STATIC_QUERY_ID && Ret::HAS_STATIC_QUERY_ID;
+    const HAS_STATIC_QUERY_ID: bool = T::HAS_STATIC_QUERY_ID
+        && U::HAS_STATIC_QUERY_ID
+        && Ret::HAS_STATIC_QUERY_ID
+        && F::HAS_STATIC_QUERY_ID;
 }
 
 /// A `DELETE` statement with a boxed `WHERE` clause
-pub type BoxedDeleteStatement<'a, DB, T, Ret = NoReturningClause> =
-    DeleteStatement<T, BoxedWhereClause<'a, DB>, Ret>;
+pub type BoxedDeleteStatement<'a, DB, T, Ret = NoReturningClause, F = NoUsingClause> =
+    DeleteStatement<T, BoxedWhereClause<'a, DB>, Ret, F>;
 
 impl<T: QuerySource, U> DeleteStatement<T, U, NoReturningClause> {
     pub(crate) fn new(table: T, where_clause: U) -> Self {
@@ -81,9 +96,66 @@ impl<T: QuerySource, U> DeleteStatement<T, U, NoReturningClause> {
             from_clause: FromClause::new(table),
             where_clause,
             returning: NoReturningClause,
+            using_clause: NoUsingClause,
+        }
+    }
+
+    /// Adds another table to this `DELETE` statement
+    ///
+    /// This allows the `WHERE` clause of the statement to reference columns
+    /// of the given table, without falling back to a correlated subquery.
+    /// The conditions relating both tables need to be provided via
+    /// [`filter`](DeleteStatement::filter()). Only rows of the target table
+    /// are deleted. Both tables need to be allowed to appear in the same query via
+    /// [`allow_tables_to_appear_in_same_query!`](crate::allow_tables_to_appear_in_same_query!).
+    ///
+    /// This generates `DELETE FROM target USING other WHERE …` on PostgreSQL
+    /// and `DELETE FROM target USING target, other WHERE …` on MySQL.
+    /// SQLite does not support deleting rows based on other tables.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # #[cfg(not(feature = "sqlite"))]
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "sqlite"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::{posts, users};
+    /// #     let connection = &mut establish_connection();
+    /// let deleted_rows = diesel::delete(posts::table)
+    ///     .using(users::table)
+    ///     .filter(posts::user_id.eq(users::id))
+    ///     .filter(users::name.eq("Sean"))
+    ///     .execute(connection)?;
+    /// assert_eq!(2, deleted_rows);
+    ///
+    /// let titles = posts::table.select(posts::title).load::<String>(connection)?;
+    /// assert_eq!(vec!["My first post too"], titles);
+    /// #     Ok(())
+    /// # }
+    /// # #[cfg(feature = "sqlite")]
+    /// # fn main() {}
+    /// ```
+    pub fn using<O>(self, source: O) -> DeleteStatement<T, U, NoReturningClause, UsingClause<O>>
+    where
+        O: QuerySource + AppearsInFromClause<T, Count = Never>,
+        T: AppearsInFromClause<O, Count = Never>,
+    {
+        DeleteStatement {
+            from_clause: self.from_clause,
+            where_clause: self.where_clause,
+            returning: self.returning,
+            using_clause: UsingClause::new(source),
         }
     }
+}
 
+impl<T: QuerySource, U, F> DeleteStatement<T, U, NoReturningClause, F> {
     /// Adds the given predicate to the `WHERE` clause of the statement being
     /// constructed.
     ///
@@ -201,88 +273,98 @@ impl<T: QuerySource, U> DeleteStatement<T, U, NoReturningClause> {
     }
 }
 
-impl<T, U, Ret, Predicate> FilterDsl<Predicate> for DeleteStatement<T, U, Ret>
+impl<T, U, Ret, F, Predicate> FilterDsl<Predicate> for DeleteStatement<T, U, Ret, F>
 where
     U: WhereAnd<Predicate>,
-    Predicate: AppearsOnTable<T>,
+    F: UsingClauseQuerySource<T>,
+    Predicate: AppearsOnTable<F::QuerySource>,
     T: QuerySource,
 {
-    type Output = DeleteStatement<T, U::Output, Ret>;
+    type Output = DeleteStatement<T, U::Output, Ret, F>;
 
     fn filter(self, predicate: Predicate) -> Self::Output {
         DeleteStatement {
             from_clause: self.from_clause,
             where_clause: self.where_clause.and(predicate),
             returning: self.returning,
+            using_clause: self.using_clause,
         }
     }
 }
 
-impl<T, U, Ret, Predicate> OrFilterDsl<Predicate> for DeleteStatement<T, U, Ret>
+impl<T, U, Ret, F, Predicate> OrFilterDsl<Predicate> for DeleteStatement<T, U, Ret, F>
 where
     T: QuerySource,
     U: WhereOr<Predicate>,
-    Predicate: AppearsOnTable<T>,
+    F: UsingClauseQuerySource<T>,
+    Predicate: AppearsOnTable<F::QuerySource>,
 {
-    type Output = DeleteStatement<T, U::Output, Ret>;
+    type Output = DeleteStatement<T, U::Output, Ret, F>;
 
     fn or_filter(self, predicate: Predicate) -> Self::Output {
         DeleteStatement {
             from_clause: self.from_clause,
             where_clause: self.where_clause.or(predicate),
             returning: self.returning,
+            using_clause: self.using_clause,
         }
     }
 }
 
-impl<'a, T, U, Ret, DB> BoxedDsl<'a, DB> for DeleteStatement<T, U, Ret>
+impl<'a, T, U, Ret, F, DB> BoxedDsl<'a, DB> for DeleteStatement<T, U, Ret, F>
 where
     U: Into<BoxedWhereClause<'a, DB>>,
     T: QuerySource,
 {
-    type Output = BoxedDeleteStatement<'a, DB, T, Ret>;
+    type Output = BoxedDeleteStatement<'a, DB, T, Ret, F>;
 
     fn internal_into_boxed(self) -> Self::Output {
         DeleteStatement {
             where_clause: self.where_clause.into(),
             returning: self.returning,
             from_clause: self.from_clause,
+            using_clause: self.using_clause,
         }
     }
 }
 
-impl<T, U, Ret, DB> QueryFragment<DB> for DeleteStatement<T, U, Ret>
+impl<T, U, Ret, F, DB> QueryFragment<DB> for DeleteStatement<T, U, Ret, F>
 where
     DB: Backend + DieselReserveSpecialization,
     T: Table,
     FromClause<T>: QueryFragment<DB>,
+    T::FromClause: QueryFragment<DB>,
     U: QueryFragment<DB>,
     Ret: QueryFragment<DB>,
+    F: DeleteUsingClauseFragment<DB>,
 {
     fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        out.mark_statement_kind(StatementKind::Delete);
         out.push_sql("DELETE");
         self.from_clause.walk_ast(out.reborrow())?;
+        self.using_clause
+            .walk_using_clause(&self.from_clause.from_clause, out.reborrow())?;
         self.where_clause.walk_ast(out.reborrow())?;
         self.returning.walk_ast(out.reborrow())?;
         Ok(())
     }
 }
 
-impl<T, U> AsQuery for DeleteStatement<T, U, NoReturningClause>
+impl<T, U, F> AsQuery for DeleteStatement<T, U, NoReturningClause, F>
 where
     T: Table,
-    DeleteStatement<T, U, ReturningClause<T::AllColumns>>: Query,
+    DeleteStatement<T, U, ReturningClause<T::AllColumns>, F>: Query,
     T::AllColumns: SelectableExpression<ReturningQuerySource<DeleteStmt, T>>,
 {
     type SqlType = <Self::Query as Query>::SqlType;
-    type Query = DeleteStatement<T, U, ReturningClause<T::AllColumns>>;
+    type Query = DeleteStatement<T, U, ReturningClause<T::AllColumns>, F>;
 
     fn as_query(self) -> Self::Query {
         self.returning(T::all_columns())
     }
 }
 
-impl<T, U, Ret> Query for DeleteStatement<T, U, ReturningClause<Ret>>
+impl<T, U, Ret, F> Query for DeleteStatement<T, U, ReturningClause<Ret>, F>
 where
     T: Table,
     Ret: SelectableExpression<ReturningQuerySource<DeleteStmt, T>>,
@@ -290,9 +372,9 @@ where
     type SqlType = <Ret as Expression>::SqlType;
 }
 
-impl<T, U, Ret> RunQueryDslSupport for DeleteStatement<T, U, Ret> where T: QuerySource {}
+impl<T, U, Ret, F> RunQueryDslSupport for DeleteStatement<T, U, Ret, F> where T: QuerySource {}
 
-impl<T: QuerySource, U> DeleteStatement<T, U, NoReturningClause> {
+impl<T: QuerySource, U, F> DeleteStatement<T, U, NoReturningClause, F> {
     /// Specify what expression is returned after execution of the `delete`.
     ///
     /// # Examples
@@ -314,14 +396,15 @@ impl<T: QuerySource, U> DeleteStatement<T, U, NoReturningClause> {
     /// # #[cfg(not(feature = "postgres"))]
     /// # fn main() {}
     /// ```
-    pub fn returning<E>(self, returns: E) -> DeleteStatement<T, U, ReturningClause<E>>
+    pub fn returning<E>(self, returns: E) -> DeleteStatement<T, U, ReturningClause<E>, F>
     where
-        DeleteStatement<T, U, ReturningClause<E>>: Query,
+        DeleteStatement<T, U, ReturningClause<E>, F>: Query,
     {
         DeleteStatement {
             where_clause: self.where_clause,
             from_clause: self.from_clause,
             returning: ReturningClause(returns),
+            using_clause: self.using_clause,
         }
     }
 }
@@ -34,5 +34,5 @@ impl<S, G> ValidDistinctForGroupBy<S, G> for DistinctClause {}
 
 // This is rexported from another location
 #[allow(unreachable_pub, unused_imports)]
-#[cfg(feature = "postgres_backend")]
-pub use crate::pg::DistinctOnClause;
+#[cfg(any(feature = "postgres_backend", feature = "distinct_on_for_mysql_8"))]
+pub use crate::query_builder::distinct_on::DistinctOnClause;
@@ -1,22 +1,18 @@
 use crate::QuerySource;
 use crate::expression::{SelectableExpression, ValidGrouping};
-use crate::pg::Pg;
 use crate::query_builder::group_by_clause::ValidGroupByClause;
 use crate::query_builder::order_clause::NoOrderClause;
+use crate::query_builder::order_clause::OrderClause;
 use crate::query_builder::{
-    AstPass, FromClause, QueryFragment, QueryId, SelectClauseExpression, SelectQuery,
-    SelectStatement,
+    FromClause, QueryId, SelectClauseExpression, SelectQuery, SelectStatement,
 };
 use crate::query_dsl::group_by_dsl::ValidDistinctForGroupBy;
 use crate::query_dsl::methods::DistinctOnDsl;
 use crate::query_dsl::order_dsl::ValidOrderingForDistinct;
-use crate::result::QueryResult;
 use crate::sql_types::SingleValue;
-use diesel::query_builder::order_clause::OrderClause;
 
 /// Represents `DISTINCT ON (...)`
 #[derive(Debug, Clone, Copy, QueryId)]
-#[cfg(feature = "postgres_backend")]
 pub struct DistinctOnClause<T>(pub(crate) T);
 
 impl<T> ValidOrderingForDistinct<DistinctOnClause<T>> for NoOrderClause {}
@@ -272,18 +268,6 @@ impl<C> OrderDecorator for crate::helper_types::Desc<C> {
     type Column = C;
 }
 
-impl<T> QueryFragment<Pg> for DistinctOnClause<T>
-where
-    T: QueryFragment<Pg>,
-{
-    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, Pg>) -> QueryResult<()> {
-        out.push_sql("DISTINCT ON (");
-        self.0.walk_ast(out.reborrow())?;
-        out.push_sql(") ");
-        Ok(())
-    }
-}
-
 impl<ST, F, S, D, W, O, LOf, G, H, Selection> DistinctOnDsl<Selection>
     for SelectStatement<FromClause<F>, S, D, W, O, LOf, G, H>
 where
@@ -2,12 +2,15 @@ use super::delete_statement::DeleteStatement;
 use super::distinct_clause::NoDistinctClause;
 use super::insert_statement::{Insert, InsertOrIgnore, Replace};
 use super::select_clause::SelectClause;
+use super::values_list::IntoValuesRows;
 use super::{
-    AsQuery, IncompleteInsertOrIgnoreStatement, IncompleteInsertStatement,
-    IncompleteReplaceStatement, IntoUpdateTarget, SelectStatement, SqlQuery, UpdateStatement,
+    AsQuery, IncompleteInsertOrIgnoreStatement, IncompleteInsertStatement, IncompleteRecursiveCte,
+    IncompleteReplaceStatement, IncompleteWithQuery, IntoUpdateTarget, SelectStatement, SqlQuery,
+    UpdateStatement, ValuesList,
 };
 use crate::Table;
 use crate::expression::Expression;
+use crate::query_source::QueryRelation;
 use alloc::string::String;
 
 /// Creates an `UPDATE` statement.
@@ -381,6 +384,42 @@ pub fn delete<T: IntoUpdateTarget>(source: T) -> DeleteStatement<T::Table, T::Wh
 /// # }
 /// ```
 ///
+/// The column list is checked at compile time: each column must belong to the
+/// target table and the SQL types of the columns must match the SQL types
+/// selected by the query. On backends supporting it, the inserted rows can be
+/// returned by calling [`.returning`] on the resulting statement.
+///
+/// [`.returning`]: crate::query_builder::InsertStatement::returning()
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// #
+/// # #[cfg(feature = "postgres")]
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # #[cfg(feature = "postgres")]
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::{posts, users};
+/// #     let conn = &mut establish_connection();
+/// #     diesel::delete(posts::table).execute(conn)?;
+/// let new_posts = users::table
+///     .filter(users::name.eq("Sean"))
+///     .select((users::name.concat("'s First Post"), users::id));
+/// let inserted_post = diesel::insert_into(posts::table)
+///     .values(new_posts)
+///     .into_columns((posts::title, posts::user_id))
+///     .returning((posts::title, posts::user_id))
+///     .get_result::<(String, i32)>(conn)?;
+///
+/// assert_eq!((String::from("Sean's First Post"), 1), inserted_post);
+/// #     Ok(())
+/// # }
+/// # #[cfg(not(feature = "postgres"))]
+/// # fn main() {}
+/// ```
+///
 /// ### With return value
 ///
 /// ```rust
@@ -616,7 +655,217 @@ pub fn sql_query<T: Into<String>>(query: T) -> SqlQuery {
     SqlQuery::from_sql(query.into())
 }
 
+/// Creates a query with a `WITH` clause, also known as a common table expression
+///
+/// A common table expression is a named temporary result set, which only exists
+/// for the duration of a single query. The name and the columns of the common
+/// table expression are described by a query relation, usually declared via the
+/// [`view!`](crate::view!) macro. As the `view!` macro does not create anything
+/// in the database this declaration is not backed by an actual database view.
+/// The given `query` must return the same SQL type as the declared columns.
+///
+/// Additional common table expressions, which might refer to the previous ones,
+/// can be added via [`IncompleteWithQuery::with`]. The final query is set via
+/// [`IncompleteWithQuery::query`] and can use all declared common table
+/// expressions as a source in its `FROM` clause.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// #
+/// diesel::view! {
+///     named_users {
+///         id -> Integer,
+///         name -> Text,
+///     }
+/// }
+///
+/// diesel::view! {
+///     sean_ids {
+///         id -> Integer,
+///     }
+/// }
+///
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::users;
+/// use diesel::dsl::with;
+///
+/// #     let connection = &mut establish_connection();
+/// let names = with(
+///     named_users::view,
+///     users::table.select((users::id, users::name)),
+/// )
+/// .query(named_users::view.select(named_users::name).order(named_users::id))
+/// .load::<String>(connection)?;
+/// assert_eq!(vec!["Sean", "Tess"], names);
+///
+/// // common table expressions can refer to previously declared ones
+/// let ids = with(
+///     named_users::view,
+///     users::table.select((users::id, users::name)),
+/// )
+/// .with(
+///     sean_ids::view,
+///     named_users::view
+///         .filter(named_users::name.eq("Sean"))
+///         .select((named_users::id,)),
+/// )
+/// .query(sean_ids::view.select(sean_ids::id))
+/// .load::<i32>(connection)?;
+/// assert_eq!(vec![1], ids);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn with<R, Q>(relation: R, query: Q) -> crate::dsl::with<R, Q>
+where
+    R: QueryRelation,
+    Q: AsQuery<SqlType = <R::AllColumns as Expression>::SqlType>,
+{
+    IncompleteWithQuery::from_cte(relation, query.as_query())
+}
+
+/// Creates a query with a `WITH RECURSIVE` clause
+///
+/// A recursive common table expression consists of an `anchor` query, which
+/// provides the initial rows, and a recursive term which refers to the common
+/// table expression itself. The recursive term is added via
+/// [`IncompleteRecursiveCte::union_all`] or [`IncompleteRecursiveCte::union`].
+/// This is commonly used to traverse trees or graphs.
+///
+/// Otherwise this function behaves like [`with`]. Additional common table
+/// expressions can be added via [`IncompleteWithQuery::with`] afterwards.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// #
+/// diesel::view! {
+///     numbers {
+///         n -> Integer,
+///     }
+/// }
+///
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// use diesel::dsl::with_recursive;
+///
+/// #     let connection = &mut establish_connection();
+/// let result = with_recursive(numbers::view, diesel::select((1.into_sql::<Integer>(),)))
+///     .union_all(
+///         numbers::view
+///             .filter(numbers::n.lt(5))
+///             .select((numbers::n + 1,)),
+///     )
+///     .query(numbers::view.select(numbers::n))
+///     .load::<i32>(connection)?;
+/// assert_eq!(vec![1, 2, 3, 4, 5], result);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn with_recursive<R, Q>(relation: R, anchor: Q) -> crate::dsl::with_recursive<R, Q>
+where
+    R: QueryRelation,
+    Q: AsQuery<SqlType = <R::AllColumns as Expression>::SqlType>,
+{
+    IncompleteRecursiveCte::new(relation, anchor.as_query())
+}
+
+/// Creates a `VALUES` list, a query returning the given constant rows
+///
+/// The rows are given as a tuple, an array or a `Vec` of tuples. The SQL
+/// type of the rows is usually inferred from the place where the query is
+/// used, otherwise it can be specified explicitly as
+/// `values::<(Integer, Text), _>(..)`.
+///
+/// A `VALUES` list can be loaded directly, used as subquery, for example
+/// with [`eq_any`](crate::expression_methods::ExpressionMethods::eq_any()),
+/// or used as common table expression via [`with`]. The latter gives the
+/// columns of the list typed names, which allows to use the list as source
+/// in the `FROM` clause of a query, to join it with other tables or to
+/// update a table based on the given rows via
+/// [`IncompleteWithQuery::statement`](crate::query_builder::IncompleteWithQuery::statement()).
+///
+/// On MySQL the rows are rendered as `ROW(..)`, which requires MySQL 8.0.19
+/// or newer. A `VALUES` list without any row results in an error when
+/// executing the query.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use schema::users;
+/// #
+/// diesel::view! {
+///     new_names {
+///         id -> Integer,
+///         name -> Text,
+///     }
+/// }
+///
+/// diesel::allow_tables_to_appear_in_same_query!(new_names, users);
+///
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// use diesel::dsl::{values, with};
+///
+/// #     let connection = &mut establish_connection();
+/// let rows = values::<(Integer, Text), _>(((1, "a"), (2, "b"))).load::<(i32, String)>(connection)?;
+/// assert_eq!(vec![(1, "a".to_string()), (2, "b".to_string())], rows);
+///
+/// // join against a list of constants
+/// let renamed = with(new_names::view, values(vec![(1, "Sean Griffin"), (3, "Jim")]))
+///     .query(
+///         users::table
+///             .inner_join(new_names::view.on(new_names::id.eq(users::id)))
+///             .select((users::name, new_names::name)),
+///     )
+///     .load::<(String, String)>(connection)?;
+/// assert_eq!(vec![("Sean".to_string(), "Sean Griffin".to_string())], renamed);
+///
+/// // update a table from a list of rows
+/// with(new_names::view, values([(1, "Sean Griffin"), (2, "Tess Griffin")]))
+///     .statement(
+///         diesel::update(users::table)
+///             .filter(users::id.eq_any(new_names::view.select(new_names::id)))
+///             .set(
+///                 users::name.eq(new_names::view
+///                     .filter(new_names::id.eq(users::id))
+///                     .select(new_names::name)
+///                     .single_value()
+///                     .assume_not_null()),
+///             ),
+///     )
+///     .execute(connection)?;
+/// let names = users::table
+///     .select(users::name)
+///     .order(users::id)
+///     .load::<String>(connection)?;
+/// assert_eq!(vec!["Sean Griffin", "Tess Griffin"], names);
+/// #     Ok(())
+/// # }
+/// ```
+pub fn values<ST, T>(rows: T) -> crate::dsl::values<ST, T>
+where
+    T: IntoValuesRows<ST>,
+{
+    ValuesList::new(rows.into_values_rows())
+}
+
 #[cfg(feature = "postgres_backend")]
 pub use crate::pg::query_builder::copy::copy_from::copy_from;
 #[cfg(feature = "postgres_backend")]
 pub use crate::pg::query_builder::copy::copy_to::copy_to;
+#[cfg(feature = "postgres_backend")]
+pub use crate::pg::query_builder::notify::notify;
@@ -12,6 +12,10 @@ pub trait ColumnList {
     ///
     /// Column names must *not* be qualified.
     fn walk_ast<DB: Backend>(&self, out: AstPass<'_, '_, DB>) -> QueryResult<()>;
+
+    /// Append the names of these columns to `names`
+    #[cfg(feature = "fixtures")]
+    fn column_names(names: &mut alloc::vec::Vec<&'static str>);
 }
 
 impl<C> ColumnList for C
@@ -24,4 +28,9 @@ where
         out.push_identifier(C::NAME)?;
         Ok(())
     }
+
+    #[cfg(feature = "fixtures")]
+    fn column_names(names: &mut alloc::vec::Vec<&'static str>) {
+        names.push(C::NAME);
+    }
 }
@@ -0,0 +1,229 @@
+use super::InsertStatement;
+use crate::connection::Connection;
+use crate::query_source::{Column, QuerySource};
+use crate::result::QueryResult;
+
+use self::private::LoadInsertedKey;
+
+impl<T, U, Op> InsertStatement<T, U, Op>
+where
+    T: QuerySource,
+{
+    /// Executes this insert statement, loads the generated `key` of the
+    /// inserted row and passes it to `children` to insert dependent rows
+    ///
+    /// This is the usual way to insert a parent row together with child
+    /// rows referencing it via a foreign key. Both steps are executed in
+    /// a single transaction, so either the parent and all children are
+    /// inserted or nothing is. The key of the parent is returned together
+    /// with the result of `children`.
+    ///
+    /// This statement must insert exactly one row. The key is loaded with
+    /// as few round trips as the backend allows:
+    ///
+    /// * PostgreSQL, and SQLite with the `returning_clauses_for_sqlite_3_35`
+    ///   feature, use a `RETURNING` clause
+    /// * SQLite without that feature uses `last_insert_rowid()`, which
+    ///   requires `key` to be the `INTEGER PRIMARY KEY` column of the table
+    /// * MySQL uses `LAST_INSERT_ID()`, which requires `key` to be an
+    ///   `AUTO_INCREMENT` column
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # use schema::{posts, users};
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let connection = &mut establish_connection();
+    /// let (user_id, inserted_posts) = diesel::insert_into(users::table)
+    ///     .values(users::name.eq("Ruby"))
+    ///     .execute_with_children(connection, users::id, |conn, user_id: i32| {
+    ///         diesel::insert_into(posts::table)
+    ///             .values(vec![
+    ///                 (posts::user_id.eq(user_id), posts::title.eq("Hello")),
+    ///                 (posts::user_id.eq(user_id), posts::title.eq("World")),
+    ///             ])
+    ///             .execute(conn)
+    ///     })?;
+    /// assert_eq!(2, inserted_posts);
+    ///
+    /// let titles = posts::table
+    ///     .filter(posts::user_id.eq(user_id))
+    ///     .select(posts::title)
+    ///     .order(posts::id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Hello", "World"], titles);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn execute_with_children<'query, Conn, K, V, R, F>(
+        self,
+        conn: &mut Conn,
+        key: K,
+        children: F,
+    ) -> QueryResult<(V, R)>
+    where
+        Conn: Connection,
+        K: Column<Table = T>,
+        V: Clone,
+        Self: LoadInsertedKey<'query, Conn, Conn::Backend, K, V>,
+        F: FnOnce(&mut Conn, V) -> QueryResult<R>,
+    {
+        conn.transaction(|conn| {
+            let parent_key = self.load_inserted_key(conn, key)?;
+            let children = children(conn, parent_key.clone())?;
+            Ok((parent_key, children))
+        })
+    }
+}
+
+#[cfg_attr(
+    not(any(
+        feature = "postgres_backend",
+        feature = "mysql_backend",
+        feature = "__sqlite-shared"
+    )),
+    allow(unused_imports)
+)]
+mod private {
+    use crate::query_builder::InsertStatement;
+    #[cfg(any(
+        feature = "postgres_backend",
+        feature = "returning_clauses_for_sqlite_3_35"
+    ))]
+    use crate::query_builder::returning::ReturningClause;
+    use crate::query_dsl::LoadQuery;
+    use crate::query_source::QuerySource;
+    use crate::result::QueryResult;
+    use crate::{Connection, RunQueryDsl};
+    #[cfg(any(
+        feature = "mysql_backend",
+        all(
+            feature = "__sqlite-shared",
+            not(feature = "returning_clauses_for_sqlite_3_35")
+        )
+    ))]
+    use crate::{expression::SqlLiteral, query_dsl::methods::ExecuteDsl, query_source::Column};
+
+    /// Executes an insert statement and loads the
+    /// generated key of the inserted row
+    ///
+    /// `DB` is the backend of `Conn`, which allows to implement
+    /// this trait differently for each backend.
+    #[allow(unreachable_pub)]
+    pub trait LoadInsertedKey<'query, Conn, DB, K, V> {
+        fn load_inserted_key(self, conn: &mut Conn, key: K) -> QueryResult<V>;
+    }
+
+    #[cfg(any(
+        feature = "postgres_backend",
+        feature = "returning_clauses_for_sqlite_3_35"
+    ))]
+    fn load_via_returning<'query, T, U, Op, Conn, K, V>(
+        stmt: InsertStatement<T, U, Op>,
+        conn: &mut Conn,
+        key: K,
+    ) -> QueryResult<V>
+    where
+        T: QuerySource,
+        InsertStatement<T, U, Op, ReturningClause<K>>: LoadQuery<'query, Conn, V>,
+    {
+        InsertStatement::new(
+            stmt.target,
+            stmt.records,
+            stmt.operator,
+            ReturningClause(key),
+        )
+        .get_result(conn)
+    }
+
+    #[cfg(any(
+        feature = "mysql_backend",
+        all(
+            feature = "__sqlite-shared",
+            not(feature = "returning_clauses_for_sqlite_3_35")
+        )
+    ))]
+    fn load_via_function<'query, T, U, Op, Conn, K, V>(
+        stmt: InsertStatement<T, U, Op>,
+        conn: &mut Conn,
+        function: &str,
+    ) -> QueryResult<V>
+    where
+        T: QuerySource,
+        Conn: Connection,
+        K: Column,
+        K::SqlType: crate::expression::TypedExpressionType,
+        InsertStatement<T, U, Op>: ExecuteDsl<Conn>,
+        crate::dsl::select<SqlLiteral<K::SqlType>>: LoadQuery<'query, Conn, V>,
+    {
+        ExecuteDsl::execute(stmt, conn)?;
+        crate::select(crate::dsl::sql::<K::SqlType>(function)).get_result(conn)
+    }
+
+    #[cfg(feature = "postgres_backend")]
+    impl<'query, T, U, Op, Conn, K, V> LoadInsertedKey<'query, Conn, crate::pg::Pg, K, V>
+        for InsertStatement<T, U, Op>
+    where
+        T: QuerySource,
+        Conn: Connection<Backend = crate::pg::Pg>,
+        InsertStatement<T, U, Op, ReturningClause<K>>: LoadQuery<'query, Conn, V>,
+    {
+        fn load_inserted_key(self, conn: &mut Conn, key: K) -> QueryResult<V> {
+            load_via_returning(self, conn, key)
+        }
+    }
+
+    #[cfg(feature = "returning_clauses_for_sqlite_3_35")]
+    impl<'query, T, U, Op, Conn, K, V> LoadInsertedKey<'query, Conn, crate::sqlite::Sqlite, K, V>
+        for InsertStatement<T, U, Op>
+    where
+        T: QuerySource,
+        Conn: Connection<Backend = crate::sqlite::Sqlite>,
+        InsertStatement<T, U, Op, ReturningClause<K>>: LoadQuery<'query, Conn, V>,
+    {
+        fn load_inserted_key(self, conn: &mut Conn, key: K) -> QueryResult<V> {
+            load_via_returning(self, conn, key)
+        }
+    }
+
+    #[cfg(all(
+        feature = "__sqlite-shared",
+        not(feature = "returning_clauses_for_sqlite_3_35")
+    ))]
+    impl<'query, T, U, Op, Conn, K, V> LoadInsertedKey<'query, Conn, crate::sqlite::Sqlite, K, V>
+        for InsertStatement<T, U, Op>
+    where
+        T: QuerySource,
+        Conn: Connection<Backend = crate::sqlite::Sqlite>,
+        K: Column,
+        K::SqlType: crate::expression::TypedExpressionType,
+        InsertStatement<T, U, Op>: ExecuteDsl<Conn>,
+        crate::dsl::select<SqlLiteral<K::SqlType>>: LoadQuery<'query, Conn, V>,
+    {
+        fn load_inserted_key(self, conn: &mut Conn, _key: K) -> QueryResult<V> {
+            load_via_function::<_, _, _, _, K, _>(self, conn, "last_insert_rowid()")
+        }
+    }
+
+    #[cfg(feature = "mysql_backend")]
+    impl<'query, T, U, Op, Conn, K, V> LoadInsertedKey<'query, Conn, crate::mysql::Mysql, K, V>
+        for InsertStatement<T, U, Op>
+    where
+        T: QuerySource,
+        Conn: Connection<Backend = crate::mysql::Mysql>,
+        K: Column,
+        K::SqlType: crate::expression::TypedExpressionType,
+        InsertStatement<T, U, Op>: ExecuteDsl<Conn>,
+        crate::dsl::select<SqlLiteral<K::SqlType>>: LoadQuery<'query, Conn, V>,
+    {
+        fn load_inserted_key(self, conn: &mut Conn, _key: K) -> QueryResult<V> {
+            load_via_function::<_, _, _, _, K, _>(self, conn, "LAST_INSERT_ID()")
+        }
+    }
+}
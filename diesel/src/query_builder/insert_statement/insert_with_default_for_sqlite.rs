@@ -276,13 +276,15 @@ where
     }
 }
 
-impl<V, T, QId, C, Op, O, Target, ConflictOpt, const STATIC_QUERY_ID: bool> ExecuteDsl<C, Sqlite>
+impl<V, T, QId, C, Op, O, Target, ConflictOpt, ConflictWhere, const STATIC_QUERY_ID: bool>
+    ExecuteDsl<C, Sqlite>
     for InsertStatement<
         T,
         OnConflictValues<
             BatchInsert<Vec<ValuesClause<V, T>>, T, QId, STATIC_QUERY_ID>,
             Target,
             ConflictOpt,
+            ConflictWhere,
         >,
         Op,
     >
@@ -366,14 +368,27 @@ where
     }
 }
 
-impl<'query, V, T, QId, Op, O, U, B, Target, ConflictOpt, const STATIC_QUERY_ID: bool>
-    LoadQuery<'query, SqliteConnection, U, B>
+impl<
+    'query,
+    V,
+    T,
+    QId,
+    Op,
+    O,
+    U,
+    B,
+    Target,
+    ConflictOpt,
+    ConflictWhere,
+    const STATIC_QUERY_ID: bool,
+> LoadQuery<'query, SqliteConnection, U, B>
     for InsertStatement<
         T,
         OnConflictValues<
             BatchInsert<Vec<ValuesClause<V, T>>, T, QId, STATIC_QUERY_ID>,
             Target,
             ConflictOpt,
+            ConflictWhere,
         >,
         Op,
     >
@@ -393,14 +408,28 @@ where
     }
 }
 
-impl<'query, V, T, QId, Op, Ret, O, U, B, Target, ConflictOpt, const STATIC_QUERY_ID: bool>
-    LoadQuery<'query, SqliteConnection, U, B>
+impl<
+    'query,
+    V,
+    T,
+    QId,
+    Op,
+    Ret,
+    O,
+    U,
+    B,
+    Target,
+    ConflictOpt,
+    ConflictWhere,
+    const STATIC_QUERY_ID: bool,
+> LoadQuery<'query, SqliteConnection, U, B>
     for InsertStatement<
         T,
         OnConflictValues<
             BatchInsert<Vec<ValuesClause<V, T>>, T, QId, STATIC_QUERY_ID>,
             Target,
             ConflictOpt,
+            ConflictWhere,
         >,
         Op,
         ReturningClause<Ret>,
@@ -458,7 +487,7 @@ where
 {
 }
 
-impl<V, T, QId, Op, O, Target, ConflictOpt, const STATIC_QUERY_ID: bool>
+impl<V, T, QId, Op, O, Target, ConflictOpt, ConflictWhere, const STATIC_QUERY_ID: bool>
     RunQueryDsl<SqliteConnection>
     for (
         O,
@@ -468,6 +497,7 @@ impl<V, T, QId, Op, O, Target, ConflictOpt, const STATIC_QUERY_ID: bool>
                 BatchInsert<Vec<ValuesClause<V, T>>, T, QId, STATIC_QUERY_ID>,
                 Target,
                 ConflictOpt,
+                ConflictWhere,
             >,
             Op,
         >,
@@ -482,13 +512,14 @@ where
             BatchInsert<Vec<ValuesClause<V, T>>, T, QId, STATIC_QUERY_ID>,
             Target,
             ConflictOpt,
+            ConflictWhere,
         >,
         Op,
     >: RunQueryDsl<SqliteConnection>,
 {
 }
 
-impl<V, T, QId, Op, Ret, O, Target, ConflictOpt, const STATIC_QUERY_ID: bool>
+impl<V, T, QId, Op, Ret, O, Target, ConflictOpt, ConflictWhere, const STATIC_QUERY_ID: bool>
     RunQueryDsl<SqliteConnection>
     for (
         O,
@@ -498,6 +529,7 @@ impl<V, T, QId, Op, Ret, O, Target, ConflictOpt, const STATIC_QUERY_ID: bool>
                 BatchInsert<Vec<ValuesClause<V, T>>, T, QId, STATIC_QUERY_ID>,
                 Target,
                 ConflictOpt,
+                ConflictWhere,
             >,
             Op,
             ReturningClause<Ret>,
@@ -513,6 +545,7 @@ where
             BatchInsert<Vec<ValuesClause<V, T>>, T, QId, STATIC_QUERY_ID>,
             Target,
             ConflictOpt,
+            ConflictWhere,
         >,
         Op,
         ReturningClause<Ret>,
@@ -618,7 +651,7 @@ where
 }
 
 #[diagnostic::do_not_recommend]
-impl<'query, V, T, QId, Op, U, B, Target, ConflictOpt, const STATIC_QUERY_ID: bool>
+impl<'query, V, T, QId, Op, U, B, Target, ConflictOpt, ConflictWhere, const STATIC_QUERY_ID: bool>
     LoadQuery<'query, SqliteConnection, U, B>
     for (
         Yes,
@@ -628,6 +661,7 @@ impl<'query, V, T, QId, Op, U, B, Target, ConflictOpt, const STATIC_QUERY_ID: bo
                 BatchInsert<Vec<ValuesClause<V, T>>, T, QId, STATIC_QUERY_ID>,
                 Target,
                 ConflictOpt,
+                ConflictWhere,
             >,
             Op,
         >,
@@ -638,8 +672,12 @@ where
     Op: Copy,
     Target: Copy,
     ConflictOpt: Copy,
-    InsertStatement<T, OnConflictValues<ValuesClause<V, T>, Target, ConflictOpt>, Op>:
-        LoadQuery<'query, SqliteConnection, U, B>,
+    ConflictWhere: Copy,
+    InsertStatement<
+        T,
+        OnConflictValues<ValuesClause<V, T>, Target, ConflictOpt, ConflictWhere>,
+        Op,
+    >: LoadQuery<'query, SqliteConnection, U, B>,
     Self: RunQueryDsl<SqliteConnection>,
 {
     type RowIter<'conn> = alloc::vec::IntoIter<QueryResult<U>>;
@@ -664,10 +702,10 @@ where
                     into_clause: query.into_clause,
                 };
 
-                let result = stmt
-                    .internal_load(conn)?
-                    .next()
-                    .ok_or(crate::result::Error::NotFound)?;
+                // rows skipped by the conflict clause do not return anything
+                let Some(result) = stmt.internal_load(conn)?.next() else {
+                    continue;
+                };
 
                 match &result {
                     Ok(_) | Err(crate::result::Error::DeserializationError(_)) => {
@@ -685,8 +723,20 @@ where
 }
 
 #[diagnostic::do_not_recommend]
-impl<'query, V, T, QId, Op, Ret, U, B, Target, ConflictOpt, const STATIC_QUERY_ID: bool>
-    LoadQuery<'query, SqliteConnection, U, B>
+impl<
+    'query,
+    V,
+    T,
+    QId,
+    Op,
+    Ret,
+    U,
+    B,
+    Target,
+    ConflictOpt,
+    ConflictWhere,
+    const STATIC_QUERY_ID: bool,
+> LoadQuery<'query, SqliteConnection, U, B>
     for (
         Yes,
         InsertStatement<
@@ -695,6 +745,7 @@ impl<'query, V, T, QId, Op, Ret, U, B, Target, ConflictOpt, const STATIC_QUERY_I
                 BatchInsert<Vec<ValuesClause<V, T>>, T, QId, STATIC_QUERY_ID>,
                 Target,
                 ConflictOpt,
+                ConflictWhere,
             >,
             Op,
             ReturningClause<Ret>,
@@ -707,9 +758,10 @@ where
     ReturningClause<Ret>: Copy,
     Target: Copy,
     ConflictOpt: Copy,
+    ConflictWhere: Copy,
     InsertStatement<
         T,
-        OnConflictValues<ValuesClause<V, T>, Target, ConflictOpt>,
+        OnConflictValues<ValuesClause<V, T>, Target, ConflictOpt, ConflictWhere>,
         Op,
         ReturningClause<Ret>,
     >: LoadQuery<'query, SqliteConnection, U, B>,
@@ -737,10 +789,10 @@ where
                     into_clause: query.into_clause,
                 };
 
-                let result = stmt
-                    .internal_load(conn)?
-                    .next()
-                    .ok_or(crate::result::Error::NotFound)?;
+                // rows skipped by the conflict clause do not return anything
+                let Some(result) = stmt.internal_load(conn)?.next() else {
+                    continue;
+                };
 
                 match &result {
                     Ok(_) | Err(crate::result::Error::DeserializationError(_)) => {
@@ -856,12 +908,18 @@ where
     }
 }
 
-impl<V, T, QId, C, Op, Target, ConflictOpt, const STATIC_QUERY_ID: bool> ExecuteDsl<C, Sqlite>
+impl<V, T, QId, C, Op, Target, ConflictOpt, ConflictWhere, const STATIC_QUERY_ID: bool>
+    ExecuteDsl<C, Sqlite>
     for (
         No,
         InsertStatement<
             T,
-            OnConflictValues<BatchInsert<V, T, QId, STATIC_QUERY_ID>, Target, ConflictOpt>,
+            OnConflictValues<
+                BatchInsert<V, T, QId, STATIC_QUERY_ID>,
+                Target,
+                ConflictOpt,
+                ConflictWhere,
+            >,
             Op,
         >,
     )
@@ -870,8 +928,12 @@ where
     T: Table + QueryId + 'static,
     T::FromClause: QueryFragment<Sqlite>,
     Op: QueryFragment<Sqlite> + QueryId,
-    OnConflictValues<SqliteBatchInsertWrapper<V, T, QId, STATIC_QUERY_ID>, Target, ConflictOpt>:
-        QueryFragment<Sqlite> + CanInsertInSingleQuery<Sqlite> + QueryId,
+    OnConflictValues<
+        SqliteBatchInsertWrapper<V, T, QId, STATIC_QUERY_ID>,
+        Target,
+        ConflictOpt,
+        ConflictWhere,
+    >: QueryFragment<Sqlite> + CanInsertInSingleQuery<Sqlite> + QueryId,
 {
     fn execute((No, query): Self, conn: &mut C) -> QueryResult<usize> {
         let query = InsertStatement {
@@ -964,13 +1026,18 @@ where
 }
 
 #[diagnostic::do_not_recommend]
-impl<'query, V, T, QId, Op, U, B, Target, ConflictOpt, const STATIC_QUERY_ID: bool>
+impl<'query, V, T, QId, Op, U, B, Target, ConflictOpt, ConflictWhere, const STATIC_QUERY_ID: bool>
     LoadQuery<'query, SqliteConnection, U, B>
     for (
         No,
         InsertStatement<
             T,
-            OnConflictValues<BatchInsert<V, T, QId, STATIC_QUERY_ID>, Target, ConflictOpt>,
+            OnConflictValues<
+                BatchInsert<V, T, QId, STATIC_QUERY_ID>,
+                Target,
+                ConflictOpt,
+                ConflictWhere,
+            >,
             Op,
         >,
     )
@@ -978,14 +1045,24 @@ where
     T: Table + QueryId + 'static,
     InsertStatement<
         T,
-        OnConflictValues<SqliteBatchInsertWrapper<V, T, QId, STATIC_QUERY_ID>, Target, ConflictOpt>,
+        OnConflictValues<
+            SqliteBatchInsertWrapper<V, T, QId, STATIC_QUERY_ID>,
+            Target,
+            ConflictOpt,
+            ConflictWhere,
+        >,
         Op,
     >: LoadQuery<'query, SqliteConnection, U, B>,
     Self: RunQueryDsl<SqliteConnection>,
 {
     type RowIter<'conn> = <InsertStatement<
         T,
-        OnConflictValues<SqliteBatchInsertWrapper<V, T, QId, STATIC_QUERY_ID>, Target, ConflictOpt>,
+        OnConflictValues<
+            SqliteBatchInsertWrapper<V, T, QId, STATIC_QUERY_ID>,
+            Target,
+            ConflictOpt,
+            ConflictWhere,
+        >,
         Op,
     > as LoadQuery<'query, SqliteConnection, U, B>>::RowIter<'conn>;
 
@@ -1010,13 +1087,30 @@ where
 }
 
 #[diagnostic::do_not_recommend]
-impl<'query, V, T, QId, Op, Ret, U, B, Target, ConflictOpt, const STATIC_QUERY_ID: bool>
-    LoadQuery<'query, SqliteConnection, U, B>
+impl<
+    'query,
+    V,
+    T,
+    QId,
+    Op,
+    Ret,
+    U,
+    B,
+    Target,
+    ConflictOpt,
+    ConflictWhere,
+    const STATIC_QUERY_ID: bool,
+> LoadQuery<'query, SqliteConnection, U, B>
     for (
         No,
         InsertStatement<
             T,
-            OnConflictValues<BatchInsert<V, T, QId, STATIC_QUERY_ID>, Target, ConflictOpt>,
+            OnConflictValues<
+                BatchInsert<V, T, QId, STATIC_QUERY_ID>,
+                Target,
+                ConflictOpt,
+                ConflictWhere,
+            >,
             Op,
             ReturningClause<Ret>,
         >,
@@ -1025,7 +1119,12 @@ where
     T: Table + QueryId + 'static,
     InsertStatement<
         T,
-        OnConflictValues<SqliteBatchInsertWrapper<V, T, QId, STATIC_QUERY_ID>, Target, ConflictOpt>,
+        OnConflictValues<
+            SqliteBatchInsertWrapper<V, T, QId, STATIC_QUERY_ID>,
+            Target,
+            ConflictOpt,
+            ConflictWhere,
+        >,
         Op,
         ReturningClause<Ret>,
     >: LoadQuery<'query, SqliteConnection, U, B>,
@@ -1033,7 +1132,12 @@ where
 {
     type RowIter<'conn> = <InsertStatement<
         T,
-        OnConflictValues<SqliteBatchInsertWrapper<V, T, QId, STATIC_QUERY_ID>, Target, ConflictOpt>,
+        OnConflictValues<
+            SqliteBatchInsertWrapper<V, T, QId, STATIC_QUERY_ID>,
+            Target,
+            ConflictOpt,
+            ConflictWhere,
+        >,
         Op,
         ReturningClause<Ret>,
     > as LoadQuery<'query, SqliteConnection, U, B>>::RowIter<'conn>;
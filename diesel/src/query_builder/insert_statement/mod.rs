@@ -1,6 +1,7 @@
 pub(crate) mod batch_insert;
 mod column_list;
 mod insert_from_select;
+mod insert_with_children;
 
 pub(crate) use self::batch_insert::BatchInsert;
 pub(crate) use self::column_list::ColumnList;
@@ -11,7 +12,9 @@ pub(crate) use self::private::Insert;
 )]
 pub(crate) use self::private::{InsertOrIgnore, Replace};
 
+use crate::Connection;
 use crate::backend::{DieselReserveSpecialization, SqlDialect, sql_dialect};
+use crate::connection::StatementKind;
 use crate::expression::grouped::Grouped;
 use crate::expression::operators::Eq;
 use crate::expression::{Expression, NonAggregate, SelectableExpression};
@@ -20,6 +23,7 @@ use crate::query_builder::returning::{
 };
 use crate::query_builder::*;
 use crate::query_dsl::RunQueryDslSupport;
+use crate::query_dsl::methods::ExecuteDsl;
 use crate::query_source::{Column, Table};
 use crate::{QuerySource, insertable::*};
 use core::marker::PhantomData;
@@ -248,6 +252,7 @@ where
     Op: QueryFragment<DB>,
     Ret: QueryFragment<DB>,
 {
+    out.mark_statement_kind(StatementKind::Insert);
     if records.rows_to_insert() == Some(0) {
         out.push_sql("SELECT 1 FROM ");
         into_clause.walk_ast(out.reborrow())?;
@@ -349,6 +354,91 @@ impl<T: QuerySource, U, Op> InsertStatement<T, U, Op> {
     }
 }
 
+impl<T, V, QId, Op, const STATIC_QUERY_ID: bool>
+    InsertStatement<T, BatchInsert<Vec<V>, T, QId, STATIC_QUERY_ID>, Op>
+where
+    T: QuerySource + Copy,
+    Op: Copy,
+{
+    /// Executes this insert statement by splitting the inserted records
+    /// into several statements with at most `chunk_size` rows each
+    ///
+    /// Backends limit the number of bind parameters a single statement
+    /// can contain (65535 for PostgreSQL and MySQL, 32766 for SQLite, or 999
+    /// for SQLite versions older than 3.32). Inserting a large number of
+    /// records with a single statement therefore fails at runtime. Choosing a
+    /// `chunk_size` so that `chunk_size` times the number of inserted columns
+    /// stays below that limit avoids this error.
+    ///
+    /// All statements are executed inside of a single transaction, so either
+    /// all or none of the records are inserted. Returns the total number of
+    /// inserted rows. A `chunk_size` of `0` results in an error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let new_users = (0..100)
+    ///     .map(|i| name.eq(format!("User {i}")))
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let inserted_rows = diesel::insert_into(users)
+    ///     .values(new_users)
+    ///     .execute_batched(connection, 30)?;
+    /// assert_eq!(100, inserted_rows);
+    ///
+    /// let user_count = users.count().get_result::<i64>(connection)?;
+    /// assert_eq!(102, user_count);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn execute_batched<Conn>(self, conn: &mut Conn, chunk_size: usize) -> QueryResult<usize>
+    where
+        Conn: Connection,
+        InsertStatement<T, BatchInsert<Vec<V>, T, (), false>, Op>: ExecuteDsl<Conn>,
+    {
+        if chunk_size == 0 {
+            return Err(crate::result::Error::QueryBuilderError(
+                "The chunk size of a batched insert must be greater than 0".into(),
+            ));
+        }
+        let InsertStatement {
+            operator,
+            target,
+            records,
+            ..
+        } = self;
+        conn.transaction(|conn| {
+            let mut records = records.values.into_iter();
+            let mut inserted_rows = 0;
+            loop {
+                let chunk = records.by_ref().take(chunk_size).collect::<Vec<_>>();
+                if chunk.is_empty() {
+                    break;
+                }
+                // the number of rows differs between the chunks, so we cannot
+                // reuse the query id of the original statement
+                let query = InsertStatement::new(
+                    target,
+                    BatchInsert::<_, T, (), false>::new(chunk),
+                    operator,
+                    NoReturningClause,
+                );
+                inserted_rows += ExecuteDsl::execute(query, conn)?;
+            }
+            Ok(inserted_rows)
+        })
+    }
+}
+
 /// Marker trait to indicate that no additional operations have been added
 /// to a record for insert.
 ///
@@ -1,5 +1,7 @@
 use crate::backend::{Backend, DieselReserveSpecialization};
+use crate::query_builder::nodes::{Identifier, InfixNode, StaticQueryFragment};
 use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::query_source::{Alias, AliasSource, AppearsInFromClause, Once, Table};
 use crate::result::QueryResult;
 
 #[derive(Debug, Clone, Copy, QueryId)]
@@ -17,7 +19,7 @@ where
 #[derive(Debug, Clone, Copy, QueryId)]
 pub struct LockingClause<LockMode = ForUpdate, Modifier = NoModifier> {
     pub(crate) lock_mode: LockMode,
-    modifier: Modifier,
+    pub(crate) modifier: Modifier,
 }
 
 impl<LockMode, Modifier> LockingClause<LockMode, Modifier> {
@@ -65,3 +67,123 @@ pub struct SkipLocked;
 
 #[derive(Debug, Clone, Copy, QueryId)]
 pub struct NoWait;
+
+/// A lock mode that only locks the rows of the given tables
+/// (`FOR UPDATE OF table`)
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct LockOf<LockMode, Tables> {
+    lock_mode: LockMode,
+    tables: Tables,
+}
+
+impl<LockMode, Tables> LockOf<LockMode, Tables> {
+    pub(crate) fn new(lock_mode: LockMode, tables: Tables) -> Self {
+        LockOf { lock_mode, tables }
+    }
+}
+
+impl<DB, L, T> QueryFragment<DB> for LockOf<L, T>
+where
+    DB: Backend + DieselReserveSpecialization,
+    L: QueryFragment<DB>,
+    T: LockTarget,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        self.lock_mode.walk_ast(out.reborrow())?;
+        out.push_sql(" OF ");
+        self.tables.walk_lock_target(out)
+    }
+}
+
+/// Lock modes that can be restricted to some tables via
+/// [`QueryDsl::of`](crate::QueryDsl::of)
+pub trait UntargetedLockMode {}
+
+impl UntargetedLockMode for ForUpdate {}
+impl UntargetedLockMode for ForNoKeyUpdate {}
+impl UntargetedLockMode for ForShare {}
+impl UntargetedLockMode for ForKeyShare {}
+
+/// A table, alias or tuple of those that can be used as target of a
+/// `FOR UPDATE OF` clause
+pub trait LockTarget {
+    /// Writes the unqualified names of the targets
+    fn walk_lock_target<DB: Backend>(&self, out: AstPass<'_, '_, DB>) -> QueryResult<()>;
+}
+
+impl<T> LockTarget for T
+where
+    T: Table + StaticQueryFragment,
+    T::Component: TableNameComponent,
+{
+    fn walk_lock_target<DB: Backend>(&self, mut out: AstPass<'_, '_, DB>) -> QueryResult<()> {
+        // the `OF` clause only accepts unqualified table names
+        out.push_identifier(T::STATIC_COMPONENT.table_name())
+    }
+}
+
+impl<S: AliasSource> LockTarget for Alias<S> {
+    fn walk_lock_target<DB: Backend>(&self, mut out: AstPass<'_, '_, DB>) -> QueryResult<()> {
+        out.push_identifier(S::NAME)
+    }
+}
+
+/// The static component of a table, which contains the name of the table
+pub trait TableNameComponent {
+    /// The unqualified name of the table
+    fn table_name(&self) -> &str;
+}
+
+impl TableNameComponent for Identifier<'_> {
+    fn table_name(&self) -> &str {
+        self.0
+    }
+}
+
+impl<T, M> TableNameComponent for InfixNode<T, Identifier<'_>, M> {
+    fn table_name(&self) -> &str {
+        self.rhs.0
+    }
+}
+
+/// Checks that all targets of a `FOR UPDATE OF` clause appear exactly
+/// once in the `FROM` clause `QS`
+pub trait ValidLockTarget<QS> {}
+
+impl<T, QS> ValidLockTarget<QS> for T
+where
+    T: Table,
+    QS: AppearsInFromClause<T, Count = Once>,
+{
+}
+
+impl<S, QS> ValidLockTarget<QS> for Alias<S> where QS: AppearsInFromClause<Alias<S>, Count = Once> {}
+
+macro_rules! lock_target_tuples {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST:ident, $TT:ident,)*
+        }
+    )+) => {
+        $(
+            impl<$($T: LockTarget),*> LockTarget for ($($T,)*) {
+                fn walk_lock_target<DB: Backend>(
+                    &self,
+                    mut out: AstPass<'_, '_, DB>,
+                ) -> QueryResult<()> {
+                    $(
+                        if $idx != 0 {
+                            out.push_sql(", ");
+                        }
+                        self.$idx.walk_lock_target(out.reborrow())?;
+                    )*
+                    Ok(())
+                }
+            }
+
+            impl<QS, $($T: ValidLockTarget<QS>),*> ValidLockTarget<QS> for ($($T,)*) {}
+        )*
+    }
+}
+
+crate::for_each_tuple!(lock_target_tuples, 16);
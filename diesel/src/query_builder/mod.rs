@@ -13,13 +13,15 @@ pub(crate) mod ast_pass;
 pub mod bind_collector;
 mod collected_query;
 pub(crate) mod combination_clause;
-mod debug_query;
+pub(crate) mod debug_query;
 mod delete_statement;
 mod distinct_clause;
+#[cfg(any(feature = "postgres_backend", feature = "distinct_on_for_mysql_8"))]
+pub(crate) mod distinct_on;
 pub(crate) mod from_clause;
 pub(crate) mod functions;
 pub(crate) mod group_by_clause;
-mod having_clause;
+pub(crate) mod having_clause;
 pub(crate) mod insert_statement;
 pub(crate) mod limit_clause;
 pub(crate) mod limit_offset_clause;
@@ -27,12 +29,17 @@ pub(crate) mod locking_clause;
 pub(crate) mod nodes;
 pub(crate) mod offset_clause;
 pub(crate) mod order_clause;
+pub(crate) mod pagination;
 pub(crate) mod select_clause;
 pub(crate) mod select_statement;
 mod sql_query;
+mod statement_cache_mode;
 pub(crate) mod update_statement;
 pub(crate) mod upsert;
+pub(crate) mod using_clause;
+pub(crate) mod values_list;
 pub(crate) mod where_clause;
+pub(crate) mod with_clause;
 
 #[doc(inline)]
 pub use self::ast_pass::AstPass;
@@ -41,7 +48,7 @@ pub use self::bind_collector::{BindCollector, MoveableBindCollector};
 #[doc(inline)]
 pub use self::collected_query::CollectedQuery;
 #[doc(inline)]
-pub use self::debug_query::DebugQuery;
+pub use self::debug_query::{DebugQuery, Sensitive};
 #[doc(inline)]
 pub use self::delete_statement::{BoxedDeleteStatement, DeleteStatement};
 #[doc(inline)]
@@ -50,15 +57,26 @@ pub use self::insert_statement::{
     InsertOrIgnoreStatement, InsertStatement, ReplaceStatement,
 };
 #[doc(inline)]
+pub use self::pagination::{Paginated, PaginationCount};
+#[doc(inline)]
 pub use self::query_id::QueryId;
 #[doc(inline)]
 pub use self::sql_query::{BoxedSqlQuery, SqlQuery};
 #[doc(inline)]
+pub use self::statement_cache_mode::{StatementCacheMode, WithStatementCacheMode};
+#[doc(inline)]
 pub use self::upsert::into_conflict_clause::IntoConflictValueClause;
 #[doc(inline)]
 pub use self::upsert::on_conflict_target::{ConflictTarget, OnConflictTarget};
 #[doc(inline)]
 pub use self::upsert::on_conflict_target_decorations::DecoratableTarget;
+#[doc(inline)]
+pub use self::values_list::{IntoValuesRows, ValuesList, ValuesRow, ValuesRows};
+#[doc(inline)]
+pub use self::with_clause::{
+    CommonTableExpression, IncompleteRecursiveCte, IncompleteWithQuery, RecursiveCte,
+    RecursiveUnion, WithQuery,
+};
 
 #[doc(inline)]
 pub use self::update_statement::changeset::AsChangeset;
@@ -79,6 +97,8 @@ pub use self::limit_offset_clause::{BoxedLimitOffsetClause, LimitOffsetClause};
 pub use self::offset_clause::{NoOffsetClause, OffsetClause};
 #[cfg(feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes")]
 pub use self::order_clause::{NoOrderClause, OrderClause};
+#[cfg(feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes")]
+pub use self::using_clause::{NoUsingClause, UsingClause};
 
 #[diesel_derives::__diesel_public_if(
     feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
@@ -129,6 +149,7 @@ pub(crate) use self::select_statement::BoxedSelectStatement;
 pub(crate) use self::select_statement::SelectStatement;
 
 pub(crate) use self::insert_statement::ColumnList;
+pub(crate) use self::with_clause::CteColumnList;
 
 #[cfg(feature = "postgres_backend")]
 pub use crate::pg::query_builder::only::Only;
@@ -25,8 +25,8 @@ where
     T: StaticQueryFragment,
     T::Component: QueryFragment<DB>,
 {
-    fn walk_ast<'b>(&'b self, pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
-        T::STATIC_COMPONENT.walk_ast(pass)
+    fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        pass.walk_table_name(|pass| T::STATIC_COMPONENT.walk_ast(pass))
     }
 }
 
@@ -0,0 +1,223 @@
+//! Pagination of queries with a total count
+
+use alloc::vec::Vec;
+
+use crate::backend::Backend;
+use crate::connection::Connection;
+use crate::query_builder::{AstPass, Query, QueryFragment, QueryId};
+use crate::query_dsl::methods::LoadQuery;
+use crate::query_dsl::{RunQueryDsl, RunQueryDslSupport};
+use crate::result::QueryResult;
+use crate::sql_types::BigInt;
+
+/// How [`Paginated::load_and_count`] determines the total number of rows
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum PaginationCount {
+    /// Load the requested page and count all rows in a second query
+    ///
+    /// This is supported by all backends.
+    #[default]
+    SeparateQuery,
+    /// Load the requested page together with the total count via
+    /// `COUNT(*) OVER ()` in a single query
+    ///
+    /// This wraps the query in a subquery. The order of the rows returned
+    /// by the subquery is kept by PostgreSQL and SQLite, but MySQL might
+    /// ignore it, so this should not be used with MySQL.
+    WindowFunction,
+}
+
+/// A query split into pages
+///
+/// This type is constructed by
+/// [`PaginateDsl::paginate`](crate::query_dsl::PaginateDsl::paginate()).
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load_and_count`"]
+pub struct Paginated<Q> {
+    query: Q,
+    per_page: i64,
+    offset: i64,
+    count: PaginationCount,
+}
+
+impl<Q> Paginated<Q> {
+    pub(crate) fn new(query: Q, page: i64, per_page: i64) -> Self {
+        let per_page = per_page.max(0);
+        Self {
+            query,
+            per_page,
+            offset: (page.max(1) - 1).saturating_mul(per_page),
+            count: PaginationCount::default(),
+        }
+    }
+
+    /// Set how the total number of rows is determined
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// use diesel::query_builder::PaginationCount;
+    /// use diesel::query_dsl::PaginateDsl;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "mysql"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let (names, total) = users
+    ///     .select(name)
+    ///     .order(id)
+    ///     .paginate(1, 1)
+    ///     .count_with(PaginationCount::WindowFunction)
+    ///     .load_and_count::<String, _>(connection)?;
+    /// assert_eq!(vec!["Sean"], names);
+    /// assert_eq!(2, total);
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(feature = "mysql")]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn count_with(self, count: PaginationCount) -> Self {
+        Self { count, ..self }
+    }
+
+    /// Load the requested page together with the total
+    /// number of rows returned by the query
+    ///
+    /// See [`PaginateDsl::paginate`](crate::query_dsl::PaginateDsl::paginate())
+    /// for an example.
+    pub fn load_and_count<'query, U, Conn>(
+        &'query self,
+        conn: &mut Conn,
+    ) -> QueryResult<(Vec<U>, i64)>
+    where
+        Conn: Connection,
+        PaginatedPage<&'query Q>: LoadQuery<'query, Conn, U>,
+        WindowCountedPage<&'query Q>: LoadQuery<'query, Conn, (U, i64)>,
+        PaginationCountQuery<&'query Q>: LoadQuery<'query, Conn, i64>,
+    {
+        let count = PaginationCountQuery { query: &self.query };
+        match self.count {
+            PaginationCount::SeparateQuery => {
+                let records = PaginatedPage {
+                    query: &self.query,
+                    per_page: self.per_page,
+                    offset: self.offset,
+                }
+                .load(conn)?;
+                let total = count.get_result(conn)?;
+                Ok((records, total))
+            }
+            PaginationCount::WindowFunction => {
+                let rows = WindowCountedPage {
+                    query: &self.query,
+                    per_page: self.per_page,
+                    offset: self.offset,
+                }
+                .load::<(U, i64)>(conn)?;
+                let total = match rows.first() {
+                    Some((_, total)) => *total,
+                    // pages after the last page don't contain the count
+                    None if self.offset > 0 => count.get_result(conn)?,
+                    None => 0,
+                };
+                Ok((rows.into_iter().map(|(record, _)| record).collect(), total))
+            }
+        }
+    }
+}
+
+/// The query loading a single page of a [`Paginated`] query
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct PaginatedPage<Q> {
+    query: Q,
+    per_page: i64,
+    offset: i64,
+}
+
+impl<Q: Query> Query for PaginatedPage<Q> {
+    type SqlType = Q::SqlType;
+}
+
+impl<Q, DB> QueryFragment<DB> for PaginatedPage<Q>
+where
+    DB: Backend,
+    Q: QueryFragment<DB>,
+    i64: crate::serialize::ToSql<BigInt, DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        self.query.walk_ast(out.reborrow())?;
+        out.push_sql(" LIMIT ");
+        out.push_bind_param::<BigInt, _>(&self.per_page)?;
+        out.push_sql(" OFFSET ");
+        out.push_bind_param::<BigInt, _>(&self.offset)?;
+        Ok(())
+    }
+}
+
+impl<Q> RunQueryDslSupport for PaginatedPage<Q> {}
+
+/// The query loading a single page of a [`Paginated`] query
+/// together with the total count via `COUNT(*) OVER ()`
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct WindowCountedPage<Q> {
+    query: Q,
+    per_page: i64,
+    offset: i64,
+}
+
+impl<Q: Query> Query for WindowCountedPage<Q> {
+    type SqlType = (Q::SqlType, BigInt);
+}
+
+impl<Q, DB> QueryFragment<DB> for WindowCountedPage<Q>
+where
+    DB: Backend,
+    Q: QueryFragment<DB>,
+    i64: crate::serialize::ToSql<BigInt, DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        out.push_sql("SELECT paginated_query.*, COUNT(*) OVER () FROM (");
+        self.query.walk_ast(out.reborrow())?;
+        out.push_sql(") AS paginated_query LIMIT ");
+        out.push_bind_param::<BigInt, _>(&self.per_page)?;
+        out.push_sql(" OFFSET ");
+        out.push_bind_param::<BigInt, _>(&self.offset)?;
+        Ok(())
+    }
+}
+
+impl<Q> RunQueryDslSupport for WindowCountedPage<Q> {}
+
+/// The query counting all rows of a [`Paginated`] query
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct PaginationCountQuery<Q> {
+    query: Q,
+}
+
+impl<Q> Query for PaginationCountQuery<Q> {
+    type SqlType = BigInt;
+}
+
+impl<Q, DB> QueryFragment<DB> for PaginationCountQuery<Q>
+where
+    DB: Backend,
+    Q: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        out.push_sql("SELECT COUNT(*) FROM (");
+        self.query.walk_ast(out.reborrow())?;
+        out.push_sql(") AS paginated_query");
+        Ok(())
+    }
+}
+
+impl<Q> RunQueryDslSupport for PaginationCountQuery<Q> {}
@@ -61,6 +61,24 @@ where
     }
 }
 
+/// Indicates that `Self` can be used as `RETURNING` clause of an
+/// `UPDATE` statement for the backend `DB`
+///
+/// Not all backends supporting `RETURNING` clauses support them for
+/// `UPDATE` statements, see
+/// [`SupportsReturningClauseForUpdate`](crate::backend::sql_dialect::returning_clause::SupportsReturningClauseForUpdate)
+pub trait UpdateReturningClause<DB> {}
+
+impl<DB> UpdateReturningClause<DB> for NoReturningClause {}
+
+impl<Expr, DB> UpdateReturningClause<DB> for ReturningClause<Expr>
+where
+    DB: Backend,
+    DB::ReturningClause:
+        crate::backend::sql_dialect::returning_clause::SupportsReturningClauseForUpdate,
+{
+}
+
 /// Helper trait that maps an `INSERT`/`UPDATE`/`DELETE` statement type to
 /// the same statement type with an explicit [`ReturningClause<S>`] attached.
 ///
@@ -78,16 +96,16 @@ where
     type WithReturning = InsertStatement<T, U, Op, ReturningClause<S>>;
 }
 
-impl<S, T, U, V> ReturningClauseHelper<S> for UpdateStatement<T, U, V>
+impl<S, T, U, V, F> ReturningClauseHelper<S> for UpdateStatement<T, U, V, NoReturningClause, F>
 where
     T: QuerySource,
 {
-    type WithReturning = UpdateStatement<T, U, V, ReturningClause<S>>;
+    type WithReturning = UpdateStatement<T, U, V, ReturningClause<S>, F>;
 }
 
-impl<S, T, U> ReturningClauseHelper<S> for DeleteStatement<T, U>
+impl<S, T, U, F> ReturningClauseHelper<S> for DeleteStatement<T, U, NoReturningClause, F>
 where
     T: QuerySource,
 {
-    type WithReturning = DeleteStatement<T, U, ReturningClause<S>>;
+    type WithReturning = DeleteStatement<T, U, ReturningClause<S>, F>;
 }
@@ -1,6 +1,7 @@
 use core::marker::PhantomData;
 
 use crate::backend::{DieselReserveSpecialization, sql_dialect};
+use crate::connection::StatementKind;
 use crate::dsl::AsExprOf;
 use crate::expression::subselect::ValidSubselect;
 use crate::expression::*;
@@ -205,7 +206,8 @@ where
     DB: Backend,
     Self: QueryFragment<DB, DB::SelectStatementSyntax>,
 {
-    fn walk_ast<'b>(&'b self, pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+    fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        pass.mark_statement_kind(StatementKind::Select);
         <Self as QueryFragment<DB, DB::SelectStatementSyntax>>::walk_ast(self, pass)
     }
 }
@@ -566,6 +566,43 @@ impl<F, S, D, W, O, LOf, G, H, LC, LM, Modifier> ModifyLockDsl<Modifier>
     }
 }
 
+impl<F, S, D, W, O, LOf, G, H, LC, LM, Tables> LockTargetDsl<Tables>
+    for SelectStatement<FromClause<F>, S, D, W, O, LOf, G, H, LockingClause<LC, LM>>
+where
+    F: QuerySource,
+    LC: UntargetedLockMode,
+    Tables: LockTarget + ValidLockTarget<F>,
+{
+    type Output = SelectStatement<
+        FromClause<F>,
+        S,
+        D,
+        W,
+        O,
+        LOf,
+        G,
+        H,
+        LockingClause<LockOf<LC, Tables>, LM>,
+    >;
+
+    fn lock_of(self, tables: Tables) -> Self::Output {
+        SelectStatement::new(
+            self.select,
+            self.from,
+            self.distinct,
+            self.where_clause,
+            self.order,
+            self.limit_offset,
+            self.group_by,
+            self.having,
+            LockingClause::new(
+                LockOf::new(self.locking.lock_mode, tables),
+                self.locking.modifier,
+            ),
+        )
+    }
+}
+
 impl<'a, F, S, D, W, O, LOf, G, H, DB> BoxedDsl<'a, DB>
     for SelectStatement<FromClause<F>, S, D, W, O, LOf, G, H>
 where
@@ -31,6 +31,7 @@ use super::select_clause::*;
 use super::where_clause::*;
 use super::{AstPass, Query, QueryFragment};
 use crate::backend::{Backend, sql_dialect};
+use crate::connection::StatementKind;
 use crate::expression::subselect::ValidSubselect;
 use crate::expression::*;
 use crate::query_builder::having_clause::NoHavingClause;
@@ -259,7 +260,8 @@ where
     DB: Backend,
     Self: QueryFragment<DB, DB::SelectStatementSyntax>,
 {
-    fn walk_ast<'b>(&'b self, pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+    fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        pass.mark_statement_kind(StatementKind::Select);
         <Self as QueryFragment<DB, DB::SelectStatementSyntax>>::walk_ast(self, pass)
     }
 }
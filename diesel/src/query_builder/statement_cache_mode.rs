@@ -0,0 +1,71 @@
+//! Per query overrides of the prepared statement cache
+
+use crate::backend::Backend;
+use crate::query_builder::{AstPass, Query, QueryFragment, QueryId};
+use crate::query_dsl::RunQueryDslSupport;
+use crate::result::QueryResult;
+
+/// Controls whether a single query is stored in the prepared statement cache
+///
+/// See [`StatementCacheDsl`](crate::query_dsl::StatementCacheDsl) for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StatementCacheMode {
+    /// Store the prepared statement in the cache, even if
+    /// caching is disabled for the connection via
+    /// [`CacheSize::Disabled`](crate::connection::CacheSize::Disabled)
+    PrepareForCache,
+    /// Never store the prepared statement in the cache
+    ExecuteUnprepared,
+}
+
+/// A query with an overridden [`StatementCacheMode`]
+///
+/// This type is constructed by the methods of
+/// [`StatementCacheDsl`](crate::query_dsl::StatementCacheDsl).
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct WithStatementCacheMode<Q> {
+    query: Q,
+    mode: StatementCacheMode,
+}
+
+impl<Q> WithStatementCacheMode<Q> {
+    pub(crate) fn new(query: Q, mode: StatementCacheMode) -> Self {
+        Self { query, mode }
+    }
+}
+
+impl<Q> Query for WithStatementCacheMode<Q>
+where
+    Q: Query,
+{
+    type SqlType = Q::SqlType;
+}
+
+impl<Q, DB> QueryFragment<DB> for WithStatementCacheMode<Q>
+where
+    DB: Backend,
+    Q: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        match self.mode {
+            StatementCacheMode::PrepareForCache => out.force_cache_prepared(),
+            StatementCacheMode::ExecuteUnprepared => out.unsafe_to_cache_prepared(),
+        }
+        self.query.walk_ast(out)
+    }
+}
+
+// The mode does not change the generated SQL, so the query
+// shares its prepared statement with the unwrapped query
+impl<Q> QueryId for WithStatementCacheMode<Q>
+where
+    Q: QueryId,
+{
+    type QueryId = Q::QueryId;
+
+    const HAS_STATIC_QUERY_ID: bool = Q::HAS_STATIC_QUERY_ID;
+}
+
+impl<Q> RunQueryDslSupport for WithStatementCacheMode<Q> {}
@@ -122,10 +122,15 @@ pub struct ColumnWrapperForUpdate<C>(pub C);
 impl<DB, C> QueryFragment<DB> for ColumnWrapperForUpdate<C>
 where
     DB: Backend + DieselReserveSpecialization,
-    C: Column,
+    C: Column + QueryFragment<DB>,
 {
     fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
-        out.push_identifier(C::NAME)
+        out.redact_following_binds(C::SENSITIVE);
+        if out.should_qualify_update_targets() {
+            self.0.walk_ast(out)
+        } else {
+            out.push_identifier(C::NAME)
+        }
     }
 }
 
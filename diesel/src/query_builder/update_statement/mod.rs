@@ -3,18 +3,22 @@ pub(super) mod target;
 
 use crate::QuerySource;
 use crate::backend::DieselReserveSpecialization;
+use crate::connection::StatementKind;
 use crate::dsl::{Filter, IntoBoxed};
 use crate::expression::{
     AppearsOnTable, Expression, MixedAggregates, SelectableExpression, ValidGrouping, is_aggregate,
 };
 use crate::query_builder::returning::{
-    NoReturningClause, ReturningClause, ReturningQuerySource, UpdateStmt,
+    NoReturningClause, ReturningClause, ReturningQuerySource, UpdateReturningClause, UpdateStmt,
+};
+use crate::query_builder::using_clause::{
+    NoUsingClause, UpdateFromClauseFragment, UsingClause, UsingClauseQuerySource,
 };
 use crate::query_builder::where_clause::*;
 use crate::query_builder::*;
 use crate::query_dsl::RunQueryDslSupport;
 use crate::query_dsl::methods::{BoxedDsl, FilterDsl};
-use crate::query_source::Table;
+use crate::query_source::{AppearsInFromClause, Never, Table};
 use crate::result::EmptyChangeset;
 use crate::result::Error::QueryBuilderError;
 
@@ -27,9 +31,12 @@ impl<T: QuerySource, U> UpdateStatement<T, U, SetNotCalled> {
             where_clause: target.where_clause,
             values: SetNotCalled,
             returning: NoReturningClause,
+            using_clause: NoUsingClause,
         }
     }
+}
 
+impl<T: QuerySource, U, F> UpdateStatement<T, U, SetNotCalled, NoReturningClause, F> {
     /// Provides the `SET` clause of the `UPDATE` statement.
     ///
     /// See [`update`](crate::update()) for usage examples, or [the update
@@ -39,13 +46,14 @@ impl<T: QuerySource, U> UpdateStatement<T, U, SetNotCalled> {
     where
         T: Table,
         V: changeset::AsChangeset<Target = T>,
-        UpdateStatement<T, U, V::Changeset>: AsQuery,
+        UpdateStatement<T, U, V::Changeset, NoReturningClause, F>: AsQuery,
     {
         UpdateStatement {
             from_clause: self.from_clause,
             where_clause: self.where_clause,
             values: values.as_changeset(),
             returning: self.returning,
+            using_clause: self.using_clause,
         }
     }
 }
@@ -57,18 +65,31 @@ impl<T: QuerySource, U> UpdateStatement<T, U, SetNotCalled> {
 /// See [`update`](crate::update()) for usage examples, or [the update
 /// guide](https://diesel.rs/guides/all-about-updates/) for a more exhaustive
 /// set of examples.
-pub struct UpdateStatement<T: QuerySource, U, V = SetNotCalled, Ret = NoReturningClause> {
+pub struct UpdateStatement<
+    T: QuerySource,
+    U,
+    V = SetNotCalled,
+    Ret = NoReturningClause,
+    F = NoUsingClause,
+> {
     from_clause: T::FromClause,
     where_clause: U,
     values: V,
     returning: Ret,
+    using_clause: F,
 }
 
 /// An `UPDATE` statement with a boxed `WHERE` clause.
-pub type BoxedUpdateStatement<'a, DB, T, V = SetNotCalled, Ret = NoReturningClause> =
-    UpdateStatement<T, BoxedWhereClause<'a, DB>, V, Ret>;
+pub type BoxedUpdateStatement<
+    'a,
+    DB,
+    T,
+    V = SetNotCalled,
+    Ret = NoReturningClause,
+    F = NoUsingClause,
+> = UpdateStatement<T, BoxedWhereClause<'a, DB>, V, Ret, F>;
 
-impl<T: QuerySource, U, V, Ret> UpdateStatement<T, U, V, Ret> {
+impl<T: QuerySource, U, V, Ret, F> UpdateStatement<T, U, V, Ret, F> {
     /// Adds the given predicate to the `WHERE` clause of the statement being
     /// constructed.
     ///
@@ -154,13 +175,14 @@ impl<T: QuerySource, U, V, Ret> UpdateStatement<T, U, V, Ret> {
     }
 }
 
-impl<T, U, V, Ret, Predicate> FilterDsl<Predicate> for UpdateStatement<T, U, V, Ret>
+impl<T, U, V, Ret, F, Predicate> FilterDsl<Predicate> for UpdateStatement<T, U, V, Ret, F>
 where
     T: QuerySource,
     U: WhereAnd<Predicate>,
-    Predicate: AppearsOnTable<T>,
+    F: UsingClauseQuerySource<T>,
+    Predicate: AppearsOnTable<F::QuerySource>,
 {
-    type Output = UpdateStatement<T, U::Output, V, Ret>;
+    type Output = UpdateStatement<T, U::Output, V, Ret, F>;
 
     fn filter(self, predicate: Predicate) -> Self::Output {
         UpdateStatement {
@@ -168,16 +190,17 @@ where
             where_clause: self.where_clause.and(predicate),
             values: self.values,
             returning: self.returning,
+            using_clause: self.using_clause,
         }
     }
 }
 
-impl<'a, T, U, V, Ret, DB> BoxedDsl<'a, DB> for UpdateStatement<T, U, V, Ret>
+impl<'a, T, U, V, Ret, F, DB> BoxedDsl<'a, DB> for UpdateStatement<T, U, V, Ret, F>
 where
     T: QuerySource,
     U: Into<BoxedWhereClause<'a, DB>>,
 {
-    type Output = BoxedUpdateStatement<'a, DB, T, V, Ret>;
+    type Output = BoxedUpdateStatement<'a, DB, T, V, Ret, F>;
 
     fn internal_into_boxed(self) -> Self::Output {
         UpdateStatement {
@@ -185,18 +208,20 @@ where
             where_clause: self.where_clause.into(),
             values: self.values,
             returning: self.returning,
+            using_clause: self.using_clause,
         }
     }
 }
 
-impl<T, U, V, Ret, DB> QueryFragment<DB> for UpdateStatement<T, U, V, Ret>
+impl<T, U, V, Ret, F, DB> QueryFragment<DB> for UpdateStatement<T, U, V, Ret, F>
 where
     DB: Backend + DieselReserveSpecialization,
     T: Table,
     T::FromClause: QueryFragment<DB>,
     U: QueryFragment<DB>,
     V: QueryFragment<DB>,
-    Ret: QueryFragment<DB>,
+    Ret: QueryFragment<DB> + UpdateReturningClause<DB>,
+    F: UpdateFromClauseFragment<DB>,
 {
     fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
         if self.values.is_noop(out.backend())? {
@@ -204,17 +229,20 @@ where
         }
 
         out.unsafe_to_cache_prepared();
+        out.mark_statement_kind(StatementKind::Update);
         out.push_sql("UPDATE ");
         self.from_clause.walk_ast(out.reborrow())?;
+        self.using_clause.walk_target_tables(out.reborrow())?;
         out.push_sql(" SET ");
         self.values.walk_ast(out.reborrow())?;
+        self.using_clause.walk_from_clause(out.reborrow())?;
         self.where_clause.walk_ast(out.reborrow())?;
         self.returning.walk_ast(out.reborrow())?;
         Ok(())
     }
 }
 
-impl<T, U, V, Ret> QueryId for UpdateStatement<T, U, V, Ret>
+impl<T, U, V, Ret, F> QueryId for UpdateStatement<T, U, V, Ret, F>
 where
     T: QuerySource,
 {
@@ -223,23 +251,23 @@ where
     const HAS_STATIC_QUERY_ID: bool = false;
 }
 
-impl<T, U, V> AsQuery for UpdateStatement<T, U, V, NoReturningClause>
+impl<T, U, V, F> AsQuery for UpdateStatement<T, U, V, NoReturningClause, F>
 where
     T: Table,
-    UpdateStatement<T, U, V, ReturningClause<T::AllColumns>>: Query,
+    UpdateStatement<T, U, V, ReturningClause<T::AllColumns>, F>: Query,
     T::AllColumns: SelectableExpression<ReturningQuerySource<UpdateStmt, T>> + ValidGrouping<()>,
     <T::AllColumns as ValidGrouping<()>>::IsAggregate:
         MixedAggregates<is_aggregate::No, Output = is_aggregate::No>,
 {
     type SqlType = <Self::Query as Query>::SqlType;
-    type Query = UpdateStatement<T, U, V, ReturningClause<T::AllColumns>>;
+    type Query = UpdateStatement<T, U, V, ReturningClause<T::AllColumns>, F>;
 
     fn as_query(self) -> Self::Query {
         self.returning(T::all_columns())
     }
 }
 
-impl<T, U, V, Ret> Query for UpdateStatement<T, U, V, ReturningClause<Ret>>
+impl<T, U, V, Ret, F> Query for UpdateStatement<T, U, V, ReturningClause<Ret>, F>
 where
     T: Table,
     Ret: SelectableExpression<ReturningQuerySource<UpdateStmt, T>> + ValidGrouping<()>,
@@ -248,9 +276,9 @@ where
     type SqlType = <Ret as Expression>::SqlType;
 }
 
-impl<T: QuerySource, U, V, Ret> RunQueryDslSupport for UpdateStatement<T, U, V, Ret> {}
+impl<T: QuerySource, U, V, Ret, F> RunQueryDslSupport for UpdateStatement<T, U, V, Ret, F> {}
 
-impl<T: QuerySource, U, V> UpdateStatement<T, U, V, NoReturningClause> {
+impl<T: QuerySource, U, V, F> UpdateStatement<T, U, V, NoReturningClause, F> {
     /// Specify what expression is returned after execution of the `update`.
     /// # Examples
     ///
@@ -301,16 +329,78 @@ impl<T: QuerySource, U, V> UpdateStatement<T, U, V, NoReturningClause> {
     /// # #[cfg(not(feature = "postgres"))]
     /// # fn main() {}
     /// ```
-    pub fn returning<E>(self, returns: E) -> UpdateStatement<T, U, V, ReturningClause<E>>
+    pub fn returning<E>(self, returns: E) -> UpdateStatement<T, U, V, ReturningClause<E>, F>
     where
         T: Table,
-        UpdateStatement<T, U, V, ReturningClause<E>>: Query,
+        UpdateStatement<T, U, V, ReturningClause<E>, F>: Query,
     {
         UpdateStatement {
             from_clause: self.from_clause,
             where_clause: self.where_clause,
             values: self.values,
             returning: ReturningClause(returns),
+            using_clause: self.using_clause,
+        }
+    }
+}
+
+impl<T: QuerySource, U, V> UpdateStatement<T, U, V, NoReturningClause, NoUsingClause> {
+    /// Adds another table to this `UPDATE` statement
+    ///
+    /// This allows the `WHERE` clause of the statement to reference columns
+    /// of the given table, without falling back to a correlated subquery.
+    /// The conditions relating both tables need to be provided via
+    /// [`filter`](UpdateStatement::filter()). Both tables need to be allowed
+    /// to appear in the same query via
+    /// [`allow_tables_to_appear_in_same_query!`](crate::allow_tables_to_appear_in_same_query!).
+    ///
+    /// The generated SQL depends on the backend. PostgreSQL and SQLite use
+    /// `UPDATE target SET … FROM other WHERE …`, while MySQL uses a multi-table
+    /// update of the form `UPDATE target, other SET … WHERE …`.
+    /// Updating rows via a `FROM` clause requires SQLite 3.33 or newer.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::{posts, users};
+    /// #     let connection = &mut establish_connection();
+    /// let updated_rows = diesel::update(posts::table)
+    ///     .from(users::table)
+    ///     .filter(posts::user_id.eq(users::id))
+    ///     .filter(users::name.eq("Sean"))
+    ///     .set(posts::title.eq("Written by Sean"))
+    ///     .execute(connection)?;
+    /// assert_eq!(2, updated_rows);
+    ///
+    /// let titles = posts::table
+    ///     .select(posts::title)
+    ///     .order(posts::id)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(
+    ///     vec!["Written by Sean", "Written by Sean", "My first post too"],
+    ///     titles
+    /// );
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn from<O>(self, source: O) -> UpdateStatement<T, U, V, NoReturningClause, UsingClause<O>>
+    where
+        O: QuerySource + AppearsInFromClause<T, Count = Never>,
+        T: AppearsInFromClause<O, Count = Never>,
+    {
+        UpdateStatement {
+            from_clause: self.from_clause,
+            where_clause: self.where_clause,
+            values: self.values,
+            returning: self.returning,
+            using_clause: UsingClause::new(source),
         }
     }
 }
@@ -330,11 +420,24 @@ mod private {
         type Out;
     }
 
-    impl<T, W, Changes> SetAutoTypeHelper<Changes> for crate::query_builder::UpdateStatement<T, W>
+    impl<T, W, F, Changes> SetAutoTypeHelper<Changes>
+        for crate::query_builder::UpdateStatement<
+            T,
+            W,
+            crate::query_builder::update_statement::SetNotCalled,
+            crate::query_builder::returning::NoReturningClause,
+            F,
+        >
     where
         T: crate::QuerySource,
         Changes: crate::AsChangeset,
     {
-        type Out = crate::query_builder::UpdateStatement<T, W, Changes::Changeset>;
+        type Out = crate::query_builder::UpdateStatement<
+            T,
+            W,
+            Changes::Changeset,
+            crate::query_builder::returning::NoReturningClause,
+            F,
+        >;
     }
 }
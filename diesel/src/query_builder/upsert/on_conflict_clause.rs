@@ -97,15 +97,16 @@ where
     }
 }
 
-impl<DB, Values, Target, Action, Expr> QueryFragment<DB>
-    for OnConflictValues<Values, Target, Action, WhereClause<Expr>>
+impl<DB, Values, Target, Changes, Tab, Expr> QueryFragment<DB>
+    for OnConflictValues<Values, Target, DoUpdate<Changes, Tab>, WhereClause<Expr>>
 where
     DB: Backend,
     DB::OnConflictClause: sql_dialect::on_conflict_clause::SupportsOnConflictClause,
     DB::OnConflictClause: sql_dialect::on_conflict_clause::SupportsOnConflictClauseWhere,
     Values: QueryFragment<DB>,
     Target: QueryFragment<DB>,
-    Action: QueryFragment<DB>,
+    Changes: QueryFragment<DB>,
+    DoUpdate<Changes, Tab>: QueryFragment<DB>,
     WhereClause<Expr>: QueryFragment<DB>,
 {
     fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
@@ -113,7 +114,11 @@ where
         out.push_sql(" ON CONFLICT");
         self.target.walk_ast(out.reborrow())?;
         self.action.walk_ast(out.reborrow())?;
-        self.where_clause.walk_ast(out.reborrow())?;
+        // An empty changeset is written as `DO NOTHING`,
+        // which does not accept a `WHERE` clause
+        if !self.action.changeset.is_noop(out.backend())? {
+            self.where_clause.walk_ast(out.reborrow())?;
+        }
         Ok(())
     }
 }
@@ -1,14 +1,32 @@
 use crate::backend::Backend;
-use crate::expression::Expression;
+use crate::expression::{Expression, SqlLiteral};
 use crate::query_builder::upsert::on_conflict_target::{ConflictTarget, NoConflictTarget};
 use crate::query_builder::where_clause::{NoWhereClause, WhereAnd, WhereClause};
 use crate::query_builder::{AstPass, QueryFragment, QueryResult};
+use crate::query_source::Column;
 use crate::sql_types::BoolOrNullableBool;
 
 pub trait UndecoratedConflictTarget {}
 
+// `ON CONFLICT ON CONSTRAINT name` cannot be combined with a `WHERE` predicate,
+// so only column lists and literal targets can be decorated
 impl UndecoratedConflictTarget for NoConflictTarget {}
-impl<T> UndecoratedConflictTarget for ConflictTarget<T> {}
+impl<T: Column> UndecoratedConflictTarget for ConflictTarget<T> {}
+impl<ST> UndecoratedConflictTarget for ConflictTarget<SqlLiteral<ST>> {}
+
+macro_rules! undecorated_conflict_target_tuples {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST:ident, $TT:ident,)*
+        }
+    )+) => {
+        $(
+            impl<$($T: Column),*> UndecoratedConflictTarget for ConflictTarget<($($T,)*)> {}
+        )*
+    }
+}
+
+crate::for_each_tuple!(undecorated_conflict_target_tuples);
 
 /// Adds a `WHERE` predicate to an `ON CONFLICT` target.
 ///
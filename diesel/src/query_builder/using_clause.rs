@@ -0,0 +1,246 @@
+use crate::backend::Backend;
+use crate::query_builder::{AstPass, QueryFragment, QueryId};
+use crate::query_source::QuerySource;
+use crate::query_source::joins::{Inner, Join};
+use crate::result::QueryResult;
+
+/// Indicates that an `UPDATE` or `DELETE` statement does not reference
+/// any other table than its target table
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct NoUsingClause;
+
+/// Represents the additional table referenced by an `UPDATE … FROM` or
+/// `DELETE … USING` statement
+///
+/// See [`UpdateStatement::from`](crate::query_builder::UpdateStatement::from())
+/// and [`DeleteStatement::using`](crate::query_builder::DeleteStatement::using())
+/// for details.
+pub struct UsingClause<F: QuerySource> {
+    from_clause: F::FromClause,
+}
+
+impl<F: QuerySource> UsingClause<F> {
+    pub(crate) fn new(source: F) -> Self {
+        Self {
+            from_clause: source.from_clause(),
+        }
+    }
+}
+
+impl<F> Clone for UsingClause<F>
+where
+    F: QuerySource,
+    F::FromClause: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            from_clause: self.from_clause.clone(),
+        }
+    }
+}
+
+impl<F> Copy for UsingClause<F>
+where
+    F: QuerySource,
+    F::FromClause: Copy,
+{
+}
+
+impl<F> core::fmt::Debug for UsingClause<F>
+where
+    F: QuerySource,
+    F::FromClause: core::fmt::Debug,
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("UsingClause")
+            .field("from_clause", &self.from_clause)
+            .finish()
+    }
+}
+
+/// The query source used to type check the `WHERE` clause of an
+/// `UPDATE`/`DELETE` statement with the target table `T`
+///
+/// For statements referencing an additional table this is an inner join of
+/// both tables, which requires them to be allowed to appear in the same query
+/// via [`allow_tables_to_appear_in_same_query!`](crate::allow_tables_to_appear_in_same_query!)
+pub trait UsingClauseQuerySource<T> {
+    /// The query source expressions are checked against
+    type QuerySource;
+}
+
+impl<T> UsingClauseQuerySource<T> for NoUsingClause {
+    type QuerySource = T;
+}
+
+impl<T, F> UsingClauseQuerySource<T> for UsingClause<F>
+where
+    T: QuerySource,
+    F: QuerySource,
+{
+    type QuerySource = Join<T, F, Inner>;
+}
+
+/// Emits the additional tables of an `UPDATE` statement
+///
+/// PostgreSQL and SQLite list them in a `FROM` clause after the `SET` clause,
+/// while MySQL lists them next to the target table.
+pub trait UpdateFromClauseFragment<DB: Backend> {
+    /// Walks the tables listed directly after the target table
+    fn walk_target_tables<'b>(&'b self, out: AstPass<'_, 'b, DB>) -> QueryResult<()>;
+
+    /// Walks the `FROM` clause following the `SET` clause
+    fn walk_from_clause<'b>(&'b self, out: AstPass<'_, 'b, DB>) -> QueryResult<()>;
+}
+
+impl<DB: Backend> UpdateFromClauseFragment<DB> for NoUsingClause {
+    fn walk_target_tables<'b>(&'b self, _out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        Ok(())
+    }
+
+    fn walk_from_clause<'b>(&'b self, _out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+/// Emits the `USING` clause of a `DELETE` statement
+///
+/// PostgreSQL only lists the additional tables, while MySQL
+/// requires the target table to be listed as well.
+pub trait DeleteUsingClauseFragment<DB: Backend> {
+    /// Walks the `USING` clause following the `FROM` clause
+    /// of the target table
+    fn walk_using_clause<'b, T>(
+        &'b self,
+        target: &'b T,
+        out: AstPass<'_, 'b, DB>,
+    ) -> QueryResult<()>
+    where
+        T: QueryFragment<DB>;
+}
+
+impl<DB: Backend> DeleteUsingClauseFragment<DB> for NoUsingClause {
+    fn walk_using_clause<'b, T>(
+        &'b self,
+        _target: &'b T,
+        _out: AstPass<'_, 'b, DB>,
+    ) -> QueryResult<()>
+    where
+        T: QueryFragment<DB>,
+    {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl<F> UpdateFromClauseFragment<crate::pg::Pg> for UsingClause<F>
+where
+    F: QuerySource,
+    F::FromClause: QueryFragment<crate::pg::Pg>,
+{
+    fn walk_target_tables<'b>(&'b self, _out: AstPass<'_, 'b, crate::pg::Pg>) -> QueryResult<()> {
+        Ok(())
+    }
+
+    fn walk_from_clause<'b>(&'b self, mut out: AstPass<'_, 'b, crate::pg::Pg>) -> QueryResult<()> {
+        out.push_sql(" FROM ");
+        self.from_clause.walk_ast(out)
+    }
+}
+
+#[cfg(feature = "postgres_backend")]
+impl<F> DeleteUsingClauseFragment<crate::pg::Pg> for UsingClause<F>
+where
+    F: QuerySource,
+    F::FromClause: QueryFragment<crate::pg::Pg>,
+{
+    fn walk_using_clause<'b, T>(
+        &'b self,
+        _target: &'b T,
+        mut out: AstPass<'_, 'b, crate::pg::Pg>,
+    ) -> QueryResult<()>
+    where
+        T: QueryFragment<crate::pg::Pg>,
+    {
+        out.push_sql(" USING ");
+        self.from_clause.walk_ast(out)
+    }
+}
+
+#[cfg(feature = "__sqlite-shared")]
+impl<F> UpdateFromClauseFragment<crate::sqlite::Sqlite> for UsingClause<F>
+where
+    F: QuerySource,
+    F::FromClause: QueryFragment<crate::sqlite::Sqlite>,
+{
+    fn walk_target_tables<'b>(
+        &'b self,
+        _out: AstPass<'_, 'b, crate::sqlite::Sqlite>,
+    ) -> QueryResult<()> {
+        Ok(())
+    }
+
+    fn walk_from_clause<'b>(
+        &'b self,
+        mut out: AstPass<'_, 'b, crate::sqlite::Sqlite>,
+    ) -> QueryResult<()> {
+        out.push_sql(" FROM ");
+        self.from_clause.walk_ast(out)
+    }
+}
+
+#[cfg(feature = "mysql_backend")]
+impl<F> UpdateFromClauseFragment<crate::mysql::Mysql> for UsingClause<F>
+where
+    F: QuerySource,
+    F::FromClause: QueryFragment<crate::mysql::Mysql>,
+{
+    fn walk_target_tables<'b>(
+        &'b self,
+        mut out: AstPass<'_, 'b, crate::mysql::Mysql>,
+    ) -> QueryResult<()> {
+        // columns in the `SET` clause of a multi table update
+        // need to be qualified to be unambiguous
+        out.qualify_update_targets(true);
+        out.push_sql(", ");
+        self.from_clause.walk_ast(out)
+    }
+
+    fn walk_from_clause<'b>(
+        &'b self,
+        _out: AstPass<'_, 'b, crate::mysql::Mysql>,
+    ) -> QueryResult<()> {
+        Ok(())
+    }
+}
+
+#[cfg(feature = "mysql_backend")]
+impl<F> DeleteUsingClauseFragment<crate::mysql::Mysql> for UsingClause<F>
+where
+    F: QuerySource,
+    F::FromClause: QueryFragment<crate::mysql::Mysql>,
+{
+    fn walk_using_clause<'b, T>(
+        &'b self,
+        target: &'b T,
+        mut out: AstPass<'_, 'b, crate::mysql::Mysql>,
+    ) -> QueryResult<()>
+    where
+        T: QueryFragment<crate::mysql::Mysql>,
+    {
+        // `DELETE FROM t USING t, o` only deletes rows from `t`
+        out.push_sql(" USING ");
+        target.walk_ast(out.reborrow())?;
+        out.push_sql(", ");
+        self.from_clause.walk_ast(out)
+    }
+}
+
+impl<F> QueryId for UsingClause<F>
+where
+    F: QuerySource + QueryId + 'static,
+{
+    type QueryId = UsingClause<F>;
+
+    const HAS_STATIC_QUERY_ID: bool = F::HAS_STATIC_QUERY_ID;
+}
@@ -0,0 +1,232 @@
+//! Standalone `VALUES` lists
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+use crate::backend::Backend;
+use crate::expression::AppearsOnTable;
+use crate::expression::array_comparison::AsInExpression;
+use crate::expression::row_value::{AsRowValue, RowValue};
+use crate::expression::subselect::{Subselect, ValidSubselect};
+use crate::query_builder::{AstPass, Query, QueryFragment, QueryId, SelectQuery};
+use crate::query_dsl::RunQueryDslSupport;
+use crate::result::{Error, QueryResult};
+use crate::sql_types::SqlType;
+
+/// A `VALUES` list, which is a query returning the given rows
+///
+/// This type is constructed by [`diesel::dsl::values`](crate::dsl::values()).
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct ValuesList<ST, Rows> {
+    rows: Rows,
+    _sql_type: PhantomData<ST>,
+}
+
+impl<ST, Rows> ValuesList<ST, Rows> {
+    pub(crate) fn new(rows: Rows) -> Self {
+        ValuesList {
+            rows,
+            _sql_type: PhantomData,
+        }
+    }
+}
+
+impl<ST, Rows> Query for ValuesList<ST, Rows>
+where
+    ST: SqlType,
+{
+    type SqlType = ST;
+}
+
+impl<ST, Rows> SelectQuery for ValuesList<ST, Rows>
+where
+    ST: SqlType,
+{
+    type SqlType = ST;
+}
+
+impl<ST, Rows, DB> QueryFragment<DB> for ValuesList<ST, Rows>
+where
+    DB: Backend,
+    Rows: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        out.push_sql("VALUES ");
+        self.rows.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<ST, Rows> QueryId for ValuesList<ST, Rows> {
+    type QueryId = ();
+
+    const HAS_STATIC_QUERY_ID: bool = false;
+}
+
+impl<ST, Rows, QS> ValidSubselect<QS> for ValuesList<ST, Rows> where Rows: AppearsOnTable<QS> {}
+
+impl<ST, Rows> AsInExpression<ST> for ValuesList<(ST,), Rows>
+where
+    ST: SqlType,
+    (ST,): SqlType,
+    Subselect<Self, ST>: crate::Expression<SqlType = ST>,
+{
+    type InExpression = Subselect<Self, ST>;
+
+    fn as_in_expression(self) -> Self::InExpression {
+        Subselect::new(self)
+    }
+}
+
+impl<ST, Rows> RunQueryDslSupport for ValuesList<ST, Rows> {}
+
+/// A single row of a [`ValuesList`]
+///
+/// This is rendered as `(a, b)`, or as `ROW(a, b)` on MySQL.
+#[derive(Debug, Clone, Copy)]
+pub struct ValuesRow<T>(RowValue<T>);
+
+impl<T, QS> AppearsOnTable<QS> for ValuesRow<T> where RowValue<T>: AppearsOnTable<QS> {}
+
+impl<T> crate::Expression for ValuesRow<T>
+where
+    RowValue<T>: crate::Expression,
+{
+    type SqlType = <RowValue<T> as crate::Expression>::SqlType;
+}
+
+#[cfg(feature = "postgres_backend")]
+impl<T> QueryFragment<crate::pg::Pg> for ValuesRow<T>
+where
+    RowValue<T>: QueryFragment<crate::pg::Pg>,
+{
+    fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, crate::pg::Pg>) -> QueryResult<()> {
+        self.0.walk_ast(out)
+    }
+}
+
+#[cfg(feature = "__sqlite-shared")]
+impl<T> QueryFragment<crate::sqlite::Sqlite> for ValuesRow<T>
+where
+    RowValue<T>: QueryFragment<crate::sqlite::Sqlite>,
+{
+    fn walk_ast<'b>(&'b self, out: AstPass<'_, 'b, crate::sqlite::Sqlite>) -> QueryResult<()> {
+        self.0.walk_ast(out)
+    }
+}
+
+#[cfg(feature = "mysql_backend")]
+impl<T> QueryFragment<crate::mysql::Mysql> for ValuesRow<T>
+where
+    RowValue<T>: QueryFragment<crate::mysql::Mysql>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, crate::mysql::Mysql>) -> QueryResult<()> {
+        out.push_sql("ROW");
+        self.0.walk_ast(out)
+    }
+}
+
+/// A list of rows of a [`ValuesList`] whose length is only known at runtime
+#[derive(Debug, Clone)]
+pub struct ValuesRows<T>(Vec<ValuesRow<T>>);
+
+impl<T, QS> AppearsOnTable<QS> for ValuesRows<T> where ValuesRow<T>: AppearsOnTable<QS> {}
+
+impl<T> crate::Expression for ValuesRows<T>
+where
+    ValuesRow<T>: crate::Expression,
+{
+    type SqlType = <ValuesRow<T> as crate::Expression>::SqlType;
+}
+
+impl<T, DB> QueryFragment<DB> for ValuesRows<T>
+where
+    DB: Backend,
+    ValuesRow<T>: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        if self.0.is_empty() {
+            return Err(Error::QueryBuilderError(
+                "A `VALUES` list requires at least one row".into(),
+            ));
+        }
+        // the number of bind values depends on the number of rows
+        out.unsafe_to_cache_prepared();
+        for (idx, row) in self.0.iter().enumerate() {
+            if idx != 0 {
+                out.push_sql(", ");
+            }
+            row.walk_ast(out.reborrow())?;
+        }
+        Ok(())
+    }
+}
+
+/// Converts a collection of rows into the rows of a [`ValuesList`]
+/// with the given tuple sql type
+///
+/// This is implemented for tuples, arrays and vectors of rows. Each row
+/// is a tuple that implements [`AsRowValue`] for the sql type, so it can
+/// contain rust values as well as expressions.
+pub trait IntoValuesRows<ST> {
+    /// The rows of the `VALUES` list
+    type Rows;
+
+    /// Convert `self` into the rows of a `VALUES` list
+    fn into_values_rows(self) -> Self::Rows;
+}
+
+impl<T, ST> IntoValuesRows<ST> for Vec<T>
+where
+    T: AsRowValue<ST>,
+{
+    type Rows = ValuesRows<T::Expression>;
+
+    fn into_values_rows(self) -> Self::Rows {
+        ValuesRows(
+            self.into_iter()
+                .map(|row| ValuesRow(row.as_row_value()))
+                .collect(),
+        )
+    }
+}
+
+impl<T, ST, const N: usize> IntoValuesRows<ST> for [T; N]
+where
+    T: AsRowValue<ST>,
+{
+    type Rows = ValuesRows<T::Expression>;
+
+    fn into_values_rows(self) -> Self::Rows {
+        ValuesRows(
+            self.into_iter()
+                .map(|row| ValuesRow(row.as_row_value()))
+                .collect(),
+        )
+    }
+}
+
+macro_rules! values_rows_impls {
+    ($(
+        $Tuple:tt {
+            $(($idx:tt) -> $T:ident, $ST:ident, $TT:ident,)+
+        }
+    )+) => {
+        $(
+            #[cfg_attr(diesel_docsrs, doc(hidden))]
+            impl<$($T,)+ RowST> IntoValuesRows<RowST> for ($($T,)+)
+            where
+                $($T: AsRowValue<RowST>,)+
+            {
+                type Rows = ($(ValuesRow<$T::Expression>,)+);
+
+                fn into_values_rows(self) -> Self::Rows {
+                    ($(ValuesRow(self.$idx.as_row_value()),)+)
+                }
+            }
+        )+
+    }
+}
+
+crate::for_each_tuple!(values_rows_impls);
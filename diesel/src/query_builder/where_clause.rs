@@ -73,6 +73,12 @@ impl<DB> From<NoWhereClause> for BoxedWhereClause<'_, DB> {
 #[derive(Debug, Clone, Copy)]
 pub struct WhereClause<Expr>(Expr);
 
+impl<Expr> WhereClause<Expr> {
+    pub(crate) fn predicate(&self) -> &Expr {
+        &self.0
+    }
+}
+
 impl<Expr: diesel::query_builder::QueryId> diesel::query_builder::QueryId for WhereClause<Expr> {
     type QueryId = WhereClause<<Expr as diesel::query_builder::QueryId>::QueryId>;
     const HAS_STATIC_QUERY_ID: bool =
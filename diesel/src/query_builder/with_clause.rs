@@ -0,0 +1,304 @@
+//! Common table expressions (`WITH` clauses)
+
+use core::marker::PhantomData;
+
+use crate::backend::Backend;
+use crate::expression::Expression;
+use crate::query_builder::combination_clause::{All, Distinct, SupportsCombinationClause, Union};
+use crate::query_builder::nodes::StaticQueryFragment;
+use crate::query_builder::{AsQuery, AstPass, Query, QueryFragment, QueryId};
+use crate::query_dsl::RunQueryDslSupport;
+use crate::query_source::{QueryRelation, QueryRelationField};
+use crate::result::QueryResult;
+
+/// Represents the column list of a common table expression
+///
+/// This trait is implemented by fields of query relations and tuples thereof.
+pub trait CteColumnList {
+    /// Generate the SQL for this column list.
+    ///
+    /// Column names must *not* be qualified.
+    fn walk_ast<DB: Backend>(&self, out: AstPass<'_, '_, DB>) -> QueryResult<()>;
+}
+
+impl<C> CteColumnList for C
+where
+    C: QueryRelationField,
+{
+    fn walk_ast<DB: Backend>(&self, mut out: AstPass<'_, '_, DB>) -> QueryResult<()> {
+        out.push_identifier(C::NAME)?;
+        Ok(())
+    }
+}
+
+/// A single common table expression, rendered as
+/// `"name" ("column", ...) AS (query)`
+///
+/// The name and the columns of the common table expression are taken from
+/// the query relation `R`, which is usually declared via the
+/// [`view!`](crate::view!) macro.
+/// See [`diesel::dsl::with`](crate::dsl::with()) for details.
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct CommonTableExpression<R, Q> {
+    relation: PhantomData<R>,
+    query: Q,
+}
+
+impl<R, Q, DB> QueryFragment<DB> for CommonTableExpression<R, Q>
+where
+    DB: Backend,
+    R: QueryRelation + StaticQueryFragment,
+    R::Component: QueryFragment<DB>,
+    R::AllColumns: CteColumnList,
+    Q: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        R::STATIC_COMPONENT.walk_ast(out.reborrow())?;
+        out.push_sql(" (");
+        <R as QueryRelation>::all_columns().walk_ast(out.reborrow())?;
+        out.push_sql(") AS (");
+        self.query.walk_ast(out.reborrow())?;
+        out.push_sql(")");
+        Ok(())
+    }
+}
+
+/// The first part of a query using common table expressions
+///
+/// This type is constructed by [`diesel::dsl::with`](crate::dsl::with()).
+/// Additional common table expressions can be added with
+/// [`with`](IncompleteWithQuery::with()), the final query is set
+/// with [`query`](IncompleteWithQuery::query()).
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct IncompleteWithQuery<Ctes> {
+    ctes: Ctes,
+}
+
+impl<Ctes> IncompleteWithQuery<Ctes> {
+    pub(crate) fn new(ctes: Ctes) -> Self {
+        IncompleteWithQuery { ctes }
+    }
+
+    /// Adds another common table expression to this query
+    ///
+    /// The given query may refer to any common table expression
+    /// added before.
+    ///
+    /// See [`diesel::dsl::with`](crate::dsl::with()) for an example.
+    pub fn with<R, Q>(
+        self,
+        _relation: R,
+        query: Q,
+    ) -> IncompleteWithQuery<(Ctes, CommonTableExpression<R, Q::Query>)>
+    where
+        R: QueryRelation,
+        Q: AsQuery<SqlType = <R::AllColumns as Expression>::SqlType>,
+    {
+        IncompleteWithQuery {
+            ctes: (
+                self.ctes,
+                CommonTableExpression {
+                    relation: PhantomData,
+                    query: query.as_query(),
+                },
+            ),
+        }
+    }
+
+    /// Sets the query which uses the common table expressions
+    ///
+    /// See [`diesel::dsl::with`](crate::dsl::with()) for an example.
+    pub fn query<Q>(self, query: Q) -> WithQuery<Ctes, Q::Query>
+    where
+        Q: AsQuery,
+    {
+        WithQuery {
+            ctes: self.ctes,
+            query: query.as_query(),
+        }
+    }
+
+    /// Sets a statement without a result which uses the common table
+    /// expressions, like an `UPDATE`, `DELETE` or `INSERT` statement
+    ///
+    /// The resulting statement is executed via
+    /// [`execute`](crate::RunQueryDsl::execute()).
+    /// See [`diesel::dsl::values`](crate::dsl::values()) for an example.
+    pub fn statement<S>(self, statement: S) -> WithQuery<Ctes, S> {
+        WithQuery {
+            ctes: self.ctes,
+            query: statement,
+        }
+    }
+}
+
+impl<R, Q> IncompleteWithQuery<CommonTableExpression<R, Q>> {
+    pub(crate) fn from_cte(_relation: R, query: Q) -> Self {
+        IncompleteWithQuery::new(CommonTableExpression {
+            relation: PhantomData,
+            query,
+        })
+    }
+}
+
+/// The first part of a recursive common table expression
+///
+/// This type is constructed by
+/// [`diesel::dsl::with_recursive`](crate::dsl::with_recursive()).
+/// The recursive term is set with [`union`](IncompleteRecursiveCte::union())
+/// or [`union_all`](IncompleteRecursiveCte::union_all()).
+#[derive(Debug, Clone, Copy)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct IncompleteRecursiveCte<R, Anchor> {
+    relation: PhantomData<R>,
+    anchor: Anchor,
+}
+
+impl<R, Anchor> IncompleteRecursiveCte<R, Anchor> {
+    pub(crate) fn new(_relation: R, anchor: Anchor) -> Self {
+        IncompleteRecursiveCte {
+            relation: PhantomData,
+            anchor,
+        }
+    }
+
+    /// Sets the recursive term of this common table expression, combining
+    /// it with the anchor query using `UNION`
+    ///
+    /// Duplicated rows are removed, which terminates the recursion for
+    /// cyclic data.
+    pub fn union<Q>(
+        self,
+        recursive: Q,
+    ) -> IncompleteWithQuery<RecursiveCte<R, Anchor, Distinct, Q::Query>>
+    where
+        Anchor: Query,
+        Q: AsQuery<SqlType = Anchor::SqlType>,
+    {
+        self.recursive_term(Distinct, recursive.as_query())
+    }
+
+    /// Sets the recursive term of this common table expression, combining
+    /// it with the anchor query using `UNION ALL`
+    pub fn union_all<Q>(
+        self,
+        recursive: Q,
+    ) -> IncompleteWithQuery<RecursiveCte<R, Anchor, All, Q::Query>>
+    where
+        Anchor: Query,
+        Q: AsQuery<SqlType = Anchor::SqlType>,
+    {
+        self.recursive_term(All, recursive.as_query())
+    }
+
+    fn recursive_term<Rule, Q>(
+        self,
+        duplicate_rule: Rule,
+        recursive: Q,
+    ) -> IncompleteWithQuery<RecursiveCte<R, Anchor, Rule, Q>> {
+        IncompleteWithQuery::new(RecursiveCte {
+            cte: CommonTableExpression {
+                relation: self.relation,
+                query: RecursiveUnion {
+                    anchor: self.anchor,
+                    duplicate_rule,
+                    recursive,
+                },
+            },
+        })
+    }
+}
+
+/// A recursive common table expression, rendered as
+/// `RECURSIVE "name" ("column", ...) AS (anchor UNION [ALL] recursive)`
+///
+/// See [`diesel::dsl::with_recursive`](crate::dsl::with_recursive()) for details.
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct RecursiveCte<R, Anchor, Rule, Recursive> {
+    cte: CommonTableExpression<R, RecursiveUnion<Anchor, Rule, Recursive>>,
+}
+
+impl<R, Anchor, Rule, Recursive, DB> QueryFragment<DB> for RecursiveCte<R, Anchor, Rule, Recursive>
+where
+    DB: Backend,
+    CommonTableExpression<R, RecursiveUnion<Anchor, Rule, Recursive>>: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        out.push_sql("RECURSIVE ");
+        self.cte.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+/// The `anchor UNION [ALL] recursive` part of a recursive common table expression
+///
+/// In contrast to [`CombineDsl::union`](crate::query_dsl::CombineDsl::union())
+/// neither query is wrapped in parenthesis or subqueries, as the recursive
+/// term must refer to the common table expression directly.
+#[derive(Debug, Clone, Copy, QueryId)]
+pub struct RecursiveUnion<Anchor, Rule, Recursive> {
+    anchor: Anchor,
+    duplicate_rule: Rule,
+    recursive: Recursive,
+}
+
+impl<Anchor, Rule, Recursive> Query for RecursiveUnion<Anchor, Rule, Recursive>
+where
+    Anchor: Query,
+    Recursive: Query<SqlType = Anchor::SqlType>,
+{
+    type SqlType = Anchor::SqlType;
+}
+
+impl<Anchor, Rule, Recursive, DB> QueryFragment<DB> for RecursiveUnion<Anchor, Rule, Recursive>
+where
+    DB: Backend + SupportsCombinationClause<Union, Rule>,
+    Anchor: QueryFragment<DB>,
+    Union: QueryFragment<DB>,
+    Rule: QueryFragment<DB>,
+    Recursive: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        self.anchor.walk_ast(out.reborrow())?;
+        Union.walk_ast(out.reborrow())?;
+        self.duplicate_rule.walk_ast(out.reborrow())?;
+        self.recursive.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+/// A query prefixed by a `WITH` clause
+///
+/// This type is constructed by
+/// [`IncompleteWithQuery::query`](IncompleteWithQuery::query()).
+#[derive(Debug, Clone, Copy, QueryId)]
+#[must_use = "Queries are only executed when calling `load`, `get_result` or similar."]
+pub struct WithQuery<Ctes, Q> {
+    ctes: Ctes,
+    query: Q,
+}
+
+impl<Ctes, Q> Query for WithQuery<Ctes, Q>
+where
+    Q: Query,
+{
+    type SqlType = Q::SqlType;
+}
+
+impl<Ctes, Q, DB> QueryFragment<DB> for WithQuery<Ctes, Q>
+where
+    DB: Backend,
+    Ctes: QueryFragment<DB>,
+    Q: QueryFragment<DB>,
+{
+    fn walk_ast<'b>(&'b self, mut out: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        out.push_sql("WITH ");
+        out.walk_nested_statement(|out| self.ctes.walk_ast(out))?;
+        out.push_sql(" ");
+        self.query.walk_ast(out.reborrow())?;
+        Ok(())
+    }
+}
+
+impl<Ctes, Q> RunQueryDslSupport for WithQuery<Ctes, Q> {}
@@ -7,6 +7,14 @@ use crate::query_builder::{AsQuery, Query};
 
 /// Extension trait to combine queries using a combinator like `UNION`, `INTERSECT` or `EXCEPT`
 /// with or without `ALL` rule for duplicates
+///
+/// The combined query can be ordered via
+/// [`positional_order_by`](crate::query_dsl::positional_order_dsl::PositionalOrderDsl::positional_order_by())
+/// and restricted via [`limit`](crate::QueryDsl::limit()) and
+/// [`offset`](crate::QueryDsl::offset()). It can be used as subquery, for example
+/// with [`eq_any`](crate::ExpressionMethods::eq_any()),
+/// [`exists`](crate::dsl::exists()) or [`single_value`](crate::QueryDsl::single_value()),
+/// and as source of a common table expression via [`with`](crate::dsl::with()).
 pub trait CombineDsl {
     /// What kind of query does this type represent?
     type Query: Query;
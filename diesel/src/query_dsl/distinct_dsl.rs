@@ -1,6 +1,6 @@
 use crate::Expression;
 use crate::dsl;
-#[cfg(feature = "postgres_backend")]
+#[cfg(any(feature = "postgres_backend", feature = "distinct_on_for_mysql_8"))]
 use crate::expression::SelectableExpression;
 use crate::expression::TypedExpressionType;
 use crate::expression::ValidGrouping;
@@ -47,7 +47,7 @@ where
 /// to call `distinct_on` from generic code.
 ///
 /// [`QueryDsl`]: crate::QueryDsl
-#[cfg(feature = "postgres_backend")]
+#[cfg(any(feature = "postgres_backend", feature = "distinct_on_for_mysql_8"))]
 #[diagnostic::on_unimplemented(
     note = "a `DISTINCT ON` clause is not compatible with various other clauses like `LOCKING` clauses",
     note = "a `DISTINCT ON` clause also disallows mixing aggregate and non-aggregate expressions with the `SELECT` clause"
@@ -60,7 +60,7 @@ pub trait DistinctOnDsl<Selection> {
     fn distinct_on(self, selection: Selection) -> dsl::DistinctOn<Self, Selection>;
 }
 
-#[cfg(feature = "postgres_backend")]
+#[cfg(any(feature = "postgres_backend", feature = "distinct_on_for_mysql_8"))]
 #[diagnostic::do_not_recommend]
 impl<T, Selection> DistinctOnDsl<Selection> for T
 where
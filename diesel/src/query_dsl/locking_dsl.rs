@@ -58,3 +58,25 @@ pub trait ModifyLockDsl<Modifier> {
     /// See the trait level documentation
     fn modify_lock(self, modifier: Modifier) -> Self::Output;
 }
+
+/// Methods related to restricting the locks of a select statement to
+/// some tables of its `FROM` clause
+///
+/// This trait should not be relied on directly by most apps. Its behavior is
+/// provided by [`QueryDsl`]. However, you may need a where clause on this trait
+/// to call `of` from generic code.
+///
+/// [`QueryDsl`]: crate::QueryDsl
+#[diagnostic::on_unimplemented(
+    note = "`of` requires a locking clause without targets and tables that appear in the `FROM` clause"
+)]
+pub trait LockTargetDsl<Tables> {
+    /// The type returned by `lock_of`. See [`dsl::Of`] for convenient
+    /// access to this type.
+    ///
+    /// [`dsl::Of`]: crate::dsl::Of
+    type Output;
+
+    /// See the trait level documentation
+    fn lock_of(self, tables: Tables) -> Self::Output;
+}
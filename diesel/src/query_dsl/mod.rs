@@ -13,6 +13,7 @@
 
 use crate::backend::Backend;
 use crate::connection::Connection;
+use crate::deserialize::QueryableInto;
 use crate::expression::Expression;
 use crate::expression::count::CountStar;
 use crate::helper_types::*;
@@ -39,12 +40,14 @@ mod locking_dsl;
 mod nullable_select_dsl;
 mod offset_dsl;
 pub(crate) mod order_dsl;
+mod paginate_dsl;
 #[doc(hidden)]
 pub mod positional_order_dsl;
 mod save_changes_dsl;
 #[doc(hidden)]
 pub mod select_dsl;
 mod single_value_dsl;
+mod statement_cache_dsl;
 
 pub use self::belonging_to_dsl::BelongingToDsl;
 pub use self::combine_dsl::CombineDsl;
@@ -53,7 +56,9 @@ pub use self::join_dsl::{InternalJoinDsl, JoinOnDsl, JoinWithImplicitOnClause};
 pub use self::load_dsl::CompatibleType;
 #[doc(hidden)]
 pub use self::load_dsl::LoadQuery;
+pub use self::paginate_dsl::PaginateDsl;
 pub use self::save_changes_dsl::{SaveChangesDsl, UpdateAndFetchResults};
+pub use self::statement_cache_dsl::StatementCacheDsl;
 
 /// The traits used by `QueryDsl`.
 ///
@@ -70,7 +75,7 @@ pub mod methods {
     pub use super::having_dsl::HavingDsl;
     pub use super::limit_dsl::LimitDsl;
     pub use super::load_dsl::{ExecuteDsl, LoadQuery};
-    pub use super::locking_dsl::{LockingDsl, ModifyLockDsl};
+    pub use super::locking_dsl::{LockTargetDsl, LockingDsl, ModifyLockDsl};
     pub use super::nullable_select_dsl::SelectNullableDsl;
     pub use super::offset_dsl::OffsetDsl;
     pub use super::order_dsl::{OrderDsl, ThenOrderDsl};
@@ -125,6 +130,16 @@ pub trait QueryDsl: Sized {
 
     /// Adds the `DISTINCT ON` clause to a query.
     ///
+    /// If the query has an `ORDER BY` clause, the expressions passed to
+    /// `distinct_on` must be a prefix of the ordering. This is checked at
+    /// compile time.
+    ///
+    /// On MySQL this method is available with the `distinct_on_for_mysql_8`
+    /// feature. The clause is emulated by partitioning the rows with the
+    /// `ROW_NUMBER()` window function, which requires MySQL 8.0 or newer.
+    /// The emulation is only supported for queries on a single table
+    /// without `GROUP BY`, `HAVING` or locking clauses.
+    ///
     /// # Example
     ///
     /// ```rust
@@ -184,7 +199,7 @@ pub trait QueryDsl: Sized {
     /// );
     /// # }
     /// ```
-    #[cfg(feature = "postgres_backend")]
+    #[cfg(any(feature = "postgres_backend", feature = "distinct_on_for_mysql_8"))]
     fn distinct_on<Expr>(self, expr: Expr) -> DistinctOn<Self, Expr>
     where
         Self: methods::DistinctOnDsl<Expr>,
@@ -1277,6 +1292,48 @@ pub trait QueryDsl: Sized {
         methods::ModifyLockDsl::modify_lock(self, lock::NoWait)
     }
 
+    /// Restricts the row locks of a locking clause to the given tables
+    ///
+    /// This adds `OF table` to the locking clause, which only locks the rows
+    /// of the given tables instead of the rows of all tables in the `FROM`
+    /// clause. Accepts a table, an alias or a tuple of those, which need to
+    /// appear in the `FROM` clause of the query.
+    ///
+    /// This is only supported in PostgreSQL and MySQL 8+.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # include!("../doctest_setup.rs");
+    /// # fn main() {
+    /// #     run_test();
+    /// # }
+    /// #
+    /// # #[cfg(any(feature = "postgres", feature = "mysql"))]
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use crate::schema::{posts, users};
+    /// #     let connection = &mut establish_connection();
+    /// // Executes `SELECT … FROM users INNER JOIN posts ON …
+    /// // FOR UPDATE OF users SKIP LOCKED`
+    /// let locked_users = users::table
+    ///     .inner_join(posts::table)
+    ///     .select(users::name)
+    ///     .for_update()
+    ///     .of(users::table)
+    ///     .skip_locked()
+    ///     .load::<String>(connection)?;
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(feature = "__sqlite-shared")]
+    /// # fn run_test() -> QueryResult<()> { Ok(()) }
+    /// ```
+    fn of<Tables>(self, tables: Tables) -> Of<Self, Tables>
+    where
+        Self: methods::LockTargetDsl<Tables>,
+    {
+        methods::LockTargetDsl::lock_of(self, tables)
+    }
+
     /// Boxes the pieces of a query into a single type.
     ///
     /// This is useful for cases where you want to conditionally modify a query,
@@ -1595,6 +1652,27 @@ pub trait RunQueryDsl<Conn>: Sized {
         self.internal_load(conn)?.collect()
     }
 
+    /// Executes the given query, converting each row into a domain type
+    ///
+    /// The rows are loaded as [`U::Row`](QueryableInto::Row) and converted
+    /// via the [`TryFrom`] implementation of `U`. This avoids collecting the
+    /// loaded rows into an intermediate `Vec` before converting them.
+    /// Conversion errors are returned as
+    /// [`Error::DeserializationError`](crate::result::Error::DeserializationError).
+    ///
+    /// See [`QueryableInto`] for an example.
+    fn load_into<'query, U>(self, conn: &mut Conn) -> QueryResult<Vec<U>>
+    where
+        U: QueryableInto,
+        Self: LoadQuery<'query, Conn, U::Row>,
+    {
+        self.internal_load(conn)?
+            .map(|row| {
+                U::try_from(row?).map_err(|e| crate::result::Error::DeserializationError(e.into()))
+            })
+            .collect()
+    }
+
     /// Executes the given query, returning an [`Iterator`] with the returned rows.
     ///
     /// The iterator's item is [`QueryResult<U>`](crate::result::QueryResult).
@@ -1727,6 +1805,60 @@ pub trait RunQueryDsl<Conn>: Sized {
         self.internal_load(conn)
     }
 
+    /// Executes the given query and passes each returned row to the given callback
+    ///
+    /// Rows are deserialized one after another and handed to the callback directly,
+    /// without collecting them into an intermediate container. This makes this method
+    /// a good fit for processing large result sets. Returning an error from the callback
+    /// stops the iteration and returns that error.
+    ///
+    /// The generic type parameters have the same meaning as for [`RunQueryDsl::load_iter`].
+    /// In particular, the loading mode decides whether the rows are streamed from the
+    /// database one by one. For `PgConnection` this requires
+    /// [`PgRowByRowLoadingMode`](crate::pg::PgRowByRowLoadingMode), as the
+    /// [`DefaultLoadingMode`](crate::connection::DefaultLoadingMode) receives
+    /// the complete result set at once.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// use diesel::connection::DefaultLoadingMode;
+    ///
+    /// let mut name_length = 0;
+    /// users
+    ///     .select(name)
+    ///     .for_each_row::<String, DefaultLoadingMode>(connection, |user_name| {
+    ///         name_length += user_name.len();
+    ///         Ok(())
+    ///     })?;
+    /// assert_eq!(8, name_length);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn for_each_row<'conn, 'query: 'conn, U, B>(
+        self,
+        conn: &'conn mut Conn,
+        mut callback: impl FnMut(U) -> QueryResult<()>,
+    ) -> QueryResult<()>
+    where
+        U: 'conn,
+        Self: LoadQuery<'query, Conn, U, B> + 'conn,
+    {
+        for row in self.internal_load(conn)? {
+            callback(row?)?;
+        }
+        Ok(())
+    }
+
     /// Runs the command, and returns the affected row.
     ///
     /// `Err(NotFound)` will be returned if the query affected 0 rows. You can
@@ -0,0 +1,50 @@
+use crate::query_builder::{AsQuery, Paginated};
+
+/// The `paginate` method
+///
+/// This trait is not part of the prelude, as many applications already
+/// define a `paginate` method following the guides. Import it via
+/// `use diesel::query_dsl::PaginateDsl`.
+pub trait PaginateDsl: AsQuery + Sized {
+    /// Split the query into pages of `per_page` rows
+    ///
+    /// Pages start at `1`. The returned query loads the requested page
+    /// together with the total number of rows via
+    /// [`load_and_count`](Paginated::load_and_count()).
+    /// By default the total count is loaded via a second query, see
+    /// [`count_with`](Paginated::count_with()) for other options.
+    ///
+    /// The query must not contain a `LIMIT`, `OFFSET` or locking clause, as
+    /// the page is selected by appending `LIMIT` and `OFFSET` to the query.
+    /// The total count is determined by wrapping the query in a subquery,
+    /// which requires unique names for all selected columns on MySQL.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// use diesel::query_dsl::PaginateDsl;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::posts::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let (titles, total) = posts
+    ///     .select(title)
+    ///     .order(id)
+    ///     .paginate(2, 2)
+    ///     .load_and_count::<String, _>(connection)?;
+    /// assert_eq!(vec!["My first post too"], titles);
+    /// assert_eq!(3, total);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn paginate(self, page: i64, per_page: i64) -> Paginated<Self::Query> {
+        Paginated::new(self.as_query(), page, per_page)
+    }
+}
+
+impl<T: AsQuery> PaginateDsl for T {}
@@ -0,0 +1,112 @@
+use crate::query_builder::{StatementCacheMode, WithStatementCacheMode};
+
+/// Methods to control whether a single query is stored in the
+/// prepared statement cache of the connection
+///
+/// By default diesel caches the prepared statements of all queries that
+/// are safe to cache, unless caching is disabled for the whole connection via
+/// [`Connection::set_prepared_statement_cache_size`](crate::Connection::set_prepared_statement_cache_size).
+/// These methods override that decision for a single query, which allows to keep
+/// one-off dynamic queries out of the cache or to force caching of frequently
+/// executed queries on connections that do not cache statements otherwise,
+/// for example because they are used behind a connection pooler.
+///
+/// These methods need to be called on the final query, right before
+/// executing it via [`RunQueryDsl`](crate::RunQueryDsl). Queries that are
+/// never safe to cache (for example queries containing [`sql`](crate::dsl::sql())
+/// fragments) are not cached, regardless of the chosen mode.
+pub trait StatementCacheDsl: Sized {
+    /// Set the [`StatementCacheMode`] used for this query
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// # use diesel::query_builder::StatementCacheMode;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let names = users
+    ///     .select(name)
+    ///     .order(id)
+    ///     .set_statement_cache_mode(StatementCacheMode::ExecuteUnprepared)
+    ///     .load::<String>(connection)?;
+    /// assert_eq!(vec!["Sean", "Tess"], names);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn set_statement_cache_mode(self, mode: StatementCacheMode) -> WithStatementCacheMode<Self> {
+        WithStatementCacheMode::new(self, mode)
+    }
+
+    /// Never store the prepared statement of this query in the cache
+    ///
+    /// This is a shorthand for
+    /// [`.set_statement_cache_mode(StatementCacheMode::ExecuteUnprepared)`](StatementCacheDsl::set_statement_cache_mode()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let count = users
+    ///     .filter(name.eq("Sean"))
+    ///     .count()
+    ///     .never_cache()
+    ///     .get_result::<i64>(connection)?;
+    /// assert_eq!(1, count);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn never_cache(self) -> WithStatementCacheMode<Self> {
+        self.set_statement_cache_mode(StatementCacheMode::ExecuteUnprepared)
+    }
+
+    /// Store the prepared statement of this query in the cache, even
+    /// if caching is disabled for the connection
+    ///
+    /// This is a shorthand for
+    /// [`.set_statement_cache_mode(StatementCacheMode::PrepareForCache)`](StatementCacheDsl::set_statement_cache_mode()).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// use diesel::connection::CacheSize;
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// connection.set_prepared_statement_cache_size(CacheSize::Disabled);
+    ///
+    /// let user_name = users
+    ///     .select(name)
+    ///     .filter(id.eq(1))
+    ///     .always_prepare()
+    ///     .get_result::<String>(connection)?;
+    /// assert_eq!("Sean", user_name);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    fn always_prepare(self) -> WithStatementCacheMode<Self> {
+        self.set_statement_cache_mode(StatementCacheMode::PrepareForCache)
+    }
+}
+
+impl<T> StatementCacheDsl for T {}
@@ -104,7 +104,7 @@ where
     S::Target: QueryFragment<DB>,
 {
     fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
-        self.source.target().walk_ast(pass.reborrow())?;
+        pass.walk_table_name(|pass| self.source.target().walk_ast(pass))?;
         pass.push_sql(" AS ");
         pass.push_identifier(S::NAME)?;
         Ok(())
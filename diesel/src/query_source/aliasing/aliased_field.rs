@@ -49,6 +49,7 @@ where
     C: QueryRelationField<QueryRelation = S::Target>,
 {
     fn walk_ast<'b>(&'b self, mut pass: AstPass<'_, 'b, DB>) -> QueryResult<()> {
+        pass.redact_following_binds(C::SENSITIVE);
         pass.push_identifier(S::NAME)?;
         pass.push_sql(".");
         pass.push_identifier(C::NAME)?;
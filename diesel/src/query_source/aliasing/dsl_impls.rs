@@ -2,7 +2,7 @@ use super::field_alias_mapper::FieldAliasMapper;
 use super::{Alias, AliasSource};
 
 use crate::dsl;
-#[cfg(feature = "postgres_backend")]
+#[cfg(any(feature = "postgres_backend", feature = "distinct_on_for_mysql_8"))]
 use crate::expression::SelectableExpression;
 use crate::expression::{Expression, TypedExpressionType, ValidGrouping};
 use crate::expression_methods::EqAll;
@@ -156,7 +156,7 @@ where
     }
 }
 
-#[cfg(feature = "postgres_backend")]
+#[cfg(any(feature = "postgres_backend", feature = "distinct_on_for_mysql_8"))]
 impl<S, Selection> DistinctOnDsl<Selection> for Alias<S>
 where
     S: AliasSource,
@@ -225,8 +225,12 @@ where
 /// [`inner_join`]: crate::query_dsl::QueryDsl::inner_join()
 #[diagnostic::on_unimplemented(
     message = "cannot join `{T}` to `{Self}` due to missing relation",
-    note = "joining tables directly either requires a `diesel::joinable!` definition \
-            or calling `JoinOnDsl::on` to manually specify the `ON` clause of the join`"
+    label = "the `ON` clause of this join cannot be inferred",
+    note = "joining tables without an explicit `ON` clause requires a `diesel::joinable!` definition",
+    note = "declare the foreign key with `diesel::joinable!(child -> parent (foreign_key));` \
+            if both tables are related",
+    note = "otherwise call `JoinOnDsl::on` to specify the `ON` clause manually, \
+            e.g. `.inner_join(rhs.on(lhs_column.eq(rhs_column)))`"
 )]
 pub trait JoinTo<T> {
     #[doc(hidden)]
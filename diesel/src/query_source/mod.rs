@@ -51,6 +51,21 @@ pub trait Column: Expression {
 
     /// The name of this column
     const NAME: &'static str;
+
+    /// Whether values of this column are redacted in logged or
+    /// debug-rendered queries
+    ///
+    /// This is set for columns declared with `#[diesel(sensitive)]`
+    /// in [`table!`](crate::table!).
+    const SENSITIVE: bool = false;
+
+    /// Whether values of this column are generated by the database
+    ///
+    /// Inserts skip values for such columns, so that the database
+    /// can compute them. This is set for columns declared with
+    /// `#[diesel(generated)]` or `#[generated_always_as = "..."]`
+    /// in [`table!`](crate::table!).
+    const GENERATED: bool = false;
 }
 
 /// A field on a database relation. Types which implement this trait
@@ -62,6 +77,13 @@ pub trait QueryRelationField: Expression {
 
     /// The name of this field
     const NAME: &'static str;
+
+    /// Whether values of this field are redacted in logged or
+    /// debug-rendered queries
+    ///
+    /// This is set for fields declared with `#[diesel(sensitive)]`
+    /// in [`table!`](crate::table!) or [`view!`](crate::view!).
+    const SENSITIVE: bool = false;
 }
 
 impl<C> QueryRelationField for C
@@ -71,6 +93,7 @@ where
     type QueryRelation = C::Table;
 
     const NAME: &'static str = <C as Column>::NAME;
+    const SENSITIVE: bool = <C as Column>::SENSITIVE;
 }
 
 /// A SQL database relation that can be queried
@@ -182,9 +182,9 @@ use alloc::fmt;
 use core::marker::PhantomData;
 
 use crate::backend::Backend;
+use crate::connection::middleware::{ConnectionWrapper, DelegatingTransactionManager};
 use crate::connection::{
-    ConnectionSealed, LoadConnection, SimpleConnection, TransactionManager,
-    TransactionManagerStatus,
+    ConnectionSealed, DatabaseWarning, LoadConnection, SimpleConnection, TransactionManager,
 };
 use crate::expression::QueryMetadata;
 use crate::prelude::*;
@@ -322,7 +322,7 @@ where
 {
     type Backend = <M::Connection as Connection>::Backend;
     type TransactionManager =
-        PoolTransactionManager<<M::Connection as Connection>::TransactionManager>;
+        DelegatingTransactionManager<<M::Connection as Connection>::TransactionManager>;
 
     fn establish(_: &str) -> ConnectionResult<Self> {
         Err(ConnectionError::BadConnection(String::from(
@@ -341,6 +341,16 @@ where
         (**self).execute_returning_count(source)
     }
 
+    fn execute_returning_count_with_warnings<T>(
+        &mut self,
+        source: &T,
+    ) -> QueryResult<(usize, Vec<DatabaseWarning>)>
+    where
+        T: QueryFragment<Self::Backend> + QueryId,
+    {
+        (**self).execute_returning_count_with_warnings(source)
+    }
+
     fn transaction_state(
         &mut self,
     ) -> &mut <Self::TransactionManager as TransactionManager<Self>>::TransactionStateData {
@@ -358,6 +368,14 @@ where
     fn set_prepared_statement_cache_size(&mut self, size: crate::connection::CacheSize) {
         (**self).set_prepared_statement_cache_size(size)
     }
+
+    fn clear_prepared_statement_cache(&mut self) {
+        (**self).clear_prepared_statement_cache()
+    }
+
+    fn cached_statements(&self) -> alloc::vec::Vec<alloc::string::String> {
+        (**self).cached_statements()
+    }
 }
 
 impl<B, M> LoadConnection<B> for PooledConnection<M>
@@ -381,33 +399,17 @@ where
 }
 
 #[doc(hidden)]
-#[allow(missing_debug_implementations)]
-pub struct PoolTransactionManager<T>(core::marker::PhantomData<T>);
+pub type PoolTransactionManager<T> = DelegatingTransactionManager<T>;
 
-impl<M, T> TransactionManager<PooledConnection<M>> for PoolTransactionManager<T>
+impl<M> ConnectionWrapper for PooledConnection<M>
 where
     M: ManageConnection,
-    M::Connection: Connection<TransactionManager = T> + R2D2Connection,
-    T: TransactionManager<M::Connection>,
+    M::Connection: Connection,
 {
-    type TransactionStateData = T::TransactionStateData;
-
-    fn begin_transaction(conn: &mut PooledConnection<M>) -> QueryResult<()> {
-        T::begin_transaction(&mut **conn)
-    }
-
-    fn rollback_transaction(conn: &mut PooledConnection<M>) -> QueryResult<()> {
-        T::rollback_transaction(&mut **conn)
-    }
-
-    fn commit_transaction(conn: &mut PooledConnection<M>) -> QueryResult<()> {
-        T::commit_transaction(&mut **conn)
-    }
+    type Inner = M::Connection;
 
-    fn transaction_manager_status_mut(
-        conn: &mut PooledConnection<M>,
-    ) -> &mut TransactionManagerStatus {
-        T::transaction_manager_status_mut(&mut **conn)
+    fn inner_connection(&mut self) -> &mut M::Connection {
+        self
     }
 }
 
@@ -434,6 +436,11 @@ where
     }
 }
 
+// only used by the `R2D2Connection` impls of the built-in connections
+#[cfg_attr(
+    not(any(feature = "postgres", feature = "__sqlite-shared", feature = "mysql")),
+    allow(dead_code)
+)]
 #[derive(QueryId)]
 pub(crate) struct CheckConnectionQuery;
 
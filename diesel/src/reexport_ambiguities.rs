@@ -17,6 +17,8 @@ macro_rules! make_proxy_mod {
 
             // Drop unintended types
             type abbrev = ();
+            type age = ();
+            type array_agg = ();
             type array_append = ();
             type array_cat = ();
             type array_dims = ();
@@ -40,6 +42,8 @@ macro_rules! make_proxy_mod {
             type avg = ();
             type broadcast = ();
             type cardinality = ();
+            type date_part = ();
+            type date_trunc = ();
             type daterange = ();
             type family = ();
             type first_value = ();
@@ -50,10 +54,14 @@ macro_rules! make_proxy_mod {
             type int4range = ();
             type int8range = ();
             type isempty = ();
+            type json_array_elements = ();
             type json_array_length = ();
             type json_build_array_0 = ();
             type json_build_array_1 = ();
             type json_build_array_2 = ();
+            type json_build_object_0 = ();
+            type json_build_object_1 = ();
+            type json_build_object_2 = ();
             type json_extract_path_1 = ();
             type json_extract_path_2 = ();
             type json_extract_path_text_1 = ();
@@ -63,10 +71,14 @@ macro_rules! make_proxy_mod {
             type json_populate_record = ();
             type json_strip_nulls = ();
             type json_typeof = ();
+            type jsonb_array_elements = ();
             type jsonb_array_length = ();
             type jsonb_build_array_0 = ();
             type jsonb_build_array_1 = ();
             type jsonb_build_array_2 = ();
+            type jsonb_build_object_0 = ();
+            type jsonb_build_object_1 = ();
+            type jsonb_build_object_2 = ();
             type jsonb_extract_path_1 = ();
             type jsonb_extract_path_2 = ();
             type jsonb_extract_path_text_1 = ();
@@ -92,18 +104,26 @@ macro_rules! make_proxy_mod {
             type lower_inc = ();
             type lower_inf = ();
             type lower = ();
+            type macaddr8_set7bit = ();
             type masklen = ();
             type max = ();
             type min = ();
+            type multirange = ();
             type multirange_merge = ();
             type netmask = ();
             type network = ();
             type nth_value = ();
             type numrange = ();
+            type range_agg = ();
             type range_merge = ();
             type row_to_json = ();
             type set_masklen = ();
+            type st_contains = ();
+            type st_distance = ();
+            type st_dwithin = ();
+            type string_agg = ();
             type sum = ();
+            type to_char = ();
             type to_json = ();
             type to_jsonb = ();
             type trim_array = ();
@@ -177,6 +197,12 @@ macro_rules! make_proxy_mod {
             type jsonb_set_0 = ();
             type jsonb_set_1 = ();
             type jsonb_set_2 = ();
+            type strftime = ();
+            // mysql
+            type date_format = ();
+            type json_contains = ();
+            type json_extract = ();
+            type json_unquote = ();
         }
     };
 }
@@ -103,6 +103,18 @@ pub enum Error {
 
     /// Closing a handle failed
     ClosingHandle(&'static str),
+
+    /// The connection does not support starting a transaction with the
+    /// given isolation level and access mode
+    ///
+    /// This variant is returned by
+    /// [`Connection::transaction_with`](crate::Connection::transaction_with)
+    UnsupportedTransactionOptions {
+        /// The requested isolation level
+        isolation: crate::connection::Isolation,
+        /// The requested access mode
+        access: crate::connection::Access,
+    },
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -131,8 +143,10 @@ pub enum DatabaseErrorKind {
     ///
     /// Corresponds to SQLSTATE code 40001
     ///
-    /// This error is only detected for PostgreSQL, as we do not yet support
-    /// transaction isolation levels for other backends.
+    /// This error is also returned for other errors caused by concurrent
+    /// transactions, which can be resolved by retrying the transaction:
+    /// deadlocks on PostgreSQL (SQLSTATE code 40P01) and MySQL (error 1213)
+    /// and `SQLITE_BUSY` errors on SQLite.
     SerializationFailure = 3,
 
     /// The command could not be completed because the transaction was read
@@ -411,6 +425,11 @@ impl Display for Error {
             Error::ClosingHandle(message) => {
                 write!(f, "Error closing SQLite blob: {message}")
             }
+            Error::UnsupportedTransactionOptions { isolation, access } => write!(
+                f,
+                "Transactions with isolation level {isolation:?} and access mode {access:?} \
+                 are not supported by this connection"
+            ),
         }
     }
 }
@@ -457,6 +476,16 @@ impl PartialEq for Error {
             (&Error::NotFound, &Error::NotFound) => true,
             (&Error::RollbackTransaction, &Error::RollbackTransaction) => true,
             (&Error::AlreadyInTransaction, &Error::AlreadyInTransaction) => true,
+            (
+                Error::UnsupportedTransactionOptions {
+                    isolation: a_isolation,
+                    access: a_access,
+                },
+                Error::UnsupportedTransactionOptions {
+                    isolation: b_isolation,
+                    access: b_access,
+                },
+            ) => a_isolation == b_isolation && a_access == b_access,
             _ => false,
         }
     }
@@ -289,26 +289,71 @@ pub struct Date;
 
 /// The interval SQL type.
 ///
-/// This type is currently only implemented for PostgreSQL.
+/// Only PostgreSQL has a native interval type. MySQL and SQLite only support
+/// intervals as bind parameters, which can be added to or subtracted from
+/// date, time and timestamp expressions.
 ///
 /// ### [`ToSql`](crate::serialize::ToSql) impls
 ///
-/// - [`PgInterval`] which can be constructed using [`IntervalDsl`]
+/// - [`PgInterval`] which can be constructed using [`IntervalDsl`] (PostgreSQL only)
 /// - [`chrono::Duration`][Duration] with `feature = "chrono"`
+/// - [`time::Duration`][TimeDuration] with `feature = "time"`
+/// - [`std::time::Duration`][StdDuration]
 ///
 /// ### [`FromSql`](crate::deserialize::FromSql) impls
 ///
+/// These impls are only provided for PostgreSQL.
+///
 /// - [`PgInterval`] which can be constructed using [`IntervalDsl`]
 /// - [`chrono::Duration`][Duration] with `feature = "chrono"`
-///   (There might be some information loss due to special behavior for literal `month` (or longer) intervals;
-///   Please read official documentation of [PostgreSQL Interval].)
+/// - [`time::Duration`][TimeDuration] with `feature = "time"`
+/// - [`std::time::Duration`][StdDuration], which fails for negative intervals
+///
+/// Converting an interval into one of the duration types assumes that a month
+/// has 30 days and a day has 24 hours, like PostgreSQL's `justify_interval`.
+/// There might therefore be some information loss for literal `month` (or longer) intervals;
+/// Please read official documentation of [PostgreSQL Interval].
+///
+/// ### Interval arithmetic
+///
+/// `date + interval`, `time + interval` and `timestamp + interval` (and the
+/// same with `-`) are rendered for each backend as follows:
+///
+/// - PostgreSQL uses the `+` and `-` operators
+/// - MySQL sends the interval as number of microseconds and uses
+///   `DATE_ADD(x, INTERVAL ? MICROSECOND)` and `DATE_SUB(x, INTERVAL ? MICROSECOND)`
+/// - SQLite sends the interval as number of seconds and uses
+///   `datetime(x, ? || ' seconds')` or `time(x, ? || ' seconds')`. Both functions
+///   return whole seconds, so fractional seconds are dropped.
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// # use diesel::dsl::now;
+/// # use std::time::Duration;
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     let connection = &mut establish_connection();
+/// let in_the_future = diesel::select(now.lt(now + Duration::from_secs(60 * 60)))
+///     .get_result::<bool>(connection)?;
+/// assert!(in_the_future);
+/// #     Ok(())
+/// # }
+/// ```
 ///
 /// [`PgInterval`]: ../pg/data_types/struct.PgInterval.html
 /// [`IntervalDsl`]: ../pg/expression/extensions/trait.IntervalDsl.html
 /// [Duration]: https://docs.rs/chrono/*/chrono/type.Duration.html
+/// [TimeDuration]: https://docs.rs/time/*/time/struct.Duration.html
+/// [StdDuration]: https://doc.rust-lang.org/std/time/struct.Duration.html
 /// [PostgreSQL Interval]: https://www.postgresql.org/docs/current/datatype-datetime.html#DATATYPE-INTERVAL-INPUT
 #[derive(Debug, Clone, Copy, Default, QueryId, SqlType)]
 #[diesel(postgres_type(oid = 1186, array_oid = 1187))]
+#[diesel(sqlite_type(name = "Double"))]
+#[diesel(mysql_type(name = "LongLong"))]
 pub struct Interval;
 
 /// The time SQL type.
@@ -39,6 +39,9 @@ pub trait Add {
     type Rhs: SqlType;
     /// The SQL type of the result of adding `Rhs` to `Self`
     type Output: SqlType;
+
+    #[doc(hidden)]
+    const KIND: OperationKind = OperationKind::Numeric;
 }
 
 /// Represents SQL types which can be subtracted.
@@ -47,6 +50,24 @@ pub trait Sub {
     type Rhs: SqlType;
     /// The SQL type of the result of subtracting `Rhs` from `Self`
     type Output: SqlType;
+
+    #[doc(hidden)]
+    const KIND: OperationKind = OperationKind::Numeric;
+}
+
+/// Which kind of values are combined by an addition or subtraction
+///
+/// Backends which can't add an interval to a date or time with the `+`
+/// operator use this to render the operation as function call instead.
+#[doc(hidden)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OperationKind {
+    /// Any operation which is not listed below
+    Numeric,
+    /// Adding an interval to or subtracting it from a time of day
+    TimeInterval,
+    /// Adding an interval to or subtracting it from a date or timestamp
+    DateTimeInterval,
 }
 
 /// Represents SQL types which can be multiplied.
@@ -96,31 +117,43 @@ numeric_type!(SmallInt, Integer, BigInt, Float, Double, Numeric);
 impl Add for Time {
     type Rhs = Interval;
     type Output = Time;
+
+    const KIND: OperationKind = OperationKind::TimeInterval;
 }
 
 impl Sub for Time {
     type Rhs = Interval;
     type Output = Time;
+
+    const KIND: OperationKind = OperationKind::TimeInterval;
 }
 
 impl Add for Date {
     type Rhs = Interval;
     type Output = Timestamp;
+
+    const KIND: OperationKind = OperationKind::DateTimeInterval;
 }
 
 impl Sub for Date {
     type Rhs = Interval;
     type Output = Timestamp;
+
+    const KIND: OperationKind = OperationKind::DateTimeInterval;
 }
 
 impl Add for Timestamp {
     type Rhs = Interval;
     type Output = Timestamp;
+
+    const KIND: OperationKind = OperationKind::DateTimeInterval;
 }
 
 impl Sub for Timestamp {
     type Rhs = Interval;
     type Output = Timestamp;
+
+    const KIND: OperationKind = OperationKind::DateTimeInterval;
 }
 
 impl Add for Interval {
@@ -151,6 +184,8 @@ where
 {
     type Rhs = Nullable<T::Rhs>;
     type Output = Nullable<T::Output>;
+
+    const KIND: OperationKind = T::KIND;
 }
 
 impl<T> Sub for Nullable<T>
@@ -161,6 +196,8 @@ where
 {
     type Rhs = Nullable<T::Rhs>;
     type Output = Nullable<T::Output>;
+
+    const KIND: OperationKind = T::KIND;
 }
 
 impl<T> Mul for Nullable<T>
@@ -76,6 +76,7 @@ impl SqlDialect for Sqlite {
         sql_dialect::aggregate_function_expressions::PostgresLikeAggregateFunctionExpressions;
     type BuiltInWindowFunctionRequireOrder =
         sql_dialect::built_in_window_function_require_order::NoOrderRequired;
+    type IntervalArithmetic = SqliteIntervalArithmetic;
 }
 
 impl DieselReserveSpecialization for Sqlite {}
@@ -95,3 +96,7 @@ pub struct SqliteBatchInsert;
 pub struct SqliteReturningClause;
 
 impl sql_dialect::returning_clause::SupportsReturningClause for SqliteReturningClause {}
+impl sql_dialect::returning_clause::SupportsReturningClauseForUpdate for SqliteReturningClause {}
+
+#[derive(Debug, Copy, Clone)]
+pub struct SqliteIntervalArithmetic;
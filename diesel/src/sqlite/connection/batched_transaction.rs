@@ -0,0 +1,69 @@
+/// The mode of a WAL checkpoint
+///
+/// See the [SQLite documentation](https://www.sqlite.org/pragma.html#pragma_wal_checkpoint)
+/// for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SqliteWalCheckpointMode {
+    /// Checkpoint as many frames as possible without waiting for readers or writers
+    Passive,
+    /// Wait for writers, then checkpoint all frames
+    Full,
+    /// Like [`Full`](Self::Full), but also wait until readers are done so the
+    /// next writer restarts the WAL file from the beginning
+    Restart,
+    /// Like [`Restart`](Self::Restart), but also truncate the WAL file to zero bytes
+    Truncate,
+}
+
+impl SqliteWalCheckpointMode {
+    pub(super) fn pragma(self) -> &'static str {
+        match self {
+            Self::Passive => "PRAGMA wal_checkpoint(PASSIVE)",
+            Self::Full => "PRAGMA wal_checkpoint(FULL)",
+            Self::Restart => "PRAGMA wal_checkpoint(RESTART)",
+            Self::Truncate => "PRAGMA wal_checkpoint(TRUNCATE)",
+        }
+    }
+}
+
+/// Options for
+/// [`SqliteConnection::immediate_transaction_batched`](super::SqliteConnection::immediate_transaction_batched)
+///
+/// A chunk size can be converted into options using the default settings,
+/// so a plain `usize` can be passed wherever options are expected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SqliteBatchOptions {
+    pub(super) chunk_size: usize,
+    pub(super) checkpoint: Option<SqliteWalCheckpointMode>,
+}
+
+impl SqliteBatchOptions {
+    /// Group the items into chunks of `chunk_size` items, each of them
+    /// committed in its own transaction
+    ///
+    /// A chunk size of `0` is treated as `1`.
+    pub fn new(chunk_size: usize) -> Self {
+        Self {
+            chunk_size: chunk_size.max(1),
+            checkpoint: None,
+        }
+    }
+
+    /// Run a WAL checkpoint with the given mode after each committed chunk
+    ///
+    /// By default no explicit checkpoint is run and SQLite's automatic
+    /// checkpointing applies. Explicit checkpoints keep the WAL file small
+    /// during long running imports. This has no effect if the database
+    /// does not use `journal_mode = WAL`.
+    pub fn checkpoint_after_each_chunk(mut self, mode: SqliteWalCheckpointMode) -> Self {
+        self.checkpoint = Some(mode);
+        self
+    }
+}
+
+impl From<usize> for SqliteBatchOptions {
+    fn from(chunk_size: usize) -> Self {
+        Self::new(chunk_size)
+    }
+}
@@ -88,12 +88,35 @@ impl<'a> SqliteBindCollector<'a> {
 #[derive(Debug)]
 pub struct SqliteBindValue<'a> {
     pub(in crate::sqlite) inner: SqliteBindValueRef<'a>,
+    // Overrides the type metadata of the bound SQL type, if set
+    bind_type: Option<SqliteType>,
+}
+
+impl<'a> SqliteBindValue<'a> {
+    // `Numeric` values use `Double` as type metadata, but are bound
+    // with the storage class that represents them best to keep their
+    // full precision (see `NumericString` and `SqliteNumeric`)
+    pub(in crate::sqlite) fn numeric(value: impl Into<SqliteBindValue<'a>>) -> Self {
+        let inner = value.into().inner;
+        let bind_type = match inner {
+            SqliteBindValueRef::BorrowedString(_) | SqliteBindValueRef::String(_) => {
+                SqliteType::Text
+            }
+            SqliteBindValueRef::I32(_) | SqliteBindValueRef::I64(_) => SqliteType::Long,
+            _ => SqliteType::Double,
+        };
+        Self {
+            inner,
+            bind_type: Some(bind_type),
+        }
+    }
 }
 
 impl From<i32> for SqliteBindValue<'_> {
     fn from(i: i32) -> Self {
         Self {
             inner: SqliteBindValueRef::I32(i),
+            bind_type: None,
         }
     }
 }
@@ -102,6 +125,7 @@ impl From<i64> for SqliteBindValue<'_> {
     fn from(i: i64) -> Self {
         Self {
             inner: SqliteBindValueRef::I64(i),
+            bind_type: None,
         }
     }
 }
@@ -110,6 +134,7 @@ impl From<f64> for SqliteBindValue<'_> {
     fn from(f: f64) -> Self {
         Self {
             inner: SqliteBindValueRef::F64(f),
+            bind_type: None,
         }
     }
 }
@@ -123,6 +148,7 @@ where
             Some(v) => v.into(),
             None => Self {
                 inner: SqliteBindValueRef::Null,
+                bind_type: None,
             },
         }
     }
@@ -132,6 +158,7 @@ impl<'a> From<&'a str> for SqliteBindValue<'a> {
     fn from(s: &'a str) -> Self {
         Self {
             inner: SqliteBindValueRef::BorrowedString(s),
+            bind_type: None,
         }
     }
 }
@@ -140,6 +167,7 @@ impl From<String> for SqliteBindValue<'_> {
     fn from(s: String) -> Self {
         Self {
             inner: SqliteBindValueRef::String(s.into_boxed_str()),
+            bind_type: None,
         }
     }
 }
@@ -148,6 +176,7 @@ impl From<Vec<u8>> for SqliteBindValue<'_> {
     fn from(b: Vec<u8>) -> Self {
         Self {
             inner: SqliteBindValueRef::Binary(b.into_boxed_slice()),
+            bind_type: None,
         }
     }
 }
@@ -156,6 +185,7 @@ impl<'a> From<&'a [u8]> for SqliteBindValue<'a> {
     fn from(b: &'a [u8]) -> Self {
         Self {
             inner: SqliteBindValueRef::BorrowedBinary(b),
+            bind_type: None,
         }
     }
 }
@@ -258,13 +288,16 @@ impl<'a> BindCollector<'a, Sqlite> for SqliteBindCollector<'a> {
     {
         let value = SqliteBindValue {
             inner: SqliteBindValueRef::Null,
+            bind_type: None,
         };
         let mut to_sql_output = Output::new(value, metadata_lookup);
         let is_null = bind
             .to_sql(&mut to_sql_output)
             .map_err(crate::result::Error::SerializationError)?;
         let bind = to_sql_output.into_inner();
-        let metadata = Sqlite::metadata(metadata_lookup);
+        let metadata = bind
+            .bind_type
+            .unwrap_or_else(|| Sqlite::metadata(metadata_lookup));
         self.binds.push((
             match is_null {
                 IsNull::No => bind.inner,
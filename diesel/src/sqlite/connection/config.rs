@@ -0,0 +1,359 @@
+use alloc::borrow::Cow;
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::time::Duration;
+
+use crate::result::ConnectionError;
+
+/// The journal mode of a SQLite database
+///
+/// See the [SQLite documentation](https://www.sqlite.org/pragma.html#pragma_journal_mode)
+/// for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SqliteJournalMode {
+    /// Delete the rollback journal at the end of each transaction
+    Delete,
+    /// Truncate the rollback journal to zero bytes instead of deleting it
+    Truncate,
+    /// Overwrite the header of the rollback journal with zeros instead of deleting it
+    Persist,
+    /// Store the rollback journal in memory
+    Memory,
+    /// Use a write-ahead log instead of a rollback journal
+    Wal,
+    /// Disable the rollback journal
+    Off,
+}
+
+impl SqliteJournalMode {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Delete => "DELETE",
+            Self::Truncate => "TRUNCATE",
+            Self::Persist => "PERSIST",
+            Self::Memory => "MEMORY",
+            Self::Wal => "WAL",
+            Self::Off => "OFF",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        [
+            Self::Delete,
+            Self::Truncate,
+            Self::Persist,
+            Self::Memory,
+            Self::Wal,
+            Self::Off,
+        ]
+        .into_iter()
+        .find(|mode| mode.as_str().eq_ignore_ascii_case(value))
+    }
+}
+
+/// How often SQLite syncs written data to disk
+///
+/// See the [SQLite documentation](https://www.sqlite.org/pragma.html#pragma_synchronous)
+/// for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SqliteSynchronous {
+    /// Hand off data to the operating system without syncing
+    Off,
+    /// Sync at the most critical moments, which is safe in WAL mode
+    Normal,
+    /// Sync before each commit
+    Full,
+    /// Like [`Full`](Self::Full), but also sync the directory after
+    /// deleting a rollback journal
+    Extra,
+}
+
+impl SqliteSynchronous {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Off => "OFF",
+            Self::Normal => "NORMAL",
+            Self::Full => "FULL",
+            Self::Extra => "EXTRA",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        [Self::Off, Self::Normal, Self::Full, Self::Extra]
+            .into_iter()
+            .find(|mode| mode.as_str().eq_ignore_ascii_case(value))
+    }
+}
+
+/// The size of the page cache of a SQLite connection
+///
+/// See the [SQLite documentation](https://www.sqlite.org/pragma.html#pragma_cache_size)
+/// for details.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SqliteCacheSize {
+    /// Cache the given number of pages
+    Pages(u32),
+    /// Cache as many pages as fit into the given number of kibibytes
+    Kibibytes(u32),
+}
+
+impl SqliteCacheSize {
+    fn pragma_value(self) -> i64 {
+        match self {
+            Self::Pages(pages) => i64::from(pages),
+            Self::Kibibytes(kib) => -i64::from(kib),
+        }
+    }
+}
+
+/// Connection level settings for [`SqliteConnection`](super::SqliteConnection)
+///
+/// Each setting corresponds to a `PRAGMA` and is left at SQLite's default
+/// unless it is set explicitly. The settings are applied when the connection
+/// is established via
+/// [`SqliteConnection::establish_with_config`](super::SqliteConnection::establish_with_config)
+/// or later on via
+/// [`SqliteConnection::apply_config`](super::SqliteConnection::apply_config).
+///
+/// The settings can also be given as query parameters of a
+/// `file:` or `sqlite://` connection URL, see
+/// [`SqliteConnection`](super::SqliteConnection) for details.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// use diesel::sqlite::{SqliteConnectionConfig, SqliteJournalMode, SqliteSynchronous};
+/// use std::time::Duration;
+///
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> Result<(), Box<dyn std::error::Error>> {
+/// #     let dir = tempfile::tempdir()?;
+/// #     let path = dir.path().join("app.db");
+/// #     let path = path.to_str().unwrap();
+/// let config = SqliteConnectionConfig::new()
+///     .busy_timeout(Duration::from_secs(2))
+///     .journal_mode(SqliteJournalMode::Wal)
+///     .synchronous(SqliteSynchronous::Normal)
+///     .foreign_keys(true);
+/// let conn = &mut SqliteConnection::establish_with_config(path, &config)?;
+/// # assert_eq!(conn.config(), &config);
+/// #     Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SqliteConnectionConfig {
+    busy_timeout: Option<Duration>,
+    journal_mode: Option<SqliteJournalMode>,
+    synchronous: Option<SqliteSynchronous>,
+    foreign_keys: Option<bool>,
+    cache_size: Option<SqliteCacheSize>,
+    mmap_size: Option<u64>,
+    query_only: Option<bool>,
+}
+
+impl SqliteConnectionConfig {
+    /// Creates a configuration which leaves every setting at SQLite's default
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wait up to `timeout` for locks held by other connections
+    /// instead of failing with `database is locked`
+    ///
+    /// Timeouts longer than `i32::MAX` milliseconds are truncated.
+    pub fn busy_timeout(mut self, timeout: Duration) -> Self {
+        self.busy_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the journal mode of the database
+    ///
+    /// The journal mode can't be changed for in-memory databases, SQLite
+    /// keeps using `MEMORY` in this case.
+    pub fn journal_mode(mut self, mode: SqliteJournalMode) -> Self {
+        self.journal_mode = Some(mode);
+        self
+    }
+
+    /// Set how often SQLite syncs written data to disk
+    pub fn synchronous(mut self, synchronous: SqliteSynchronous) -> Self {
+        self.synchronous = Some(synchronous);
+        self
+    }
+
+    /// Enable or disable the enforcement of foreign key constraints
+    pub fn foreign_keys(mut self, enabled: bool) -> Self {
+        self.foreign_keys = Some(enabled);
+        self
+    }
+
+    /// Set the size of the page cache
+    pub fn cache_size(mut self, size: SqliteCacheSize) -> Self {
+        self.cache_size = Some(size);
+        self
+    }
+
+    /// Set the maximum number of bytes of the database file
+    /// that are accessed via memory mapped I/O
+    ///
+    /// `0` disables memory mapped I/O.
+    pub fn mmap_size(mut self, bytes: u64) -> Self {
+        self.mmap_size = Some(bytes);
+        self
+    }
+
+    /// Reject any statement modifying the database
+    ///
+    /// Unlike opening the database with `mode=ro`, this can be changed
+    /// for an existing connection.
+    pub fn query_only(mut self, enabled: bool) -> Self {
+        self.query_only = Some(enabled);
+        self
+    }
+
+    /// Whether the connection rejects statements modifying the database
+    pub(crate) fn is_query_only(&self) -> bool {
+        self.query_only.unwrap_or(false)
+    }
+
+    /// Settings explicitly set in `other` replace the settings in `self`
+    pub(super) fn merge(self, other: &Self) -> Self {
+        Self {
+            busy_timeout: other.busy_timeout.or(self.busy_timeout),
+            journal_mode: other.journal_mode.or(self.journal_mode),
+            synchronous: other.synchronous.or(self.synchronous),
+            foreign_keys: other.foreign_keys.or(self.foreign_keys),
+            cache_size: other.cache_size.or(self.cache_size),
+            mmap_size: other.mmap_size.or(self.mmap_size),
+            query_only: other.query_only.or(self.query_only),
+        }
+    }
+
+    /// Returns the `PRAGMA` statements applying this configuration
+    ///
+    /// Each `PRAGMA` has to be executed on its own. `busy_timeout` comes first
+    /// so that the following statements already wait for locks, `query_only`
+    /// comes last as it would reject changing the journal mode.
+    pub(super) fn pragmas(&self) -> Vec<String> {
+        let mut pragmas = Vec::new();
+        if let Some(timeout) = self.busy_timeout {
+            let millis = i32::try_from(timeout.as_millis()).unwrap_or(i32::MAX);
+            pragmas.push(format!("PRAGMA busy_timeout = {millis}"));
+        }
+        if let Some(mode) = self.journal_mode {
+            pragmas.push(format!("PRAGMA journal_mode = {}", mode.as_str()));
+        }
+        if let Some(synchronous) = self.synchronous {
+            pragmas.push(format!("PRAGMA synchronous = {}", synchronous.as_str()));
+        }
+        if let Some(enabled) = self.foreign_keys {
+            pragmas.push(format!("PRAGMA foreign_keys = {}", i32::from(enabled)));
+        }
+        if let Some(size) = self.cache_size {
+            pragmas.push(format!("PRAGMA cache_size = {}", size.pragma_value()));
+        }
+        if let Some(bytes) = self.mmap_size {
+            pragmas.push(format!("PRAGMA mmap_size = {bytes}"));
+        }
+        if let Some(enabled) = self.query_only {
+            pragmas.push(format!("PRAGMA query_only = {}", i32::from(enabled)));
+        }
+        pragmas
+    }
+
+    /// Removes the query parameters configuring the connection from
+    /// `database_url`
+    ///
+    /// Only `file:` and `sqlite://` URLs are inspected, as a `?` is a valid
+    /// part of a plain file name. Any other query parameter, like `mode=ro`,
+    /// is left in place to be interpreted by SQLite itself.
+    pub(super) fn from_url(database_url: &str) -> Result<(Cow<'_, str>, Self), ConnectionError> {
+        let mut config = Self::default();
+        let is_uri = database_url.starts_with("file:") || database_url.starts_with("sqlite://");
+        let Some((base, query)) = database_url.split_once('?').filter(|_| is_uri) else {
+            return Ok((Cow::Borrowed(database_url), config));
+        };
+        let (query, fragment) = match query.split_once('#') {
+            Some((query, fragment)) => (query, Some(fragment)),
+            None => (query, None),
+        };
+
+        let mut remaining = Vec::new();
+        for param in query.split('&').filter(|p| !p.is_empty()) {
+            let (key, value) = param.split_once('=').unwrap_or((param, ""));
+            if !config.parse_param(key, value)? {
+                remaining.push(param);
+            }
+        }
+
+        let mut url = base.to_string();
+        if !remaining.is_empty() {
+            url.push('?');
+            url.push_str(&remaining.join("&"));
+        }
+        if let Some(fragment) = fragment {
+            url.push('#');
+            url.push_str(fragment);
+        }
+        Ok((Cow::Owned(url), config))
+    }
+
+    /// Returns `false` if `key` is not a setting of this configuration
+    fn parse_param(&mut self, key: &str, value: &str) -> Result<bool, ConnectionError> {
+        fn invalid(key: &str, value: &str) -> ConnectionError {
+            ConnectionError::InvalidConnectionUrl(format!(
+                "Invalid value `{value}` for the `{key}` query parameter"
+            ))
+        }
+        fn parse_bool(key: &str, value: &str) -> Result<bool, ConnectionError> {
+            match value.to_ascii_lowercase().as_str() {
+                "true" | "on" | "yes" | "1" => Ok(true),
+                "false" | "off" | "no" | "0" => Ok(false),
+                _ => Err(invalid(key, value)),
+            }
+        }
+
+        match key {
+            "busy_timeout" => {
+                let millis = value.parse().map_err(|_| invalid(key, value))?;
+                self.busy_timeout = Some(Duration::from_millis(millis));
+            }
+            "journal_mode" => {
+                let mode = SqliteJournalMode::parse(value).ok_or_else(|| invalid(key, value))?;
+                self.journal_mode = Some(mode);
+            }
+            "wal" => {
+                if parse_bool(key, value)? {
+                    self.journal_mode = Some(SqliteJournalMode::Wal);
+                }
+            }
+            "synchronous" => {
+                let synchronous =
+                    SqliteSynchronous::parse(value).ok_or_else(|| invalid(key, value))?;
+                self.synchronous = Some(synchronous);
+            }
+            "foreign_keys" => self.foreign_keys = Some(parse_bool(key, value)?),
+            "cache_size" => {
+                let size: i64 = value.parse().map_err(|_| invalid(key, value))?;
+                let magnitude =
+                    u32::try_from(size.unsigned_abs()).map_err(|_| invalid(key, value))?;
+                self.cache_size = Some(if size < 0 {
+                    SqliteCacheSize::Kibibytes(magnitude)
+                } else {
+                    SqliteCacheSize::Pages(magnitude)
+                });
+            }
+            "mmap_size" => self.mmap_size = Some(value.parse().map_err(|_| invalid(key, value))?),
+            "query_only" => self.query_only = Some(parse_bool(key, value)?),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+}
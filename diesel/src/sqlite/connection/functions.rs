@@ -111,9 +111,7 @@ where
     Sqlite: HasSqlType<RetSqlType>,
 {
     let mut metadata_lookup = ();
-    let value = SqliteBindValue {
-        inner: SqliteBindValueRef::Null,
-    };
+    let value = SqliteBindValue::from(None::<i32>);
     let mut buf = Output::new(value, &mut metadata_lookup);
     let is_null = result.to_sql(&mut buf).map_err(Error::SerializationError)?;
 
@@ -124,14 +122,14 @@ where
     }
 }
 
-struct FunctionRow<'a> {
+pub(super) struct FunctionRow<'a> {
     args: &'a [Option<OwnedSqliteValue>],
     field_count: usize,
 }
 
 impl FunctionRow<'_> {
     #[allow(unsafe_code)] // complicated ptr cast
-    fn new(args: &mut [*mut ffi::sqlite3_value]) -> Self {
+    pub(super) fn new(args: &mut [*mut ffi::sqlite3_value]) -> Self {
         let lengths = args.len();
         let args = unsafe {
             core::slice::from_raw_parts(
@@ -207,7 +205,7 @@ impl<'a> RowIndex<&'a str> for FunctionRow<'_> {
     }
 }
 
-struct FunctionArgument<'a> {
+pub(super) struct FunctionArgument<'a> {
     args: &'a [Option<OwnedSqliteValue>],
     col_idx: usize,
 }
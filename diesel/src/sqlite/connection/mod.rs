@@ -5,8 +5,10 @@ extern crate libsqlite3_sys as ffi;
 use sqlite_wasm_rs as ffi;
 
 pub mod authorizer;
+mod batched_transaction;
 mod bind_collector;
 mod collation_needed;
+mod config;
 mod functions;
 mod hooks;
 mod limits;
@@ -20,8 +22,10 @@ mod statement_iterator;
 mod stmt;
 mod trace;
 mod update_hook;
+pub mod virtual_table;
 
 pub use self::authorizer::{AuthorizerContext, AuthorizerDecision};
+pub use self::batched_transaction::{SqliteBatchOptions, SqliteWalCheckpointMode};
 #[diesel_derives::__diesel_public_if(
     feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
 )]
@@ -30,6 +34,9 @@ pub use self::bind_collector::SqliteBindValue;
 #[cfg(feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes")]
 pub use self::bind_collector::{OwnedSqliteBindValue, SqliteBindCollectorData, SqliteBindValueRef};
 pub use self::collation_needed::{CollationNeededContext, SqliteTextRep};
+pub use self::config::{
+    SqliteCacheSize, SqliteConnectionConfig, SqliteJournalMode, SqliteSynchronous,
+};
 pub use self::limits::SqliteLimit;
 use self::raw::RawConnection;
 pub use self::serialized_database::SerializedDatabase;
@@ -52,7 +59,8 @@ use crate::result::*;
 use crate::serialize::ToSql;
 use crate::sql_types::{HasSqlType, TypeMetadata};
 use crate::sqlite::{Sqlite, SqliteFunctionBehavior};
-use alloc::string::String;
+use alloc::boxed::Box;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
 use core::ffi as libc;
 use core::num::NonZeroI64;
@@ -64,6 +72,22 @@ use core::num::NonZeroI64;
 /// - [URIs](https://sqlite.org/uri.html) (`file://test.db`)
 /// - Special identifiers (`:memory:`)
 ///
+/// `file:` and `sqlite://` URLs accept the following query parameters to
+/// configure the connection, which correspond to the settings of
+/// [`SqliteConnectionConfig`]:
+///
+/// - `busy_timeout=<milliseconds>`
+/// - `journal_mode=<delete|truncate|persist|memory|wal|off>`
+/// - `wal=<true|false>`, a shorthand for `journal_mode=wal`
+/// - `synchronous=<off|normal|full|extra>`
+/// - `foreign_keys=<true|false>`
+/// - `cache_size=<n>`, with negative values giving the size in kibibytes
+/// - `mmap_size=<bytes>`
+/// - `query_only=<true|false>`
+///
+/// Any other query parameter, like `mode=ro` to open the database read only,
+/// is passed on to SQLite (`sqlite://test.db?mode=ro&wal=true`).
+///
 /// # Supported loading model implementations
 ///
 /// * [`DefaultLoadingMode`]
@@ -148,7 +172,9 @@ use core::num::NonZeroI64;
 /// `Database locked` error. However, it's possible to configure it for greater concurrency,
 /// trading latency for not having to deal with retries yourself.
 ///
-/// You can use this example as blue-print for which statements to run after establishing a connection.
+/// Most of these settings can be given as [`SqliteConnectionConfig`] to
+/// [`SqliteConnection::establish_with_config`], which applies them in the right order.
+/// Otherwise you can use this example as blue-print for which statements to run after establishing a connection.
 /// It is **important** to run each `PRAGMA` in a single statement to make sure all of them apply
 /// correctly. In addition the order of the `PRAGMA` statements is relevant to prevent timeout
 /// issues for the later `PRAGMA` statements.
@@ -203,6 +229,7 @@ pub struct SqliteConnection {
     //
     // We are not allowed to modify the inner buffer until the database connection is dropped
     serialized_data: Vec<Vec<u8>>,
+    config: SqliteConnectionConfig,
 }
 
 // This relies on the invariant that RawConnection or Statement are never
@@ -219,10 +246,10 @@ impl SimpleConnection for SqliteConnection {
             });
         let resp = self.raw_connection.exec(query);
         self.instrumentation
-            .on_connection_event(InstrumentationEvent::FinishQuery {
-                query: &StrQueryHelper::new(query),
-                error: resp.as_ref().err(),
-            });
+            .on_connection_event(InstrumentationEvent::finish_query(
+                &StrQueryHelper::new(query),
+                resp.as_ref().err(),
+            ));
         resp
     }
 }
@@ -249,19 +276,7 @@ impl Connection for SqliteConnection {
     /// * Persistent VFS (Virtual File Systems) is optional,
     ///   see <https://github.com/Spxg/sqlite-wasm-rs> for details
     fn establish(database_url: &str) -> ConnectionResult<Self> {
-        let mut instrumentation = DynInstrumentation::default_instrumentation();
-        instrumentation.on_connection_event(InstrumentationEvent::StartEstablishConnection {
-            url: database_url,
-        });
-
-        let establish_result = Self::establish_inner(database_url);
-        instrumentation.on_connection_event(InstrumentationEvent::FinishEstablishConnection {
-            url: database_url,
-            error: establish_result.as_ref().err(),
-        });
-        let mut conn = establish_result?;
-        conn.instrumentation = instrumentation;
-        Ok(conn)
+        Self::establish_with_config(database_url, &SqliteConnectionConfig::default())
     }
 
     fn execute_returning_count<T>(&mut self, source: &T) -> QueryResult<usize>
@@ -276,6 +291,19 @@ impl Connection for SqliteConnection {
         })
     }
 
+    fn begin_transaction_with_sql(
+        &mut self,
+        isolation: Isolation,
+        access: Access,
+    ) -> QueryResult<alloc::borrow::Cow<'static, str>> {
+        // SQLite transactions are always serializable, which satisfies
+        // every weaker isolation level as well
+        match access {
+            Access::ReadWrite => Ok("BEGIN".into()),
+            Access::ReadOnly => Err(Error::UnsupportedTransactionOptions { isolation, access }),
+        }
+    }
+
     fn transaction_state(&mut self) -> &mut AnsiTransactionManager
     where
         Self: Sized,
@@ -294,6 +322,18 @@ impl Connection for SqliteConnection {
     fn set_prepared_statement_cache_size(&mut self, size: CacheSize) {
         self.statement_cache.set_cache_size(size);
     }
+
+    fn clear_prepared_statement_cache(&mut self) {
+        self.statement_cache.clear();
+    }
+
+    fn cached_statements(&self) -> Vec<String> {
+        self.statement_cache
+            .cached_sql()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    }
 }
 
 impl LoadConnection<DefaultLoadingMode> for SqliteConnection {
@@ -378,6 +418,143 @@ pub enum BusyDecision {
 }
 
 impl SqliteConnection {
+    /// Establish a connection to the database specified by `database_url`
+    /// and apply the given configuration
+    ///
+    /// Settings given as query parameters of `database_url` are applied as
+    /// well, settings in `config` take precedence over them. See
+    /// [`SqliteConnection`] for the supported URLs and query parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// use diesel::sqlite::{SqliteCacheSize, SqliteConnectionConfig};
+    ///
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::sql;
+    /// #     use diesel::sql_types::{BigInt, Bool};
+    /// let config = SqliteConnectionConfig::new()
+    ///     .foreign_keys(true)
+    ///     .cache_size(SqliteCacheSize::Kibibytes(8192));
+    /// let conn = &mut SqliteConnection::establish_with_config(":memory:", &config).unwrap();
+    ///
+    /// let foreign_keys = diesel::select(sql::<Bool>("foreign_keys FROM pragma_foreign_keys"))
+    ///     .get_result::<bool>(conn)?;
+    /// assert!(foreign_keys);
+    /// let cache_size = diesel::select(sql::<BigInt>("cache_size FROM pragma_cache_size"))
+    ///     .get_result::<i64>(conn)?;
+    /// assert_eq!(-8192, cache_size);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn establish_with_config(
+        database_url: &str,
+        config: &SqliteConnectionConfig,
+    ) -> ConnectionResult<Self> {
+        let mut instrumentation = DynInstrumentation::default_instrumentation();
+        instrumentation.on_connection_event(InstrumentationEvent::StartEstablishConnection {
+            url: database_url,
+        });
+
+        let establish_result = Self::establish_inner(database_url, config);
+        instrumentation.on_connection_event(InstrumentationEvent::FinishEstablishConnection {
+            url: database_url,
+            error: establish_result.as_ref().err(),
+        });
+        let mut conn = establish_result?;
+        conn.instrumentation = instrumentation;
+        Ok(conn)
+    }
+
+    /// Apply the given configuration to this connection
+    ///
+    /// Settings which are not set in `config` keep their current value.
+    /// SQLite ignores changes of the journal mode and of foreign key
+    /// enforcement inside of a transaction, therefore this method returns
+    /// [`Error::AlreadyInTransaction`] if a transaction is open.
+    ///
+    /// The configuration outlives transactions. In particular,
+    /// a [read only transaction](crate::sqlite::TransactionBuilder::read_only)
+    /// restores the configured
+    /// [`query_only`](SqliteConnectionConfig::query_only) setting once it ends.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// use diesel::sqlite::SqliteConnectionConfig;
+    ///
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// conn.apply_config(&SqliteConnectionConfig::new().query_only(true))?;
+    ///
+    /// let write_attempt = diesel::insert_into(users)
+    ///     .values(name.eq("Ruby"))
+    ///     .execute(conn);
+    /// assert!(write_attempt.is_err());
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn apply_config(&mut self, config: &SqliteConnectionConfig) -> QueryResult<()> {
+        if AnsiTransactionManager::transaction_manager_status_mut(self)
+            .transaction_depth()?
+            .is_some()
+        {
+            return Err(Error::AlreadyInTransaction);
+        }
+        for pragma in config.pragmas() {
+            self.batch_execute(&pragma)?;
+        }
+        self.config = self.config.merge(config);
+        Ok(())
+    }
+
+    /// The configuration applied to this connection
+    ///
+    /// This only contains settings applied via
+    /// [`establish_with_config`](Self::establish_with_config),
+    /// [`apply_config`](Self::apply_config) or the connection URL.
+    pub fn config(&self) -> &SqliteConnectionConfig {
+        &self.config
+    }
+
+    /// Build a transaction, specifying additional details such as
+    /// `BEGIN IMMEDIATE` or a read only transaction
+    ///
+    /// See [`TransactionBuilder`] for more examples.
+    ///
+    /// [`TransactionBuilder`]: crate::sqlite::TransactionBuilder
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// conn.build_transaction()
+    ///     .immediate()
+    ///     .read_only()
+    ///     .run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn build_transaction(&mut self) -> crate::sqlite::TransactionBuilder<'_, Self> {
+        let query_only = self.config.is_query_only();
+        crate::sqlite::TransactionBuilder::new(self).restore_query_only(query_only)
+    }
+
     /// Run a transaction with `BEGIN IMMEDIATE`
     ///
     /// This method will return an error if a transaction is already open.
@@ -436,6 +613,75 @@ impl SqliteConnection {
         self.transaction_sql(f, "BEGIN EXCLUSIVE")
     }
 
+    /// Process `items` in chunks, each of them in its own `BEGIN IMMEDIATE` transaction
+    ///
+    /// Committing each row in its own transaction is slow on SQLite, while
+    /// a single transaction for a huge amount of rows blocks other writers
+    /// for a long time. This method groups the items into chunks of the size
+    /// given by `options`, which accepts a plain chunk size or
+    /// [`SqliteBatchOptions`] to also run a WAL checkpoint after each chunk.
+    /// `f` is called once per chunk inside of a new transaction, and the
+    /// results of all chunks are returned in order.
+    ///
+    /// If `f` returns an error, the transaction of the current chunk is rolled
+    /// back and the error is returned. Chunks committed before are kept.
+    ///
+    /// This method will return an error if a transaction is already open,
+    /// as the chunks cannot be committed in that case.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users;
+    /// #     let mut conn = establish_connection();
+    /// use diesel::sqlite::{SqliteBatchOptions, SqliteWalCheckpointMode};
+    ///
+    /// let names = (0..1000).map(|i| users::name.eq(format!("User {i}")));
+    /// let options = SqliteBatchOptions::new(250)
+    ///     .checkpoint_after_each_chunk(SqliteWalCheckpointMode::Passive);
+    /// let inserted = conn.immediate_transaction_batched(names, options, |conn, chunk| {
+    ///     diesel::insert_into(users::table)
+    ///         .values(chunk)
+    ///         .execute(conn)
+    /// })?;
+    /// assert_eq!(vec![250; 4], inserted);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn immediate_transaction_batched<I, T, E, F>(
+        &mut self,
+        items: I,
+        options: impl Into<SqliteBatchOptions>,
+        mut f: F,
+    ) -> Result<Vec<T>, E>
+    where
+        I: IntoIterator,
+        F: FnMut(&mut Self, Vec<I::Item>) -> Result<T, E>,
+        E: From<Error>,
+    {
+        let options = options.into();
+        let mut items = items.into_iter();
+        let mut results = Vec::new();
+        loop {
+            let chunk = items.by_ref().take(options.chunk_size).collect::<Vec<_>>();
+            if chunk.is_empty() {
+                break;
+            }
+            results.push(self.transaction_sql(|conn| f(conn, chunk), "BEGIN IMMEDIATE")?);
+            if let Some(checkpoint) = options.checkpoint {
+                self.batch_execute(checkpoint.pragma())?;
+            }
+        }
+        Ok(results)
+    }
+
     /// Returns the rowid of the most recent successful INSERT on this connection.
     ///
     /// Returns `None` if no successful INSERT into a rowid table has been performed
@@ -520,7 +766,71 @@ impl SqliteConnection {
         let table_name = table.table();
 
         self.raw_connection
-            .blob_open(database_name, table_name, column_name, row_id)
+            .blob_open(database_name, table_name, column_name, row_id, false)
+    }
+
+    /// Returns an object that can be used to incrementally read and write a BLOB
+    ///
+    /// Writing to the returned object cannot change the size of the BLOB.
+    /// Use [`zeroblob`](crate::sqlite::expression::functions::zeroblob()) to reserve
+    /// space for a BLOB that should be streamed into the database.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// # table! {
+    /// #     myblobs {
+    /// #         id -> Integer,
+    /// #         mydata -> Blob,
+    /// #     }
+    /// # }
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// # fn run_test() -> Result<(), Box<dyn std::error::Error>> {
+    /// use diesel::dsl::zeroblob;
+    /// use diesel::connection::SimpleConnection;
+    /// use std::io::{Read, Seek, SeekFrom, Write};
+    ///
+    /// let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// conn.batch_execute("CREATE TABLE myblobs (id INTEGER PRIMARY KEY, mydata BLOB)")?;
+    /// diesel::insert_into(myblobs::table)
+    ///     .values((myblobs::id.eq(1), myblobs::mydata.eq(zeroblob(6))))
+    ///     .execute(conn)?;
+    ///
+    /// let mut data = conn.get_blob(myblobs::mydata, 1)?;
+    /// // data is written in chunks, without buffering the whole blob in memory
+    /// for chunk in [b"abc", b"def"] {
+    ///     data.write_all(chunk)?;
+    /// }
+    /// data.seek(SeekFrom::Start(0))?;
+    /// let mut buf = vec![];
+    /// data.read_to_end(&mut buf)?;
+    /// assert_eq!(buf, b"abcdef");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_blob<'conn, 'query, U>(
+        &'conn mut self,
+        blob_column: U,
+        row_id: i64,
+    ) -> Result<sqlite_blob::SqliteBlob<'conn>, Error>
+    where
+        'query: 'conn,
+        U: ColumnHasTable,
+        U::Table: NamedTable,
+    {
+        let table = blob_column.table();
+
+        let database_name = table.schema().unwrap_or("main");
+        let column_name = blob_column.name();
+        let table_name = table.table();
+
+        let inner =
+            self.raw_connection
+                .blob_open(database_name, table_name, column_name, row_id, true)?;
+        Ok(sqlite_blob::SqliteBlob { inner })
     }
 
     fn transaction_sql<T, E, F>(&mut self, f: F, sql: &str) -> Result<T, E>
@@ -560,21 +870,21 @@ impl SqliteConnection {
             &[],
             raw_connection,
             Statement::prepare,
-            &mut *self.instrumentation,
+            self.instrumentation.tracked(),
         ) {
             Ok(statement) => statement,
             Err(e) => {
                 self.instrumentation
-                    .on_connection_event(InstrumentationEvent::FinishQuery {
-                        query: &crate::debug_query(&source),
-                        error: Some(&e),
-                    });
+                    .on_connection_event(InstrumentationEvent::finish_query(
+                        &crate::debug_query(&source),
+                        Some(&e),
+                    ));
 
                 return Err(e);
             }
         };
 
-        StatementUse::bind(statement, source, &mut *self.instrumentation)
+        StatementUse::bind(statement, source, self.instrumentation.tracked())
     }
 
     #[doc(hidden)]
@@ -668,6 +978,24 @@ impl SqliteConnection {
             .register_collation_function(collation_name, collation)
     }
 
+    /// Register a virtual table module
+    ///
+    /// Virtual tables provide their content from rust code, which
+    /// allows to query data sources like CSV files with SQL. After registering the module
+    /// a table can be created via `CREATE VIRTUAL TABLE my_table USING module_name(arguments)`.
+    /// See [`VirtualTable`](virtual_table::VirtualTable) for an example.
+    ///
+    /// If the name is already registered it will be overwritten.
+    ///
+    /// The module is only registered for this connection, so it needs to be registered
+    /// on every connection accessing a virtual table created with it.
+    pub fn register_virtual_table<T>(&mut self, module_name: &str) -> QueryResult<()>
+    where
+        T: virtual_table::VirtualTable,
+    {
+        self.raw_connection.register_virtual_table::<T>(module_name)
+    }
+
     /// Serialize the current SQLite database into a byte buffer.
     ///
     /// The serialized data is identical to the data that would be written to disk if the database
@@ -735,6 +1063,120 @@ impl SqliteConnection {
         }
     }
 
+    /// Copies the content of this database into the database of `destination`
+    ///
+    /// This uses SQLite's [online backup API](https://www.sqlite.org/backup.html),
+    /// so it's safe to call while other connections read from or write
+    /// to either database. The previous content of the destination database
+    /// is replaced. The copy is made in small steps, other connections can
+    /// access the source database in between. If the source database is
+    /// modified by another connection during the backup, the backup restarts
+    /// to produce a consistent snapshot.
+    ///
+    /// Only the `main` database is copied, attached databases are not included.
+    /// The backup fails if `destination` is reading from or writing to its
+    /// database in an open transaction.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// let snapshot = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// connection.backup_to(snapshot)?;
+    ///
+    /// let names = users.select(name).order(id).load::<String>(snapshot)?;
+    /// assert_eq!(vec!["Sean", "Tess"], names);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn backup_to(&mut self, destination: &mut SqliteConnection) -> QueryResult<()> {
+        self.raw_connection.backup_to(&destination.raw_connection)
+    }
+
+    /// Copies the content of this database into the database file at `path`
+    ///
+    /// The file is created if it does not exist yet, otherwise its content
+    /// is replaced. `path` accepts the same values as
+    /// [`SqliteConnection::establish`](Connection::establish). See
+    /// [`SqliteConnection::backup_to`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// #     let dir = tempfile::tempdir().unwrap();
+    /// #     let path = dir.path().join("backup.db");
+    /// #     let path = path.to_str().unwrap();
+    /// connection.backup_to_file(path)?;
+    ///
+    /// let backup = &mut SqliteConnection::establish(path).unwrap();
+    /// assert_eq!(2, users.count().get_result::<i64>(backup)?);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn backup_to_file(&mut self, path: &str) -> QueryResult<()> {
+        let destination = RawConnection::establish(path).map_err(|e| {
+            Error::DatabaseError(DatabaseErrorKind::Unknown, Box::new(e.to_string()))
+        })?;
+        self.raw_connection.backup_to(&destination)
+    }
+
+    /// Writes a vacuumed copy of this database into a new database file at `path`
+    ///
+    /// This executes [`VACUUM INTO`](https://www.sqlite.org/lang_vacuum.html#vacuuminto),
+    /// which writes a consistent snapshot of the database in a single
+    /// read transaction, so it's safe to call while other connections write
+    /// to the database. In contrast to [`SqliteConnection::backup_to_file`]
+    /// the copy doesn't contain any unused pages, but it's not possible to
+    /// overwrite an existing, non-empty file.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let connection = &mut establish_connection();
+    /// #     let dir = tempfile::tempdir().unwrap();
+    /// #     let path = dir.path().join("snapshot.db");
+    /// #     let path = path.to_str().unwrap();
+    /// connection.vacuum_into(path)?;
+    ///
+    /// let snapshot = &mut SqliteConnection::establish(path).unwrap();
+    /// assert_eq!(2, users.count().get_result::<i64>(snapshot)?);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn vacuum_into(&mut self, path: &str) -> QueryResult<()> {
+        use crate::RunQueryDsl;
+
+        crate::sql_query("VACUUM INTO ?")
+            .bind::<crate::sql_types::Text, _>(path)
+            .execute(self)
+            .map(|_| ())
+    }
+
     /// Provides temporary access to the raw SQLite database connection handle.
     ///
     /// This method provides a way to access the underlying `sqlite3` pointer,
@@ -869,6 +1311,7 @@ impl SqliteConnection {
             metadata_lookup: (),
             instrumentation: DynInstrumentation::default_instrumentation(),
             serialized_data: Vec::new(),
+            config: SqliteConnectionConfig::default(),
         }));
 
         let result = f(&mut conn.0);
@@ -1071,13 +1514,14 @@ impl SqliteConnection {
             .get_db_config_bool(ffi::SQLITE_DBCONFIG_TRUSTED_SCHEMA)
     }
 
-    /// Runs the given closure with the `load_extension()` SQL function enabled,
-    /// disabling it again afterwards.
+    /// Runs the given closure with extension loading enabled, disabling it
+    /// again afterwards.
     ///
-    /// This controls the [`load_extension()`](https://www.sqlite.org/lang_corefunc.html#load_extension)
-    /// **SQL function**, not the `sqlite3_load_extension()` C API (which Diesel
-    /// does not expose). Extension loading is off by default, and scoping it to a
-    /// closure keeps the window in which it is enabled as small as possible.
+    /// This toggles `SQLITE_DBCONFIG_ENABLE_LOAD_EXTENSION`, which controls the
+    /// `sqlite3_load_extension()` C API. Extension loading is off by default, and
+    /// scoping it to a closure keeps the window in which it is enabled as small
+    /// as possible. To load a single extension prefer `load_extension`
+    /// (behind the `sqlite_load_extension` feature), which does this for you.
     ///
     /// Requires SQLite 3.13.0 or later, otherwise returns an error. Has no effect
     /// if SQLite was compiled with `SQLITE_OMIT_LOAD_EXTENSION`.
@@ -1131,6 +1575,50 @@ impl SqliteConnection {
         }
     }
 
+    /// Loads a [run-time loadable extension](https://www.sqlite.org/loadext.html)
+    /// like spatialite, sqlean or sqlite-vec into this connection.
+    ///
+    /// `path` is the file name of the shared library. SQLite tries the
+    /// platform specific suffix (`.so`, `.dylib` or `.dll`) if the file
+    /// cannot be found as given. `entry_point` is the name of the
+    /// initialization function, by default SQLite derives it from the file name.
+    ///
+    /// Extension loading is enabled only for the duration of this call and
+    /// disabled again afterwards, regardless of whether loading succeeded.
+    /// Only the C API is enabled, so the `load_extension()` SQL function stays
+    /// unavailable to queries. The extension remains loaded for the lifetime of
+    /// the connection, so call this once per connection, for example from
+    /// a connection pool's `on_acquire` customizer.
+    ///
+    /// Returns an error if the extension cannot be loaded, if SQLite was
+    /// compiled with `SQLITE_OMIT_LOAD_EXTENSION` or if the linked SQLite is
+    /// older than 3.13.0.
+    ///
+    /// # Safety considerations
+    ///
+    /// An extension is native code running inside your process with full access
+    /// to it. Only load extensions from trusted locations, never from paths
+    /// controlled by users.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # include!("../../doctest_setup.rs");
+    /// # fn main() -> QueryResult<()> {
+    /// #     let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    /// conn.load_extension("/usr/lib/mod_spatialite", None)?;
+    /// conn.load_extension("./extensions/vec0", Some("sqlite3_vec_init"))?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "sqlite_load_extension")]
+    #[cfg_attr(diesel_docsrs, doc(cfg(feature = "sqlite_load_extension")))]
+    pub fn load_extension(&mut self, path: &str, entry_point: Option<&str>) -> QueryResult<()> {
+        self.with_load_extension_enabled(|conn| {
+            conn.raw_connection.load_extension(path, entry_point)
+        })
+    }
+
     fn set_load_extension_enabled(&mut self, enabled: bool) -> QueryResult<()> {
         self.raw_connection
             .set_db_config_bool(ffi::SQLITE_DBCONFIG_ENABLE_LOAD_EXTENSION, enabled)
@@ -1338,19 +1826,26 @@ impl SqliteConnection {
         )
     }
 
-    fn establish_inner(database_url: &str) -> Result<SqliteConnection, ConnectionError> {
+    fn establish_inner(
+        database_url: &str,
+        config: &SqliteConnectionConfig,
+    ) -> Result<SqliteConnection, ConnectionError> {
         use crate::result::ConnectionError::CouldntSetupConfiguration;
-        let raw_connection = RawConnection::establish(database_url)?;
-        let conn = Self {
+        let (database_url, url_config) = SqliteConnectionConfig::from_url(database_url)?;
+        let raw_connection = RawConnection::establish(&database_url)?;
+        let mut conn = Self {
             statement_cache: StatementCache::new(),
             raw_connection,
             transaction_state: AnsiTransactionManager::default(),
             metadata_lookup: (),
             instrumentation: DynInstrumentation::none(),
             serialized_data: Vec::new(),
+            config: SqliteConnectionConfig::default(),
         };
         conn.register_diesel_sql_functions()
             .map_err(CouldntSetupConfiguration)?;
+        conn.apply_config(&url_config.merge(config))
+            .map_err(CouldntSetupConfiguration)?;
         Ok(conn)
     }
 }
@@ -1745,6 +2240,69 @@ mod tests {
         );
     }
 
+    #[diesel_test_helper::test]
+    fn backup_replaces_the_destination_database() {
+        let source = &mut connection();
+        crate::sql_query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)")
+            .execute(source)
+            .unwrap();
+        crate::sql_query("INSERT INTO users (name) VALUES ('John Doe'), ('Jane Doe')")
+            .execute(source)
+            .unwrap();
+
+        let destination = &mut connection();
+        crate::sql_query("CREATE TABLE posts (id INTEGER PRIMARY KEY)")
+            .execute(destination)
+            .unwrap();
+        source.backup_to(destination).unwrap();
+
+        let names = sql::<Text>("SELECT name FROM users ORDER BY id")
+            .load::<String>(destination)
+            .unwrap();
+        assert_eq!(vec!["John Doe", "Jane Doe"], names);
+        assert!(
+            sql::<Integer>("SELECT id FROM posts")
+                .load::<i32>(destination)
+                .is_err()
+        );
+    }
+
+    // Gated out on WASM as `tempfile::tempdir()` panics due to the lack of a filesystem
+    #[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+    #[diesel_test_helper::test]
+    fn vacuum_into_writes_a_new_database_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshot.db");
+        let path = path.to_str().unwrap();
+
+        let conn = &mut connection();
+        crate::sql_query("CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT)")
+            .execute(conn)
+            .unwrap();
+        crate::sql_query("INSERT INTO users (name) VALUES ('John Doe')")
+            .execute(conn)
+            .unwrap();
+        conn.vacuum_into(path).unwrap();
+
+        let snapshot = &mut SqliteConnection::establish(path).unwrap();
+        let names = sql::<Text>("SELECT name FROM users")
+            .load::<String>(snapshot)
+            .unwrap();
+        assert_eq!(vec!["John Doe"], names);
+
+        // existing databases are never overwritten by `VACUUM INTO`
+        assert!(conn.vacuum_into(path).is_err());
+        // while a backup replaces their content
+        crate::sql_query("INSERT INTO users (name) VALUES ('Jane Doe')")
+            .execute(conn)
+            .unwrap();
+        conn.backup_to_file(path).unwrap();
+        let names = sql::<Text>("SELECT name FROM users ORDER BY id")
+            .load::<String>(snapshot)
+            .unwrap();
+        assert_eq!(vec!["John Doe", "Jane Doe"], names);
+    }
+
     #[diesel_test_helper::test]
     fn register_custom_function() {
         let connection = &mut connection();
@@ -2026,6 +2584,274 @@ mod tests {
         assert_eq!(Ok(&[][..]), result.as_ref().map(|vec| vec.as_ref()));
     }
 
+    table! {
+        squares (value) {
+            value -> Integer,
+            square -> Integer,
+        }
+    }
+
+    /// Contains the numbers from 1 up to the given count and their squares,
+    /// supports looking up a single number via `value = ?`
+    struct Squares {
+        count: i32,
+    }
+
+    struct SquaresCursor {
+        current: i32,
+        last: i32,
+    }
+
+    impl virtual_table::VirtualTable for Squares {
+        type Cursor = SquaresCursor;
+
+        fn connect(arguments: &[&str]) -> QueryResult<Self> {
+            let count = arguments
+                .first()
+                .and_then(|a| a.trim().parse().ok())
+                .ok_or_else(|| Error::QueryBuilderError("Expected a count".into()))?;
+            Ok(Self { count })
+        }
+
+        fn declaration(&self) -> String {
+            "CREATE TABLE x(value INTEGER, square INTEGER)".into()
+        }
+
+        fn best_index(&self, index_info: &mut virtual_table::IndexInfo<'_>) -> QueryResult<()> {
+            let value_eq = index_info.constraints().position(|c| {
+                c.usable && c.column == Some(0) && c.op == virtual_table::IndexConstraintOp::Eq
+            });
+            if let Some(constraint) = value_eq {
+                index_info.use_constraint(constraint, 0, true)?;
+                index_info.set_index_number(1);
+                index_info.set_estimated_cost(1.0);
+            } else {
+                index_info.set_estimated_cost(self.count.into());
+            }
+            Ok(())
+        }
+
+        fn open(&self) -> QueryResult<SquaresCursor> {
+            Ok(SquaresCursor {
+                current: 1,
+                last: self.count,
+            })
+        }
+    }
+
+    impl virtual_table::VirtualTableCursor for SquaresCursor {
+        fn filter(
+            &mut self,
+            index_number: i32,
+            arguments: &virtual_table::FilterArguments<'_>,
+        ) -> QueryResult<()> {
+            if index_number == 1 {
+                let value = arguments
+                    .get::<Integer, i32>(0)
+                    .map_err(Error::DeserializationError)?;
+                self.current = value.max(1);
+                self.last = value;
+            }
+            Ok(())
+        }
+
+        fn next(&mut self) -> QueryResult<()> {
+            self.current += 1;
+            Ok(())
+        }
+
+        fn eof(&self) -> bool {
+            self.current > self.last
+        }
+
+        fn column(
+            &self,
+            column: usize,
+            context: &mut virtual_table::ColumnContext<'_>,
+        ) -> QueryResult<()> {
+            match column {
+                0 => context.set::<Integer, _>(&self.current),
+                _ => context.set::<Integer, _>(&(self.current * self.current)),
+            }
+        }
+
+        fn rowid(&self) -> QueryResult<i64> {
+            Ok(self.current.into())
+        }
+    }
+
+    #[diesel_test_helper::test]
+    fn register_virtual_table() {
+        let connection = &mut connection();
+        connection
+            .register_virtual_table::<Squares>("squares")
+            .unwrap();
+        crate::sql_query("CREATE VIRTUAL TABLE squares USING squares(4)")
+            .execute(connection)
+            .unwrap();
+
+        let all = squares::table
+            .order(squares::value)
+            .load::<(i32, i32)>(connection);
+        assert_eq!(Ok(vec![(1, 1), (2, 4), (3, 9), (4, 16)]), all);
+
+        // the constraint is omitted by SQLite, so this only returns
+        // a single row if the value was passed to the cursor
+        let three = squares::table
+            .filter(squares::value.eq(3))
+            .select(squares::square)
+            .load::<i32>(connection);
+        assert_eq!(Ok(vec![9]), three);
+
+        let sum = squares::table
+            .select(crate::dsl::sum(squares::square))
+            .get_result::<Option<i64>>(connection);
+        assert_eq!(Ok(Some(30)), sum);
+    }
+
+    #[diesel_test_helper::test]
+    fn virtual_table_errors_are_returned() {
+        let connection = &mut connection();
+        connection
+            .register_virtual_table::<Squares>("squares")
+            .unwrap();
+
+        let result = crate::sql_query("CREATE VIRTUAL TABLE squares USING squares(many)")
+            .execute(connection);
+        assert!(
+            matches!(&result, Err(Error::DatabaseError(_, info)) if info.message().contains("Expected a count")),
+            "{result:?}"
+        );
+    }
+
+    fn pragma_value(connection: &mut SqliteConnection, pragma: &str) -> String {
+        // the only column of `pragma_busy_timeout` is named `timeout`
+        let column = pragma.strip_prefix("busy_").unwrap_or(pragma);
+        crate::select(sql::<Text>(&format!(
+            "CAST({column} AS TEXT) FROM pragma_{pragma}"
+        )))
+        .get_result(connection)
+        .unwrap()
+    }
+
+    #[diesel_test_helper::test]
+    fn establish_with_config_applies_pragmas() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.db");
+        let config = SqliteConnectionConfig::new()
+            .busy_timeout(core::time::Duration::from_millis(1500))
+            .journal_mode(SqliteJournalMode::Wal)
+            .synchronous(SqliteSynchronous::Normal)
+            .foreign_keys(true)
+            .cache_size(SqliteCacheSize::Pages(500))
+            .mmap_size(0);
+        let connection =
+            &mut SqliteConnection::establish_with_config(path.to_str().unwrap(), &config).unwrap();
+
+        assert_eq!("1500", pragma_value(connection, "busy_timeout"));
+        assert_eq!("wal", pragma_value(connection, "journal_mode"));
+        assert_eq!("1", pragma_value(connection, "synchronous"));
+        assert_eq!("1", pragma_value(connection, "foreign_keys"));
+        assert_eq!("500", pragma_value(connection, "cache_size"));
+        assert_eq!(&config, connection.config());
+    }
+
+    #[diesel_test_helper::test]
+    fn url_query_parameters_configure_the_connection() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("url.db");
+        let url = format!(
+            "sqlite://{}?wal=true&foreign_keys=on&cache_size=-4096",
+            path.display()
+        );
+        let connection = &mut SqliteConnection::establish(&url).unwrap();
+
+        assert_eq!("wal", pragma_value(connection, "journal_mode"));
+        assert_eq!("1", pragma_value(connection, "foreign_keys"));
+        assert_eq!("-4096", pragma_value(connection, "cache_size"));
+
+        // explicit settings take precedence over the url
+        let config = SqliteConnectionConfig::new().foreign_keys(false);
+        let connection = &mut SqliteConnection::establish_with_config(&url, &config).unwrap();
+        assert_eq!("0", pragma_value(connection, "foreign_keys"));
+    }
+
+    #[diesel_test_helper::test]
+    fn sqlite_query_parameters_are_passed_on() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("read_only.db");
+        let connection = &mut SqliteConnection::establish(path.to_str().unwrap()).unwrap();
+        crate::sql_query("CREATE TABLE t (id INTEGER)")
+            .execute(connection)
+            .unwrap();
+
+        let url = format!("file:{}?mode=ro&busy_timeout=100", path.display());
+        let connection = &mut SqliteConnection::establish(&url).unwrap();
+        assert_eq!("100", pragma_value(connection, "busy_timeout"));
+        let result = crate::sql_query("INSERT INTO t VALUES (1)").execute(connection);
+        assert!(
+            matches!(&result, Err(Error::DatabaseError(_, info)) if info.message().contains("readonly")),
+            "{result:?}"
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn invalid_url_query_parameters_are_rejected() {
+        let result = SqliteConnection::establish("file::memory:?synchronous=sometimes");
+        assert!(
+            matches!(&result, Err(ConnectionError::InvalidConnectionUrl(msg)) if msg.contains("synchronous")),
+            "{:?}",
+            result.as_ref().err()
+        );
+
+        // plain file names may contain a `?`
+        let (url, config) = SqliteConnectionConfig::from_url("test.db?wal=true").unwrap();
+        assert_eq!("test.db?wal=true", url);
+        assert_eq!(SqliteConnectionConfig::default(), config);
+
+        let (url, config) =
+            SqliteConnectionConfig::from_url("file:test.db?mode=ro&wal=1&cache=shared#x").unwrap();
+        assert_eq!("file:test.db?mode=ro&cache=shared#x", url);
+        assert_eq!(
+            SqliteConnectionConfig::new().journal_mode(SqliteJournalMode::Wal),
+            config
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn apply_config_fails_inside_of_transactions() {
+        let connection = &mut connection();
+        let config = SqliteConnectionConfig::new().foreign_keys(true);
+
+        let result = connection.transaction(|conn| conn.apply_config(&config));
+        assert_eq!(Err(Error::AlreadyInTransaction), result);
+        assert_eq!(&SqliteConnectionConfig::default(), connection.config());
+
+        connection.apply_config(&config).unwrap();
+        assert_eq!("1", pragma_value(connection, "foreign_keys"));
+    }
+
+    #[diesel_test_helper::test]
+    fn read_only_transactions_restore_configured_query_only() {
+        let connection = &mut connection();
+        crate::sql_query("CREATE TABLE t (id INTEGER)")
+            .execute(connection)
+            .unwrap();
+        connection
+            .apply_config(&SqliteConnectionConfig::new().query_only(true))
+            .unwrap();
+
+        connection
+            .build_transaction()
+            .read_only()
+            .run(|_| QueryResult::Ok(()))
+            .unwrap();
+
+        assert_eq!("1", pragma_value(connection, "query_only"));
+        let result = crate::sql_query("INSERT INTO t VALUES (1)").execute(connection);
+        assert!(result.is_err());
+    }
+
     // regression test for https://github.com/diesel-rs/diesel/issues/3425
     #[diesel_test_helper::test]
     fn test_correct_serialization_of_owned_strings() {
@@ -2345,6 +3171,45 @@ mod tests {
         assert_eq!(buf, b"abc");
     }
 
+    #[diesel_test_helper::test]
+    fn write_bytes_to_blob() {
+        table! {
+            blobs {
+                id -> Integer,
+                data -> Blob,
+            }
+        }
+
+        use crate::sqlite::expression::functions::zeroblob;
+        use std::io::{Read, Seek, SeekFrom, Write};
+
+        let conn = &mut connection();
+
+        let _ = crate::sql_query("CREATE TABLE blobs (id INTEGER PRIMARY KEY, data BLOB)")
+            .execute(conn);
+
+        crate::insert_into(blobs::table)
+            .values(blobs::data.eq(zeroblob(5)))
+            .execute(conn)
+            .unwrap();
+
+        let mut data = conn.get_blob(blobs::data, 1).unwrap();
+        assert_eq!(data.len(), 5);
+        data.write_all(b"abc").unwrap();
+        // writing cannot grow the blob
+        assert_eq!(data.write(b"defg").unwrap(), 2);
+        assert_eq!(data.write(b"g").unwrap(), 0);
+
+        data.seek(SeekFrom::Start(1)).unwrap();
+        data.write_all(b"x").unwrap();
+        data.close().unwrap();
+
+        let mut data = conn.get_read_only_blob(blobs::data, 1).unwrap();
+        let mut buf = vec![];
+        data.read_to_end(&mut buf).unwrap();
+        assert_eq!(buf, b"axcde");
+    }
+
     #[diesel_test_helper::test]
     fn aggregate_function_works_with_aligned_data() {
         #[derive(Debug, Default)]
@@ -2871,6 +3736,41 @@ mod tests {
         assert!(!conn.is_load_extension_enabled().unwrap());
     }
 
+    #[cfg(feature = "sqlite_load_extension")]
+    #[diesel_test_helper::test]
+    fn load_extension_reports_errors_and_disables_loading_again() {
+        let conn = &mut connection();
+        let error = conn
+            .load_extension("/this/extension/does/not/exist", None)
+            .unwrap_err();
+        assert!(
+            matches!(error, Error::DatabaseError(DatabaseErrorKind::Unknown, _)),
+            "{error:?}"
+        );
+        assert!(!conn.is_load_extension_enabled().unwrap());
+        // the `load_extension()` SQL function remains disabled
+        let sql_function = crate::sql_query("SELECT load_extension('/this/does/not/exist')")
+            .execute(conn)
+            .unwrap_err();
+        assert!(
+            sql_function.to_string().contains("not authorized"),
+            "{sql_function}"
+        );
+    }
+
+    #[cfg(feature = "sqlite_load_extension")]
+    #[diesel_test_helper::test]
+    fn load_extension_rejects_nul_bytes() {
+        let conn = &mut connection();
+        let error = conn.load_extension("ext\0ension", None).unwrap_err();
+        assert!(matches!(error, Error::InvalidCString(_)), "{error:?}");
+        let error = conn
+            .load_extension("extension", Some("init\0"))
+            .unwrap_err();
+        assert!(matches!(error, Error::InvalidCString(_)), "{error:?}");
+        assert!(!conn.is_load_extension_enabled().unwrap());
+    }
+
     #[cfg(all(
         feature = "std",
         not(all(target_family = "wasm", target_os = "unknown"))
@@ -3293,4 +4193,111 @@ mod tests {
         let result = crate::sql_query("SELECT val FROM innocuous_view").execute(conn);
         assert!(result.is_ok());
     }
+
+    crate::table! {
+        batched_items {
+            id -> Integer,
+        }
+    }
+
+    #[diesel_test_helper::test]
+    fn immediate_transaction_batched_commits_each_chunk() {
+        let conn = &mut connection();
+        conn.batch_execute("CREATE TABLE batched_items (id INTEGER PRIMARY KEY)")
+            .unwrap();
+
+        let result = conn.immediate_transaction_batched(1..=10, 4, |conn, chunk| {
+            if chunk.contains(&9) {
+                return Err(Error::RollbackTransaction);
+            }
+            let values = chunk
+                .into_iter()
+                .map(|id| batched_items::id.eq(id))
+                .collect::<Vec<_>>();
+            crate::insert_into(batched_items::table)
+                .values(values)
+                .execute(conn)
+        });
+        assert_eq!(Err(Error::RollbackTransaction), result);
+
+        let ids = batched_items::table
+            .select(batched_items::id)
+            .order(batched_items::id)
+            .load::<i32>(conn)
+            .unwrap();
+        assert_eq!((1..=8).collect::<Vec<_>>(), ids);
+        assert_eq!(
+            Ok(None),
+            <AnsiTransactionManager as TransactionManager<SqliteConnection>>::transaction_manager_status_mut(conn)
+                .transaction_depth(),
+            "no transaction is left open"
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn immediate_transaction_batched_returns_results_of_all_chunks() {
+        let conn = &mut connection();
+        let options = SqliteBatchOptions::new(0)
+            .checkpoint_after_each_chunk(SqliteWalCheckpointMode::Truncate);
+
+        let chunks = conn
+            .immediate_transaction_batched(1..=3, options, |_, chunk| QueryResult::Ok(chunk))
+            .unwrap();
+        assert_eq!(vec![vec![1], vec![2], vec![3]], chunks);
+
+        let chunks = conn
+            .immediate_transaction_batched(Vec::<i32>::new(), 10, |_, chunk| QueryResult::Ok(chunk))
+            .unwrap();
+        assert!(chunks.is_empty());
+    }
+
+    #[diesel_test_helper::test]
+    fn immediate_transaction_batched_fails_inside_of_a_transaction() {
+        let conn = &mut connection();
+
+        let result = conn.transaction(|conn| {
+            conn.immediate_transaction_batched([1], 1, |_, _| QueryResult::Ok(()))
+        });
+        assert_eq!(Err(Error::AlreadyInTransaction), result);
+    }
+
+    #[diesel_test_helper::test]
+    fn double_binds_reject_text_values() {
+        use crate::serialize::{IsNull, Output, ToSql};
+        use crate::sql_types::{Double, Numeric};
+
+        #[derive(Debug)]
+        struct TextAsDouble;
+
+        impl ToSql<Double, Sqlite> for TextAsDouble {
+            fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> crate::serialize::Result {
+                out.set_value("1.5");
+                Ok(IsNull::No)
+            }
+        }
+
+        let conn = &mut connection();
+        let result = crate::sql_query("SELECT ?")
+            .bind::<Double, _>(TextAsDouble)
+            .execute(conn);
+        assert!(
+            matches!(result, Err(Error::SerializationError(ref e)) if e.to_string().starts_with("Type mismatch")),
+            "{result:?}"
+        );
+
+        // `Numeric` values keep using their own storage class
+        crate::sql_query("CREATE TABLE numerics(value NUMERIC)")
+            .execute(conn)
+            .unwrap();
+        crate::sql_query("INSERT INTO numerics VALUES (?)")
+            .bind::<Numeric, _>(
+                "1.50000000000000000001"
+                    .parse::<crate::data_types::NumericString>()
+                    .unwrap(),
+            )
+            .execute(conn)
+            .unwrap();
+        let stored = sql::<Text>("SELECT typeof(value) FROM numerics").get_result::<String>(conn);
+        assert_eq!(Ok("real".to_owned()), stored);
+    }
 }
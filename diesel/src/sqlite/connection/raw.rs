@@ -14,6 +14,7 @@ use super::serialized_database::SerializedDatabase;
 use super::stmt::ensure_sqlite_ok;
 use super::trace::{SqliteTraceEvent, SqliteTraceFlags, TRACE_PROFILE, TRACE_ROW, TRACE_STMT};
 use super::update_hook::{SqliteChangeEvent, SqliteChangeOp};
+use super::virtual_table::{self, VirtualTable};
 use super::{BusyDecision, CommitDecision, ProgressDecision};
 use super::{Sqlite, SqliteAggregateFunction};
 use crate::deserialize::FromSqlRow;
@@ -41,11 +42,11 @@ use core::{mem, ptr, slice, str};
 pub(super) const SQLITE_DBCONFIG_ENABLE_ATTACH_CREATE: i32 = 1020;
 pub(super) const SQLITE_DBCONFIG_ENABLE_ATTACH_WRITE: i32 = 1021;
 
-// Runtime extension loading (`sqlite3_load_extension`) is deliberately unsupported.
-// Platforms that build SQLite with `-DSQLITE_OMIT_LOAD_EXTENSION` (see #2180) drop
-// the symbol from the ABI, and the runtime `dlsym` workaround in #4954 proved too
-// fragile to ship. Use `declare_sql_function`, `register_auto_extension`, or
-// `SqliteConnection::with_raw_connection` instead.
+// Runtime extension loading (`sqlite3_load_extension`) is only available behind the
+// `sqlite_load_extension` feature. Platforms that build SQLite with
+// `-DSQLITE_OMIT_LOAD_EXTENSION` (see #2180) drop the symbol from the ABI, and the
+// runtime `dlsym` workaround in #4954 proved too fragile to ship, so referencing the
+// symbol has to be opt-in.
 
 /// For use in FFI function, which cannot unwind.
 /// Print the message, ask to open an issue at Github and [`abort`](std::process::abort).
@@ -286,6 +287,28 @@ impl RawConnection {
         result
     }
 
+    pub(super) fn register_virtual_table<T: VirtualTable>(
+        &self,
+        module_name: &str,
+    ) -> QueryResult<()> {
+        let c_module_name = Self::get_fn_name(module_name)?;
+        // the module definition needs to outlive the registration,
+        // SQLite calls the destructor on failure as well
+        let module = Box::into_raw(Box::new(virtual_table::module::<T>()));
+
+        let result = unsafe {
+            ffi::sqlite3_create_module_v2(
+                self.internal_connection.as_ptr(),
+                c_module_name.as_ptr(),
+                module,
+                module as *mut _,
+                Some(destroy_boxed::<ffi::sqlite3_module>),
+            )
+        };
+
+        Self::process_sql_function_result(result)
+    }
+
     pub(super) fn serialize(&mut self) -> SerializedDatabase {
         unsafe {
             let mut size: ffi::sqlite3_int64 = 0;
@@ -328,6 +351,48 @@ impl RawConnection {
         }
     }
 
+    /// Copies the `main` database of this connection into the `main`
+    /// database of `destination` using the online backup API
+    pub(super) fn backup_to(&self, destination: &RawConnection) -> QueryResult<()> {
+        // number of pages copied per step, other connections
+        // can access the source database in between
+        const PAGES_PER_STEP: libc::c_int = 128;
+        // time to wait before retrying a step if the source
+        // or destination database is locked
+        const LOCKED_RETRY_MS: libc::c_int = 10;
+
+        let main = c"main";
+        let destination_ptr = destination.internal_connection.as_ptr();
+        let backup = unsafe {
+            ffi::sqlite3_backup_init(
+                destination_ptr,
+                main.as_ptr(),
+                self.internal_connection.as_ptr(),
+                main.as_ptr(),
+            )
+        };
+        if backup.is_null() {
+            // the error is stored in the destination connection
+            return ensure_sqlite_ok(
+                unsafe { ffi::sqlite3_errcode(destination_ptr) },
+                destination_ptr,
+            );
+        }
+        loop {
+            match unsafe { ffi::sqlite3_backup_step(backup, PAGES_PER_STEP) } {
+                ffi::SQLITE_OK => {}
+                ffi::SQLITE_BUSY | ffi::SQLITE_LOCKED => unsafe {
+                    ffi::sqlite3_sleep(LOCKED_RETRY_MS);
+                },
+                // SQLITE_DONE or an error, which is returned by
+                // `sqlite3_backup_finish` as well
+                _ => break,
+            }
+        }
+        let result = unsafe { ffi::sqlite3_backup_finish(backup) };
+        ensure_sqlite_ok(result, destination_ptr)
+    }
+
     pub(super) fn set_limit(&self, limit: SqliteLimit, value: i32) -> i32 {
         unsafe { ffi::sqlite3_limit(self.internal_connection.as_ptr(), limit.to_ffi(), value) }
     }
@@ -373,6 +438,42 @@ impl RawConnection {
         Ok(current_value != 0)
     }
 
+    /// Load an extension through `sqlite3_load_extension()`.
+    ///
+    /// Loading has to be enabled via `SQLITE_DBCONFIG_ENABLE_LOAD_EXTENSION`
+    /// before, otherwise SQLite refuses to load anything.
+    #[cfg(feature = "sqlite_load_extension")]
+    pub(super) fn load_extension(&self, path: &str, entry_point: Option<&str>) -> QueryResult<()> {
+        let path = CString::new(path)?;
+        let entry_point = entry_point.map(CString::new).transpose()?;
+        let mut error_message: *mut libc::c_char = ptr::null_mut();
+
+        let result = unsafe {
+            ffi::sqlite3_load_extension(
+                self.internal_connection.as_ptr(),
+                path.as_ptr(),
+                entry_point.as_ref().map_or(ptr::null(), |e| e.as_ptr()),
+                &mut error_message,
+            )
+        };
+
+        if result == ffi::SQLITE_OK {
+            return Ok(());
+        }
+        // Unlike most functions, `sqlite3_load_extension()` reports its error
+        // through the out parameter, which we have to free ourselves
+        let message = if error_message.is_null() {
+            String::from("Failed to load extension")
+        } else {
+            let message = unsafe { CStr::from_ptr(error_message) }
+                .to_string_lossy()
+                .into_owned();
+            unsafe { ffi::sqlite3_free(error_message as *mut libc::c_void) };
+            message
+        };
+        Err(DatabaseError(DatabaseErrorKind::Unknown, Box::new(message)))
+    }
+
     fn get_fn_name(fn_name: &str) -> Result<CString, NulError> {
         CString::new(fn_name)
     }
@@ -395,6 +496,7 @@ impl RawConnection {
         table_name: &str,
         column_name: &str,
         row_id: i64,
+        read_write: bool,
     ) -> Result<super::sqlite_blob::SqliteReadOnlyBlob<'conn>, Error> {
         let database_name = alloc::ffi::CString::new(database_name)?;
         let column_name = alloc::ffi::CString::new(column_name)?;
@@ -410,7 +512,7 @@ impl RawConnection {
                 table_name.as_c_str().as_ptr(),
                 column_name.as_c_str().as_ptr(),
                 row_id,
-                0,
+                i32::from(read_write),
                 &mut blob,
             )
         };
@@ -432,7 +534,7 @@ impl RawConnection {
 
         Ok(super::sqlite_blob::SqliteReadOnlyBlob {
             blob,
-            read_index: 0,
+            position: 0,
             blob_size,
             _pd: core::marker::PhantomData,
         })
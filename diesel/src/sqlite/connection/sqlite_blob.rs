@@ -19,7 +19,7 @@ use sqlite_wasm_rs as ffi;
 #[cfg_attr(not(feature = "std"), expect(dead_code))]
 pub struct SqliteReadOnlyBlob<'conn> {
     pub(crate) blob: core::ptr::NonNull<ffi::sqlite3_blob>,
-    pub(crate) read_index: usize,
+    pub(crate) position: usize,
 
     pub(crate) blob_size: usize,
     pub(crate) _pd: core::marker::PhantomData<&'conn mut ffi::sqlite3_blob>,
@@ -57,8 +57,10 @@ impl SqliteReadOnlyBlob<'_> {
     ///
     /// > The BLOB handle is closed unconditionally. Even if this routine returns an error code,
     /// > the handle is still closed.
-    pub fn close(mut self) -> Result<(), crate::result::Error> {
-        self.close_inner()
+    pub fn close(self) -> Result<(), crate::result::Error> {
+        // the handle must not be closed again by the drop impl
+        let mut this = core::mem::ManuallyDrop::new(self);
+        this.close_inner()
     }
 
     fn close_inner(&mut self) -> Result<(), crate::result::Error> {
@@ -90,7 +92,7 @@ fn to_io_error(error: core::num::TryFromIntError) -> std::io::Error {
 impl std::io::Read for SqliteReadOnlyBlob<'_> {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
         let buflen: i32 = buf.len().try_into().map_err(to_io_error)?;
-        let offset: i32 = self.read_index.try_into().map_err(to_io_error)?;
+        let offset: i32 = self.position.try_into().map_err(to_io_error)?;
 
         // From the sqlite docs:
         //
@@ -117,8 +119,8 @@ impl std::io::Read for SqliteReadOnlyBlob<'_> {
             return Err(std::io::Error::other(error_message.to_string()));
         }
 
-        self.read_index += usize::try_from(read_length).map_err(to_io_error)?;
-        debug_assert!(self.read_index <= self.blob_size);
+        self.position += usize::try_from(read_length).map_err(to_io_error)?;
+        debug_assert!(self.position <= self.blob_size);
 
         usize::try_from(read_length).map_err(to_io_error)
     }
@@ -129,10 +131,10 @@ impl std::io::Seek for SqliteReadOnlyBlob<'_> {
     fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
         match pos {
             std::io::SeekFrom::Start(n) => {
-                self.read_index = usize::try_from(n).map_err(to_io_error)?.min(self.blob_size);
+                self.position = usize::try_from(n).map_err(to_io_error)?.min(self.blob_size);
             }
             std::io::SeekFrom::End(n) => {
-                self.read_index = if n.is_positive() {
+                self.position = if n.is_positive() {
                     self.blob_size
                 } else {
                     self.blob_size - usize::try_from(n.unsigned_abs()).map_err(to_io_error)?
@@ -142,17 +144,116 @@ impl std::io::Seek for SqliteReadOnlyBlob<'_> {
                 let n = isize::try_from(n).map_err(to_io_error)?;
 
                 if n.is_negative() {
-                    self.read_index = if self.read_index < n.unsigned_abs() {
+                    self.position = if self.position < n.unsigned_abs() {
                         0
                     } else {
-                        self.read_index - n.unsigned_abs()
+                        self.position - n.unsigned_abs()
                     };
                 } else {
-                    self.read_index = (self.read_index + n.unsigned_abs()).min(self.blob_size);
+                    self.position = (self.position + n.unsigned_abs()).min(self.blob_size);
                 }
             }
         }
 
-        u64::try_from(self.read_index).map_err(to_io_error)
+        u64::try_from(self.position).map_err(to_io_error)
+    }
+}
+
+/// A SQLite Blob which can be read and written
+///
+/// This interface allows to incrementally read and write a blob stored in a SQLite
+/// database, without loading the whole blob into memory.
+/// Notably this type implements [`std::io::Read`], [`std::io::Write`] and [`std::io::Seek`]
+/// to integrate with standard Rust IO mechanisms.
+///
+/// Writing cannot change the size of the blob. Use
+/// [`zeroblob`](crate::sqlite::expression::functions::zeroblob()) to reserve space for
+/// a blob that should be written incrementally.
+///
+/// You can use [`SqliteConnection::get_blob`](super::SqliteConnection::get_blob)
+/// to get a new instance of this type
+///
+/// See the [SQLite documentation](https://sqlite.org/c3ref/blob_open.html) for more details
+#[expect(missing_debug_implementations)]
+pub struct SqliteBlob<'conn> {
+    pub(crate) inner: SqliteReadOnlyBlob<'conn>,
+}
+
+impl SqliteBlob<'_> {
+    /// Is the blob storage empty
+    pub fn is_empty(&self) -> bool {
+        self.inner.is_empty()
+    }
+
+    /// The size of the underlying blob in bytes
+    pub fn len(&self) -> usize {
+        self.inner.len()
+    }
+
+    /// Close the handle
+    ///
+    /// Even if an error is returned, the handle is still closed (from the sqlite documentation):
+    ///
+    /// > The BLOB handle is closed unconditionally. Even if this routine returns an error code,
+    /// > the handle is still closed.
+    pub fn close(self) -> Result<(), crate::result::Error> {
+        self.inner.close()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Read for SqliteBlob<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.inner.read(buf)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Seek for SqliteBlob<'_> {
+    fn seek(&mut self, pos: std::io::SeekFrom) -> std::io::Result<u64> {
+        self.inner.seek(pos)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for SqliteBlob<'_> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let buflen: i32 = buf.len().try_into().unwrap_or(i32::MAX);
+        let offset: i32 = self.inner.position.try_into().map_err(to_io_error)?;
+
+        // Similar to reading, writing past the end of the blob results
+        // in an error. The size of the blob cannot be changed, so we only
+        // write as much as fits into the remaining space
+        let write_length: i32 = (i32::try_from(self.inner.blob_size)
+            .map_err(to_io_error)?
+            .saturating_sub(offset))
+        .min(buflen);
+
+        if write_length == 0 {
+            return Ok(0);
+        }
+
+        let ret = unsafe {
+            ffi::sqlite3_blob_write(
+                self.inner.blob.as_ptr(),
+                buf.as_ptr() as *const core::ffi::c_void,
+                write_length,
+                offset,
+            )
+        };
+
+        if ret != ffi::SQLITE_OK {
+            let error_message = crate::sqlite::connection::error_message(ret);
+            return Err(std::io::Error::other(error_message.to_string()));
+        }
+
+        self.inner.position += usize::try_from(write_length).map_err(to_io_error)?;
+        debug_assert!(self.inner.position <= self.inner.blob_size);
+
+        usize::try_from(write_length).map_err(to_io_error)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
     }
 }
@@ -231,6 +231,9 @@ fn last_error(raw_connection: *mut ffi::sqlite3) -> Error {
         }
         ffi::SQLITE_CONSTRAINT_NOTNULL => DatabaseErrorKind::NotNullViolation,
         ffi::SQLITE_CONSTRAINT_CHECK => DatabaseErrorKind::CheckViolation,
+        // the primary result code, which includes `SQLITE_BUSY_SNAPSHOT`
+        // for transactions that cannot be upgraded to a write transaction
+        code if code & 0xff == ffi::SQLITE_BUSY => DatabaseErrorKind::SerializationFailure,
         _ => DatabaseErrorKind::Unknown,
     };
     let error_information = Box::new(error_message);
@@ -382,10 +385,10 @@ impl<'stmt, 'query> BoundStatement<'stmt, 'query> {
             // it's safe to get a reference from this ptr as it's guaranteed to not be null
             let q = unsafe { q.as_ref() };
             self.instrumentation.on_connection_event(
-                crate::connection::InstrumentationEvent::FinishQuery {
-                    query: &crate::debug_query(&q),
-                    error: Some(e),
-                },
+                crate::connection::InstrumentationEvent::finish_query(
+                    &crate::debug_query(&q),
+                    Some(e),
+                ),
             );
         }
     }
@@ -427,10 +430,10 @@ impl Drop for BoundStatement<'_, '_> {
             };
             if !self.has_error {
                 self.instrumentation.on_connection_event(
-                    crate::connection::InstrumentationEvent::FinishQuery {
-                        query: &crate::debug_query(&query),
-                        error: None,
-                    },
+                    crate::connection::InstrumentationEvent::finish_query(
+                        &crate::debug_query(&query),
+                        None,
+                    ),
                 );
             }
             core::mem::drop(query);
@@ -0,0 +1,607 @@
+//! Types used by [`SqliteConnection::register_virtual_table`](super::SqliteConnection::register_virtual_table).
+//!
+//! A [virtual table](https://www.sqlite.org/vtab.html) is a table whose
+//! content is provided by rust code instead of the database file. This
+//! allows to query other data sources like CSV files or in-memory data
+//! structures with SQL. Virtual tables registered via diesel are read-only.
+#![allow(unsafe_code)] // ffi calls
+
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+extern crate libsqlite3_sys as ffi;
+
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+use sqlite_wasm_rs as ffi;
+
+use super::functions::{FunctionRow, process_sql_function_result};
+use crate::deserialize::{self, FromSql};
+use crate::result::{Error, QueryResult};
+use crate::row::{Field, Row};
+use crate::serialize::ToSql;
+use crate::sql_types::HasSqlType;
+use crate::sqlite::Sqlite;
+use alloc::boxed::Box;
+use alloc::ffi::CString;
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::ffi as libc;
+use core::ffi::CStr;
+use core::{mem, ptr, slice};
+
+/// A read-only virtual table implementation
+///
+/// Register the implementation via
+/// [`SqliteConnection::register_virtual_table`](super::SqliteConnection::register_virtual_table)
+/// and create a table via `CREATE VIRTUAL TABLE name USING module_name(arguments)`.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../../doctest_setup.rs");
+/// use diesel::sql_types::Integer;
+/// use diesel::sqlite::virtual_table::{ColumnContext, FilterArguments, VirtualTable, VirtualTableCursor};
+///
+/// /// A table containing the numbers from 1 up to the given argument
+/// struct Numbers {
+///     count: i32,
+/// }
+///
+/// impl VirtualTable for Numbers {
+///     type Cursor = NumbersCursor;
+///
+///     fn connect(arguments: &[&str]) -> QueryResult<Self> {
+///         let count = arguments
+///             .first()
+///             .and_then(|a| a.trim().parse().ok())
+///             .ok_or_else(|| diesel::result::Error::QueryBuilderError("Expected a count".into()))?;
+///         Ok(Self { count })
+///     }
+///
+///     fn declaration(&self) -> String {
+///         "CREATE TABLE x(value INTEGER)".into()
+///     }
+///
+///     fn open(&self) -> QueryResult<NumbersCursor> {
+///         Ok(NumbersCursor { current: 1, count: self.count })
+///     }
+/// }
+///
+/// struct NumbersCursor {
+///     current: i32,
+///     count: i32,
+/// }
+///
+/// impl VirtualTableCursor for NumbersCursor {
+///     fn filter(&mut self, _index_number: i32, _arguments: &FilterArguments<'_>) -> QueryResult<()> {
+///         self.current = 1;
+///         Ok(())
+///     }
+///
+///     fn next(&mut self) -> QueryResult<()> {
+///         self.current += 1;
+///         Ok(())
+///     }
+///
+///     fn eof(&self) -> bool {
+///         self.current > self.count
+///     }
+///
+///     fn column(&self, _column: usize, context: &mut ColumnContext<'_>) -> QueryResult<()> {
+///         context.set::<Integer, _>(&self.current)
+///     }
+///
+///     fn rowid(&self) -> QueryResult<i64> {
+///         Ok(self.current.into())
+///     }
+/// }
+///
+/// # fn main() -> QueryResult<()> {
+/// #     let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+/// conn.register_virtual_table::<Numbers>("numbers")?;
+/// diesel::sql_query("CREATE VIRTUAL TABLE three USING numbers(3)").execute(conn)?;
+///
+/// let sum = diesel::select(diesel::dsl::sql::<Integer>("(SELECT sum(value) FROM three)"))
+///     .get_result::<i32>(conn)?;
+/// assert_eq!(6, sum);
+/// #     Ok(())
+/// # }
+/// ```
+pub trait VirtualTable: Sized + Send + 'static {
+    /// The cursor used to iterate over the rows of this table
+    type Cursor: VirtualTableCursor;
+
+    /// Creates an instance of this virtual table
+    ///
+    /// This is called for `CREATE VIRTUAL TABLE` statements and whenever
+    /// a connection first accesses an existing virtual table. `arguments`
+    /// contains the arguments passed to the module in the `CREATE VIRTUAL TABLE`
+    /// statement as written there, so quoted arguments still contain their quotes.
+    fn connect(arguments: &[&str]) -> QueryResult<Self>;
+
+    /// The `CREATE TABLE` statement declaring the columns of this table
+    ///
+    /// The table name used in the statement is ignored, e.g.
+    /// `CREATE TABLE x(name TEXT, value INTEGER)`.
+    fn declaration(&self) -> String;
+
+    /// Chooses how a query is executed
+    ///
+    /// The chosen [`index number`](IndexInfo::set_index_number) and the
+    /// values of the used constraints are passed to
+    /// [`VirtualTableCursor::filter`]. The default implementation
+    /// performs a full table scan without using any constraint.
+    fn best_index(&self, index_info: &mut IndexInfo<'_>) -> QueryResult<()> {
+        let _ = index_info;
+        Ok(())
+    }
+
+    /// Opens a new cursor to iterate over the rows of this table
+    fn open(&self) -> QueryResult<Self::Cursor>;
+}
+
+/// A cursor over the rows of a [`VirtualTable`]
+pub trait VirtualTableCursor: Sized + 'static {
+    /// Starts a new search, the cursor has to point to the first matching row afterwards
+    ///
+    /// `index_number` is the value set via [`IndexInfo::set_index_number`]
+    /// and `arguments` contains the values of the constraints for which an
+    /// [`argument index`](IndexInfo::use_constraint) was set.
+    fn filter(&mut self, index_number: i32, arguments: &FilterArguments<'_>) -> QueryResult<()>;
+
+    /// Advances the cursor to the next row
+    fn next(&mut self) -> QueryResult<()>;
+
+    /// Returns `true` if the cursor points past the last row
+    fn eof(&self) -> bool;
+
+    /// Sets the value of the given column for the current row
+    ///
+    /// The column is `NULL` if no value is set via [`ColumnContext::set`].
+    fn column(&self, column: usize, context: &mut ColumnContext<'_>) -> QueryResult<()>;
+
+    /// Returns the rowid of the current row
+    fn rowid(&self) -> QueryResult<i64>;
+}
+
+/// The operator of a constraint passed to [`VirtualTable::best_index`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum IndexConstraintOp {
+    /// `=`
+    Eq,
+    /// `>`
+    Gt,
+    /// `<=`
+    Le,
+    /// `<`
+    Lt,
+    /// `>=`
+    Ge,
+    /// `MATCH`
+    Match,
+    /// `LIKE`
+    Like,
+    /// `GLOB`
+    Glob,
+    /// `REGEXP`
+    Regexp,
+    /// `!=` or `<>`
+    Ne,
+    /// `IS NOT`
+    IsNot,
+    /// `IS NOT NULL`
+    IsNotNull,
+    /// `IS NULL`
+    IsNull,
+    /// `IS`
+    Is,
+    /// An operator this release does not name, like `LIMIT` or an overloaded function
+    Other(u8),
+}
+
+impl IndexConstraintOp {
+    // The constants are not available in all supported versions of libsqlite3-sys
+    fn from_ffi(op: u8) -> Self {
+        match op {
+            2 => Self::Eq,
+            4 => Self::Gt,
+            8 => Self::Le,
+            16 => Self::Lt,
+            32 => Self::Ge,
+            64 => Self::Match,
+            65 => Self::Like,
+            66 => Self::Glob,
+            67 => Self::Regexp,
+            68 => Self::Ne,
+            69 => Self::IsNot,
+            70 => Self::IsNotNull,
+            71 => Self::IsNull,
+            72 => Self::Is,
+            other => Self::Other(other),
+        }
+    }
+}
+
+/// A `WHERE` clause term of a query on a virtual table
+#[derive(Debug, Clone, Copy)]
+#[non_exhaustive]
+pub struct IndexConstraint {
+    /// The constrained column, `None` for the rowid
+    pub column: Option<usize>,
+    /// The operator of the constraint
+    pub op: IndexConstraintOp,
+    /// Whether the constraint can be used for the current query plan
+    pub usable: bool,
+}
+
+/// Information about a query passed to [`VirtualTable::best_index`]
+#[allow(missing_debug_implementations)]
+pub struct IndexInfo<'a> {
+    inner: &'a mut ffi::sqlite3_index_info,
+}
+
+impl IndexInfo<'_> {
+    /// The constraints of the query
+    pub fn constraints(&self) -> impl Iterator<Item = IndexConstraint> + '_ {
+        self.raw_constraints().iter().map(|c| IndexConstraint {
+            column: usize::try_from(c.iColumn).ok(),
+            op: IndexConstraintOp::from_ffi(c.op),
+            usable: c.usable != 0,
+        })
+    }
+
+    /// Passes the value of the constraint at the given position in
+    /// [`constraints`](Self::constraints) to [`VirtualTableCursor::filter`]
+    ///
+    /// `argument_index` is the position of the value in
+    /// [`FilterArguments`], starting at zero. If `omit` is `true`
+    /// SQLite does not double check the constraint for the returned rows.
+    pub fn use_constraint(
+        &mut self,
+        constraint: usize,
+        argument_index: usize,
+        omit: bool,
+    ) -> QueryResult<()> {
+        let len = self.raw_constraints().len();
+        let argv_index = argument_index
+            .checked_add(1)
+            .and_then(|i| libc::c_int::try_from(i).ok())
+            .filter(|_| constraint < len)
+            .ok_or_else(|| {
+                Error::QueryBuilderError("Invalid constraint or argument index".into())
+            })?;
+        // SAFETY: SQLite allocates one usage entry per constraint
+        // and we checked that the index is in range
+        let usage = unsafe { &mut *self.inner.aConstraintUsage.add(constraint) };
+        usage.argvIndex = argv_index;
+        usage.omit = omit.into();
+        Ok(())
+    }
+
+    /// Sets the number passed to [`VirtualTableCursor::filter`]
+    /// to identify the chosen query plan
+    pub fn set_index_number(&mut self, index_number: i32) {
+        self.inner.idxNum = index_number;
+    }
+
+    /// Sets the estimated cost of the chosen query plan
+    ///
+    /// SQLite chooses the plan with the lowest cost if
+    /// `best_index` is called several times for a query.
+    pub fn set_estimated_cost(&mut self, cost: f64) {
+        self.inner.estimatedCost = cost;
+    }
+
+    fn raw_constraints(&self) -> &[ffi::sqlite3_index_constraint] {
+        let len = usize::try_from(self.inner.nConstraint).unwrap_or_default();
+        if len == 0 || self.inner.aConstraint.is_null() {
+            &[]
+        } else {
+            // SAFETY: SQLite passes an array of `nConstraint` constraints
+            unsafe { slice::from_raw_parts(self.inner.aConstraint, len) }
+        }
+    }
+}
+
+/// The constraint values passed to [`VirtualTableCursor::filter`]
+#[allow(missing_debug_implementations)]
+pub struct FilterArguments<'a> {
+    row: FunctionRow<'a>,
+}
+
+impl FilterArguments<'_> {
+    /// The number of arguments
+    pub fn len(&self) -> usize {
+        self.row.field_count()
+    }
+
+    /// Returns `true` if there are no arguments
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Deserializes the argument at the given index
+    pub fn get<ST, T>(&self, index: usize) -> deserialize::Result<T>
+    where
+        T: FromSql<ST, Sqlite>,
+    {
+        let field = self
+            .row
+            .get(index)
+            .ok_or_else(|| alloc::format!("There is no filter argument at index {index}"))?;
+        T::from_nullable_sql(field.value())
+    }
+}
+
+/// Receives the value of a column in [`VirtualTableCursor::column`]
+#[allow(missing_debug_implementations)]
+pub struct ColumnContext<'a> {
+    ctx: &'a mut ffi::sqlite3_context,
+}
+
+impl ColumnContext<'_> {
+    /// Sets the value of the column
+    pub fn set<ST, T>(&mut self, value: &T) -> QueryResult<()>
+    where
+        T: ToSql<ST, Sqlite>,
+        Sqlite: HasSqlType<ST>,
+    {
+        process_sql_function_result::<ST, T>(value)?
+            .result_of(self.ctx)
+            .map_err(|e| Error::SerializationError(Box::new(e)))
+    }
+}
+
+#[repr(C)]
+struct TableInstance<T> {
+    // must be the first field, as SQLite only knows about this part
+    base: ffi::sqlite3_vtab,
+    table: T,
+}
+
+#[repr(C)]
+struct CursorInstance<C> {
+    // must be the first field, as SQLite only knows about this part
+    base: ffi::sqlite3_vtab_cursor,
+    cursor: C,
+}
+
+/// Builds the module definition passed to `sqlite3_create_module_v2`
+pub(super) fn module<T: VirtualTable>() -> ffi::sqlite3_module {
+    // The number of fields depends on the libsqlite3-sys version,
+    // all fields are either integers or optional function pointers,
+    // so all zero is a valid value
+    let mut module: ffi::sqlite3_module = unsafe { mem::zeroed() };
+    module.iVersion = 1;
+    module.xCreate = Some(connect::<T>);
+    module.xConnect = Some(connect::<T>);
+    module.xBestIndex = Some(best_index::<T>);
+    module.xDisconnect = Some(disconnect::<T>);
+    module.xDestroy = Some(disconnect::<T>);
+    module.xOpen = Some(open::<T>);
+    module.xClose = Some(close::<T::Cursor>);
+    module.xFilter = Some(filter::<T::Cursor>);
+    module.xNext = Some(next::<T::Cursor>);
+    module.xEof = Some(eof::<T::Cursor>);
+    module.xColumn = Some(column::<T::Cursor>);
+    module.xRowid = Some(rowid::<T::Cursor>);
+    module
+}
+
+/// Copies the message into memory allocated by SQLite,
+/// as SQLite frees error messages via `sqlite3_free`
+fn sqlite_string(message: &str) -> *mut libc::c_char {
+    let Ok(len) = libc::c_int::try_from(message.len() + 1) else {
+        return ptr::null_mut();
+    };
+    unsafe {
+        let out = ffi::sqlite3_malloc(len) as *mut u8;
+        if !out.is_null() {
+            ptr::copy_nonoverlapping(message.as_ptr(), out, message.len());
+            *out.add(message.len()) = 0;
+        }
+        out as *mut libc::c_char
+    }
+}
+
+unsafe fn set_table_error(vtab: *mut ffi::sqlite3_vtab, message: &str) {
+    unsafe {
+        ffi::sqlite3_free((*vtab).zErrMsg as *mut libc::c_void);
+        (*vtab).zErrMsg = sqlite_string(message);
+    }
+}
+
+fn catch_panic<R>(f: impl FnOnce() -> QueryResult<R>) -> Result<R, String> {
+    // A panicking implementation is reported as failed operation
+    // to SQLite, as we cannot unwind across the FFI boundary
+    crate::util::std_compat::catch_unwind(core::panic::AssertUnwindSafe(f))
+        .unwrap_or_else(|_| {
+            Err(Error::QueryBuilderError(
+                "The virtual table implementation panicked".into(),
+            ))
+        })
+        .map_err(|e| e.to_string())
+}
+
+unsafe extern "C" fn connect<T: VirtualTable>(
+    db: *mut ffi::sqlite3,
+    _aux: *mut libc::c_void,
+    arg_count: libc::c_int,
+    arg_values: *const *const libc::c_char,
+    vtab: *mut *mut ffi::sqlite3_vtab,
+    error_message: *mut *mut libc::c_char,
+) -> libc::c_int {
+    let result = catch_panic(|| {
+        let arg_values =
+            unsafe { slice::from_raw_parts(arg_values, usize::try_from(arg_count).unwrap_or(0)) };
+        // The first three arguments are the module, database and table name
+        let arguments = arg_values
+            .iter()
+            .skip(3)
+            .map(|a| unsafe { CStr::from_ptr(*a) }.to_str())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| Error::DeserializationError(Box::new(e)))?;
+        let table = T::connect(&arguments)?;
+        let declaration = CString::new(table.declaration())?;
+        let result = unsafe { ffi::sqlite3_declare_vtab(db, declaration.as_ptr()) };
+        super::stmt::ensure_sqlite_ok(result, db)?;
+        Ok(Box::into_raw(Box::new(TableInstance {
+            base: unsafe { mem::zeroed() },
+            table,
+        })))
+    });
+    match result {
+        Ok(instance) => {
+            unsafe { *vtab = instance as *mut ffi::sqlite3_vtab };
+            ffi::SQLITE_OK
+        }
+        Err(e) => {
+            unsafe { *error_message = sqlite_string(&e) };
+            ffi::SQLITE_ERROR
+        }
+    }
+}
+
+unsafe extern "C" fn best_index<T: VirtualTable>(
+    vtab: *mut ffi::sqlite3_vtab,
+    info: *mut ffi::sqlite3_index_info,
+) -> libc::c_int {
+    let result = catch_panic(|| {
+        let instance = unsafe { &*(vtab as *const TableInstance<T>) };
+        let mut info = IndexInfo {
+            inner: unsafe { &mut *info },
+        };
+        instance.table.best_index(&mut info)
+    });
+    match result {
+        Ok(()) => ffi::SQLITE_OK,
+        Err(e) => {
+            unsafe { set_table_error(vtab, &e) };
+            ffi::SQLITE_ERROR
+        }
+    }
+}
+
+unsafe extern "C" fn disconnect<T: VirtualTable>(vtab: *mut ffi::sqlite3_vtab) -> libc::c_int {
+    let instance = unsafe { Box::from_raw(vtab as *mut TableInstance<T>) };
+    unsafe { ffi::sqlite3_free(instance.base.zErrMsg as *mut libc::c_void) };
+    // dropping the table could panic as well
+    match crate::util::std_compat::catch_unwind(core::panic::AssertUnwindSafe(|| drop(instance))) {
+        Ok(()) => ffi::SQLITE_OK,
+        Err(_) => ffi::SQLITE_ERROR,
+    }
+}
+
+unsafe extern "C" fn open<T: VirtualTable>(
+    vtab: *mut ffi::sqlite3_vtab,
+    cursor: *mut *mut ffi::sqlite3_vtab_cursor,
+) -> libc::c_int {
+    let result = catch_panic(|| {
+        let instance = unsafe { &*(vtab as *const TableInstance<T>) };
+        let cursor = instance.table.open()?;
+        Ok(Box::into_raw(Box::new(CursorInstance {
+            base: unsafe { mem::zeroed() },
+            cursor,
+        })))
+    });
+    match result {
+        Ok(instance) => {
+            unsafe { *cursor = instance as *mut ffi::sqlite3_vtab_cursor };
+            ffi::SQLITE_OK
+        }
+        Err(e) => {
+            unsafe { set_table_error(vtab, &e) };
+            ffi::SQLITE_ERROR
+        }
+    }
+}
+
+unsafe extern "C" fn close<C: VirtualTableCursor>(
+    cursor: *mut ffi::sqlite3_vtab_cursor,
+) -> libc::c_int {
+    let instance = unsafe { Box::from_raw(cursor as *mut CursorInstance<C>) };
+    match crate::util::std_compat::catch_unwind(core::panic::AssertUnwindSafe(|| drop(instance))) {
+        Ok(()) => ffi::SQLITE_OK,
+        Err(_) => ffi::SQLITE_ERROR,
+    }
+}
+
+/// Runs `f` with the cursor and reports errors via the table of the cursor
+unsafe fn with_cursor<C: VirtualTableCursor>(
+    cursor: *mut ffi::sqlite3_vtab_cursor,
+    f: impl FnOnce(&mut C) -> QueryResult<()>,
+) -> libc::c_int {
+    let result = catch_panic(|| {
+        let instance = unsafe { &mut *(cursor as *mut CursorInstance<C>) };
+        f(&mut instance.cursor)
+    });
+    match result {
+        Ok(()) => ffi::SQLITE_OK,
+        Err(e) => {
+            unsafe { set_table_error((*cursor).pVtab, &e) };
+            ffi::SQLITE_ERROR
+        }
+    }
+}
+
+unsafe extern "C" fn filter<C: VirtualTableCursor>(
+    cursor: *mut ffi::sqlite3_vtab_cursor,
+    index_number: libc::c_int,
+    _index_string: *const libc::c_char,
+    arg_count: libc::c_int,
+    arg_values: *mut *mut ffi::sqlite3_value,
+) -> libc::c_int {
+    let arg_count = usize::try_from(arg_count).unwrap_or(0);
+    let args: &mut [*mut ffi::sqlite3_value] = if arg_count == 0 || arg_values.is_null() {
+        &mut []
+    } else {
+        unsafe { slice::from_raw_parts_mut(arg_values, arg_count) }
+    };
+    let arguments = FilterArguments {
+        row: FunctionRow::new(args),
+    };
+    unsafe { with_cursor::<C>(cursor, |c| c.filter(index_number, &arguments)) }
+}
+
+unsafe extern "C" fn next<C: VirtualTableCursor>(
+    cursor: *mut ffi::sqlite3_vtab_cursor,
+) -> libc::c_int {
+    unsafe { with_cursor::<C>(cursor, |c| c.next()) }
+}
+
+unsafe extern "C" fn eof<C: VirtualTableCursor>(
+    cursor: *mut ffi::sqlite3_vtab_cursor,
+) -> libc::c_int {
+    let instance = unsafe { &*(cursor as *const CursorInstance<C>) };
+    // stop iterating if the implementation panics
+    crate::util::std_compat::catch_unwind(core::panic::AssertUnwindSafe(|| instance.cursor.eof()))
+        .unwrap_or(true)
+        .into()
+}
+
+unsafe extern "C" fn column<C: VirtualTableCursor>(
+    cursor: *mut ffi::sqlite3_vtab_cursor,
+    ctx: *mut ffi::sqlite3_context,
+    column: libc::c_int,
+) -> libc::c_int {
+    let Ok(column) = usize::try_from(column) else {
+        return ffi::SQLITE_ERROR;
+    };
+    unsafe {
+        with_cursor::<C>(cursor, |c| {
+            let mut context = ColumnContext { ctx: &mut *ctx };
+            c.column(column, &mut context)
+        })
+    }
+}
+
+unsafe extern "C" fn rowid<C: VirtualTableCursor>(
+    cursor: *mut ffi::sqlite3_vtab_cursor,
+    rowid: *mut ffi::sqlite3_int64,
+) -> libc::c_int {
+    unsafe {
+        with_cursor::<C>(cursor, |c| {
+            *rowid = c.rowid()?;
+            Ok(())
+        })
+    }
+}
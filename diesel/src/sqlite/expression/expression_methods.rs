@@ -1,8 +1,8 @@
 //! Sqlite specific expression methods.
 
 pub(in crate::sqlite) use self::private::{
-    BinaryOrNullableBinary, JsonOrNullableJson, MaybeNullableValue, NotBlob, TextOrNullableText,
-    TextOrNullableTextOrBinaryOrNullableBinary,
+    BinaryOrNullableBinary, DateOrTimeOrTimestamp, JsonOrNullableJson, MaybeNullableValue, NotBlob,
+    TextOrNullableText, TextOrNullableTextOrBinaryOrNullableBinary,
 };
 use super::operators::*;
 use crate::dsl;
@@ -219,6 +219,22 @@ pub(in crate::sqlite) mod private {
     impl JsonOrNullableJson for Json {}
     impl JsonOrNullableJson for Nullable<Json> {}
 
+    #[diagnostic::on_unimplemented(
+        message = "`{Self}` is neither `diesel::sql_types::Date`, `diesel::sql_types::Time`, \
+                   `diesel::sql_types::Timestamp`, `diesel::sql_types::TimestamptzSqlite` \
+                   nor the `Nullable` variant of one of these types",
+        note = "try to provide an expression that produces one of the expected sql types"
+    )]
+    pub trait DateOrTimeOrTimestamp {}
+    impl DateOrTimeOrTimestamp for Date {}
+    impl DateOrTimeOrTimestamp for Time {}
+    impl DateOrTimeOrTimestamp for Timestamp {}
+    impl DateOrTimeOrTimestamp for TimestamptzSqlite {}
+    impl DateOrTimeOrTimestamp for Nullable<Date> {}
+    impl DateOrTimeOrTimestamp for Nullable<Time> {}
+    impl DateOrTimeOrTimestamp for Nullable<Timestamp> {}
+    impl DateOrTimeOrTimestamp for Nullable<TimestamptzSqlite> {}
+
     pub trait MaybeNullableValue<T>: SingleValue {
         type Out: SingleValue;
     }
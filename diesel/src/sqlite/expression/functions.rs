@@ -5,6 +5,7 @@ use crate::expression::functions::declare_sql_function;
 use crate::expression_methods::json_expression_methods::private::JsonOrNullableJsonOrJsonbOrNullableJsonb;
 use crate::sql_types::*;
 use crate::sqlite::expression::expression_methods::BinaryOrNullableBinary;
+use crate::sqlite::expression::expression_methods::DateOrTimeOrTimestamp;
 use crate::sqlite::expression::expression_methods::JsonOrNullableJson;
 use crate::sqlite::expression::expression_methods::MaybeNullableValue;
 use crate::sqlite::expression::expression_methods::NotBlob;
@@ -2695,6 +2696,65 @@ extern "SQL" {
         target: T,
         patch: P,
     ) -> P::Out;
+
+    /// Returns a BLOB consisting of `n` bytes of `0x00`
+    ///
+    /// This is usually used to reserve space for a BLOB that is later written
+    /// incrementally via [`SqliteConnection::get_blob`](crate::sqlite::SqliteConnection::get_blob).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::zeroblob;
+    /// #     let connection = &mut establish_connection();
+    /// let result = diesel::select(zeroblob(4)).get_result::<Vec<u8>>(connection)?;
+    /// assert_eq!(vec![0, 0, 0, 0], result);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "__sqlite-shared")]
+    #[skip_return_type_helper]
+    fn zeroblob(n: BigInt) -> Binary;
+
+    /// Formats a date, time or timestamp as text according to the given format
+    ///
+    /// This is the SQLite equivalent of the PostgreSQL `to_char` function.
+    /// See the [SQLite documentation](https://www.sqlite.org/lang_datefunc.html)
+    /// for the supported format specifiers. Returns `NULL` if the value is not
+    /// a valid time value. To group rows by day use [`date`](crate::dsl::date())
+    /// instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use diesel::dsl::{sql, strftime};
+    /// #     use diesel::sql_types::Timestamp;
+    /// #     let connection = &mut establish_connection();
+    /// let formatted = diesel::select(strftime(
+    ///     "%d.%m.%Y %H:%M",
+    ///     sql::<Timestamp>("'2024-03-15 13:45:12'"),
+    /// ))
+    /// .get_result::<Option<String>>(connection)?;
+    /// assert_eq!(Some("15.03.2024 13:45".to_owned()), formatted);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "__sqlite-shared")]
+    fn strftime<T: DateOrTimeOrTimestamp + SingleValue>(format: Text, time: T) -> Nullable<Text>;
 }
 
 pub(super) mod return_type_helpers_reexported {
@@ -12,6 +12,7 @@ mod function_behavior;
 
 pub mod query_builder;
 
+mod transaction;
 mod types;
 
 pub use self::auto_extension::cancel_auto_extension;
@@ -29,19 +30,25 @@ pub use self::connection::SqliteTraceEvent;
 pub use self::connection::SqliteTraceFlags;
 pub use self::connection::SqliteValue;
 pub use self::connection::authorizer;
-pub use self::connection::sqlite_blob::SqliteReadOnlyBlob;
+pub use self::connection::sqlite_blob::{SqliteBlob, SqliteReadOnlyBlob};
+pub use self::connection::virtual_table;
 pub use self::connection::{AuthorizerContext, AuthorizerDecision};
 pub use self::connection::{CollationNeededContext, SqliteTextRep};
 #[cfg(feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes")]
 pub use self::connection::{
     OwnedSqliteBindValue, SqliteBindCollector, SqliteBindCollectorData, SqliteBindValueRef,
 };
+pub use self::connection::{SqliteBatchOptions, SqliteWalCheckpointMode};
+pub use self::connection::{
+    SqliteCacheSize, SqliteConnectionConfig, SqliteJournalMode, SqliteSynchronous,
+};
 pub use self::connection::{
     SqliteChangeEvent, SqliteChangeOp, SqliteChangeOps, SqliteUpdateRouter,
 };
 #[cfg(feature = "__sqlite-shared")]
 pub use self::function_behavior::SqliteFunctionBehavior;
 pub use self::query_builder::SqliteQueryBuilder;
+pub use self::transaction::TransactionBuilder;
 
 /// Trait for the implementation of a SQLite aggregate function
 ///
@@ -69,6 +76,12 @@ pub trait SqliteAggregateFunction<Args>: Default {
     fn finalize(aggregator: Option<Self>) -> Self::Output;
 }
 
+/// Data structures for SQLite types which have no corresponding Rust type
+pub mod data_types {
+    #[doc(inline)]
+    pub use super::types::numeric::SqliteNumeric;
+}
+
 /// SQLite specific sql types
 pub mod sql_types {
     #[doc(inline)]
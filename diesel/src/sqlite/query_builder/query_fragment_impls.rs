@@ -1,4 +1,6 @@
 use crate::QueryResult;
+use crate::expression::Expression;
+use crate::expression::ops::{Add, Sub};
 use crate::query_builder::AstPass;
 use crate::query_builder::BoxedSelectStatement;
 use crate::query_builder::QueryFragment;
@@ -7,6 +9,7 @@ use crate::query_builder::select_statement::boxed::BoxedQueryHelper;
 use crate::query_builder::upsert::into_conflict_clause::OnConflictSelectWrapper;
 use crate::query_builder::where_clause::BoxedWhereClause;
 use crate::query_builder::where_clause::WhereClause;
+use crate::sql_types::ops::OperationKind;
 
 // The corresponding impl for`NoWhereClause` is missing because of
 // https://www.sqlite.org/lang_UPSERT.html (Parsing Ambiguity)
@@ -41,3 +44,43 @@ where
         })
     }
 }
+
+// SQLite has no interval type, intervals are sent as a number of seconds
+// and applied as modifier of the `datetime` and `time` functions instead.
+// Both functions return whole seconds, fractional seconds are dropped.
+macro_rules! sqlite_interval_arithmetic {
+    ($name:ident, $op:expr_2021, $sign:expr_2021) => {
+        impl<L, R>
+            QueryFragment<crate::sqlite::Sqlite, crate::sqlite::backend::SqliteIntervalArithmetic>
+            for $name<L, R>
+        where
+            L: Expression + QueryFragment<crate::sqlite::Sqlite>,
+            L::SqlType: crate::sql_types::ops::$name,
+            R: QueryFragment<crate::sqlite::Sqlite>,
+        {
+            fn walk_ast<'b>(
+                &'b self,
+                mut out: AstPass<'_, 'b, crate::sqlite::Sqlite>,
+            ) -> QueryResult<()> {
+                let function = match <L::SqlType as crate::sql_types::ops::$name>::KIND {
+                    OperationKind::Numeric => return self.walk_operator(out, $op),
+                    OperationKind::TimeInterval => "time(",
+                    OperationKind::DateTimeInterval => "datetime(",
+                };
+                out.push_sql(function);
+                self.lhs.walk_ast(out.reborrow())?;
+                out.push_sql(", ");
+                out.push_sql($sign);
+                out.push_sql("(");
+                self.rhs.walk_ast(out.reborrow())?;
+                out.push_sql(") || ' seconds')");
+                Ok(())
+            }
+        }
+    };
+}
+
+#[cfg(feature = "__sqlite-shared")]
+sqlite_interval_arithmetic!(Add, " + ", "");
+#[cfg(feature = "__sqlite-shared")]
+sqlite_interval_arithmetic!(Sub, " - ", "-");
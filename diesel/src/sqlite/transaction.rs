@@ -0,0 +1,267 @@
+use crate::connection::{AnsiTransactionManager, TransactionManager, finish_transaction};
+use crate::prelude::*;
+use crate::result::Error;
+use crate::sqlite::Sqlite;
+
+/// Used to build a transaction, specifying additional details.
+///
+/// This struct is returned by [`.build_transaction`].
+/// See the documentation for methods on this struct for usage examples.
+/// See [the SQLite documentation for `BEGIN TRANSACTION`][sqlite-docs]
+/// for details on the behavior of each option.
+///
+/// SQLite transactions are always `SERIALIZABLE`, therefore
+/// this builder does not offer to set an isolation level.
+///
+/// [`.build_transaction`]: crate::SqliteConnection::build_transaction()
+/// [sqlite-docs]: https://www.sqlite.org/lang_transaction.html
+#[allow(missing_debug_implementations)] // False positive. Connection isn't Debug.
+#[must_use = "Transaction builder does nothing unless you call `run` on it"]
+pub struct TransactionBuilder<'a, C> {
+    connection: &'a mut C,
+    behavior: Behavior,
+    read_only: bool,
+    restore_query_only: bool,
+}
+
+impl<'a, C> TransactionBuilder<'a, C>
+where
+    C: Connection<Backend = Sqlite, TransactionManager = AnsiTransactionManager>,
+{
+    /// Creates a new TransactionBuilder.
+    #[diesel_derives::__diesel_public_if(
+        feature = "i-implement-a-third-party-backend-and-opt-into-breaking-changes"
+    )]
+    pub(crate) fn new(connection: &'a mut C) -> Self {
+        Self {
+            connection,
+            behavior: Behavior::Deferred,
+            read_only: false,
+            restore_query_only: false,
+        }
+    }
+
+    /// The value `PRAGMA query_only` is reset to after a read only transaction
+    pub(crate) fn restore_query_only(mut self, enabled: bool) -> Self {
+        self.restore_query_only = enabled;
+        self
+    }
+
+    /// Starts the transaction with `BEGIN DEFERRED`
+    ///
+    /// This is the default. The transaction acquires locks only once
+    /// the database is accessed for the first time.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// conn.build_transaction().deferred().run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn deferred(mut self) -> Self {
+        self.behavior = Behavior::Deferred;
+        self
+    }
+
+    /// Starts the transaction with `BEGIN IMMEDIATE`
+    ///
+    /// The transaction starts to write to the database immediately,
+    /// which prevents `SQLITE_BUSY` errors when upgrading a read
+    /// transaction to a write transaction later on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// conn.build_transaction().immediate().run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn immediate(mut self) -> Self {
+        self.behavior = Behavior::Immediate;
+        self
+    }
+
+    /// Starts the transaction with `BEGIN EXCLUSIVE`
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+    /// conn.build_transaction().exclusive().run(|conn| Ok(()))
+    /// # }
+    /// ```
+    pub fn exclusive(mut self) -> Self {
+        self.behavior = Behavior::Exclusive;
+        self
+    }
+
+    /// Makes the transaction read only
+    ///
+    /// SQLite has no read only transactions, instead `PRAGMA query_only`
+    /// is enabled for the duration of the transaction. Any statement
+    /// modifying the database fails in the meantime.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("../doctest_setup.rs");
+    /// #
+    /// # fn main() {
+    /// #     run_test().unwrap();
+    /// # }
+    /// #
+    /// # fn run_test() -> QueryResult<()> {
+    /// #     use schema::users::dsl::*;
+    /// #     let conn = &mut establish_connection();
+    /// conn.build_transaction()
+    ///     .read_only()
+    ///     .run::<_, diesel::result::Error, _>(|conn| {
+    ///         let read_attempt = users.select(name).load::<String>(conn);
+    ///         assert!(read_attempt.is_ok());
+    ///
+    ///         let write_attempt = diesel::insert_into(users)
+    ///             .values(name.eq("Ruby"))
+    ///             .execute(conn);
+    ///         assert!(write_attempt.is_err());
+    ///
+    ///         Ok(())
+    ///     })?;
+    ///
+    /// // the connection is writable again after the transaction
+    /// diesel::insert_into(users)
+    ///     .values(name.eq("Ruby"))
+    ///     .execute(conn)?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn read_only(mut self) -> Self {
+        self.read_only = true;
+        self
+    }
+
+    /// Runs the given function inside of the transaction
+    /// with the parameters given to this builder.
+    ///
+    /// This function executes the provided closure `f` inside a database
+    /// transaction. If there is already an open transaction for the current
+    /// connection it will return an error. The connection is committed if
+    /// the closure returns `Ok(_)`, it will be rolled back if it returns `Err(_)`.
+    /// For both cases the original result value will be returned from this function.
+    pub fn run<T, E, F>(&mut self, f: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut C) -> Result<T, E>,
+        E: From<Error>,
+    {
+        AnsiTransactionManager::begin_transaction_sql(
+            &mut *self.connection,
+            self.behavior.to_sql(),
+        )?;
+        if !self.read_only {
+            return finish_transaction::<AnsiTransactionManager, _, _, _, _>(
+                &mut *self.connection,
+                f,
+            );
+        }
+
+        if let Err(e) = self.connection.batch_execute("PRAGMA query_only = 1") {
+            AnsiTransactionManager::rollback_transaction(&mut *self.connection)?;
+            return Err(e.into());
+        }
+        let result =
+            finish_transaction::<AnsiTransactionManager, _, _, _, _>(&mut *self.connection, f);
+        // `query_only` is a property of the connection
+        // and not reset by the end of the transaction
+        let restore = if self.restore_query_only {
+            "PRAGMA query_only = 1"
+        } else {
+            "PRAGMA query_only = 0"
+        };
+        self.connection.batch_execute(restore)?;
+        result
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Behavior {
+    Deferred,
+    Immediate,
+    Exclusive,
+}
+
+impl Behavior {
+    fn to_sql(self) -> &'static str {
+        match self {
+            Behavior::Deferred => "BEGIN DEFERRED",
+            Behavior::Immediate => "BEGIN IMMEDIATE",
+            Behavior::Exclusive => "BEGIN EXCLUSIVE",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::connection::{AnsiTransactionManager, TransactionManager};
+    use crate::prelude::*;
+    use crate::result::Error;
+
+    fn transaction_depth(conn: &mut SqliteConnection) -> Option<core::num::NonZeroU32> {
+        <AnsiTransactionManager as TransactionManager<SqliteConnection>>::transaction_manager_status_mut(conn)
+            .transaction_depth()
+            .expect("Transaction depth")
+    }
+
+    #[diesel_test_helper::test]
+    fn read_only_transactions_reject_writes_and_reset_query_only() {
+        let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+        crate::sql_query("CREATE TABLE read_only_test (id INTEGER)")
+            .execute(conn)
+            .unwrap();
+
+        let result = conn
+            .build_transaction()
+            .immediate()
+            .read_only()
+            .run(|conn| crate::sql_query("INSERT INTO read_only_test VALUES (1)").execute(conn));
+        assert!(matches!(result, Err(Error::DatabaseError(_, _))));
+        assert_eq!(None, transaction_depth(conn));
+
+        conn.build_transaction()
+            .read_only()
+            .run(|conn| crate::sql_query("SELECT * FROM read_only_test").execute(conn))
+            .unwrap();
+        assert_eq!(
+            Ok(1),
+            crate::sql_query("INSERT INTO read_only_test VALUES (1)").execute(conn)
+        );
+    }
+
+    #[diesel_test_helper::test]
+    fn build_transaction_cannot_be_nested() {
+        let conn = &mut SqliteConnection::establish(":memory:").unwrap();
+        let result = conn.transaction(|conn| conn.build_transaction().exclusive().run(|_| Ok(())));
+        assert_eq!(Err(Error::AlreadyInTransaction), result);
+        assert_eq!(None, transaction_depth(conn));
+    }
+}
@@ -1,11 +1,11 @@
 extern crate chrono;
 
-use self::chrono::{DateTime, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
+use self::chrono::{DateTime, Duration, Local, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Utc};
 
 use crate::backend::Backend;
 use crate::deserialize::{self, FromSql};
 use crate::serialize::{self, IsNull, Output, ToSql};
-use crate::sql_types::{Date, Time, Timestamp, TimestamptzSqlite};
+use crate::sql_types::{Date, Interval, Time, Timestamp, TimestamptzSqlite};
 use crate::sqlite::Sqlite;
 
 /// Warning to future editors:
@@ -236,6 +236,17 @@ impl<TZ: TimeZone> ToSql<TimestamptzSqlite, Sqlite> for DateTime<TZ> {
     }
 }
 
+#[cfg(all(feature = "__sqlite-shared", feature = "chrono"))]
+impl ToSql<Interval, Sqlite> for Duration {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        let microseconds = self
+            .num_microseconds()
+            .ok_or("Failed to create microseconds by overflow")?;
+        out.set_value(microseconds as f64 / 1_000_000.0);
+        Ok(IsNull::No)
+    }
+}
+
 #[cfg(all(test, not(all(target_family = "wasm", target_os = "unknown"))))]
 #[allow(clippy::unwrap_used)]
 mod tests {
@@ -1,5 +1,5 @@
 use crate::deserialize::{self, FromSql};
-use crate::serialize::{self, Output, ToSql};
+use crate::serialize::{self, IsNull, Output, ToSql};
 use crate::sql_types;
 use crate::sqlite::Sqlite;
 use crate::sqlite::connection::SqliteValue;
@@ -94,6 +94,16 @@ impl ToSql<sql_types::TimestamptzSqlite, Sqlite> for String {
     }
 }
 
+// SQLite has no interval type, intervals are sent as a number of seconds
+// and applied as modifier of the `datetime` and `time` functions instead
+#[cfg(feature = "__sqlite-shared")]
+impl ToSql<sql_types::Interval, Sqlite> for core::time::Duration {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.as_secs_f64());
+        Ok(IsNull::No)
+    }
+}
+
 #[cfg(all(
     test,
     feature = "chrono",
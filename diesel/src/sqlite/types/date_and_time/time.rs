@@ -4,7 +4,7 @@
 extern crate time;
 
 use self::time::{
-    Date as NaiveDate, OffsetDateTime, PrimitiveDateTime, Time as NaiveTime, UtcOffset,
+    Date as NaiveDate, Duration, OffsetDateTime, PrimitiveDateTime, Time as NaiveTime, UtcOffset,
     error::ComponentRange, macros::format_description,
 };
 // the non-deprecated variant does not exist in our minimal supported version
@@ -14,7 +14,7 @@ use self::time::format_description::FormatItem;
 use crate::backend::Backend;
 use crate::deserialize::{self, FromSql};
 use crate::serialize::{self, IsNull, Output, ToSql};
-use crate::sql_types::{Date, Time, Timestamp, TimestamptzSqlite};
+use crate::sql_types::{Date, Interval, Time, Timestamp, TimestamptzSqlite};
 use crate::sqlite::Sqlite;
 
 /// Warning to future editors:
@@ -310,6 +310,14 @@ impl ToSql<TimestamptzSqlite, Sqlite> for OffsetDateTime {
     }
 }
 
+#[cfg(all(feature = "__sqlite-shared", feature = "time"))]
+impl ToSql<Interval, Sqlite> for Duration {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(self.as_seconds_f64());
+        Ok(IsNull::No)
+    }
+}
+
 #[cfg(all(test, not(all(target_family = "wasm", target_os = "unknown"))))]
 mod tests {
     extern crate dotenvy;
@@ -1,5 +1,7 @@
 //! Support for JSON and JSONB values under SQLite.
 
+#[cfg(feature = "serde")]
+use crate::data_types::Json;
 use crate::deserialize::{self, FromSql};
 use crate::serialize::{self, IsNull, Output, ToSql};
 use crate::sql_types;
@@ -59,6 +61,57 @@ impl ToSql<sql_types::Jsonb, Sqlite> for serde_json::Value {
     }
 }
 
+#[cfg(all(feature = "__sqlite-shared", feature = "serde"))]
+impl<T> FromSql<sql_types::Json, Sqlite> for Json<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        serde_json::from_str(value.read_text())
+            .map(Json)
+            .map_err(Into::into)
+    }
+}
+
+#[cfg(all(feature = "__sqlite-shared", feature = "serde"))]
+impl<T> ToSql<sql_types::Json, Sqlite> for Json<T>
+where
+    T: serde::Serialize + core::fmt::Debug,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        out.set_value(serde_json::to_string(&self.0)?);
+        Ok(IsNull::No)
+    }
+}
+
+#[cfg(all(feature = "__sqlite-shared", feature = "serde"))]
+impl<T> FromSql<sql_types::Jsonb, Sqlite> for Json<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+        let value = <serde_json::Value as FromSql<sql_types::Jsonb, Sqlite>>::from_sql(value)?;
+        serde_json::from_value(value).map(Json).map_err(Into::into)
+    }
+}
+
+#[cfg(all(feature = "__sqlite-shared", feature = "serde"))]
+impl<T> ToSql<sql_types::Jsonb, Sqlite> for Json<T>
+where
+    T: serde::Serialize + core::fmt::Debug,
+{
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+        use self::jsonb::*;
+
+        let value = serde_json::to_value(&self.0)?;
+        let mut buffer = Vec::new();
+        write_jsonb_value(&value, &mut buffer)?;
+        out.set_value(buffer);
+
+        Ok(IsNull::No)
+    }
+}
+
 #[cfg(all(feature = "__sqlite-shared", feature = "serde_json"))]
 mod jsonb {
     extern crate serde_json;
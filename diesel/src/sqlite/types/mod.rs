@@ -2,7 +2,7 @@ mod date_and_time;
 mod enum_;
 #[cfg(all(feature = "__sqlite-shared", feature = "serde_json"))]
 mod json;
-mod numeric;
+pub(super) mod numeric;
 
 use super::Sqlite;
 use super::connection::SqliteValue;
@@ -99,3 +99,92 @@ mod bigdecimal {
         }
     }
 }
+
+mod numeric_string {
+    use alloc::string::{String, ToString};
+
+    use crate::data_types::NumericString;
+    use crate::deserialize::{self, FromSql, FromSqlRow};
+    use crate::expression::AsExpression;
+    use crate::serialize::{self, IsNull, Output, ToSql};
+    use crate::sql_types::Numeric;
+    use crate::sqlite::connection::{SqliteBindValue, SqliteValue};
+    use crate::sqlite::{Sqlite, SqliteType};
+
+    /// Represents a `NUMERIC` value as stored by SQLite
+    ///
+    /// SQLite stores values with `NUMERIC` affinity using the
+    /// storage class that represents the value best. This type exposes
+    /// the stored value without any further conversion.
+    #[derive(Debug, Clone, PartialEq, AsExpression, FromSqlRow)]
+    #[diesel(sql_type = Numeric)]
+    pub enum SqliteNumeric {
+        /// A value stored with the `INTEGER` storage class
+        Integer(i64),
+        /// A value stored with the `REAL` storage class
+        Real(f64),
+        /// A value stored with the `TEXT` storage class
+        Text(String),
+    }
+
+    #[cfg(feature = "__sqlite-shared")]
+    impl FromSql<Numeric, Sqlite> for SqliteNumeric {
+        fn from_sql(mut value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+            match value.value_type() {
+                Some(SqliteType::Long) => Ok(Self::Integer(value.read_long())),
+                Some(SqliteType::Double) => Ok(Self::Real(value.read_double())),
+                Some(SqliteType::Text) => Ok(Self::Text(value.read_text().to_string())),
+                _ => Err("Expected a numeric value, got a blob".into()),
+            }
+        }
+    }
+
+    #[cfg(feature = "__sqlite-shared")]
+    impl ToSql<Numeric, Sqlite> for SqliteNumeric {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+            match self {
+                Self::Integer(i) => out.set_value(SqliteBindValue::numeric(*i)),
+                Self::Real(f) => out.set_value(SqliteBindValue::numeric(*f)),
+                Self::Text(s) => out.set_value(SqliteBindValue::numeric(s.as_str())),
+            }
+            Ok(IsNull::No)
+        }
+    }
+
+    impl TryFrom<SqliteNumeric> for NumericString {
+        type Error = alloc::boxed::Box<dyn core::error::Error + Send + Sync>;
+
+        fn try_from(value: SqliteNumeric) -> Result<Self, Self::Error> {
+            match value {
+                SqliteNumeric::Integer(i) => Ok(i.into()),
+                SqliteNumeric::Real(f) if f.is_finite() => {
+                    Ok(NumericString::new_unchecked(f.to_string()))
+                }
+                SqliteNumeric::Real(f) if f.is_nan() => {
+                    Ok(NumericString::new_unchecked("NaN".into()))
+                }
+                SqliteNumeric::Real(_) => {
+                    Err("Infinite values are not valid decimal numbers".into())
+                }
+                SqliteNumeric::Text(s) => NumericString::try_from(s),
+            }
+        }
+    }
+
+    #[cfg(feature = "__sqlite-shared")]
+    impl ToSql<Numeric, Sqlite> for NumericString {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Sqlite>) -> serialize::Result {
+            out.set_value(SqliteBindValue::numeric(self.as_str()));
+            Ok(IsNull::No)
+        }
+    }
+
+    #[cfg(feature = "__sqlite-shared")]
+    impl FromSql<Numeric, Sqlite> for NumericString {
+        fn from_sql(value: SqliteValue<'_, '_, '_>) -> deserialize::Result<Self> {
+            SqliteNumeric::from_sql(value)?.try_into()
+        }
+    }
+}
+
+pub use self::numeric_string::SqliteNumeric;
@@ -0,0 +1,84 @@
+//! Helpers to test that values survive a round trip through the database
+//!
+//! Serializing a value via [`ToSql`](crate::serialize::ToSql) and
+//! deserializing it again via [`FromSql`](crate::deserialize::FromSql)
+//! should result in the same value. Asymmetric implementations often only
+//! fail for some values, for example because of time zones, precision or
+//! text encoding. The [`roundtrip!`] macro checks this property for
+//! randomly generated values, which are created by a
+//! [`proptest`](https://docs.rs/proptest) [`Strategy`]. Failing values are
+//! shrunk to a minimal example before they are reported.
+//! [`strategies`] contains strategies generating values which are
+//! representable by the built-in SQL types of each backend. They
+//! can be combined to generate values of custom types.
+//!
+//! This module is only available with the `proptest` feature.
+//!
+//! # Example
+//!
+//! ```rust
+//! # include!("../doctest_setup.rs");
+//! use diesel::sql_types::{Integer, Text};
+//! use diesel::test_helpers::roundtrip;
+//! use diesel::test_helpers::strategies::{self, Strategy};
+//! # use diesel::{AsExpression, FromSqlRow};
+//!
+//! # fn main() {
+//! #     let connection = &mut establish_connection();
+//! roundtrip!(connection, Integer, strategies::any::<i32>());
+//! # #[cfg(feature = "postgres")]
+//! roundtrip!(connection, Text, strategies::pg::text());
+//! # #[cfg(feature = "sqlite")]
+//! roundtrip!(connection, Text, strategies::sqlite::text());
+//! # #[cfg(feature = "mysql")]
+//! roundtrip!(connection, Text, strategies::mysql::text());
+//!
+//! // strategies can be mapped to generate values of custom types
+//! #[derive(Debug, Clone, PartialEq, AsExpression, FromSqlRow)]
+//! #[diesel(sql_type = Integer)]
+//! struct Percentage(i32);
+//! # impl<DB> diesel::serialize::ToSql<Integer, DB> for Percentage
+//! # where
+//! #     DB: diesel::backend::Backend,
+//! #     i32: diesel::serialize::ToSql<Integer, DB>,
+//! # {
+//! #     fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, DB>) -> diesel::serialize::Result {
+//! #         self.0.to_sql(out)
+//! #     }
+//! # }
+//! # impl<DB> diesel::deserialize::FromSql<Integer, DB> for Percentage
+//! # where
+//! #     DB: diesel::backend::Backend,
+//! #     i32: diesel::deserialize::FromSql<Integer, DB>,
+//! # {
+//! #     fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {
+//! #         i32::from_sql(bytes).map(Percentage)
+//! #     }
+//! # }
+//!
+//! let percentages = strategies::any::<u8>().prop_map(|p| Percentage(i32::from(p % 101)));
+//! roundtrip!(connection, Integer, percentages);
+//! # }
+//! ```
+
+#[cfg(test)]
+mod connection;
+#[cfg(feature = "proptest")]
+mod roundtrip;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+
+#[cfg(test)]
+pub use self::connection::*;
+#[cfg(feature = "proptest")]
+#[doc(hidden)]
+pub use self::roundtrip::{__comparator, __eq, __nullable_comparator};
+#[cfg(feature = "proptest")]
+#[doc(inline)]
+pub use self::roundtrip::{check_roundtrip, check_roundtrips, f32_eq, f64_eq};
+#[cfg(feature = "proptest")]
+#[doc(inline)]
+pub use self::strategies::Strategy;
+#[cfg(feature = "proptest")]
+#[doc(inline)]
+pub use crate::__diesel_roundtrip as roundtrip;
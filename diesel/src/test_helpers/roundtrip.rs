@@ -0,0 +1,212 @@
+use core::cell::RefCell;
+use core::fmt::Debug;
+
+use proptest::test_runner::{Config, TestCaseError, TestError, TestRunner};
+
+use super::strategies::Strategy;
+use crate::connection::Connection;
+use crate::deserialize::FromSqlRow;
+use crate::dsl;
+use crate::expression::{AsExpression, IntoSql, TypedExpressionType};
+use crate::query_builder::{AsQuery, QueryId};
+use crate::query_dsl::{LoadQuery, RunQueryDsl};
+use crate::select;
+use crate::sql_types::{HasSqlType, SingleValue, SqlType};
+
+/// Checks that `value` is unchanged after sending it to the database as `ST`
+/// and loading it back
+///
+/// `compare` returns `true` if both values are considered equal.
+///
+/// # Panics
+///
+/// This function panics if the query fails or if the loaded value is not
+/// equal to `value`.
+pub fn check_roundtrip<ST, T, C, F>(conn: &mut C, value: T, compare: F)
+where
+    C: Connection,
+    F: Fn(&T, &T) -> bool,
+    ST: QueryId + SqlType + TypedExpressionType + SingleValue,
+    C::Backend: HasSqlType<ST>,
+    T: AsExpression<ST> + FromSqlRow<ST, C::Backend> + Clone + Debug + 'static,
+    for<'a> dsl::select<<T as AsExpression<ST>>::Expression>: AsQuery + LoadQuery<'a, C, T>,
+{
+    if let Err(message) = roundtrip::<ST, T, C, F>(conn, value, compare) {
+        panic!("{message}");
+    }
+}
+
+/// Checks that `tests` values generated by `strategy` are unchanged after
+/// sending them to the database as `ST` and loading them back
+///
+/// The values are generated by a [`proptest`] [`TestRunner`], which
+/// shrinks the first failing value to a minimal example. The runner is
+/// configured via the `PROPTEST_*` environment variables, except for the
+/// number of cases and failure persistence, which is disabled.
+///
+/// See [`check_roundtrip`] for details. Most users want to use the
+/// [`roundtrip!`](super::roundtrip) macro instead, which additionally
+/// checks the nullable variant of `ST`.
+///
+/// # Panics
+///
+/// This function panics with the minimal failing value if any query fails
+/// or if any loaded value differs from the generated one.
+pub fn check_roundtrips<ST, T, C, S, F>(conn: &mut C, strategy: S, compare: F, tests: u32)
+where
+    C: Connection,
+    S: Strategy<Value = T>,
+    F: Fn(&T, &T) -> bool,
+    ST: QueryId + SqlType + TypedExpressionType + SingleValue,
+    C::Backend: HasSqlType<ST>,
+    T: AsExpression<ST> + FromSqlRow<ST, C::Backend> + Clone + Debug + 'static,
+    for<'a> dsl::select<<T as AsExpression<ST>>::Expression>: AsQuery + LoadQuery<'a, C, T>,
+{
+    let mut config = Config::with_cases(tests);
+    config.failure_persistence = None;
+    let mut runner = TestRunner::new(config);
+    // the runner only accepts `Fn` closures
+    let conn = RefCell::new(conn);
+    let result = runner.run(&strategy, |value| {
+        let mut conn = conn.borrow_mut();
+        roundtrip::<ST, T, C, _>(&mut **conn, value, &compare).map_err(TestCaseError::fail)
+    });
+    match result {
+        Ok(()) => {}
+        Err(TestError::Fail(reason, value)) => {
+            panic!("{reason}; minimal failing value: {value:?}")
+        }
+        Err(e @ TestError::Abort(_)) => panic!("{e}"),
+    }
+}
+
+fn roundtrip<ST, T, C, F>(conn: &mut C, value: T, compare: F) -> Result<(), String>
+where
+    C: Connection,
+    F: Fn(&T, &T) -> bool,
+    ST: QueryId + SqlType + TypedExpressionType + SingleValue,
+    C::Backend: HasSqlType<ST>,
+    T: AsExpression<ST> + FromSqlRow<ST, C::Backend> + Clone + Debug + 'static,
+    for<'a> dsl::select<<T as AsExpression<ST>>::Expression>: AsQuery + LoadQuery<'a, C, T>,
+{
+    let query = select(value.clone().into_sql::<ST>());
+    match query.get_result::<T>(conn) {
+        Ok(loaded) if compare(&value, &loaded) => Ok(()),
+        Ok(loaded) => Err(format!(
+            "Value did not round trip: sent {value:?}, loaded {loaded:?}"
+        )),
+        Err(e) => Err(format!("Query failed: {e:?} -> value: {value:?}")),
+    }
+}
+
+/// Compares two `f32` values, treating `NaN` as equal to itself
+#[allow(clippy::float_cmp)]
+pub fn f32_eq(a: &f32, b: &f32) -> bool {
+    (a.is_nan() && b.is_nan()) || a == b
+}
+
+/// Compares two `f64` values, treating `NaN` as equal to itself
+#[allow(clippy::float_cmp)]
+pub fn f64_eq(a: &f64, b: &f64) -> bool {
+    (a.is_nan() && b.is_nan()) || a == b
+}
+
+#[doc(hidden)]
+pub fn __eq<T: PartialEq>(a: &T, b: &T) -> bool {
+    a == b
+}
+
+// Forces closures passed to `roundtrip!` to be inferred with a signature
+// that accepts references of any lifetime
+#[doc(hidden)]
+pub fn __comparator<T, F>(compare: F) -> F
+where
+    F: Fn(&T, &T) -> bool,
+{
+    compare
+}
+
+#[doc(hidden)]
+pub fn __nullable_comparator<T, F>(compare: F) -> impl Fn(&Option<T>, &Option<T>) -> bool
+where
+    F: Fn(&T, &T) -> bool,
+{
+    move |a, b| match (a, b) {
+        (Some(a), Some(b)) => compare(a, b),
+        (None, None) => true,
+        _ => false,
+    }
+}
+
+/// Checks that values generated by a [`proptest`] [`Strategy`] survive a
+/// round trip through the database
+///
+/// The macro accepts a mutable reference to a connection, the SQL type
+/// to send the values as and a [`Strategy`] generating the values. The check
+/// is performed both for the given SQL type and for its
+/// [`Nullable`](crate::sql_types::Nullable) variant. Values are compared
+/// with `==` by default, a custom comparison can be provided via
+/// `compare = ...`. By default 100 values are checked per SQL type,
+/// which can be changed via `tests = ...`.
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// use diesel::sql_types::Double;
+/// use diesel::test_helpers::{f64_eq, roundtrip, strategies};
+///
+/// # fn main() {
+/// #     let connection = &mut establish_connection();
+/// # #[cfg(feature = "postgres")]
+/// # let doubles = strategies::any::<f64>();
+/// # #[cfg(not(feature = "postgres"))]
+/// let doubles = strategies::sqlite::double();
+/// roundtrip!(connection, Double, doubles, compare = f64_eq, tests = 20);
+/// # }
+/// ```
+///
+/// # Panics
+///
+/// The macro panics if any query fails or if any loaded value differs
+/// from the value sent to the database.
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __diesel_roundtrip {
+    ($conn: expr, $sql_type: ty, $strategy: expr $(,)?) => {
+        $crate::__diesel_roundtrip!(
+            $conn,
+            $sql_type,
+            $strategy,
+            compare = $crate::test_helpers::__eq,
+            tests = 100
+        )
+    };
+    ($conn: expr, $sql_type: ty, $strategy: expr, tests = $tests: expr $(,)?) => {
+        $crate::__diesel_roundtrip!(
+            $conn,
+            $sql_type,
+            $strategy,
+            compare = $crate::test_helpers::__eq,
+            tests = $tests
+        )
+    };
+    ($conn: expr, $sql_type: ty, $strategy: expr, compare = $compare: expr $(,)?) => {
+        $crate::__diesel_roundtrip!($conn, $sql_type, $strategy, compare = $compare, tests = 100)
+    };
+    ($conn: expr, $sql_type: ty, $strategy: expr, compare = $compare: expr, tests = $tests: expr $(,)?) => {{
+        let conn = &mut *$conn;
+        let strategy = $strategy;
+        let compare = $crate::test_helpers::__comparator($compare);
+        $crate::test_helpers::check_roundtrips::<$sql_type, _, _, _, _>(
+            &mut *conn,
+            &strategy,
+            &compare,
+            $tests,
+        );
+        $crate::test_helpers::check_roundtrips::<$crate::sql_types::Nullable<$sql_type>, _, _, _, _>(
+            &mut *conn,
+            $crate::test_helpers::strategies::option(&strategy),
+            $crate::test_helpers::__nullable_comparator(&compare),
+            $tests,
+        );
+    }};
+}
@@ -0,0 +1,100 @@
+//! Strategies generating values for round trip tests
+//!
+//! The strategies in this module are [`proptest`] strategies, so they can be
+//! combined with any other strategy and adapted via [`Strategy::prop_map`]
+//! to generate values of custom types. Values generated by
+//! [`any`] cover the whole domain of a type, which is a good fit for types
+//! like integers or booleans.
+//!
+//! The backend specific modules contain strategies which only generate
+//! values representable by the corresponding SQL type of that backend.
+
+#[doc(inline)]
+pub use proptest::arbitrary::any;
+#[doc(inline)]
+pub use proptest::collection::vec;
+#[doc(inline)]
+pub use proptest::option::of as option;
+#[doc(inline)]
+pub use proptest::strategy::Strategy;
+
+#[cfg(feature = "mysql_backend")]
+pub mod mysql;
+#[cfg(feature = "postgres_backend")]
+pub mod pg;
+#[cfg(feature = "__sqlite-shared")]
+pub mod sqlite;
+
+#[cfg(all(
+    feature = "chrono",
+    any(
+        feature = "postgres_backend",
+        feature = "mysql_backend",
+        feature = "__sqlite-shared"
+    )
+))]
+/// Generates dates in `earliest..=latest`
+pub(crate) fn date_between(
+    earliest: chrono::NaiveDate,
+    latest: chrono::NaiveDate,
+) -> impl Strategy<Value = chrono::NaiveDate> + Clone {
+    let days = latest.signed_duration_since(earliest).num_days();
+    (0..=days).prop_map(move |offset| earliest + chrono::Duration::days(offset))
+}
+
+#[cfg(all(
+    feature = "chrono",
+    any(
+        feature = "postgres_backend",
+        feature = "mysql_backend",
+        feature = "__sqlite-shared"
+    )
+))]
+/// Generates times whose fractional seconds are a multiple of `nanos_step`
+pub(crate) fn time_with_precision(
+    nanos_step: u32,
+) -> impl Strategy<Value = chrono::NaiveTime> + Clone {
+    (0..86_400_u32, 0..1_000_000_000 / nanos_step).prop_map(move |(seconds, steps)| {
+        chrono::NaiveTime::from_num_seconds_from_midnight_opt(seconds, steps * nanos_step)
+            .expect("Seconds and nanoseconds are in range")
+    })
+}
+
+#[cfg(all(
+    feature = "numeric",
+    any(feature = "postgres_backend", feature = "mysql_backend")
+))]
+/// Generates decimals with up to 19 digits before and 20 digits
+/// after the decimal point
+pub(crate) fn bigdecimal() -> impl Strategy<Value = bigdecimal::BigDecimal> + Clone {
+    (any::<i64>(), any::<u64>()).prop_map(|(integer, fraction)| {
+        format!("{integer}.{fraction}")
+            .parse()
+            .expect("Formatted string is a valid decimal")
+    })
+}
+
+#[cfg(all(
+    feature = "serde_json",
+    any(feature = "postgres_backend", feature = "mysql_backend")
+))]
+/// Generates json values without floating point numbers, as those
+/// are not guaranteed to be stored exactly
+pub(crate) fn json_value() -> impl Strategy<Value = serde_json::Value> + Clone {
+    use proptest::prop_oneof;
+    use serde_json::Value;
+
+    let leaf = prop_oneof![
+        proptest::strategy::Just(Value::Null),
+        any::<bool>().prop_map(Value::Bool),
+        any::<i32>().prop_map(|i| Value::Number(i.into())),
+        "[a-zA-Z]*".prop_map(Value::String),
+    ];
+    leaf.prop_recursive(2, 64, 16, |inner| {
+        prop_oneof![
+            vec(inner.clone(), 0..16).prop_map(Value::Array),
+            proptest::collection::btree_map("[a-zA-Z]*", inner, 0..7)
+                .prop_map(|fields| Value::Object(fields.into_iter().collect())),
+        ]
+    })
+}
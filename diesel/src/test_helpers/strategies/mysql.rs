@@ -0,0 +1,78 @@
+//! Strategies generating values representable by MySQL
+//!
+//! Types whose whole domain is supported by MySQL, like integers,
+//! strings, booleans or byte vectors can use [`any`](super::any).
+
+use super::Strategy;
+
+/// Generates strings for `Text`
+pub fn text() -> impl Strategy<Value = String> + Clone {
+    super::any::<String>()
+}
+
+/// Generates floats for `Float`
+///
+/// MySQL supports neither `NaN` nor infinite values.
+pub fn float() -> impl Strategy<Value = f32> + Clone {
+    use proptest::num::f32::*;
+
+    POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO
+}
+
+/// Generates doubles for `Double`
+///
+/// MySQL supports neither `NaN` nor infinite values.
+pub fn double() -> impl Strategy<Value = f64> + Clone {
+    use proptest::num::f64::*;
+
+    POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO
+}
+
+/// Generates dates for `Date` between the years 1000 and 9999
+#[cfg(feature = "chrono")]
+pub fn date() -> impl Strategy<Value = chrono::NaiveDate> + Clone {
+    let earliest = chrono::NaiveDate::from_ymd_opt(1000, 1, 1).expect("Date is valid");
+    let latest = chrono::NaiveDate::from_ymd_opt(9999, 12, 31).expect("Date is valid");
+    super::date_between(earliest, latest)
+}
+
+/// Generates times for `Time`
+///
+/// MySQL truncates fractional seconds of `TIME` values.
+#[cfg(feature = "chrono")]
+pub fn time() -> impl Strategy<Value = chrono::NaiveTime> + Clone {
+    super::time_with_precision(1_000_000_000)
+}
+
+/// Generates date times for `Datetime` with microsecond precision
+#[cfg(feature = "chrono")]
+pub fn datetime() -> impl Strategy<Value = chrono::NaiveDateTime> + Clone {
+    (date(), super::time_with_precision(1_000)).prop_map(|(date, time)| date.and_time(time))
+}
+
+/// Generates timestamps for `Timestamp` with microsecond precision
+///
+/// MySQL only supports timestamps between 1970 and 2038.
+#[cfg(feature = "chrono")]
+pub fn timestamp() -> impl Strategy<Value = chrono::NaiveDateTime> + Clone {
+    // leave a day of margin to both ends to account for the session time zone
+    let earliest = chrono::NaiveDate::from_ymd_opt(1970, 1, 2).expect("Date is valid");
+    let latest = chrono::NaiveDate::from_ymd_opt(2038, 1, 18).expect("Date is valid");
+    (
+        super::date_between(earliest, latest),
+        super::time_with_precision(1_000),
+    )
+        .prop_map(|(date, time)| date.and_time(time))
+}
+
+/// Generates decimals for `Numeric`
+#[cfg(feature = "numeric")]
+pub fn numeric() -> impl Strategy<Value = bigdecimal::BigDecimal> + Clone {
+    super::bigdecimal()
+}
+
+/// Generates json values for `Json`
+#[cfg(feature = "serde_json")]
+pub fn json() -> impl Strategy<Value = serde_json::Value> + Clone {
+    super::json_value()
+}
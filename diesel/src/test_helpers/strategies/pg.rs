@@ -0,0 +1,54 @@
+//! Strategies generating values representable by PostgreSQL
+//!
+//! Types whose whole domain is supported by PostgreSQL, like integers,
+//! floats including `NaN`, booleans, byte vectors or the types in
+//! [`diesel::pg::data_types`](crate::pg::data_types) can use
+//! [`any`](super::any).
+
+use super::Strategy;
+
+/// Generates strings for `Text`
+///
+/// PostgreSQL does not accept NUL characters in text values.
+pub fn text() -> impl Strategy<Value = String> + Clone {
+    "[^\\x00]*"
+}
+
+/// Generates dates for `Date`
+///
+/// The earliest date supported by PostgreSQL is 4714-11-24 BC.
+#[cfg(feature = "chrono")]
+pub fn date() -> impl Strategy<Value = chrono::NaiveDate> + Clone {
+    let earliest = chrono::NaiveDate::from_ymd_opt(-4713, 11, 24).expect("Date is valid");
+    super::date_between(earliest, chrono::NaiveDate::MAX)
+}
+
+/// Generates times for `Time` with microsecond precision
+#[cfg(feature = "chrono")]
+pub fn time() -> impl Strategy<Value = chrono::NaiveTime> + Clone {
+    super::time_with_precision(1_000)
+}
+
+/// Generates timestamps for `Timestamp` with microsecond precision
+#[cfg(feature = "chrono")]
+pub fn timestamp() -> impl Strategy<Value = chrono::NaiveDateTime> + Clone {
+    (date(), time()).prop_map(|(date, time)| date.and_time(time))
+}
+
+/// Generates timestamps for `Timestamptz` with microsecond precision
+#[cfg(feature = "chrono")]
+pub fn timestamptz() -> impl Strategy<Value = chrono::DateTime<chrono::Utc>> + Clone {
+    timestamp().prop_map(|t| t.and_utc())
+}
+
+/// Generates decimals for `Numeric`
+#[cfg(feature = "numeric")]
+pub fn numeric() -> impl Strategy<Value = bigdecimal::BigDecimal> + Clone {
+    super::bigdecimal()
+}
+
+/// Generates json values for `Json` and `Jsonb`
+#[cfg(feature = "serde_json")]
+pub fn json() -> impl Strategy<Value = serde_json::Value> + Clone {
+    super::json_value()
+}
@@ -0,0 +1,47 @@
+//! Strategies generating values representable by SQLite
+//!
+//! Types whose whole domain is supported by SQLite, like integers,
+//! strings, booleans or byte vectors can use [`any`](super::any).
+
+use super::Strategy;
+
+/// Generates strings for `Text`
+pub fn text() -> impl Strategy<Value = String> + Clone {
+    super::any::<String>()
+}
+
+/// Generates floats for `Float`
+///
+/// SQLite stores `NaN` as `NULL`.
+pub fn float() -> impl Strategy<Value = f32> + Clone {
+    use proptest::num::f32::*;
+
+    POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO | INFINITE
+}
+
+/// Generates doubles for `Double`
+///
+/// SQLite stores `NaN` as `NULL`.
+pub fn double() -> impl Strategy<Value = f64> + Clone {
+    use proptest::num::f64::*;
+
+    POSITIVE | NEGATIVE | NORMAL | SUBNORMAL | ZERO | INFINITE
+}
+
+/// Generates dates for `Date`
+#[cfg(feature = "chrono")]
+pub fn date() -> impl Strategy<Value = chrono::NaiveDate> + Clone {
+    super::date_between(chrono::NaiveDate::MIN, chrono::NaiveDate::MAX)
+}
+
+/// Generates times for `Time` with microsecond precision
+#[cfg(feature = "chrono")]
+pub fn time() -> impl Strategy<Value = chrono::NaiveTime> + Clone {
+    super::time_with_precision(1_000)
+}
+
+/// Generates timestamps for `Timestamp` with microsecond precision
+#[cfg(feature = "chrono")]
+pub fn timestamp() -> impl Strategy<Value = chrono::NaiveDateTime> + Clone {
+    (date(), time()).prop_map(|(date, time)| date.and_time(time))
+}
@@ -9,6 +9,11 @@ mod std {
     #[diesel(foreign_derive)]
     #[diesel(sql_type = crate::sql_types::Timestamp)]
     struct SystemTimeProxy(std::time::SystemTime);
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[diesel(sql_type = crate::sql_types::Interval)]
+    struct DurationProxy(core::time::Duration);
 }
 
 #[cfg(feature = "chrono")]
@@ -60,11 +65,11 @@ mod chrono {
 
 #[cfg(feature = "time")]
 mod time {
-    use time::{Date as NaiveDate, OffsetDateTime, PrimitiveDateTime, Time as NaiveTime};
+    use time::{Date as NaiveDate, Duration, OffsetDateTime, PrimitiveDateTime, Time as NaiveTime};
 
     use crate::deserialize::FromSqlRow;
     use crate::expression::AsExpression;
-    use crate::sql_types::{Date, Time, Timestamp};
+    use crate::sql_types::{Date, Interval, Time, Timestamp};
 
     #[derive(AsExpression, FromSqlRow)]
     #[diesel(foreign_derive)]
@@ -103,4 +108,9 @@ mod time {
     #[cfg_attr(feature = "__sqlite-shared", diesel(sql_type = crate::sql_types::TimestamptzSqlite))]
     #[cfg_attr(feature = "mysql_backend", diesel(sql_type = crate::sql_types::Datetime))]
     struct DateTimeProxy(OffsetDateTime);
+
+    #[derive(AsExpression, FromSqlRow)]
+    #[diesel(foreign_derive)]
+    #[diesel(sql_type = Interval)]
+    struct DurationProxy(Duration);
 }
@@ -138,6 +138,23 @@ macro_rules! tuple_impls {
                     )+
                     Ok(())
                 }
+
+                #[cfg(feature = "fixtures")]
+                fn column_names(names: &mut Vec<&'static str>) {
+                    $($T::column_names(names);)+
+                }
+            }
+
+            impl<$($T: CteColumnList,)+> CteColumnList for ($($T,)+) {
+                fn walk_ast<__DB: Backend>(&self, mut out: AstPass<'_, '_, __DB>) -> QueryResult<()> {
+                    $(
+                        if $idx != 0 {
+                            out.push_sql(", ");
+                        }
+                        self.$idx.walk_ast(out.reborrow())?;
+                    )+
+                    Ok(())
+                }
             }
 
             fake_variadic! {
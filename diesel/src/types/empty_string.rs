@@ -0,0 +1,113 @@
+use alloc::borrow::Cow;
+use alloc::string::String;
+
+use crate::backend::Backend;
+use crate::deserialize::{self, FromSql, FromSqlRow};
+use crate::sql_types::{Nullable, Text};
+
+/// Converts empty strings to `None` before they are bound to a query
+///
+/// This is used by the `Insertable` and `AsChangeset` derives for fields
+/// annotated with `#[diesel(treat_empty_string_as_null)]`
+pub trait EmptyStringAsNull {
+    /// The value that is bound to the query
+    type Output;
+
+    /// Returns `None` for empty strings and the value itself otherwise
+    fn empty_string_as_null(self) -> Self::Output;
+}
+
+macro_rules! empty_string_as_null {
+    ($($(#[$meta:meta])* impl$(<$($lt:lifetime),+>)? for $ty:ty;)*) => {$(
+        $(#[$meta])*
+        impl$(<$($lt),+>)? EmptyStringAsNull for $ty {
+            type Output = Option<Self>;
+
+            fn empty_string_as_null(self) -> Self::Output {
+                if self.is_empty() { None } else { Some(self) }
+            }
+        }
+    )*};
+}
+
+empty_string_as_null! {
+    impl for String;
+    impl<'a> for &'a str;
+    impl<'a> for &'a String;
+    impl<'a> for Cow<'a, str>;
+    impl<'a, 'b> for &'a Cow<'b, str>;
+}
+
+// borrowed `Insertable` implementations for structs
+// containing `&str` fields take a reference to that field
+impl<'a> EmptyStringAsNull for &&'a str {
+    type Output = Option<&'a str>;
+
+    fn empty_string_as_null(self) -> Self::Output {
+        (*self).empty_string_as_null()
+    }
+}
+
+impl<T> EmptyStringAsNull for Option<T>
+where
+    T: EmptyStringAsNull<Output = Option<T>>,
+{
+    type Output = Self;
+
+    fn empty_string_as_null(self) -> Self::Output {
+        self.and_then(T::empty_string_as_null)
+    }
+}
+
+impl<'a, T> EmptyStringAsNull for &'a Option<T>
+where
+    &'a T: EmptyStringAsNull<Output = Option<&'a T>>,
+{
+    type Output = Option<&'a T>;
+
+    fn empty_string_as_null(self) -> Self::Output {
+        self.as_ref()
+            .and_then(EmptyStringAsNull::empty_string_as_null)
+    }
+}
+
+/// Loads `NULL` values as empty strings
+///
+/// This is used by the `Queryable`, `QueryableByName` and `Selectable`
+/// derives for `String` fields annotated with
+/// `#[diesel(treat_empty_string_as_null)]`
+#[derive(Debug, Clone, PartialEq, Eq, FromSqlRow)]
+pub struct NullAsEmptyString(String);
+
+impl From<NullAsEmptyString> for String {
+    fn from(value: NullAsEmptyString) -> Self {
+        value.0
+    }
+}
+
+impl<DB> FromSql<Text, DB> for NullAsEmptyString
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        String::from_sql(bytes).map(Self)
+    }
+}
+
+impl<DB> FromSql<Nullable<Text>, DB> for NullAsEmptyString
+where
+    DB: Backend,
+    String: FromSql<Text, DB>,
+{
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        String::from_sql(bytes).map(Self)
+    }
+
+    fn from_nullable_sql(bytes: Option<DB::RawValue<'_>>) -> deserialize::Result<Self> {
+        match bytes {
+            Some(bytes) => <Self as FromSql<Text, DB>>::from_sql(bytes),
+            None => Ok(Self(String::new())),
+        }
+    }
+}
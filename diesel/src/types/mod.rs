@@ -1,4 +1,5 @@
 //! A module containing helper to work with Rust side types
+pub(crate) mod empty_string;
 pub(crate) mod enum_;
 
 #[doc(inline)]
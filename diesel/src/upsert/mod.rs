@@ -22,10 +22,103 @@ mod on_conflict_extension;
 pub use self::on_conflict_extension::{
     DecoratableTarget, IncompleteDoUpdate, IncompleteOnConflict,
 };
+#[cfg(feature = "mysql_backend")]
+pub use crate::mysql::query_builder::new_row::NewRow;
 #[cfg(feature = "postgres_backend")]
 pub use crate::pg::query_builder::on_constraint::*;
+#[cfg(feature = "postgres_backend")]
+pub use crate::pg::query_builder::was_inserted::WasInserted;
 
 /// Represents `excluded.column` in an `ON CONFLICT DO UPDATE` clause.
+///
+/// Use `new_row` for MySQL's `ON DUPLICATE KEY UPDATE` clause instead.
 pub fn excluded<T>(excluded: T) -> Excluded<T> {
     Excluded::new(excluded)
 }
+
+/// Represents `VALUES(column)` in MySQL's `ON DUPLICATE KEY UPDATE` clause,
+/// the counterpart of [`excluded`] for PostgreSQL and SQLite.
+///
+/// It refers to the value that would have been inserted into `column`
+/// if there had been no conflict. For multi-row inserts it refers to the
+/// value of the row that caused the conflict.
+///
+/// MySQL 8.0.20 deprecated this syntax in favor of row aliases
+/// (`INSERT … AS new … new.column`), which are not supported by MariaDB.
+/// Diesel generates `VALUES(column)` as it is accepted by both.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("on_conflict_docs_setup.rs");
+/// #
+/// # #[cfg(feature = "mysql")]
+/// # fn main() -> diesel::QueryResult<()> {
+/// #     use self::users::dsl::*;
+/// #     let conn = &mut establish_connection();
+/// #     diesel::sql_query("DELETE FROM users").execute(conn)?;
+/// use diesel::upsert::new_row;
+///
+/// diesel::insert_into(users)
+///     .values(&User { id: 1, name: "Sean" })
+///     .execute(conn)?;
+///
+/// diesel::insert_into(users)
+///     .values(&vec![User { id: 1, name: "Jim" }, User { id: 2, name: "Tess" }])
+///     .on_conflict(diesel::dsl::DuplicatedKeys)
+///     .do_update()
+///     .set(name.eq(new_row(name)))
+///     .execute(conn)?;
+///
+/// let users_in_db = users.order(id).load(conn);
+/// assert_eq!(
+///     Ok(vec![(1, "Jim".to_string()), (2, "Tess".to_string())]),
+///     users_in_db
+/// );
+/// # Ok(())
+/// # }
+/// # #[cfg(not(feature = "mysql"))]
+/// # fn main() {}
+/// ```
+#[cfg(feature = "mysql_backend")]
+pub fn new_row<T: crate::Column>(column: T) -> NewRow<T> {
+    NewRow::new(column)
+}
+
+/// Represents `"table".xmax = 0`, which allows to detect whether a row
+/// returned by an `ON CONFLICT DO UPDATE` statement was inserted or updated.
+///
+/// PostgreSQL sets the `xmax` system column of a row updated by an upsert,
+/// while it is `0` for newly inserted rows. This is an implementation
+/// detail of PostgreSQL, but widely used for this purpose.
+///
+/// # Example
+///
+/// ```rust
+/// # include!("../doctest_setup.rs");
+/// #
+/// # fn main() {
+/// #     run_test().unwrap();
+/// # }
+/// #
+/// # fn run_test() -> QueryResult<()> {
+/// #     use schema::users::dsl::*;
+/// #     let conn = &mut establish_connection();
+/// use diesel::upsert::{excluded, was_inserted};
+///
+/// let upserted = diesel::insert_into(users)
+///     .values(&vec![(id.eq(1), name.eq("Sean")), (id.eq(3), name.eq("Jim"))])
+///     .on_conflict(id)
+///     .do_update()
+///     .set(name.eq(excluded(name)))
+///     .returning((id, was_inserted(users)))
+///     .get_results::<(i32, bool)>(conn)?;
+///
+/// assert_eq!(vec![(1, false), (3, true)], upserted);
+/// #     Ok(())
+/// # }
+/// ```
+#[cfg(feature = "postgres_backend")]
+pub fn was_inserted<T: crate::Table>(table: T) -> WasInserted<T> {
+    WasInserted::new(table)
+}
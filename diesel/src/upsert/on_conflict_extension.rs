@@ -1,4 +1,4 @@
-use crate::expression::Expression;
+use crate::expression::{AppearsOnTable, Expression};
 use crate::query_builder::update_statement::SetAutoTypeHelper;
 use crate::query_builder::upsert::into_conflict_clause::IntoConflictValueClause;
 use crate::query_builder::upsert::on_conflict_actions::*;
@@ -541,7 +541,8 @@ impl<T: QuerySource, U, Op, Ret, Target>
     /// if the rows being inserted conflict with each other.
     ///
     /// For some backends (PostgreSQL, SQLite) a `WHERE` clause can be used to limit the rows actually updated.
-    /// For PostgreSQL and SQLite you can use the `.filter()` method to add conditions like that.
+    /// For PostgreSQL and SQLite you can use the [`.filter()`](InsertStatement::filter) method
+    /// to add conditions like that.
     ///
     /// # Examples
     ///
@@ -775,6 +776,49 @@ impl<T: QuerySource, U, Op, Ret, Target>
     /// # fn main() {}
     /// ```
     ///
+    /// ## Combine the existing and the rejected value
+    ///
+    /// Columns of the target table refer to the values of the existing row,
+    /// while `excluded` refers to the rejected values. Both can be combined in
+    /// arbitrary expressions. Together with `.returning()` this allows to get
+    /// the updated values without executing a separate query, for example for
+    /// counters.
+    ///
+    /// ```rust
+    /// # include!("on_conflict_docs_setup.rs");
+    /// #
+    /// # #[cfg(feature = "postgres")]
+    /// # fn main() -> diesel::QueryResult<()> {
+    /// #     use self::users::dsl::*;
+    /// use diesel::upsert::excluded;
+    ///
+    /// #     let conn = &mut establish_connection();
+    /// #     diesel::sql_query("TRUNCATE TABLE users").execute(conn).unwrap();
+    /// diesel::insert_into(users)
+    ///     .values(User {
+    ///         id: 1,
+    ///         name: "Pascal",
+    ///     })
+    ///     .execute(conn)?;
+    ///
+    /// let updated = diesel::insert_into(users)
+    ///     .values(User {
+    ///         id: 1,
+    ///         name: "Sean",
+    ///     })
+    ///     .on_conflict(id)
+    ///     .do_update()
+    ///     .set(name.eq(name.concat(" & ").concat(excluded(name))))
+    ///     .returning((id, name.concat("!")))
+    ///     .get_result::<(i32, String)>(conn)?;
+    ///
+    /// assert_eq!((1, "Pascal & Sean!".to_string()), updated);
+    /// # Ok(())
+    /// # }
+    /// # #[cfg(not(feature = "postgres"))]
+    /// # fn main() {}
+    /// ```
+    ///
     /// ## Use `.filter()`method to limit the rows actually updated
     ///
     /// ```rust
@@ -782,8 +826,6 @@ impl<T: QuerySource, U, Op, Ret, Target>
     /// #
     /// # #[cfg(not(feature = "mysql"))]
     /// # fn main() {
-    /// #     use diesel::QueryDsl;
-    /// #     use diesel::query_dsl::methods::FilterDsl;
     /// use self::users::dsl::*;
     /// #     let conn = &mut establish_connection();
     /// #     #[cfg(feature = "postgres")]
@@ -857,14 +899,100 @@ impl<T: QuerySource, U, Op, Ret, Target>
     }
 }
 
-impl<T, U, Op, Ret, Target, Action, WhereClause, Predicate> FilterDsl<Predicate>
-    for InsertStatement<T, OnConflictValues<U, Target, Action, WhereClause>, Op, Ret>
+impl<T, U, Op, Ret, Target, Changes, Tab, WhereClause>
+    InsertStatement<T, OnConflictValues<U, Target, DoUpdate<Changes, Tab>, WhereClause>, Op, Ret>
+where
+    T: QuerySource,
+{
+    /// Adds a `WHERE` clause to the `DO UPDATE` action of this upsert
+    ///
+    /// Conflicting rows are only updated if the predicate holds for them.
+    /// Rows for which it does not hold are left untouched and are neither
+    /// counted as affected nor returned by a `RETURNING` clause. The
+    /// values of the row that was about to be inserted can be referenced
+    /// via [`excluded`](crate::upsert::excluded), which allows to express
+    /// conditional upserts like "only update if the incoming row is newer"
+    /// by comparing a version or timestamp column.
+    ///
+    /// If there is already a `WHERE` clause, the predicate will be `AND`ed
+    /// with it. This method is only supported by PostgreSQL and SQLite.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # include!("on_conflict_docs_setup.rs");
+    /// #
+    /// # #[cfg(not(feature = "mysql"))]
+    /// # fn main() {
+    /// use self::users::dsl::*;
+    /// use diesel::upsert::excluded;
+    ///
+    /// #     let conn = &mut establish_connection();
+    /// #     #[cfg(feature = "postgres")]
+    /// #     diesel::sql_query("TRUNCATE TABLE users").execute(conn).unwrap();
+    /// #     #[cfg(feature = "__sqlite-shared")]
+    /// #     diesel::delete(users).execute(conn).unwrap();
+    /// diesel::insert_into(users)
+    ///     .values(&User { id: 1, name: "Sean" })
+    ///     .execute(conn)
+    ///     .unwrap();
+    ///
+    /// // only replace names which sort after the stored one
+    /// let upsert = |new_name| {
+    ///     diesel::insert_into(users)
+    ///         .values(User { id: 1, name: new_name })
+    ///         .on_conflict(id)
+    ///         .do_update()
+    ///         .set(name.eq(excluded(name)))
+    ///         .filter(excluded(name).gt(name))
+    /// };
+    ///
+    /// assert_eq!(Ok(0), upsert("Jim").execute(conn));
+    /// assert_eq!(Ok(1), upsert("Tess").execute(conn));
+    ///
+    /// let users_in_db = users.load(conn);
+    /// assert_eq!(Ok(vec![(1, "Tess".to_string())]), users_in_db);
+    /// # }
+    /// # #[cfg(feature = "mysql")]
+    /// # fn main() {}
+    /// ```
+    pub fn filter<Predicate>(self, predicate: Predicate) -> crate::dsl::Filter<Self, Predicate>
+    where
+        Self: FilterDsl<Predicate>,
+    {
+        FilterDsl::filter(self, predicate)
+    }
+
+    /// Adds a predicate to the `WHERE` clause of the `DO UPDATE` action
+    /// of this upsert, combined with the existing clause via `OR`
+    ///
+    /// See [`filter`](Self::filter) for details.
+    pub fn or_filter<Predicate>(self, predicate: Predicate) -> crate::dsl::OrFilter<Self, Predicate>
+    where
+        Self: OrFilterDsl<Predicate>,
+    {
+        OrFilterDsl::or_filter(self, predicate)
+    }
+}
+
+impl<T, U, Op, Ret, Target, Changes, Tab, WhereClause, Predicate> FilterDsl<Predicate>
+    for InsertStatement<
+        T,
+        OnConflictValues<U, Target, DoUpdate<Changes, Tab>, WhereClause>,
+        Op,
+        Ret,
+    >
 where
     T: QuerySource,
     WhereClause: WhereAnd<Predicate>,
+    Predicate: AppearsOnTable<T>,
 {
-    type Output =
-        InsertStatement<T, OnConflictValues<U, Target, Action, WhereClause::Output>, Op, Ret>;
+    type Output = InsertStatement<
+        T,
+        OnConflictValues<U, Target, DoUpdate<Changes, Tab>, WhereClause::Output>,
+        Op,
+        Ret,
+    >;
 
     fn filter(self, predicate: Predicate) -> Self::Output {
         self.replace_values(|values| {
@@ -873,14 +1001,24 @@ where
     }
 }
 
-impl<T, U, Op, Ret, Target, Action, WhereClause, Predicate> OrFilterDsl<Predicate>
-    for InsertStatement<T, OnConflictValues<U, Target, Action, WhereClause>, Op, Ret>
+impl<T, U, Op, Ret, Target, Changes, Tab, WhereClause, Predicate> OrFilterDsl<Predicate>
+    for InsertStatement<
+        T,
+        OnConflictValues<U, Target, DoUpdate<Changes, Tab>, WhereClause>,
+        Op,
+        Ret,
+    >
 where
     T: QuerySource,
     WhereClause: WhereOr<Predicate>,
+    Predicate: AppearsOnTable<T>,
 {
-    type Output =
-        InsertStatement<T, OnConflictValues<U, Target, Action, WhereClause::Output>, Op, Ret>;
+    type Output = InsertStatement<
+        T,
+        OnConflictValues<U, Target, DoUpdate<Changes, Tab>, WhereClause::Output>,
+        Op,
+        Ret,
+    >;
 
     fn or_filter(self, predicate: Predicate) -> Self::Output {
         self.replace_values(|values| {
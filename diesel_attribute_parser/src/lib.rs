@@ -70,6 +70,7 @@ pub enum FieldAttr {
     SqlType(Ident, TypePath),
     TreatNoneAsDefaultValue(Ident, LitBool),
     TreatNoneAsNull(Ident, LitBool),
+    TreatEmptyStringAsNull(Ident),
 
     SerializeAs(Ident, Type),
     DeserializeAs(Ident, Type),
@@ -193,6 +194,7 @@ impl Parse for FieldAttr {
                 name,
                 parse_eq(input, TREAT_NONE_AS_NULL_NOTE)?,
             )),
+            "treat_empty_string_as_null" => Ok(FieldAttr::TreatEmptyStringAsNull(name)),
             "serialize_as" => Ok(FieldAttr::SerializeAs(
                 name,
                 parse_eq_type(input, SERIALIZE_AS_NOTE)?,
@@ -219,6 +221,7 @@ impl Parse for FieldAttr {
                     "sql_type",
                     "treat_none_as_default_value",
                     "treat_none_as_null",
+                    "treat_empty_string_as_null",
                     "serialize_as",
                     "deserialize_as",
                     "select_expression",
@@ -236,6 +239,7 @@ impl MySpanned for FieldAttr {
             FieldAttr::Embed(ident)
             | FieldAttr::SkipInsertion(ident)
             | FieldAttr::SkipUpdate(ident)
+            | FieldAttr::TreatEmptyStringAsNull(ident)
             | FieldAttr::ColumnName(ident, _)
             | FieldAttr::SqlType(ident, _)
             | FieldAttr::TreatNoneAsNull(ident, _)
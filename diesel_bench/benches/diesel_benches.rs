@@ -205,6 +205,34 @@ pub fn bench_trivial_query(b: &mut Bencher, size: usize) {
     b.iter(|| users::table.load::<User>(&mut conn).unwrap())
 }
 
+pub fn bench_trivial_query_for_each_row(b: &mut Bencher, size: usize) {
+    #[cfg(feature = "postgres")]
+    type LoadingMode = diesel::pg::PgRowByRowLoadingMode;
+    #[cfg(not(feature = "postgres"))]
+    type LoadingMode = diesel::connection::DefaultLoadingMode;
+
+    let mut conn = connection();
+    match size {
+        1 => insert_users::<_, 1>(&mut conn, |_| None),
+        10 => insert_users::<_, 10>(&mut conn, |_| None),
+        100 => insert_users::<_, 100>(&mut conn, |_| None),
+        1_000 => insert_users::<_, 1_000>(&mut conn, |_| None),
+        10_000 => insert_users::<_, 10_000>(&mut conn, |_| None),
+        _ => unimplemented!(),
+    }
+
+    b.iter(|| {
+        let mut loaded = Vec::new();
+        users::table
+            .for_each_row::<User, LoadingMode>(&mut conn, |user| {
+                loaded.push(user);
+                Ok(())
+            })
+            .unwrap();
+        loaded
+    })
+}
+
 pub fn bench_trivial_query_boxed(b: &mut Bencher, size: usize) {
     let mut conn = connection();
 
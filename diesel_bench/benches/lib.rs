@@ -77,6 +77,12 @@ fn bench_trivial_query(c: &mut CriterionType) {
             crate::diesel_benches::bench_trivial_query_boxed(b, *i);
         });
 
+        group.bench_with_input(
+            BenchmarkId::new("diesel_for_each_row", size),
+            size,
+            |b, i| crate::diesel_benches::bench_trivial_query_for_each_row(b, *i),
+        );
+
         group.bench_with_input(
             BenchmarkId::new("diesel_queryable_by_name", size),
             size,
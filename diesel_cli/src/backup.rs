@@ -0,0 +1,201 @@
+//! Creates and restores backups of a database
+//!
+//! PostgreSQL and MySQL databases are backed up with the native tools of
+//! the database (`pg_dump`/`pg_restore` and `mysqldump`/`mysql`), which
+//! need to be installed and available in the `PATH`. SQLite databases are
+//! copied with `VACUUM INTO`, which writes a consistent snapshot even while
+//! other connections write to the database, and restored with SQLite's
+//! online backup API.
+
+use std::path::Path;
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+use std::process::Command;
+
+use crate::database::Backend;
+use crate::errors::Error;
+
+/// Writes a backup of the database specified by `database_url` to `out`
+pub fn backup_database(database_url: &str, out: &Path) -> Result<(), Error> {
+    match Backend::for_url(database_url)? {
+        #[cfg(feature = "postgres")]
+        Backend::Pg => {
+            let mut command = Command::new("pg_dump");
+            command
+                .arg("--format=custom")
+                .arg("--file")
+                .arg(out)
+                .arg("--dbname")
+                .arg(database_url);
+            run(command)?;
+        }
+        #[cfg(feature = "sqlite")]
+        Backend::Sqlite => {
+            // `VACUUM INTO` refuses to overwrite existing files
+            if out.exists() {
+                std::fs::remove_file(out)
+                    .map_err(|e| Error::IoError(e, Some(out.to_path_buf())))?;
+            }
+            let mut conn = establish_sqlite(database_url)?;
+            conn.vacuum_into(sqlite_path(out)?)?;
+        }
+        #[cfg(feature = "mysql")]
+        Backend::Mysql => {
+            let options = MysqlOptions::from_url(database_url)?;
+            let mut command = options.command("mysqldump");
+            command
+                .arg("--single-transaction")
+                .arg("--routines")
+                .arg("--triggers")
+                .arg("--result-file")
+                .arg(out)
+                .arg(&options.database);
+            run(command)?;
+        }
+    }
+    println!("Wrote backup to {}", out.display());
+    Ok(())
+}
+
+/// Restores the database specified by `database_url` from a backup
+/// written by [`backup_database`]
+///
+/// The database is created if it does not exist yet. Existing
+/// tables contained in the backup are replaced.
+pub fn restore_database(database_url: &str, input: &Path) -> Result<(), Error> {
+    if !input.exists() {
+        return Err(Error::IoError(
+            std::io::ErrorKind::NotFound.into(),
+            Some(input.to_path_buf()),
+        ));
+    }
+    match Backend::for_url(database_url)? {
+        #[cfg(feature = "postgres")]
+        Backend::Pg => {
+            crate::database::create_database_if_needed(database_url)?;
+            let mut command = Command::new("pg_restore");
+            command
+                .arg("--clean")
+                .arg("--if-exists")
+                .arg("--no-owner")
+                .arg("--single-transaction")
+                .arg("--dbname")
+                .arg(database_url)
+                .arg(input);
+            run(command)?;
+        }
+        #[cfg(feature = "sqlite")]
+        Backend::Sqlite => {
+            // the backup API replaces the content of the database
+            // safely, even if it's opened by other connections
+            let mut backup = establish_sqlite(sqlite_path(input)?)?;
+            backup.backup_to_file(database_url)?;
+        }
+        #[cfg(feature = "mysql")]
+        Backend::Mysql => {
+            crate::database::create_database_if_needed(database_url)?;
+            let options = MysqlOptions::from_url(database_url)?;
+            let mut command = options.command("mysql");
+            command.arg(&options.database);
+            let file =
+                std::fs::File::open(input).map_err(|e| Error::IoError(e, Some(input.into())))?;
+            command.stdin(file);
+            run(command)?;
+        }
+    }
+    println!("Restored backup from {}", input.display());
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+fn establish_sqlite(database_url: &str) -> Result<diesel::SqliteConnection, Error> {
+    use diesel::Connection;
+
+    diesel::SqliteConnection::establish(database_url).map_err(|error| Error::ConnectionError {
+        error,
+        url: database_url.to_owned(),
+    })
+}
+
+#[cfg(feature = "sqlite")]
+fn sqlite_path(path: &Path) -> Result<&str, Error> {
+    path.to_str().ok_or_else(|| {
+        Error::UnsupportedFeature(format!(
+            "The backup path `{}` is not valid UTF-8",
+            path.display()
+        ))
+    })
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+fn run(mut command: Command) -> Result<(), Error> {
+    let program = command.get_program().to_string_lossy().into_owned();
+    tracing::debug!(?command, "Run external command");
+    let status = command
+        .status()
+        .map_err(|e| Error::ExternalCommandError(program.clone(), e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(Error::ExternalCommandFailed(program, status))
+    }
+}
+
+/// The connection options of a MySQL url as understood by
+/// the `mysql` and `mysqldump` command line tools
+#[cfg(feature = "mysql")]
+struct MysqlOptions {
+    host: Option<String>,
+    port: Option<u16>,
+    user: Option<String>,
+    password: Option<String>,
+    database: String,
+}
+
+#[cfg(feature = "mysql")]
+impl MysqlOptions {
+    fn from_url(database_url: &str) -> Result<Self, Error> {
+        let url = url::Url::parse(database_url)?;
+        let decode = |s: &str| {
+            percent_encoding::percent_decode_str(s)
+                .decode_utf8_lossy()
+                .into_owned()
+        };
+        let database = url
+            .path_segments()
+            .and_then(|mut segments| segments.next())
+            .filter(|database| !database.is_empty())
+            .map(decode)
+            .ok_or_else(|| {
+                Error::UnsupportedFeature(format!(
+                    "The database url `{database_url}` does not contain a database name"
+                ))
+            })?;
+        Ok(Self {
+            host: url.host_str().map(decode),
+            port: url.port(),
+            user: Some(url.username())
+                .filter(|user| !user.is_empty())
+                .map(decode),
+            password: url.password().map(decode),
+            database,
+        })
+    }
+
+    fn command(&self, program: &str) -> Command {
+        let mut command = Command::new(program);
+        if let Some(ref host) = self.host {
+            command.arg("--host").arg(host);
+        }
+        if let Some(port) = self.port {
+            command.arg("--port").arg(port.to_string());
+        }
+        if let Some(ref user) = self.user {
+            command.arg("--user").arg(user);
+        }
+        // passing the password as argument would expose it to other users
+        if let Some(ref password) = self.password {
+            command.env("MYSQL_PWD", password);
+        }
+        command
+    }
+}
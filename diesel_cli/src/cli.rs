@@ -4,6 +4,7 @@ use std::path::PathBuf;
 use crate::database::DatabaseArgs;
 use crate::migrations::MigrationArgs;
 use crate::print_schema::PrintSchemaArgs;
+use crate::seed::SeedArgs;
 use clap::CommandFactory;
 use clap::{ArgAction, Parser, Subcommand};
 use clap_complete::{Shell, generate};
@@ -21,6 +22,13 @@ pub struct Cli {
     #[arg(long = "database-url", global = true)]
     pub database_url: Option<String>,
 
+    /// The backend used for the database URL, one of `postgres`, `mysql` or
+    /// `sqlite`. Falls back to the `DIESEL_BACKEND` environment variable if
+    /// unspecified. By default the backend is inferred from the scheme of the
+    /// database URL, while URLs without scheme are treated as SQLite databases.
+    #[arg(id = "BACKEND", long = "backend", global = true)]
+    pub backend: Option<String>,
+
     /// The location of the configuration file to use. Falls back to the
     /// `DIESEL_CONFIG_FILE` environment variable if unspecified. Defaults
     /// to `diesel.toml` in your project root. See
@@ -76,6 +84,9 @@ pub enum DieselCliCommand {
 
     /// Print table definitions for database schema.
     PrintSchema(PrintSchemaArgs),
+
+    /// A group of commands for seeding your database.
+    Seed(SeedArgs),
 }
 
 #[tracing::instrument]
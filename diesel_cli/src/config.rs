@@ -1,6 +1,6 @@
 use super::find_project_root;
 use crate::infer_schema_internals::TableName;
-use crate::print_schema::{self, ColumnSorting, DocConfig, PrintSchemaArgs};
+use crate::print_schema::{self, ColumnSorting, DocConfig, MysqlIntrospection, PrintSchemaArgs};
 use serde::de::{self, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer};
 use serde_regex::Serde as RegexWrapper;
@@ -17,6 +17,27 @@ pub struct Config {
     pub print_schema: RootPrintSchema,
     #[serde(default)]
     pub migrations_directory: Option<MigrationsDirectory>,
+    #[serde(default)]
+    pub analyze_after_migrations: bool,
+}
+
+const WORKSPACE_CONFIG_FILE: &str = "diesel.workspace.toml";
+
+/// Merges `member` into `workspace`, values of `member` take precedence
+///
+/// Tables are merged key by key, all other values (including arrays)
+/// replace the inherited value.
+fn merge_config_tables(workspace: &mut toml::Table, member: toml::Table) {
+    for (key, value) in member {
+        match (workspace.get_mut(&key), value) {
+            (Some(toml::Value::Table(inherited)), toml::Value::Table(value)) => {
+                merge_config_tables(inherited, value);
+            }
+            (_, value) => {
+                workspace.insert(key, value);
+            }
+        }
+    }
 }
 
 fn get_values_with_indices<'a, T: Clone + Send + Sync + 'static>(
@@ -35,21 +56,55 @@ impl Config {
 
     pub fn read(config_file: Option<std::path::PathBuf>) -> Result<Self, crate::errors::Error> {
         let path = Self::file_path(config_file);
-
-        if path.exists() {
-            let content = fs::read_to_string(&path)
-                .map_err(|e| crate::errors::Error::IoError(e, Some(path.to_owned())))?;
-            let mut result = toml::from_str::<Self>(&content)?;
+        let workspace_config = Self::read_workspace_config(&path)?;
+
+        if path.exists() || workspace_config.is_some() {
+            let content = if path.exists() {
+                fs::read_to_string(&path)
+                    .map_err(|e| crate::errors::Error::IoError(e, Some(path.to_owned())))?
+            } else {
+                String::new()
+            };
+            let mut result = match workspace_config {
+                Some(mut workspace_config) => {
+                    merge_config_tables(&mut workspace_config, toml::from_str(&content)?);
+                    workspace_config.try_into::<Self>()?
+                }
+                None => toml::from_str::<Self>(&content)?,
+            };
             result.set_relative_path_base(
                 path.parent()
                     .expect("This is not executed in the file-system root, right?"),
             );
+            result.print_schema.apply_crate_outputs()?;
             Ok(result)
         } else {
             Ok(Self::default())
         }
     }
 
+    /// Reads the closest `diesel.workspace.toml` found in the directory of
+    /// the given config file or any of its parent directories
+    ///
+    /// Settings of this file are inherited by the config file and can be
+    /// overridden there. Relative paths are resolved relative to the
+    /// inheriting config file.
+    fn read_workspace_config(path: &Path) -> Result<Option<toml::Table>, crate::errors::Error> {
+        let path = std::path::absolute(path)
+            .map_err(|e| crate::errors::Error::IoError(e, Some(path.to_owned())))?;
+        let Some(workspace_path) = path
+            .ancestors()
+            .skip(1)
+            .map(|dir| dir.join(WORKSPACE_CONFIG_FILE))
+            .find(|workspace_path| workspace_path.is_file())
+        else {
+            return Ok(None);
+        };
+        let content = fs::read_to_string(&workspace_path)
+            .map_err(|e| crate::errors::Error::IoError(e, Some(workspace_path)))?;
+        Ok(Some(toml::from_str(&content)?))
+    }
+
     fn set_relative_path_base(&mut self, base: &Path) {
         self.print_schema.set_relative_path_base(base);
         if let Some(ref mut migration) = self.migrations_directory {
@@ -167,6 +222,14 @@ impl Config {
                         args.allow_tables_appear_in_same_query_indices.as_deref(),
                         &args.inner.allow_tables_to_appear_in_same_query_config,
                     );
+                let allow_columns_to_appear_in_same_group_by_clause_config_with_indices =
+                    get_values_with_indices(
+                        args.allow_columns_appear_in_same_group_by_indices
+                            .as_deref(),
+                        &args
+                            .inner
+                            .allow_columns_to_appear_in_same_group_by_clause_config,
+                    );
                 let patch_file_with_indices = get_values_with_indices(
                     args.patch_file_indices.as_deref(),
                     &args.inner.patch_file,
@@ -270,6 +333,15 @@ impl Config {
                             **allow_tables_to_appear_in_same_query_config;
                     }
 
+                    if let Some(allow_columns_to_appear_in_same_group_by_clause_config) =
+                        allow_columns_to_appear_in_same_group_by_clause_config_with_indices
+                            .as_ref()
+                            .and_then(|v| v.range(boundary).nth(0).map(|v| v.1))
+                    {
+                        print_schema.allow_columns_to_appear_in_same_group_by_clause_config =
+                            Some(**allow_columns_to_appear_in_same_group_by_clause_config);
+                    }
+
                     if let Some(sorting) = column_sorting_with_indices
                         .as_ref()
                         .and_then(|v| v.range(boundary).nth(0).map(|v| v.1))
@@ -298,6 +370,24 @@ impl Config {
                     if args.inner.no_generate_rust_enum_types {
                         print_schema.generate_rust_enum_definitions = Some(false);
                     }
+                    if args.inner.generate_id_types {
+                        print_schema.generate_id_types = true;
+                    }
+                    if let Some(mysql_introspection) = args.inner.mysql_introspection {
+                        print_schema.mysql_introspection = mysql_introspection;
+                    }
+                    if let Some(connections) = args.inner.mysql_introspection_connections {
+                        print_schema.mysql_introspection_connections = Some(connections);
+                    }
+                    if let Some(jobs) = args.inner.introspection_jobs {
+                        print_schema.introspection_jobs = Some(jobs);
+                    }
+                    if let Some(ref visibility) = args.inner.schema_visibility {
+                        print_schema.schema_visibility = Some(visibility.clone());
+                    }
+                    if args.inner.no_cache {
+                        print_schema.introspection_cache = false;
+                    }
 
                     if let Some(excepts) = &except_custom_type_definitions_with_indices {
                         let rules = excepts
@@ -378,6 +468,14 @@ impl Config {
                     allow_tables_to_appear_in_same_query_config.to_owned();
             }
 
+            if let Some(allow_columns_to_appear_in_same_group_by_clause_config) = args
+                .allow_columns_to_appear_in_same_group_by_clause_config
+                .first()
+            {
+                config.allow_columns_to_appear_in_same_group_by_clause_config =
+                    Some(allow_columns_to_appear_in_same_group_by_clause_config.to_owned());
+            }
+
             if let Some(sorting) = args.column_sorting.first() {
                 config.column_sorting = sorting.to_owned();
             }
@@ -404,6 +502,24 @@ impl Config {
             if args.no_generate_rust_enum_types {
                 config.generate_rust_enum_definitions = Some(false);
             }
+            if args.generate_id_types {
+                config.generate_id_types = true;
+            }
+            if let Some(mysql_introspection) = args.mysql_introspection {
+                config.mysql_introspection = mysql_introspection;
+            }
+            if let Some(connections) = args.mysql_introspection_connections {
+                config.mysql_introspection_connections = Some(connections);
+            }
+            if let Some(jobs) = args.introspection_jobs {
+                config.introspection_jobs = Some(jobs);
+            }
+            if let Some(visibility) = args.schema_visibility {
+                config.schema_visibility = Some(visibility);
+            }
+            if args.no_cache {
+                config.introspection_cache = false;
+            }
 
             if !args.custom_type_derives.is_empty() {
                 config.custom_type_derives = Some(args.custom_type_derives.into_iter().collect());
@@ -435,6 +551,8 @@ impl Config {
 pub struct RootPrintSchema {
     has_multiple_schema: bool,
     pub all_configs: BTreeMap<String, PrintSchema>,
+    /// The crate directories the schemas of the given schema keys are written to
+    crate_outputs: BTreeMap<String, PathBuf>,
 }
 
 impl<'de> Deserialize<'de> for RootPrintSchema {
@@ -444,12 +562,15 @@ impl<'de> Deserialize<'de> for RootPrintSchema {
     {
         #[derive(Deserialize)]
         struct Inner {
+            #[serde(default)]
+            crate_outputs: BTreeMap<String, PathBuf>,
             #[serde(flatten)]
             default_config: PrintSchema,
             #[serde(flatten)]
             other_configs: BTreeMap<String, PrintSchema>,
         }
         let Inner {
+            crate_outputs,
             other_configs,
             default_config,
         } = Inner::deserialize(deserializer)?;
@@ -457,6 +578,7 @@ impl<'de> Deserialize<'de> for RootPrintSchema {
             Ok(RootPrintSchema {
                 has_multiple_schema: false,
                 all_configs: BTreeMap::from([("default".into(), default_config)]),
+                crate_outputs,
             })
         } else {
             let mut other_configs = other_configs;
@@ -466,6 +588,7 @@ impl<'de> Deserialize<'de> for RootPrintSchema {
             Ok(RootPrintSchema {
                 all_configs: other_configs,
                 has_multiple_schema: true,
+                crate_outputs,
             })
         }
     }
@@ -480,6 +603,40 @@ impl RootPrintSchema {
         for config in self.all_configs.values_mut() {
             config.set_relative_path_base(base);
         }
+        for crate_dir in self.crate_outputs.values_mut() {
+            if crate_dir.is_relative() {
+                *crate_dir = base.join(&crate_dir);
+            }
+        }
+    }
+
+    /// Points the schema file of each key listed in `crate_outputs` to
+    /// `src/schema.rs` of the given crate and records the crate name, so
+    /// that tables owned by other crates can be referenced
+    fn apply_crate_outputs(&mut self) -> Result<(), crate::errors::Error> {
+        for (key, crate_dir) in &self.crate_outputs {
+            let config = self
+                .all_configs
+                .get_mut(key)
+                .ok_or_else(|| crate::errors::Error::NoSchemaKeyFound(key.clone()))?;
+            if config.file.is_some() {
+                return Err(crate::errors::Error::CrateOutputConflictsWithFile(
+                    key.clone(),
+                ));
+            }
+            let manifest_path = crate_dir.join("Cargo.toml");
+            let manifest = fs::read_to_string(&manifest_path)
+                .map_err(|e| crate::errors::Error::IoError(e, Some(manifest_path.clone())))?;
+            let manifest = toml::from_str::<toml::Table>(&manifest)?;
+            let crate_name = manifest
+                .get("package")
+                .and_then(|package| package.get("name"))
+                .and_then(|name| name.as_str())
+                .ok_or_else(|| crate::errors::Error::NoCrateName(manifest_path.clone()))?;
+            config.file = Some(crate_dir.join("src").join("schema.rs"));
+            config.crate_name = Some(crate_name.replace('-', "_"));
+        }
+        Ok(())
     }
 }
 
@@ -494,6 +651,9 @@ pub struct PrintSchema {
     pub allow_tables_to_appear_in_same_query_config:
         print_schema::AllowTablesToAppearInSameQueryConfig,
     #[serde(default)]
+    pub allow_columns_to_appear_in_same_group_by_clause_config:
+        Option<print_schema::AllowTablesToAppearInSameQueryConfig>,
+    #[serde(default)]
     pub filter: Filtering,
     #[serde(default)]
     pub column_sorting: ColumnSorting,
@@ -521,6 +681,28 @@ pub struct PrintSchema {
     pub custom_enum_derives: Option<BTreeSet<String>>,
     #[serde(default)]
     pub generate_rust_enum_definitions: Option<bool>,
+    #[serde(default)]
+    pub mysql_introspection: MysqlIntrospection,
+    #[serde(default)]
+    pub mysql_introspection_connections: Option<usize>,
+    #[serde(default)]
+    pub introspection_jobs: Option<usize>,
+    #[serde(default)]
+    pub introspection_cache: bool,
+    #[serde(default)]
+    pub removed_tables_module: bool,
+    #[serde(default)]
+    pub generate_id_types: bool,
+    #[serde(default)]
+    pub schema_visibility: Option<String>,
+    #[serde(default)]
+    pub module: Option<String>,
+    #[serde(default)]
+    pub header: Option<String>,
+    /// The name of the crate the schema is written to, set via
+    /// `print_schema.crate_outputs`
+    #[serde(skip)]
+    pub crate_name: Option<String>,
 }
 
 impl PrintSchema {
@@ -528,6 +710,44 @@ impl PrintSchema {
         self.generate_missing_sql_type_definitions.unwrap_or(true)
     }
 
+    /// The number of connections used to load the schema of individual tables
+    pub fn introspection_jobs(&self) -> usize {
+        self.introspection_jobs.unwrap_or(1)
+    }
+
+    /// The number of connections used by `mysql_introspection = "show_commands"`
+    #[cfg(feature = "mysql")]
+    pub fn mysql_introspection_connections(&self, default: usize) -> usize {
+        self.mysql_introspection_connections
+            .or(self.introspection_jobs)
+            .unwrap_or(default)
+    }
+
+    /// The visibility of the generated modules, `None` if the
+    /// default `pub` visibility is used
+    pub fn schema_visibility(&self) -> Result<Option<&str>, crate::errors::Error> {
+        let Some(visibility) = self.schema_visibility.as_deref() else {
+            return Ok(None);
+        };
+        match syn::parse_str::<syn::Visibility>(visibility) {
+            Ok(syn::Visibility::Inherited) | Err(_) => Err(
+                crate::errors::Error::InvalidSchemaVisibility(visibility.to_owned()),
+            ),
+            Ok(_) => Ok(Some(visibility)),
+        }
+    }
+
+    /// The name of the module wrapping the generated schema, if any
+    pub fn module_name(&self) -> Result<Option<&str>, crate::errors::Error> {
+        let Some(module) = self.module.as_deref() else {
+            return Ok(None);
+        };
+        if syn::parse_str::<syn::Ident>(module).is_err() {
+            return Err(crate::errors::Error::InvalidSchemaModule(module.to_owned()));
+        }
+        Ok(Some(module))
+    }
+
     pub fn generate_rust_enum_definitions(&self) -> bool {
         self.generate_rust_enum_definitions.unwrap_or(true)
     }
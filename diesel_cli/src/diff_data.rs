@@ -0,0 +1,265 @@
+//! Compares the content of a table between two databases
+//!
+//! Rows are compared in chunks of consecutive primary keys. For each chunk
+//! the row count and a checksum over all column values is computed on both
+//! sides, so only the primary key ranges that differ need to be inspected
+//! afterwards.
+
+use std::hash::{DefaultHasher, Hash, Hasher};
+
+use diesel::sql_types::{BigInt, Text};
+use diesel::{QueryResult, QueryableByName};
+
+use crate::database::{Backend, InferConnection};
+use crate::infer_schema_internals::{
+    SupportedQueryRelationStructures, TableName, get_column_information, get_primary_keys,
+};
+use crate::print_schema::ColumnSorting;
+
+#[derive(QueryableByName)]
+struct DiffRow {
+    #[diesel(sql_type = BigInt)]
+    pk: i64,
+    #[diesel(sql_type = Text)]
+    row_data: String,
+}
+
+struct TableQuery {
+    select: String,
+    primary_key: String,
+}
+
+impl TableQuery {
+    fn new(backend: Backend, table: &TableName, primary_key: &str, columns: &[String]) -> Self {
+        let quote = |identifier: &str| match backend {
+            #[cfg(feature = "mysql")]
+            Backend::Mysql => format!("`{}`", identifier.replace('`', "``")),
+            #[cfg(any(feature = "postgres", feature = "sqlite"))]
+            _ => format!("\"{}\"", identifier.replace('"', "\"\"")),
+        };
+        let table_name = match table.schema {
+            Some(ref schema) => format!("{}.{}", quote(schema), quote(&table.sql_name)),
+            None => quote(&table.sql_name),
+        };
+        let primary_key = quote(primary_key);
+        let columns = columns.iter().map(|c| quote(c)).collect::<Vec<_>>();
+        let (pk, row_data) = match backend {
+            #[cfg(feature = "postgres")]
+            Backend::Pg => (
+                format!("CAST({primary_key} AS BIGINT)"),
+                format!("CAST(ROW({}) AS TEXT)", columns.join(", ")),
+            ),
+            #[cfg(feature = "sqlite")]
+            Backend::Sqlite => (
+                format!("CAST({primary_key} AS INTEGER)"),
+                columns
+                    .iter()
+                    .map(|c| format!("quote({c})"))
+                    .collect::<Vec<_>>()
+                    .join(" || ',' || "),
+            ),
+            #[cfg(feature = "mysql")]
+            Backend::Mysql => (
+                format!("CAST({primary_key} AS SIGNED)"),
+                format!(
+                    "CONCAT_WS(',', {})",
+                    columns
+                        .iter()
+                        .map(|c| format!("QUOTE({c})"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            ),
+        };
+        Self {
+            select: format!("SELECT {pk} AS pk, {row_data} AS row_data FROM {table_name}"),
+            primary_key,
+        }
+    }
+
+    /// Rows with a primary key greater than `after`, limited to `limit` rows
+    fn next_rows(&self, after: Option<i64>, limit: usize) -> String {
+        format!(
+            "{}{} ORDER BY {} LIMIT {limit}",
+            self.select,
+            self.after(after),
+            self.primary_key,
+        )
+    }
+
+    /// Rows with a primary key in the range `(after, up_to]`
+    fn rows_in_range(&self, after: Option<i64>, up_to: i64) -> String {
+        let condition = match after {
+            Some(_) => format!(" AND {} <= {up_to}", self.primary_key),
+            None => format!(" WHERE {} <= {up_to}", self.primary_key),
+        };
+        format!(
+            "{}{}{condition} ORDER BY {}",
+            self.select,
+            self.after(after),
+            self.primary_key,
+        )
+    }
+
+    fn after(&self, after: Option<i64>) -> String {
+        after
+            .map(|after| format!(" WHERE {} > {after}", self.primary_key))
+            .unwrap_or_default()
+    }
+}
+
+fn load_rows(conn: &mut InferConnection, query: String) -> QueryResult<Vec<DiffRow>> {
+    match conn {
+        #[cfg(feature = "postgres")]
+        InferConnection::Pg(c) => diesel::RunQueryDsl::load(diesel::sql_query(query), c),
+        #[cfg(feature = "sqlite")]
+        InferConnection::Sqlite(c) => diesel::RunQueryDsl::load(diesel::sql_query(query), c),
+        #[cfg(feature = "mysql")]
+        InferConnection::Mysql(c) => diesel::RunQueryDsl::load(diesel::sql_query(query), c),
+    }
+}
+
+fn checksum(rows: &[DiffRow]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for row in rows {
+        row.pk.hash(&mut hasher);
+        row.row_data.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+fn describe_range(primary_key: &str, after: Option<i64>, up_to: Option<i64>) -> String {
+    match (after, up_to) {
+        (Some(after), Some(up_to)) => format!("{primary_key} in ({after}, {up_to}]"),
+        (None, Some(up_to)) => format!("{primary_key} <= {up_to}"),
+        (Some(after), None) => format!("{primary_key} > {after}"),
+        (None, None) => "all rows".to_owned(),
+    }
+}
+
+/// Returns the name of the single integer primary key column together with
+/// the names of all columns of the table
+fn table_layout(
+    conn: &mut InferConnection,
+    table: &TableName,
+) -> Result<(String, Vec<String>), crate::errors::Error> {
+    let columns = get_column_information(
+        conn,
+        table,
+        &ColumnSorting::OrdinalPosition,
+        &[],
+        SupportedQueryRelationStructures::Table,
+    )?;
+    let primary_keys = get_primary_keys(conn, table)?;
+    let primary_key = match &*primary_keys {
+        [primary_key] => columns
+            .iter()
+            .find(|c| c.column_name == *primary_key)
+            .filter(|c| c.type_name.to_lowercase().contains("int"))
+            .map(|c| c.column_name.clone()),
+        _ => None,
+    };
+    let primary_key = primary_key.ok_or_else(|| {
+        crate::errors::Error::UnsupportedFeature(format!(
+            "`diesel database diff-data` requires a single integer primary key, \
+             but table `{table}` has the primary key ({})",
+            primary_keys.join(", ")
+        ))
+    })?;
+    Ok((
+        primary_key,
+        columns.into_iter().map(|c| c.column_name).collect(),
+    ))
+}
+
+/// Compares the rows of `table` in both databases and prints all
+/// primary key ranges whose row count or checksum differs
+pub fn diff_table_data(
+    source: &mut InferConnection,
+    target: &mut InferConnection,
+    table: &TableName,
+    chunk_size: usize,
+) -> Result<(), crate::errors::Error> {
+    let backend = Backend::for_connection(source);
+    if Backend::for_connection(target) != backend {
+        return Err(crate::errors::Error::UnsupportedFeature(
+            "`diesel database diff-data` requires both databases to use the same backend"
+                .to_owned(),
+        ));
+    }
+    let chunk_size = chunk_size.max(1);
+
+    let (primary_key, columns) = table_layout(source, table)?;
+    let (target_primary_key, target_columns) = table_layout(target, table)?;
+    if primary_key != target_primary_key || columns != target_columns {
+        return Err(crate::errors::Error::TableColumnsDiffer(table.clone()));
+    }
+    let query = TableQuery::new(backend, table, &primary_key, &columns);
+
+    println!("Comparing table {table} in chunks of {chunk_size} rows");
+    let mut source_rows = 0;
+    let mut target_rows = 0;
+    let mut chunks = 0;
+    let mut divergent_chunks = 0;
+    let mut last_key = None;
+    loop {
+        let source_chunk = load_rows(source, query.next_rows(last_key, chunk_size))?;
+        let Some(up_to) = source_chunk.last().map(|row| row.pk) else {
+            break;
+        };
+        let target_chunk = load_rows(target, query.rows_in_range(last_key, up_to))?;
+        chunks += 1;
+        source_rows += source_chunk.len();
+        target_rows += target_chunk.len();
+        if source_chunk.len() != target_chunk.len() {
+            divergent_chunks += 1;
+            println!(
+                "{}: {} rows in source, {} rows in target",
+                describe_range(&primary_key, last_key, Some(up_to)),
+                source_chunk.len(),
+                target_chunk.len(),
+            );
+        } else if checksum(&source_chunk) != checksum(&target_chunk) {
+            divergent_chunks += 1;
+            println!(
+                "{}: checksums differ",
+                describe_range(&primary_key, last_key, Some(up_to)),
+            );
+        }
+        last_key = Some(up_to);
+    }
+
+    // rows that only exist in the target database
+    let remaining_start = last_key;
+    let mut remaining = 0;
+    loop {
+        let target_chunk = load_rows(target, query.next_rows(last_key, chunk_size))?;
+        let Some(up_to) = target_chunk.last().map(|row| row.pk) else {
+            break;
+        };
+        remaining += target_chunk.len();
+        last_key = Some(up_to);
+    }
+    if remaining > 0 {
+        chunks += 1;
+        divergent_chunks += 1;
+        target_rows += remaining;
+        println!(
+            "{}: 0 rows in source, {remaining} rows in target",
+            describe_range(&primary_key, remaining_start, None),
+        );
+    }
+
+    println!(
+        "{source_rows} rows in source, {target_rows} rows in target, \
+         {divergent_chunks} of {chunks} chunks differ"
+    );
+    if divergent_chunks > 0 {
+        Err(crate::errors::Error::TableDataDiffers(
+            table.clone(),
+            divergent_chunks,
+        ))
+    } else {
+        Ok(())
+    }
+}
@@ -22,6 +22,31 @@ pub enum Error {
         "The --database-url argument must be passed, or the DATABASE_URL environment variable must be set."
     )]
     DatabaseUrlMissing,
+    #[error("Database url `{url}` requires the `{feature}` feature but it's not enabled.")]
+    #[cfg(not(all(feature = "postgres", feature = "mysql", feature = "sqlite")))]
+    UrlRequiresFeature { url: String, feature: &'static str },
+    #[error(
+        "`{url}` is not a valid database URL. It should start with {available_schemes}, \
+         or maybe you meant to use the `sqlite` feature which is not enabled."
+    )]
+    #[cfg(not(feature = "sqlite"))]
+    AmbiguousDatabaseUrl {
+        url: String,
+        available_schemes: String,
+    },
+    #[error(
+        "Cannot determine the backend for the database url `{0}`. Use a url starting with \
+         `postgres://`, `mysql://` or `sqlite://`, or select the backend with `--backend` or \
+         the `DIESEL_BACKEND` environment variable."
+    )]
+    UnknownDatabaseUrlScheme(String),
+    #[error("`{0}` is not a valid backend. Expected one of `postgres`, `mysql` or `sqlite`.")]
+    InvalidBackend(String),
+    #[error("The `{0}` backend requires the `{0}` feature but it's not enabled.")]
+    #[cfg(not(all(feature = "postgres", feature = "mysql", feature = "sqlite")))]
+    BackendNotEnabled(&'static str),
+    #[error("The database url `{url}` cannot be used with the selected `{backend}` backend.")]
+    BackendConflictsWithUrl { backend: &'static str, url: String },
     #[error("Encountered an IO error: {0} for `{n}`", n=print_optional_path(.1))]
     IoError(#[source] std::io::Error, Option<PathBuf>),
     #[error("Failed to execute a database query: {0}")]
@@ -50,6 +75,8 @@ pub enum Error {
          Rerun the command locally, and commit the changes."
     )]
     SchemaWouldChange(String),
+    #[error("`print-schema --diff` requires `print_schema.file` to be set in your diesel.toml")]
+    NoSchemaFileConfigured,
     #[error("Failed to parse config file: {0}")]
     InvalidConfig(#[from] toml::de::Error),
     #[error("Failed to format a string: {0}")]
@@ -66,6 +93,14 @@ pub enum Error {
     ClapMatchesError(#[from] clap::parser::MatchesError),
     #[error("No `[print_schema.{0}]` entries in your diesel.toml")]
     NoSchemaKeyFound(String),
+    #[error("`print_schema.crate_outputs` and `print_schema.{0}.file` cannot be used together")]
+    CrateOutputConflictsWithFile(String),
+    #[error("No `package.name` found in `{n}`", n=print_path(.0))]
+    NoCrateName(PathBuf),
+    #[error("`{0}` is not a valid visibility for `print_schema.schema_visibility`")]
+    InvalidSchemaVisibility(String),
+    #[error("`{0}` is not a valid module name for `print_schema.module`")]
+    InvalidSchemaModule(String),
     #[error("Failed To Run rustfmt")]
     RustFmtFail(String),
     #[error("Failed to acquire migration folder lock: {1} for `{n}`", n=print_path(.0))]
@@ -74,6 +109,8 @@ pub enum Error {
     TooManyMigrations(PathBuf, String),
     #[error("Specified migration version `{1}` already exists inside `{n}`", n=print_path(.0))]
     DuplicateMigrationVersion(PathBuf, String),
+    #[error("Cannot renumber the migrations inside `{n}`: the applied migration version `{1}` sorts after the current timestamp", n=print_path(.0))]
+    AppliedMigrationVersionTooRecent(PathBuf, String),
     #[error("Could not resolved view: Failed to resolve relation `{n}`", n=print_relation(.0))]
     CouldNotResolveView(TableName),
     #[error("Invalid field used in view definition: `{n}`, field `{f}`", n = print_relation(.0), f=.1)]
@@ -82,6 +119,20 @@ pub enum Error {
     CyclicViewDefinition(TableName),
     #[error("Error inferring view definitions: {0}")]
     InferError(diesel_infer_query::Error),
+    #[error("Failed to serialize the introspection cache: {0}")]
+    IntrospectionCacheError(#[from] serde_json::Error),
+    #[error("Table `{n}` has different columns in both databases", n=print_relation(.0))]
+    TableColumnsDiffer(TableName),
+    #[error("Table `{n}` differs between both databases in {1} chunk(s)", n=print_relation(.0))]
+    TableDataDiffers(TableName, usize),
+    #[error("`{n}` already exists", n=print_path(.0))]
+    SeedFileExists(PathBuf),
+    #[cfg(any(feature = "postgres", feature = "mysql"))]
+    #[error("Failed to run `{0}`: {1}")]
+    ExternalCommandError(String, #[source] std::io::Error),
+    #[cfg(any(feature = "postgres", feature = "mysql"))]
+    #[error("`{0}` failed with {1}")]
+    ExternalCommandFailed(String, std::process::ExitStatus),
 }
 
 fn print_path(path: &Path) -> String {
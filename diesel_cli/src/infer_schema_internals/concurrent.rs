@@ -0,0 +1,66 @@
+//! Helpers to load the schema of several tables concurrently
+//!
+//! Introspection queries are executed table by table. For databases with
+//! a high latency this quickly dominates the runtime of `print-schema`, so
+//! tables are distributed over several connections and loaded in parallel.
+
+use std::thread;
+
+/// Loads `items` by distributing them over up to `jobs` connections
+///
+/// The first chunk is loaded using the given connection, all other chunks
+/// use a new connection established via `establish`. Additional connections
+/// can only be opened if a database url is known, otherwise everything is
+/// loaded using the given connection.
+///
+/// The results are returned in the same order as `items`.
+pub(super) fn load_concurrently<C, I, T, E, L>(
+    connection: &mut C,
+    database_url: Option<&str>,
+    jobs: usize,
+    items: &[I],
+    establish: E,
+    load: L,
+) -> Result<Vec<T>, crate::errors::Error>
+where
+    I: Sync,
+    T: Send,
+    E: Fn(&str) -> Result<C, crate::errors::Error> + Sync,
+    L: Fn(&mut C, &I) -> Result<T, crate::errors::Error> + Sync,
+{
+    let jobs = match database_url {
+        Some(_) => jobs.max(1),
+        None => 1,
+    };
+    let chunk_size = items.len().div_ceil(jobs).max(1);
+    let mut chunks = items.chunks(chunk_size);
+    let first_chunk = chunks.next().unwrap_or_default();
+
+    thread::scope(|s| {
+        let workers = chunks
+            .map(|chunk| {
+                let url = database_url.expect("Only a single chunk exists without a database url");
+                let (establish, load) = (&establish, &load);
+                s.spawn(move || {
+                    let mut connection = establish(url)?;
+                    chunk
+                        .iter()
+                        .map(|item| load(&mut connection, item))
+                        .collect::<Result<Vec<_>, _>>()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut loaded = first_chunk
+            .iter()
+            .map(|item| load(connection, item))
+            .collect::<Result<Vec<_>, _>>()?;
+        for worker in workers {
+            let items = worker
+                .join()
+                .unwrap_or_else(|e| std::panic::resume_unwind(e))?;
+            loaded.extend(items);
+        }
+        Ok(loaded)
+    })
+}
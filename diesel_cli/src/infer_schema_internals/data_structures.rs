@@ -15,9 +15,12 @@ pub struct ColumnInformation {
     pub nullable: bool,
     pub max_length: Option<u64>,
     pub comment: Option<String>,
+    pub generated_always_as: Option<String>,
+    /// Whether this is a `GENERATED ALWAYS AS IDENTITY` column
+    pub generated_as_identity: bool,
 }
 
-#[derive(Debug, PartialEq, Clone, Eq)]
+#[derive(Debug, PartialEq, Clone, Eq, serde::Serialize, serde::Deserialize)]
 pub struct ColumnType {
     pub schema: Option<String>,
     pub rust_name: String,
@@ -164,12 +167,16 @@ impl fmt::Display for ColumnType {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ColumnDefinition {
     pub sql_name: String,
     pub rust_name: String,
     pub ty: ColumnType,
     pub comment: Option<String>,
+    #[serde(default)]
+    pub generated_always_as: Option<String>,
+    #[serde(default)]
+    pub generated_as_identity: bool,
 }
 
 impl ColumnInformation {
@@ -192,6 +199,8 @@ impl ColumnInformation {
             nullable,
             max_length,
             comment,
+            generated_always_as: None,
+            generated_as_identity: false,
         }
     }
 }
@@ -1,6 +1,6 @@
 #![allow(clippy::expect_fun_call)] // My calls are so fun
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use super::data_structures::ForeignKeyConstraint;
 use super::inference::get_primary_keys;
@@ -38,6 +38,7 @@ pub fn remove_unsafe_foreign_keys_for_codegen(
     conn: &mut InferConnection,
     foreign_keys: &[ForeignKeyConstraint],
     safe_tables: &[TableName],
+    known_primary_keys: &HashMap<TableName, Vec<String>>,
 ) -> Vec<ForeignKeyConstraint> {
     foreign_keys
         .iter()
@@ -69,10 +70,13 @@ pub fn remove_unsafe_foreign_keys_for_codegen(
             }
         })
         .filter(|fk| {
-            let pk_columns = get_primary_keys(conn, &fk.parent_table).expect(&format!(
-                "Error loading primary keys for `{}`",
-                fk.parent_table
-            ));
+            let pk_columns = match known_primary_keys.get(&fk.parent_table) {
+                Some(pk_columns) => pk_columns.clone(),
+                None => get_primary_keys(conn, &fk.parent_table).expect(&format!(
+                    "Error loading primary keys for `{}`",
+                    fk.parent_table
+                )),
+            };
             let condition =
                 pk_columns.len() == 1 && Some(&pk_columns[0]) == fk.primary_key_columns.first();
             if !condition {
@@ -2,6 +2,7 @@ use std::collections::HashMap;
 
 use diesel::result::Error::NotFound;
 
+use super::introspection_cache::IntrospectionCache;
 use super::table_data::*;
 use super::{SchemaResolverImpl, data_structures::*};
 
@@ -175,7 +176,28 @@ fn get_table_comment(
     }
 }
 
-fn get_column_information(
+/// Returns the sequence generating the values of the given column
+///
+/// Only PostgreSQL tracks the ownership of sequences, for other
+/// backends this always returns `None`.
+pub fn get_owned_sequence(
+    conn: &mut InferConnection,
+    table: &TableName,
+    column: &str,
+) -> Result<Option<String>, crate::errors::Error> {
+    #[cfg(not(feature = "postgres"))]
+    let _ = (table, column);
+    match *conn {
+        #[cfg(feature = "sqlite")]
+        InferConnection::Sqlite(_) => Ok(None),
+        #[cfg(feature = "postgres")]
+        InferConnection::Pg(ref mut c) => Ok(super::pg::get_owned_sequence(c, table, column)?),
+        #[cfg(feature = "mysql")]
+        InferConnection::Mysql(_) => Ok(None),
+    }
+}
+
+pub(crate) fn get_column_information(
     conn: &mut InferConnection,
     table: &TableName,
     column_sorting: &ColumnSorting,
@@ -285,20 +307,144 @@ pub fn load_foreign_key_constraints(
         }
     };
 
-    constraints.map(|mut ct| {
-        ct.sort();
-        ct.iter_mut().for_each(|foreign_key_constraint| {
-            for name in &mut foreign_key_constraint.foreign_key_columns_rust {
-                if is_reserved_name(name) {
-                    *name = format!("{name}_");
-                }
-            }
-        });
+    constraints.map(|ct| {
+        let ct = normalize_foreign_key_constraints(ct);
         tracing::info!(?ct, "Loaded foreign key constraints");
         ct
     })
 }
 
+pub(super) fn normalize_foreign_key_constraints(
+    mut ct: Vec<ForeignKeyConstraint>,
+) -> Vec<ForeignKeyConstraint> {
+    ct.sort();
+    ct.iter_mut().for_each(|foreign_key_constraint| {
+        for name in &mut foreign_key_constraint.foreign_key_columns_rust {
+            if is_reserved_name(name) {
+                *name = format!("{name}_");
+            }
+        }
+    });
+    ct
+}
+
+/// Schema information loaded upfront for all tables, instead of
+/// being queried table by table
+#[derive(Default)]
+pub struct PreloadedSchema {
+    pub table_names: Option<Vec<(SupportedQueryRelationStructures, TableName)>>,
+    pub foreign_keys: Option<Vec<ForeignKeyConstraint>>,
+    pub tables: Vec<TableData>,
+    /// The SQL names of the primary key columns of the loaded tables
+    pub primary_keys: HashMap<TableName, Vec<String>>,
+}
+
+#[tracing::instrument(skip(connection, database_url))]
+pub fn preload_schema(
+    connection: &mut InferConnection,
+    database_url: Option<&str>,
+    config: &PrintSchema,
+) -> Result<PreloadedSchema, crate::errors::Error> {
+    match connection {
+        #[cfg(feature = "mysql")]
+        InferConnection::Mysql(c)
+            if config.mysql_introspection
+                == crate::print_schema::MysqlIntrospection::ShowCommands =>
+        {
+            super::mysql_show::load_schema(c, database_url, config)
+        }
+        // sqlite databases are local, so there is nothing to gain from
+        // using several connections
+        #[cfg(feature = "sqlite")]
+        InferConnection::Sqlite(_) if config.introspection_cache => {
+            load_tables_concurrently(connection, None, config)
+        }
+        #[cfg(feature = "sqlite")]
+        InferConnection::Sqlite(_) => Ok(PreloadedSchema::default()),
+        #[allow(unreachable_patterns)]
+        _ if config.introspection_cache
+            || (config.introspection_jobs() > 1 && database_url.is_some()) =>
+        {
+            load_tables_concurrently(connection, database_url, config)
+        }
+        #[allow(unreachable_patterns)]
+        _ => Ok(PreloadedSchema::default()),
+    }
+}
+
+/// Loads the data of all tables selected by the configuration using
+/// `config.introspection_jobs()` connections
+///
+/// If `config.introspection_cache` is set, tables that did not change since
+/// the last run are taken from the introspection cache instead.
+fn load_tables_concurrently(
+    connection: &mut InferConnection,
+    database_url: Option<&str>,
+    config: &PrintSchema,
+) -> Result<PreloadedSchema, crate::errors::Error> {
+    let table_names = load_table_names(connection, config.schema_name())?;
+    let tables = filter_column_structure(
+        &filter_table_names(&table_names, &config.filter, config.include_views),
+        SupportedQueryRelationStructures::Table,
+    );
+
+    let cache = if config.introspection_cache {
+        Some(IntrospectionCache::load(connection, config)?)
+    } else {
+        None
+    };
+    let cached_tables = tables
+        .iter()
+        .map(|table| cache.as_ref().and_then(|c| c.get(table)))
+        .collect::<Vec<_>>();
+    let tables_to_load = tables
+        .iter()
+        .zip(&cached_tables)
+        .filter(|(_, cached)| cached.is_none())
+        .map(|(table, _)| table.clone())
+        .collect::<Vec<_>>();
+
+    let loaded_tables = super::concurrent::load_concurrently(
+        connection,
+        database_url,
+        config.introspection_jobs(),
+        &tables_to_load,
+        |url| InferConnection::from_url(url.to_owned()),
+        |connection, table| {
+            load_table_data_with_primary_key(
+                connection,
+                table.clone(),
+                config,
+                SupportedQueryRelationStructures::Table,
+            )
+        },
+    )?;
+
+    // keep the order of `tables` by filling the gaps with the loaded tables
+    let mut loaded_tables = loaded_tables.into_iter();
+    let all_tables = cached_tables
+        .into_iter()
+        .filter_map(|cached| cached.or_else(|| loaded_tables.next()))
+        .collect::<Vec<_>>();
+    if let Some(cache) = cache {
+        cache.save(
+            all_tables
+                .iter()
+                .map(|(data, primary_key)| (data, primary_key.as_slice())),
+        )?;
+    }
+
+    let mut out = PreloadedSchema {
+        table_names: Some(table_names),
+        ..PreloadedSchema::default()
+    };
+    for (data, primary_key) in all_tables {
+        out.primary_keys.insert(data.name.clone(), primary_key);
+        out.tables.push(data);
+    }
+    Ok(out)
+}
+
 #[tracing::instrument(skip(connection))]
 fn load_column_structure_data(
     connection: &mut InferConnection,
@@ -347,6 +493,8 @@ fn load_column_structure_data(
         let ColumnInformation {
             column_name,
             comment,
+            generated_always_as,
+            generated_as_identity,
             ..
         } = c;
         let rust_name = rust_name_for_sql_name(&column_name, Some(name));
@@ -356,6 +504,8 @@ fn load_column_structure_data(
             ty,
             rust_name,
             comment,
+            generated_always_as,
+            generated_as_identity,
         })
     })
     .collect::<Result<_, crate::errors::Error>>()
@@ -369,22 +519,33 @@ pub fn load_table_data(
     config: &PrintSchema,
     tpe: SupportedQueryRelationStructures,
 ) -> Result<TableData, crate::errors::Error> {
-    let primary_key = match tpe {
+    load_table_data_with_primary_key(connection, name, config, tpe).map(|(data, _)| data)
+}
+
+/// Loads the table data together with the SQL names of the primary key columns
+fn load_table_data_with_primary_key(
+    connection: &mut InferConnection,
+    name: TableName,
+    config: &PrintSchema,
+    tpe: SupportedQueryRelationStructures,
+) -> Result<(TableData, Vec<String>), crate::errors::Error> {
+    let primary_key_sql = match tpe {
         SupportedQueryRelationStructures::Table => get_primary_keys(connection, &name)?,
         SupportedQueryRelationStructures::View => Vec::new(),
     };
     let (table_comment, column_data) =
-        load_column_structure_data(connection, &name, config, Some(&primary_key), tpe)?;
-    let primary_key = primary_key
+        load_column_structure_data(connection, &name, config, Some(&primary_key_sql), tpe)?;
+    let primary_key = primary_key_sql
         .iter()
         .map(|k| rust_name_for_sql_name(k, Some(&name)))
         .collect::<Vec<_>>();
-    Ok(TableData {
+    let data = TableData {
         name,
         primary_key,
         column_data,
         comment: table_comment,
-    })
+    };
+    Ok((data, primary_key_sql))
 }
 
 #[tracing::instrument(skip(resolver))]
@@ -0,0 +1,304 @@
+//! An opt-in cache for introspection results
+//!
+//! Loading the schema of a table requires several queries, which adds up for
+//! databases with many tables or a high latency. With
+//! `print_schema.introspection_cache = true` the loaded tables are stored in
+//! `.diesel/introspection-cache.json` in the project root. Subsequent runs
+//! only execute a single fingerprint query per schema and reuse the
+//! cached data of all tables whose fingerprint did not change.
+//!
+//! Cache entries are keyed by the server version and the configuration
+//! options that influence the loaded table data.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use diesel::dsl::sql;
+use diesel::prelude::*;
+use diesel::sql_types::Text;
+use serde::{Deserialize, Serialize};
+
+use super::data_structures::ColumnDefinition;
+use super::table_data::{TableData, TableName};
+use crate::config::PrintSchema;
+use crate::database::InferConnection;
+
+const CACHE_FILE: &str = "introspection-cache.json";
+
+#[derive(Default, Serialize, Deserialize)]
+struct CacheFile {
+    diesel_cli_version: String,
+    /// Cached tables by the key of the configuration they were loaded for
+    entries: BTreeMap<String, CacheEntry>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct CacheEntry {
+    /// Cached tables by their fully qualified SQL name
+    tables: BTreeMap<String, CachedTable>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedTable {
+    fingerprint: String,
+    sql_name: String,
+    rust_name: String,
+    schema: Option<String>,
+    primary_key: Vec<String>,
+    primary_key_sql: Vec<String>,
+    column_data: Vec<ColumnDefinition>,
+    comment: Option<String>,
+}
+
+pub(super) struct IntrospectionCache {
+    path: PathBuf,
+    key: String,
+    file: CacheFile,
+    fingerprints: HashMap<String, String>,
+}
+
+impl IntrospectionCache {
+    /// Loads the cache file and the current fingerprints of all tables
+    /// in the configured schema
+    pub(super) fn load(
+        connection: &mut InferConnection,
+        config: &PrintSchema,
+    ) -> Result<Self, crate::errors::Error> {
+        let path = crate::find_project_root()?.join(".diesel").join(CACHE_FILE);
+        let server_version = server_version(connection)?;
+        let key = format!(
+            "{:016x}",
+            fingerprint([server_version.as_str(), &config_key(config)])
+        );
+        let fingerprints = load_fingerprints(connection, config)?;
+
+        let file = match std::fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str::<CacheFile>(&content)
+                .ok()
+                .filter(|f| f.diesel_cli_version == env!("CARGO_PKG_VERSION"))
+                .unwrap_or_else(|| {
+                    tracing::warn!(?path, "Ignoring outdated or invalid introspection cache");
+                    CacheFile::default()
+                }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => CacheFile::default(),
+            Err(e) => return Err(crate::errors::Error::IoError(e, Some(path))),
+        };
+
+        Ok(Self {
+            path,
+            key,
+            file,
+            fingerprints,
+        })
+    }
+
+    /// Returns the cached data of the given table and the SQL names of its
+    /// primary key columns, if the table did not change since it was cached
+    pub(super) fn get(&self, table: &TableName) -> Option<(TableData, Vec<String>)> {
+        let cached = self
+            .file
+            .entries
+            .get(&self.key)?
+            .tables
+            .get(&table.full_sql_name())?;
+        if self.fingerprints.get(&table.sql_name) != Some(&cached.fingerprint) {
+            return None;
+        }
+        tracing::debug!(%table, "Using cached table data");
+        let data = TableData {
+            name: TableName {
+                sql_name: cached.sql_name.clone(),
+                rust_name: cached.rust_name.clone(),
+                schema: cached.schema.clone(),
+            },
+            primary_key: cached.primary_key.clone(),
+            column_data: cached.column_data.clone(),
+            comment: cached.comment.clone(),
+        };
+        Some((data, cached.primary_key_sql.clone()))
+    }
+
+    /// Stores the given tables and writes the cache file
+    ///
+    /// Only tables that are part of `tables` are kept for the current
+    /// configuration, so that dropped tables do not accumulate.
+    pub(super) fn save<'a>(
+        mut self,
+        tables: impl Iterator<Item = (&'a TableData, &'a [String])>,
+    ) -> Result<(), crate::errors::Error> {
+        let entry = CacheEntry {
+            tables: tables
+                .filter_map(|(data, primary_key_sql)| {
+                    let fingerprint = self.fingerprints.get(&data.name.sql_name)?.clone();
+                    let cached = CachedTable {
+                        fingerprint,
+                        sql_name: data.name.sql_name.clone(),
+                        rust_name: data.name.rust_name.clone(),
+                        schema: data.name.schema.clone(),
+                        primary_key: data.primary_key.clone(),
+                        primary_key_sql: primary_key_sql.to_vec(),
+                        column_data: data.column_data.clone(),
+                        comment: data.comment.clone(),
+                    };
+                    Some((data.name.full_sql_name(), cached))
+                })
+                .collect(),
+        };
+        self.file.diesel_cli_version = env!("CARGO_PKG_VERSION").to_owned();
+        self.file.entries.insert(self.key, entry);
+
+        let content = serde_json::to_string_pretty(&self.file)?;
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| crate::errors::Error::IoError(e, Some(parent.to_owned())))?;
+        }
+        std::fs::write(&self.path, content)
+            .map_err(|e| crate::errors::Error::IoError(e, Some(self.path)))
+    }
+}
+
+/// The configuration options that influence the loaded table data
+fn config_key(config: &PrintSchema) -> String {
+    format!(
+        "{:?}|{:?}|{:?}|{:?}|{:?}",
+        config.schema,
+        config.with_docs,
+        config.column_sorting,
+        config.sqlite_integer_primary_key_is_bigint,
+        config
+            .pg_domains_as_custom_types
+            .iter()
+            .map(|r| r.as_str())
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn server_version(connection: &mut InferConnection) -> Result<String, crate::errors::Error> {
+    let query = match connection {
+        #[cfg(feature = "sqlite")]
+        InferConnection::Sqlite(_) => "SELECT sqlite_version()",
+        #[cfg(feature = "postgres")]
+        InferConnection::Pg(_) => "SELECT version()",
+        #[cfg(feature = "mysql")]
+        InferConnection::Mysql(_) => "SELECT VERSION()",
+    };
+    Ok(sql::<Text>(query).get_result(connection)?)
+}
+
+/// Computes a fingerprint for each table of the configured schema
+///
+/// Each fingerprint covers the columns (including identity and generation
+/// expressions), constraints and comments of the table, so that any change
+/// to them invalidates the cached data.
+fn load_fingerprints(
+    connection: &mut InferConnection,
+    config: &PrintSchema,
+) -> Result<HashMap<String, String>, crate::errors::Error> {
+    let rows = match connection {
+        #[cfg(feature = "sqlite")]
+        InferConnection::Sqlite(c) => {
+            if config.schema_name().is_some() {
+                return Err(crate::errors::Error::InvalidSqliteSchema);
+            }
+            // the `CREATE TABLE` statement contains the whole table definition
+            sql::<(Text, Text)>("SELECT name, COALESCE(sql, '') FROM sqlite_master ORDER BY name")
+                .load::<(String, String)>(c)?
+        }
+        #[cfg(feature = "postgres")]
+        InferConnection::Pg(c) => sql::<(Text, Text)>(
+            "SELECT c.relname, concat_ws(':', a.attnum, a.attname, \
+                    format_type(a.atttypid, a.atttypmod), a.attnotnull, \
+                    format_type(t.typbasetype, t.typtypmod), col_description(c.oid, a.attnum), \
+                    a.attidentity, a.attgenerated, \
+                    CASE WHEN a.attgenerated <> '' THEN pg_get_expr(d.adbin, d.adrelid) END) \
+             FROM pg_catalog.pg_attribute a \
+             INNER JOIN pg_catalog.pg_class c ON c.oid = a.attrelid \
+             INNER JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+             INNER JOIN pg_catalog.pg_type t ON t.oid = a.atttypid \
+             LEFT JOIN pg_catalog.pg_attrdef d ON d.adrelid = a.attrelid AND d.adnum = a.attnum \
+             WHERE n.nspname = ",
+        )
+        .bind::<Text, _>(config.schema_name().unwrap_or("public"))
+        .sql(
+            " AND a.attnum > 0 AND NOT a.attisdropped \
+             UNION ALL \
+             SELECT c.relname, concat_ws(':', 'comment', obj_description(c.oid, 'pg_class')) \
+             FROM pg_catalog.pg_class c \
+             INNER JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+             WHERE n.nspname = ",
+        )
+        .bind::<Text, _>(config.schema_name().unwrap_or("public"))
+        .sql(
+            " UNION ALL \
+             SELECT c.relname, concat_ws(':', co.conname, pg_get_constraintdef(co.oid)) \
+             FROM pg_catalog.pg_constraint co \
+             INNER JOIN pg_catalog.pg_class c ON c.oid = co.conrelid \
+             INNER JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+             WHERE co.contype IN ('p', 'f') AND n.nspname = ",
+        )
+        .bind::<Text, _>(config.schema_name().unwrap_or("public"))
+        .sql(" ORDER BY 1, 2")
+        .load::<(String, String)>(c)?,
+        #[cfg(feature = "mysql")]
+        InferConnection::Mysql(c) => {
+            use super::information_schema::DefaultSchema;
+
+            let default_schema = diesel::mysql::Mysql::default_schema(c)?;
+            let schema = config.schema_name().unwrap_or(&default_schema);
+            sql::<(Text, Text)>(
+                "SELECT table_name, CONCAT_WS(':', ordinal_position, column_name, column_type, \
+                        is_nullable, column_comment, extra, COALESCE(generation_expression, '')) \
+                 FROM information_schema.columns WHERE table_schema = ",
+            )
+            .bind::<Text, _>(schema)
+            .sql(
+                " UNION ALL \
+                 SELECT table_name, CONCAT_WS(':', 'comment', table_comment) \
+                 FROM information_schema.tables WHERE table_schema = ",
+            )
+            .bind::<Text, _>(schema)
+            .sql(
+                " UNION ALL \
+                 SELECT table_name, CONCAT_WS(':', constraint_name, ordinal_position, column_name, \
+                        COALESCE(referenced_table_schema, ''), COALESCE(referenced_table_name, ''), \
+                        COALESCE(referenced_column_name, '')) \
+                 FROM information_schema.key_column_usage WHERE table_schema = ",
+            )
+            .bind::<Text, _>(schema)
+            .sql(" ORDER BY 1, 2")
+            .load::<(String, String)>(c)?
+        }
+    };
+
+    let mut rows_by_table = BTreeMap::<String, Vec<String>>::new();
+    for (table, row) in rows {
+        rows_by_table.entry(table).or_default().push(row);
+    }
+    Ok(rows_by_table
+        .into_iter()
+        .map(|(table, rows)| {
+            let fingerprint = format!("{:016x}", fingerprint(rows.iter().map(String::as_str)));
+            (table, fingerprint)
+        })
+        .collect())
+}
+
+/// A stable 64 bit FNV-1a hash of the given parts
+fn fingerprint<'a>(parts: impl IntoIterator<Item = &'a str>) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    parts
+        .into_iter()
+        .flat_map(|part| part.bytes().chain(std::iter::once(0)))
+        .fold(OFFSET_BASIS, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(PRIME)
+        })
+}
+
+#[test]
+fn fingerprint_depends_on_all_parts() {
+    assert_eq!(fingerprint(["a", "b"]), fingerprint(["a", "b"]));
+    assert_ne!(fingerprint(["a", "b"]), fingerprint(["ab"]));
+    assert_ne!(fingerprint(["a", "b"]), fingerprint(["b", "a"]));
+}
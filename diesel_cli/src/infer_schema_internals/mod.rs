@@ -1,12 +1,16 @@
+mod concurrent;
 mod data_structures;
 mod foreign_keys;
 mod inference;
+mod introspection_cache;
 mod table_data;
 
 #[cfg(feature = "uses_information_schema")]
 mod information_schema;
 #[cfg(feature = "mysql")]
 pub mod mysql;
+#[cfg(feature = "mysql")]
+mod mysql_show;
 #[cfg(feature = "postgres")]
 pub mod pg;
 mod schema_resolver;
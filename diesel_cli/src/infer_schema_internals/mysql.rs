@@ -41,6 +41,9 @@ pub fn get_table_data(
             character_maximum_length,
             // MySQL comments are not nullable and are empty strings if not set
             null_if_text(column_comment, ""),
+            // virtual generated columns are not recorded as they cannot
+            // be created as `STORED` columns
+            dsl::case_when(extra.like("%STORED GENERATED%"), generation_expression),
         ))
         .filter(table_name.eq(&table.sql_name))
         .filter(table_schema.eq(schema_name));
@@ -72,6 +75,7 @@ where
         String,
         Option<u64>,
         Option<String>,
+        Option<String>,
     ): FromStaticSqlRow<ST, Mysql>,
 {
     type Row = (
@@ -81,17 +85,14 @@ where
         String,
         Option<u64>,
         Option<String>,
+        Option<String>,
     );
 
     fn build(row: Self::Row) -> deserialize::Result<Self> {
-        Ok(ColumnInformation::new(
-            row.0,
-            row.1,
-            row.2,
-            row.3 == "YES",
-            row.4,
-            row.5,
-        ))
+        Ok(ColumnInformation {
+            generated_always_as: row.6,
+            ..ColumnInformation::new(row.0, row.1, row.2, row.3 == "YES", row.4, row.5)
+        })
     }
 }
 
@@ -141,6 +142,8 @@ mod information_schema {
             udt_schema -> VarChar,
             column_type -> VarChar,
             column_comment -> VarChar,
+            generation_expression -> Text,
+            extra -> VarChar,
         }
     }
 
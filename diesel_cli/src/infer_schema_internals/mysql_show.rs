@@ -0,0 +1,526 @@
+//! Schema introspection for MySQL based on `SHOW` commands
+//!
+//! Querying `information_schema` gets slow on servers with a large number
+//! of tables, as each of these queries scans the data dictionary of the
+//! whole server. Instead this module loads the table list via `SHOW FULL TABLES`
+//! and each table via `SHOW FULL COLUMNS` and `SHOW CREATE TABLE`, which only
+//! touch the requested table. Tables are distributed over several connections
+//! to load them in parallel.
+//!
+//! Views are still loaded via `information_schema`.
+
+use diesel::dsl::sql;
+use diesel::mysql::Mysql;
+use diesel::prelude::*;
+use diesel::sql_types::{Nullable, Text};
+use std::collections::HashMap;
+
+use super::concurrent::load_concurrently;
+use super::data_structures::*;
+use super::inference::{
+    PreloadedSchema, filter_column_structure, filter_table_names,
+    normalize_foreign_key_constraints, rust_name_for_sql_name,
+};
+use super::information_schema::DefaultSchema;
+use super::table_data::*;
+use crate::config::PrintSchema;
+use crate::print_schema::{ColumnSorting, DocConfig};
+
+/// The row returned by `SHOW FULL COLUMNS`:
+/// `Field`, `Type`, `Collation`, `Null`, `Key`, `Default`, `Extra`, `Privileges` and `Comment`
+type ShowColumnsSqlType = (
+    Text,
+    Text,
+    Nullable<Text>,
+    Text,
+    Text,
+    Nullable<Text>,
+    Text,
+    Text,
+    Text,
+);
+type ShowColumnsRow = (
+    String,
+    String,
+    Option<String>,
+    String,
+    String,
+    Option<String>,
+    String,
+    String,
+    String,
+);
+
+const DEFAULT_CONNECTIONS: usize = 4;
+
+pub fn load_schema(
+    connection: &mut MysqlConnection,
+    database_url: Option<&str>,
+    config: &PrintSchema,
+) -> Result<PreloadedSchema, crate::errors::Error> {
+    let default_schema = Mysql::default_schema(connection)?;
+    let schema_name = config.schema_name().unwrap_or(&default_schema);
+    let table_names = load_table_names(connection, schema_name, &default_schema)?;
+    let tables_to_load = filter_column_structure(
+        &filter_table_names(&table_names, &config.filter, config.include_views),
+        SupportedQueryRelationStructures::Table,
+    );
+
+    let loaded_tables = load_concurrently(
+        connection,
+        database_url,
+        config.mysql_introspection_connections(DEFAULT_CONNECTIONS),
+        &tables_to_load,
+        |url| {
+            MysqlConnection::establish(url).map_err(|error| crate::errors::Error::ConnectionError {
+                error,
+                url: url.to_owned(),
+            })
+        },
+        |connection, table| load_table(connection, table, schema_name, &default_schema, config),
+    )?;
+
+    let mut out = PreloadedSchema {
+        table_names: Some(table_names),
+        ..PreloadedSchema::default()
+    };
+    let mut foreign_keys = Vec::new();
+    for table in loaded_tables {
+        out.primary_keys
+            .insert(table.data.name.clone(), table.primary_key);
+        foreign_keys.extend(table.foreign_keys);
+        out.tables.push(table.data);
+    }
+    let foreign_keys = normalize_foreign_key_constraints(foreign_keys);
+    tracing::info!(?foreign_keys, "Loaded foreign key constraints");
+    out.foreign_keys = Some(foreign_keys);
+    Ok(out)
+}
+
+fn load_table_names(
+    connection: &mut MysqlConnection,
+    schema_name: &str,
+    default_schema: &str,
+) -> QueryResult<Vec<(SupportedQueryRelationStructures, TableName)>> {
+    let mut table_names = sql::<(Text, Text)>(&format!(
+        "SHOW FULL TABLES FROM {}",
+        quote_identifier(schema_name)
+    ))
+    .load::<(String, String)>(connection)?;
+    table_names.sort_unstable();
+    Ok(table_names
+        .into_iter()
+        .filter(|(name, tpe)| {
+            !name.starts_with("__")
+                && SupportedQueryRelationStructures::ALL_NAMES.contains(&tpe.as_str())
+        })
+        .map(|(name, tpe)| {
+            let tpe = tpe
+                .parse::<SupportedQueryRelationStructures>()
+                .expect("This should never happen.");
+            let mut table = TableName::new(name, schema_name);
+            table.strip_schema_if_matches(default_schema);
+            (tpe, table)
+        })
+        .collect())
+}
+
+struct LoadedTable {
+    data: TableData,
+    /// SQL names of the primary key columns
+    primary_key: Vec<String>,
+    foreign_keys: Vec<ForeignKeyConstraint>,
+}
+
+#[tracing::instrument(skip(connection, config))]
+fn load_table(
+    connection: &mut MysqlConnection,
+    table: &TableName,
+    schema_name: &str,
+    default_schema: &str,
+    config: &PrintSchema,
+) -> Result<LoadedTable, crate::errors::Error> {
+    let qualified_name = format!(
+        "{}.{}",
+        quote_identifier(schema_name),
+        quote_identifier(&table.sql_name)
+    );
+    let mut columns =
+        sql::<ShowColumnsSqlType>(&format!("SHOW FULL COLUMNS FROM {qualified_name}"))
+            .load::<ShowColumnsRow>(connection)?
+            .into_iter()
+            .map(
+                |(
+                    name,
+                    type_name,
+                    _collation,
+                    null,
+                    _key,
+                    _default,
+                    _extra,
+                    _privileges,
+                    comment,
+                )| {
+                    let max_length = max_length(&type_name);
+                    ColumnInformation::new(
+                        name,
+                        type_name,
+                        None,
+                        null == "YES",
+                        max_length,
+                        Some(comment).filter(|c| !c.is_empty()),
+                    )
+                },
+            )
+            .collect::<Vec<_>>();
+    if let ColumnSorting::Name = config.column_sorting {
+        columns.sort_by_key(|c| c.column_name.to_lowercase());
+    }
+
+    let (_, create_table) = sql::<(Text, Text)>(&format!("SHOW CREATE TABLE {qualified_name}"))
+        .get_result::<(String, String)>(connection)?;
+    let mut definition = CreateTableDefinition::parse(&create_table);
+    if definition.primary_key.is_empty() {
+        return Err(crate::errors::Error::NoPrimaryKeyFound(table.clone()));
+    }
+
+    let column_data = columns
+        .into_iter()
+        .map(|c| {
+            let ty = super::mysql::determine_column_type(&c)?;
+            Ok(ColumnDefinition {
+                rust_name: rust_name_for_sql_name(&c.column_name, Some(table)),
+                generated_always_as: definition.generated_columns.remove(&c.column_name),
+                generated_as_identity: false,
+                sql_name: c.column_name,
+                ty,
+                comment: c.comment,
+            })
+        })
+        .collect::<Result<Vec<_>, crate::errors::Error>>()?;
+
+    let foreign_keys = definition
+        .foreign_keys
+        .into_iter()
+        .map(|fk| {
+            let mut parent_table = TableName::new(
+                fk.parent_table,
+                fk.parent_schema.as_deref().unwrap_or(schema_name),
+            );
+            parent_table.strip_schema_if_matches(default_schema);
+            ForeignKeyConstraint {
+                child_table: table.clone(),
+                parent_table,
+                foreign_key_columns_rust: fk.foreign_key_columns.clone(),
+                foreign_key_columns: fk.foreign_key_columns,
+                primary_key_columns: fk.primary_key_columns,
+            }
+        })
+        .collect();
+
+    // No point in keeping table comments if they are not going to be displayed
+    let comment = match config.with_docs {
+        DocConfig::NoDocComments => None,
+        DocConfig::OnlyDatabaseComments
+        | DocConfig::DatabaseCommentsFallbackToAutoGeneratedDocComment => definition.comment,
+    };
+
+    Ok(LoadedTable {
+        data: TableData {
+            name: table.clone(),
+            primary_key: definition
+                .primary_key
+                .iter()
+                .map(|k| rust_name_for_sql_name(k, Some(table)))
+                .collect(),
+            column_data,
+            comment,
+        },
+        primary_key: definition.primary_key,
+        foreign_keys,
+    })
+}
+
+fn quote_identifier(identifier: &str) -> String {
+    format!("`{}`", identifier.replace('`', "``"))
+}
+
+/// Computes the value `information_schema.columns.character_maximum_length`
+/// would contain for a column of the given type
+fn max_length(type_name: &str) -> Option<u64> {
+    let (name, arguments) = type_name.split_once('(')?;
+    let (arguments, _) = arguments.rsplit_once(')')?;
+    match name {
+        "char" | "varchar" | "binary" | "varbinary" => arguments.parse().ok(),
+        "enum" | "set" => {
+            let values = arguments
+                .strip_prefix('\'')?
+                .strip_suffix('\'')?
+                .split("','")
+                .map(|v| v.replace("''", "'").chars().count() as u64);
+            if name == "enum" {
+                values.max()
+            } else {
+                // all values separated by commas
+                values.reduce(|len, value_len| len + 1 + value_len)
+            }
+        }
+        _ => None,
+    }
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct CreateTableDefinition {
+    primary_key: Vec<String>,
+    foreign_keys: Vec<ForeignKeyDefinition>,
+    comment: Option<String>,
+    /// Generation expressions of stored generated columns by column name
+    generated_columns: HashMap<String, String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct ForeignKeyDefinition {
+    foreign_key_columns: Vec<String>,
+    parent_schema: Option<String>,
+    parent_table: String,
+    primary_key_columns: Vec<String>,
+}
+
+impl CreateTableDefinition {
+    /// Parses the output of `SHOW CREATE TABLE`
+    ///
+    /// MySQL puts each column, key and constraint definition on a separate
+    /// line, followed by a line containing the table options.
+    fn parse(create_table: &str) -> Self {
+        let mut out = Self::default();
+        for line in create_table.lines().skip(1) {
+            let line = line.trim();
+            if let Some(rest) = line.strip_prefix("PRIMARY KEY ") {
+                if let Some((columns, _)) = parse_identifier_list(rest) {
+                    out.primary_key = columns;
+                }
+            } else if let Some(rest) = line.strip_prefix("CONSTRAINT ") {
+                if let Some(fk) = parse_foreign_key(rest) {
+                    out.foreign_keys.push(fk);
+                }
+            } else if let Some((column, expression)) = parse_generated_column(line) {
+                out.generated_columns.insert(column, expression);
+            } else if line.starts_with(')') {
+                out.comment = line
+                    .find(" COMMENT='")
+                    .and_then(|idx| parse_string_literal(&line[idx + " COMMENT=".len()..]))
+                    .filter(|c| !c.is_empty());
+                break;
+            }
+        }
+        out
+    }
+}
+
+// parses `` `name` FOREIGN KEY (`a`) REFERENCES `schema`.`table` (`b`) ON DELETE CASCADE ``
+fn parse_foreign_key(definition: &str) -> Option<ForeignKeyDefinition> {
+    let (_, rest) = parse_identifier(definition)?;
+    let rest = rest.trim_start().strip_prefix("FOREIGN KEY ")?;
+    let (foreign_key_columns, rest) = parse_identifier_list(rest)?;
+    let rest = rest.trim_start().strip_prefix("REFERENCES ")?;
+    let (first, rest) = parse_identifier(rest)?;
+    let (parent_schema, parent_table, rest) = match rest.strip_prefix('.') {
+        Some(rest) => {
+            let (table, rest) = parse_identifier(rest)?;
+            (Some(first), table, rest)
+        }
+        None => (None, first, rest),
+    };
+    let (primary_key_columns, _) = parse_identifier_list(rest.trim_start())?;
+    Some(ForeignKeyDefinition {
+        foreign_key_columns,
+        parent_schema,
+        parent_table,
+        primary_key_columns,
+    })
+}
+
+// parses `` `name` int GENERATED ALWAYS AS ((`a` + 1)) STORED `` into the
+// column name and the generation expression, ignoring virtual columns
+fn parse_generated_column(definition: &str) -> Option<(String, String)> {
+    let (column, rest) = parse_identifier(definition)?;
+    let idx = rest.find(" GENERATED ALWAYS AS (")?;
+    let rest = &rest[idx + " GENERATED ALWAYS AS ".len()..];
+    let mut depth = 0;
+    let mut quote = None;
+    let end = rest.char_indices().find(|&(_, c)| {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '`' | '"') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => depth -= 1,
+            (None, _) => {}
+        }
+        depth == 0
+    });
+    let (idx, _) = end?;
+    rest[idx + 1..]
+        .trim_start()
+        .starts_with("STORED")
+        .then(|| (column, rest[1..idx].to_owned()))
+}
+
+// parses a list of quoted identifiers like `` (`a`,`b`(10)) ``, ignoring prefix
+// lengths and sort orders of key parts
+fn parse_identifier_list(input: &str) -> Option<(Vec<String>, &str)> {
+    let mut rest = input.strip_prefix('(')?;
+    let mut identifiers = Vec::new();
+    loop {
+        let (identifier, after) = parse_identifier(rest.trim_start())?;
+        identifiers.push(identifier);
+        let mut depth = 0;
+        let end = after.char_indices().find(|&(_, c)| match c {
+            '(' => {
+                depth += 1;
+                false
+            }
+            ')' if depth > 0 => {
+                depth -= 1;
+                false
+            }
+            ',' | ')' => depth == 0,
+            _ => false,
+        });
+        let (idx, c) = end?;
+        rest = &after[idx + 1..];
+        if c == ')' {
+            return Some((identifiers, rest));
+        }
+    }
+}
+
+// parses an identifier quoted with backticks or, with `ANSI_QUOTES`, with
+// double quotes. Quote characters inside the identifier are doubled.
+fn parse_identifier(input: &str) -> Option<(String, &str)> {
+    let quote = input.chars().next().filter(|c| matches!(c, '`' | '"'))?;
+    let mut identifier = String::new();
+    let mut chars = input[1..].char_indices();
+    while let Some((idx, c)) = chars.next() {
+        if c == quote {
+            if input[1 + idx + 1..].starts_with(quote) {
+                identifier.push(quote);
+                chars.next();
+            } else {
+                return Some((identifier, &input[1 + idx + 1..]));
+            }
+        } else {
+            identifier.push(c);
+        }
+    }
+    None
+}
+
+// parses a string literal like `'it''s a \n comment'`
+fn parse_string_literal(input: &str) -> Option<String> {
+    let mut chars = input.strip_prefix('\'')?.chars().peekable();
+    let mut out = String::new();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' if chars.peek() == Some(&'\'') => {
+                out.push('\'');
+                chars.next();
+            }
+            '\'' => return Some(out),
+            '\\' => match chars.next()? {
+                'n' => out.push('\n'),
+                'r' => out.push('\r'),
+                't' => out.push('\t'),
+                '0' => out.push('\0'),
+                'Z' => out.push('\x1a'),
+                c => out.push(c),
+            },
+            c => out.push(c),
+        }
+    }
+    None
+}
+
+#[test]
+fn max_length_matches_information_schema() {
+    assert_eq!(Some(50), max_length("varchar(50)"));
+    assert_eq!(Some(16), max_length("binary(16)"));
+    assert_eq!(None, max_length("int(11) unsigned"));
+    assert_eq!(None, max_length("decimal(10,2)"));
+    assert_eq!(None, max_length("text"));
+    assert_eq!(Some(8), max_length("enum('active','disabled')"));
+    assert_eq!(Some(19), max_length("set('val1','val2','val3','val4')"));
+}
+
+#[test]
+fn create_table_definition_is_parsed() {
+    let create_table = "CREATE TABLE `comments` (\n  \
+                        `id` int NOT NULL AUTO_INCREMENT COMMENT 'PRIMARY KEY (`foo`)',\n  \
+                        `post_user_id` int NOT NULL,\n  \
+                        `post_number` int NOT NULL,\n  \
+                        `author_id` int NOT NULL,\n  \
+                        PRIMARY KEY (`id`,`post_number`),\n  \
+                        KEY `post_user_id` (`post_user_id`,`post_number`),\n  \
+                        CONSTRAINT `comments_ibfk_1` FOREIGN KEY (`post_user_id`, `post_number`) \
+                        REFERENCES `posts` (`user_id`, `post_number`),\n  \
+                        CONSTRAINT `fk``author` FOREIGN KEY (`author_id`) \
+                        REFERENCES `other`.`users` (`id`) ON DELETE CASCADE\n\
+                        ) ENGINE=InnoDB DEFAULT CHARSET=utf8mb4 COMMENT='it''s a \\\\ comment\\n'";
+
+    let expected = CreateTableDefinition {
+        primary_key: vec!["id".into(), "post_number".into()],
+        foreign_keys: vec![
+            ForeignKeyDefinition {
+                foreign_key_columns: vec!["post_user_id".into(), "post_number".into()],
+                parent_schema: None,
+                parent_table: "posts".into(),
+                primary_key_columns: vec!["user_id".into(), "post_number".into()],
+            },
+            ForeignKeyDefinition {
+                foreign_key_columns: vec!["author_id".into()],
+                parent_schema: Some("other".into()),
+                parent_table: "users".into(),
+                primary_key_columns: vec!["id".into()],
+            },
+        ],
+        comment: Some("it's a \\ comment\n".into()),
+        generated_columns: HashMap::new(),
+    };
+    assert_eq!(expected, CreateTableDefinition::parse(create_table));
+}
+
+#[test]
+fn stored_generated_columns_are_parsed() {
+    let create_table = "CREATE TABLE `users` (\n  \
+                        `id` int NOT NULL,\n  \
+                        `name` varchar(50) NOT NULL,\n  \
+                        `name_length` int GENERATED ALWAYS AS (length(`name`)) STORED,\n  \
+                        `greeting` text GENERATED ALWAYS AS (concat(_utf8mb4'(hi) ',`name`)) STORED NOT NULL,\n  \
+                        `upper_name` text GENERATED ALWAYS AS (upper(`name`)) VIRTUAL,\n  \
+                        PRIMARY KEY (`id`)\n\
+                        ) ENGINE=InnoDB";
+
+    let definition = CreateTableDefinition::parse(create_table);
+    assert_eq!(
+        HashMap::from([
+            ("name_length".to_owned(), "length(`name`)".to_owned()),
+            (
+                "greeting".to_owned(),
+                "concat(_utf8mb4'(hi) ',`name`)".to_owned()
+            ),
+        ]),
+        definition.generated_columns
+    );
+}
+
+#[test]
+fn prefix_lengths_and_quoted_identifiers_are_handled() {
+    assert_eq!(
+        Some((vec!["na`me".to_owned(), "id".to_owned()], " USING BTREE")),
+        parse_identifier_list("(`na``me`(10),\"id\") USING BTREE")
+    );
+    assert_eq!(
+        CreateTableDefinition::default(),
+        CreateTableDefinition::parse("CREATE TABLE `t` (\n  `id` int\n) ENGINE=InnoDB")
+    );
+}
@@ -26,6 +26,11 @@ extern "SQL" {
         oid: sql_types::Oid,
         catalog: sql_types::Text,
     ) -> sql_types::Nullable<sql_types::Text>;
+
+    fn pg_get_serial_sequence(
+        table: sql_types::Text,
+        column: sql_types::Text,
+    ) -> sql_types::Nullable<sql_types::Text>;
 }
 
 mod information_schema {
@@ -44,6 +49,8 @@ mod information_schema {
             udt_schema -> VarChar,
             domain_name -> Nullable<VarChar>,
             domain_schema -> Nullable<VarChar>,
+            generation_expression -> Nullable<Text>,
+            identity_generation -> Nullable<VarChar>,
         }
     }
 }
@@ -115,14 +122,16 @@ pub fn determine_column_type(
 
 diesel::postfix_operator!(Regclass, "::regclass", sql_types::Oid, backend: Pg);
 
-fn regclass(table: &TableName) -> Regclass<AsExprOf<String, sql_types::Text>> {
-    let table_name = match table.schema {
+fn quoted_table_name(table: &TableName) -> String {
+    match table.schema {
         Some(ref schema_name) => format!("\"{}\".\"{}\"", schema_name, table.sql_name),
         None => format!("\"{}\"", table.sql_name),
-    };
+    }
+}
 
+fn regclass(table: &TableName) -> Regclass<AsExprOf<String, sql_types::Text>> {
     Regclass::new(<String as AsExpression<sql_types::Text>>::as_expression(
-        table_name,
+        quoted_table_name(table),
     ))
 }
 
@@ -152,6 +161,8 @@ pub fn get_table_data(
         nullable: String,
         max_length: Option<i32>,
         comment: Option<String>,
+        generation_expression: Option<String>,
+        identity_generation: Option<String>,
     }
 
     let schema_name = match table.schema {
@@ -169,6 +180,8 @@ pub fn get_table_data(
             __is_nullable,
             character_maximum_length,
             col_description(regclass(table), ordinal_position),
+            generation_expression,
+            identity_generation,
         ))
         .filter(table_name.eq(&table.sql_name))
         .filter(table_schema.eq(schema_name));
@@ -202,14 +215,18 @@ pub fn get_table_data(
                 })
                 .transpose()?;
 
-            Ok(ColumnInformation::new(
-                row.column_name,
-                type_name,
-                type_schema,
-                row.nullable == "YES",
-                max_length,
-                row.comment,
-            ))
+            Ok(ColumnInformation {
+                generated_always_as: row.generation_expression,
+                generated_as_identity: row.identity_generation.as_deref() == Some("ALWAYS"),
+                ..ColumnInformation::new(
+                    row.column_name,
+                    type_name,
+                    type_schema,
+                    row.nullable == "YES",
+                    max_length,
+                    row.comment,
+                )
+            })
         })
         .collect()
 }
@@ -221,6 +238,16 @@ pub fn get_table_comment(
     diesel::select(obj_description(regclass(table), "pg_class")).get_result(conn)
 }
 
+/// Returns the qualified name of the sequence owned by the given column,
+/// which is the case for `serial` and identity columns
+pub fn get_owned_sequence(
+    conn: &mut PgConnection,
+    table: &TableName,
+    column: &str,
+) -> QueryResult<Option<String>> {
+    diesel::select(pg_get_serial_sequence(quoted_table_name(table), column)).get_result(conn)
+}
+
 #[allow(clippy::similar_names)]
 pub fn load_foreign_key_constraints(
     connection: &mut PgConnection,
@@ -1,5 +1,5 @@
 use super::{
-    ColumnDefinition, QueryRelationData, SupportedQueryRelationStructures, TableName,
+    ColumnDefinition, QueryRelationData, SupportedQueryRelationStructures, TableData, TableName,
     load_table_data, load_table_names, load_view_data,
 };
 use crate::config::PrintSchema;
@@ -37,6 +37,16 @@ impl<'a, 'b> SchemaResolverImpl<'a, 'b> {
         }
     }
 
+    /// Use already loaded table data instead of querying it again
+    pub(crate) fn with_preloaded_tables(mut self, tables: Vec<TableData>) -> Self {
+        self.cached_results.extend(
+            tables
+                .into_iter()
+                .map(|t| (t.name.clone(), QueryRelationData::Table(t))),
+        );
+        self
+    }
+
     pub(crate) fn resolve_query_relations(
         mut self,
     ) -> Result<Vec<QueryRelationData>, crate::errors::Error> {
@@ -187,6 +187,20 @@ pub fn get_table_data(
     // See: https://github.com/diesel-rs/diesel/issues/3579 as to why we use a direct
     // `sql_query` with `QueryableByName` instead of using `sql::<pragma_table_info::SqlType>`.
     let mut result = sql_query(query).load::<ColumnInformation>(conn)?;
+    // Generated columns are supported since SQLite 3.31.0 and their generation
+    // expression is only available as part of the table definition
+    if kind == SupportedQueryRelationStructures::Table
+        && sqlite_version >= SqliteVersion::new(3, 31, 0)
+    {
+        let create_table = sqlite_master::table
+            .filter(sqlite_master::name.eq(&table.sql_name))
+            .select(sqlite_master::sql)
+            .get_result::<String>(conn)?;
+        let mut generated_columns = stored_generated_columns(&create_table);
+        for c in &mut result {
+            c.generated_always_as = generated_columns.remove(&c.column_name);
+        }
+    }
     // Add implicit rowid primary key column if the only primary key is rowid
     // and ensure that the rowid column uses the right type.
     let primary_key = match kind {
@@ -206,6 +220,8 @@ pub fn get_table_data(
                     nullable: false,
                     max_length: None,
                     comment: None,
+                    generated_always_as: None,
+                    generated_as_identity: false,
                 },
             );
         }
@@ -483,6 +499,74 @@ fn is_double(type_name: &str) -> bool {
     type_name.contains("double") || type_name.contains("num") || type_name.contains("dec")
 }
 
+/// Extracts the generation expressions of all `STORED` generated columns
+/// from a `CREATE TABLE` statement
+fn stored_generated_columns(create_table: &str) -> HashMap<String, String> {
+    let mut generated_columns = HashMap::new();
+    let Some(start) = create_table.find('(') else {
+        return generated_columns;
+    };
+    let mut rest = &create_table[start + 1..];
+    while let Some(end) = top_level_position(rest, |c| c == ',' || c == ')') {
+        let definition = rest[..end].trim();
+        if let Some((column, expression)) = parse_stored_generated_column(definition) {
+            generated_columns.insert(column, expression);
+        }
+        if rest[end..].starts_with(')') {
+            break;
+        }
+        rest = &rest[end + 1..];
+    }
+    generated_columns
+}
+
+// parses `name INTEGER GENERATED ALWAYS AS (a + 1) STORED` or the short form
+// `name AS (a + 1) STORED` into the column name and the generation expression
+fn parse_stored_generated_column(definition: &str) -> Option<(String, String)> {
+    let (column, rest) = match definition.chars().next()? {
+        quote @ ('"' | '`' | '[') => {
+            let closing = if quote == '[' { ']' } else { quote };
+            let end = definition[1..].find(closing)? + 1;
+            (&definition[1..end], &definition[end + 1..])
+        }
+        _ => definition.split_once(char::is_whitespace)?,
+    };
+    let upper = rest.to_uppercase();
+    let expression_start = upper.match_indices("AS").find_map(|(idx, _)| {
+        let after = &upper[idx + 2..];
+        let is_keyword =
+            upper[..idx].ends_with(char::is_whitespace) && after.trim_start().starts_with('(');
+        is_keyword.then(|| idx + 2 + (after.len() - after.trim_start().len()) + 1)
+    })?;
+    let rest = &rest[expression_start..];
+    let end = top_level_position(rest, |c| c == ')')?;
+    rest[end + 1..]
+        .trim_start()
+        .to_uppercase()
+        .starts_with("STORED")
+        .then(|| (column.to_owned(), rest[..end].trim().to_owned()))
+}
+
+// finds the first character outside of parentheses, string literals
+// and quoted identifiers that matches `is_match`
+fn top_level_position(input: &str, mut is_match: impl FnMut(char) -> bool) -> Option<usize> {
+    let mut depth = 0;
+    let mut closing_quote = None;
+    for (idx, c) in input.char_indices() {
+        match (closing_quote, c) {
+            (Some(q), c) if c == q => closing_quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"' | '`') => closing_quote = Some(c),
+            (None, '[') => closing_quote = Some(']'),
+            (None, '(') => depth += 1,
+            (None, ')') if depth > 0 => depth -= 1,
+            (None, c) if depth == 0 && is_match(c) => return Some(idx),
+            (None, _) => {}
+        }
+    }
+    None
+}
+
 pub(crate) fn load_view_sql_definition(
     sqlite_connection: &mut SqliteConnection,
     name: &TableName,
@@ -779,3 +863,51 @@ fn integer_primary_key_sqlite_3_37() {
         );
     }
 }
+
+#[test]
+fn stored_generated_columns_are_extracted() {
+    let create_table = "CREATE TABLE users (\n\
+                        id INTEGER PRIMARY KEY,\n\
+                        \"first name\" TEXT NOT NULL DEFAULT 'a, (b',\n\
+                        last_name TEXT NOT NULL,\n\
+                        full_name TEXT GENERATED ALWAYS AS (\"first name\" || ' ) ' || last_name) STORED,\n\
+                        [name length] INTEGER AS (length(last_name)) stored NOT NULL,\n\
+                        upper_name TEXT AS (upper(last_name)) VIRTUAL,\n\
+                        CHECK (length(last_name) > 0)\n\
+                        )";
+    assert_eq!(
+        HashMap::from([
+            (
+                "full_name".to_owned(),
+                "\"first name\" || ' ) ' || last_name".to_owned()
+            ),
+            ("name length".to_owned(), "length(last_name)".to_owned()),
+        ]),
+        stored_generated_columns(create_table)
+    );
+}
+
+#[test]
+fn get_table_data_loads_generation_expressions() {
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query(
+        "CREATE TABLE users (id INTEGER PRIMARY KEY, name TEXT NOT NULL, \
+         name_length INTEGER GENERATED ALWAYS AS (length(name)) STORED)",
+    )
+    .execute(&mut conn)
+    .unwrap();
+    let columns = get_table_data(
+        &mut conn,
+        &TableName::from_name("users"),
+        &ColumnSorting::OrdinalPosition,
+        SupportedQueryRelationStructures::Table,
+    )
+    .unwrap();
+    assert_eq!(
+        vec![None, None, Some("length(name)".to_owned())],
+        columns
+            .into_iter()
+            .map(|c| c.generated_always_as)
+            .collect::<Vec<_>>()
+    );
+}
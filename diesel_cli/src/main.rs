@@ -16,17 +16,20 @@
 )]
 #![cfg_attr(not(test), warn(clippy::unwrap_used))]
 
+mod backup;
 mod config;
 
 #[macro_use]
 mod database;
 mod cli;
+mod diff_data;
 mod errors;
 mod infer_schema_internals;
 mod migrations;
 mod print_schema;
 #[cfg(any(feature = "postgres", feature = "mysql"))]
 mod query_helper;
+mod seed;
 
 use clap::Parser;
 
@@ -34,7 +37,6 @@ use database::InferConnection;
 use diesel::Connection;
 use diesel::backend::Backend;
 use diesel_migrations::{FileBasedMigrations, HarnessWithOutput, MigrationHarness};
-use similar_asserts::SimpleDiff;
 use std::error::Error;
 use std::path::{Path, PathBuf};
 use std::{env, fs};
@@ -73,6 +75,10 @@ fn inner_main() -> Result<(), crate::errors::Error> {
 
     let cli = Cli::parse();
 
+    if let Some(backend) = database::backend_override(cli.backend)? {
+        database::set_backend_override(backend);
+    }
+
     let database_url = cli.database_url;
     let config_file = cli.config_file;
     let locked_schema = cli.locked_schema;
@@ -105,6 +111,9 @@ fn inner_main() -> Result<(), crate::errors::Error> {
         DieselCliCommand::PrintSchema(args) => {
             self::print_schema::run_infer_schema(args, config_file, database_url)?
         }
+        DieselCliCommand::Seed(args) => {
+            self::seed::run_seed_command(args, config_file, database_url)?
+        }
     }
 
     Ok(())
@@ -294,6 +303,7 @@ fn regenerate_schema_if_file_specified(
     for config in config.all_configs.values() {
         if let Some(ref path) = config.file {
             let mut connection = InferConnection::from_maybe_url(database_url.clone())?;
+            let resolved_database_url = database::database_url(database_url.clone()).ok();
             if let Some(parent) = path.parent() {
                 fs::create_dir_all(parent)
                     .map_err(|e| crate::errors::Error::IoError(e, Some(parent.to_owned())))?;
@@ -301,6 +311,7 @@ fn regenerate_schema_if_file_specified(
 
             let schema = print_schema::output_schema(
                 &mut connection,
+                resolved_database_url.as_deref(),
                 config,
                 multi_schema_safe_tables.as_deref(),
                 multi_schema_table_prefixes.as_ref(),
@@ -309,13 +320,7 @@ fn regenerate_schema_if_file_specified(
                 let old_buf = std::fs::read_to_string(path)
                     .map_err(|e| crate::errors::Error::IoError(e, Some(path.to_owned())))?;
 
-                if schema.lines().ne(old_buf.lines()) {
-                    let label = path.file_name().expect("We have a file name here");
-                    let label = label.to_string_lossy();
-                    println!(
-                        "{}",
-                        SimpleDiff::from_str(&old_buf, &schema, &label, "new schema")
-                    );
+                if print_schema::print_schema_diff(path, &old_buf, &schema) {
                     return Err(crate::errors::Error::SchemaWouldChange(
                         path.display().to_string(),
                     ));
@@ -0,0 +1,202 @@
+//! Refreshes the planner statistics of tables touched by applied migrations
+//!
+//! This is enabled by setting `analyze_after_migrations = true` in `diesel.toml`.
+
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::Path;
+use std::sync::LazyLock;
+
+use diesel::connection::SimpleConnection;
+use diesel::migration::MigrationVersion;
+use regex::Regex;
+
+use crate::database::{Backend, InferConnection};
+use crate::infer_schema_internals::{
+    SupportedQueryRelationStructures, TableName, load_table_names,
+};
+
+const IDENTIFIER: &str = r#"(?:"[^"]+"|`[^`]+`|\[[^\]]+\]|[A-Za-z_][A-Za-z0-9_$]*)"#;
+
+static TOUCHED_TABLE: LazyLock<Regex> = LazyLock::new(|| {
+    let statement = [
+        r"CREATE\s+(?:(?:GLOBAL\s+|LOCAL\s+)?(?:TEMP|TEMPORARY|UNLOGGED)\s+)?TABLE(?:\s+IF\s+NOT\s+EXISTS)?",
+        r"ALTER\s+TABLE(?:\s+IF\s+EXISTS)?(?:\s+ONLY)?",
+        r"INSERT\s+(?:OR\s+[A-Za-z]+\s+)?(?:IGNORE\s+)?INTO",
+        r"UPDATE(?:\s+ONLY)?",
+        r"DELETE\s+FROM(?:\s+ONLY)?",
+        r"CREATE\s+(?:UNIQUE\s+)?INDEX(?:\s+CONCURRENTLY)?(?:\s+IF\s+NOT\s+EXISTS)?(?:\s+\S+)?\s+ON(?:\s+ONLY)?",
+    ]
+    .join("|");
+    Regex::new(&format!(
+        r"(?i)\b(?:{statement})\s+({IDENTIFIER})(?:\s*\.\s*({IDENTIFIER}))?"
+    ))
+    .expect("The regex is valid")
+});
+
+/// Runs `ANALYZE` (or the backend specific equivalent) for each existing table
+/// that is modified by the `up.sql` of one of the applied migrations
+pub(super) fn analyze_migrated_tables(
+    conn: &mut InferConnection,
+    migrations_dir: &Path,
+    applied_versions: &[MigrationVersion<'_>],
+) -> Result<(), crate::errors::Error> {
+    if applied_versions.is_empty() {
+        return Ok(());
+    }
+    let sql = applied_migrations_sql(migrations_dir, applied_versions)?;
+    let touched = touched_tables(&sql);
+    if touched.is_empty() {
+        return Ok(());
+    }
+
+    let schemas = touched
+        .iter()
+        .map(|(schema, _)| schema.as_deref())
+        .collect::<BTreeSet<_>>();
+    let mut tables = Vec::new();
+    for schema in schemas {
+        let existing = load_table_names(conn, schema)?;
+        tables.extend(
+            existing
+                .into_iter()
+                .filter(|(kind, _)| *kind == SupportedQueryRelationStructures::Table)
+                .map(|(_, table)| table)
+                .filter(|table| {
+                    touched.iter().any(|(touched_schema, name)| {
+                        touched_schema.as_deref() == schema
+                            && name.eq_ignore_ascii_case(&table.sql_name)
+                    })
+                })
+                .map(|mut table| {
+                    if table.schema.is_none() {
+                        table.schema = schema.map(Into::into);
+                    }
+                    table
+                }),
+        );
+    }
+
+    let backend = Backend::for_connection(conn);
+    for table in &tables {
+        println!("Analyzing table {}", table.full_sql_name());
+        conn.batch_execute(&analyze_statement(backend, table))?;
+    }
+    #[cfg(feature = "sqlite")]
+    if matches!(backend, Backend::Sqlite) && !tables.is_empty() {
+        conn.batch_execute("PRAGMA optimize")?;
+    }
+    Ok(())
+}
+
+fn applied_migrations_sql(
+    migrations_dir: &Path,
+    applied_versions: &[MigrationVersion<'_>],
+) -> Result<String, crate::errors::Error> {
+    let applied_versions = applied_versions
+        .iter()
+        .map(ToString::to_string)
+        .collect::<BTreeSet<_>>();
+    let io_error = |e| crate::errors::Error::IoError(e, Some(migrations_dir.to_owned()));
+    let mut sql = String::new();
+    for entry in fs::read_dir(migrations_dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        let version = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.split('_').next())
+            .map(|version| version.replace('-', ""));
+        let up_sql = path.join("up.sql");
+        if version.is_some_and(|v| applied_versions.contains(&v)) && up_sql.is_file() {
+            let content = fs::read_to_string(&up_sql)
+                .map_err(|e| crate::errors::Error::IoError(e, Some(up_sql)))?;
+            sql.push_str(&content);
+            sql.push('\n');
+        }
+    }
+    Ok(sql)
+}
+
+/// Returns the (optionally schema qualified) names of all tables created or
+/// modified by the given SQL
+fn touched_tables(sql: &str) -> BTreeSet<(Option<String>, String)> {
+    TOUCHED_TABLE
+        .captures_iter(sql)
+        .map(|captures| {
+            let first = unquote(&captures[1]);
+            match captures.get(2) {
+                Some(table) => (Some(first), unquote(table.as_str())),
+                None => (None, first),
+            }
+        })
+        .collect()
+}
+
+fn unquote(identifier: &str) -> String {
+    let mut chars = identifier.chars();
+    match (chars.next(), chars.next_back()) {
+        (Some('"'), Some('"')) | (Some('`'), Some('`')) | (Some('['), Some(']')) => {
+            chars.as_str().to_owned()
+        }
+        _ => identifier.to_owned(),
+    }
+}
+
+fn analyze_statement(backend: Backend, table: &TableName) -> String {
+    let quote = |identifier: &str| match backend {
+        #[cfg(feature = "mysql")]
+        Backend::Mysql => format!("`{}`", identifier.replace('`', "``")),
+        #[cfg(any(feature = "postgres", feature = "sqlite"))]
+        _ => format!("\"{}\"", identifier.replace('"', "\"\"")),
+    };
+    let name = match table.schema {
+        Some(ref schema) => format!("{}.{}", quote(schema), quote(&table.sql_name)),
+        None => quote(&table.sql_name),
+    };
+    match backend {
+        #[cfg(feature = "mysql")]
+        Backend::Mysql => format!("ANALYZE TABLE {name}"),
+        #[cfg(any(feature = "postgres", feature = "sqlite"))]
+        _ => format!("ANALYZE {name}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::touched_tables;
+
+    #[test]
+    fn touched_tables_are_extracted_from_sql() {
+        let sql = r#"
+            CREATE TABLE users (id SERIAL PRIMARY KEY);
+            CREATE TABLE IF NOT EXISTS "Posts" (id INTEGER);
+            ALTER TABLE ONLY app.comments ADD COLUMN body TEXT;
+            INSERT OR IGNORE INTO `tags` VALUES (1);
+            UPDATE users SET name = 'Sean';
+            DELETE FROM [likes];
+            CREATE UNIQUE INDEX CONCURRENTLY idx_name ON "app"."followers" (user_id);
+            DROP TABLE old_users;
+        "#;
+
+        let mut tables = touched_tables(sql)
+            .into_iter()
+            .map(|(schema, name)| match schema {
+                Some(schema) => format!("{schema}.{name}"),
+                None => name,
+            })
+            .collect::<Vec<_>>();
+        tables.sort();
+
+        assert_eq!(
+            vec![
+                "Posts",
+                "app.comments",
+                "app.followers",
+                "likes",
+                "tags",
+                "users"
+            ],
+            tables
+        );
+    }
+}
@@ -41,7 +41,21 @@ pub fn generate_sql_based_on_diff_schema(
     let content = std::fs::read_to_string(&schema_path)
         .map_err(|e| crate::errors::Error::IoError(e, Some(schema_path.clone())))?;
 
-    let syn_file = syn::parse_file(&content)?;
+    generate_sql_based_on_schema_content(config, database_url, &content, true)
+}
+
+/// Generates the migration for the difference between the tables declared
+/// in `content` and the database
+///
+/// Tables that only exist in the database are dropped if
+/// `drop_missing_tables` is set and ignored otherwise.
+pub(super) fn generate_sql_based_on_schema_content(
+    mut config: PrintSchema,
+    database_url: Option<String>,
+    content: &str,
+    drop_missing_tables: bool,
+) -> Result<(String, String), crate::errors::Error> {
+    let syn_file = syn::parse_file(content)?;
 
     let mut tables_from_schema = SchemaCollector::default();
 
@@ -72,9 +86,11 @@ pub fn generate_sql_based_on_diff_schema(
 
     let mut table_pk_key_list = HashMap::new();
     let mut expected_schema_map = HashMap::new();
+    let mut table_order = Vec::new();
 
     for t in tables_from_schema.table_decls {
         let t = t?;
+        table_order.push(t.view.table_name.to_string());
         let keys = t.primary_keys.as_ref().map(|keys| {
             keys.keys
                 .iter()
@@ -135,6 +151,8 @@ pub fn generate_sql_based_on_diff_schema(
                         ));
                     }
                     schema_diff.push(update_columns(view, columns.column_data)?);
+                } else if !drop_missing_tables {
+                    tracing::info!("Table does not exist in schema, skipping");
                 } else {
                     tracing::info!("Table does not exist yet");
                     let foreign_keys = foreign_key_map
@@ -164,7 +182,10 @@ pub fn generate_sql_based_on_diff_schema(
         }
     }
 
-    schema_diff.extend(expected_schema_map.into_values().map(|t| {
+    let tables_to_create =
+        order_tables_to_create(&table_order, expected_schema_map, &expected_fk_map);
+    let created_tables = tables_to_create.len();
+    schema_diff.extend(tables_to_create.into_iter().map(|t| {
         tracing::info!(table = ?t.view.sql_name, "Tables does not exist in database");
         let foreign_keys = expected_fk_map
             .remove(&t.view.table_name.to_string())
@@ -187,8 +208,17 @@ pub fn generate_sql_based_on_diff_schema(
 
     let mut up_sql = String::new();
     let mut down_sql = String::new();
+    // new tables are dropped in reverse order, so that tables are
+    // dropped before the tables they reference
+    let mut created_tables_down_sql = Vec::with_capacity(created_tables);
 
     for diff in schema_diff {
+        #[cfg(feature = "sqlite")]
+        if matches!(conn, InferConnection::Sqlite(_)) && diff.changes_generated_columns() {
+            return Err(crate::errors::Error::UnsupportedFeature(
+                "SQLite cannot add stored generated columns to existing tables".into(),
+            ));
+        }
         let up = match conn {
             #[cfg(feature = "postgres")]
             InferConnection::Pg(_) => {
@@ -232,6 +262,14 @@ pub fn generate_sql_based_on_diff_schema(
         };
         up_sql += &up;
         up_sql += "\n";
+        if matches!(diff, SchemaDiff::CreateTable { .. }) {
+            created_tables_down_sql.push(down);
+        } else {
+            down_sql += &down;
+            down_sql += "\n";
+        }
+    }
+    for down in created_tables_down_sql.into_iter().rev() {
         down_sql += &down;
         down_sql += "\n";
     }
@@ -239,6 +277,38 @@ pub fn generate_sql_based_on_diff_schema(
     Ok((up_sql, down_sql))
 }
 
+/// Orders the tables that do not exist in the database yet, so that
+/// tables are created after the tables they reference
+///
+/// Apart from that the order of the schema file is kept.
+fn order_tables_to_create(
+    table_order: &[String],
+    mut tables: HashMap<String, TableDecl>,
+    foreign_keys: &HashMap<String, Vec<Joinable>>,
+) -> Vec<TableDecl> {
+    let mut pending = table_order
+        .iter()
+        .filter(|t| tables.contains_key(*t))
+        .cloned()
+        .collect::<Vec<_>>();
+    let mut ordered = Vec::with_capacity(pending.len());
+    while !pending.is_empty() {
+        let next = pending
+            .iter()
+            .position(|t| {
+                foreign_keys.get(t).into_iter().flatten().all(|fk| {
+                    let parent = fk.parent_table.to_string();
+                    parent == *t || !pending.contains(&parent)
+                })
+            })
+            // cyclic references cannot be ordered, so keep the schema order
+            .unwrap_or(0);
+        let table = pending.remove(next);
+        ordered.extend(tables.remove(&table));
+    }
+    ordered
+}
+
 fn update_columns(
     view: ViewDecl,
     columns: Vec<ColumnDefinition>,
@@ -259,6 +329,12 @@ fn update_columns(
             if !is_same_type(&c.ty, tpe) {
                 tracing::info!(old = ?c, new = ?def.sql_name, "Column changed type");
                 changed_columns.push((c, def));
+            } else if !is_same_generation_expression(
+                c.generated_always_as.as_deref(),
+                generation_expression(&def).as_deref(),
+            ) {
+                tracing::info!(old = ?c, new = ?def.sql_name, "Column changed generation expression");
+                changed_columns.push((c, def));
             }
         } else {
             tracing::info!(column = ?c, "Column was removed");
@@ -317,6 +393,50 @@ fn is_same_type(ty: &ColumnType, tpe: ColumnType) -> bool {
     false
 }
 
+fn generation_expression(c: &ColumnDef) -> Option<String> {
+    c.generated_always_as.as_ref().map(syn::LitStr::value)
+}
+
+/// Compares generation expressions ignoring whitespace, case and
+/// parentheses around the whole expression, as databases return the
+/// expression in a normalized form
+fn is_same_generation_expression(in_database: Option<&str>, in_schema: Option<&str>) -> bool {
+    fn normalize(expression: &str) -> String {
+        let mut normalized = expression
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+        while let Some(inner) = normalized
+            .strip_prefix('(')
+            .and_then(|n| n.strip_suffix(')'))
+            .filter(|inner| is_balanced(inner))
+        {
+            normalized = inner.to_owned();
+        }
+        normalized
+    }
+
+    fn is_balanced(expression: &str) -> bool {
+        let mut depth = 0_usize;
+        expression.chars().all(|c| {
+            match c {
+                '(' => depth += 1,
+                ')' if depth == 0 => return false,
+                ')' => depth -= 1,
+                _ => {}
+            }
+            true
+        }) && depth == 0
+    }
+
+    match (in_database, in_schema) {
+        (None, None) => true,
+        (Some(in_database), Some(in_schema)) => normalize(in_database) == normalize(in_schema),
+        _ => false,
+    }
+}
+
 #[allow(clippy::enum_variant_names)]
 enum SchemaDiff {
     DropTable {
@@ -337,6 +457,30 @@ enum SchemaDiff {
 }
 
 impl SchemaDiff {
+    /// Whether applying this diff or reverting it adds generated
+    /// columns to an existing table
+    #[cfg(feature = "sqlite")]
+    fn changes_generated_columns(&self) -> bool {
+        match self {
+            SchemaDiff::DropTable { .. } | SchemaDiff::CreateTable { .. } => false,
+            SchemaDiff::ChangeTable {
+                added_columns,
+                removed_columns,
+                changed_columns,
+                ..
+            } => {
+                added_columns
+                    .iter()
+                    .chain(changed_columns.iter().map(|(_, c)| c))
+                    .any(|c| c.generated_always_as.is_some())
+                    || removed_columns
+                        .iter()
+                        .chain(changed_columns.iter().map(|(c, _)| c))
+                        .any(|c| c.generated_always_as.is_some())
+            }
+        }
+    }
+
     fn generate_up_sql<DB>(
         &self,
         query_builder: &mut impl QueryBuilder<DB>,
@@ -370,6 +514,8 @@ impl SchemaDiff {
                             rust_name: c.sql_name.clone(),
                             ty,
                             comment: None,
+                            generated_always_as: generation_expression(c),
+                            generated_as_identity: false,
                         })
                     })
                     .collect::<Result<Vec<_>, crate::errors::Error>>()?;
@@ -423,6 +569,7 @@ impl SchemaDiff {
                         &table.to_lowercase(),
                         &c.column_name.to_string().to_lowercase(),
                         &ColumnType::for_column_def(c)?,
+                        generation_expression(c).as_deref(),
                     )?;
                     query_builder.push_sql("\n");
                 }
@@ -482,6 +629,8 @@ impl SchemaDiff {
                             rust_name: c.sql_name.clone(),
                             ty,
                             comment: None,
+                            generated_always_as: generation_expression(c),
+                            generated_as_identity: false,
                         })
                     })
                     .collect::<Result<Vec<_>, _>>()?;
@@ -521,6 +670,7 @@ impl SchemaDiff {
                         &table.to_lowercase(),
                         &c.sql_name.to_lowercase(),
                         &c.ty,
+                        c.generated_always_as.as_deref(),
                     )?;
                     query_builder.push_sql("\n");
                 }
@@ -543,6 +693,8 @@ fn extract_record_types_from_changed_columns(
                 rust_name: c.sql_name.clone(),
                 ty,
                 comment: None,
+                generated_always_as: generation_expression(c),
+                generated_as_identity: false,
             })
         })
         .chain(changed_columns.iter().map(|(c, _)| Ok(c.clone())))
@@ -676,6 +828,7 @@ where
             record_type,
             &format!("{}_RECORD_FIELD_{idx}", column_name.to_uppercase()),
             true,
+            None,
         )?;
         if idx != record_types.len() - 1 {
             query_builder.push_sql(",");
@@ -691,6 +844,7 @@ fn generate_add_column<DB>(
     table: &str,
     column: &str,
     ty: &ColumnType,
+    generated_always_as: Option<&str>,
 ) -> QueryResult<()>
 where
     DB: Backend,
@@ -699,7 +853,7 @@ where
     query_builder.push_identifier(table)?;
     query_builder.push_sql(" ADD COLUMN ");
     query_builder.push_identifier(column)?;
-    generate_column_type_name(query_builder, ty, column, false)?;
+    generate_column_type_name(query_builder, ty, column, false, generated_always_as)?;
     query_builder.push_sql(";");
     Ok(())
 }
@@ -764,9 +918,21 @@ where
                 sql_name: "Integer".into(),
                 ..column.ty.clone()
             };
-            generate_column_type_name(query_builder, &ty, &column.sql_name, false)?;
+            generate_column_type_name(
+                query_builder,
+                &ty,
+                &column.sql_name,
+                false,
+                column.generated_always_as.as_deref(),
+            )?;
         } else {
-            generate_column_type_name(query_builder, &column.ty, &column.sql_name, false)?;
+            generate_column_type_name(
+                query_builder,
+                &column.ty,
+                &column.sql_name,
+                false,
+                column.generated_always_as.as_deref(),
+            )?;
         }
 
         if is_only_primary_key {
@@ -818,6 +984,7 @@ fn generate_column_type_name<DB>(
     ty: &ColumnType,
     column_name: &str,
     for_record: bool,
+    generated_always_as: Option<&str>,
 ) -> QueryResult<()>
 where
     DB: Backend,
@@ -836,6 +1003,12 @@ where
     if let Some(max_length) = ty.max_length {
         query_builder.push_sql(&format!("({max_length})"));
     }
+    // MySQL requires the generation clause to directly follow the column type
+    if let Some(expression) = generated_always_as {
+        query_builder.push_sql(" GENERATED ALWAYS AS (");
+        query_builder.push_sql(expression);
+        query_builder.push_sql(") STORED");
+    }
     if !for_record {
         if !ty.is_nullable {
             query_builder.push_sql(" NOT NULL");
@@ -0,0 +1,155 @@
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use diesel::Connection;
+use diesel::backend::Backend;
+use diesel_migrations::MigrationHarness;
+
+/// A migration directory which needs a new version
+#[derive(Debug)]
+struct Renaming {
+    from: PathBuf,
+    to: PathBuf,
+}
+
+/// Gives unapplied migrations whose version sorts before the latest applied
+/// migration a fresh version based on the current timestamp
+///
+/// This happens after merging branches which both added migrations: the
+/// migrations of one branch are applied first, the other ones would then
+/// be run out of order. The relative order of the renumbered migrations
+/// is kept. The caller is expected to hold the lock of the migrations folder.
+pub(super) fn fix_timestamps<Conn, DB>(
+    conn: &mut Conn,
+    migrations_folder: &Path,
+    dry_run: bool,
+) -> Result<(), crate::errors::Error>
+where
+    Conn: MigrationHarness<DB> + Connection<Backend = DB> + 'static,
+    DB: Backend,
+{
+    let applied_versions = conn
+        .applied_migrations()
+        .map_err(crate::errors::Error::MigrationError)?
+        .into_iter()
+        .map(|v| v.to_string())
+        .collect::<HashSet<_>>();
+    let Some(latest_applied) = applied_versions.iter().max().cloned() else {
+        println!("No migrations have been applied yet, nothing to renumber");
+        return Ok(());
+    };
+
+    let mut directories = migration_directories(migrations_folder)?;
+    directories.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let out_of_order = directories
+        .iter()
+        .filter(|(version, _)| !applied_versions.contains(version) && *version < latest_applied)
+        .map(|(_, dir_name)| dir_name.as_str())
+        .collect::<Vec<_>>();
+    if out_of_order.is_empty() {
+        println!("All pending migrations are ordered after the applied ones, nothing to renumber");
+        return Ok(());
+    }
+
+    let timestamp = Utc::now().format(crate::TIMESTAMP_FORMAT).to_string();
+    if version_from_dir_name(&format!("{timestamp}-0000")) <= latest_applied {
+        return Err(crate::errors::Error::AppliedMigrationVersionTooRecent(
+            migrations_folder.to_path_buf(),
+            latest_applied,
+        ));
+    }
+
+    let renamings = out_of_order
+        .into_iter()
+        .enumerate()
+        .map(|(subversion, dir_name)| {
+            let name = dir_name.split_once('_').map_or("", |(_, name)| name);
+            let new_version = format!("{timestamp}-{subversion:04x}");
+            let new_dir_name = if name.is_empty() {
+                new_version
+            } else {
+                format!("{new_version}_{name}")
+            };
+            Renaming {
+                from: migrations_folder.join(dir_name),
+                to: migrations_folder.join(new_dir_name),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    if let Some(existing) = renamings.iter().find(|r| r.to.exists()) {
+        return Err(crate::errors::Error::IoError(
+            std::io::ErrorKind::AlreadyExists.into(),
+            Some(existing.to.clone()),
+        ));
+    }
+
+    for renaming in &renamings {
+        println!(
+            "{} {} to {}",
+            if dry_run { "Would rename" } else { "Renaming" },
+            file_name(&renaming.from),
+            file_name(&renaming.to),
+        );
+    }
+    if !dry_run {
+        rename_all(&renamings)?;
+    }
+    Ok(())
+}
+
+/// Returns the version and the name of each migration directory
+fn migration_directories(
+    migrations_folder: &Path,
+) -> Result<Vec<(String, String)>, crate::errors::Error> {
+    let io_error = |e| crate::errors::Error::IoError(e, Some(migrations_folder.to_path_buf()));
+    let mut directories = Vec::new();
+    for entry in migrations_folder.read_dir().map_err(io_error)? {
+        let entry = entry.map_err(io_error)?;
+        let dir_name = entry.file_name().to_string_lossy().into_owned();
+        if dir_name.starts_with('.') || !entry.path().join("up.sql").is_file() {
+            continue;
+        }
+        directories.push((version_from_dir_name(&dir_name), dir_name));
+    }
+    Ok(directories)
+}
+
+/// The version diesel records for a migration directory,
+/// everything before the first `_` without dashes
+fn version_from_dir_name(dir_name: &str) -> String {
+    dir_name
+        .split('_')
+        .next()
+        .unwrap_or_default()
+        .replace('-', "")
+}
+
+/// Renames all directories, undoing the already performed renamings
+/// if one of them fails
+fn rename_all(renamings: &[Renaming]) -> Result<(), crate::errors::Error> {
+    for (done, renaming) in renamings.iter().enumerate() {
+        if let Err(e) = fs::rename(&renaming.from, &renaming.to) {
+            for renaming in renamings[..done].iter().rev() {
+                if let Err(undo_error) = fs::rename(&renaming.to, &renaming.from) {
+                    eprintln!(
+                        "Failed to rename {} back to {}: {undo_error}",
+                        renaming.to.display(),
+                        renaming.from.display()
+                    );
+                }
+            }
+            return Err(crate::errors::Error::IoError(
+                e,
+                Some(renaming.from.clone()),
+            ));
+        }
+    }
+    Ok(())
+}
+
+fn file_name(path: &Path) -> std::borrow::Cow<'_, str> {
+    path.file_name().unwrap_or_default().to_string_lossy()
+}
@@ -0,0 +1,435 @@
+use heck::ToSnakeCase;
+use std::collections::HashMap;
+use std::fmt::Write;
+use std::path::Path;
+use syn::parse::{Parse, ParseStream};
+use syn::visit::Visit;
+
+use crate::config::PrintSchema;
+
+/// Generates a migration creating the tables of all structs in `entity_file_path`
+/// that are annotated with `#[diesel(table_name = …)]`
+///
+/// The entities are translated into `table!` and `joinable!` declarations,
+/// which are then compared to the database in the same way as `--diff-schema`
+/// does. Tables that are not used by any entity are left untouched.
+#[tracing::instrument]
+pub fn generate_sql_based_on_entities(
+    mut config: PrintSchema,
+    database_url: Option<String>,
+    entity_file_path: &Path,
+    table_name: Vec<String>,
+    only_tables: Vec<bool>,
+    except_tables: Vec<bool>,
+) -> Result<(String, String), crate::errors::Error> {
+    config.set_filter(&table_name, &only_tables, &except_tables)?;
+
+    let project_root = crate::find_project_root()?;
+
+    let entity_path = project_root.join(entity_file_path);
+    let content = std::fs::read_to_string(&entity_path)
+        .map_err(|e| crate::errors::Error::IoError(e, Some(entity_path.clone())))?;
+
+    let syn_file = syn::parse_file(&content)?;
+    let mut collector = EntityCollector::default();
+    collector.visit_file(&syn_file);
+    if let Some(error) = collector.errors.into_iter().reduce(|mut a, b| {
+        a.combine(b);
+        a
+    }) {
+        return Err(error.into());
+    }
+
+    let schema = schema_for_entities(collector.entities)?;
+    tracing::debug!(%schema, "Schema generated from entities");
+    super::diff_schema::generate_sql_based_on_schema_content(config, database_url, &schema, false)
+}
+
+struct Entity {
+    struct_name: syn::Ident,
+    table_name: syn::Ident,
+    primary_keys: Option<Vec<syn::Ident>>,
+    /// Parent structs with the optional name of the foreign key column
+    belongs_to: Vec<(syn::Ident, Option<syn::Ident>)>,
+    columns: Vec<EntityColumn>,
+    /// Whether the struct represents a whole row, in which case
+    /// it takes precedence over other structs for the same table
+    is_queryable: bool,
+}
+
+struct EntityColumn {
+    name: syn::Ident,
+    rust_type: syn::Type,
+    sql_type: Option<syn::Type>,
+}
+
+#[derive(Default)]
+struct EntityCollector {
+    entities: Vec<Entity>,
+    errors: Vec<syn::Error>,
+}
+
+impl<'ast> Visit<'ast> for EntityCollector {
+    fn visit_item_struct(&mut self, i: &'ast syn::ItemStruct) {
+        match parse_entity(i) {
+            Ok(Some(entity)) => self.entities.push(entity),
+            Ok(None) => {}
+            Err(e) => self.errors.push(e),
+        }
+        syn::visit::visit_item_struct(self, i)
+    }
+}
+
+fn parse_entity(item: &syn::ItemStruct) -> syn::Result<Option<Entity>> {
+    let mut table_name = None;
+    let mut primary_keys = None;
+    let mut belongs_to = Vec::new();
+    for attr in item.attrs.iter().filter(|a| a.path().is_ident("diesel")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("table_name") {
+                let path = meta.value()?.parse::<syn::Path>()?;
+                table_name = path.segments.last().map(|s| s.ident.clone());
+            } else if meta.path.is_ident("primary_key") {
+                primary_keys = Some(parse_idents_in_parens(meta.input)?);
+            } else if meta.path.is_ident("belongs_to") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                let parent = content.parse::<syn::Path>()?;
+                let mut foreign_key = None;
+                while content.parse::<Option<syn::Token![,]>>()?.is_some() && !content.is_empty() {
+                    let key = content.parse::<syn::Ident>()?;
+                    content.parse::<syn::Token![=]>()?;
+                    let value = content.parse::<syn::Ident>()?;
+                    if key == "foreign_key" {
+                        foreign_key = Some(value);
+                    }
+                }
+                let parent = parent.segments.last().map(|s| s.ident.clone());
+                belongs_to.extend(parent.map(|p| (p, foreign_key)));
+            } else {
+                skip_meta_value(meta.input)?;
+            }
+            Ok(())
+        })?;
+    }
+    let Some(table_name) = table_name else {
+        return Ok(None);
+    };
+
+    let is_queryable = item
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident("derive"))
+        .filter_map(|a| {
+            a.parse_args_with(
+                syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+            )
+            .ok()
+        })
+        .flatten()
+        .any(|p| {
+            p.segments
+                .last()
+                .is_some_and(|s| s.ident == "Queryable" || s.ident == "Selectable")
+        });
+
+    let mut columns = Vec::new();
+    for field in &item.fields {
+        let Some(mut name) = field.ident.clone() else {
+            return Err(syn::Error::new_spanned(
+                item,
+                "Entities with unnamed fields are not supported by `--from-entity`",
+            ));
+        };
+        let mut sql_type = None;
+        let mut embed = false;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("diesel")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("column_name") {
+                    name = meta.value()?.parse()?;
+                } else if meta.path.is_ident("sql_type") {
+                    sql_type = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("embed") {
+                    embed = true;
+                } else {
+                    skip_meta_value(meta.input)?;
+                }
+                Ok(())
+            })?;
+        }
+        // embedded structs are collected on their own
+        if !embed {
+            columns.push(EntityColumn {
+                name,
+                rust_type: field.ty.clone(),
+                sql_type,
+            });
+        }
+    }
+
+    Ok(Some(Entity {
+        struct_name: item.ident.clone(),
+        table_name,
+        primary_keys,
+        belongs_to,
+        columns,
+        is_queryable,
+    }))
+}
+
+fn parse_idents_in_parens(input: ParseStream<'_>) -> syn::Result<Vec<syn::Ident>> {
+    let content;
+    syn::parenthesized!(content in input);
+    Ok(content
+        .parse_terminated(syn::Ident::parse, syn::Token![,])?
+        .into_iter()
+        .collect())
+}
+
+/// Skips the value of an attribute option that is not relevant for the schema,
+/// like `= value` or `(…)`
+fn skip_meta_value(input: ParseStream<'_>) -> syn::Result<()> {
+    input.step(|cursor| {
+        let mut rest = *cursor;
+        while let Some((_, next)) = rest.token_tree() {
+            if matches!(rest.punct(), Some((p, _)) if p.as_char() == ',') {
+                break;
+            }
+            rest = next;
+        }
+        Ok(((), rest))
+    })
+}
+
+/// Translates the given entities into `table!` and `joinable!` declarations
+fn schema_for_entities(mut entities: Vec<Entity>) -> Result<String, crate::errors::Error> {
+    let table_for_struct = entities
+        .iter()
+        .map(|e| (e.struct_name.to_string(), e.table_name.to_string()))
+        .collect::<HashMap<_, _>>();
+    // structs representing whole rows determine the column order
+    entities.sort_by_key(|e| !e.is_queryable);
+
+    let mut tables = Vec::<(String, Option<Vec<String>>, Vec<(String, String)>)>::new();
+    let mut joinables = Vec::new();
+    for entity in entities {
+        let table_name = entity.table_name.to_string();
+        let index = match tables.iter().position(|(t, _, _)| *t == table_name) {
+            Some(index) => index,
+            None => {
+                tables.push((table_name.clone(), None, Vec::new()));
+                tables.len() - 1
+            }
+        };
+        let (_, primary_keys, columns) = &mut tables[index];
+        if primary_keys.is_none() {
+            *primary_keys = entity
+                .primary_keys
+                .map(|keys| keys.iter().map(ToString::to_string).collect());
+        }
+        for column in entity.columns {
+            let name = column.name.to_string();
+            if columns.iter().any(|(c, _)| *c == name) {
+                continue;
+            }
+            let sql_type = match column.sql_type {
+                Some(sql_type) => quote_type(&sql_type),
+                None => sql_type_for_rust_type(&column.rust_type).ok_or_else(|| {
+                    crate::errors::Error::UnsupportedFeature(format!(
+                        "Cannot infer the SQL type of `{}.{name}` from `{}`. \
+                         Add a `#[diesel(sql_type = …)]` attribute to the field",
+                        entity.struct_name,
+                        quote_type(&column.rust_type),
+                    ))
+                })?,
+            };
+            columns.push((name, sql_type));
+        }
+        for (parent, foreign_key) in entity.belongs_to {
+            let Some(parent_table) = table_for_struct.get(&parent.to_string()) else {
+                tracing::warn!(%parent, "Skipping association with unknown entity");
+                continue;
+            };
+            let foreign_key = foreign_key
+                .map(|f| f.to_string())
+                .unwrap_or_else(|| format!("{}_id", parent.to_string().to_snake_case()));
+            let joinable =
+                format!("diesel::joinable!({table_name} -> {parent_table} ({foreign_key}));\n");
+            if !joinables.contains(&joinable) {
+                joinables.push(joinable);
+            }
+        }
+    }
+
+    let mut schema = String::new();
+    for (table, primary_keys, columns) in tables {
+        let primary_keys = primary_keys.unwrap_or_else(|| vec!["id".into()]);
+        writeln!(schema, "diesel::table! {{")?;
+        writeln!(schema, "    {table} ({}) {{", primary_keys.join(", "))?;
+        for (column, sql_type) in columns {
+            writeln!(schema, "        {column} -> {sql_type},")?;
+        }
+        writeln!(schema, "    }}")?;
+        writeln!(schema, "}}\n")?;
+    }
+    for joinable in joinables {
+        schema += &joinable;
+    }
+    Ok(schema)
+}
+
+fn quote_type(ty: &syn::Type) -> String {
+    let mut out = String::new();
+    write_type(ty, &mut out);
+    out
+}
+
+fn write_type(ty: &syn::Type, out: &mut String) {
+    match ty {
+        syn::Type::Path(p) => {
+            for (idx, segment) in p.path.segments.iter().enumerate() {
+                if idx > 0 {
+                    out.push_str("::");
+                }
+                out.push_str(&segment.ident.to_string());
+                if let syn::PathArguments::AngleBracketed(args) = &segment.arguments {
+                    out.push('<');
+                    let types = args.args.iter().filter_map(|a| match a {
+                        syn::GenericArgument::Type(t) => Some(t),
+                        _ => None,
+                    });
+                    for (idx, t) in types.enumerate() {
+                        if idx > 0 {
+                            out.push_str(", ");
+                        }
+                        write_type(t, out);
+                    }
+                    out.push('>');
+                }
+            }
+        }
+        syn::Type::Reference(r) => {
+            out.push('&');
+            write_type(&r.elem, out);
+        }
+        syn::Type::Slice(s) => {
+            out.push('[');
+            write_type(&s.elem, out);
+            out.push(']');
+        }
+        syn::Type::Tuple(t) => {
+            out.push('(');
+            for (idx, t) in t.elems.iter().enumerate() {
+                if idx > 0 {
+                    out.push_str(", ");
+                }
+                write_type(t, out);
+            }
+            out.push(')');
+        }
+        _ => out.push('_'),
+    }
+}
+
+/// Maps the Rust type of a field to the SQL type diesel uses by default
+/// for that type
+fn sql_type_for_rust_type(ty: &syn::Type) -> Option<String> {
+    let path = match ty {
+        syn::Type::Reference(r) => return sql_type_for_rust_type(&r.elem),
+        syn::Type::Slice(s) if is_u8(&s.elem) => return Some("Binary".into()),
+        syn::Type::Path(p) => &p.path,
+        _ => return None,
+    };
+    let last = path.segments.last()?;
+    let first_type_argument = || match &last.arguments {
+        syn::PathArguments::AngleBracketed(args) => args.args.iter().find_map(|a| match a {
+            syn::GenericArgument::Type(t) => Some(t),
+            _ => None,
+        }),
+        _ => None,
+    };
+    let sql_type = match last.ident.to_string().as_str() {
+        "Option" => format!(
+            "Nullable<{}>",
+            sql_type_for_rust_type(first_type_argument()?)?
+        ),
+        "Vec" if is_u8(first_type_argument()?) => "Binary".into(),
+        "Vec" => format!("Array<{}>", sql_type_for_rust_type(first_type_argument()?)?),
+        "Box" | "Cow" => sql_type_for_rust_type(first_type_argument()?)?,
+        "i8" => "TinyInt".into(),
+        "i16" => "SmallInt".into(),
+        "i32" => "Integer".into(),
+        "i64" => "BigInt".into(),
+        "u8" => "Unsigned<TinyInt>".into(),
+        "u16" => "Unsigned<SmallInt>".into(),
+        "u32" => "Unsigned<Integer>".into(),
+        "u64" => "Unsigned<BigInt>".into(),
+        "f32" => "Float".into(),
+        "f64" => "Double".into(),
+        "bool" => "Bool".into(),
+        "String" | "str" => "Text".into(),
+        "NaiveDateTime" | "PrimitiveDateTime" => "Timestamp".into(),
+        "DateTime" | "OffsetDateTime" => "Timestamptz".into(),
+        "NaiveDate" | "Date" => "Date".into(),
+        "NaiveTime" | "Time" => "Time".into(),
+        "Uuid" => "Uuid".into(),
+        "BigDecimal" => "Numeric".into(),
+        _ => return None,
+    };
+    Some(sql_type)
+}
+
+fn is_u8(ty: &syn::Type) -> bool {
+    matches!(ty, syn::Type::Path(p) if p.path.is_ident("u8"))
+}
+
+#[test]
+fn entities_are_translated_into_table_declarations() {
+    let file = syn::parse_quote! {
+        #[derive(Queryable, Selectable)]
+        #[diesel(table_name = crate::schema::users)]
+        struct User {
+            id: i32,
+            #[diesel(column_name = user_name)]
+            name: String,
+            bio: Option<String>,
+        }
+
+        #[derive(Insertable)]
+        #[diesel(table_name = users)]
+        struct NewUser<'a> {
+            #[diesel(column_name = user_name)]
+            name: &'a str,
+        }
+
+        #[derive(Queryable, Associations)]
+        #[diesel(table_name = posts, primary_key(post_id))]
+        #[diesel(belongs_to(User))]
+        struct Post {
+            post_id: i64,
+            user_id: i32,
+            #[diesel(sql_type = diesel::sql_types::Jsonb)]
+            data: serde_json::Value,
+            tags: Vec<String>,
+            payload: Vec<u8>,
+        }
+
+        struct NotAnEntity {
+            id: i32,
+        }
+    };
+    let mut collector = EntityCollector::default();
+    collector.visit_file(&file);
+    assert!(collector.errors.is_empty());
+
+    let schema = schema_for_entities(collector.entities).unwrap();
+    assert_eq!(
+        schema,
+        "diesel::table! {\n    users (id) {\n        id -> Integer,\n        \
+         user_name -> Text,\n        bio -> Nullable<Text>,\n    }\n}\n\n\
+         diesel::table! {\n    posts (post_id) {\n        post_id -> BigInt,\n        \
+         user_id -> Integer,\n        data -> diesel::sql_types::Jsonb,\n        \
+         tags -> Array<Text>,\n        payload -> Binary,\n    }\n}\n\n\
+         diesel::joinable!(posts -> users (user_id));\n"
+    );
+}
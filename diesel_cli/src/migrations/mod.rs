@@ -3,7 +3,7 @@ use chrono::Utc;
 use clap::{ArgAction, Args, Subcommand, ValueEnum};
 use diesel::Connection;
 use diesel::backend::Backend;
-use diesel::migration::{Migration, MigrationSource};
+use diesel::migration::{Migration, MigrationSource, MigrationVersion};
 use diesel_migrations::{FileBasedMigrations, HarnessWithOutput, MigrationError, MigrationHarness};
 use fd_lock::RwLock;
 use std::any::Any;
@@ -17,7 +17,10 @@ use std::{env, io};
 use crate::database::InferConnection;
 use crate::{config::Config, regenerate_schema_if_file_specified};
 
+mod analyze;
 mod diff_schema;
+mod fix_timestamps;
+mod from_entity;
 
 #[derive(Debug, Args)]
 pub struct MigrationArgs {
@@ -96,7 +99,20 @@ pub enum MigrationCommand {
     /// Returns true if there are any pending migrations.
     Pending,
 
+    /// Gives pending migrations which are ordered before already applied ones
+    /// a new version based on the current timestamp.
+    ///
+    /// This repairs the order of migrations after merging branches which
+    /// both added migrations. The migration directories are renamed,
+    /// their relative order is kept.
+    FixTimestamps {
+        /// Only print the renamings without performing them.
+        #[arg(long = "dry-run", action = ArgAction::SetTrue)]
+        dry_run: bool,
+    },
+
     /// Generate a new migration with the given name, and the current timestamp as the version.
+    #[command(group = clap::ArgGroup::new("SCHEMA_SOURCE").args(["SCHEMA_RS", "ENTITY_RS"]))]
     Generate {
         /// The name of the migration to create.
         #[arg(required = true, index = 1, required = true, num_args = 1)]
@@ -113,6 +129,11 @@ pub enum MigrationCommand {
         #[arg(short = 'u', long = "no-down", action = ArgAction::SetTrue)]
         no_down: bool,
 
+        /// Print the generated SQL instead of creating the migration.
+        /// Only used with the `--diff-schema` or `--from-entity` argument.
+        #[arg(long = "dry-run", requires = "SCHEMA_SOURCE", action = ArgAction::SetTrue)]
+        dry_run: bool,
+
         /// The format of the migration to be generated.
         #[arg(
             long = "format",
@@ -139,14 +160,31 @@ pub enum MigrationCommand {
         )]
         schema_rs: Option<String>,
 
+        /// Populate the generated migrations
+        /// based on the model structs in the given Rust file.
+        /// All structs with a `#[diesel(table_name = …)]`
+        /// attribute are considered. Tables that do not
+        /// exist in the database yet are created, other
+        /// tables are updated to match the structs.
+        /// Be sure to check whether the generated migrations
+        /// meet your expectations.
+        #[arg(
+            id = "ENTITY_RS",
+            long = "from-entity",
+            num_args = 1,
+            require_equals = true,
+            conflicts_with = "SCHEMA_RS"
+        )]
+        entity_rs: Option<PathBuf>,
+
         /// For SQLite 3.37 and above, detect `INTEGER PRIMARY KEY` columns as `BigInt`,
         /// when the table isn't declared with `WITHOUT ROWID`.
         /// See https://www.sqlite.org/lang_createtable.html#rowid for more information.
-        /// Only used with the `--diff-schema` argument.
+        /// Only used with the `--diff-schema` or `--from-entity` argument.
         #[arg(
             id = PrintSchemaArgs::SQLITE_INTEGER_PRIMARY_KEY_IS_BIGINT,
             long = "sqlite-integer-primary-key-is-bigint",
-            requires = "SCHEMA_RS",
+            requires = "SCHEMA_SOURCE",
             action = ArgAction::SetTrue
         )]
         sqlite_integer_primary_key_is_bigint: bool,
@@ -208,7 +246,15 @@ pub(super) fn run_migration_command(
             let (mut conn, dir) =
                 conn_and_migration_dir(migration_dir, database_url.clone(), config_file.clone())?;
 
-            run_migrations_with_output(&mut conn, dir)?;
+            let migrations_path = dir.path().to_owned();
+            let applied_versions = run_migrations_with_output(&mut conn, dir)?;
+            if Config::read(config_file.clone())?.analyze_after_migrations {
+                self::analyze::analyze_migrated_tables(
+                    &mut conn,
+                    &migrations_path,
+                    &applied_versions,
+                )?;
+            }
             if !no_schema {
                 regenerate_schema_if_file_specified(config_file, database_url, locked_schema)?;
             }
@@ -258,27 +304,33 @@ pub(super) fn run_migration_command(
                 .map_err(crate::errors::Error::MigrationError)?;
             println!("{result:?}");
         }
+        MigrationCommand::FixTimestamps { dry_run } => {
+            let mut conn = InferConnection::from_maybe_url(database_url)?;
+            let migrations_folder = migrations_dir(migration_dir, config_file)?;
+            let mut lock = RwLock::new(migration_folder_lock(migrations_folder.clone())?);
+            let _guard = lock.write().map_err(|err| {
+                crate::errors::Error::FailedToAcquireMigrationFolderLock(
+                    migrations_folder.clone(),
+                    err.to_string(),
+                )
+            })?;
+            self::fix_timestamps::fix_timestamps(&mut conn, &migrations_folder, dry_run)?;
+        }
         MigrationCommand::Generate {
             migration_name,
             version,
             no_down,
+            dry_run,
             format,
             schema_rs,
+            entity_rs,
             sqlite_integer_primary_key_is_bigint,
             table_name,
             only_tables,
             except_tables,
             schema_key,
         } => {
-            let migrations_folder = migrations_dir(migration_dir, config_file.clone())?;
-            let mut lock = RwLock::new(migration_folder_lock(migrations_folder.clone())?);
-            let _ = lock.write().map_err(|err| {
-                crate::errors::Error::FailedToAcquireMigrationFolderLock(
-                    migrations_folder.clone(),
-                    err.to_string(),
-                )
-            })?;
-            let (up_sql, down_sql) = if let Some(schema_rs_arg) = schema_rs {
+            let (up_sql, down_sql) = if schema_rs.is_some() || entity_rs.is_some() {
                 let schema_key = schema_key
                     .first()
                     .cloned()
@@ -296,26 +348,50 @@ pub(super) fn run_migration_command(
                     print_schema.sqlite_integer_primary_key_is_bigint = Some(true);
                 }
 
-                let diff_schema = if schema_rs_arg == "NOT_SET" {
-                    print_schema
-                        .file
-                        .clone()
-                        .ok_or(crate::errors::Error::NoSchemaKeyFound(schema_key))?
+                if let Some(entity_rs) = entity_rs {
+                    self::from_entity::generate_sql_based_on_entities(
+                        print_schema,
+                        database_url,
+                        &entity_rs,
+                        table_name,
+                        only_tables,
+                        except_tables,
+                    )?
                 } else {
-                    PathBuf::from(schema_rs_arg)
-                };
-                self::diff_schema::generate_sql_based_on_diff_schema(
-                    print_schema,
-                    database_url,
-                    &diff_schema,
-                    table_name,
-                    only_tables,
-                    except_tables,
-                )?
+                    let diff_schema = match schema_rs.as_deref() {
+                        Some("NOT_SET") | None => print_schema
+                            .file
+                            .clone()
+                            .ok_or(crate::errors::Error::NoSchemaKeyFound(schema_key))?,
+                        Some(schema_rs) => PathBuf::from(schema_rs),
+                    };
+                    self::diff_schema::generate_sql_based_on_diff_schema(
+                        print_schema,
+                        database_url,
+                        &diff_schema,
+                        table_name,
+                        only_tables,
+                        except_tables,
+                    )?
+                }
             } else {
                 (String::new(), String::new())
             };
 
+            if dry_run {
+                print_sql_migration(!no_down, &up_sql, &down_sql);
+                return Ok(());
+            }
+
+            let migrations_folder = migrations_dir(migration_dir, config_file.clone())?;
+            let mut lock = RwLock::new(migration_folder_lock(migrations_folder.clone())?);
+            let _ = lock.write().map_err(|err| {
+                crate::errors::Error::FailedToAcquireMigrationFolderLock(
+                    migrations_folder.clone(),
+                    err.to_string(),
+                )
+            })?;
+
             let explicit_version = version.is_some();
             let migration_version = migration_version(version);
             let migration_dir = create_migration_dir(
@@ -442,6 +518,25 @@ fn create_migration_dir<'a>(
     ))
 }
 
+const UP_SQL_HEADER: &str = "-- Your SQL goes here\n";
+const DOWN_SQL_HEADER: &str = "-- This file should undo anything in `up.sql`\n";
+
+/// Prints the content of the `up.sql` and `down.sql` files
+/// `generate_sql_migration` would create
+fn print_sql_migration(with_down: bool, up_sql: &str, down_sql: &str) {
+    fn print_file(name: &str, header: &str, sql: &str) {
+        print!("-- {name}\n{header}{sql}");
+        if !sql.is_empty() && !sql.ends_with('\n') {
+            println!();
+        }
+    }
+
+    print_file("up.sql", UP_SQL_HEADER, up_sql);
+    if with_down {
+        print_file("down.sql", DOWN_SQL_HEADER, down_sql);
+    }
+}
+
 fn generate_sql_migration(
     path: &Path,
     with_down: bool,
@@ -462,7 +557,7 @@ fn generate_sql_migration(
     );
     let mut up = fs::File::create(&up_path)
         .map_err(|e| crate::errors::Error::IoError(e, Some(up_path.clone())))?;
-    up.write_all(b"-- Your SQL goes here\n")
+    up.write_all(UP_SQL_HEADER.as_bytes())
         .map_err(|e| crate::errors::Error::IoError(e, Some(up_path.clone())))?;
     up.write_all(up_sql.as_bytes())
         .map_err(|e| crate::errors::Error::IoError(e, Some(up_path.clone())))?;
@@ -475,7 +570,7 @@ fn generate_sql_migration(
         );
         let mut down = fs::File::create(&down_path)
             .map_err(|e| crate::errors::Error::IoError(e, Some(down_path.clone())))?;
-        down.write_all(b"-- This file should undo anything in `up.sql`\n")
+        down.write_all(DOWN_SQL_HEADER.as_bytes())
             .map_err(|e| crate::errors::Error::IoError(e, Some(up_path.clone())))?;
         down.write_all(down_sql.as_bytes())
             .map_err(|e| crate::errors::Error::IoError(e, Some(up_path.clone())))?;
@@ -492,14 +587,14 @@ fn migration_version<'a>(matches: Option<String>) -> Box<dyn Display + 'a> {
 pub fn run_migrations_with_output<Conn, DB>(
     conn: &mut Conn,
     migrations: FileBasedMigrations,
-) -> Result<(), crate::errors::Error>
+) -> Result<Vec<MigrationVersion<'static>>, crate::errors::Error>
 where
     Conn: MigrationHarness<DB> + Connection<Backend = DB> + 'static,
     DB: Backend,
 {
     HarnessWithOutput::write_to_stdout(conn)
         .run_pending_migrations(migrations)
-        .map(|_| ())
+        .map(|versions| versions.iter().map(MigrationVersion::as_owned).collect())
         .map_err(crate::errors::Error::MigrationError)
 }
 
@@ -3,11 +3,14 @@ use crate::database::{Backend, InferConnection};
 use crate::infer_schema_internals::*;
 use clap::{ArgAction, ArgMatches, Args, FromArgMatches};
 use diesel::QueryResult;
+use heck::ToPascalCase;
 use serde::{Deserialize, Serialize};
+use similar_asserts::SimpleDiff;
 use std::borrow::Cow;
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt::{self, Display, Formatter, Write};
 use std::io::{Write as IoWrite, stdout};
+use std::path::Path;
 use std::{process, str};
 
 const SCHEMA_HEADER: &str = "// @generated automatically by Diesel CLI.\n";
@@ -23,6 +26,7 @@ pub struct PrintSchemaArgs {
     pub with_docs_indices: Option<Vec<usize>>,
     pub with_docs_config_indices: Option<Vec<usize>>,
     pub allow_tables_appear_in_same_query_indices: Option<Vec<usize>>,
+    pub allow_columns_appear_in_same_group_by_indices: Option<Vec<usize>>,
     pub patch_file_indices: Option<Vec<usize>>,
     pub column_sorting_indices: Option<Vec<usize>>,
     pub import_types_indices: Option<Vec<usize>>,
@@ -44,6 +48,8 @@ impl PrintSchemaArgs {
     const WITH_DOCS_CONFIG: &'static str = "WITH_DOCS_CONFIG";
     const ALLOW_TABLES_APPEAR_IN_SAME_QUERY: &'static str =
         "ALLOW_TABLES_TO_APPEAR_IN_SAME_QUERY_CONFIG";
+    const ALLOW_COLUMNS_APPEAR_IN_SAME_GROUP_BY: &'static str =
+        "ALLOW_COLUMNS_TO_APPEAR_IN_SAME_GROUP_BY_CLAUSE_CONFIG";
     const PATCH_FILE: &'static str = "PATCH_FILE";
     const COLUMN_SORTING: &'static str = "COLUMN_SORTING";
     const IMPORT_TYPES: &'static str = "IMPORT_TYPES";
@@ -67,6 +73,7 @@ impl PrintSchemaArgs {
             with_docs_indices,
             with_docs_config_indices,
             allow_tables_appear_in_same_query_indices,
+            allow_columns_appear_in_same_group_by_indices,
             patch_file_indices,
             column_sorting_indices,
             import_types_indices,
@@ -89,6 +96,10 @@ impl PrintSchemaArgs {
                 allow_tables_appear_in_same_query_indices,
                 Self::ALLOW_TABLES_APPEAR_IN_SAME_QUERY,
             ),
+            (
+                allow_columns_appear_in_same_group_by_indices,
+                Self::ALLOW_COLUMNS_APPEAR_IN_SAME_GROUP_BY,
+            ),
             (patch_file_indices, Self::PATCH_FILE),
             (column_sorting_indices, Self::COLUMN_SORTING),
             (import_types_indices, Self::IMPORT_TYPES),
@@ -131,6 +142,7 @@ impl FromArgMatches for PrintSchemaArgs {
             with_docs_indices: None,
             with_docs_config_indices: None,
             allow_tables_appear_in_same_query_indices: None,
+            allow_columns_appear_in_same_group_by_indices: None,
             patch_file_indices: None,
             column_sorting_indices: None,
             import_types_indices: None,
@@ -244,6 +256,18 @@ pub struct InnerPrintSchemaArgs {
     )]
     pub allow_tables_to_appear_in_same_query_config: Vec<AllowTablesToAppearInSameQueryConfig>,
 
+    /// Group tables in allow_columns_to_appear_in_same_group_by_clause!().
+    /// No invocation is generated if unspecified.
+    #[arg(
+        id = PrintSchemaArgs::ALLOW_COLUMNS_APPEAR_IN_SAME_GROUP_BY,
+        long = "allow-columns-to-appear-in-same-group-by-clause-config",
+        action = ArgAction::Append,
+        value_enum,
+        num_args = 1
+    )]
+    pub allow_columns_to_appear_in_same_group_by_clause_config:
+        Vec<AllowTablesToAppearInSameQueryConfig>,
+
     /// Sort order for table columns.
     #[arg(
         id = PrintSchemaArgs::COLUMN_SORTING,
@@ -313,6 +337,26 @@ pub struct InnerPrintSchemaArgs {
         value_parser = clap::value_parser!(bool),
     )]
     pub sqlite_integer_primary_key_is_bigint: Vec<bool>,
+    /// How to load the schema of MySQL databases.
+    #[arg(long = "mysql-introspection", value_enum, num_args = 1)]
+    pub mysql_introspection: Option<MysqlIntrospection>,
+    /// The number of connections used to load the schema of MySQL databases
+    /// with `--mysql-introspection show_commands`. Defaults to the value of
+    /// `--introspection-jobs` if set.
+    #[arg(long = "mysql-introspection-connections", num_args = 1)]
+    pub mysql_introspection_connections: Option<usize>,
+    /// The number of connections used to load the schema of individual tables
+    /// concurrently. Defaults to a single connection.
+    #[arg(long = "introspection-jobs", num_args = 1)]
+    pub introspection_jobs: Option<usize>,
+    /// The visibility of the generated modules, for example `pub(crate)`.
+    /// Defaults to `pub`.
+    #[arg(long = "schema-visibility", num_args = 1)]
+    pub schema_visibility: Option<String>,
+    /// Ignore the introspection cache enabled via `print_schema.introspection_cache`
+    /// and load the whole schema from the database.
+    #[arg(long = "no-cache", action = ArgAction::SetTrue)]
+    pub no_cache: bool,
     /// A list of derives to implement for every automatically generated Rust enum in the schema, separated by commas.
     #[arg(
         id = PrintSchemaArgs::CUSTOM_RUST_ENUM_TYPE_DERIVES,
@@ -324,6 +368,17 @@ pub struct InnerPrintSchemaArgs {
     /// Generate Rust enum type definitions for sql side enum types
     #[arg(long = "no-generate-rust-enum-types", action = ArgAction::SetTrue)]
     pub no_generate_rust_enum_types: bool,
+    /// Generate a newtype wrapper for the primary key of each table
+    /// with a single integer or text primary key column.
+    #[arg(long = "generate-id-types", action = ArgAction::SetTrue)]
+    pub generate_id_types: bool,
+    /// Print a diff between the configured schema files and the
+    /// regenerated schema instead of printing the schema.
+    ///
+    /// No file is written. The command exits with a non-zero exit code
+    /// if any schema file would change.
+    #[arg(long = "diff", action = ArgAction::SetTrue)]
+    pub diff: bool,
 }
 
 #[tracing::instrument]
@@ -334,7 +389,9 @@ pub fn run_infer_schema(
 ) -> Result<(), crate::errors::Error> {
     use crate::print_schema::*;
 
-    let mut conn = InferConnection::from_maybe_url(database_url)?;
+    let database_url = crate::database::database_url(database_url)?;
+    let mut conn = InferConnection::from_maybe_url(Some(database_url.clone()))?;
+    let diff = args.inner.diff;
     let root_config = Config::read(config_file)?
         .set_filter(&args)?
         .update_config(args)?
@@ -349,9 +406,19 @@ pub fn run_infer_schema(
     } else {
         None
     };
+    if diff {
+        return run_print_schema_diff(
+            &mut conn,
+            Some(&database_url),
+            &root_config,
+            multi_schema_safe_tables.as_deref(),
+            multi_schema_table_prefixes.as_ref(),
+        );
+    }
     for config in root_config.all_configs.values() {
         run_print_schema(
             &mut conn,
+            Some(&database_url),
             config,
             &mut stdout(),
             multi_schema_safe_tables.as_deref(),
@@ -362,6 +429,65 @@ pub fn run_infer_schema(
     Ok(())
 }
 
+fn run_print_schema_diff(
+    connection: &mut InferConnection,
+    database_url: Option<&str>,
+    root_config: &config::RootPrintSchema,
+    multi_schema_safe_tables: Option<&[TableName]>,
+    multi_schema_table_prefixes: Option<&BTreeMap<TableName, String>>,
+) -> Result<(), crate::errors::Error> {
+    if root_config.all_configs.values().all(|c| c.file.is_none()) {
+        return Err(crate::errors::Error::NoSchemaFileConfigured);
+    }
+    let mut changed_files = Vec::new();
+    for config in root_config.all_configs.values() {
+        let Some(ref path) = config.file else {
+            continue;
+        };
+        let schema = output_schema(
+            connection,
+            database_url,
+            config,
+            multi_schema_safe_tables,
+            multi_schema_table_prefixes,
+        )?;
+        // a missing schema file is reported as a diff against an empty file
+        let old_buf = match std::fs::read_to_string(path) {
+            Ok(old_buf) => old_buf,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(crate::errors::Error::IoError(e, Some(path.to_owned()))),
+        };
+        if print_schema_diff(path, &old_buf, &schema) {
+            changed_files.push(path.display().to_string());
+        }
+    }
+    if changed_files.is_empty() {
+        Ok(())
+    } else {
+        Err(crate::errors::Error::SchemaWouldChange(
+            changed_files.join("`, `"),
+        ))
+    }
+}
+
+/// Prints a diff between the schema file content at `path` and the
+/// newly generated schema
+///
+/// Returns `true` if the content differs
+pub(crate) fn print_schema_diff(path: &Path, old_buf: &str, schema: &str) -> bool {
+    if schema.lines().ne(old_buf.lines()) {
+        let label = path.file_name().expect("We have a file name here");
+        let label = label.to_string_lossy();
+        println!(
+            "{}",
+            SimpleDiff::from_str(old_buf, schema, &label, "new schema")
+        );
+        true
+    } else {
+        false
+    }
+}
+
 /// How to sort columns when querying the table schema.
 #[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, clap::ValueEnum)]
 #[clap(rename_all = "snake_case")]
@@ -375,6 +501,19 @@ pub enum ColumnSorting {
     Name,
 }
 
+/// How to load the schema of MySQL databases.
+#[derive(Debug, Default, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+#[clap(rename_all = "snake_case")]
+pub enum MysqlIntrospection {
+    /// Query the `information_schema` tables for each table
+    #[serde(rename = "information_schema")]
+    #[default]
+    InformationSchema,
+    /// Use `SHOW` commands, loading tables over several connections in parallel
+    #[serde(rename = "show_commands")]
+    ShowCommands,
+}
+
 #[derive(Clone, Copy, Debug, Default)]
 pub enum DocConfig {
     DatabaseCommentsFallbackToAutoGeneratedDocComment,
@@ -401,6 +540,7 @@ pub enum AllowTablesToAppearInSameQueryConfig {
 
 pub fn run_print_schema<W: IoWrite>(
     connection: &mut InferConnection,
+    database_url: Option<&str>,
     config: &config::PrintSchema,
     output: &mut W,
     multi_schema_safe_tables: Option<&[TableName]>,
@@ -408,6 +548,7 @@ pub fn run_print_schema<W: IoWrite>(
 ) -> Result<(), crate::errors::Error> {
     let schema = output_schema(
         connection,
+        database_url,
         config,
         multi_schema_safe_tables,
         multi_schema_table_prefixes,
@@ -474,6 +615,8 @@ fn pg_diesel_types() -> HashSet<&'static str> {
     types.insert("PgLsn");
     types.insert("Record");
     types.insert("Interval");
+    types.insert("Geometry");
+    types.insert("Geography");
 
     // hidden type defs
     types.insert("Int4range");
@@ -681,6 +824,13 @@ pub(crate) fn module_prefix_for_config(
     config: &config::PrintSchema,
     use_file_module_paths: bool,
 ) -> Option<String> {
+    if let Some(crate_name) = config.crate_name.as_ref().filter(|_| use_file_module_paths) {
+        // the schema is written to `src/schema.rs` of another crate
+        return Some(match config.schema_name() {
+            Some(pg_schema) => format!("{crate_name}::schema::{pg_schema}"),
+            None => format!("{crate_name}::schema"),
+        });
+    }
     match config.schema_name() {
         Some(pg_schema) => Some(if use_file_module_paths {
             let file = config.file.as_ref()?;
@@ -730,22 +880,30 @@ fn table_codegen_path<'a>(
     }
 }
 
-#[tracing::instrument(skip(connection))]
+#[tracing::instrument(skip(connection, database_url))]
 pub fn output_schema(
     connection: &mut InferConnection,
+    database_url: Option<&str>,
     config: &config::PrintSchema,
     multi_schema_safe_tables: Option<&[TableName]>,
     multi_schema_table_prefixes: Option<&BTreeMap<TableName, String>>,
 ) -> Result<String, crate::errors::Error> {
     let backend = Backend::for_connection(connection);
-    let unfiltered_table_names = load_table_names(connection, config.schema_name())?;
+    let mut preloaded = preload_schema(connection, database_url, config)?;
+    let unfiltered_table_names = match preloaded.table_names.take() {
+        Some(table_names) => table_names,
+        None => load_table_names(connection, config.schema_name())?,
+    };
     let table_names = filter_table_names(
         &unfiltered_table_names,
         &config.filter,
         config.include_views,
     );
 
-    let foreign_keys = load_foreign_key_constraints(connection, config.schema_name())?;
+    let foreign_keys = match preloaded.foreign_keys.take() {
+        Some(foreign_keys) => foreign_keys,
+        None => load_foreign_key_constraints(connection, config.schema_name())?,
+    };
     let fk_safe_tables: Cow<'_, [TableName]> = multi_schema_safe_tables
         .map(Cow::Borrowed)
         .unwrap_or_else(|| {
@@ -765,20 +923,38 @@ pub fn output_schema(
     let foreign_keys_for_allow_tables =
         filter_foreign_keys_for_grouping(&foreign_keys, &fk_safe_tables);
     let duplicate_foreign_keys = duplicated_foreign_keys(&foreign_keys);
-    let foreign_keys_for_joinable =
-        remove_unsafe_foreign_keys_for_codegen(connection, &foreign_keys, &fk_safe_tables)
-            .into_iter()
-            .filter(|fk| current_schema_safe_tables.contains(&fk.child_table))
-            .collect::<Vec<_>>();
+    let foreign_keys_for_joinable = remove_unsafe_foreign_keys_for_codegen(
+        connection,
+        &foreign_keys,
+        &fk_safe_tables,
+        &preloaded.primary_keys,
+    )
+    .into_iter()
+    .filter(|fk| current_schema_safe_tables.contains(&fk.child_table))
+    .collect::<Vec<_>>();
     let foreign_keys_for_joinable =
         remove_duplicated_foreign_keys(&foreign_keys_for_joinable, &duplicate_foreign_keys);
 
     let local_safe_tables: BTreeSet<TableName> =
         current_schema_safe_tables.iter().cloned().collect();
 
-    let resolver = SchemaResolverImpl::new(connection, table_names, config, unfiltered_table_names);
+    let resolver = SchemaResolverImpl::new(connection, table_names, config, unfiltered_table_names)
+        .with_preloaded_tables(preloaded.tables);
     let data = resolver.resolve_query_relations()?;
 
+    let visibility = config.schema_visibility()?;
+    let removed_tables = if config.removed_tables_module {
+        removed_tables(config, &data)
+    } else {
+        Vec::new()
+    };
+
+    let id_types = if config.generate_id_types {
+        id_types(connection, &data)?
+    } else {
+        Vec::new()
+    };
+
     let columns_custom_types = if config.generate_missing_sql_type_definitions() {
         Some(load_custom_types(connection, &data, config)?)
     } else {
@@ -792,6 +968,8 @@ pub fn output_schema(
         with_docs: config.with_docs,
         allow_tables_to_appear_in_same_query_config: config
             .allow_tables_to_appear_in_same_query_config,
+        allow_columns_to_appear_in_same_group_by_clause_config: config
+            .allow_columns_to_appear_in_same_group_by_clause_config,
         custom_types_for_tables: columns_custom_types.map(|t| CustomTypesForTables {
             backend,
             types_overrides_sorted: t.custom_type_list,
@@ -803,14 +981,24 @@ pub fn output_schema(
             sql_type_derives: config.custom_type_derives(),
             rust_type_derives: config.custom_rust_types_derives(),
             generate_rust_enums: config.generate_rust_enum_definitions(),
+            visibility: visibility.unwrap_or("pub").to_owned(),
         }),
         import_types: config.import_types(),
         local_safe_tables: &local_safe_tables,
         multi_schema_table_prefixes,
+        removed_tables,
+        id_types,
+        visibility,
     };
 
     let mut out = String::new();
-    writeln!(out, "{SCHEMA_HEADER}")?;
+    match config.header {
+        Some(ref header) => writeln!(out, "{}\n", header.trim_end())?,
+        None => writeln!(out, "{SCHEMA_HEADER}")?,
+    }
+    if let Some(module) = config.module_name()? {
+        writeln!(out, "{} mod {module} {{", visibility.unwrap_or("pub"))?;
+    }
     if let Some(schema_name) = config.schema_name() {
         write!(out, "{}", ModuleDefinition(schema_name, definitions))?;
     } else {
@@ -827,6 +1015,9 @@ pub fn output_schema(
 
         write!(out, "{definitions}")?;
     }
+    if config.module.is_some() {
+        writeln!(out, "}}")?;
+    }
 
     out = match format_schema(&out) {
         Ok(schema) => schema,
@@ -962,6 +1153,7 @@ struct CustomTypesForTables {
     sql_type_derives: BTreeSet<String>,
     rust_type_derives: BTreeSet<String>,
     generate_rust_enums: bool,
+    visibility: String,
 }
 
 pub struct CustomTypesForTablesForDisplay<'a> {
@@ -989,7 +1181,7 @@ impl Display for CustomTypesForTablesForDisplay<'_> {
                 }
                 let mut rust_types = Vec::new();
                 let mut out = PadAdapter::new(f);
-                writeln!(out, "pub mod sql_types {{")?;
+                writeln!(out, "{} mod sql_types {{", self.custom_types.visibility)?;
                 for (idx, &ct) in types_to_generate.iter().enumerate() {
                     let is_enum = if let Some(variants) = self
                         .custom_types
@@ -1047,7 +1239,12 @@ impl Display for CustomTypesForTablesForDisplay<'_> {
                 }
 
                 writeln!(f, "}}\n")?;
-                rust_enum_module(f, rust_types, self.custom_types.generate_rust_enums)?;
+                rust_enum_module(
+                    f,
+                    rust_types,
+                    self.custom_types.generate_rust_enums,
+                    &self.custom_types.visibility,
+                )?;
 
                 Ok(())
             }
@@ -1086,7 +1283,7 @@ impl Display for CustomTypesForTablesForDisplay<'_> {
                         sql_name: "".into(),
                     };
                     let _ = a.rust_name();
-                    rust_enum_module(f, Vec::new(), false)?;
+                    rust_enum_module(f, Vec::new(), false, &self.custom_types.visibility)?;
                 }
                 unreachable!(
                     "Diesel only support a closed set of types for Sqlite. \
@@ -1126,7 +1323,7 @@ impl Display for CustomTypesForTablesForDisplay<'_> {
                 }
                 let mut rust_types = Vec::new();
                 let mut out = PadAdapter::new(f);
-                writeln!(out, "pub mod sql_types {{")?;
+                writeln!(out, "{} mod sql_types {{", self.custom_types.visibility)?;
 
                 for (idx, &(custom_type, table, column)) in types_to_generate.iter().enumerate() {
                     let enum_type = if let Some(variants) =
@@ -1181,7 +1378,12 @@ impl Display for CustomTypesForTablesForDisplay<'_> {
                 }
 
                 writeln!(f, "}}\n")?;
-                rust_enum_module(f, rust_types, self.custom_types.generate_rust_enums)?;
+                rust_enum_module(
+                    f,
+                    rust_types,
+                    self.custom_types.generate_rust_enums,
+                    &self.custom_types.visibility,
+                )?;
                 Ok(())
             }
         }
@@ -1192,12 +1394,13 @@ fn rust_enum_module(
     f: &mut Formatter<'_>,
     rust_types: Vec<RustEnum<'_>>,
     generate_rust_enums: bool,
+    visibility: &str,
 ) -> Result<(), fmt::Error> {
     if generate_rust_enums && !rust_types.is_empty() {
         writeln!(f, "/// A module containing custom Rust type definitions")?;
         writeln!(f, "///")?;
         writeln!(f, "/// (Automatically generated by Diesel.)")?;
-        writeln!(f, "pub mod rust_types {{")?;
+        writeln!(f, "{visibility} mod rust_types {{")?;
         let mut out = PadAdapter::new(f);
         writeln!(out, "{}", RustEnums(rust_types))?;
         writeln!(f, "}}\n")?;
@@ -1211,7 +1414,12 @@ impl Display for ModuleDefinition<'_> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         {
             let mut out = PadAdapter::new(f);
-            writeln!(out, "pub mod {} {{", self.0)?;
+            writeln!(
+                out,
+                "{} mod {} {{",
+                self.1.visibility.unwrap_or("pub"),
+                self.0
+            )?;
             if let Some(ref custom_types_for_tables) = self.1.custom_types_for_tables {
                 write!(
                     out,
@@ -1235,53 +1443,20 @@ struct QueryRelationDefinitions<'a> {
     fk_constraints_for_allow_tables: Vec<ForeignKeyConstraint>,
     with_docs: DocConfig,
     allow_tables_to_appear_in_same_query_config: AllowTablesToAppearInSameQueryConfig,
+    allow_columns_to_appear_in_same_group_by_clause_config:
+        Option<AllowTablesToAppearInSameQueryConfig>,
     import_types: Option<&'a [String]>,
     custom_types_for_tables: Option<CustomTypesForTables>,
     local_safe_tables: &'a BTreeSet<TableName>,
     multi_schema_table_prefixes: Option<&'a BTreeMap<TableName, String>>,
+    removed_tables: Vec<String>,
+    id_types: Vec<IdType>,
+    visibility: Option<&'a str>,
 }
 
-impl<'a> Display for QueryRelationDefinitions<'a> {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        let mut is_first = true;
-        for (table_idx, table) in self.data.iter().enumerate() {
-            if is_first {
-                is_first = false;
-            } else {
-                writeln!(f)?;
-            }
-            writeln!(
-                f,
-                "{}",
-                QueryRelationDefinition {
-                    table,
-                    with_docs: self.with_docs,
-                    import_types: self.import_types,
-                    custom_type_overrides: self
-                        .custom_types_for_tables
-                        .as_ref()
-                        .map(|cts| cts.types_overrides_sorted[table_idx].as_slice())
-                }
-            )?;
-        }
-
-        if !self.fk_constraints_for_joinable.is_empty() {
-            writeln!(f)?;
-        }
-
-        for foreign_key in &self.fk_constraints_for_joinable {
-            writeln!(
-                f,
-                "{}",
-                Joinable {
-                    constraint: foreign_key,
-                    local_safe_tables: self.local_safe_tables,
-                    table_prefixes: self.multi_schema_table_prefixes,
-                }
-            )?;
-        }
-
-        let table_groups = match self.allow_tables_to_appear_in_same_query_config {
+impl QueryRelationDefinitions<'_> {
+    fn table_groups(&self, config: AllowTablesToAppearInSameQueryConfig) -> Vec<Vec<&TableName>> {
+        let table_groups = match config {
             AllowTablesToAppearInSameQueryConfig::FkRelatedTables => foreign_key_table_groups(
                 self.data
                     .iter()
@@ -1312,7 +1487,7 @@ impl<'a> Display for QueryRelationDefinitions<'a> {
             }
             AllowTablesToAppearInSameQueryConfig::None => vec![],
         };
-        let table_groups = if self.multi_schema_table_prefixes.is_some() {
+        if self.multi_schema_table_prefixes.is_some() {
             table_groups
                 .into_iter()
                 .filter(|table_group| {
@@ -1328,7 +1503,52 @@ impl<'a> Display for QueryRelationDefinitions<'a> {
                 .collect()
         } else {
             table_groups
-        };
+        }
+    }
+}
+
+impl<'a> Display for QueryRelationDefinitions<'a> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let mut is_first = true;
+        for (table_idx, table) in self.data.iter().enumerate() {
+            if is_first {
+                is_first = false;
+            } else {
+                writeln!(f)?;
+            }
+            writeln!(
+                f,
+                "{}",
+                QueryRelationDefinition {
+                    table,
+                    with_docs: self.with_docs,
+                    import_types: self.import_types,
+                    custom_type_overrides: self
+                        .custom_types_for_tables
+                        .as_ref()
+                        .map(|cts| cts.types_overrides_sorted[table_idx].as_slice()),
+                    visibility: self.visibility,
+                }
+            )?;
+        }
+
+        if !self.fk_constraints_for_joinable.is_empty() {
+            writeln!(f)?;
+        }
+
+        for foreign_key in &self.fk_constraints_for_joinable {
+            writeln!(
+                f,
+                "{}",
+                Joinable {
+                    constraint: foreign_key,
+                    local_safe_tables: self.local_safe_tables,
+                    table_prefixes: self.multi_schema_table_prefixes,
+                }
+            )?;
+        }
+
+        let table_groups = self.table_groups(self.allow_tables_to_appear_in_same_query_config);
         for (table_group_index, table_group) in table_groups
             .into_iter()
             .filter(|table_group| table_group.len() >= 2)
@@ -1356,6 +1576,398 @@ impl<'a> Display for QueryRelationDefinitions<'a> {
             writeln!(f, ");")?;
         }
 
+        if let Some(config) = self.allow_columns_to_appear_in_same_group_by_clause_config {
+            // Only columns of tables generated in this module are known here
+            let table_groups = self.table_groups(config).into_iter().map(|table_group| {
+                table_group
+                    .into_iter()
+                    .filter_map(|table| self.data.iter().find(|t| t.table_name() == table))
+                    .collect::<Vec<_>>()
+            });
+            for (table_group_index, table_group) in table_groups
+                .filter(|table_group| table_group.len() >= 2)
+                .enumerate()
+            {
+                if table_group_index == 0 {
+                    writeln!(f)?;
+                }
+                write!(
+                    f,
+                    "diesel::allow_columns_to_appear_in_same_group_by_clause!("
+                )?;
+                {
+                    let mut out = PadAdapter::new(f);
+                    writeln!(out)?;
+                    for table in table_group {
+                        for column in table.columns() {
+                            write!(
+                                out,
+                                "{}::{},",
+                                table.table_name().rust_name,
+                                column.rust_name
+                            )?;
+                        }
+                    }
+                }
+                writeln!(f, ");")?;
+            }
+        }
+
+        if !self.id_types.is_empty() {
+            writeln!(f)?;
+            write!(
+                f,
+                "{}",
+                IdTypes {
+                    types: &self.id_types,
+                    with_docs: !matches!(self.with_docs, DocConfig::NoDocComments),
+                    visibility: self.visibility.unwrap_or("pub"),
+                }
+            )?;
+        }
+
+        if !self.removed_tables.is_empty() {
+            writeln!(f)?;
+            write!(
+                f,
+                "{}",
+                RemovedTables {
+                    tables: &self.removed_tables,
+                    with_docs: !matches!(self.with_docs, DocConfig::NoDocComments),
+                    visibility: self.visibility.unwrap_or("pub"),
+                }
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the tables declared in the existing schema file that are
+/// no longer part of the generated schema
+///
+/// A missing or unparsable schema file is treated as a file without tables.
+fn removed_tables(config: &config::PrintSchema, data: &[QueryRelationData]) -> Vec<String> {
+    let Some(ref path) = config.file else {
+        return Vec::new();
+    };
+    let Ok(content) = std::fs::read_to_string(path) else {
+        return Vec::new();
+    };
+    let file = match syn::parse_file(&content) {
+        Ok(file) => file,
+        Err(e) => {
+            tracing::warn!(
+                ?path,
+                "Couldn't parse the existing schema file to find removed tables ({e})"
+            );
+            return Vec::new();
+        }
+    };
+    let mut collector = TableNameCollector::default();
+    syn::visit::Visit::visit_file(&mut collector, &file);
+
+    let current_tables = data
+        .iter()
+        .map(|t| t.table_name().rust_name.as_str())
+        .collect::<HashSet<_>>();
+    collector
+        .table_names
+        .into_iter()
+        .filter(|t| !current_tables.contains(t.as_str()))
+        .collect()
+}
+
+#[derive(Default)]
+struct TableNameCollector {
+    table_names: BTreeSet<String>,
+}
+
+impl<'ast> syn::visit::Visit<'ast> for TableNameCollector {
+    fn visit_macro(&mut self, i: &'ast syn::Macro) {
+        let last_segment = i.path.segments.last();
+        if last_segment.map(|s| s.ident == "table").unwrap_or(false) {
+            if let Ok(table) = i.parse_body::<diesel_table_macro_syntax::TableDecl>() {
+                self.table_names.insert(table.view.table_name.to_string());
+            }
+        } else if last_segment.map(|s| s.ident == "view").unwrap_or(false)
+            && let Ok(view) = i.parse_body::<diesel_table_macro_syntax::ViewDecl>()
+        {
+            self.table_names.insert(view.table_name.to_string());
+        }
+        syn::visit::visit_macro(self, i)
+    }
+}
+
+/// Deprecated stubs for tables removed from the database schema
+///
+/// Code still referring to these tables gets a deprecation warning
+/// explaining that the table was removed, instead of an unresolved
+/// import error. The stubs are dropped again on the next regeneration.
+struct RemovedTables<'a> {
+    tables: &'a [String],
+    with_docs: bool,
+    visibility: &'a str,
+}
+
+impl Display for RemovedTables<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.with_docs {
+            writeln!(f, "/// Stubs for tables removed from the database schema")?;
+            writeln!(f, "///")?;
+            writeln!(
+                f,
+                "/// These stubs are removed the next time the schema is generated."
+            )?;
+        }
+        writeln!(f, "{} mod removed {{", self.visibility)?;
+        {
+            let mut out = PadAdapter::new(f);
+            for (idx, table) in self.tables.iter().enumerate() {
+                if idx > 0 {
+                    writeln!(out)?;
+                }
+                writeln!(
+                    out,
+                    "#[deprecated(note = \"the `{table}` table was removed from the database schema\")]"
+                )?;
+                writeln!(out, "pub mod {table} {{")?;
+                writeln!(out, "    #[allow(non_camel_case_types)]")?;
+                writeln!(out, "    #[derive(Debug, Clone, Copy)]")?;
+                writeln!(out, "    pub struct table;")?;
+                writeln!(out)?;
+                writeln!(out, "    pub mod dsl {{")?;
+                writeln!(out, "        pub use super::table as {table};")?;
+                writeln!(out, "    }}")?;
+                writeln!(out, "}}")?;
+            }
+        }
+        writeln!(f, "}}")?;
+        writeln!(f)?;
+        writeln!(f, "#[allow(deprecated)]")?;
+        writeln!(f, "{} use self::removed::*;", self.visibility)?;
+        Ok(())
+    }
+}
+
+/// A newtype wrapper for the primary key of a table
+struct IdType {
+    name: String,
+    table: String,
+    rust_type: &'static str,
+    sql_type: String,
+    is_copy: bool,
+    sequence: Option<String>,
+}
+
+/// Collects the typed primary keys generated by `generate_id_types`
+///
+/// Only tables with a single, not nullable integer or text primary
+/// key column get a typed primary key.
+fn id_types(
+    connection: &mut InferConnection,
+    data: &[QueryRelationData],
+) -> Result<Vec<IdType>, crate::errors::Error> {
+    let mut id_types = Vec::new();
+    for table in data {
+        let QueryRelationData::Table(table) = table else {
+            continue;
+        };
+        let [primary_key] = table.primary_key.as_slice() else {
+            continue;
+        };
+        let Some(column) = table
+            .column_data
+            .iter()
+            .find(|c| &c.rust_name == primary_key)
+        else {
+            continue;
+        };
+        let Some((rust_type, is_copy)) = id_rust_type(&column.ty) else {
+            continue;
+        };
+        let sql_type = if column.ty.is_unsigned {
+            format!(
+                "diesel::sql_types::Unsigned<diesel::sql_types::{}>",
+                column.ty.rust_name
+            )
+        } else {
+            format!("diesel::sql_types::{}", column.ty.rust_name)
+        };
+        id_types.push(IdType {
+            name: id_type_name(&table.name.rust_name),
+            table: table.name.rust_name.clone(),
+            rust_type,
+            sql_type,
+            is_copy,
+            sequence: get_owned_sequence(connection, &table.name, &column.sql_name)?,
+        });
+    }
+
+    // tables like `user` and `users` would end up with the same name
+    let mut name_counts = HashMap::<String, usize>::new();
+    for id_type in &id_types {
+        *name_counts.entry(id_type.name.clone()).or_default() += 1;
+    }
+    for id_type in &mut id_types {
+        if name_counts[&id_type.name] > 1 {
+            id_type.name = format!(
+                "{}Id",
+                id_type.table.trim_start_matches("r#").to_pascal_case()
+            );
+        }
+    }
+    Ok(id_types)
+}
+
+/// The Rust type wrapped by the typed primary key and whether it is `Copy`
+fn id_rust_type(ty: &ColumnType) -> Option<(&'static str, bool)> {
+    if ty.is_nullable || ty.is_array || ty.record.is_some() {
+        return None;
+    }
+    let rust_type = match (ty.rust_name.to_lowercase().as_str(), ty.is_unsigned) {
+        ("int2" | "smallint", false) => "i16",
+        ("int2" | "smallint", true) => "u16",
+        ("int4" | "integer", false) => "i32",
+        ("int4" | "integer", true) => "u32",
+        ("int8" | "bigint", false) => "i64",
+        ("int8" | "bigint", true) => "u64",
+        ("text" | "varchar", false) => return Some(("String", false)),
+        _ => return None,
+    };
+    Some((rust_type, true))
+}
+
+/// Derives the name of a typed primary key from the table name,
+/// for example `UserId` for the `users` table
+fn id_type_name(table_name: &str) -> String {
+    let name = table_name.trim_start_matches("r#");
+    let singular = if let Some(stem) = name.strip_suffix("ies") {
+        Cow::Owned(format!("{stem}y"))
+    } else if ["sses", "xes", "ches", "shes"]
+        .iter()
+        .any(|suffix| name.ends_with(suffix))
+    {
+        Cow::Borrowed(&name[..name.len() - 2])
+    } else if name.ends_with("ss") || name.ends_with("us") || name.ends_with("is") {
+        Cow::Borrowed(name)
+    } else {
+        Cow::Borrowed(name.strip_suffix('s').unwrap_or(name))
+    };
+    format!("{}Id", singular.to_pascal_case())
+}
+
+struct IdTypes<'a> {
+    types: &'a [IdType],
+    with_docs: bool,
+    visibility: &'a str,
+}
+
+impl Display for IdTypes<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.with_docs {
+            writeln!(f, "/// A module containing typed primary keys")?;
+            writeln!(f, "///")?;
+            writeln!(
+                f,
+                "/// Using them as field types in your models turns mixing up"
+            )?;
+            writeln!(f, "/// ids of different tables into a compile time error.")?;
+            writeln!(f, "///")?;
+            writeln!(f, "/// (Automatically generated by Diesel.)")?;
+        }
+        writeln!(f, "{} mod id_types {{", self.visibility)?;
+        {
+            let mut out = PadAdapter::new(f);
+            for (idx, id_type) in self.types.iter().enumerate() {
+                if idx > 0 {
+                    writeln!(out)?;
+                }
+                let IdType {
+                    name,
+                    table,
+                    rust_type,
+                    sql_type,
+                    is_copy,
+                    sequence,
+                } = id_type;
+                if self.with_docs {
+                    writeln!(
+                        out,
+                        "/// The primary key of the [`{table}`](super::{table}) table"
+                    )?;
+                    if let Some(sequence) = sequence {
+                        writeln!(out, "///")?;
+                        writeln!(out, "/// Generated by the `{sequence}` sequence.")?;
+                    }
+                }
+                writeln!(
+                    out,
+                    "#[derive(Debug, Clone, {}PartialEq, Eq, PartialOrd, Ord, Hash, \
+                     diesel::expression::AsExpression, diesel::deserialize::FromSqlRow)]",
+                    if *is_copy { "Copy, " } else { "" }
+                )?;
+                writeln!(out, "#[diesel(sql_type = {sql_type})]")?;
+                writeln!(out, "pub struct {name}(pub {rust_type});")?;
+                writeln!(out)?;
+                writeln!(out, "impl From<{rust_type}> for {name} {{")?;
+                writeln!(out, "    fn from(id: {rust_type}) -> Self {{")?;
+                writeln!(out, "        Self(id)")?;
+                writeln!(out, "    }}")?;
+                writeln!(out, "}}")?;
+                writeln!(out)?;
+                writeln!(out, "impl From<{name}> for {rust_type} {{")?;
+                writeln!(out, "    fn from(id: {name}) -> Self {{")?;
+                writeln!(out, "        id.0")?;
+                writeln!(out, "    }}")?;
+                writeln!(out, "}}")?;
+                writeln!(out)?;
+                writeln!(
+                    out,
+                    "impl<DB> diesel::deserialize::FromSql<{sql_type}, DB> for {name}"
+                )?;
+                writeln!(out, "where")?;
+                writeln!(out, "    DB: diesel::backend::Backend,")?;
+                writeln!(
+                    out,
+                    "    {rust_type}: diesel::deserialize::FromSql<{sql_type}, DB>,"
+                )?;
+                writeln!(out, "{{")?;
+                writeln!(
+                    out,
+                    "    fn from_sql(bytes: DB::RawValue<'_>) -> diesel::deserialize::Result<Self> {{"
+                )?;
+                writeln!(
+                    out,
+                    "        <{rust_type} as diesel::deserialize::FromSql<{sql_type}, DB>>::from_sql(bytes).map(Self)"
+                )?;
+                writeln!(out, "    }}")?;
+                writeln!(out, "}}")?;
+                writeln!(out)?;
+                writeln!(
+                    out,
+                    "impl<DB> diesel::serialize::ToSql<{sql_type}, DB> for {name}"
+                )?;
+                writeln!(out, "where")?;
+                writeln!(out, "    DB: diesel::backend::Backend,")?;
+                writeln!(
+                    out,
+                    "    {rust_type}: diesel::serialize::ToSql<{sql_type}, DB>,"
+                )?;
+                writeln!(out, "{{")?;
+                writeln!(
+                    out,
+                    "    fn to_sql<'b>(&'b self, out: &mut diesel::serialize::Output<'b, '_, DB>) -> diesel::serialize::Result {{"
+                )?;
+                writeln!(
+                    out,
+                    "        <{rust_type} as diesel::serialize::ToSql<{sql_type}, DB>>::to_sql(&self.0, out)"
+                )?;
+                writeln!(out, "    }}")?;
+                writeln!(out, "}}")?;
+            }
+        }
+        writeln!(f, "}}")?;
         Ok(())
     }
 }
@@ -1430,6 +2042,7 @@ struct QueryRelationDefinition<'a> {
     with_docs: DocConfig,
     import_types: Option<&'a [String]>,
     custom_type_overrides: Option<&'a [Option<ColumnType>]>,
+    visibility: Option<&'a str>,
 }
 
 fn write_doc_comments(out: &mut impl fmt::Write, doc: &str) -> fmt::Result {
@@ -1520,6 +2133,9 @@ impl<'a> Display for QueryRelationDefinition<'a> {
                 )?;
             }
 
+            if let Some(visibility) = self.visibility {
+                write!(out, "{visibility} ")?;
+            }
             write!(out, "{} ", self.table.table_name())?;
 
             if let QueryRelationData::Table(t) = self.table {
@@ -1609,6 +2225,16 @@ impl Display for ColumnDefinitions<'_> {
                 if let Some(max_length) = column.ty.max_length {
                     writeln!(out, r#"#[max_length = {max_length}]"#)?;
                 }
+                if let Some(ref expression) = column.generated_always_as {
+                    writeln!(
+                        out,
+                        r#"#[generated_always_as = {}]"#,
+                        escape_rust_string(expression)
+                    )?;
+                }
+                if column.generated_as_identity {
+                    writeln!(out, "#[diesel(generated)]")?;
+                }
 
                 writeln!(out, "{} -> {},", column.rust_name, column_type)?;
             }
@@ -0,0 +1,232 @@
+//! Scaffolds a binary that seeds the database with initial data
+//!
+//! The generated `src/bin/seed.rs` includes the schema file configured
+//! in `diesel.toml` and contains a commented out bulk insert for each
+//! table declared in it, as a starting point for programmatic seeding.
+
+use clap::{Args, Subcommand};
+use std::fmt::Write;
+use std::path::{Component, Path, PathBuf};
+use syn::visit::Visit;
+
+use diesel_table_macro_syntax::TableDecl;
+
+use crate::config::Config;
+use crate::database::Backend;
+use crate::errors::Error;
+
+#[derive(Debug, Args)]
+pub struct SeedArgs {
+    #[command(subcommand)]
+    pub command: SeedCommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SeedCommand {
+    /// Generates `src/bin/seed.rs`, a binary that seeds the database
+    /// specified in your DATABASE_URL.
+    ///
+    /// The binary includes the schema file configured in your diesel.toml
+    /// and contains an example bulk insert for each table in it.
+    /// Run it with `cargo run --bin seed`.
+    Init {
+        /// The key of the `[print_schema]` section in your diesel.toml
+        /// whose schema file is used.
+        #[arg(id = "SCHEMA_KEY", long = "schema-key", default_value = "default")]
+        schema_key: String,
+    },
+}
+
+pub fn run_seed_command(
+    args: SeedArgs,
+    config_file: Option<PathBuf>,
+    database_url: Option<String>,
+) -> Result<(), Error> {
+    match args.command {
+        SeedCommand::Init { schema_key } => {
+            let backend = Backend::for_url(&crate::database::database_url(database_url)?)?;
+            let project_root = crate::find_project_root()?;
+            let schema_file = Config::read(config_file)?
+                .print_schema
+                .all_configs
+                .get(&schema_key)
+                .and_then(|config| config.file.clone())
+                .unwrap_or_else(|| project_root.join("src").join("schema.rs"));
+            let bin_dir = project_root.join("src").join("bin");
+            let seed_file = bin_dir.join("seed.rs");
+            if seed_file.exists() {
+                return Err(Error::SeedFileExists(seed_file));
+            }
+
+            let schema = std::fs::read_to_string(&schema_file)
+                .map_err(|e| Error::IoError(e, Some(schema_file.clone())))?;
+            let mut collector = TableCollector::default();
+            collector.visit_file(&syn::parse_file(&schema)?);
+            let tables = collector
+                .tables
+                .into_iter()
+                .collect::<Result<Vec<_>, _>>()?;
+
+            let schema_path = relative_module_path(&schema_file, &bin_dir);
+            let content = seed_file_content(backend, &schema_path, &tables)?;
+            std::fs::create_dir_all(&bin_dir)
+                .map_err(|e| Error::IoError(e, Some(bin_dir.clone())))?;
+            std::fs::write(&seed_file, content)
+                .map_err(|e| Error::IoError(e, Some(seed_file.clone())))?;
+            println!(
+                "Creating {}",
+                crate::convert_absolute_path_to_relative(&seed_file, &project_root).display()
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Collects the `table!` declarations of a schema file together
+/// with the modules they are declared in
+#[derive(Default)]
+struct TableCollector {
+    modules: Vec<String>,
+    tables: Vec<Result<(Vec<String>, TableDecl), syn::Error>>,
+}
+
+impl<'ast> Visit<'ast> for TableCollector {
+    fn visit_item_mod(&mut self, i: &'ast syn::ItemMod) {
+        self.modules.push(i.ident.to_string());
+        syn::visit::visit_item_mod(self, i);
+        self.modules.pop();
+    }
+
+    fn visit_macro(&mut self, i: &'ast syn::Macro) {
+        if i.path.segments.last().is_some_and(|s| s.ident == "table") {
+            self.tables
+                .push(i.parse_body().map(|table| (self.modules.clone(), table)));
+        }
+        syn::visit::visit_macro(self, i)
+    }
+}
+
+/// The path of `file` relative to `dir`, as used by `#[path = "…"]`
+fn relative_module_path(file: &Path, dir: &Path) -> String {
+    crate::convert_absolute_path_to_relative(file, dir)
+        .components()
+        .map(|c| match c {
+            Component::ParentDir => "..".into(),
+            c => c.as_os_str().to_string_lossy(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn seed_file_content(
+    backend: Backend,
+    schema_path: &str,
+    tables: &[(Vec<String>, TableDecl)],
+) -> Result<String, Error> {
+    let connection = match backend {
+        #[cfg(feature = "postgres")]
+        Backend::Pg => "PgConnection",
+        #[cfg(feature = "sqlite")]
+        Backend::Sqlite => "SqliteConnection",
+        #[cfg(feature = "mysql")]
+        Backend::Mysql => "MysqlConnection",
+    };
+
+    let mut out = String::new();
+    writeln!(out, "//! Seeds the database specified in your DATABASE_URL")?;
+    writeln!(out, "//!")?;
+    writeln!(out, "//! Run with `cargo run --bin seed`.")?;
+    writeln!(out)?;
+    writeln!(out, "use diesel::prelude::*;")?;
+    writeln!(out)?;
+    writeln!(out, "#[allow(dead_code)]")?;
+    writeln!(out, "#[path = \"{schema_path}\"]")?;
+    writeln!(out, "mod schema;")?;
+    writeln!(out)?;
+    writeln!(out, "fn main() {{")?;
+    writeln!(
+        out,
+        "    let database_url = std::env::var(\"DATABASE_URL\").expect(\"DATABASE_URL must be set\");"
+    )?;
+    writeln!(
+        out,
+        "    let mut conn = {connection}::establish(&database_url)"
+    )?;
+    writeln!(
+        out,
+        "        .unwrap_or_else(|e| panic!(\"Error connecting to {{database_url}}: {{e}}\"));"
+    )?;
+    writeln!(
+        out,
+        "    conn.transaction(seed).expect(\"Failed to seed the database\");"
+    )?;
+    writeln!(out, "}}")?;
+    writeln!(out)?;
+    writeln!(out, "#[allow(unused_variables)]")?;
+    writeln!(
+        out,
+        "fn seed(conn: &mut {connection}) -> QueryResult<()> {{"
+    )?;
+    writeln!(
+        out,
+        "    // Insert several rows at once by passing a `Vec` of records to `values`."
+    )?;
+    for (modules, table) in tables {
+        write_example_insert(&mut out, modules, table)?;
+    }
+    writeln!(out, "    Ok(())")?;
+    writeln!(out, "}}")?;
+    Ok(out)
+}
+
+fn write_example_insert(
+    out: &mut String,
+    modules: &[String],
+    table: &TableDecl,
+) -> Result<(), Error> {
+    let mut path = String::from("schema");
+    for module in modules {
+        write!(path, "::{module}")?;
+    }
+    write!(path, "::{}", table.view.table_name)?;
+    // a single integer primary key is usually generated by the database
+    let generated_key = match table.primary_keys.as_ref().map(|pk| &pk.keys) {
+        Some(keys) if keys.len() == 1 => keys.first().map(ToString::to_string),
+        Some(_) => None,
+        None => Some("id".to_owned()),
+    }
+    .filter(|key| {
+        table.view.column_defs.iter().any(|c| {
+            c.column_name == key
+                && c.tpe.path.segments.last().is_some_and(|s| {
+                    ["Integer", "BigInt", "Int4", "Int8", "Serial", "BigSerial"]
+                        .iter()
+                        .any(|t| s.ident == t)
+                })
+        })
+    });
+
+    writeln!(out)?;
+    writeln!(out, "    // diesel::insert_into({path}::table)")?;
+    writeln!(out, "    //     .values(vec![")?;
+    writeln!(out, "    //         (")?;
+    for column in &table.view.column_defs {
+        if generated_key
+            .as_ref()
+            .is_some_and(|key| column.column_name == key)
+            || column.generated
+            || column.generated_always_as.is_some()
+        {
+            continue;
+        }
+        writeln!(
+            out,
+            "    //             {path}::{}.eq(todo!()),",
+            column.column_name
+        )?;
+    }
+    writeln!(out, "    //         ),")?;
+    writeln!(out, "    //     ])")?;
+    writeln!(out, "    //     .execute(conn)?;")?;
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use crate::support::{database, project};
+
+#[test]
+fn database_backup_and_restore_round_trip() {
+    let p = project("database_backup_round_trip").build();
+    let db = database(&p.database_url()).create();
+    db.execute("CREATE TABLE users (id INTEGER PRIMARY KEY, name VARCHAR(255) NOT NULL)");
+    db.execute("INSERT INTO users (id, name) VALUES (1, 'Sean'), (2, 'Tess')");
+
+    let result = p
+        .command("database")
+        .arg("backup")
+        .arg("--out=backup.dump")
+        .run();
+
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(
+        result.stdout().contains("Wrote backup to backup.dump"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+    assert!(p.has_file("backup.dump"));
+
+    let result = p.command("database").arg("drop").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(!db.exists());
+
+    let result = p
+        .command("database")
+        .arg("restore")
+        .arg("--in=backup.dump")
+        .run();
+
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(
+        result.stdout().contains("Restored backup from backup.dump"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+    assert!(db.table_exists("users"));
+}
+
+#[test]
+fn database_restore_fails_for_missing_backup() {
+    let p = project("database_restore_missing_backup").build();
+    let db = database(&p.database_url()).create();
+    db.execute("CREATE TABLE users (id INTEGER PRIMARY KEY)");
+
+    let result = p
+        .command("database")
+        .arg("restore")
+        .arg("--in=missing.dump")
+        .run();
+
+    assert!(!result.is_success(), "Result was successful {:?}", result);
+    assert!(
+        result.stderr().contains("missing.dump"),
+        "Unexpected stderr {}",
+        result.stderr()
+    );
+    assert!(db.table_exists("users"));
+}
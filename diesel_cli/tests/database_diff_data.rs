@@ -0,0 +1,94 @@
+use crate::support::{database, project};
+
+const CREATE_USERS: &str =
+    "CREATE TABLE users (id INTEGER PRIMARY KEY, name VARCHAR(255) NOT NULL)";
+
+fn insert_users(ids: impl IntoIterator<Item = i32>) -> String {
+    let values = ids
+        .into_iter()
+        .map(|id| format!("({id}, 'user {id}')"))
+        .collect::<Vec<_>>();
+    format!("INSERT INTO users (id, name) VALUES {}", values.join(", "))
+}
+
+#[test]
+fn database_diff_data_succeeds_for_identical_tables() {
+    let source = project("database_diff_data_identical_source").build();
+    let target = project("database_diff_data_identical_target").build();
+    let databases = [&source, &target].map(|p| database(&p.database_url()).create());
+    for db in &databases {
+        db.execute(CREATE_USERS);
+        db.execute(&insert_users(1..=10));
+    }
+
+    let result = source
+        .command("database")
+        .arg("diff-data")
+        .arg("--table=users")
+        .arg(format!("--target={}", target.database_url()))
+        .arg("--chunk-size=3")
+        .run();
+
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(
+        result
+            .stdout()
+            .contains("10 rows in source, 10 rows in target, 0 of 4 chunks differ"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+}
+
+#[test]
+fn database_diff_data_reports_divergent_ranges() {
+    let source = project("database_diff_data_divergent_source").build();
+    let target = project("database_diff_data_divergent_target").build();
+    let source_db = database(&source.database_url()).create();
+    source_db.execute(CREATE_USERS);
+    source_db.execute(&insert_users(1..=10));
+    let target_db = database(&target.database_url()).create();
+    target_db.execute(CREATE_USERS);
+    target_db.execute(&insert_users((1..=12).filter(|id| *id != 2)));
+    target_db.execute("UPDATE users SET name = 'changed' WHERE id = 8");
+
+    let result = source
+        .command("database")
+        .arg("diff-data")
+        .arg("--table=users")
+        .arg(format!("--target={}", target.database_url()))
+        .arg("--chunk-size=3")
+        .run();
+
+    assert!(!result.is_success(), "Result was successful {:?}", result);
+    assert!(
+        result
+            .stdout()
+            .contains("id <= 3: 3 rows in source, 2 rows in target"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+    assert!(
+        !result.stdout().contains("id in (3, 6]"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+    assert!(
+        result.stdout().contains("id in (6, 9]: checksums differ"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+    assert!(
+        result
+            .stdout()
+            .contains("id > 10: 0 rows in source, 2 rows in target"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+    assert!(
+        result
+            .stderr()
+            .contains("Table `users` differs between both databases in 3 chunk(s)"),
+        "Unexpected stderr {}",
+        result.stderr()
+    );
+}
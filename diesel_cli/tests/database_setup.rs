@@ -22,6 +22,45 @@ fn database_setup_creates_database() {
     assert!(db.exists());
 }
 
+#[test]
+#[cfg(feature = "postgres")]
+fn database_setup_creates_database_with_multiple_hosts() {
+    let p = project("database_setup_creates_database_with_multiple_hosts")
+        .folder("migrations")
+        .build();
+
+    let db = database(&p.database_url());
+    // nothing listens on port 1, so the second host is used
+    let database_url = p.database_url();
+    let (scheme, rest) = database_url.split_once("://").unwrap();
+    let (user_info, rest) = match rest.rsplit_once('@') {
+        Some((user_info, rest)) => (format!("{user_info}@"), rest),
+        None => (String::new(), rest),
+    };
+    let separator = if rest.contains('?') { '&' } else { '?' };
+    let multi_host_url = format!(
+        "{scheme}://{user_info}localhost:1,{rest}{separator}target_session_attrs=read-write"
+    );
+
+    // sanity check
+    assert!(!db.exists());
+
+    let result = p
+        .command("database")
+        .arg("setup")
+        .env("DATABASE_URL", &multi_host_url)
+        .run();
+
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(
+        result.stdout().contains("Creating database:"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+    assert!(db.exists());
+    assert!(db.table_exists("__diesel_schema_migrations"));
+}
+
 #[test]
 fn database_setup_creates_schema_table() {
     let p = project("database_setup_creates_schema_table")
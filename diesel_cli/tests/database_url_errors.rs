@@ -96,3 +96,43 @@ fn broken_dotenv_file_results_in_error() {
     );
     assert!(!result.is_success());
 }
+
+#[test]
+fn unknown_database_url_scheme_results_in_error() {
+    let p = project("unknown_database_url_scheme_results_in_error").build();
+    let result = p
+        .command_without_database_url("setup")
+        .env("DATABASE_URL", "oracle://localhost")
+        .run();
+    assert!(
+        result
+            .stderr()
+            .contains("Cannot determine the backend for the database url `oracle://localhost`.")
+    );
+    assert!(!result.is_success());
+}
+
+#[test]
+fn invalid_backend_results_in_error() {
+    let p = project("invalid_backend_results_in_error").build();
+    let result = p.command("setup").arg("--backend").arg("oracle").run();
+    assert!(result.stderr().contains(
+        "`oracle` is not a valid backend. Expected one of `postgres`, `mysql` or `sqlite`."
+    ));
+    assert!(!result.is_success());
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn backend_conflicting_with_database_url_results_in_error() {
+    let p = project("backend_conflicting_with_database_url").build();
+    let result = p
+        .command_without_database_url("setup")
+        .env("DATABASE_URL", "postgres://localhost")
+        .env("DIESEL_BACKEND", "sqlite")
+        .run();
+    assert!(result.stderr().contains(
+        "The database url `postgres://localhost` cannot be used with the selected `sqlite` backend."
+    ));
+    assert!(!result.is_success());
+}
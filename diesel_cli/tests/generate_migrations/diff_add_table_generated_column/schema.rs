@@ -0,0 +1,8 @@
+table! {
+    users {
+        id -> Integer,
+        name -> Text,
+        #[generated_always_as = "length(name)"]
+        name_length -> Integer,
+    }
+}
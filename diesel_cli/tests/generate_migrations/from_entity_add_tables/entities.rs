@@ -0,0 +1,26 @@
+use diesel::prelude::*;
+
+#[derive(Queryable, Selectable, Associations)]
+#[diesel(table_name = posts)]
+#[diesel(belongs_to(User))]
+pub struct Post {
+    pub id: i32,
+    pub user_id: i32,
+    pub title: String,
+    pub body: Option<String>,
+}
+
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = crate::schema::users)]
+pub struct User {
+    pub id: i32,
+    pub name: String,
+    #[diesel(column_name = hair_color)]
+    pub hair: Option<String>,
+}
+
+#[derive(Insertable)]
+#[diesel(table_name = users)]
+pub struct NewUser<'a> {
+    pub name: &'a str,
+}
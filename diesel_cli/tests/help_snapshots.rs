@@ -53,6 +53,54 @@ fn database_setup_help() {
     insta::assert_snapshot!(res.stdout());
 }
 
+#[test]
+fn database_diff_data_help() {
+    let res = project("database-diff-data-help")
+        .build()
+        .command("database")
+        .arg("diff-data")
+        .arg("--help")
+        .run();
+    assert!(res.is_success());
+    insta::assert_snapshot!(res.stdout());
+}
+
+#[test]
+fn database_backup_help() {
+    let res = project("database-backup-help")
+        .build()
+        .command("database")
+        .arg("backup")
+        .arg("--help")
+        .run();
+    assert!(res.is_success());
+    insta::assert_snapshot!(res.stdout());
+}
+
+#[test]
+fn database_restore_help() {
+    let res = project("database-restore-help")
+        .build()
+        .command("database")
+        .arg("restore")
+        .arg("--help")
+        .run();
+    assert!(res.is_success());
+    insta::assert_snapshot!(res.stdout());
+}
+
+#[test]
+fn seed_init_help() {
+    let res = project("seed-init-help")
+        .build()
+        .command("seed")
+        .arg("init")
+        .arg("--help")
+        .run();
+    assert!(res.is_success());
+    insta::assert_snapshot!(res.stdout());
+}
+
 #[test]
 fn completions_help() {
     let res = project("completions-help")
@@ -146,6 +194,18 @@ fn migration_pending_help() {
     insta::assert_snapshot!(res.stdout());
 }
 
+#[test]
+fn migration_fix_timestamps_help() {
+    let res = project("migration-fix-timestamps-help")
+        .build()
+        .command("migration")
+        .arg("fix-timestamps")
+        .arg("--help")
+        .run();
+    assert!(res.is_success());
+    insta::assert_snapshot!(res.stdout());
+}
+
 #[test]
 fn migration_generate_help() {
     let res = project("migration-generate-help")
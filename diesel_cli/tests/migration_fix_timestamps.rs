@@ -0,0 +1,165 @@
+use crate::support::{database, project};
+
+// skips the initial migration created by `diesel setup` for PostgreSQL
+fn migration_dir_names(p: &crate::support::Project) -> Vec<String> {
+    let mut names = p
+        .migrations()
+        .iter()
+        .map(|m| m.path().file_name().unwrap().to_string_lossy().into_owned())
+        .filter(|name| !name.ends_with("_diesel_initial_setup"))
+        .collect::<Vec<_>>();
+    names.sort();
+    names
+}
+
+#[test]
+fn migration_fix_timestamps_renumbers_pending_migrations_before_applied_ones() {
+    let p = project("migration_fix_timestamps_renumbers")
+        .folder("migrations")
+        .build();
+    let db = database(&p.database_url());
+
+    p.command("setup").run();
+
+    p.create_migration(
+        "2020-01-01-000000_create_users",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY)",
+        Some("DROP TABLE users"),
+        None,
+    );
+    p.create_migration(
+        "2020-03-01-000000_create_comments",
+        "CREATE TABLE comments (id INTEGER PRIMARY KEY)",
+        Some("DROP TABLE comments"),
+        None,
+    );
+    let result = p.command("migration").arg("run").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+
+    // migrations merged from another branch
+    p.create_migration(
+        "2020-02-01-000000_create_posts",
+        "CREATE TABLE posts (id INTEGER PRIMARY KEY)",
+        Some("DROP TABLE posts"),
+        None,
+    );
+    p.create_migration(
+        "2020-02-02-000000_create_tags",
+        "CREATE TABLE tags (id INTEGER PRIMARY KEY)",
+        Some("DROP TABLE tags"),
+        None,
+    );
+
+    let result = p.command("migration").arg("fix-timestamps").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(
+        result
+            .stdout()
+            .contains("Renaming 2020-02-01-000000_create_posts to "),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+
+    let names = migration_dir_names(&p);
+    assert_eq!(4, names.len(), "{names:?}");
+    assert_eq!("2020-01-01-000000_create_users", names[0]);
+    assert_eq!("2020-03-01-000000_create_comments", names[1]);
+    assert!(names[2].ends_with("-0000_create_posts"), "{names:?}");
+    assert!(names[3].ends_with("-0001_create_tags"), "{names:?}");
+
+    // the renamed migrations are run after the applied ones
+    let result = p.command("migration").arg("run").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(db.table_exists("posts"));
+    assert!(db.table_exists("tags"));
+
+    let result = p.command("migration").arg("fix-timestamps").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(
+        result.stdout().contains("nothing to renumber"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+}
+
+#[test]
+fn migration_fix_timestamps_dry_run_does_not_rename() {
+    let p = project("migration_fix_timestamps_dry_run")
+        .folder("migrations")
+        .build();
+
+    p.command("setup").run();
+
+    p.create_migration(
+        "2020-03-01-000000_create_users",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY)",
+        Some("DROP TABLE users"),
+        None,
+    );
+    let result = p.command("migration").arg("run").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+
+    p.create_migration(
+        "2020-02-01-000000_create_posts",
+        "CREATE TABLE posts (id INTEGER PRIMARY KEY)",
+        Some("DROP TABLE posts"),
+        None,
+    );
+
+    let result = p
+        .command("migration")
+        .arg("fix-timestamps")
+        .arg("--dry-run")
+        .run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(
+        result
+            .stdout()
+            .contains("Would rename 2020-02-01-000000_create_posts to "),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+    assert_eq!(
+        vec![
+            "2020-02-01-000000_create_posts".to_owned(),
+            "2020-03-01-000000_create_users".to_owned(),
+        ],
+        migration_dir_names(&p)
+    );
+}
+
+#[test]
+fn migration_fix_timestamps_fails_for_applied_versions_after_now() {
+    let p = project("migration_fix_timestamps_future")
+        .folder("migrations")
+        .build();
+
+    p.command("setup").run();
+
+    p.create_migration(
+        "9999-01-01-000000_create_users",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY)",
+        Some("DROP TABLE users"),
+        None,
+    );
+    let result = p.command("migration").arg("run").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+
+    p.create_migration(
+        "2020-02-01-000000_create_posts",
+        "CREATE TABLE posts (id INTEGER PRIMARY KEY)",
+        Some("DROP TABLE posts"),
+        None,
+    );
+
+    let result = p.command("migration").arg("fix-timestamps").run();
+    assert!(!result.is_success(), "Result was successful {:?}", result);
+    assert!(
+        result
+            .stderr()
+            .contains("sorts after the current timestamp"),
+        "Unexpected stderr {}",
+        result.stderr()
+    );
+    assert!(p.has_file("migrations/2020-02-01-000000_create_posts"));
+}
@@ -239,6 +239,17 @@ fn migration_generate_from_diff_alter_table_drop_column() {
     test_generate_migration("diff_alter_table_drop_column", Vec::new());
 }
 
+#[test]
+fn migration_generate_from_diff_add_table_generated_column() {
+    test_generate_migration("diff_add_table_generated_column", Vec::new());
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+#[test]
+fn migration_generate_from_diff_alter_table_add_generated_column() {
+    test_generate_migration("diff_alter_table_add_generated_column", Vec::new());
+}
+
 #[test]
 fn migration_generate_from_diff_add_table_with_fk() {
     test_generate_migration("diff_add_table_with_fk", Vec::new());
@@ -279,6 +290,11 @@ fn migration_generate_from_diff_except_tables() {
     test_generate_migration("diff_except_tables", vec!["-e", "table_b", "table_c"]);
 }
 
+#[test]
+fn migration_generate_from_entity_add_tables() {
+    test_generate_migration("from_entity_add_tables", Vec::new());
+}
+
 #[cfg(feature = "postgres")]
 #[test]
 fn migration_generate_postgres_specific_types() {
@@ -291,6 +307,28 @@ fn migration_generate_postgres_add_record() {
     test_generate_migration("postgres_add_record", Vec::new())
 }
 
+#[test]
+fn migration_generate_dry_run_requires_a_schema_source() {
+    let p = project("migration_generate_dry_run_requires_a_schema_source")
+        .folder("migrations")
+        .build();
+
+    let result = p
+        .command("migration")
+        .arg("generate")
+        .arg("hello")
+        .arg("--dry-run")
+        .run();
+
+    assert!(!result.is_success(), "Result was successful {:?}", result);
+    assert!(
+        result.stderr().contains("--diff-schema"),
+        "Unexpected stderr {}",
+        result.stderr()
+    );
+    assert!(p.migrations().is_empty());
+}
+
 #[test]
 fn migration_generate_with_duplicate_specified_version_fails() {
     const VERSION_ARG: &str = "--version=12345";
@@ -414,24 +452,44 @@ fn run_generate_migration_test(test_name: &str, args: Vec<&str>, p: Project) {
         db.execute(schema);
     }
 
-    let mut schema_rs = backend_file_path(test_name, "schema.rs");
-    if !schema_rs.exists() {
-        schema_rs = backend_file_path(test_name, "../schema.rs");
-    }
+    let entities_rs = backend_file_path(test_name, "../entities.rs");
+    let schema_source = if entities_rs.exists() {
+        format!("--from-entity={}", entities_rs.display())
+    } else {
+        let mut schema_rs = backend_file_path(test_name, "schema.rs");
+        if !schema_rs.exists() {
+            schema_rs = backend_file_path(test_name, "../schema.rs");
+        }
+        format!("--diff-schema={}", schema_rs.display())
+    };
 
     let result = p.command("print-schema").run();
     assert!(result.is_success(), "Result was unsuccessful {:?}", result);
     let initial_schema = result.stdout().replace("\r\n", "\n");
 
+    let dry_run = p
+        .command("migration")
+        .arg("generate")
+        .arg(test_name)
+        .arg("--version=12345")
+        .arg(&schema_source)
+        .arg("--dry-run")
+        .args(args.clone())
+        .run();
+
+    assert!(
+        dry_run.is_success(),
+        "Result was unsuccessful {:?}",
+        dry_run
+    );
+    assert!(!p.has_file(format!("migrations/12345_{test_name}")));
+
     let result = p
         .command("migration")
         .arg("generate")
         .arg(test_name)
         .arg("--version=12345")
-        .arg(format!(
-            "--diff-schema={schema_rs}",
-            schema_rs = schema_rs.display()
-        ))
+        .arg(schema_source)
         .args(args.clone())
         .run();
 
@@ -440,6 +498,16 @@ fn run_generate_migration_test(test_name: &str, args: Vec<&str>, p: Project) {
     let up_sql = p.file_contents(format!("migrations/12345_{test_name}/up.sql"));
     let down_sql = p.file_contents(format!("migrations/12345_{test_name}/down.sql"));
 
+    // the dry run prints exactly what is written to the migration files
+    assert_eq!(
+        dry_run.stdout().replace("\r\n", "\n"),
+        format!(
+            "-- up.sql\n{}-- down.sql\n{}",
+            with_trailing_newline(&up_sql),
+            with_trailing_newline(&down_sql)
+        )
+    );
+
     let mut setting = insta::Settings::new();
     setting.set_snapshot_path(backend_file_path(test_name, "up.sql"));
     setting.set_omit_expression(true);
@@ -488,6 +556,14 @@ fn run_generate_migration_test(test_name: &str, args: Vec<&str>, p: Project) {
     assert_eq!(final_schema, initial_schema);
 }
 
+fn with_trailing_newline(content: &str) -> String {
+    if content.ends_with('\n') {
+        content.to_owned()
+    } else {
+        format!("{content}\n")
+    }
+}
+
 fn read_file(path: &Path) -> String {
     let mut file = File::open(path).unwrap_or_else(|_| panic!("Could not open {}", path.display()));
     let mut string = String::new();
@@ -422,6 +422,54 @@ fn migration_run_updates_schema_if_config_present() {
     assert!(p.has_file("src/my_schema.rs"));
 }
 
+#[test]
+fn migration_run_updates_schema_with_config_inherited_from_workspace() {
+    let p = project("migration_run_updates_schema_with_workspace_config")
+        .folder("migrations")
+        .folder("service")
+        .file(
+            "diesel.workspace.toml",
+            r#"
+            [print_schema]
+            file = "src/workspace_schema.rs"
+            import_types = ["diesel::sql_types::*", "crate::custom_types::*"]
+            "#,
+        )
+        .file(
+            "service/diesel.toml",
+            r#"
+            [print_schema]
+            file = "src/my_schema.rs"
+            "#,
+        )
+        .build();
+
+    // Make sure the project is setup
+    p.command("setup").run();
+
+    p.create_migration(
+        "12345_create_users_table",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY)",
+        Some("DROP TABLE users"),
+        None,
+    );
+
+    let result = p
+        .command("migration")
+        .arg("run")
+        .arg("--config-file")
+        .arg("service/diesel.toml")
+        .run();
+
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(!p.has_file("service/src/workspace_schema.rs"));
+    assert!(p.has_file("service/src/my_schema.rs"));
+    assert!(
+        p.file_contents("service/src/my_schema.rs")
+            .contains("use crate::custom_types::*;")
+    );
+}
+
 #[test]
 fn migration_run_ignores_schema_if_config_present_with_no_schema_cli_flag() {
     let p = project("migration_run_ignores_schema_with_cli_flag")
@@ -703,3 +751,48 @@ fn reset_search_path_after_migration() {
     let result = p.command("migration").arg("run").run();
     assert!(result.is_success(), "Migration failed: {:?}", result);
 }
+
+#[test]
+fn migration_run_analyzes_touched_tables_if_configured() {
+    let p = project("migration_run_analyzes_touched_tables")
+        .folder("migrations")
+        .file("diesel.toml", "analyze_after_migrations = true")
+        .build();
+
+    // Make sure the project is setup
+    p.command("setup").run();
+
+    p.create_migration(
+        "12345_create_users_table",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY);\n\
+         INSERT INTO users (id) VALUES (1);\n\
+         CREATE TABLE old_users (id INTEGER PRIMARY KEY);\n\
+         DROP TABLE old_users;",
+        Some("DROP TABLE users"),
+        None,
+    );
+
+    let result = p.command("migration").arg("run").run();
+
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    let analyzed = result
+        .stdout()
+        .lines()
+        .filter(|line| line.starts_with("Analyzing table"))
+        .collect::<Vec<_>>();
+    assert_eq!(1, analyzed.len(), "Unexpected stdout {}", result.stdout());
+    assert!(
+        analyzed[0].ends_with("users"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+
+    // Nothing is analyzed if no migration ran
+    let result = p.command("migration").arg("run").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(
+        !result.stdout().contains("Analyzing table"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+}
@@ -164,6 +164,30 @@ fn print_schema_datetime_for_mysql() {
     test_print_schema("print_schema_datetime_for_mysql", vec!["--with-docs"]);
 }
 
+#[test]
+#[cfg(feature = "postgres")]
+fn print_schema_introspection_jobs() {
+    test_print_schema(
+        "print_schema_introspection_jobs",
+        vec!["--with-docs", "--introspection-jobs", "2"],
+    );
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn print_schema_mysql_show_commands() {
+    test_print_schema(
+        "print_schema_mysql_show_commands",
+        vec![
+            "--with-docs",
+            "--mysql-introspection",
+            "show_commands",
+            "--mysql-introspection-connections",
+            "2",
+        ],
+    );
+}
+
 #[test]
 #[cfg(not(windows))]
 fn print_schema_patch_file() {
@@ -198,6 +222,24 @@ fn print_schema_custom_types_custom_schema() {
     );
 }
 
+#[test]
+#[cfg(feature = "postgres")]
+fn print_schema_with_schema_visibility() {
+    test_print_schema(
+        "print_schema_with_schema_visibility",
+        vec!["--schema-visibility", "pub(crate)"],
+    );
+}
+
+#[test]
+#[cfg(any(feature = "postgres", feature = "sqlite"))]
+fn print_schema_with_id_types() {
+    test_print_schema(
+        "print_schema_with_id_types",
+        vec!["--with-docs", "--generate-id-types"],
+    );
+}
+
 #[test]
 #[cfg(feature = "postgres")]
 fn print_schema_custom_enum_derives() {
@@ -408,6 +450,19 @@ fn print_schema_allows_none() {
     )
 }
 
+#[test]
+fn print_schema_allow_columns_to_appear_in_same_group_by_clause() {
+    test_print_schema(
+        "print_schema_allow_columns_to_appear_in_same_group_by_clause",
+        vec![
+            "--allow-tables-to-appear-in-same-query-config",
+            "fk_related_tables",
+            "--allow-columns-to-appear-in-same-group-by-clause-config",
+            "fk_related_tables",
+        ],
+    )
+}
+
 #[test]
 fn print_schema_reserved_names() {
     test_print_schema("print_schema_reserved_name_mitigation_issue_3404", vec![])
@@ -452,6 +507,12 @@ fn print_schema_citext() {
     test_print_schema("print_schema_citext", vec![])
 }
 
+#[cfg(feature = "postgres")]
+#[test]
+fn print_schema_identity_columns() {
+    test_print_schema("print_schema_identity_columns", vec![])
+}
+
 #[test]
 fn print_schema_with_multiple_schema() {
     test_multiple_print_schema(
@@ -619,6 +680,312 @@ fn print_schema_rust_injection() {
     )
 }
 
+#[test]
+fn print_schema_diff_does_not_write_schema_file() {
+    let p = project("print_schema_diff_does_not_write_schema_file")
+        .folder("migrations")
+        .file(
+            "diesel.toml",
+            r#"
+            [print_schema]
+            file = "src/schema.rs"
+            "#,
+        )
+        .build();
+    let db = database(&p.database_url());
+
+    p.command("setup").run();
+    p.create_migration(
+        "12345_create_users_table",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY)",
+        Some("DROP TABLE users"),
+        None,
+    );
+    let result = p.command("migration").arg("run").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    let schema = p.file_contents("src/schema.rs");
+
+    let result = p.command("print-schema").arg("--diff").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(
+        result.stdout().is_empty(),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+
+    db.execute("CREATE TABLE posts (id INTEGER PRIMARY KEY)");
+
+    let result = p.command("print-schema").arg("--diff").run();
+    assert_eq!(1, result.code(), "Result was successful {:?}", result);
+    assert!(
+        result.stdout().contains("+diesel::table! {") && result.stdout().contains("posts (id)"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+    assert!(
+        result
+            .stderr()
+            .contains("Command would result in changes to")
+            && result.stderr().contains("schema.rs"),
+        "Unexpected stderr {}",
+        result.stderr()
+    );
+    assert_eq!(schema, p.file_contents("src/schema.rs"));
+}
+
+#[test]
+fn print_schema_introspection_cache_detects_changed_tables() {
+    let p = project("print_schema_introspection_cache_detects_changed_tables")
+        .file(
+            "diesel.toml",
+            r#"
+            [print_schema]
+            introspection_cache = true
+            "#,
+        )
+        .build();
+    let db = database(&p.database_url());
+
+    p.command("setup").run();
+    db.execute("CREATE TABLE users (id INTEGER PRIMARY KEY)");
+    db.execute("CREATE TABLE posts (id INTEGER PRIMARY KEY)");
+
+    let result = p.command("print-schema").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(p.has_file(".diesel/introspection-cache.json"));
+    let cached = p.command("print-schema").run();
+    assert!(cached.is_success(), "Result was unsuccessful {:?}", cached);
+    assert_eq!(result.stdout(), cached.stdout());
+
+    db.execute("ALTER TABLE users ADD COLUMN name TEXT");
+
+    let result = p.command("print-schema").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(
+        result.stdout().contains("name -> Nullable<Text>"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+
+    p.delete_single_file(".diesel/introspection-cache.json");
+    let uncached = p.command("print-schema").arg("--no-cache").run();
+    assert!(
+        uncached.is_success(),
+        "Result was unsuccessful {:?}",
+        uncached
+    );
+    assert_eq!(result.stdout(), uncached.stdout());
+    assert!(!p.has_file(".diesel/introspection-cache.json"));
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn print_schema_introspection_cache_detects_changed_identity_columns() {
+    let p = project("print_schema_introspection_cache_detects_changed_identity_columns")
+        .file(
+            "diesel.toml",
+            r#"
+            [print_schema]
+            introspection_cache = true
+            "#,
+        )
+        .build();
+    let db = database(&p.database_url());
+
+    p.command("setup").run();
+    db.execute("CREATE TABLE users (id INTEGER GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY)");
+
+    let result = p.command("print-schema").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(
+        !result.stdout().contains("#[diesel(generated)]"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+
+    db.execute("ALTER TABLE users ALTER COLUMN id SET GENERATED ALWAYS");
+
+    let result = p.command("print-schema").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(
+        result.stdout().contains("#[diesel(generated)]"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn print_schema_with_crate_outputs() {
+    let p = project("print_schema_with_crate_outputs")
+        .file(
+            "diesel.toml",
+            r#"
+            [print_schema.crate_outputs]
+            game = "crates/game-service"
+            people = "crates/people-service"
+
+            [print_schema.game]
+            schema = "game"
+
+            [print_schema.people]
+            schema = "people"
+            "#,
+        )
+        .folder("crates/game-service")
+        .folder("crates/people-service")
+        .file(
+            "crates/game-service/Cargo.toml",
+            "[package]\nname = \"game-service\"\n",
+        )
+        .file(
+            "crates/people-service/Cargo.toml",
+            "[package]\nname = \"people-service\"\n",
+        )
+        .build();
+
+    p.command("setup").run();
+    p.create_migration(
+        "12345_create_schema",
+        "CREATE SCHEMA people;
+         CREATE SCHEMA game;
+         CREATE TABLE people.api_token (id SERIAL PRIMARY KEY);
+         CREATE TABLE game.game_session (
+             id SERIAL PRIMARY KEY,
+             api_token_id INTEGER NOT NULL REFERENCES people.api_token(id)
+         );",
+        None,
+        None,
+    );
+    let result = p.command("migration").arg("run").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+
+    let game = p.file_contents("crates/game-service/src/schema.rs");
+    assert!(
+        game.contains(
+            "diesel::joinable!(game_session -> people_service::schema::people::api_token (api_token_id));"
+        ),
+        "Unexpected schema {game}"
+    );
+    let people = p.file_contents("crates/people-service/src/schema.rs");
+    assert!(
+        people.contains("people.api_token (id)"),
+        "Unexpected schema {people}"
+    );
+    assert!(
+        !people.contains("game_session"),
+        "Unexpected schema {people}"
+    );
+}
+
+#[test]
+fn print_schema_with_module_and_header() {
+    let p = project("print_schema_with_module_and_header")
+        .folder("migrations")
+        .file(
+            "diesel.toml",
+            r#"
+            [print_schema]
+            file = "src/schema.rs"
+            module = "generated"
+            header = "// @generated\n#![allow(clippy::all)]"
+            "#,
+        )
+        .build();
+
+    p.command("setup").run();
+    p.create_migration(
+        "12345_create_users",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY);",
+        None,
+        None,
+    );
+    let result = p.command("migration").arg("run").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    let schema = p.file_contents("src/schema.rs").replace("\r\n", "\n");
+    assert!(
+        schema.starts_with("// @generated\n#![allow(clippy::all)]\n\npub mod generated {\n"),
+        "Unexpected schema {schema}"
+    );
+    assert!(
+        schema.contains("    diesel::table! {\n        users (id) {"),
+        "Unexpected schema {schema}"
+    );
+
+    // the written schema is considered up to date
+    let result = p
+        .command("migration")
+        .arg("run")
+        .arg("--locked-schema")
+        .run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+}
+
+#[test]
+fn print_schema_removed_tables_module() {
+    let p = project("print_schema_removed_tables_module")
+        .folder("migrations")
+        .file(
+            "diesel.toml",
+            r#"
+            [print_schema]
+            file = "src/schema.rs"
+            removed_tables_module = true
+            "#,
+        )
+        .build();
+
+    p.command("setup").run();
+    p.create_migration(
+        "12345_create_tables",
+        "CREATE TABLE users (id INTEGER PRIMARY KEY);
+         CREATE TABLE posts (id INTEGER PRIMARY KEY);",
+        None,
+        None,
+    );
+    let result = p.command("migration").arg("run").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    let schema = p.file_contents("src/schema.rs");
+    assert!(
+        !schema.contains("pub mod removed"),
+        "Unexpected schema {schema}"
+    );
+
+    p.create_migration("12346_drop_posts", "DROP TABLE posts", None, None);
+    let result = p.command("migration").arg("run").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    let schema = p.file_contents("src/schema.rs");
+    assert!(
+        schema.contains(
+            "#[deprecated(note = \"the `posts` table was removed from the database schema\")]\n    pub mod posts {"
+        ),
+        "Unexpected schema {schema}"
+    );
+    assert!(
+        schema.contains("pub use self::removed::*;"),
+        "Unexpected schema {schema}"
+    );
+    assert!(
+        !schema.contains("pub mod users"),
+        "Unexpected schema {schema}"
+    );
+
+    // the stubs are only kept for a single regeneration
+    p.create_migration(
+        "12347_create_comments",
+        "CREATE TABLE comments (id INTEGER PRIMARY KEY)",
+        None,
+        None,
+    );
+    let result = p.command("migration").arg("run").run();
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    let schema = p.file_contents("src/schema.rs");
+    assert!(
+        !schema.contains("pub mod removed"),
+        "Unexpected schema {schema}"
+    );
+}
+
 #[cfg(feature = "sqlite")]
 const BACKEND: &str = "sqlite";
 #[cfg(feature = "postgres")]
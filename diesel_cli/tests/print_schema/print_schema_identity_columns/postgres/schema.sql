@@ -0,0 +1,5 @@
+CREATE TABLE users (
+    id INTEGER GENERATED ALWAYS AS IDENTITY PRIMARY KEY,
+    external_id INTEGER GENERATED BY DEFAULT AS IDENTITY,
+    name TEXT NOT NULL
+);
@@ -0,0 +1,22 @@
+CREATE TABLE users (
+    id INTEGER PRIMARY KEY AUTO_INCREMENT COMMENT 'user id',
+    name VARCHAR(50) NOT NULL
+) COMMENT 'the user''s table';
+
+CREATE TABLE posts (
+    user_id INTEGER NOT NULL,
+    post_number INTEGER NOT NULL,
+    title VARCHAR(200) NOT NULL,
+    body TEXT,
+    PRIMARY KEY (user_id, post_number),
+    CONSTRAINT fk_posts_user FOREIGN KEY (user_id) REFERENCES users (id)
+);
+
+CREATE TABLE comments (
+    id INTEGER PRIMARY KEY AUTO_INCREMENT,
+    post_user_id INTEGER NOT NULL,
+    post_number INTEGER NOT NULL,
+    author_id INTEGER NOT NULL,
+    FOREIGN KEY (post_user_id, post_number) REFERENCES posts (user_id, post_number),
+    FOREIGN KEY (author_id) REFERENCES users (id)
+);
@@ -0,0 +1,21 @@
+CREATE TABLE users (
+    id INTEGER NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE countries (
+    code TEXT NOT NULL PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE posts (
+    id INTEGER NOT NULL PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES users,
+    country_code TEXT REFERENCES countries
+);
+
+CREATE TABLE post_tags (
+    post_id INTEGER NOT NULL REFERENCES posts,
+    tag TEXT NOT NULL,
+    PRIMARY KEY (post_id, tag)
+);
@@ -0,0 +1,13 @@
+CREATE TYPE user_status AS ENUM ('active', 'banned');
+
+CREATE TABLE users (
+    id SERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    status user_status NOT NULL
+);
+
+CREATE TABLE posts (
+    id SERIAL PRIMARY KEY,
+    user_id INTEGER NOT NULL REFERENCES users,
+    title TEXT NOT NULL
+);
@@ -0,0 +1,70 @@
+use crate::support::project;
+
+const SCHEMA: &str = r#"
+diesel::table! {
+    users (id) {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
+pub mod blog {
+    diesel::table! {
+        blog.posts (user_id, title) {
+            user_id -> Integer,
+            title -> Text,
+        }
+    }
+}
+"#;
+
+#[test]
+fn seed_init_generates_seed_binary() {
+    let p = project("seed_init_generates_seed_binary")
+        .folder("src")
+        .file("diesel.toml", "[print_schema]\nfile = \"src/schema.rs\"")
+        .file("src/schema.rs", SCHEMA)
+        .build();
+
+    let result = p.command("seed").arg("init").run();
+
+    assert!(result.is_success(), "Result was unsuccessful {:?}", result);
+    assert!(
+        result.stdout().contains("Creating src/bin/seed.rs"),
+        "Unexpected stdout {}",
+        result.stdout()
+    );
+    let seed = p.file_contents("src/bin/seed.rs");
+    assert!(seed.contains("#[path = \"../schema.rs\"]"), "{seed}");
+    assert!(seed.contains("::establish(&database_url)"), "{seed}");
+    // the generated integer primary key is left out
+    assert!(!seed.contains("schema::users::id.eq"), "{seed}");
+    assert!(seed.contains("schema::users::name.eq(todo!())"), "{seed}");
+    assert!(
+        seed.contains("schema::blog::posts::user_id.eq(todo!())"),
+        "{seed}"
+    );
+    assert!(
+        seed.contains("schema::blog::posts::title.eq(todo!())"),
+        "{seed}"
+    );
+}
+
+#[test]
+fn seed_init_does_not_overwrite_existing_seed_binary() {
+    let p = project("seed_init_does_not_overwrite")
+        .folder("src/bin")
+        .file("src/schema.rs", SCHEMA)
+        .file("src/bin/seed.rs", "fn main() {}")
+        .build();
+
+    let result = p.command("seed").arg("init").run();
+
+    assert!(!result.is_success(), "Result was successful {:?}", result);
+    assert!(
+        result.stderr().contains("already exists"),
+        "Unexpected stderr {}",
+        result.stderr()
+    );
+    assert_eq!("fn main() {}", p.file_contents("src/bin/seed.rs"));
+}
@@ -11,13 +11,6 @@ table! {
 fn main() {
     let mut conn = MysqlConnection::establish("…").unwrap();
 
-    // no support for aggregate filters
-    users::table
-        .select(dsl::count(users::id).aggregate_filter(users::name.eq("Sean")))
-        .get_result::<i64>(&mut conn)
-        //~^ ERROR: `Filter<WhereClause<Grouped<Eq<name, ...>>>>` is no valid SQL fragment for the `Mysql` backend
-        .unwrap();
-
     // no support for aggregate order
     users::table
         .select(dsl::count(users::id).aggregate_order(users::name))
@@ -11,7 +11,7 @@ struct User1 {
 #[derive(Queryable)]
 struct User2 {
     #[diesel(what = true)]
-    //~^ ERROR: unknown attribute, expected one of `embed`, `skip_insertion`, `column_name`, `sql_type`, `treat_none_as_default_value`, `treat_none_as_null`, `serialize_as`, `deserialize_as`, `select_expression`, `select_expression_type`
+    //~^ ERROR: unknown attribute, expected one of `embed`, `skip_insertion`, `column_name`, `sql_type`, `treat_none_as_default_value`, `treat_none_as_null`, `treat_empty_string_as_null`, `serialize_as`, `deserialize_as`, `select_expression`, `select_expression_type`
     id: i32,
 }
 
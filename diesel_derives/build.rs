@@ -217,6 +217,10 @@ fn main() {
                 "diesel_derives__tests__define_sql_function_1.snap"
             })],
         ),
+        (
+            "diesel_test",
+            vec![Example::new("diesel_derives__tests__diesel_test_1.snap")],
+        ),
         (
             "enum",
             vec![
@@ -291,12 +291,12 @@ fn field_changeset_ty(
     let column_name = field.column_name()?.to_ident()?;
     if !treat_none_as_null && is_option_ty(&field.ty) {
         let field_ty = inner_of_option_ty(&field.ty);
-        Ok(
-            quote!(std::option::Option<diesel::dsl::Eq<#table_name::#column_name, #lifetime #field_ty>>),
-        )
+        let bind_ty = field.ty_for_bind(quote!(#lifetime #field_ty));
+        Ok(quote!(std::option::Option<diesel::dsl::Eq<#table_name::#column_name, #bind_ty>>))
     } else {
         let field_ty = &field.ty;
-        Ok(quote!(diesel::dsl::Eq<#table_name::#column_name, #lifetime #field_ty>))
+        let bind_ty = field.ty_for_bind(quote!(#lifetime #field_ty));
+        Ok(quote!(diesel::dsl::Eq<#table_name::#column_name, #bind_ty>))
     }
 }
 
@@ -309,19 +309,19 @@ fn field_changeset_expr(
     let field_name = &field.name;
     let column_name = field.column_name()?.to_ident()?;
     if !treat_none_as_null && is_option_ty(&field.ty) {
+        let value = field.expr_for_bind(quote!(x));
         if lifetime.is_some() {
             Ok(
-                quote!(self.#field_name.as_ref().map(|x| diesel::ExpressionMethods::eq(#table_name::#column_name, x))),
+                quote!(self.#field_name.as_ref().map(|x| diesel::ExpressionMethods::eq(#table_name::#column_name, #value))),
             )
         } else {
             Ok(
-                quote!(self.#field_name.map(|x| diesel::ExpressionMethods::eq(#table_name::#column_name, x))),
+                quote!(self.#field_name.map(|x| diesel::ExpressionMethods::eq(#table_name::#column_name, #value))),
             )
         }
     } else {
-        Ok(
-            quote!(diesel::ExpressionMethods::eq(#table_name::#column_name, #lifetime self.#field_name)),
-        )
+        let value = field.expr_for_bind(quote!(#lifetime self.#field_name));
+        Ok(quote!(diesel::ExpressionMethods::eq(#table_name::#column_name, #value)))
     }
 }
 
@@ -0,0 +1,106 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::spanned::Spanned;
+
+pub(crate) struct DieselTestArgs {
+    migrations: Option<syn::LitStr>,
+}
+
+impl DieselTestArgs {
+    pub(crate) fn parse_from_macro_input(input: TokenStream) -> syn::Result<Self> {
+        if input.is_empty() {
+            return Ok(Self { migrations: None });
+        }
+        let input_span = input.span();
+        let parsed: syn::MetaNameValue = syn::parse2(input).map_err(|e| {
+            let span = e.span();
+            syn::Error::new(
+                span,
+                format!("{e}, the correct format is `migrations = \"path/to/migrations\"`"),
+            )
+        })?;
+        match parsed {
+            syn::MetaNameValue {
+                path,
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(s),
+                        ..
+                    }),
+                ..
+            } if path.is_ident("migrations") => Ok(Self {
+                migrations: Some(s),
+            }),
+            _ => Err(syn::Error::new(
+                input_span,
+                "Invalid config, the correct format is `migrations = \"path/to/migrations\"`",
+            )),
+        }
+    }
+}
+
+pub(crate) fn expand(args: DieselTestArgs, item: syn::ItemFn) -> syn::Result<TokenStream> {
+    let syn::ItemFn {
+        attrs,
+        vis,
+        sig,
+        block,
+    } = item;
+
+    if let Some(asyncness) = sig.asyncness {
+        return Err(syn::Error::new(
+            asyncness.span(),
+            "`#[diesel_test]` does not support async functions",
+        ));
+    }
+    if !sig.generics.params.is_empty() || sig.generics.where_clause.is_some() {
+        return Err(syn::Error::new(
+            sig.generics.span(),
+            "`#[diesel_test]` does not support generic functions",
+        ));
+    }
+    let connection_arg = match sig.inputs.first() {
+        Some(syn::FnArg::Typed(arg)) if sig.inputs.len() == 1 => arg,
+        _ => {
+            return Err(syn::Error::new(
+                sig.inputs.span(),
+                "`#[diesel_test]` expects a function with a single \
+                 `&mut YourConnection` argument",
+            ));
+        }
+    };
+    let connection_type = match &*connection_arg.ty {
+        syn::Type::Reference(syn::TypeReference {
+            mutability: Some(_),
+            elem,
+            ..
+        }) => elem,
+        ty => {
+            return Err(syn::Error::new(
+                ty.span(),
+                "`#[diesel_test]` expects the connection to be passed as `&mut YourConnection`",
+            ));
+        }
+    };
+
+    let fn_name = &sig.ident;
+    let output = &sig.output;
+    let migrations = args.migrations.map(|m| quote!(#m));
+
+    Ok(quote! {
+        #(#attrs)*
+        #[test]
+        #vis fn #fn_name() #output {
+            fn __diesel_test_body(#connection_arg) #output #block
+
+            const MIGRATIONS: diesel_migrations::EmbeddedMigrations =
+                diesel_migrations::embed_migrations!(#migrations);
+
+            diesel_migrations::__run_diesel_test::<#connection_type, _>(
+                concat!(module_path!(), "::", stringify!(#fn_name)),
+                MIGRATIONS,
+                __diesel_test_body,
+            )
+        }
+    })
+}
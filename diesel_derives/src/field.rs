@@ -1,7 +1,8 @@
 use diesel_attribute_parser::{AttributeSpanWrapper, FieldAttr, SqlIdentifier, parse_attributes};
 use proc_macro2::{Span, TokenStream};
+use quote::quote;
 use syn::spanned::Spanned;
-use syn::{Expr, Field as SynField, Ident, Index, Result, Type};
+use syn::{Expr, Field as SynField, Ident, Index, Result, Type, parse_quote};
 
 pub struct Field {
     pub ty: Type,
@@ -11,6 +12,7 @@ pub struct Field {
     pub sql_type: Option<AttributeSpanWrapper<Type>>,
     pub treat_none_as_default_value: Option<AttributeSpanWrapper<bool>>,
     pub treat_none_as_null: Option<AttributeSpanWrapper<bool>>,
+    pub treat_empty_string_as_null: Option<AttributeSpanWrapper<bool>>,
     pub serialize_as: Option<AttributeSpanWrapper<Type>>,
     pub deserialize_as: Option<AttributeSpanWrapper<Type>>,
     pub select_expression: Option<AttributeSpanWrapper<Expr>>,
@@ -37,6 +39,7 @@ impl Field {
         let mut select_expression_type = None;
         let mut treat_none_as_default_value = None;
         let mut treat_none_as_null = None;
+        let mut treat_empty_string_as_null = None;
 
         for attr in parse_attributes(attrs)? {
             let attribute_span = attr.attribute_span;
@@ -70,6 +73,13 @@ impl Field {
                         ident_span,
                     })
                 }
+                FieldAttr::TreatEmptyStringAsNull(_) => {
+                    treat_empty_string_as_null = Some(AttributeSpanWrapper {
+                        item: true,
+                        attribute_span,
+                        ident_span,
+                    })
+                }
                 FieldAttr::SerializeAs(_, value) => {
                     check_serde_as_supported_type(&value, "serialize_as")?;
                     serialize_as = Some(AttributeSpanWrapper {
@@ -125,6 +135,29 @@ impl Field {
             }
         }
 
+        if let Some(ref attr) = treat_empty_string_as_null {
+            if serialize_as.is_some() {
+                return Err(syn::Error::new(
+                    attr.ident_span,
+                    "`treat_empty_string_as_null` cannot be combined with `serialize_as`",
+                ));
+            }
+            if embed.is_some() {
+                return Err(syn::Error::new(
+                    attr.ident_span,
+                    "`treat_empty_string_as_null` cannot be combined with `embed`",
+                ));
+            }
+            // `Option<String>` fields already load `NULL` values as `None`
+            if deserialize_as.is_none() && is_string_ty(ty) {
+                deserialize_as = Some(AttributeSpanWrapper {
+                    item: parse_quote!(diesel::internal::derives::empty_string::NullAsEmptyString),
+                    attribute_span: attr.attribute_span,
+                    ident_span: attr.ident_span,
+                });
+            }
+        }
+
         let name = match ident.clone() {
             Some(x) => FieldName::Named(x),
             None => FieldName::Unnamed(index.into()),
@@ -143,6 +176,7 @@ impl Field {
             sql_type,
             treat_none_as_default_value,
             treat_none_as_null,
+            treat_empty_string_as_null,
             serialize_as,
             deserialize_as,
             select_expression,
@@ -176,6 +210,33 @@ impl Field {
         }
     }
 
+    pub(crate) fn treat_empty_string_as_null(&self) -> bool {
+        self.treat_empty_string_as_null
+            .as_ref()
+            .map(|a| a.item)
+            .unwrap_or(false)
+    }
+
+    /// The type of the value bound to the query for this field,
+    /// given the type of the value taken from the struct
+    pub(crate) fn ty_for_bind(&self, ty: TokenStream) -> TokenStream {
+        if self.treat_empty_string_as_null() {
+            quote!(<#ty as diesel::internal::derives::empty_string::EmptyStringAsNull>::Output)
+        } else {
+            ty
+        }
+    }
+
+    /// The value bound to the query for this field,
+    /// given the value taken from the struct
+    pub(crate) fn expr_for_bind(&self, expr: TokenStream) -> TokenStream {
+        if self.treat_empty_string_as_null() {
+            quote!(diesel::internal::derives::empty_string::EmptyStringAsNull::empty_string_as_null(#expr))
+        } else {
+            expr
+        }
+    }
+
     pub(crate) fn embed(&self) -> bool {
         self.embed.as_ref().map(|a| a.item).unwrap_or(false)
     }
@@ -206,6 +267,19 @@ impl quote::ToTokens for FieldName {
     }
 }
 
+fn is_string_ty(ty: &Type) -> bool {
+    match ty {
+        Type::Path(syn::TypePath { qself: None, path }) => path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident == "String" && s.arguments.is_none()),
+        Type::Paren(syn::TypeParen { elem, .. }) | Type::Group(syn::TypeGroup { elem, .. }) => {
+            is_string_ty(elem)
+        }
+        _ => false,
+    }
+}
+
 fn check_serde_as_supported_type(ty: &Type, attr_name: &str) -> Result<()> {
     match ty {
         Type::Path(_) => Ok(()),
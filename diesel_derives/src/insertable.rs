@@ -312,20 +312,22 @@ fn field_ty(
     let span = Span::mixed_site().located_at(field.span);
     if treat_none_as_default_value {
         let inner_ty = inner_of_option_ty(&field.ty);
+        let bind_ty = field.ty_for_bind(quote!(#lifetime #inner_ty));
 
         Ok(quote_spanned! {span=>
             std::option::Option<diesel::dsl::Eq<
                 #table_name::#column_name,
-                #lifetime #inner_ty,
+                #bind_ty,
             >>
         })
     } else {
         let inner_ty = &field.ty;
+        let bind_ty = field.ty_for_bind(quote!(#lifetime #inner_ty));
 
         Ok(quote_spanned! {span=>
             diesel::dsl::Eq<
                 #table_name::#column_name,
-                #lifetime #inner_ty,
+                #bind_ty,
             >
         })
     }
@@ -343,20 +345,21 @@ fn field_expr(
     let column: Expr = parse_quote!(#table_name::#column_name);
     if treat_none_as_default_value {
         if is_option_ty(&field.ty) {
+            let value = field.expr_for_bind(quote!(x));
             if lifetime.is_some() {
                 Ok(
-                    quote!(self.#field_name.as_ref().map(|x| diesel::ExpressionMethods::eq(#column, x))),
+                    quote!(self.#field_name.as_ref().map(|x| diesel::ExpressionMethods::eq(#column, #value))),
                 )
             } else {
-                Ok(quote!(self.#field_name.map(|x| diesel::ExpressionMethods::eq(#column, x))))
+                Ok(quote!(self.#field_name.map(|x| diesel::ExpressionMethods::eq(#column, #value))))
             }
         } else {
-            Ok(
-                quote!(std::option::Option::Some(diesel::ExpressionMethods::eq(#column, #lifetime self.#field_name))),
-            )
+            let value = field.expr_for_bind(quote!(#lifetime self.#field_name));
+            Ok(quote!(std::option::Option::Some(diesel::ExpressionMethods::eq(#column, #value))))
         }
     } else {
-        Ok(quote!(diesel::ExpressionMethods::eq(#column, #lifetime self.#field_name)))
+        let value = field.expr_for_bind(quote!(#lifetime self.#field_name));
+        Ok(quote!(diesel::ExpressionMethods::eq(#column, #value)))
     }
 }
 
@@ -37,6 +37,7 @@ mod associations;
 mod diesel_for_each_tuple;
 mod diesel_numeric_ops;
 mod diesel_public_if;
+mod diesel_test;
 mod enum_;
 mod from_sql_row;
 mod has_query;
@@ -114,6 +115,10 @@ mod valid_grouping;
 ///   the actual field type.
 /// * `#[diesel(treat_none_as_null = true/false)]`, overrides the container-level
 ///   `treat_none_as_null` attribute for the current field.
+/// * `#[diesel(treat_empty_string_as_null)]`, updates the column to `NULL` if the
+///   field contains an empty string. This can be used on fields of type `String`,
+///   `&str`, `Cow<str>` or an `Option` of these types. It cannot be combined with
+///   `serialize_as` or `embed`.
 /// * `#[diesel(skip_update)]`, skips updating this field. Useful for working with
 ///   generated columns.
 #[cfg_attr(diesel_docsrs, doc = include_str!(concat!(env!("OUT_DIR"), "/as_changeset.md")))]
@@ -398,8 +403,13 @@ fn derive_identifiable_inner(input: proc_macro2::TokenStream) -> proc_macro2::To
 ///   the actual field type.
 /// * `#[diesel(treat_none_as_default_value = true/false)]`, overrides the container-level
 ///   `treat_none_as_default_value` attribute for the current field.
+/// * `#[diesel(treat_empty_string_as_null)]`, inserts `NULL` if the field contains
+///   an empty string. This can be used on fields of type `String`, `&str`,
+///   `Cow<str>` or an `Option` of these types. It cannot be combined with
+///   `serialize_as` or `embed`.
 /// * `#[diesel(skip_insertion)]`, skips insertion of this field. Useful for working with
-///   generated columns.
+///   generated columns. Fields mapping to columns marked as generated in
+///   [`table!`](../diesel/macro.table.html) are skipped without this attribute.
 ///
 /// # Examples
 ///
@@ -569,6 +579,10 @@ fn derive_query_id_inner(input: proc_macro2::TokenStream) -> proc_macro2::TokenS
 ///   + Implementing any of the [`TryInto`]/[`TryFrom`]/[`Into`]/[`From`] traits
 ///   + Using an method on the type directly (Useful if it's not possible to implement the traits mentioned above
 ///     due to the orphan rule)
+/// * `#[diesel(treat_empty_string_as_null)]`, loads `NULL` values as empty
+///   strings into `String` fields. This is the counterpart of the attribute
+///   with the same name on `Insertable` and `AsChangeset`, so that the same struct
+///   can be used for all of them.
 ///
 /// [`TryInto`]: https://doc.rust-lang.org/stable/std/convert/trait.TryInto.html
 /// [`TryFrom`]: https://doc.rust-lang.org/stable/std/convert/trait.TryFrom.html
@@ -790,6 +804,8 @@ fn derive_queryable_inner(input: proc_macro2::TokenStream) -> proc_macro2::Token
 ///   into the field type, the implementation will deserialize into `Type`.
 ///   Then `Type` is converted via `.into()` into the field type. By default,
 ///   this derive will deserialize directly into the field type
+/// * `#[diesel(treat_empty_string_as_null)]`, loads `NULL` values as empty
+///   strings into `String` fields
 /// * `#[diesel(embed)]`, specifies that the current field maps not only
 ///   a single database column, but it is a type that implements
 ///   `QueryableByName` on its own
@@ -1433,6 +1449,70 @@ fn __diesel_public_if_inner(
 /// changes which columns form the primary key, the whole `table!` block still
 /// needs to be duplicated behind the relevant `#[cfg(...)]` attributes.
 ///
+/// Columns whose value is computed by the database can record the generation
+/// expression with the `generated_always_as` attribute. Other columns that
+/// the database always generates, like `GENERATED ALWAYS AS IDENTITY` columns,
+/// can be marked with `#[diesel(generated)]`. Values for both kinds of columns
+/// are skipped by inserts, including those of
+/// [`Insertable`](../diesel/insertable/derive.Insertable.html) structs, so that the database
+/// can compute them. `diesel migration generate --diff-schema` uses the
+/// generation expression to create these columns as
+/// `GENERATED ALWAYS AS (...) STORED`. `diesel print-schema` emits
+/// `generated_always_as` for stored generated columns and
+/// `#[diesel(generated)]` for `GENERATED ALWAYS AS IDENTITY` columns.
+///
+/// ```
+/// # extern crate diesel;
+///
+/// diesel::table! {
+///     users {
+///         #[diesel(generated)]
+///         id -> Integer,
+///         first_name -> Text,
+///         last_name -> Text,
+///         #[generated_always_as = "first_name || ' ' || last_name"]
+///         full_name -> Text,
+///     }
+/// }
+/// ```
+///
+/// Columns holding sensitive data like passwords or personal information
+/// can be marked with `#[diesel(sensitive)]`. Bind values following such a
+/// column, for example values compared with or assigned to it, are printed
+/// as `<redacted>` whenever a query is rendered via
+/// [`debug_query`](../diesel/fn.debug_query.html) or passed to an
+/// [`Instrumentation`](../diesel/connection/trait.Instrumentation.html).
+/// This applies until the next column or clause. Binds of raw SQL can be
+/// redacted with [`Sensitive`](../diesel/query_builder/struct.Sensitive.html).
+///
+/// ```
+/// # extern crate diesel;
+///
+/// diesel::table! {
+///     users {
+///         id -> Integer,
+///         name -> Text,
+///         #[diesel(sensitive)]
+///         password_hash -> Text,
+///     }
+/// }
+/// ```
+///
+/// The generated module is `pub` by default. If you don't want your schema
+/// to be part of the public API of your crate, you can specify a different
+/// visibility in front of the table name:
+///
+/// ```
+/// # extern crate diesel;
+///
+/// diesel::table! {
+///     pub(crate) users {
+///         id -> Integer,
+///         name -> Text,
+///     }
+/// }
+/// ```
+///
 /// This module will also contain several helper types:
 ///
 /// dsl
@@ -1793,6 +1873,18 @@ fn view_proc_inner(input: proc_macro2::TokenStream) -> proc_macro2::TokenStream
 /// # fn main() {}
 /// ```
 ///
+/// # Transactions
+///
+/// The derived transaction manager forwards to the transaction manager of the
+/// inner connection, so nested transactions, savepoints, `test_transaction`
+/// and `transaction_with_retries` behave exactly like on the inner connection
+/// type. In addition the enum gets inherent `transaction_depth` and `is_broken`
+/// methods, which report the state of the inner transaction manager for
+/// the sync and the `diesel-async` variant alike. `is_broken` returns `true`
+/// if the connection is left with an open transaction or a transaction manager
+/// in an error state, which is what connection pools use to decide whether the
+/// connection can be reused.
+///
 /// # Limitations
 ///
 /// The derived connection implementation can only cover the common subset of
@@ -2760,6 +2852,8 @@ fn declare_sql_function_inner(
 ///   Then `Type` is converted via
 ///   [`.try_into`](https://doc.rust-lang.org/stable/std/convert/trait.TryInto.html#tymethod.try_into)
 ///   into the field type. By default, this derive will deserialize directly into the field type
+/// * `#[diesel(treat_empty_string_as_null)]`, loads `NULL` values as empty
+///   strings into `String` fields
 ///
 /// # Examples
 ///
@@ -2974,3 +3068,71 @@ fn derive_enum_inner(input: proc_macro2::TokenStream) -> proc_macro2::TokenStrea
         .and_then(enum_::derive)
         .unwrap_or_else(syn::Error::into_compile_error)
 }
+
+/// Declares a test function that runs against a fresh database
+///
+/// This attribute replaces `#[test]`. The annotated function needs to accept exactly one
+/// argument of the type `&mut YourConnection`. Before the test body is executed a new,
+/// empty database is created for this test and all migrations from the configured
+/// migrations directory are applied to it. The database is removed again after the
+/// test finished, independently of whether it succeeded or panicked.
+///
+/// The migrations are embedded and run via `diesel_migrations`, so your crate
+/// needs to depend on `diesel_migrations` as well. This attribute should be used
+/// via the re-export as `diesel::diesel_test`.
+///
+/// The connection type needs to implement `diesel::connection::TestDatabaseConnection`,
+/// which is done for the following connection types:
+///
+/// * `SqliteConnection`: Each test uses a new database file in the temporary directory
+///   of your system
+/// * `PgConnection`: Each test creates a new database on the server specified
+///   by the `PG_DATABASE_URL` environment variable (falling back to `DATABASE_URL`).
+///   If the `DIESEL_TEST_PG_TEMPLATE` environment variable is set, the test database is
+///   created as a copy of the named template database. This allows to run migrations
+///   only once for your whole test suite, as only migrations missing from the
+///   template are applied.
+///
+/// # Attributes
+///
+/// * `#[diesel_test(migrations = "path/to/migrations")]`, the migrations directory relative
+///   to the directory containing your `Cargo.toml`. If not given, the migrations directory
+///   is located in the same way as for `embed_migrations!()`.
+///
+/// # Example
+///
+/// ```ignore
+/// use diesel::prelude::*;
+/// use diesel::diesel_test;
+///
+/// #[diesel_test(migrations = "migrations")]
+/// fn users_can_be_inserted(conn: &mut SqliteConnection) {
+///     diesel::insert_into(users::table)
+///         .values(users::name.eq("Sean"))
+///         .execute(conn)
+///         .unwrap();
+///
+///     let count = users::table.count().get_result::<i64>(conn).unwrap();
+///     assert_eq!(count, 1);
+/// }
+/// ```
+#[cfg_attr(diesel_docsrs, doc = include_str!(concat!(env!("OUT_DIR"), "/diesel_test.md")))]
+#[proc_macro_attribute]
+pub fn diesel_test(
+    attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    diesel_test_inner(attr.into(), input.into()).into()
+}
+
+fn diesel_test_inner(
+    attr: proc_macro2::TokenStream,
+    input: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    diesel_test::DieselTestArgs::parse_from_macro_input(attr)
+        .and_then(|args| {
+            let item = syn::parse2(input)?;
+            diesel_test::expand(args, item)
+        })
+        .unwrap_or_else(syn::Error::into_compile_error)
+}
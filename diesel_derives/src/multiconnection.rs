@@ -351,6 +351,37 @@ fn generate_connection_impl(
         }
     });
 
+    // the async connection trait does not provide these methods
+    let statement_cache_impl = (!is_async).then(|| {
+        let clear_arms = connection_types.iter().map(|c| {
+            let variant_ident = c.name;
+            quote::quote! {
+                Self::#variant_ident(conn) => {
+                    #conn::clear_prepared_statement_cache(conn);
+                }
+            }
+        });
+        let cached_statements_arms = connection_types.iter().map(|c| {
+            let variant_ident = c.name;
+            quote::quote! {
+                Self::#variant_ident(conn) => #conn::cached_statements(conn)
+            }
+        });
+        quote::quote! {
+            fn clear_prepared_statement_cache(&mut self) {
+                match self {
+                    #(#clear_arms,)*
+                }
+            }
+
+            fn cached_statements(&self) -> std::vec::Vec<std::string::String> {
+                match self {
+                    #(#cached_statements_arms,)*
+                }
+            }
+        }
+    });
+
     let get_instrumentation_impl = connection_types.iter().map(|c| {
         let variant_ident = c.name;
         quote::quote! {
@@ -394,6 +425,57 @@ fn generate_connection_impl(
         }
     });
 
+    // the async transaction manager does not provide `begin_transaction_with`
+    let begin_transaction_with_impl = (!is_async).then(|| {
+        let arms = connection_types.iter().map(|c| {
+            let ident = c.name;
+            let ty = c.ty;
+            quote::quote! {
+                Self::#ident(conn) => <#ty as #conn>::TransactionManager::begin_transaction_with(conn, isolation, access)
+            }
+        });
+        quote::quote! {
+            fn begin_transaction_with(
+                conn: &mut MultiConnection,
+                isolation: diesel::connection::Isolation,
+                access: diesel::connection::Access,
+            ) -> diesel::QueryResult<()> {
+                match conn {
+                    #(#arms,)*
+                }
+            }
+        }
+    });
+
+    // the async transaction manager does not provide savepoint methods
+    let savepoint_impl = (!is_async).then(|| {
+        let methods = [
+            "begin_savepoint",
+            "release_savepoint",
+            "rollback_to_savepoint",
+        ]
+        .map(|method| {
+            let method = syn::Ident::new(method, proc_macro2::Span::call_site());
+            let arms = connection_types.iter().map(|c| {
+                let ident = c.name;
+                let ty = c.ty;
+                quote::quote! {
+                    Self::#ident(conn) => <#ty as #conn>::TransactionManager::#method(conn, name)
+                }
+            });
+            quote::quote! {
+                fn #method(conn: &mut MultiConnection, name: &str) -> diesel::QueryResult<()> {
+                    match conn {
+                        #(#arms,)*
+                    }
+                }
+            }
+        });
+        quote::quote! {
+            #(#methods)*
+        }
+    });
+
     let is_broken_transaction_manager_impl = connection_types.iter().map(|c| {
         let ident = c.name;
         let ty = c.ty;
@@ -652,6 +734,8 @@ fn generate_connection_impl(
                 }
             }
 
+            #statement_cache_impl
+
             #async_token fn begin_test_transaction(&mut self) -> diesel::QueryResult<()> {
                 match self {
                     #(#impl_begin_test_transaction,)*
@@ -670,6 +754,10 @@ fn generate_connection_impl(
                 }
             }
 
+            #begin_transaction_with_impl
+
+            #savepoint_impl
+
             #async_token fn rollback_transaction(conn: &mut MultiConnection) -> diesel::QueryResult<()> {
                 match conn {
                     #(#rollback_transaction_impl,)*
@@ -695,6 +783,27 @@ fn generate_connection_impl(
             }
         }
 
+        impl MultiConnection {
+            /// Returns the number of open transactions and savepoints
+            /// of the inner connection
+            ///
+            /// This is `None` outside of a transaction, `1` inside of a transaction
+            /// and increases by one for each nested transaction or savepoint.
+            pub fn transaction_depth(&mut self) -> diesel::QueryResult<Option<std::num::NonZeroU32>> {
+                <Self as #transaction_manager<Self>>::transaction_manager_status_mut(self).transaction_depth()
+            }
+
+            /// Checks whether the inner connection is left with an open transaction
+            /// or a transaction manager in an error state
+            ///
+            /// Connection pools and other wrappers use this to decide whether the
+            /// connection can be reused. Transactions opened by
+            /// `begin_test_transaction` are not considered as broken.
+            pub fn is_broken(&mut self) -> bool {
+                <Self as #transaction_manager<Self>>::is_broken_transaction_manager(self)
+            }
+        }
+
         #migration_connection_impl
 
         #r2d2_impl
@@ -1576,6 +1685,14 @@ fn generate_querybuilder(
             <S> diesel::query_builder::QueryFragment<super::backend::MultiBackend, super::backend::MultiAliasSyntax>
                 for diesel::query_source::Alias<S>
         },
+        quote::quote! {
+            <L, R> diesel::query_builder::QueryFragment<super::backend::MultiBackend, super::backend::MultiIntervalArithmetic>
+                for diesel::internal::derives::numeric_ops::Add<L, R>
+        },
+        quote::quote! {
+            <L, R> diesel::query_builder::QueryFragment<super::backend::MultiBackend, super::backend::MultiIntervalArithmetic>
+                for diesel::internal::derives::numeric_ops::Sub<L, R>
+        },
     ])
     .map(|t| generate_queryfragment_impls(t, &query_fragment_bounds));
 
@@ -2023,6 +2140,7 @@ fn generate_backend(connection_types: &[ConnectionVariant], helper: &MultiHelper
         pub struct MultiWindowFrameExclusionSupport;
         pub struct MultiAggregateFunctionExpressions;
         pub struct MultiBuiltInWindowFunctionRequireOrder;
+        pub struct MultiIntervalArithmetic;
 
         impl diesel::backend::SqlDialect for MultiBackend {
             type ReturningClause = MultiReturningClause;
@@ -2041,6 +2159,7 @@ fn generate_backend(connection_types: &[ConnectionVariant], helper: &MultiHelper
             type WindowFrameExclusionSupport = MultiWindowFrameExclusionSupport;
             type AggregateFunctionExpressions = MultiAggregateFunctionExpressions;
             type BuiltInWindowFunctionRequireOrder = MultiBuiltInWindowFunctionRequireOrder;
+            type IntervalArithmetic = MultiIntervalArithmetic;
         }
 
         impl diesel::internal::derives::multiconnection::TrustedBackend for MultiBackend {}
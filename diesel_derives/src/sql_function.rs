@@ -471,6 +471,40 @@ fn expand_nonvariadic(
         ));
 
     let args_iter = args.iter();
+    // functions with a single argument can use the default implementation
+    // that wraps the whole argument list into one `CASE WHEN … END` expression
+    let walk_filtered_arguments = if arg_name.len() > 1 {
+        quote! {
+            #[allow(unused_assignments)]
+            fn walk_filtered_arguments<'__b, __F>(
+                &'__b self,
+                filter: &'__b __F,
+                mut out: AstPass<'_, '__b, __DieselInternal>,
+            ) -> QueryResult<()>
+            where
+                __F: QueryFragment<__DieselInternal>,
+            {
+                let mut needs_comma = false;
+                #(
+                    if !self.#arg_name.is_noop(out.backend())? {
+                        if needs_comma {
+                            out.push_sql(", ");
+                        }
+                        out.push_sql("CASE WHEN ");
+                        filter.walk_ast(out.reborrow())?;
+                        out.push_sql(" THEN ");
+                        self.#arg_name.walk_ast(out.reborrow())?;
+                        out.push_sql(" END");
+                        needs_comma = true;
+                    }
+                )*
+                Ok(())
+            }
+        }
+    } else {
+        quote! {}
+    };
+
     let mut tokens = quote! {
         use diesel::{self, QueryResult};
         use diesel::expression::{AsExpression, Expression, SelectableExpression, AppearsOnTable, ValidGrouping};
@@ -540,6 +574,8 @@ fn expand_nonvariadic(
                 )*
                 Ok(())
             }
+
+            #walk_filtered_arguments
         }
 
         #query_fragment_impl
@@ -325,6 +325,11 @@ fn expand(input: TableDecl, kind: QuerySourceMacroKind) -> TokenStream {
         input.view.table_name
     );
     let meta = &input.view.meta;
+    let visibility = input
+        .view
+        .visibility
+        .clone()
+        .unwrap_or_else(|| parse_quote!(pub));
     let table_name = &input.view.table_name;
     let imports = if input.view.use_statements.is_empty() {
         vec![parse_quote!(
@@ -553,7 +558,7 @@ fn expand(input: TableDecl, kind: QuerySourceMacroKind) -> TokenStream {
     quote::quote! {
         #(#meta)*
         #[allow(unused_imports, dead_code, unreachable_pub, unused_qualifications)]
-        pub mod #table_name {
+        #visibility mod #table_name {
             const _: () = {
                 assert!(
                     #column_count <= diesel::internal::table_macro::MAX_COLUMN_COUNT,
@@ -1071,6 +1076,20 @@ fn expand_column_def(
         }
     });
 
+    let sensitive = column_def.sensitive;
+    let sensitive_const = column_def.sensitive.then(|| {
+        quote::quote! {
+            const SENSITIVE: bool = true;
+        }
+    });
+
+    let generated_const =
+        (column_def.generated || column_def.generated_always_as.is_some()).then(|| {
+            quote::quote! {
+                const GENERATED: bool = true;
+            }
+        });
+
     let table_specific_impls = if matches!(kind, QuerySourceMacroKind::Table) {
         quote::quote! {
             #(#cfg_attrs)*
@@ -1078,6 +1097,8 @@ fn expand_column_def(
                 type Table = super::table;
 
                 const NAME: &'static str = #sql_name;
+                #sensitive_const
+                #generated_const
             }
         }
     } else {
@@ -1087,6 +1108,7 @@ fn expand_column_def(
                 type QueryRelation = super::view;
 
                 const NAME: &'static str = #sql_name;
+                #sensitive_const
             }
         }
     };
@@ -1110,6 +1132,7 @@ fn expand_column_def(
             #[allow(non_snake_case)]
             fn walk_ast<'b>(&'b self, mut __diesel_internal_out: diesel::query_builder::AstPass<'_, 'b, DB>) -> diesel::result::QueryResult<()>
             {
+                __diesel_internal_out.redact_following_binds(#sensitive);
                 if !__diesel_internal_out.should_skip_from() {
                     const FROM_CLAUSE: diesel::internal::table_macro::StaticQueryFragmentInstance<#query_source_ident> = diesel::internal::table_macro::StaticQueryFragmentInstance::new();
 
@@ -0,0 +1,24 @@
+use super::AttributeMacro;
+
+use super::expand_with;
+
+#[test]
+pub(crate) fn diesel_test_1() {
+    let attr = quote::quote! {
+        migrations = "migrations"
+    };
+    let input = quote::quote! {
+        fn users_can_be_inserted(conn: &mut SqliteConnection) {
+            diesel::insert_into(users::table)
+                .values(users::name.eq("Sean"))
+                .execute(conn)
+                .unwrap();
+        }
+    };
+    expand_with(
+        &crate::diesel_test_inner as &dyn Fn(_, _) -> _,
+        (attr, input),
+        AttributeMacro(syn::parse_quote!(diesel::diesel_test)),
+        "diesel_test_1",
+    );
+}
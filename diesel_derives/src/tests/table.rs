@@ -343,3 +343,68 @@ pub(crate) fn table_with_complex_feature_gates_cross_column_impls() {
         "IsContainedInGroupBy<col_ab> for col_cd should have cfg attrs from both columns"
     );
 }
+
+#[test]
+pub(crate) fn table_with_sensitive_column() {
+    let input = quote::quote! {
+        users {
+            id -> Integer,
+            #[diesel(sensitive)]
+            password_hash -> Text,
+        }
+    };
+    let name = if cfg!(feature = "postgres") {
+        "table_with_sensitive_column (postgres)"
+    } else {
+        "table_with_sensitive_column"
+    };
+
+    expand_with(
+        &crate::table_proc_inner as &dyn Fn(_) -> _,
+        input,
+        FunctionMacro(syn::parse_quote!(table)),
+        name,
+    );
+}
+
+#[test]
+pub(crate) fn table_with_generated_columns() {
+    let input = quote::quote! {
+        users {
+            #[diesel(generated)]
+            id -> Integer,
+            name -> Text,
+            #[generated_always_as = "upper(name)"]
+            upper_name -> Text,
+        }
+    };
+    let name = if cfg!(feature = "postgres") {
+        "table_with_generated_columns (postgres)"
+    } else {
+        "table_with_generated_columns"
+    };
+
+    expand_with(
+        &crate::table_proc_inner as &dyn Fn(_) -> _,
+        input,
+        FunctionMacro(syn::parse_quote!(table)),
+        name,
+    );
+}
+
+#[test]
+pub(crate) fn table_with_custom_visibility() {
+    let input = quote::quote! {
+        pub(crate) users {
+            id -> Integer,
+            name -> Text,
+        }
+    };
+
+    let generated_str = crate::table_proc_inner(input).to_string();
+
+    assert!(
+        generated_str.contains("pub (crate) mod users {"),
+        "The generated module should use the given visibility"
+    );
+}
@@ -854,6 +854,50 @@ fn option_fields_are_skipped() {
     assert_eq!(Ok(expected), actual);
 }
 
+#[test]
+fn empty_strings_are_assigned_null_when_specified() {
+    #[derive(AsChangeset)]
+    #[diesel(table_name = users)]
+    struct UserForm<'a> {
+        name: &'a str,
+        #[diesel(treat_empty_string_as_null)]
+        hair_color: String,
+        #[diesel(treat_empty_string_as_null)]
+        r#type: Option<&'a str>,
+    }
+
+    let connection = &mut connection_with_sean_and_tess_in_users_table();
+
+    update(users::table.find(1))
+        .set(&UserForm {
+            name: "Jim",
+            hair_color: String::new(),
+            r#type: Some(""),
+        })
+        .execute(connection)
+        .unwrap();
+    update(users::table.find(2))
+        .set(UserForm {
+            name: "Ruby",
+            hair_color: String::from("blue"),
+            r#type: None,
+        })
+        .execute(connection)
+        .unwrap();
+
+    let expected = vec![
+        (1, String::from("Jim"), None, None),
+        (
+            2,
+            String::from("Ruby"),
+            Some(String::from("blue")),
+            Some(String::from("admin")),
+        ),
+    ];
+    let actual = users::table.order(users::id).load(connection);
+    assert_eq!(Ok(expected), actual);
+}
+
 #[test]
 fn option_fields_are_assigned_null_when_specified() {
     #[derive(AsChangeset)]
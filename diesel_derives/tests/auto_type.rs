@@ -152,6 +152,21 @@ fn test_expression_methods() -> _ {
         .and(users::id.not_between(v, v))
 }
 
+#[auto_type]
+fn test_row_value_expression_methods() -> _ {
+    let v = 42_i32;
+    let n: &'static str = "foo";
+    let rows: Vec<(i32, &'static str)> = Vec::new();
+    (users::id, users::name)
+        .row_eq((v, n))
+        .and((users::id, users::name).row_ne((v, n)))
+        .and((users::id, users::name).row_gt((v, n)))
+        .and((users::id, users::name).row_ge((v, n)))
+        .and((users::id, users::name).row_lt((v, n)))
+        .and((users::id, users::name).row_le((v, n)))
+        .and((users::id, users::name).row_eq_any(rows))
+}
+
 #[auto_type]
 fn test_boolean_expression_methods() -> _ {
     let v = 42_i32;
@@ -170,6 +185,8 @@ fn test_text_expression_methods() -> _ {
         .like(a)
         .and(users::name.not_like(a))
         .and(users::name.concat(a).eq(a))
+        .and(users::name.eq_ignore_case(a))
+        .and(users::name.like_ignore_case(a))
 }
 
 #[auto_type]
@@ -262,6 +279,16 @@ fn test_pg_net_expression_methods() -> _ {
         //.and(pg_extras::net.and(pg_extras::net).contains_or_eq(pg_extras::net))
         //.and(pg_extras::net.or(pg_extras::net).contains(pg_extras::net))
         .and(pg_extras::net.diff(pg_extras::net).eq(42_i64))
+        .and(
+            pg_extras::net
+                .add_offset(42_i64)
+                .overlaps_with(pg_extras::net),
+        )
+        .and(
+            pg_extras::net
+                .sub_offset(42_i64)
+                .overlaps_with(pg_extras::net),
+        )
 }
 
 #[cfg(feature = "postgres")]
@@ -450,6 +477,7 @@ fn postgres_functions() -> _ {
         upper_inf(pg_extras::range),
         range_merge(pg_extras::range, pg_extras::range),
         multirange_merge(pg_extras::multirange),
+        multirange(pg_extras::range),
         int4range(users::id.nullable(), users::id.nullable(), bound),
         int8range(users::bigint.nullable(), users::bigint.nullable(), bound),
         numrange(users::numeric.nullable(), users::numeric.nullable(), bound),
@@ -523,6 +551,15 @@ fn postgres_functions() -> _ {
         json_build_array_2(pg_extras::name, pg_extras::id),
         jsonb_build_array_1(pg_extras::jsonb),
         jsonb_build_array_2(pg_extras::text_array, pg_extras::jsonb),
+        json_build_object_1(pg_extras::name, pg_extras::id),
+        jsonb_build_object_2(
+            pg_extras::name,
+            pg_extras::id,
+            pg_extras::name,
+            pg_extras::jsonb,
+        ),
+        json_array_elements(pg_extras::json),
+        jsonb_array_elements(pg_extras::jsonb),
         json_extract_path_1(pg_extras::json, pg_extras::name),
         json_extract_path_2(pg_extras::json, pg_extras::name, pg_extras::name),
         jsonb_extract_path_1(pg_extras::jsonb, pg_extras::name),
@@ -569,6 +569,54 @@ fn serialize_as_with_option() {
     assert_eq!(Ok(expected), saved);
 }
 
+#[test]
+fn treat_empty_string_as_null() {
+    #[derive(Insertable)]
+    #[diesel(table_name = users)]
+    struct NewUser<'a> {
+        id: i32,
+        name: &'a str,
+        #[diesel(treat_empty_string_as_null)]
+        hair_color: String,
+        #[diesel(treat_empty_string_as_null)]
+        r#type: Option<&'a str>,
+    }
+
+    let conn = &mut connection();
+    let new_users = [
+        NewUser {
+            id: 1,
+            name: "Sean",
+            hair_color: String::new(),
+            r#type: Some(""),
+        },
+        NewUser {
+            id: 2,
+            name: "Tess",
+            hair_color: "Brown".into(),
+            r#type: Some("admin"),
+        },
+    ];
+    insert_into(users::table)
+        .values(&new_users[0])
+        .execute(conn)
+        .unwrap();
+    insert_into(users::table)
+        .values(new_users.into_iter().nth(1).unwrap())
+        .execute(conn)
+        .unwrap();
+
+    let saved = users::table
+        .select((users::id, users::hair_color, users::r#type))
+        .order(users::id)
+        .load::<(i32, Option<String>, Option<String>)>(conn);
+    let expected = vec![
+        (1, None, None),
+        (2, Some("Brown".to_string()), Some("admin".to_string())),
+    ];
+    assert_eq!(Ok(expected), saved);
+}
+
 // this is a compile test to verify that we don't
 // emit bounds that cannot be evaluated by rustc
 //
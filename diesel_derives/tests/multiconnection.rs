@@ -518,3 +518,111 @@ fn check_enum_works() {
 
     assert_eq!(r, v);
 }
+
+#[test]
+// mysql struggles with selects without from
+#[cfg(not(feature = "mysql"))]
+fn transaction_with_is_forwarded() {
+    use diesel::connection::{Access, Isolation};
+
+    let mut conn = establish_connection();
+    let r = conn
+        .transaction_with(Isolation::Serializable, Access::ReadWrite, |conn| {
+            diesel::select(1.into_sql::<diesel::sql_types::Integer>()).get_result::<i32>(conn)
+        })
+        .unwrap();
+    assert_eq!(r, 1);
+
+    let r = conn.transaction(|conn| {
+        conn.transaction_with(Isolation::Serializable, Access::ReadWrite, |_| Ok(()))
+    });
+    assert_eq!(r, Err(diesel::result::Error::AlreadyInTransaction));
+}
+
+#[test]
+fn nested_transactions_are_forwarded() {
+    use diesel::result::Error;
+    use std::num::NonZeroU32;
+
+    let mut conn = establish_connection();
+    assert_eq!(Ok(None), conn.transaction_depth());
+    assert!(!conn.is_broken());
+
+    conn.transaction::<_, Error, _>(|conn| {
+        assert_eq!(Ok(NonZeroU32::new(1)), conn.transaction_depth());
+        // an open transaction is not supposed to be returned to a pool
+        assert!(conn.is_broken());
+
+        conn.transaction::<_, Error, _>(|conn| {
+            assert_eq!(Ok(NonZeroU32::new(2)), conn.transaction_depth());
+            Ok(())
+        })?;
+
+        let r = conn.transaction::<(), _, _>(|conn| {
+            assert_eq!(Ok(NonZeroU32::new(2)), conn.transaction_depth());
+            Err(Error::RollbackTransaction)
+        });
+        assert_eq!(Err(Error::RollbackTransaction), r);
+
+        conn.savepoint("multi", |conn| {
+            assert_eq!(Ok(NonZeroU32::new(2)), conn.transaction_depth());
+            conn.rollback_to_savepoint("multi")
+        })?;
+        assert_eq!(Ok(NonZeroU32::new(1)), conn.transaction_depth());
+        Ok(())
+    })
+    .unwrap();
+
+    assert_eq!(Ok(None), conn.transaction_depth());
+    assert!(!conn.is_broken());
+}
+
+#[test]
+fn test_transaction_is_forwarded() {
+    use diesel::result::Error;
+    use std::num::NonZeroU32;
+
+    let mut conn = establish_connection();
+    conn.test_transaction::<_, Error, _>(|conn| {
+        assert_eq!(Ok(NonZeroU32::new(1)), conn.transaction_depth());
+        Ok(())
+    });
+    assert_eq!(Ok(None), conn.transaction_depth());
+
+    conn.begin_test_transaction().unwrap();
+    assert_eq!(Ok(NonZeroU32::new(1)), conn.transaction_depth());
+    // test transactions are deliberately kept open
+    assert!(!conn.is_broken());
+    conn.test_transaction::<_, Error, _>(|conn| {
+        assert_eq!(Ok(NonZeroU32::new(2)), conn.transaction_depth());
+        Ok(())
+    });
+    assert_eq!(Ok(NonZeroU32::new(1)), conn.transaction_depth());
+}
+
+#[test]
+fn transaction_with_retries_is_forwarded() {
+    use diesel::connection::RetryPolicy;
+    use diesel::result::{DatabaseErrorKind, Error};
+
+    let mut conn = establish_connection();
+    let policy = RetryPolicy::default().initial_backoff(std::time::Duration::ZERO);
+    let mut attempts = 0;
+    let r = conn.transaction_with_retries(&policy, |conn| {
+        attempts += 1;
+        assert_eq!(Ok(std::num::NonZeroU32::new(1)), conn.transaction_depth());
+        if attempts < 3 {
+            Err(Error::DatabaseError(
+                DatabaseErrorKind::SerializationFailure,
+                Box::new(String::from("conflict")),
+            ))
+        } else {
+            Ok(attempts)
+        }
+    });
+    assert_eq!(Ok(3), r);
+    assert_eq!(Ok(None), conn.transaction_depth());
+
+    let r = conn.transaction(|conn| conn.transaction_with_retries(&policy, |_| Ok(())));
+    assert_eq!(Err(Error::AlreadyInTransaction), r);
+}
@@ -164,3 +164,50 @@ fn deserialize_as_with_tuple_type() {
         data,
     );
 }
+
+#[test]
+fn treat_empty_string_as_null_loads_null_as_empty_string() {
+    use crate::helpers::connection_with_sean_and_tess_in_users_table;
+    use crate::schema::users;
+
+    #[derive(Debug, Clone, PartialEq, Queryable, QueryableByName, Selectable)]
+    #[diesel(table_name = users)]
+    #[diesel(check_for_backend(crate::helpers::TestBackend))]
+    struct User {
+        id: i32,
+        #[diesel(treat_empty_string_as_null)]
+        hair_color: String,
+        #[diesel(treat_empty_string_as_null)]
+        r#type: Option<String>,
+    }
+
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+    update(users::table.find(1))
+        .set((
+            users::hair_color.eq(None::<String>),
+            users::r#type.eq(None::<String>),
+        ))
+        .execute(conn)
+        .unwrap();
+
+    let expected = vec![
+        User {
+            id: 1,
+            hair_color: String::new(),
+            r#type: None,
+        },
+        User {
+            id: 2,
+            hair_color: "brown".into(),
+            r#type: Some("admin".into()),
+        },
+    ];
+    let loaded = users::table
+        .select(User::as_select())
+        .order(users::id)
+        .load(conn);
+    assert_eq!(Ok(expected.clone()), loaded);
+
+    let loaded = sql_query("SELECT id, hair_color, type FROM users ORDER BY id").load(conn);
+    assert_eq!(Ok(expected), loaded);
+}
@@ -51,6 +51,13 @@
 //! For Rust-based migrations, call [`RustMigration::without_transaction`] on the builder,
 //! or return `false` from [`TypedMigration::run_in_transaction`] when implementing the
 //! trait directly.
+//!
+//! ## Testing
+//!
+//! The [`diesel::diesel_test`] attribute declares a test that receives a connection
+//! to a newly created database with all migrations applied. The database is removed
+//! once the test finished. See [`TestDatabaseConnection`](diesel::connection::TestDatabaseConnection)
+//! for the supported connection types.
 
 mod combined_migrations;
 mod embedded_migrations;
@@ -58,6 +65,7 @@ mod errors;
 mod file_based_migrations;
 mod migration_harness;
 mod rust_migrations;
+mod test_database;
 
 pub use self::embedded_migrations::EmbeddedMigrations;
 pub use self::file_based_migrations::FileBasedMigrations;
@@ -72,3 +80,5 @@ pub use crate::embedded_migrations::{EmbeddedMigration, EmbeddedName};
 pub use crate::errors::MigrationError;
 #[doc(hidden)]
 pub use crate::file_based_migrations::TomlMetadataWrapper;
+#[doc(hidden)]
+pub use crate::test_database::__run_diesel_test;
@@ -0,0 +1,21 @@
+use diesel::connection::TestDatabaseConnection;
+
+use crate::{EmbeddedMigrations, MigrationHarness};
+
+#[doc(hidden)]
+pub fn __run_diesel_test<C, R>(
+    test_name: &str,
+    migrations: EmbeddedMigrations,
+    test: impl FnOnce(&mut C) -> R,
+) -> R
+where
+    C: TestDatabaseConnection + MigrationHarness<C::Backend>,
+{
+    let mut database = C::create_test_database(test_name)
+        .unwrap_or_else(|e| panic!("Failed to create the database for `{test_name}`: {e}"));
+    database
+        .connection()
+        .run_pending_migrations(migrations)
+        .unwrap_or_else(|e| panic!("Failed to run migrations for `{test_name}`: {e}"));
+    test(database.connection())
+}
@@ -7,6 +7,7 @@ use syn::spanned::Spanned;
 pub struct ViewDecl {
     pub use_statements: Vec<syn::ItemUse>,
     pub meta: Vec<syn::Attribute>,
+    pub visibility: Option<syn::Visibility>,
     pub schema: Option<Ident>,
     _punct: Option<syn::Token![.]>,
     pub sql_name: String,
@@ -51,6 +52,9 @@ pub struct ColumnDef {
     _arrow: syn::Token![->],
     pub tpe: syn::TypePath,
     pub max_length: Option<syn::LitInt>,
+    pub generated_always_as: Option<syn::LitStr>,
+    pub generated: bool,
+    pub sensitive: bool,
 }
 
 impl syn::parse::Parse for ViewDecl {
@@ -65,6 +69,7 @@ impl syn::parse::Parse for ViewDecl {
             };
         }
         let mut meta = syn::Attribute::parse_outer(buf)?;
+        let visibility = parse_visibility(buf)?;
         let fork = buf.fork();
         let (schema, punct, table_name) = if parse_table_with_schema(&fork).is_ok() {
             let (schema, punct, table_name) = parse_table_with_schema(buf)?;
@@ -80,6 +85,7 @@ impl syn::parse::Parse for ViewDecl {
         Ok(Self {
             use_statements,
             meta,
+            visibility,
             schema,
             _punct: punct,
             sql_name,
@@ -102,6 +108,7 @@ impl syn::parse::Parse for TableDecl {
             };
         }
         let mut meta = syn::Attribute::parse_outer(buf)?;
+        let visibility = parse_visibility(buf)?;
         let fork = buf.fork();
         let (schema, punct, table_name) = if parse_table_with_schema(&fork).is_ok() {
             let (schema, punct, table_name) = parse_table_with_schema(buf)?;
@@ -124,6 +131,7 @@ impl syn::parse::Parse for TableDecl {
             view: ViewDecl {
                 use_statements,
                 meta,
+                visibility,
                 schema,
                 _punct: punct,
                 sql_name,
@@ -136,6 +144,17 @@ impl syn::parse::Parse for TableDecl {
     }
 }
 
+/// Parses the optional visibility of the generated module,
+/// which is `pub` if no visibility is given
+fn parse_visibility(
+    buf: &syn::parse::ParseBuffer<'_>,
+) -> Result<Option<syn::Visibility>, syn::Error> {
+    match buf.parse()? {
+        syn::Visibility::Inherited => Ok(None),
+        visibility => Ok(Some(visibility)),
+    }
+}
+
 impl syn::parse::Parse for PrimaryKey {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
         let content;
@@ -157,6 +176,13 @@ impl syn::parse::Parse for ColumnDef {
             syn::Lit::Int(lit_int) => Some(lit_int),
             _ => None,
         })?;
+        let generated_always_as = take_lit(&mut meta, "generated_always_as", |lit| match lit {
+            syn::Lit::Str(lit_str) => Some(lit_str),
+            _ => None,
+        })?;
+        let flags = take_diesel_flags(&mut meta, &["generated", "sensitive"])?;
+        let generated = flags.iter().any(|f| f == "generated");
+        let sensitive = flags.iter().any(|f| f == "sensitive");
 
         Ok(Self {
             meta,
@@ -164,6 +190,9 @@ impl syn::parse::Parse for ColumnDef {
             _arrow,
             tpe,
             max_length,
+            generated_always_as,
+            generated,
+            sensitive,
             sql_name,
         })
     }
@@ -233,3 +262,35 @@ where
     }
     Ok(None)
 }
+
+/// Removes all `#[diesel(...)]` attributes and returns the flags
+/// they contain, rejecting flags not contained in `known_flags`
+fn take_diesel_flags(
+    meta: &mut Vec<syn::Attribute>,
+    known_flags: &[&'static str],
+) -> Result<Vec<Ident>, syn::Error> {
+    let mut flags = Vec::new();
+    while let Some(index) = meta.iter().position(|m| m.path().is_ident("diesel")) {
+        let attribute = meta.remove(index);
+        let parsed = attribute.parse_args_with(
+            syn::punctuated::Punctuated::<Ident, syn::Token![,]>::parse_terminated,
+        )?;
+        for flag in parsed {
+            if !known_flags.iter().any(|known| flag == known) {
+                let expected = known_flags
+                    .iter()
+                    .map(|known| format!("`#[diesel({known})]`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                return Err(syn::Error::new(
+                    flag.span(),
+                    format_args!(
+                        "Unknown attribute `#[diesel({flag})]`, expected one of {expected}"
+                    ),
+                ));
+            }
+            flags.push(flag);
+        }
+    }
+    Ok(flags)
+}
@@ -285,7 +285,6 @@ fn count_all() {
     assert_eq!(res, 3);
 }
 
-#[cfg(not(feature = "mysql"))]
 #[diesel_test_helper::test]
 fn filter() {
     use crate::schema::users;
@@ -299,6 +298,43 @@ fn filter() {
     assert_eq!(res, 1);
 }
 
+#[diesel_test_helper::test]
+fn filter_count_star() {
+    use crate::schema::users;
+
+    let mut conn = connection_with_sean_and_tess_in_users_table();
+
+    let res = users::table
+        .select((
+            dsl::count_star().aggregate_filter(users::name.eq("Sean")),
+            dsl::count_star().aggregate_filter(users::name.ne("Sean")),
+            dsl::count_star()
+                .aggregate_filter(users::name.eq("Sean"))
+                .aggregate_filter(users::id.gt(1)),
+        ))
+        .get_result::<(i64, i64, i64)>(&mut conn)
+        .unwrap();
+    assert_eq!(res, (1, 1, 0));
+}
+
+#[diesel_test_helper::test]
+fn filter_with_other_aggregate_modifiers() {
+    use crate::schema::users;
+
+    let mut conn = connection_with_sean_and_tess_in_users_table();
+
+    let res = users::table
+        .select((
+            dsl::max(users::id).aggregate_filter(users::name.eq("Sean")),
+            dsl::count(users::name)
+                .aggregate_distinct()
+                .aggregate_filter(users::id.ge(1)),
+        ))
+        .get_result::<(Option<i32>, i64)>(&mut conn)
+        .unwrap();
+    assert_eq!(res, (Some(1), 2));
+}
+
 #[cfg(not(feature = "mysql"))]
 #[diesel_test_helper::test]
 fn order() {
@@ -370,3 +406,57 @@ fn order_group_by_then_order_by() {
         .unwrap();
     assert_eq!(res, Some(1));
 }
+
+#[cfg(feature = "postgres")]
+#[diesel_test_helper::test]
+fn ordered_array_and_string_aggregates() {
+    let mut conn = connection_with_sean_and_tess_in_users_table();
+
+    let res = users::table
+        .select((
+            dsl::array_agg(users::id).aggregate_order(users::id.desc()),
+            dsl::string_agg(users::name, ", ").aggregate_order(users::name.desc()),
+            dsl::string_agg(users::name, ", ")
+                .aggregate_order(users::name)
+                .aggregate_filter(users::id.gt(5)),
+        ))
+        .get_result::<(Option<Vec<i32>>, Option<String>, Option<String>)>(&mut conn)
+        .unwrap();
+    assert_eq!(
+        res,
+        (Some(vec![2, 1]), Some("Tess, Sean".to_string()), None)
+    );
+}
+
+#[cfg(feature = "postgres")]
+#[diesel_test_helper::test]
+fn ordered_set_aggregates() {
+    let mut conn = connection_with_sean_and_tess_in_users_table();
+
+    let res = users::table
+        .select((
+            dsl::percentile_cont(0.5).within_group(users::id),
+            dsl::percentile_disc(0.5).within_group(users::name.desc()),
+            dsl::mode().within_group(users::hair_color),
+        ))
+        .get_result::<(Option<f64>, Option<String>, Option<String>)>(&mut conn)
+        .unwrap();
+    assert_eq!(res, (Some(1.5), Some("Tess".to_string()), None));
+
+    let res = users::table
+        .group_by(users::name)
+        .select((
+            users::name,
+            dsl::percentile_cont(0.5).within_group(users::id),
+        ))
+        .order_by(users::name)
+        .load::<(String, Option<f64>)>(&mut conn)
+        .unwrap();
+    assert_eq!(
+        res,
+        vec![
+            ("Sean".to_string(), Some(1.0)),
+            ("Tess".to_string(), Some(2.0))
+        ]
+    );
+}
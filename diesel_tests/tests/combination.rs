@@ -235,6 +235,83 @@ fn as_subquery_for_eq_in() {
     assert_eq!(out, vec!["First post", "Second post"]);
 }
 
+// MySQL doesn't support `LIMIT` in `IN` subqueries
+#[cfg(not(feature = "mysql"))]
+#[diesel_test_helper::test]
+fn ordered_and_limited_combination_as_subquery_for_eq_in() {
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+
+    insert_into(posts::table)
+        .values(&[
+            (posts::user_id.eq(1), posts::title.eq("First post")),
+            (posts::user_id.eq(2), posts::title.eq("Second post")),
+        ])
+        .execute(conn)
+        .unwrap();
+
+    let subquery = users::table
+        .select(users::id)
+        .filter(users::name.eq("Sean"))
+        .union(
+            users::table
+                .select(users::id)
+                .filter(users::name.ne("Sean")),
+        )
+        .positional_order_by(OrderColumn::from(1).desc())
+        .limit(1);
+
+    let out = posts::table
+        .filter(posts::user_id.eq_any(subquery))
+        .select(posts::title)
+        .load::<String>(conn)
+        .unwrap();
+
+    assert_eq!(out, vec!["Second post"]);
+}
+
+#[diesel_test_helper::test]
+fn combination_as_single_value() {
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+
+    let subquery = users::table
+        .select(users::name)
+        .filter(users::name.eq("Sean"))
+        .union(
+            users::table
+                .select(users::name)
+                .filter(users::name.eq("Tess")),
+        )
+        .positional_order_by(OrderColumn::from(1).desc());
+
+    let out = select(subquery.single_value())
+        .get_result::<Option<String>>(conn)
+        .unwrap();
+
+    assert_eq!(out, Some(String::from("Tess")));
+}
+
+#[diesel_test_helper::test]
+fn combination_in_exists() {
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+
+    let query = |name: &'static str| {
+        users::table
+            .select(users::id)
+            .filter(users::name.eq("Jim"))
+            .union(users::table.select(users::id).filter(users::name.eq(name)))
+    };
+
+    let sean_or_jim_exists = select(dsl::exists(query("Sean")))
+        .get_result::<bool>(conn)
+        .unwrap();
+    let jim_or_bob_exists = select(dsl::exists(query("Bob")))
+        .get_result::<bool>(conn)
+        .unwrap();
+
+    assert!(sean_or_jim_exists);
+    assert!(!jim_or_bob_exists);
+}
+
 #[diesel_test_helper::test]
 fn positional_order_by() {
     use crate::schema::users::dsl::*;
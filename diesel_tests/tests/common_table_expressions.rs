@@ -0,0 +1,223 @@
+use crate::schema::*;
+use diesel::dsl::{with, with_recursive};
+use diesel::*;
+
+diesel::view! {
+    named_users {
+        user_id -> Integer,
+        user_name -> Text,
+    }
+}
+
+diesel::view! {
+    greetings {
+        greeting -> Text,
+    }
+}
+
+diesel::view! {
+    descendants {
+        id -> Integer,
+        depth -> Integer,
+    }
+}
+
+diesel::allow_tables_to_appear_in_same_query!(named_users, users);
+diesel::allow_tables_to_appear_in_same_query!(descendants, trees);
+
+#[diesel_test_helper::test]
+fn select_from_common_table_expression() {
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+
+    let data = with(
+        named_users::view,
+        users::table.select((users::id, users::name)),
+    )
+    .query(
+        named_users::view
+            .select(named_users::user_name)
+            .order(named_users::user_name.desc()),
+    )
+    .load::<String>(conn);
+
+    assert_eq!(Ok(vec![String::from("Tess"), String::from("Sean")]), data);
+}
+
+#[diesel_test_helper::test]
+fn common_table_expression_with_binds() {
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+
+    let data = with(
+        named_users::view,
+        users::table
+            .filter(users::name.ne("Sean"))
+            .select((users::id, users::name)),
+    )
+    .query(
+        named_users::view
+            .filter(named_users::user_name.ne("Jim"))
+            .select(named_users::user_name),
+    )
+    .load::<String>(conn);
+
+    assert_eq!(Ok(vec![String::from("Tess")]), data);
+}
+
+#[diesel_test_helper::test]
+fn common_table_expression_referencing_another_one() {
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+
+    let data = with(
+        named_users::view,
+        users::table.select((users::id, users::name)),
+    )
+    .with(
+        greetings::view,
+        named_users::view
+            .filter(named_users::user_name.eq("Sean"))
+            .select((named_users::user_name.concat(" says hi"),)),
+    )
+    .query(greetings::view.select(greetings::greeting))
+    .load::<String>(conn);
+
+    assert_eq!(Ok(vec![String::from("Sean says hi")]), data);
+}
+
+#[diesel_test_helper::test]
+fn common_table_expression_in_subselect() {
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+    let tess_id = users::table
+        .filter(users::name.eq("Tess"))
+        .select(users::id)
+        .first::<i32>(conn)
+        .unwrap();
+
+    let data = with(
+        named_users::view,
+        users::table
+            .filter(users::name.eq("Tess"))
+            .select((users::id, users::name)),
+    )
+    .query(
+        users::table
+            .filter(users::id.eq_any(named_users::view.select(named_users::user_id)))
+            .select(users::id),
+    )
+    .load::<i32>(conn);
+
+    assert_eq!(Ok(vec![tess_id]), data);
+}
+
+#[diesel_test_helper::test]
+fn common_table_expression_from_combined_query() {
+    use diesel::query_dsl::positional_order_dsl::PositionalOrderDsl;
+
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+
+    let data = with(
+        named_users::view,
+        users::table
+            .filter(users::name.eq("Tess"))
+            .select((users::id, users::name))
+            .union(
+                users::table
+                    .filter(users::name.eq("Sean"))
+                    .select((users::id, users::name)),
+            )
+            .positional_order_by(2)
+            .limit(1),
+    )
+    .query(named_users::view.select(named_users::user_name))
+    .load::<String>(conn);
+
+    assert_eq!(Ok(vec![String::from("Sean")]), data);
+}
+
+#[diesel_test_helper::test]
+fn recursive_common_table_expression_traverses_tree() {
+    let conn = &mut connection();
+    insert_into(trees::table)
+        .values(&vec![
+            (trees::id.eq(1), trees::parent_id.eq(None)),
+            (trees::id.eq(2), trees::parent_id.eq(Some(1))),
+            (trees::id.eq(3), trees::parent_id.eq(Some(2))),
+            (trees::id.eq(4), trees::parent_id.eq(Some(1))),
+            (trees::id.eq(5), trees::parent_id.eq(None)),
+            (trees::id.eq(6), trees::parent_id.eq(Some(5))),
+        ])
+        .execute(conn)
+        .unwrap();
+
+    let data = with_recursive(
+        descendants::view,
+        trees::table
+            .filter(trees::id.eq(1))
+            .select((trees::id, 0.into_sql::<sql_types::Integer>())),
+    )
+    .union_all(
+        trees::table
+            .inner_join(descendants::view.on(trees::parent_id.eq(descendants::id.nullable())))
+            .select((trees::id, descendants::depth + 1)),
+    )
+    .query(
+        descendants::view
+            .select((descendants::id, descendants::depth))
+            .order(descendants::id),
+    )
+    .load::<(i32, i32)>(conn);
+
+    assert_eq!(Ok(vec![(1, 0), (2, 1), (3, 2), (4, 1)]), data);
+}
+
+#[diesel_test_helper::test]
+fn recursive_common_table_expression_with_union_terminates_on_cycles() {
+    let conn = &mut connection();
+    insert_into(trees::table)
+        .values(&vec![
+            (trees::id.eq(1), trees::parent_id.eq(Some(2))),
+            (trees::id.eq(2), trees::parent_id.eq(Some(1))),
+        ])
+        .execute(conn)
+        .unwrap();
+
+    let data = with_recursive(
+        descendants::view,
+        trees::table
+            .filter(trees::id.eq(1))
+            .select((trees::id, 0.into_sql::<sql_types::Integer>())),
+    )
+    .union(
+        trees::table
+            .inner_join(descendants::view.on(trees::parent_id.eq(descendants::id.nullable())))
+            .select((trees::id, 0.into_sql::<sql_types::Integer>())),
+    )
+    .query(
+        descendants::view
+            .select(descendants::id)
+            .order(descendants::id),
+    )
+    .load::<i32>(conn);
+
+    assert_eq!(Ok(vec![1, 2]), data);
+}
+
+#[diesel_test_helper::test]
+#[cfg(feature = "postgres")]
+fn common_table_expression_generates_expected_sql() {
+    let query = with(
+        named_users::view,
+        users::table
+            .filter(users::name.eq("Sean"))
+            .select((users::id, users::name)),
+    )
+    .query(named_users::view.select(named_users::user_name));
+
+    assert_eq!(
+        "WITH \"named_users\" (\"user_id\", \"user_name\") AS (\
+         SELECT \"users\".\"id\", \"users\".\"name\" FROM \"users\" \
+         WHERE (\"users\".\"name\" = $1)) \
+         SELECT \"named_users\".\"user_name\" FROM \"named_users\" \
+         -- binds: [\"Sean\"]",
+        debug_query::<diesel::pg::Pg, _>(&query).to_string()
+    );
+}
@@ -249,6 +249,36 @@ fn copy_from_from_insertable_vec() {
     assert_eq!(users[1], ("Tess".to_owned(), Some("green".into())));
 }
 
+#[diesel_test_helper::test]
+fn copy_from_with_binary_writer() {
+    use diesel::pg::CopyBinaryWriter;
+    use diesel::sql_types::{Nullable, Text};
+
+    let conn = &mut connection();
+
+    let users = [("Sean", None), ("Tess", Some("green"))];
+    let count = diesel::copy_from(users::table)
+        .from_raw_data((users::name, users::hair_color), |copy| {
+            let mut writer = CopyBinaryWriter::new(copy)?;
+            for user in &users {
+                writer.write_row::<(Text, Nullable<Text>), _>(user)?;
+            }
+            writer.finish()
+        })
+        .with_format(CopyFormat::Binary)
+        .execute(conn)
+        .unwrap();
+    assert_eq!(count, 2);
+    let users = users::table
+        .select((users::name, users::hair_color))
+        .order(users::id)
+        .load::<(String, Option<String>)>(conn)
+        .unwrap();
+
+    assert_eq!(users[0], ("Sean".to_owned(), None));
+    assert_eq!(users[1], ("Tess".to_owned(), Some("green".into())));
+}
+
 #[diesel_test_helper::test]
 fn copy_to_csv() {
     let conn = &mut connection_with_sean_and_tess_in_users_table();
@@ -17,7 +17,7 @@ table! {
     }
 }
 
-#[derive(SqlType)]
+#[derive(SqlType, QueryId)]
 #[diesel(postgres_type(name = "My_Type"))]
 pub struct MyType;
 
@@ -212,3 +212,136 @@ fn custom_type_schema_inference() {
     assert_eq!(other_ty.oid().unwrap(), inferred_ty.oid().unwrap());
     assert_ne!(public_ty.oid().unwrap(), other_ty.oid().unwrap());
 }
+
+table! {
+    use diesel::sql_types::*;
+    use super::{MyType, PositiveInt, IntTextPair};
+    custom_type_arrays {
+        id -> Integer,
+        enums -> Array<MyType>,
+        nullable_enums -> Nullable<Array<Nullable<MyType>>>,
+        domains -> Array<PositiveInt>,
+        composites -> Array<IntTextPair>,
+    }
+}
+
+#[derive(SqlType, QueryId)]
+#[diesel(postgres_type(name = "positive_int"))]
+pub struct PositiveInt;
+
+#[derive(Debug, PartialEq, Eq, FromSqlRow, AsExpression)]
+#[diesel(sql_type = PositiveInt)]
+pub struct Positive(i32);
+
+impl ToSql<PositiveInt, Pg> for Positive {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        ToSql::<sql_types::Integer, Pg>::to_sql(&self.0, out)
+    }
+}
+
+impl FromSql<PositiveInt, Pg> for Positive {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        FromSql::<sql_types::Integer, Pg>::from_sql(bytes).map(Positive)
+    }
+}
+
+#[derive(SqlType, QueryId)]
+#[diesel(postgres_type(name = "int_text_pair"))]
+pub struct IntTextPair;
+
+#[derive(Debug, PartialEq, Eq, FromSqlRow, AsExpression)]
+#[diesel(sql_type = IntTextPair)]
+pub struct Pair(i32, String);
+
+impl ToSql<IntTextPair, Pg> for Pair {
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, Pg>) -> serialize::Result {
+        serialize::WriteTuple::<(sql_types::Integer, sql_types::Text)>::write_tuple(
+            &(self.0, self.1.as_str()),
+            &mut out.reborrow(),
+        )
+    }
+}
+
+impl FromSql<IntTextPair, Pg> for Pair {
+    fn from_sql(bytes: PgValue<'_>) -> deserialize::Result<Self> {
+        let (id, text) =
+            FromSql::<sql_types::Record<(sql_types::Integer, sql_types::Text)>, Pg>::from_sql(
+                bytes,
+            )?;
+        Ok(Pair(id, text))
+    }
+}
+
+#[derive(Insertable, Queryable, Debug, PartialEq)]
+#[diesel(table_name = custom_type_arrays)]
+struct HasCustomTypeArrays {
+    id: i32,
+    enums: Vec<MyEnum>,
+    nullable_enums: Option<Vec<Option<MyEnum>>>,
+    domains: Vec<Positive>,
+    composites: Vec<Pair>,
+}
+
+#[diesel_test_helper::test]
+fn arrays_of_custom_types_round_trip() {
+    let connection = &mut connection();
+    connection
+        .batch_execute(
+            r#"
+        CREATE TYPE "My_Type" AS ENUM ('foo', 'bar');
+        CREATE DOMAIN positive_int AS INTEGER CHECK (VALUE > 0);
+        CREATE TYPE int_text_pair AS (id INTEGER, text TEXT);
+        CREATE TABLE custom_type_arrays (
+            id SERIAL PRIMARY KEY,
+            enums "My_Type"[] NOT NULL,
+            nullable_enums "My_Type"[],
+            domains positive_int[] NOT NULL,
+            composites int_text_pair[] NOT NULL
+        );
+    "#,
+        )
+        .unwrap();
+
+    let data = vec![
+        HasCustomTypeArrays {
+            id: 1,
+            enums: vec![MyEnum::Foo, MyEnum::Bar, MyEnum::Foo],
+            nullable_enums: Some(vec![Some(MyEnum::Bar), None]),
+            domains: vec![Positive(1), Positive(42)],
+            composites: vec![Pair(1, "one".into()), Pair(2, "two".into())],
+        },
+        HasCustomTypeArrays {
+            id: 2,
+            enums: Vec::new(),
+            nullable_enums: None,
+            domains: Vec::new(),
+            composites: Vec::new(),
+        },
+    ];
+    let inserted = insert_into(custom_type_arrays::table)
+        .values(&data)
+        .get_results(connection)
+        .unwrap();
+    assert_eq!(data, inserted);
+
+    // binds of custom type arrays can be used in expressions
+    let ids = custom_type_arrays::table
+        .select(custom_type_arrays::id)
+        .filter(custom_type_arrays::enums.contains(vec![MyEnum::Bar]))
+        .filter(custom_type_arrays::domains.eq(vec![Positive(1), Positive(42)]))
+        .load::<i32>(connection)
+        .unwrap();
+    assert_eq!(vec![1], ids);
+
+    // the domain constraint is checked for each element
+    let result = insert_into(custom_type_arrays::table)
+        .values(HasCustomTypeArrays {
+            id: 3,
+            enums: Vec::new(),
+            nullable_enums: None,
+            domains: vec![Positive(-1)],
+            composites: Vec::new(),
+        })
+        .execute(connection);
+    assert!(result.is_err(), "{result:?}");
+}
@@ -31,7 +31,7 @@ fn delete_single_record() {
 #[diesel_test_helper::test]
 #[cfg(not(any(
     all(feature = "sqlite", not(feature = "returning_clauses_for_sqlite_3_35")),
-    feature = "mysql"
+    all(feature = "mysql", not(feature = "returning_clauses_for_mariadb"))
 )))]
 fn return_deleted_records() {
     use crate::schema::users::dsl::*;
@@ -60,3 +60,31 @@ fn delete_or_filter() {
 
     assert_eq!(Ok(0), num_users);
 }
+
+#[diesel_test_helper::test]
+#[cfg(not(feature = "sqlite"))]
+fn delete_with_using_clause_filters_on_other_table() {
+    let connection = &mut connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", connection);
+    let tess = find_user_by_name("Tess", connection);
+    insert_into(posts::table)
+        .values(&vec![
+            sean.new_post("Hello", None),
+            sean.new_post("World", None),
+            tess.new_post("Hello 2", None),
+        ])
+        .execute(connection)
+        .unwrap();
+
+    let deleted_rows = delete(posts::table)
+        .using(users::table)
+        .filter(posts::user_id.eq(users::id))
+        .filter(users::name.eq("Sean"))
+        .execute(connection);
+    assert_eq!(Ok(2), deleted_rows);
+
+    let titles = posts::table.select(posts::title).load::<String>(connection);
+    assert_eq!(Ok(vec!["Hello 2".to_string()]), titles);
+    // rows of the referenced table are kept
+    assert_eq!(Ok(2), users::table.count().get_result(connection));
+}
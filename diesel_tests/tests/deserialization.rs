@@ -108,3 +108,61 @@ fn check_deserialize_composite_ptr_types() {
     assert_eq!(r[0].username.0, "John");
     assert_eq!(&r[0].password_hash, b"abc");
 }
+
+#[derive(Debug, PartialEq)]
+struct ShortNamedUser {
+    id: i32,
+    name: String,
+}
+
+impl TryFrom<(i32, String)> for ShortNamedUser {
+    type Error = String;
+
+    fn try_from((id, name): (i32, String)) -> Result<Self, Self::Error> {
+        if name.len() > 4 {
+            return Err(format!("name `{name}` is too long"));
+        }
+        Ok(Self { id, name })
+    }
+}
+
+impl QueryableInto for ShortNamedUser {
+    type Row = (i32, String);
+}
+
+#[diesel_test_helper::test]
+fn load_into_converts_rows_and_reports_conversion_errors() {
+    use crate::schema::users::dsl::*;
+    let connection = &mut connection_with_sean_and_tess_in_users_table();
+
+    let loaded = users
+        .select((id, name))
+        .order(id)
+        .load_into::<ShortNamedUser>(connection);
+    let expected = vec![
+        ShortNamedUser {
+            id: 1,
+            name: "Sean".into(),
+        },
+        ShortNamedUser {
+            id: 2,
+            name: "Tess".into(),
+        },
+    ];
+    assert_eq!(Ok(expected), loaded);
+
+    diesel::insert_into(users)
+        .values(name.eq("Jim Halpert"))
+        .execute(connection)
+        .unwrap();
+    let error = users
+        .select((id, name))
+        .load_into::<ShortNamedUser>(connection)
+        .unwrap_err();
+    match error {
+        diesel::result::Error::DeserializationError(e) => {
+            assert_eq!("name `Jim Halpert` is too long", e.to_string())
+        }
+        e => panic!("Unexpected error {e:?}"),
+    }
+}
@@ -17,7 +17,7 @@ fn simple_distinct() {
     assert_eq!(expected_data, data);
 }
 
-#[cfg(feature = "postgres")]
+#[cfg(any(feature = "postgres", feature = "distinct_on_for_mysql_8"))]
 #[diesel_test_helper::test]
 fn distinct_on() {
     use crate::schema::users::dsl::*;
@@ -58,7 +58,7 @@ fn distinct_on() {
     assert_eq!(expected_data, data);
 }
 
-#[cfg(feature = "postgres")]
+#[cfg(any(feature = "postgres", feature = "distinct_on_for_mysql_8"))]
 #[diesel_test_helper::test]
 fn distinct_on_select_by() {
     use crate::schema::users::dsl::*;
@@ -82,7 +82,7 @@ fn distinct_on_select_by() {
     assert_eq!(expected_data, data);
 }
 
-#[cfg(feature = "postgres")]
+#[cfg(any(feature = "postgres", feature = "distinct_on_for_mysql_8"))]
 #[diesel_test_helper::test]
 fn distinct_on_select_order_by_two_columns() {
     use diesel::sql_types::Integer;
@@ -0,0 +1,148 @@
+use crate::schema::*;
+use diesel::dsl::*;
+use diesel::sql_types::{Integer, Text};
+use diesel::*;
+
+fn insert_scored_users(conn: &mut TestConnection) {
+    diesel::insert_into(users::table)
+        .values(&vec![
+            NewUser::new("Sean", Some("black")),
+            NewUser::new("Tess", Some("brown")),
+            NewUser::new("Jim", None),
+            NewUser::new("Sam", Some("black")),
+        ])
+        .execute(conn)
+        .unwrap();
+}
+
+#[diesel_test_helper::test]
+fn case_when_with_many_branches_in_order_by() {
+    use crate::schema::users::dsl::*;
+
+    let conn = &mut connection();
+    insert_scored_users(conn);
+
+    let score = case_when::<_, _, Integer>(hair_color.is_null(), 3)
+        .when(name.like("S%").and(hair_color.eq("black")), 2)
+        .when(hair_color.eq_any(["brown", "blond"]), 1)
+        .otherwise(0);
+    let names = users
+        .select((name, score.clone()))
+        .order((score.desc(), name))
+        .load::<(String, i32)>(conn)
+        .unwrap();
+
+    let expected = vec![
+        (String::from("Jim"), 3),
+        (String::from("Sam"), 2),
+        (String::from("Sean"), 2),
+        (String::from("Tess"), 1),
+    ];
+    assert_eq!(expected, names);
+}
+
+#[diesel_test_helper::test]
+fn case_when_in_aggregates() {
+    use crate::schema::users::dsl::*;
+
+    let conn = &mut connection();
+    insert_scored_users(conn);
+
+    let black_haired = users
+        .select(count(case_when(hair_color.eq("black"), id)))
+        .get_result::<i64>(conn)
+        .unwrap();
+    assert_eq!(2, black_haired);
+
+    let mut counts = users
+        .group_by(hair_color)
+        .select((
+            hair_color,
+            max(case_when::<_, _, Integer>(name.like("S%"), 1).otherwise(0)),
+        ))
+        .load::<(Option<String>, Option<i32>)>(conn)
+        .unwrap();
+    // the position of `NULL` in the ordering differs between backends
+    counts.sort();
+    let expected = vec![
+        (None, Some(0)),
+        (Some(String::from("black")), Some(1)),
+        (Some(String::from("brown")), Some(0)),
+    ];
+    assert_eq!(expected, counts);
+}
+
+#[diesel_test_helper::test]
+fn case_with_operand() {
+    use crate::schema::users::dsl::*;
+
+    let conn = &mut connection();
+    insert_scored_users(conn);
+
+    let colors = users
+        .select(
+            case(hair_color)
+                .when::<_, _, Text>("black", "dark")
+                .when("brown", "medium")
+                .otherwise("unknown"),
+        )
+        .order(name)
+        .load::<String>(conn)
+        .unwrap();
+    assert_eq!(vec!["unknown", "dark", "dark", "medium"], colors);
+
+    let ids = users
+        .select(case(name).when("Tess", id).when("Jim", id * 10))
+        .filter(
+            case(name)
+                .when::<_, _, Integer>("Sean", 1)
+                .otherwise(0)
+                .eq(0),
+        )
+        .order(name)
+        .load::<Option<i32>>(conn)
+        .unwrap();
+    assert_eq!(3, ids.len());
+    assert!(ids[0].is_some());
+    assert_eq!(None, ids[1]);
+    assert!(ids[2].is_some());
+}
+
+#[diesel_test_helper::test]
+fn case_with_operand_on_alias() {
+    use crate::schema::users;
+
+    let conn = &mut connection();
+    insert_scored_users(conn);
+
+    let user_alias = alias!(users as user_alias);
+    let colors = user_alias
+        .select(
+            case(user_alias.field(users::hair_color))
+                .when::<_, _, Text>("black", "dark")
+                .otherwise("light"),
+        )
+        .order(user_alias.field(users::name))
+        .load::<String>(conn)
+        .unwrap();
+    assert_eq!(vec!["light", "dark", "dark", "light"], colors);
+}
+
+#[diesel_test_helper::test]
+fn case_with_operand_sql() {
+    use crate::schema::users::dsl::*;
+
+    let query = users.select(case(id).when::<_, _, Text>(1, "first").otherwise("other"));
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    if cfg!(feature = "postgres") {
+        assert_eq!(
+            r#"SELECT CASE ("users"."id") WHEN ($1) THEN ($2) ELSE ($3) END FROM "users" -- binds: [1, "first", "other"]"#,
+            sql
+        );
+    } else {
+        assert_eq!(
+            r#"SELECT CASE (`users`.`id`) WHEN (?) THEN (?) ELSE (?) END FROM `users` -- binds: [1, "first", "other"]"#,
+            sql
+        );
+    }
+}
@@ -452,6 +452,40 @@ fn adding_interval_to_nullable_things() {
     assert_eq!(expected_data, actual_data);
 }
 
+#[diesel_test_helper::test]
+fn adding_and_subtracting_durations() {
+    use self::has_timestamps::dsl::*;
+    use std::time::Duration;
+
+    let connection = &mut connection();
+    setup_test_table(connection);
+    diesel::sql_query(
+        "INSERT INTO has_timestamps (created_at, updated_at) VALUES
+                       ('2015-11-15 06:07:41', '2015-11-15 20:07:41')",
+    )
+    .execute(connection)
+    .unwrap();
+
+    let fourteen_hours = Duration::from_secs(14 * 60 * 60);
+    let count = has_timestamps
+        .filter((created_at + fourteen_hours).eq(updated_at))
+        .count()
+        .get_result(connection);
+    assert_eq!(Ok(1), count);
+
+    let count = has_timestamps
+        .filter((updated_at - fourteen_hours).eq(created_at))
+        .count()
+        .get_result(connection);
+    assert_eq!(Ok(1), count);
+
+    let count = has_timestamps
+        .filter((created_at + fourteen_hours + Duration::from_secs(1)).eq(updated_at))
+        .count()
+        .get_result(connection);
+    assert_eq!(Ok(0), count);
+}
+
 #[cfg(any(feature = "postgres", feature = "sqlite", feature = "mysql"))]
 fn setup_test_table(conn: &mut TestConnection) {
     use crate::schema_dsl::*;
@@ -1,5 +1,6 @@
 use bigdecimal;
 
+mod case_when;
 mod date_and_time;
 mod ops;
 
@@ -729,3 +729,170 @@ fn filter_subselect_with_pg_any() {
         .load(conn);
     assert_eq!(Ok(vec![sean]), users_with_published_posts);
 }
+
+#[diesel_test_helper::test]
+fn select_subselect_referencing_outer_table() {
+    use diesel::dsl::exists;
+
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", conn);
+
+    insert_into(posts::table)
+        .values(&sean.new_post("Hello", None))
+        .execute(conn)
+        .unwrap();
+
+    let users_with_posts = users::table
+        .select((
+            users::name,
+            exists(posts::table.filter(posts::user_id.eq(users::id))),
+        ))
+        .order(users::id)
+        .load::<(String, bool)>(conn);
+    let expected = vec![("Sean".to_string(), true), ("Tess".to_string(), false)];
+    assert_eq!(Ok(expected), users_with_posts);
+}
+
+#[diesel_test_helper::test]
+#[cfg(not(feature = "sqlite"))]
+fn filter_subselect_with_quantified_comparison() {
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", conn);
+    let tess = find_user_by_name("Tess", conn);
+
+    insert_into(posts::table)
+        .values(&vec![
+            sean.new_post("Hello", None),
+            tess.new_post("Hello 2", None),
+        ])
+        .execute(conn)
+        .unwrap();
+
+    let data = users::table
+        .filter(users::id.gt_any(posts::table.select(posts::user_id)))
+        .load(conn);
+    assert_eq!(Ok(vec![tess.clone()]), data);
+
+    let data = users::table
+        .filter(users::id.lt_all(posts::table.select(posts::user_id)))
+        .load::<User>(conn);
+    assert_eq!(Ok(vec![]), data);
+
+    let data = users::table
+        .filter(
+            users::id.ge_all(
+                posts::table
+                    .select(posts::user_id)
+                    .filter(posts::user_id.eq(users::id)),
+            ),
+        )
+        .order(users::id)
+        .load(conn);
+    assert_eq!(Ok(vec![sean, tess]), data);
+}
+
+#[diesel_test_helper::test]
+#[cfg(feature = "postgres")]
+fn quantified_comparison_sql() {
+    let query = users::table
+        .select(users::id)
+        .filter(users::id.le_any(posts::table.select(posts::user_id)));
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    assert_eq!(
+        "SELECT \"users\".\"id\" FROM \"users\" WHERE (\"users\".\"id\" <= \
+         ANY (SELECT \"posts\".\"user_id\" FROM \"posts\")) -- binds: []",
+        sql
+    );
+}
+
+#[diesel_test_helper::test]
+fn filter_by_row_value_comparison() {
+    use crate::schema::users::dsl::*;
+
+    let connection = &mut connection();
+    insert_into(users)
+        .values(&vec![
+            NewUser::new("Sean", Some("black")),
+            NewUser::new("Tess", Some("brown")),
+            NewUser::new("Sean", Some("brown")),
+            NewUser::new("Jim", None),
+        ])
+        .execute(connection)
+        .unwrap();
+
+    let page = |after: (&str, &str), connection: &mut TestConnection| {
+        users
+            .select((name, hair_color))
+            .filter((name, hair_color).row_gt(after))
+            .order((name, hair_color))
+            .limit(2)
+            .load::<(String, Option<String>)>(connection)
+            .unwrap()
+    };
+    assert_eq!(
+        vec![
+            ("Sean".to_string(), Some("brown".to_string())),
+            ("Tess".to_string(), Some("brown".to_string())),
+        ],
+        page(("Sean", "black"), connection)
+    );
+    assert_eq!(
+        Vec::<(String, Option<String>)>::new(),
+        page(("Tess", "brown"), connection)
+    );
+
+    // comparisons with a `NULL` element are `NULL`
+    let data = users
+        .select(name)
+        .filter((name, hair_color).row_le(("Jim", "black")))
+        .load::<String>(connection)
+        .unwrap();
+    assert!(data.is_empty());
+
+    let data = users
+        .select(name)
+        .filter((name, hair_color).row_eq(("Sean", "brown")))
+        .load::<String>(connection)
+        .unwrap();
+    assert_eq!(vec!["Sean"], data);
+}
+
+#[diesel_test_helper::test]
+fn filter_by_row_value_eq_any() {
+    use crate::schema::users::dsl::*;
+
+    let connection = &mut connection_with_sean_and_tess_in_users_table();
+
+    let data = users
+        .select(name)
+        .filter((id, name).row_eq_any(vec![(1, "Sean"), (2, "Tess"), (2, "Sean")]))
+        .order(id)
+        .load::<String>(connection)
+        .unwrap();
+    assert_eq!(vec!["Sean", "Tess"], data);
+
+    let data = users
+        .select(name)
+        .filter((id, name).row_eq_any(Vec::<(i32, &str)>::new()))
+        .load::<String>(connection)
+        .unwrap();
+    assert!(data.is_empty());
+}
+
+#[diesel_test_helper::test]
+fn row_value_comparison_sql() {
+    use crate::schema::users::dsl::*;
+
+    let query = users
+        .select(id)
+        .filter((name, id).row_gt(("Sean", 1)))
+        .filter((id, name).row_eq_any([(1, "Sean"), (2, "Tess")]));
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+
+    let expected = if cfg!(feature = "postgres") {
+        r#"SELECT "users"."id" FROM "users" WHERE ((("users"."name", "users"."id") > ($1, $2)) AND (("users"."id", "users"."name") IN (($3, $4), ($5, $6)))) -- binds: ["Sean", 1, 1, "Sean", 2, "Tess"]"#
+    } else {
+        r#"SELECT `users`.`id` FROM `users` WHERE (((`users`.`name`, `users`.`id`) > (?, ?)) AND ((`users`.`id`, `users`.`name`) IN ((?, ?), (?, ?)))) -- binds: ["Sean", 1, 1, "Sean", 2, "Tess"]"#
+    };
+    assert_eq!(expected, sql);
+}
@@ -207,6 +207,34 @@ fn insert_records_as_vec() {
     assert_eq!(expected_users, actual_users);
 }
 
+#[diesel_test_helper::test]
+fn insert_records_batched() {
+    use crate::schema::users::{id, name, table as users};
+    let connection = &mut connection();
+    let new_users = (0..7)
+        .map(|i| NewUser::new(&format!("User {i}"), None))
+        .collect::<Vec<_>>();
+
+    let inserted_rows = insert_into(users)
+        .values(&new_users)
+        .execute_batched(connection, 3)
+        .unwrap();
+    assert_eq!(7, inserted_rows);
+
+    let actual_names = users
+        .select(name)
+        .order(id)
+        .load::<String>(connection)
+        .unwrap();
+    let expected_names = (0..7).map(|i| format!("User {i}")).collect::<Vec<_>>();
+    assert_eq!(expected_names, actual_names);
+
+    let result = insert_into(users)
+        .values(&new_users)
+        .execute_batched(connection, 0);
+    assert!(matches!(result, Err(result::Error::QueryBuilderError(_))));
+}
+
 #[diesel_test_helper::test]
 fn insert_records_as_static_array() {
     use crate::schema::users::{id, table as users};
@@ -377,6 +405,23 @@ fn insert_record_using_returning_clause() {
     assert_eq!(expected_user, inserted_user);
 }
 
+#[diesel_test_helper::test]
+#[cfg(feature = "returning_clauses_for_mariadb")]
+fn insert_record_using_returning_clause_on_mariadb() {
+    use crate::schema::users::dsl::*;
+    let connection = &mut connection();
+    let new_user = &NewUser::new("Sean", Some("Black"));
+
+    let (inserted_name, inserted_hair_color) = insert_into(users)
+        .values(new_user)
+        .returning((name, hair_color))
+        .get_result::<(String, Option<String>)>(connection)
+        .unwrap();
+
+    assert_eq!("Sean", inserted_name);
+    assert_eq!(Some("Black".to_string()), inserted_hair_color);
+}
+
 #[diesel_test_helper::test]
 #[cfg(feature = "returning_clauses_for_sqlite_3_35")]
 fn insert_record_attached_database_using_returning_clause() {
@@ -1410,3 +1455,114 @@ fn returning_old_column_in_insert_on_conflict_do_update_via_selectable() {
         row,
     );
 }
+
+#[diesel_test_helper::test]
+fn insert_with_children_inserts_parent_and_children() {
+    use crate::schema::posts;
+    use crate::schema::users;
+
+    let connection = &mut connection();
+
+    let (user_id, inserted_posts) = insert_into(users::table)
+        .values(users::name.eq("Sean"))
+        .execute_with_children(connection, users::id, |conn, user_id: i32| {
+            insert_into(posts::table)
+                .values(vec![
+                    (posts::user_id.eq(user_id), posts::title.eq("First")),
+                    (posts::user_id.eq(user_id), posts::title.eq("Second")),
+                ])
+                .execute(conn)
+        })
+        .unwrap();
+    assert_eq!(2, inserted_posts);
+
+    let user_name = users::table
+        .find(user_id)
+        .select(users::name)
+        .first::<String>(connection);
+    assert_eq!(Ok("Sean".to_string()), user_name);
+    let post_user_ids = posts::table.select(posts::user_id).load::<i32>(connection);
+    assert_eq!(Ok(vec![user_id, user_id]), post_user_ids);
+}
+
+#[diesel_test_helper::test]
+fn insert_with_children_rolls_back_parent_if_children_fail() {
+    use crate::schema::users;
+
+    let connection = &mut connection();
+
+    let result = insert_into(users::table)
+        .values(users::name.eq("Sean"))
+        .execute_with_children(connection, users::id, |_, _: i32| {
+            Err::<(), _>(diesel::result::Error::RollbackTransaction)
+        });
+    assert_eq!(Err(diesel::result::Error::RollbackTransaction), result);
+
+    let user_count = users::table.count().get_result::<i64>(connection);
+    assert_eq!(Ok(0), user_count);
+}
+
+#[diesel_test_helper::test]
+fn insert_skips_generated_columns() {
+    table! {
+        users {
+            #[diesel(generated)]
+            id -> Integer,
+            name -> Text,
+            hair_color -> Nullable<Text>,
+        }
+    }
+
+    #[derive(Insertable)]
+    #[diesel(table_name = users)]
+    struct UserWithId<'a> {
+        id: i32,
+        name: &'a str,
+        hair_color: Option<&'a str>,
+    }
+
+    let connection = &mut connection();
+    let new_users = vec![
+        UserWithId {
+            id: 42,
+            name: "Sean",
+            hair_color: Some("Black"),
+        },
+        UserWithId {
+            id: 42,
+            name: "Tess",
+            hair_color: None,
+        },
+    ];
+
+    let query = insert_into(users::table).values(&new_users[0]);
+    let sql = debug_query::<TestBackend, _>(&query).to_string();
+    assert!(!sql.contains("42"), "{sql}");
+
+    insert_into(users::table)
+        .values(&new_users)
+        .execute(connection)
+        .unwrap();
+    insert_into(users::table)
+        .values((users::id.eq(42), users::name.eq("Jim")))
+        .execute(connection)
+        .unwrap();
+
+    let users = users::table
+        .select((users::id, users::name, users::hair_color))
+        .order(users::id)
+        .load::<(i32, String, Option<String>)>(connection)
+        .unwrap();
+    assert_eq!(3, users.len());
+    assert!(users.iter().all(|(id, ..)| *id != 42), "{users:?}");
+    let users = users
+        .into_iter()
+        .map(|(_, name, hair_color)| (name, hair_color))
+        .collect::<Vec<_>>();
+    let expected = vec![
+        ("Sean".to_string(), Some("Black".to_string())),
+        ("Tess".to_string(), None),
+        ("Jim".to_string(), None),
+    ];
+    assert_eq!(expected, users);
+}
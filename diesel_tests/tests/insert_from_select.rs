@@ -132,6 +132,53 @@ fn insert_from_boxed_reference() {
     assert_eq!(expected, data);
 }
 
+#[diesel_test_helper::test]
+#[cfg(not(any(not(feature = "returning_clauses_for_sqlite_3_35"), feature = "mysql")))]
+fn insert_from_select_with_returning_clause() {
+    use crate::schema::posts::dsl::*;
+    use crate::schema::users::dsl::{id, name, users};
+
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+    let inserted = insert_into(posts)
+        .values(users.select((id, name.concat(" says hi"))))
+        .into_columns((user_id, title))
+        .returning((user_id, title))
+        .get_results::<(i32, String)>(conn);
+
+    let mut inserted = inserted.unwrap();
+    inserted.sort();
+    let expected = vec![
+        (1, String::from("Sean says hi")),
+        (2, String::from("Tess says hi")),
+    ];
+    assert_eq!(expected, inserted);
+}
+
+#[diesel_test_helper::test]
+#[cfg(not(any(not(feature = "returning_clauses_for_sqlite_3_35"), feature = "mysql")))]
+fn insert_from_combined_select_with_returning_clause() {
+    use crate::schema::posts::dsl::*;
+    use crate::schema::users::dsl::{id, name, users};
+
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+    let sean = users
+        .select((id, name.concat(" says hi")))
+        .filter(name.eq("Sean"));
+    let tess = users
+        .select((id, name.concat(" says bye")))
+        .filter(name.eq("Tess"));
+    let inserted = sean
+        .union_all(tess)
+        .insert_into(posts)
+        .into_columns((user_id, title))
+        .returning(title)
+        .get_results::<String>(conn);
+
+    let mut inserted = inserted.unwrap();
+    inserted.sort();
+    assert_eq!(vec!["Sean says hi", "Tess says bye"], inserted);
+}
+
 #[diesel_test_helper::test]
 #[cfg(feature = "sqlite")]
 fn insert_or_ignore_with_select() {
@@ -2,14 +2,20 @@ use crate::schema::TestConnection;
 use crate::schema::users;
 use diesel::Connection;
 use diesel::QueryResult;
+use diesel::connection::DatabaseWarning;
 use diesel::connection::DefaultLoadingMode;
+use diesel::connection::Instrumentation;
 use diesel::connection::InstrumentationEvent;
 use diesel::connection::LoadConnection;
 use diesel::connection::SimpleConnection;
+use diesel::connection::StatementCacheStatus;
+use diesel::connection::StatementInfo;
+use diesel::connection::StatementKind;
 use diesel::query_builder::AsQuery;
 use std::num::NonZeroU32;
 use std::sync::Arc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 use crate::schema::connection_with_sean_and_tess_in_users_table;
 
@@ -18,6 +24,7 @@ enum Event {
     StartQuery { query: String },
     CacheQuery { sql: String },
     FinishQuery { query: String, error: Option<()> },
+    QueryWarnings { warnings: Vec<DatabaseWarning> },
     BeginTransaction { depth: NonZeroU32 },
     CommitTransaction { depth: NonZeroU32 },
     RollbackTransaction { depth: NonZeroU32 },
@@ -38,6 +45,9 @@ impl From<InstrumentationEvent<'_>> for Event {
                 query: query.to_string(),
                 error: error.map(|_| ()),
             },
+            InstrumentationEvent::QueryWarnings { warnings, .. } => Event::QueryWarnings {
+                warnings: warnings.to_vec(),
+            },
             InstrumentationEvent::BeginTransaction { depth, .. } => {
                 Event::BeginTransaction { depth }
             }
@@ -97,6 +107,41 @@ fn check_events_are_emitted_for_execute_returning_count() {
     assert_matches!(events[2], Event::FinishQuery { .. });
 }
 
+#[diesel_test_helper::test]
+fn check_events_are_emitted_for_query_warnings() {
+    let (events_to_check, mut conn) = setup_test_case();
+    let (_, warnings) = conn
+        .execute_returning_count_with_warnings(&diesel::sql_query(
+            "DROP TABLE IF EXISTS does_not_exist",
+        ))
+        .unwrap();
+    let events = events_to_check.lock().unwrap();
+    assert_matches!(events[0], Event::StartQuery { .. });
+    assert_matches!(events[1], Event::FinishQuery { .. });
+    if cfg!(feature = "sqlite") {
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(events.len(), 2, "{events:?}");
+    } else {
+        assert_eq!(warnings.len(), 1, "{warnings:?}");
+        assert!(
+            warnings[0].message().contains("does_not_exist"),
+            "{warnings:?}"
+        );
+        #[cfg(feature = "postgres")]
+        {
+            assert_eq!(warnings[0].level(), "NOTICE");
+            assert_eq!(warnings[0].code(), Some("00000"));
+        }
+        #[cfg(feature = "mysql")]
+        {
+            assert_eq!(warnings[0].level(), "Note");
+            assert_eq!(warnings[0].code(), Some("1051"));
+        }
+        assert_eq!(events.len(), 3, "{events:?}");
+        assert_eq!(events[2], Event::QueryWarnings { warnings });
+    }
+}
+
 #[diesel_test_helper::test]
 fn check_events_are_emitted_for_load() {
     let (events_to_check, mut conn) = setup_test_case();
@@ -334,3 +379,216 @@ fn check_events_are_emitted_for_copy_from() {
     assert_matches!(events[0], Event::StartQuery { .. });
     assert_matches!(events[1], Event::FinishQuery { error: None, .. });
 }
+
+mod sensitive_users {
+    diesel::table! {
+        users {
+            id -> Integer,
+            name -> Text,
+            #[diesel(sensitive)]
+            hair_color -> Nullable<Text>,
+        }
+    }
+}
+
+#[diesel_test_helper::test]
+fn check_events_redact_values_of_sensitive_columns() {
+    use self::sensitive_users::users::dsl::*;
+    use diesel::prelude::*;
+
+    let (events_to_check, mut conn) = setup_test_case();
+    let result = users
+        .select(id)
+        .filter(name.eq("Sean"))
+        .filter(
+            hair_color
+                .eq("black")
+                .or(hair_color.eq_any(["brown", "green"])),
+        )
+        .load::<i32>(&mut conn)
+        .unwrap();
+    assert!(result.is_empty());
+
+    let events = events_to_check.lock().unwrap();
+    let Event::StartQuery { query } = &events[0] else {
+        panic!("expected a start query event, got {events:?}");
+    };
+    // postgres sends `eq_any` values as a single array bind
+    assert!(
+        query.contains(r#"-- binds: ["Sean", <redacted>"#),
+        "{query}"
+    );
+    assert!(!query.contains("black"), "{query}");
+    assert!(!query.contains("brown"), "{query}");
+}
+
+#[diesel_test_helper::test]
+fn debug_query_redacts_inserted_and_updated_sensitive_values() {
+    use self::sensitive_users::users::dsl::*;
+    use diesel::prelude::*;
+
+    let insert = diesel::insert_into(users).values((name.eq("Sean"), hair_color.eq("black")));
+    let sql = diesel::debug_query::<crate::schema::TestBackend, _>(&insert).to_string();
+    assert!(sql.ends_with(r#"-- binds: ["Sean", <redacted>]"#), "{sql}");
+
+    let update =
+        diesel::update(users.filter(id.eq(1))).set((name.eq("Tess"), hair_color.eq("brown")));
+    let sql = diesel::debug_query::<crate::schema::TestBackend, _>(&update).to_string();
+    assert!(
+        sql.ends_with(r#"-- binds: ["Tess", <redacted>, 1]"#),
+        "{sql}"
+    );
+}
+
+#[diesel_test_helper::test]
+fn debug_query_only_redacts_values_following_sensitive_columns() {
+    use self::sensitive_users::users::dsl::*;
+    use diesel::prelude::*;
+    use diesel::query_builder::Sensitive;
+    use diesel::sql_types::{Integer, Text};
+
+    let query = users
+        .select(id)
+        .filter(hair_color.eq_any(vec!["brown", "green"]))
+        .filter(id.eq(1))
+        .limit(2)
+        .offset(3);
+    let sql = diesel::debug_query::<crate::schema::TestBackend, _>(&query).to_string();
+    assert!(sql.ends_with("1, 2, 3]"), "{sql}");
+    assert!(!sql.contains("brown"), "{sql}");
+    assert!(!sql.contains("green"), "{sql}");
+
+    let query = diesel::sql_query("SELECT id FROM users WHERE hair_color = ? AND id = ?")
+        .bind::<Text, _>(Sensitive("black"))
+        .bind::<Integer, _>(1);
+    let sql = diesel::debug_query::<crate::schema::TestBackend, _>(&query).to_string();
+    assert!(sql.ends_with("-- binds: [<redacted>, 1]"), "{sql}");
+}
+
+#[derive(Debug)]
+struct QueryMetadata {
+    info: Option<StatementInfo>,
+    duration: Option<Duration>,
+    statement_cache: Option<StatementCacheStatus>,
+}
+
+fn setup_metadata_test_case() -> (Arc<Mutex<Vec<QueryMetadata>>>, TestConnection) {
+    let queries = Arc::new(Mutex::new(Vec::new()));
+    let queries_to_check = queries.clone();
+    let mut conn = connection_with_sean_and_tess_in_users_table();
+    conn.set_instrumentation(move |event: InstrumentationEvent<'_>| {
+        if let InstrumentationEvent::FinishQuery {
+            query,
+            duration,
+            statement_cache,
+            ..
+        } = event
+        {
+            queries.lock().unwrap().push(QueryMetadata {
+                info: query.statement_info(),
+                duration,
+                statement_cache,
+            });
+        }
+    });
+    (queries_to_check, conn)
+}
+
+#[diesel_test_helper::test]
+fn finish_query_events_contain_structured_metadata() {
+    use crate::schema::posts;
+    use diesel::prelude::*;
+
+    let (queries_to_check, mut conn) = setup_metadata_test_case();
+    users::table
+        .filter(users::name.eq("Sean"))
+        .filter(users::id.ne_all(posts::table.select(posts::user_id)))
+        .load::<crate::schema::User>(&mut conn)
+        .unwrap();
+    diesel::insert_into(users::table)
+        .values(users::name.eq("Jim"))
+        .execute(&mut conn)
+        .unwrap();
+    diesel::update(users::table.filter(users::name.eq("Jim")))
+        .set(users::hair_color.eq("black"))
+        .execute(&mut conn)
+        .unwrap();
+    diesel::delete(users::table.filter(users::name.eq("Jim")))
+        .execute(&mut conn)
+        .unwrap();
+    diesel::sql_query("SELECT 1").execute(&mut conn).unwrap();
+
+    let queries = queries_to_check.lock().unwrap();
+    let infos = queries
+        .iter()
+        .map(|q| {
+            let info = q.info.as_ref().unwrap();
+            (info.kind(), info.tables().to_vec(), info.bind_count())
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        infos,
+        [
+            (
+                StatementKind::Select,
+                vec![String::from("users"), String::from("posts")],
+                1
+            ),
+            (StatementKind::Insert, vec![String::from("users")], 1),
+            (StatementKind::Update, vec![String::from("users")], 2),
+            (StatementKind::Delete, vec![String::from("users")], 1),
+            (StatementKind::Other, Vec::new(), 0),
+        ]
+    );
+    assert!(queries.iter().all(|q| q.duration.is_some()), "{queries:?}");
+}
+
+#[diesel_test_helper::test]
+fn finish_query_events_contain_the_statement_cache_status() {
+    use diesel::prelude::*;
+
+    let (queries_to_check, mut conn) = setup_metadata_test_case();
+    for _ in 0..2 {
+        users::table
+            .select(users::id)
+            .load::<i32>(&mut conn)
+            .unwrap();
+    }
+    diesel::sql_query("SELECT 1").execute(&mut conn).unwrap();
+
+    let queries = queries_to_check.lock().unwrap();
+    let statuses = queries
+        .iter()
+        .map(|q| q.statement_cache)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        statuses,
+        [
+            Some(StatementCacheStatus::Miss),
+            Some(StatementCacheStatus::Hit),
+            Some(StatementCacheStatus::Uncached),
+        ]
+    );
+}
+
+#[diesel_test_helper::test]
+fn multiple_instrumentations_receive_all_events() {
+    let first = Arc::new(Mutex::new(Vec::<Event>::new()));
+    let second = Arc::new(Mutex::new(Vec::<Event>::new()));
+    let mut conn = connection_with_sean_and_tess_in_users_table();
+    conn.set_instrumentation(
+        [first.clone(), second.clone()]
+            .into_iter()
+            .map(|events| {
+                Box::new(move |event: InstrumentationEvent<'_>| {
+                    events.lock().unwrap().push(event.into());
+                }) as Box<dyn Instrumentation>
+            })
+            .collect::<Vec<_>>(),
+    );
+    conn.batch_execute("select 1").unwrap();
+
+    let first = first.lock().unwrap();
+    assert_eq!(first.len(), 2);
+    assert_eq!(*first, *second.lock().unwrap());
+}
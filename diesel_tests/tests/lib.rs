@@ -16,6 +16,7 @@ mod boxed_queries;
 mod cast;
 mod collation;
 mod combination;
+mod common_table_expressions;
 mod connection;
 #[cfg(feature = "postgres")]
 mod copy;
@@ -58,10 +59,12 @@ mod schema_inference;
 mod select;
 mod select_by;
 mod serialize_as;
+mod sql_only_connection;
 #[cfg(not(feature = "mysql"))] // FIXME: Figure out how to handle tests that modify schema
 mod transactions;
 mod types;
 mod types_roundtrip;
 mod update;
+mod values_list;
 mod view_testing;
 mod window_functions;
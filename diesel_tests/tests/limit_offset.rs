@@ -171,3 +171,61 @@ fn boxed_limit_offset() {
         assert_eq!(expected_data, actual_data);
     }
 }
+
+#[diesel_test_helper::test]
+fn paginate() {
+    use crate::schema::users::dsl::*;
+    use diesel::query_dsl::PaginateDsl;
+
+    let connection = &mut connection();
+    diesel::sql_query("INSERT INTO users (name) VALUES ('Sean'), ('Tess'), ('Jim')")
+        .execute(connection)
+        .unwrap();
+
+    let query = users.select(name).order(name);
+    let page = query.paginate(1, 2).load_and_count::<String, _>(connection);
+    assert_eq!(Ok((vec!["Jim".to_string(), "Sean".to_string()], 3)), page);
+
+    let page = query.paginate(2, 2).load_and_count::<String, _>(connection);
+    assert_eq!(Ok((vec!["Tess".to_string()], 3)), page);
+
+    let page = query.paginate(3, 2).load_and_count::<String, _>(connection);
+    assert_eq!(Ok((Vec::new(), 3)), page);
+
+    let page = users
+        .select(name)
+        .filter(name.ne("Jim"))
+        .order(name)
+        .into_boxed()
+        .paginate(1, 1)
+        .load_and_count::<String, _>(connection);
+    assert_eq!(Ok((vec!["Sean".to_string()], 2)), page);
+}
+
+#[cfg(any(feature = "sqlite", feature = "postgres"))]
+#[diesel_test_helper::test]
+fn paginate_with_window_function() {
+    use crate::schema::users::dsl::*;
+    use diesel::query_builder::PaginationCount;
+    use diesel::query_dsl::PaginateDsl;
+
+    let connection = &mut connection();
+    diesel::sql_query("INSERT INTO users (name) VALUES ('Sean'), ('Tess'), ('Jim')")
+        .execute(connection)
+        .unwrap();
+
+    let query = users.select((name, hair_color)).order(name.desc());
+    let page = query
+        .paginate(1, 2)
+        .count_with(PaginationCount::WindowFunction)
+        .load_and_count::<(String, Option<String>), _>(connection);
+    let expected = vec![("Tess".to_string(), None), ("Sean".to_string(), None)];
+    assert_eq!(Ok((expected, 3)), page);
+
+    // pages after the last page fall back to a separate count query
+    let page = query
+        .paginate(3, 2)
+        .count_with(PaginationCount::WindowFunction)
+        .load_and_count::<(String, Option<String>), _>(connection);
+    assert_eq!(Ok((Vec::new(), 3)), page);
+}
@@ -80,3 +80,45 @@ fn test_rust_migrations() {
     assert!(rust_migration_called2.load(Ordering::Relaxed));
     assert_eq!(GLOBAL_FLAG.load(Ordering::Relaxed), 1);
 }
+
+#[cfg(all(
+    any(feature = "sqlite", feature = "postgres"),
+    not(all(target_family = "wasm", target_os = "unknown"))
+))]
+mod diesel_test_attribute {
+    use crate::schema::{TestConnection, users};
+    use diesel::diesel_test;
+    use diesel::prelude::*;
+
+    // Both tests insert the same row, so they would observe each other
+    // if they did not run on separate databases
+    #[cfg_attr(feature = "sqlite", diesel_test(migrations = "../migrations/sqlite"))]
+    #[cfg_attr(
+        feature = "postgres",
+        diesel_test(migrations = "../migrations/postgres")
+    )]
+    fn each_test_runs_on_a_migrated_database(conn: &mut TestConnection) {
+        diesel::insert_into(users::table)
+            .values(users::name.eq("Sean"))
+            .execute(conn)
+            .unwrap();
+
+        let count = users::table.count().get_result::<i64>(conn).unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[cfg_attr(feature = "sqlite", diesel_test(migrations = "../migrations/sqlite"))]
+    #[cfg_attr(
+        feature = "postgres",
+        diesel_test(migrations = "../migrations/postgres")
+    )]
+    fn diesel_tests_can_return_results(conn: &mut TestConnection) -> QueryResult<()> {
+        diesel::insert_into(users::table)
+            .values(users::name.eq("Sean"))
+            .execute(conn)?;
+
+        let count = users::table.count().get_result::<i64>(conn)?;
+        assert_eq!(count, 1);
+        Ok(())
+    }
+}
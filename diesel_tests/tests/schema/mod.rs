@@ -341,15 +341,15 @@ impl Drop for DropTable<'_> {
 }
 
 #[cfg(feature = "sqlite")]
-const MIGRATIONS: diesel_migrations::EmbeddedMigrations =
+pub const MIGRATIONS: diesel_migrations::EmbeddedMigrations =
     diesel_migrations::embed_migrations!("../migrations/sqlite");
 
 #[cfg(feature = "postgres")]
-const MIGRATIONS: diesel_migrations::EmbeddedMigrations =
+pub const MIGRATIONS: diesel_migrations::EmbeddedMigrations =
     diesel_migrations::embed_migrations!("../migrations/postgres");
 
 #[cfg(feature = "mysql")]
-const MIGRATIONS: diesel_migrations::EmbeddedMigrations =
+pub const MIGRATIONS: diesel_migrations::EmbeddedMigrations =
     diesel_migrations::embed_migrations!("../migrations/mysql");
 
 pub fn connection() -> TestConnection {
@@ -37,6 +37,75 @@ fn selecting_a_struct() {
     assert_eq!(expected_users, actual_users);
 }
 
+#[diesel_test_helper::test]
+fn for_each_row_passes_every_row_to_the_callback() {
+    use crate::schema::users::dsl::*;
+    use diesel::connection::DefaultLoadingMode;
+
+    let connection = &mut connection();
+    diesel::sql_query("INSERT INTO users (name) VALUES ('Sean'), ('Tess')")
+        .execute(connection)
+        .unwrap();
+
+    let mut actual_users = Vec::new();
+    users
+        .select((name, hair_color))
+        .order(name)
+        .for_each_row::<NewUser, DefaultLoadingMode>(connection, |user| {
+            actual_users.push(user);
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(
+        vec![NewUser::new("Sean", None), NewUser::new("Tess", None)],
+        actual_users
+    );
+}
+
+#[cfg(feature = "postgres")]
+#[diesel_test_helper::test]
+fn for_each_row_streams_rows_with_row_by_row_loading_mode() {
+    use crate::schema::users::dsl::*;
+    use diesel::pg::PgRowByRowLoadingMode;
+
+    let connection = &mut connection();
+    diesel::sql_query("INSERT INTO users (name) VALUES ('Sean'), ('Tess')")
+        .execute(connection)
+        .unwrap();
+
+    let mut names = Vec::new();
+    users
+        .select(name)
+        .order(name)
+        .for_each_row::<String, PgRowByRowLoadingMode>(connection, |user_name| {
+            names.push(user_name);
+            Ok(())
+        })
+        .unwrap();
+    assert_eq!(vec!["Sean", "Tess"], names);
+}
+
+#[diesel_test_helper::test]
+fn for_each_row_stops_at_the_first_error_returned_by_the_callback() {
+    use crate::schema::users::dsl::*;
+    use diesel::connection::DefaultLoadingMode;
+
+    let connection = &mut connection();
+    diesel::sql_query("INSERT INTO users (name) VALUES ('Sean'), ('Tess')")
+        .execute(connection)
+        .unwrap();
+
+    let mut calls = 0;
+    let result = users
+        .select(name)
+        .for_each_row::<String, DefaultLoadingMode>(connection, |_| {
+            calls += 1;
+            Err(diesel::result::Error::NotFound)
+        });
+    assert_eq!(Err(diesel::result::Error::NotFound), result);
+    assert_eq!(1, calls);
+}
+
 #[diesel_test_helper::test]
 fn with_safe_select() {
     use crate::schema::users::dsl::*;
@@ -374,6 +443,49 @@ fn select_for_update_modifiers() {
     assert_eq!(tess.name, "Tess");
 }
 
+#[cfg(feature = "postgres")]
+#[diesel_test_helper::test]
+fn select_for_update_of_tables() {
+    use crate::schema::{posts, users};
+    use diesel::pg::Pg;
+
+    let connection = &mut connection_with_sean_and_tess_in_users_table();
+    let sean_id = find_user_by_name("Sean", connection).id;
+    insert_into(posts::table)
+        .values((posts::user_id.eq(sean_id), posts::title.eq("Hello")))
+        .execute(connection)
+        .unwrap();
+
+    let query = users::table
+        .inner_join(posts::table)
+        .select(users::name)
+        .for_update()
+        .of(users::table)
+        .skip_locked();
+    assert!(
+        debug_query::<Pg, _>(&query)
+            .to_string()
+            .ends_with(" FOR UPDATE OF \"users\" SKIP LOCKED -- binds: []"),
+        "{}",
+        debug_query::<Pg, _>(&query)
+    );
+    assert_eq!(Ok(vec!["Sean".to_string()]), query.load(connection));
+
+    let query = users::table
+        .inner_join(posts::table)
+        .select(users::name)
+        .for_share()
+        .of((users::table, posts::table));
+    assert!(
+        debug_query::<Pg, _>(&query)
+            .to_string()
+            .ends_with(" FOR SHARE OF \"users\", \"posts\" -- binds: []"),
+        "{}",
+        debug_query::<Pg, _>(&query)
+    );
+    assert_eq!(Ok(vec!["Sean".to_string()]), query.load(connection));
+}
+
 #[cfg(feature = "postgres")]
 #[diesel_test_helper::test]
 fn select_for_no_key_update_modifiers() {
@@ -0,0 +1,103 @@
+use crate::schema::{MIGRATIONS, TestBackend, users};
+use diesel::connection::{RecordedQuery, SqlOnlyConnection};
+use diesel::*;
+use diesel_migrations::MigrationHarness;
+
+#[diesel_test_helper::test]
+fn sql_only_connection_records_queries_and_binds() {
+    let mut connection = SqlOnlyConnection::<TestBackend>::new();
+
+    let affected_rows = update(users::table.filter(users::id.eq(1)))
+        .set(users::name.eq("new_name"))
+        .execute(&mut connection)
+        .unwrap();
+    assert_eq!(0, affected_rows);
+
+    let names = users::table
+        .select(users::name)
+        .load::<String>(&mut connection)
+        .unwrap();
+    assert!(names.is_empty());
+
+    let expected = if cfg!(feature = "postgres") {
+        [
+            r#"UPDATE "users" SET "name" = $1 WHERE ("users"."id" = $2)"#,
+            r#"SELECT "users"."name" FROM "users""#,
+        ]
+    } else {
+        [
+            "UPDATE `users` SET `name` = ? WHERE (`users`.`id` = ?)",
+            "SELECT `users`.`name` FROM `users`",
+        ]
+    };
+    assert_eq!(
+        vec![
+            RecordedQuery {
+                sql: expected[0].into(),
+                binds: vec![r#""new_name""#.into(), "1".into()],
+            },
+            RecordedQuery {
+                sql: expected[1].into(),
+                binds: Vec::new(),
+            },
+        ],
+        connection.take_queries()
+    );
+    assert!(connection.queries().is_empty());
+}
+
+#[diesel_test_helper::test]
+fn sql_only_connection_returns_no_rows() {
+    let mut connection = SqlOnlyConnection::<TestBackend>::new();
+
+    let result = users::table
+        .select(users::name)
+        .first::<String>(&mut connection);
+
+    assert_eq!(Err(result::Error::NotFound), result);
+    assert_eq!(1, connection.queries().len());
+}
+
+#[diesel_test_helper::test]
+fn sql_only_connection_records_transactions() {
+    let mut connection = SqlOnlyConnection::<TestBackend>::new();
+
+    connection
+        .transaction(|connection| {
+            delete(users::table).execute(connection)?;
+            QueryResult::Ok(())
+        })
+        .unwrap();
+
+    let sql = connection
+        .take_queries()
+        .into_iter()
+        .map(|query| query.sql)
+        .collect::<Vec<_>>();
+    let delete = if cfg!(feature = "postgres") {
+        r#"DELETE FROM "users""#
+    } else {
+        "DELETE FROM `users`"
+    };
+    assert_eq!(vec!["BEGIN", delete, "COMMIT"], sql);
+}
+
+#[diesel_test_helper::test]
+fn sql_only_connection_renders_migrations() {
+    let mut connection = SqlOnlyConnection::<TestBackend>::new();
+
+    let versions = connection.run_pending_migrations(MIGRATIONS).unwrap();
+    assert!(!versions.is_empty());
+
+    let sql = connection
+        .queries()
+        .iter()
+        .map(|query| query.sql.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        Some(&migration::CREATE_MIGRATIONS_TABLE),
+        sql.first(),
+        "the migrations table is created first"
+    );
+    assert!(sql.iter().any(|sql| sql.contains("CREATE TABLE users")));
+}
@@ -377,6 +377,36 @@ fn mysql_json_to_sql_json() {
     ));
 }
 
+#[diesel_test_helper::test]
+fn json_wrapper_roundtrips_serde_types() {
+    use diesel::data_types::Json as JsonWrapper;
+
+    let connection = &mut connection();
+    let value = vec![Some(1), None, Some(3)];
+    let result = diesel::select(JsonWrapper(&value).into_sql::<Json>())
+        .get_result::<JsonWrapper<Vec<Option<i32>>>>(connection)
+        .unwrap();
+    assert_eq!(value, result.into_inner());
+
+    let result = diesel::select(JsonWrapper(&value).into_sql::<Json>())
+        .get_result::<JsonWrapper<Vec<String>>>(connection);
+    assert!(result.is_err());
+}
+
+#[diesel_test_helper::test]
+#[cfg(any(feature = "postgres", feature = "sqlite"))]
+fn jsonb_wrapper_roundtrips_serde_types() {
+    use diesel::data_types::Json as JsonWrapper;
+    use std::collections::BTreeMap;
+
+    let connection = &mut connection();
+    let value = BTreeMap::from([("a".to_owned(), vec![1.5]), ("b".to_owned(), Vec::new())]);
+    let result = diesel::select(JsonWrapper(&value).into_sql::<Jsonb>())
+        .get_result::<JsonWrapper<BTreeMap<String, Vec<f64>>>>(connection)
+        .unwrap();
+    assert_eq!(value, result.into_inner());
+}
+
 use std::{f32, f64};
 
 #[diesel_test_helper::test]
@@ -1110,6 +1140,117 @@ fn mysql_numeric_bigdecimal_from_sql() {
     );
 }
 
+#[diesel_test_helper::test]
+#[cfg(feature = "postgres")]
+fn pg_numeric_string_to_sql() {
+    use diesel::data_types::NumericString;
+
+    let test_values = vec![
+        "0",
+        "0.1",
+        "1.0",
+        "141.0",
+        "-1.0",
+        // Larger than u64
+        "18446744073709551616",
+        // More precision than any float type provides
+        "12345678901234567890.0123456789",
+        // Powers of 10k (numeric is represented in base 10k)
+        "10000",
+        "100000000",
+        "1.100001",
+        "10000.100001",
+        "0.00001234",
+        "120000.00001234",
+        "120001.00001234",
+        "NaN",
+    ];
+
+    for value in test_values {
+        let expected = format!("'{}'::numeric", value);
+        let value = value.parse::<NumericString>().unwrap();
+        assert!(query_to_sql_equality::<Numeric, _>(&expected, value));
+    }
+}
+
+#[diesel_test_helper::test]
+#[cfg(feature = "postgres")]
+fn pg_numeric_string_from_sql() {
+    use diesel::data_types::NumericString;
+
+    let values = vec![
+        "0",
+        "0.1",
+        "1.0",
+        "141.0",
+        "-1.0",
+        "4.2000000",
+        "18446744073709551616",
+        "12345678901234567890.0123456789",
+        "10000",
+        "100000000",
+        "1.100001",
+        "10000.100001",
+        "0.00001234",
+        "120000.00001234",
+        "120001.00001234",
+        "NaN",
+    ];
+
+    for value in values {
+        let query = format!("'{}'::numeric", value);
+        assert_eq!(
+            value,
+            query_single_value::<Numeric, NumericString>(&query).as_str()
+        );
+    }
+}
+
+#[diesel_test_helper::test]
+#[cfg(feature = "mysql")]
+fn mysql_numeric_string_from_sql() {
+    use diesel::data_types::NumericString;
+
+    let query = "cast(141.00 as decimal(5, 2))";
+    assert_eq!(
+        "141.00",
+        query_single_value::<Numeric, NumericString>(query).as_str()
+    );
+
+    let query = "cast(12345678901234567890.0123456789 as decimal(30, 10))";
+    assert_eq!(
+        "12345678901234567890.0123456789",
+        query_single_value::<Numeric, NumericString>(query).as_str()
+    );
+}
+
+#[diesel_test_helper::test]
+#[cfg(feature = "sqlite")]
+fn sqlite_numeric_string_from_sql() {
+    use diesel::data_types::{NumericString, SqliteNumeric};
+
+    assert_eq!(
+        "42",
+        query_single_value::<Numeric, NumericString>("42").as_str()
+    );
+    assert_eq!(
+        "42.42",
+        query_single_value::<Numeric, NumericString>("42.42").as_str()
+    );
+    assert_eq!(
+        "12345678901234567890.0123456789",
+        query_single_value::<Numeric, NumericString>("'12345678901234567890.0123456789'").as_str()
+    );
+    assert_eq!(
+        SqliteNumeric::Integer(42),
+        query_single_value::<Numeric, SqliteNumeric>("42")
+    );
+    assert_eq!(
+        SqliteNumeric::Real(42.42),
+        query_single_value::<Numeric, SqliteNumeric>("42.42")
+    );
+}
+
 #[diesel_test_helper::test]
 #[cfg(feature = "postgres")]
 fn pg_uuid_from_sql() {
@@ -903,3 +903,88 @@ mod unstable_types {
         strip_nanosecond_precision
     );
 }
+
+mod roundtrip_macro {
+    use crate::schema::connection_without_transaction;
+    use diesel::backend::Backend;
+    use diesel::deserialize::{self, FromSql};
+    use diesel::serialize::{self, Output, ToSql};
+    use diesel::sql_types::*;
+    use diesel::test_helpers::{Strategy, f64_eq, roundtrip, strategies};
+    use diesel::{AsExpression, FromSqlRow};
+
+    #[cfg(feature = "mysql")]
+    use diesel::test_helpers::strategies::mysql as backend;
+    #[cfg(feature = "postgres")]
+    use diesel::test_helpers::strategies::pg as backend;
+    #[cfg(feature = "sqlite")]
+    use diesel::test_helpers::strategies::sqlite as backend;
+
+    // emails are stored with a lower case domain
+    #[derive(Debug, Clone, PartialEq, AsExpression, FromSqlRow)]
+    #[diesel(sql_type = Text)]
+    struct Email(String);
+
+    impl<DB> ToSql<Text, DB> for Email
+    where
+        DB: Backend,
+        String: ToSql<Text, DB>,
+    {
+        fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+            self.0.to_sql(out)
+        }
+    }
+
+    impl<DB> FromSql<Text, DB> for Email
+    where
+        DB: Backend,
+        String: FromSql<Text, DB>,
+    {
+        fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+            let s = String::from_sql(bytes)?;
+            let (user, domain) = s.split_once('@').ok_or("Missing @ in email")?;
+            Ok(Email(format!("{user}@{}", domain.to_lowercase())))
+        }
+    }
+
+    fn emails(domain: impl Fn(String) -> String + Clone) -> impl Strategy<Value = Email> + Clone {
+        let part = "[a-zA-Z0-9]*";
+        (part, part).prop_map(move |(user, d)| Email(format!("{user}@{}", domain(d))))
+    }
+
+    #[diesel_test_helper::test]
+    fn builtin_strategies_roundtrip() {
+        let connection = &mut connection_without_transaction();
+
+        roundtrip!(connection, Integer, strategies::any::<i32>());
+        roundtrip!(connection, Text, backend::text());
+        roundtrip!(connection, Binary, strategies::any::<Vec<u8>>(), tests = 20);
+        #[cfg(feature = "postgres")]
+        roundtrip!(
+            connection,
+            Double,
+            strategies::any::<f64>(),
+            compare = f64_eq
+        );
+        #[cfg(not(feature = "postgres"))]
+        roundtrip!(connection, Double, backend::double(), compare = f64_eq);
+        roundtrip!(connection, Date, backend::date());
+        roundtrip!(connection, Time, backend::time());
+        roundtrip!(connection, Timestamp, backend::timestamp());
+    }
+
+    #[diesel_test_helper::test]
+    fn custom_types_roundtrip() {
+        let connection = &mut connection_without_transaction();
+
+        roundtrip!(connection, Text, emails(|d| d.to_lowercase()));
+    }
+
+    #[diesel_test_helper::test]
+    #[should_panic(expected = "Value did not round trip")]
+    fn mismatches_are_reported() {
+        let connection = &mut connection_without_transaction();
+
+        roundtrip!(connection, Text, emails(|d| format!("X{d}")));
+    }
+}
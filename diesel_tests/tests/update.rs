@@ -333,6 +333,117 @@ fn upsert_with_no_changes_executes_do_nothing_owned() {
     assert_eq!(Ok(1), result);
 }
 
+#[diesel_test_helper::test]
+#[cfg(feature = "postgres")]
+fn upsert_returning_whether_rows_were_inserted() {
+    use diesel::upsert::{excluded, was_inserted};
+
+    let connection = &mut connection_with_sean_and_tess_in_users_table();
+    let result = insert_into(users::table)
+        .values(&vec![User::new(2, "Ruby"), User::new(3, "Jim")])
+        .on_conflict(users::id)
+        .do_update()
+        .set(users::name.eq(excluded(users::name)))
+        .returning((users::name, was_inserted(users::table)))
+        .get_results::<(String, bool)>(connection);
+
+    assert_eq!(
+        Ok(vec![
+            (String::from("Ruby"), false),
+            (String::from("Jim"), true)
+        ]),
+        result
+    );
+}
+
+#[diesel_test_helper::test]
+#[cfg(any(feature = "postgres", feature = "returning_clauses_for_sqlite_3_35"))]
+fn upsert_counter_returning_expressions_of_the_updated_row() {
+    use diesel::upsert::excluded;
+
+    let connection = &mut connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", connection);
+
+    let poke = |count: i32, connection: &mut TestConnection| {
+        insert_into(pokes::table)
+            .values((pokes::user_id.eq(sean.id), pokes::poke_count.eq(count)))
+            .on_conflict(pokes::user_id)
+            .do_update()
+            .set(pokes::poke_count.eq(pokes::poke_count + excluded(pokes::poke_count)))
+            .returning((pokes::user_id, pokes::poke_count, pokes::poke_count * 10))
+            .get_result::<(i32, i32, i32)>(connection)
+    };
+
+    assert_eq!(Ok((sean.id, 1, 10)), poke(1, connection));
+    assert_eq!(Ok((sean.id, 4, 40)), poke(3, connection));
+    assert_eq!(Ok((sean.id, 6, 60)), poke(2, connection));
+}
+
+#[diesel_test_helper::test]
+#[cfg(feature = "mysql")]
+fn upsert_outcome_distinguishes_inserted_and_updated_rows() {
+    let connection = &mut connection_with_sean_and_tess_in_users_table();
+    // Sean is renamed, Tess stays unchanged and Jim is inserted
+    let outcome = connection
+        .execute_upsert(
+            insert_into(users::table)
+                .values(&vec![
+                    User::new(1, "Sean"),
+                    User::new(2, "Tess"),
+                    User::new(3, "Jim"),
+                ])
+                .on_conflict(diesel::dsl::DuplicatedKeys)
+                .do_update()
+                .set(users::name.eq("Tess")),
+        )
+        .unwrap();
+
+    assert_eq!(1, outcome.inserted());
+    assert_eq!(1, outcome.updated());
+    assert_eq!(1, outcome.unchanged());
+
+    let outcome = connection
+        .execute_upsert(
+            insert_into(users::table)
+                .values(&User::new(3, "Jim"))
+                .on_conflict(diesel::dsl::DuplicatedKeys)
+                .do_update()
+                .set(users::name.eq("Jimmy")),
+        )
+        .unwrap();
+
+    assert_eq!(0, outcome.inserted());
+    assert_eq!(1, outcome.updated());
+}
+
+#[diesel_test_helper::test]
+#[cfg(feature = "mysql")]
+fn upsert_with_values_of_the_new_row() {
+    use diesel::upsert::new_row;
+
+    let connection = &mut connection_with_sean_and_tess_in_users_table();
+    let new_users = vec![User::new(2, "Ruby"), User::new(3, "Jim")];
+    let query = insert_into(users::table)
+        .values(&new_users)
+        .on_conflict(diesel::dsl::DuplicatedKeys)
+        .do_update()
+        .set(users::name.eq(new_row(users::name).concat("!")));
+
+    assert!(
+        debug_query::<diesel::mysql::Mysql, _>(&query)
+            .to_string()
+            .contains("ON DUPLICATE KEY UPDATE `name` = CONCAT(VALUES(`name`),?)")
+    );
+    query.execute(connection).unwrap();
+
+    let data = users::table
+        .select(users::name)
+        .order(users::id)
+        .load::<String>(connection);
+    let expected_data = vec!["Sean".to_string(), "Ruby!".to_string(), "Jim".to_string()];
+    assert_eq!(Ok(expected_data), data);
+}
+
 #[diesel_test_helper::test]
 #[cfg(feature = "postgres")]
 fn upsert_with_sql_literal_for_target() {
@@ -378,6 +489,62 @@ fn upsert_with_sql_literal_for_target() {
     assert_eq!(Ok(expected_data), data);
 }
 
+#[diesel_test_helper::test]
+#[cfg(feature = "postgres")]
+fn upsert_with_filter_target_for_partial_unique_index() {
+    use crate::schema::users::dsl::*;
+    use diesel::upsert::*;
+
+    let connection = &mut connection();
+    diesel::sql_query(
+        "CREATE TEMPORARY TABLE users(\
+             id SERIAL PRIMARY KEY, \
+             name TEXT NOT NULL, \
+             hair_color TEXT)",
+    )
+    .execute(connection)
+    .unwrap();
+    // Only names of users without a hair color need to be unique,
+    // similar to a soft delete column
+    diesel::sql_query("CREATE UNIQUE INDEX ON users (name) WHERE hair_color IS NULL")
+        .execute(connection)
+        .unwrap();
+    insert_sean_and_tess_into_users_table(connection);
+
+    let inserted = insert_into(users)
+        .values(&NewUser::new("Sean", None))
+        .on_conflict(name)
+        .filter_target(hair_color.is_null())
+        .do_nothing()
+        .execute(connection);
+    assert_eq!(Ok(0), inserted);
+
+    let inserted = insert_into(users)
+        .values(&NewUser::new("Sean", Some("Green")))
+        .on_conflict(name)
+        .filter_target(hair_color.is_null())
+        .do_nothing()
+        .execute(connection);
+    assert_eq!(Ok(1), inserted);
+
+    insert_into(users)
+        .values(&NewUser::new("Tess", None))
+        .on_conflict(name)
+        .filter_target(hair_color.is_null())
+        .do_update()
+        .set(name.eq("Tess (updated)"))
+        .execute(connection)
+        .unwrap();
+
+    let data = users.select((name, hair_color)).order(id).load(connection);
+    let expected_data = vec![
+        ("Sean".to_string(), None),
+        ("Tess (updated)".to_string(), None),
+        ("Sean".to_string(), Some("Green".to_string())),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
 #[diesel_test_helper::test]
 #[cfg(any(feature = "postgres", feature = "returning_clauses_for_sqlite_3_35"))]
 fn upsert_for_target_with_condition() {
@@ -451,6 +618,66 @@ fn upsert_for_target_with_condition() {
     assert_eq!(Ok(expected_data), data);
 }
 
+#[diesel_test_helper::test]
+#[cfg(any(feature = "postgres", feature = "returning_clauses_for_sqlite_3_35"))]
+fn upsert_only_updates_rows_if_the_condition_holds() {
+    use crate::schema::comments::dsl::*;
+    use diesel::upsert::*;
+
+    let connection = &mut connection();
+    diesel::sql_query(
+        "CREATE TEMPORARY TABLE comments(\
+            id INTEGER PRIMARY KEY NOT NULL, \
+            post_id INTEGER NOT NULL, \
+            text TEXT NOT NULL)",
+    )
+    .execute(connection)
+    .unwrap();
+    insert_into(comments)
+        .values(&vec![
+            Comment::new(1, 5, "Green"),
+            Comment::new(2, 5, "Blue"),
+        ])
+        .execute(connection)
+        .unwrap();
+
+    // `post_id` acts as version here, older versions must not overwrite newer ones
+    let updated: Vec<Comment> = insert_into(comments)
+        .values(&vec![
+            Comment::new(1, 4, "Red"),
+            Comment::new(2, 6, "Yellow"),
+            Comment::new(3, 1, "Black"),
+        ])
+        .on_conflict(id)
+        .do_update()
+        .set((post_id.eq(excluded(post_id)), text.eq(excluded(text))))
+        .filter(excluded(post_id).gt(post_id))
+        .get_results(connection)
+        .unwrap();
+    assert_eq!(
+        vec![Comment::new(2, 6, "Yellow"), Comment::new(3, 1, "Black")],
+        updated
+    );
+
+    let updated = insert_into(comments)
+        .values(&Comment::new(1, 4, "Red"))
+        .on_conflict(id)
+        .do_update()
+        .set(text.eq(excluded(text)))
+        .filter(excluded(post_id).gt(post_id))
+        .or_filter(text.eq("Green"))
+        .execute(connection);
+    assert_eq!(Ok(1), updated);
+
+    let data = comments.select((post_id, text)).order(id).load(connection);
+    let expected_data = vec![
+        (5, "Red".to_string()),
+        (6, "Yellow".to_string()),
+        (1, "Black".to_string()),
+    ];
+    assert_eq!(Ok(expected_data), data);
+}
+
 #[diesel_test_helper::test]
 #[cfg(feature = "postgres")]
 fn upsert_with_sql_literal_for_target_with_condition() {
@@ -788,3 +1015,90 @@ fn returning_subselect_and_old_in_update() {
     assert_eq!(Some("First Post".to_string()), post_by_old_id);
     assert_eq!("Renamed", now_boxed);
 }
+
+#[diesel_test_helper::test]
+fn update_with_from_clause_filters_on_other_table() {
+    let connection = &mut connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", connection);
+    let tess = find_user_by_name("Tess", connection);
+    insert_into(posts::table)
+        .values(&vec![
+            sean.new_post("Hello", None),
+            sean.new_post("World", None),
+            tess.new_post("Hello 2", None),
+        ])
+        .execute(connection)
+        .unwrap();
+
+    let updated_rows = update(posts::table)
+        .from(users::table)
+        .filter(posts::user_id.eq(users::id))
+        .filter(users::name.eq("Sean"))
+        .set(posts::body.eq("Written by Sean"))
+        .execute(connection)
+        .unwrap();
+    assert_eq!(2, updated_rows);
+
+    let bodies = posts::table
+        .select((posts::title, posts::body))
+        .order(posts::id)
+        .load::<(String, Option<String>)>(connection)
+        .unwrap();
+    let expected = vec![
+        ("Hello".to_string(), Some("Written by Sean".to_string())),
+        ("World".to_string(), Some("Written by Sean".to_string())),
+        ("Hello 2".to_string(), None),
+    ];
+    assert_eq!(expected, bodies);
+}
+
+#[diesel_test_helper::test]
+fn update_with_from_clause_sets_columns_with_the_same_name() {
+    let connection = &mut connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", connection);
+    insert_into(posts::table)
+        .values(&sean.new_post("Hello", None))
+        .execute(connection)
+        .unwrap();
+
+    let post_id = posts::table
+        .select(posts::id)
+        .first::<i32>(connection)
+        .unwrap();
+
+    // both tables contain an `id` column, so the assignment
+    // target must not be ambiguous
+    let updated_rows = update(posts::table)
+        .from(users::table)
+        .filter(posts::user_id.eq(users::id))
+        .set(posts::id.eq(posts::id + 10))
+        .execute(connection)
+        .unwrap();
+    assert_eq!(1, updated_rows);
+
+    let ids = posts::table.select(posts::id).load::<i32>(connection);
+    assert_eq!(Ok(vec![post_id + 10]), ids);
+}
+
+#[diesel_test_helper::test]
+#[cfg(not(any(
+    all(feature = "sqlite", not(feature = "returning_clauses_for_sqlite_3_35")),
+    feature = "mysql"
+)))]
+fn update_with_from_clause_and_returning_clause() {
+    let connection = &mut connection_with_sean_and_tess_in_users_table();
+    let tess = find_user_by_name("Tess", connection);
+    insert_into(posts::table)
+        .values(&tess.new_post("Hello", None))
+        .execute(connection)
+        .unwrap();
+
+    let titles = update(posts::table)
+        .from(users::table)
+        .filter(posts::user_id.eq(users::id))
+        .filter(users::name.eq("Tess"))
+        .set(posts::title.eq("Hello from Tess"))
+        .returning(posts::title)
+        .load::<String>(connection);
+    assert_eq!(Ok(vec!["Hello from Tess".to_string()]), titles);
+}
@@ -0,0 +1,130 @@
+use crate::schema::*;
+use diesel::dsl::{values, with};
+use diesel::sql_types::{Integer, Text};
+use diesel::*;
+
+diesel::view! {
+    new_names {
+        id -> Integer,
+        name -> Text,
+    }
+}
+
+diesel::allow_tables_to_appear_in_same_query!(new_names, users);
+
+#[diesel_test_helper::test]
+fn load_values_list() {
+    let conn = &mut connection();
+
+    let tuple_rows = values::<(Integer, Text), _>(((1, "a"), (2, "b"))).load::<(i32, String)>(conn);
+    let vec_rows =
+        values::<(Integer, Text), _>(vec![(1, "a"), (2, "b")]).load::<(i32, String)>(conn);
+
+    let expected = vec![(1, String::from("a")), (2, String::from("b"))];
+    assert_eq!(Ok(expected.clone()), tuple_rows);
+    assert_eq!(Ok(expected), vec_rows);
+}
+
+#[diesel_test_helper::test]
+fn values_list_as_subselect() {
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+
+    let data = users::table
+        .filter(users::name.eq_any(values(vec![("Tess",), ("Jim",)])))
+        .select(users::name)
+        .load::<String>(conn);
+
+    assert_eq!(Ok(vec![String::from("Tess")]), data);
+}
+
+#[diesel_test_helper::test]
+fn join_against_values_list() {
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+    let sean = find_user_by_name("Sean", conn);
+    let tess = find_user_by_name("Tess", conn);
+
+    let data = with(
+        new_names::view,
+        values([(sean.id, "Sean Griffin"), (tess.id, "Tess Griffin")]),
+    )
+    .query(
+        users::table
+            .inner_join(new_names::view.on(new_names::id.eq(users::id)))
+            .select((users::name, new_names::name))
+            .order(users::id),
+    )
+    .load::<(String, String)>(conn);
+
+    let expected = vec![
+        (String::from("Sean"), String::from("Sean Griffin")),
+        (String::from("Tess"), String::from("Tess Griffin")),
+    ];
+    assert_eq!(Ok(expected), data);
+}
+
+#[diesel_test_helper::test]
+fn update_from_values_list() {
+    let conn = &mut connection_with_sean_and_tess_in_users_table();
+    let tess = find_user_by_name("Tess", conn);
+
+    let updated_rows = with(new_names::view, values(vec![(tess.id, "Tess Griffin")]))
+        .statement(
+            update(users::table)
+                .filter(users::id.eq_any(new_names::view.select(new_names::id)))
+                .set(
+                    users::name.eq(new_names::view
+                        .filter(new_names::id.eq(users::id))
+                        .select(new_names::name)
+                        .single_value()
+                        .assume_not_null()),
+                ),
+        )
+        .execute(conn);
+    assert_eq!(Ok(1), updated_rows);
+
+    let names = users::table
+        .select(users::name)
+        .order(users::id)
+        .load::<String>(conn);
+    assert_eq!(
+        Ok(vec![String::from("Sean"), String::from("Tess Griffin")]),
+        names
+    );
+}
+
+#[diesel_test_helper::test]
+fn empty_values_list_is_an_error() {
+    let conn = &mut connection();
+
+    let result = values::<(Integer,), _>(Vec::<(i32,)>::new()).load::<(i32,)>(conn);
+
+    assert!(matches!(
+        result,
+        Err(diesel::result::Error::QueryBuilderError(_))
+    ));
+}
+
+#[diesel_test_helper::test]
+#[cfg(feature = "postgres")]
+fn values_list_generates_expected_sql() {
+    let query = with(new_names::view, values(((1, "Sean"), (2, "Tess"))))
+        .query(new_names::view.select(new_names::name));
+
+    assert_eq!(
+        "WITH \"new_names\" (\"id\", \"name\") AS (VALUES ($1, $2), ($3, $4)) \
+         SELECT \"new_names\".\"name\" FROM \"new_names\" \
+         -- binds: [1, \"Sean\", 2, \"Tess\"]",
+        debug_query::<diesel::pg::Pg, _>(&query).to_string()
+    );
+}
+
+#[diesel_test_helper::test]
+#[cfg(feature = "mysql")]
+fn values_list_generates_expected_sql() {
+    let query = values::<(Integer, Text), _>(vec![(1, "Sean"), (2, "Tess")]);
+
+    assert_eq!(
+        "VALUES ROW(?, ?), ROW(?, ?) -- binds: [1, \"Sean\", 2, \"Tess\"]",
+        debug_query::<diesel::mysql::Mysql, _>(&query).to_string()
+    );
+}
@@ -30,15 +30,7 @@ fn window_count_star() {
 #[diesel_test_helper::test]
 fn partition_by() {
     let mut conn = connection_with_sean_and_tess_in_users_table();
-
-    diesel::insert_into(posts::table)
-        .values([
-            (posts::title.eq("Post 1"), posts::user_id.eq(1)),
-            (posts::title.eq("Post 2"), posts::user_id.eq(1)),
-            (posts::title.eq("Post 3"), posts::user_id.eq(2)),
-        ])
-        .execute(&mut conn)
-        .unwrap();
+    insert_posts_for_sean_and_tess(&mut conn);
 
     let res = users::table
         .inner_join(posts::table)
@@ -57,10 +49,6 @@ fn partition_by() {
     );
 }
 
-// that's not meaningful to test with
-// only `count` as test function for now
-// TODO: change the used function here
-// as soon as we have `lead`/`lag`/`first`
 #[diesel_test_helper::test]
 fn order_smoke_test() {
     let mut conn = connection_with_sean_and_tess_in_users_table();
@@ -74,6 +62,98 @@ fn order_smoke_test() {
     assert_eq!(res, vec![1, 2]);
 }
 
+fn insert_posts_for_sean_and_tess(conn: &mut crate::schema::TestConnection) {
+    diesel::insert_into(posts::table)
+        .values([
+            (posts::title.eq("Post 1"), posts::user_id.eq(1)),
+            (posts::title.eq("Post 2"), posts::user_id.eq(1)),
+            (posts::title.eq("Post 3"), posts::user_id.eq(2)),
+        ])
+        .execute(conn)
+        .unwrap();
+}
+
+#[diesel_test_helper::test]
+fn ranking_functions() {
+    let mut conn = connection_with_sean_and_tess_in_users_table();
+    insert_posts_for_sean_and_tess(&mut conn);
+
+    let res = posts::table
+        .select((
+            posts::title,
+            dsl::row_number().window_order(posts::title),
+            dsl::rank().window_order(posts::user_id),
+            dsl::dense_rank().window_order(posts::user_id),
+        ))
+        .order_by(posts::title)
+        .load::<(String, i64, i64, i64)>(&mut conn)
+        .unwrap();
+
+    assert_eq!(
+        res,
+        vec![
+            (String::from("Post 1"), 1, 1, 1),
+            (String::from("Post 2"), 2, 1, 1),
+            (String::from("Post 3"), 3, 3, 2),
+        ]
+    );
+}
+
+#[diesel_test_helper::test]
+fn lag_and_lead_within_partition() {
+    let mut conn = connection_with_sean_and_tess_in_users_table();
+    insert_posts_for_sean_and_tess(&mut conn);
+
+    let res = posts::table
+        .select((
+            posts::title,
+            dsl::lag(posts::title)
+                .partition_by(posts::user_id)
+                .window_order(posts::title),
+            dsl::lead(posts::title)
+                .partition_by(posts::user_id)
+                .window_order(posts::title),
+        ))
+        .order_by(posts::title)
+        .load::<(String, Option<String>, Option<String>)>(&mut conn)
+        .unwrap();
+
+    assert_eq!(
+        res,
+        vec![
+            (String::from("Post 1"), None, Some(String::from("Post 2"))),
+            (String::from("Post 2"), Some(String::from("Post 1")), None),
+            (String::from("Post 3"), None, None),
+        ]
+    );
+}
+
+#[diesel_test_helper::test]
+fn running_sum_with_frame() {
+    let mut conn = connection_with_sean_and_tess_in_users_table();
+    insert_posts_for_sean_and_tess(&mut conn);
+
+    let res = posts::table
+        .select((
+            posts::title,
+            dsl::sum(posts::user_id)
+                .window_order(posts::title)
+                .frame_by(dsl::frame::Rows.frame_start_with(dsl::frame::UnboundedPreceding)),
+        ))
+        .order_by(posts::title)
+        .load::<(String, Option<i64>)>(&mut conn)
+        .unwrap();
+
+    assert_eq!(
+        res,
+        vec![
+            (String::from("Post 1"), Some(1)),
+            (String::from("Post 2"), Some(2)),
+            (String::from("Post 3"), Some(4)),
+        ]
+    );
+}
+
 #[diesel_test_helper::test]
 fn frame_no_preceding() {
     let mut conn = connection_with_sean_and_tess_in_users_table();
@@ -2,7 +2,6 @@ mod auth;
 mod cli;
 mod comment;
 mod editor;
-mod pagination;
 mod post;
 #[rustfmt::skip]
 mod schema;
@@ -12,11 +11,11 @@ mod test_helpers;
 
 use clap::Parser;
 use diesel::prelude::*;
+use diesel::query_dsl::PaginateDsl;
 
 use std::error::Error;
 
 use self::cli::*;
-use self::pagination::*;
 use self::post::*;
 use self::schema::*;
 
@@ -36,20 +35,15 @@ fn run_cli(database_url: &str, cli: Cli) -> Result<(), Box<dyn Error>> {
             use auth::User;
             use comment::*;
 
-            let mut query = posts::table
+            let per_page = per_page_or_default(per_page);
+            let (posts_with_user, total) = posts::table
                 .order(posts::published_at.desc())
                 .filter(posts::published_at.is_not_null())
                 .inner_join(users::table)
                 .select((posts::all_columns, (users::id, users::username)))
-                .paginate(page);
-
-            if let Some(per_page) = per_page {
-                use std::cmp::min;
-                query = query.per_page(min(per_page, 25));
-            }
-
-            let (posts_with_user, total_pages) =
-                query.load_and_count_pages::<(Post, User)>(conn)?;
+                .paginate(page, per_page)
+                .load_and_count::<(Post, User), _>(conn)?;
+            let total_pages = total_pages(total, per_page);
             let (posts, post_users): (Vec<_>, Vec<_>) = posts_with_user.into_iter().unzip();
 
             let comments = Comment::belonging_to(&posts)
@@ -132,19 +126,14 @@ fn run_cli(database_url: &str, cli: Cli) -> Result<(), Box<dyn Error>> {
 
             let user = current_user(conn)?;
 
-            let mut query = Comment::belonging_to(&user)
+            let per_page = per_page_or_default(per_page);
+            let (comments_and_post_title, total) = Comment::belonging_to(&user)
                 .order(comments::created_at.desc())
                 .inner_join(posts::table)
                 .select((comments::all_columns, posts::title))
-                .paginate(page);
-
-            if let Some(per_page) = per_page {
-                use std::cmp::min;
-                query = query.per_page(min(per_page, 25));
-            }
-
-            let (comments_and_post_title, total_pages) =
-                query.load_and_count_pages::<(Comment, String)>(conn)?;
+                .paginate(page, per_page)
+                .load_and_count::<(Comment, String), _>(conn)?;
+            let total_pages = total_pages(total, per_page);
             comment::render(&comments_and_post_title);
             println!("Page {page} of {total_pages}");
         }
@@ -155,6 +144,16 @@ fn run_cli(database_url: &str, cli: Cli) -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+const DEFAULT_PER_PAGE: i64 = 10;
+
+fn per_page_or_default(per_page: Option<i64>) -> i64 {
+    per_page.map_or(DEFAULT_PER_PAGE, |per_page| per_page.min(25))
+}
+
+fn total_pages(total: i64, per_page: i64) -> i64 {
+    (total + per_page - 1) / per_page.max(1)
+}
+
 fn current_user(conn: &mut PgConnection) -> Result<auth::User, Box<dyn Error>> {
     match auth::current_user_from_env(conn) {
         Ok(Some(user)) => Ok(user),
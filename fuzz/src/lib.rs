@@ -0,0 +1,392 @@
+//! Fuzz targets for diesel
+//!
+//! Each public function in this crate is the body of one `cargo-fuzz`
+//! target in `fuzz_targets/`. The functions are kept here so that they
+//! can be shared between targets and reused to reproduce a crash without
+//! libFuzzer. Run them via `cargo xtask fuzz`.
+//!
+//! The targets exercise code paths that deal with untrusted input,
+//! which is everything received from the database server, and check
+//! that values serialized by diesel are deserialized to the same value.
+//! Decoding arbitrary bytes is allowed to fail, but must never panic.
+
+use std::num::NonZeroU32;
+
+use bigdecimal::BigDecimal;
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime};
+use diesel::backend::Backend;
+use diesel::data_types::{NumericString, PgNumeric};
+use diesel::deserialize::{FromSql, FromSqlRow};
+use diesel::mysql::{Mysql, MysqlQueryBuilder, MysqlType, MysqlValue};
+use diesel::pg::{Pg, PgMetadataLookup, PgQueryBuilder, PgTypeMetadata, PgValue};
+use diesel::prelude::*;
+use diesel::query_builder::bind_collector::RawBytesBindCollector;
+use diesel::query_builder::{BindCollector, QueryBuilder};
+use diesel::serialize::ToSql;
+use diesel::sql_types::{self, HasSqlType};
+use diesel::sqlite::{Sqlite, SqliteConnection, SqliteQueryBuilder};
+
+/// Serializes values built from the input for PostgreSQL and MySQL and
+/// checks that deserializing the resulting bind values yields the same value
+pub fn bind_serialization(data: &[u8]) {
+    let bool = data.first().is_some_and(|b| b & 1 == 1);
+    let i16 = i16::from_ne_bytes(fixed_bytes(data));
+    let i32 = i32::from_ne_bytes(fixed_bytes(data));
+    let i64 = i64::from_ne_bytes(fixed_bytes(data));
+    let f32 = f32::from_ne_bytes(fixed_bytes(data));
+    let f64 = f64::from_ne_bytes(fixed_bytes(data));
+    let binary = data.to_vec();
+
+    assert_eq!(pg_round_trip::<sql_types::Bool, _>(&bool), Some(bool));
+    assert_eq!(pg_round_trip::<sql_types::SmallInt, _>(&i16), Some(i16));
+    assert_eq!(pg_round_trip::<sql_types::Integer, _>(&i32), Some(i32));
+    assert_eq!(pg_round_trip::<sql_types::BigInt, _>(&i64), Some(i64));
+    assert_eq!(
+        pg_round_trip::<sql_types::Float, _>(&f32).map(f32::to_bits),
+        Some(f32.to_bits())
+    );
+    assert_eq!(
+        pg_round_trip::<sql_types::Double, _>(&f64).map(f64::to_bits),
+        Some(f64.to_bits())
+    );
+    assert_eq!(
+        pg_round_trip::<sql_types::Binary, _>(&binary).as_ref(),
+        Some(&binary)
+    );
+
+    assert_eq!(mysql_round_trip::<sql_types::Bool, _>(&bool), Some(bool));
+    assert_eq!(mysql_round_trip::<sql_types::SmallInt, _>(&i16), Some(i16));
+    assert_eq!(mysql_round_trip::<sql_types::Integer, _>(&i32), Some(i32));
+    assert_eq!(mysql_round_trip::<sql_types::BigInt, _>(&i64), Some(i64));
+    assert_eq!(
+        mysql_round_trip::<sql_types::Float, _>(&f32).map(f32::to_bits),
+        Some(f32.to_bits())
+    );
+    assert_eq!(
+        mysql_round_trip::<sql_types::Double, _>(&f64).map(f64::to_bits),
+        Some(f64.to_bits())
+    );
+    assert_eq!(
+        mysql_round_trip::<sql_types::Binary, _>(&binary).as_ref(),
+        Some(&binary)
+    );
+
+    if let Some(timestamp) = DateTime::from_timestamp_micros(i64).map(|t| t.naive_utc()) {
+        if let Some(value) = pg_round_trip::<sql_types::Timestamp, NaiveDateTime>(&timestamp) {
+            assert_eq!(value, timestamp);
+        }
+        if let Some(value) = mysql_round_trip::<sql_types::Datetime, NaiveDateTime>(&timestamp) {
+            assert_eq!(value, timestamp);
+        }
+    }
+
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let text = text.to_owned();
+    assert_eq!(
+        pg_round_trip::<sql_types::Text, _>(&text).as_ref(),
+        Some(&text)
+    );
+    assert_eq!(
+        mysql_round_trip::<sql_types::Text, _>(&text).as_ref(),
+        Some(&text)
+    );
+
+    // Only plain decimal numbers are checked here, as `BigDecimal`
+    // also accepts exponents which could result in huge allocations
+    if let Ok(numeric) = text.parse::<NumericString>()
+        && !numeric.is_nan()
+    {
+        let expected = numeric
+            .as_str()
+            .parse::<BigDecimal>()
+            .expect("A valid `NumericString` is a valid `BigDecimal`");
+        for value in [
+            pg_round_trip::<sql_types::Numeric, _>(&numeric),
+            mysql_round_trip::<sql_types::Numeric, _>(&numeric),
+        ]
+        .into_iter()
+        .flatten()
+        {
+            assert_eq!(
+                value.as_str().parse::<BigDecimal>().ok(),
+                Some(expected.clone())
+            );
+        }
+        if let Some(value) = pg_round_trip::<sql_types::Numeric, _>(&expected) {
+            assert_eq!(value, expected);
+        }
+        if let Some(value) = mysql_round_trip::<sql_types::Numeric, _>(&expected) {
+            assert_eq!(value, expected);
+        }
+    }
+}
+
+/// Decodes the input as binary PostgreSQL `NUMERIC` value and checks that
+/// the conversions into the different numeric representations agree
+pub fn pg_numeric(data: &[u8]) {
+    let oid = NonZeroU32::new(1700).expect("Not zero");
+    let Ok(numeric) =
+        <PgNumeric as FromSql<sql_types::Numeric, Pg>>::from_sql(PgValue::new(data, &oid))
+    else {
+        return;
+    };
+    let big_decimal = BigDecimal::try_from(&numeric);
+    let Ok(string) = NumericString::try_from(&numeric) else {
+        return;
+    };
+    if string.is_nan() {
+        assert!(matches!(numeric, PgNumeric::NaN));
+        return;
+    }
+    let parsed = string
+        .as_str()
+        .parse::<BigDecimal>()
+        .expect("The string representation is a valid `BigDecimal`");
+    if let Ok(big_decimal) = big_decimal {
+        assert_eq!(big_decimal, parsed);
+    }
+
+    // Converting the normalized string representation back must be lossless
+    // if the value is still representable with 16 bit weights
+    let Ok(numeric) = PgNumeric::try_from(&string) else {
+        return;
+    };
+    let round_tripped =
+        NumericString::try_from(&numeric).expect("A valid numeric converts to a `NumericString`");
+    assert_eq!(
+        round_tripped.as_str().parse::<BigDecimal>().ok(),
+        Some(parsed)
+    );
+}
+
+/// Decodes the input as raw MySQL row value of the type selected by the
+/// first byte
+///
+/// This mirrors how values received from the server are interpreted.
+/// Any result is fine as long as decoding does not panic.
+pub fn mysql_value(data: &[u8]) {
+    const TYPES: &[MysqlType] = &[
+        MysqlType::Tiny,
+        MysqlType::UnsignedTiny,
+        MysqlType::Short,
+        MysqlType::UnsignedShort,
+        MysqlType::Long,
+        MysqlType::UnsignedLong,
+        MysqlType::LongLong,
+        MysqlType::UnsignedLongLong,
+        MysqlType::Float,
+        MysqlType::Double,
+        MysqlType::Numeric,
+        MysqlType::Time,
+        MysqlType::Date,
+        MysqlType::DateTime,
+        MysqlType::Timestamp,
+        MysqlType::String,
+        MysqlType::Blob,
+        MysqlType::Bit,
+        MysqlType::Set,
+        MysqlType::Enum,
+    ];
+    let Some((tpe, raw)) = data.split_first() else {
+        return;
+    };
+    let tpe = TYPES[usize::from(*tpe) % TYPES.len()];
+    let value = || MysqlValue::new(raw, tpe);
+
+    let _ = value().numeric_value();
+    let _ = <bool as FromSql<sql_types::Bool, Mysql>>::from_sql(value());
+    let _ = <i8 as FromSql<sql_types::TinyInt, Mysql>>::from_sql(value());
+    let _ = <i16 as FromSql<sql_types::SmallInt, Mysql>>::from_sql(value());
+    let _ = <i32 as FromSql<sql_types::Integer, Mysql>>::from_sql(value());
+    let _ = <i64 as FromSql<sql_types::BigInt, Mysql>>::from_sql(value());
+    let _ = <u8 as FromSql<sql_types::Unsigned<sql_types::TinyInt>, Mysql>>::from_sql(value());
+    let _ = <u16 as FromSql<sql_types::Unsigned<sql_types::SmallInt>, Mysql>>::from_sql(value());
+    let _ = <u32 as FromSql<sql_types::Unsigned<sql_types::Integer>, Mysql>>::from_sql(value());
+    let _ = <u64 as FromSql<sql_types::Unsigned<sql_types::BigInt>, Mysql>>::from_sql(value());
+    let _ = <f32 as FromSql<sql_types::Float, Mysql>>::from_sql(value());
+    let _ = <f64 as FromSql<sql_types::Double, Mysql>>::from_sql(value());
+    let _ = <BigDecimal as FromSql<sql_types::Numeric, Mysql>>::from_sql(value());
+    let _ = <NumericString as FromSql<sql_types::Numeric, Mysql>>::from_sql(value());
+    let _ = <String as FromSql<sql_types::Text, Mysql>>::from_sql(value());
+    let _ = <Vec<u8> as FromSql<sql_types::Binary, Mysql>>::from_sql(value());
+    let _ = <NaiveDateTime as FromSql<sql_types::Datetime, Mysql>>::from_sql(value());
+    let _ = <NaiveDate as FromSql<sql_types::Date, Mysql>>::from_sql(value());
+    let _ = <NaiveTime as FromSql<sql_types::Time, Mysql>>::from_sql(value());
+    let _ = <serde_json::Value as FromSql<sql_types::Json, Mysql>>::from_sql(value());
+}
+
+/// Binds the input as blob and as text value in SQLite and loads the
+/// value back as every supported rust type
+///
+/// SQLite converts values between storage classes on demand, so this
+/// covers the conversion from any storage class to any rust type.
+/// Any result is fine as long as loading the value does not panic.
+pub fn sqlite_value(data: &[u8]) {
+    thread_local! {
+        static CONNECTION: std::cell::RefCell<SqliteConnection> = std::cell::RefCell::new(
+            SqliteConnection::establish(":memory:").expect("Failed to open an in-memory database"),
+        );
+    }
+
+    CONNECTION.with_borrow_mut(|conn| {
+        let text = String::from_utf8_lossy(data);
+        load_sqlite_value::<sql_types::Bool>(conn, data, &text);
+        load_sqlite_value::<sql_types::SmallInt>(conn, data, &text);
+        load_sqlite_value::<sql_types::Integer>(conn, data, &text);
+        load_sqlite_value::<sql_types::BigInt>(conn, data, &text);
+        load_sqlite_value::<sql_types::Float>(conn, data, &text);
+        load_sqlite_value::<sql_types::Double>(conn, data, &text);
+        load_sqlite_value::<sql_types::Numeric>(conn, data, &text);
+        load_sqlite_value::<sql_types::Text>(conn, data, &text);
+        load_sqlite_value::<sql_types::Binary>(conn, data, &text);
+        load_sqlite_value::<sql_types::Date>(conn, data, &text);
+        load_sqlite_value::<sql_types::Time>(conn, data, &text);
+        load_sqlite_value::<sql_types::Timestamp>(conn, data, &text);
+        load_sqlite_value::<sql_types::TimestamptzSqlite>(conn, data, &text);
+        load_sqlite_value::<sql_types::Json>(conn, data, &text);
+    });
+}
+
+/// Quotes the input as identifier for all backends and checks that
+/// the quoted identifier can be unambiguously unquoted again
+pub fn identifier_escaping(data: &[u8]) {
+    let Ok(identifier) = std::str::from_utf8(data) else {
+        return;
+    };
+    check_identifier::<Pg>(PgQueryBuilder::new(), identifier, '"');
+    check_identifier::<Mysql>(MysqlQueryBuilder::new(), identifier, '`');
+    check_identifier::<Sqlite>(SqliteQueryBuilder::new(), identifier, '`');
+}
+
+fn fixed_bytes<const N: usize>(data: &[u8]) -> [u8; N] {
+    let mut out = [0; N];
+    let len = N.min(data.len());
+    out[..len].copy_from_slice(&data[..len]);
+    out
+}
+
+/// Built-in types never look up their type metadata
+struct NoTypeLookup;
+
+impl PgMetadataLookup for NoTypeLookup {
+    fn lookup_type(&mut self, type_name: &str, _schema: Option<&str>) -> PgTypeMetadata {
+        panic!("Unexpected type lookup for `{type_name}`")
+    }
+}
+
+/// Returns `None` if the value cannot be serialized, serializing a value
+/// is allowed to fail, deserializing a serialized value is not
+fn pg_round_trip<ST, T>(value: &T) -> Option<T>
+where
+    Pg: HasSqlType<ST>,
+    T: ToSql<ST, Pg> + FromSql<ST, Pg>,
+{
+    let mut collector = RawBytesBindCollector::<Pg>::new();
+    collector
+        .push_bound_value::<ST, T>(value, &mut NoTypeLookup)
+        .ok()?;
+    let oid = collector.metadata[0]
+        .oid()
+        .ok()
+        .and_then(NonZeroU32::new)
+        .expect("Built-in types have a static oid");
+    let bytes = collector.binds[0]
+        .as_deref()
+        .expect("Values are not serialized as `NULL`");
+    Some(T::from_sql(PgValue::new(bytes, &oid)).expect("Failed to deserialize a serialized value"))
+}
+
+/// See [`pg_round_trip`]
+fn mysql_round_trip<ST, T>(value: &T) -> Option<T>
+where
+    Mysql: HasSqlType<ST>,
+    T: ToSql<ST, Mysql> + FromSql<ST, Mysql>,
+{
+    let mut collector = RawBytesBindCollector::<Mysql>::new();
+    collector.push_bound_value::<ST, T>(value, &mut ()).ok()?;
+    let bytes = collector.binds[0]
+        .as_deref()
+        .expect("Values are not serialized as `NULL`");
+    Some(
+        T::from_sql(MysqlValue::new(bytes, collector.metadata[0]))
+            .expect("Failed to deserialize a serialized value"),
+    )
+}
+
+fn load_sqlite_value<ST>(conn: &mut SqliteConnection, data: &[u8], text: &str)
+where
+    ST: LoadAs + sql_types::SingleValue,
+    Sqlite: HasSqlType<ST>,
+{
+    let blob = diesel::dsl::sql::<ST>("SELECT ")
+        .bind::<sql_types::Binary, _>(data)
+        .get_result::<ST::Value>(conn);
+    let text = diesel::dsl::sql::<ST>("SELECT ")
+        .bind::<sql_types::Text, _>(text)
+        .get_result::<ST::Value>(conn);
+    for result in [blob, text] {
+        match result {
+            Ok(_) | Err(diesel::result::Error::DeserializationError(_)) => {}
+            Err(e) => panic!("Failed to load a value: {e}"),
+        }
+    }
+}
+
+/// The rust type a value of the given sql type is loaded as
+trait LoadAs: Sized {
+    type Value: FromSqlRow<Self, Sqlite> + 'static;
+}
+
+macro_rules! load_as {
+    ($($sql_type:ty => $rust_type:ty,)*) => {
+        $(
+            impl LoadAs for $sql_type {
+                type Value = $rust_type;
+            }
+        )*
+    };
+}
+
+load_as! {
+    sql_types::Bool => bool,
+    sql_types::SmallInt => i16,
+    sql_types::Integer => i32,
+    sql_types::BigInt => i64,
+    sql_types::Float => f32,
+    sql_types::Double => f64,
+    sql_types::Numeric => BigDecimal,
+    sql_types::Text => String,
+    sql_types::Binary => Vec<u8>,
+    sql_types::Date => NaiveDate,
+    sql_types::Time => NaiveTime,
+    sql_types::Timestamp => NaiveDateTime,
+    sql_types::TimestamptzSqlite => DateTime<chrono::Utc>,
+    sql_types::Json => serde_json::Value,
+}
+
+fn check_identifier<DB>(mut query_builder: DB::QueryBuilder, identifier: &str, quote: char)
+where
+    DB: Backend,
+{
+    query_builder
+        .push_identifier(identifier)
+        .expect("Quoting an identifier does not fail");
+    let quoted = query_builder.finish();
+    let inner = quoted
+        .strip_prefix(quote)
+        .and_then(|q| q.strip_suffix(quote))
+        .expect("The identifier is surrounded by quotes");
+    // Every quote inside of the identifier must be escaped by doubling it,
+    // otherwise the identifier could be terminated early
+    let mut unescaped = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c == quote {
+            assert_eq!(chars.next(), Some(quote), "Unescaped quote in `{quoted}`");
+        }
+        unescaped.push(c);
+    }
+    assert_eq!(unescaped, identifier);
+}
@@ -0,0 +1,257 @@
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use cargo_metadata::{Metadata, MetadataCommand};
+
+use crate::Backend;
+
+/// Targets we provide prebuilt diesel_cli binaries for
+const SUPPORTED_TARGETS: &[&str] = &[
+    "aarch64-apple-darwin",
+    "aarch64-unknown-linux-gnu",
+    "x86_64-apple-darwin",
+    "x86_64-unknown-linux-gnu",
+    "x86_64-pc-windows-msvc",
+];
+
+/// Files shipped next to the binary in each archive
+const INCLUDED_FILES: &[&str] = &["diesel_cli/README.md", "LICENSE-MIT", "LICENSE-APACHE"];
+
+#[derive(Debug, clap::Args)]
+pub struct DistArgs {
+    /// Target triple to build the binaries for
+    #[clap(long)]
+    target: String,
+    /// Only build the binary for a specific backend
+    ///
+    /// By default a binary supporting all backends is built
+    /// in addition to one binary for each backend
+    #[clap(long, default_value_t = Backend::All)]
+    backend: Backend,
+    /// Directory the archives, checksums and the release manifest are written to
+    ///
+    /// Defaults to `target/distrib`
+    #[clap(long = "out-dir")]
+    out_dir: Option<PathBuf>,
+    /// Build the binaries via `cargo auditable` to embed the dependency
+    /// tree, as done for the binaries released via `cargo dist`
+    #[clap(long)]
+    auditable: bool,
+}
+
+/// A set of diesel_cli features a binary is built with
+struct Variant {
+    name: &'static str,
+    features: &'static [&'static str],
+}
+
+/// All variants use the bundled database client libraries so
+/// that the binaries work without installing them
+const VARIANTS: &[Variant] = &[
+    Variant {
+        name: "all",
+        features: &["postgres-bundled", "sqlite-bundled", "mysql-bundled"],
+    },
+    Variant {
+        name: "postgres",
+        features: &["postgres-bundled"],
+    },
+    Variant {
+        name: "sqlite",
+        features: &["sqlite-bundled"],
+    },
+    Variant {
+        name: "mysql",
+        features: &["mysql-bundled"],
+    },
+];
+
+struct Artifact {
+    variant: &'static Variant,
+    archive: String,
+    sha256: String,
+}
+
+impl DistArgs {
+    pub(crate) fn run(&self) {
+        if !SUPPORTED_TARGETS.contains(&self.target.as_str()) {
+            eprintln!(
+                "Unsupported target `{}`, supported targets are: {}",
+                self.target,
+                SUPPORTED_TARGETS.join(", ")
+            );
+            std::process::exit(1);
+        }
+        let metadata = MetadataCommand::default().exec().unwrap();
+        let version = metadata
+            .packages
+            .iter()
+            .find_map(|p| (p.name == "diesel_cli").then_some(&p.version))
+            .expect("The workspace contains diesel_cli")
+            .to_string();
+        let out_dir = self
+            .out_dir
+            .clone()
+            .unwrap_or_else(|| metadata.target_directory.join("distrib").into());
+        std::fs::create_dir_all(&out_dir).unwrap();
+
+        let artifacts = VARIANTS
+            .iter()
+            .filter(|v| self.should_build(v))
+            .map(|variant| self.build_variant(&metadata, variant, &version, &out_dir))
+            .collect::<Vec<_>>();
+
+        let manifest_path = out_dir.join(format!("diesel_cli-{version}-{}.json", self.target));
+        std::fs::write(
+            &manifest_path,
+            serde_json::to_string_pretty(&self.manifest(&version, &artifacts)).unwrap(),
+        )
+        .unwrap();
+        println!("Wrote release manifest to `{}`", manifest_path.display());
+    }
+
+    fn should_build(&self, variant: &Variant) -> bool {
+        matches!(self.backend, Backend::All) || self.backend.to_string() == variant.name
+    }
+
+    fn build_variant(
+        &self,
+        metadata: &Metadata,
+        variant: &'static Variant,
+        version: &str,
+        out_dir: &Path,
+    ) -> Artifact {
+        let mut command = Command::new("cargo");
+        if self.auditable {
+            command.arg("auditable");
+        }
+        command
+            .args([
+                "build",
+                "--profile",
+                "dist",
+                // only strip the archives built here, the `cargo dist`
+                // release keeps using the profile as configured
+                "--config",
+                "profile.dist.strip=\"symbols\"",
+                "--locked",
+                "-p",
+                "diesel_cli",
+                "--no-default-features",
+                "--target",
+                &self.target,
+                "--features",
+                &variant.features.join(","),
+            ])
+            .current_dir(&metadata.workspace_root);
+        run(command, &format!("build the `{}` binary", variant.name));
+
+        let is_windows = self.target.contains("windows");
+        let binary_name = if is_windows { "diesel.exe" } else { "diesel" };
+        let name = format!("diesel_cli-{version}-{}-{}", self.target, variant.name);
+        let staging_dir = out_dir.join(&name);
+        if staging_dir.exists() {
+            std::fs::remove_dir_all(&staging_dir).unwrap();
+        }
+        std::fs::create_dir_all(&staging_dir).unwrap();
+        let binary = metadata
+            .target_directory
+            .join(&self.target)
+            .join("dist")
+            .join(binary_name);
+        std::fs::copy(&binary, staging_dir.join(binary_name)).unwrap();
+        for file in INCLUDED_FILES {
+            let source = metadata.workspace_root.join(file);
+            std::fs::copy(&source, staging_dir.join(source.file_name().unwrap())).unwrap();
+        }
+
+        // `tar` is available on all supported platforms, on windows
+        // it's bsdtar which is able to write zip archives
+        let archive = if is_windows {
+            format!("{name}.zip")
+        } else {
+            format!("{name}.tar.gz")
+        };
+        let mut command = Command::new("tar");
+        if is_windows {
+            command.args(["-a", "-c", "-f"]);
+        } else {
+            command.args(["-c", "-z", "-f"]);
+        }
+        command.args([&archive, &name]).current_dir(out_dir);
+        run(command, &format!("create `{archive}`"));
+        std::fs::remove_dir_all(&staging_dir).unwrap();
+
+        let sha256 = sha256(out_dir, &archive);
+        std::fs::write(
+            out_dir.join(format!("{archive}.sha256")),
+            format!("{sha256}  {archive}\n"),
+        )
+        .unwrap();
+        Artifact {
+            variant,
+            archive,
+            sha256,
+        }
+    }
+
+    fn manifest(&self, version: &str, artifacts: &[Artifact]) -> serde_json::Value {
+        let artifacts = artifacts
+            .iter()
+            .map(|a| {
+                serde_json::json!({
+                    "name": a.archive,
+                    "variant": a.variant.name,
+                    "features": a.variant.features,
+                    "sha256": a.sha256,
+                })
+            })
+            .collect::<Vec<_>>();
+        serde_json::json!({
+            "package": "diesel_cli",
+            "version": version,
+            "target": self.target,
+            "artifacts": artifacts,
+        })
+    }
+}
+
+fn run(mut command: Command, what: &str) {
+    println!("Run `{command:?}`");
+    let success = command
+        .stderr(Stdio::inherit())
+        .stdout(Stdio::inherit())
+        .status()
+        .unwrap()
+        .success();
+    if !success {
+        eprintln!("Failed to {what}");
+        std::process::exit(1);
+    }
+}
+
+/// Computes the checksum via `sha256sum` or `shasum`,
+/// as only one of them is installed by default on each platform
+fn sha256(dir: &Path, file: &str) -> String {
+    let output = Command::new("sha256sum")
+        .arg(file)
+        .current_dir(dir)
+        .output()
+        .or_else(|_| {
+            Command::new("shasum")
+                .args(["-a", "256", file])
+                .current_dir(dir)
+                .output()
+        })
+        .expect("Either `sha256sum` or `shasum` is installed");
+    assert!(
+        output.status.success(),
+        "Failed to compute the checksum of `{file}`"
+    );
+    String::from_utf8(output.stdout)
+        .expect("Valid UTF-8")
+        .split_whitespace()
+        .next()
+        .expect("The checksum is printed first")
+        .to_owned()
+}
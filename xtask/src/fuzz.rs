@@ -0,0 +1,76 @@
+use std::process::{Command, Stdio};
+
+use cargo_metadata::MetadataCommand;
+
+#[derive(Debug, clap::Args)]
+pub struct FuzzArgs {
+    /// Only run the given fuzz target
+    ///
+    /// By default all targets in `fuzz/fuzz_targets` are run one after another
+    target: Option<String>,
+    /// Maximal number of seconds each target is run
+    #[clap(long = "max-total-time", default_value_t = 60)]
+    max_total_time: u64,
+    /// List the available fuzz targets instead of running them
+    #[clap(long)]
+    list: bool,
+    /// additional flags passed to libFuzzer
+    #[clap(last = true)]
+    flags: Vec<String>,
+}
+
+impl FuzzArgs {
+    pub(crate) fn run(&self) {
+        let metadata = MetadataCommand::default().exec().unwrap();
+        let fuzz_dir = metadata.workspace_root.join("fuzz");
+        let mut targets = std::fs::read_dir(fuzz_dir.join("fuzz_targets"))
+            .unwrap()
+            .map(|entry| {
+                let path = entry.unwrap().path();
+                path.file_stem()
+                    .and_then(|s| s.to_str())
+                    .expect("Fuzz targets have an UTF-8 file name")
+                    .to_owned()
+            })
+            .collect::<Vec<_>>();
+        targets.sort();
+
+        if self.list {
+            for target in &targets {
+                println!("{target}");
+            }
+            return;
+        }
+        if let Some(target) = &self.target {
+            if !targets.contains(target) {
+                eprintln!(
+                    "Unknown fuzz target `{target}`, available targets are: {}",
+                    targets.join(", ")
+                );
+                std::process::exit(1);
+            }
+            targets.retain(|t| t == target);
+        }
+
+        for target in &targets {
+            // cargo-fuzz requires a nightly compiler for the sanitizer flags
+            let mut command = Command::new("cargo");
+            command
+                .args(["+nightly", "fuzz", "run", target, "--"])
+                .arg(format!("-max_total_time={}", self.max_total_time))
+                .args(&self.flags)
+                .current_dir(&fuzz_dir);
+            println!("Run fuzz target `{target}` via `{command:?}`");
+            let success = command
+                .stderr(Stdio::inherit())
+                .stdout(Stdio::inherit())
+                .status()
+                .unwrap()
+                .success();
+            if !success {
+                eprintln!("Fuzz target `{target}` failed");
+                std::process::exit(1);
+            }
+        }
+    }
+}
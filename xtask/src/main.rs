@@ -3,6 +3,10 @@ use std::fmt::Display;
 use clap::{Parser, ValueEnum};
 
 mod clippy;
+mod dist;
+mod fuzz;
+mod matrix;
+mod no_backend;
 mod semver_checks;
 mod tests;
 mod tidy;
@@ -28,6 +32,34 @@ enum Commands {
     /// This command will execute `cargo semver-checks` to verify that
     /// no breaking changes are included
     SemverChecks(semver_checks::SemverArgs),
+    /// Check that diesel builds without any backend
+    ///
+    /// This command will execute `cargo clippy` for diesel and
+    /// diesel_derives without enabling any backend feature, as used
+    /// by crates that only share `table!` definitions and derives
+    CheckNoBackend(no_backend::NoBackendArgs),
+    /// Build the release archives of diesel_cli
+    ///
+    /// This command builds stripped diesel_cli binaries for the given
+    /// target, one supporting all backends and one for each backend,
+    /// and packages them as versioned archives along with checksums
+    /// and a release manifest in `target/distrib`. The installers, SBOM
+    /// and attestations of the released binaries are still produced by
+    /// `cargo dist` (see `dist-workspace.toml`)
+    Dist(dist::DistArgs),
+    /// Print the test matrix as JSON
+    ///
+    /// This command prints one entry for each backend and wasm
+    /// combination tested by CI, containing the excluded workspace packages,
+    /// the enabled features and the `cargo xtask run-tests` command
+    /// to reproduce the entry locally
+    GenerateMatrix(matrix::MatrixArgs),
+    /// Run the fuzz targets in `fuzz/`
+    ///
+    /// Requires `cargo-fuzz` and a nightly toolchain to be installed.
+    /// Each target is run for a limited time, crashing inputs are
+    /// written to `fuzz/artifacts/<target>`
+    Fuzz(fuzz::FuzzArgs),
 }
 
 impl Commands {
@@ -37,6 +69,10 @@ impl Commands {
             Commands::Clippy(clippy) => clippy.run(),
             Commands::Tidy(tidy) => tidy.run(),
             Commands::SemverChecks(semver) => semver.run(),
+            Commands::CheckNoBackend(no_backend) => no_backend.run(),
+            Commands::Dist(dist) => dist.run(),
+            Commands::GenerateMatrix(matrix) => matrix.run(),
+            Commands::Fuzz(fuzz) => fuzz.run(),
         }
     }
 }
@@ -0,0 +1,69 @@
+use cargo_metadata::MetadataCommand;
+
+use crate::Backend;
+
+#[derive(Debug, clap::Args)]
+pub struct MatrixArgs {
+    /// Generate the matrix for a specific backend
+    #[clap(default_value_t = Backend::All)]
+    backend: Backend,
+    /// Pretty print the generated JSON
+    #[clap(long)]
+    pretty: bool,
+}
+
+impl MatrixArgs {
+    pub(crate) fn run(&self) {
+        let metadata = MetadataCommand::default().exec().unwrap();
+        let backends = if matches!(self.backend, Backend::All) {
+            Backend::ALL
+        } else {
+            std::slice::from_ref(&self.backend)
+        };
+        let include = backends
+            .iter()
+            .flat_map(|backend| {
+                // only the sqlite backend supports wasm for now
+                let wasm: &[bool] = if matches!(backend, Backend::Sqlite) {
+                    &[false, true]
+                } else {
+                    &[false]
+                };
+                wasm.iter().map(move |wasm| (*backend, *wasm))
+            })
+            .map(|(backend, wasm)| {
+                let backend_name = backend.to_string();
+                let env = if wasm {
+                    crate::utils::WASM_TEST_ENV
+                        .iter()
+                        .map(|(k, v)| (k.to_string(), serde_json::Value::from(*v)))
+                        .collect()
+                } else {
+                    serde_json::Map::new()
+                };
+                let command = if wasm {
+                    format!("cargo xtask run-tests {backend_name} --wasm")
+                } else {
+                    format!("cargo xtask run-tests {backend_name}")
+                };
+                serde_json::json!({
+                    "backend": backend_name,
+                    "wasm": wasm,
+                    "target": wasm.then_some("wasm32-unknown-unknown"),
+                    "exclude": crate::utils::get_excluded_packages(&backend_name, &metadata, wasm),
+                    "features": crate::utils::get_test_features(&backend_name, wasm),
+                    "doc_test_features": crate::utils::get_doc_test_features(&backend_name),
+                    "env": env,
+                    "command": command,
+                })
+            })
+            .collect::<Vec<_>>();
+        let matrix = serde_json::json!({ "include": include });
+        let out = if self.pretty {
+            serde_json::to_string_pretty(&matrix)
+        } else {
+            serde_json::to_string(&matrix)
+        };
+        println!("{}", out.unwrap());
+    }
+}
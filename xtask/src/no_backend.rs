@@ -0,0 +1,67 @@
+use std::process::{Command, Stdio};
+
+use cargo_metadata::MetadataCommand;
+
+#[derive(Debug, clap::Args)]
+pub struct NoBackendArgs {
+    /// Check diesel for the given target instead of the host target
+    ///
+    /// This is useful to verify that diesel builds for targets like
+    /// `wasm32-unknown-unknown` that cannot link any native database driver
+    #[clap(long)]
+    pub target: Option<String>,
+    /// additional flags passed to cargo clippy
+    pub flags: Vec<String>,
+}
+
+impl NoBackendArgs {
+    pub(crate) fn run(&self) {
+        if !self.run_clippy() {
+            std::process::exit(1);
+        }
+    }
+
+    pub(crate) fn run_clippy(&self) -> bool {
+        let metadata = MetadataCommand::default().exec().unwrap();
+        let mut command = Command::new("cargo");
+
+        // crates depending on diesel only for `table!`, the derives and
+        // the type definitions do not enable any backend feature
+        command
+            .args([
+                "clippy",
+                "-p",
+                "diesel",
+                "-p",
+                "diesel_derives",
+                "--no-default-features",
+                "-F",
+                "diesel/std",
+                "-F",
+                "diesel/with-deprecated",
+                "-F",
+                "diesel/32-column-tables",
+                "-F",
+                "diesel/chrono",
+                "-F",
+                "diesel/time",
+                "-F",
+                "diesel/uuid",
+                "-F",
+                "diesel/serde_json",
+                "-F",
+                "diesel/numeric",
+            ])
+            .args(self.target.iter().flat_map(|t| ["--target", t]))
+            .args(&self.flags)
+            .current_dir(&metadata.workspace_root);
+
+        println!("Run clippy without a backend via `{command:?}`");
+        command
+            .stderr(Stdio::inherit())
+            .stdout(Stdio::inherit())
+            .status()
+            .unwrap()
+            .success()
+    }
+}
@@ -148,21 +148,11 @@ impl TestArgs {
                 .args(["--workspace", "--no-default-features"])
                 .current_dir(&metadata.workspace_root)
                 .args(exclude)
-                .arg("-F")
-                .arg(format!("diesel/{backend}"))
-                .args(["-F", "diesel/extras"])
-                .arg("-F")
-                .arg(format!("diesel_derives/{backend}"))
-                .arg("-F")
-                .arg(format!("migrations_macros/{backend}"))
-                .arg("-F")
-                .arg(format!("diesel_migrations/{backend}"))
-                .arg("-F")
-                .arg(format!("diesel_tests/{backend}"))
-                .arg("-F")
-                .arg(format!("diesel-dynamic-schema/{backend}"))
-                .arg("-F")
-                .arg(format!("diesel_migrations/{backend}"))
+                .args(
+                    crate::utils::get_test_features(&backend_name, self.wasm)
+                        .into_iter()
+                        .flat_map(|f| ["-F".to_owned(), f]),
+                )
                 .args(&self.flags);
 
             if matches!(self.backend, Backend::Mysql) {
@@ -171,16 +161,9 @@ impl TestArgs {
             }
             if self.wasm {
                 command
-                    .env("WASM_BINDGEN_TEST_TIMEOUT", "120")
-                    .env(
-                        "CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER",
-                        "wasm-bindgen-test-runner",
-                    )
-                    .env("RUSTFLAGS", "--cfg getrandom_backend=\"wasm_js\"")
+                    .envs(crate::utils::WASM_TEST_ENV.iter().copied())
                     .arg("--target")
                     .arg("wasm32-unknown-unknown");
-            } else {
-                command.arg("-F").arg(format!("diesel_cli/{backend}"));
             }
             println!("Running tests via `{command:?}`: ");
 
@@ -217,29 +200,19 @@ impl TestArgs {
                     "dsl_auto_type",
                     "-p",
                     "diesel_table_macro_syntax",
-                    "-F",
-                    "diesel/extras",
                 ])
-                .arg("-F")
-                .arg(format!("diesel/{backend}"))
-                .arg("-F")
-                .arg(format!("diesel_derives/{backend}"))
-                .arg("-F")
-                .arg(format!("diesel-dynamic-schema/{backend}"))
-                .arg("-F")
-                .arg(format!("diesel_migrations/{backend}"));
+                .args(
+                    crate::utils::get_doc_test_features(&backend_name)
+                        .into_iter()
+                        .flat_map(|f| ["-F".to_owned(), f]),
+                );
             if matches!(backend, Backend::Mysql) {
                 // cannot run mysql tests in parallel
                 command.args(["-j", "1"]);
             }
             if self.wasm {
                 command
-                    .env("WASM_BINDGEN_TEST_TIMEOUT", "120")
-                    .env(
-                        "CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER",
-                        "wasm-bindgen-test-runner",
-                    )
-                    .env("RUSTFLAGS", "--cfg getrandom_backend=\"wasm_js\"")
+                    .envs(crate::utils::WASM_TEST_ENV.iter().copied())
                     .arg("--target")
                     .arg("wasm32-unknown-unknown");
             }
@@ -4,6 +4,7 @@ use cargo_metadata::MetadataCommand;
 
 use crate::Backend;
 use crate::clippy::ClippyArgs;
+use crate::no_backend::NoBackendArgs;
 
 #[derive(Debug, clap::Args)]
 pub struct TidyArgs {
@@ -69,6 +70,18 @@ impl TidyArgs {
         }
         .run();
 
+        let no_backend = NoBackendArgs {
+            target: None,
+            flags: Vec::new(),
+        };
+        if !no_backend.run_clippy() {
+            if !self.keep_going {
+                std::process::exit(1);
+            } else {
+                success = false;
+            }
+        }
+
         println!();
         if success {
             println!("All checks were successful. Ready to submit the code!");
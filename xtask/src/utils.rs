@@ -4,6 +4,18 @@ pub fn get_exclude_for_backend<'a>(
     backend: &str,
     metadata: &'a Metadata,
     wasm: bool,
+) -> Vec<&'a str> {
+    get_excluded_packages(backend, metadata, wasm)
+        .into_iter()
+        .flat_map(|p| ["--exclude", p])
+        .collect()
+}
+
+/// Workspace packages that are not built or tested for the given backend
+pub fn get_excluded_packages<'a>(
+    backend: &str,
+    metadata: &'a Metadata,
+    wasm: bool,
 ) -> Vec<&'a str> {
     let examples = metadata.workspace_root.join("examples");
     let backend_examples = examples.join(backend);
@@ -14,12 +26,11 @@ pub fn get_exclude_for_backend<'a>(
             if p.manifest_path.starts_with(&examples)
                 && !p.manifest_path.starts_with(&backend_examples)
             {
-                Some(["--exclude", &p.name])
+                Some(p.name.as_str())
             } else {
                 None
             }
         })
-        .flatten()
         .collect::<Vec<_>>();
     if wasm {
         let additional_excludes = [
@@ -36,11 +47,46 @@ pub fn get_exclude_for_backend<'a>(
             // due to broken cargo workspace feature unification
             "sqlite-wasm-example",
         ];
-        out.extend(
-            additional_excludes
-                .into_iter()
-                .flat_map(|v| ["--exclude", v]),
-        );
+        out.extend(additional_excludes);
     }
     out
 }
+
+/// Features enabled while running the unit and integration tests for the given backend
+pub fn get_test_features(backend: &str, wasm: bool) -> Vec<String> {
+    let mut features = vec![
+        format!("diesel/{backend}"),
+        "diesel/extras".to_owned(),
+        format!("diesel_derives/{backend}"),
+        format!("migrations_macros/{backend}"),
+        format!("diesel_migrations/{backend}"),
+        format!("diesel_tests/{backend}"),
+        format!("diesel-dynamic-schema/{backend}"),
+    ];
+    // the command line tool is not built for wasm
+    if !wasm {
+        features.push(format!("diesel_cli/{backend}"));
+    }
+    features
+}
+
+/// Features enabled while running the doc tests for the given backend
+pub fn get_doc_test_features(backend: &str) -> Vec<String> {
+    vec![
+        "diesel/extras".to_owned(),
+        format!("diesel/{backend}"),
+        format!("diesel_derives/{backend}"),
+        format!("diesel-dynamic-schema/{backend}"),
+        format!("diesel_migrations/{backend}"),
+    ]
+}
+
+/// Environment variables required to run the tests for `wasm32-unknown-unknown`
+pub const WASM_TEST_ENV: &[(&str, &str)] = &[
+    ("WASM_BINDGEN_TEST_TIMEOUT", "120"),
+    (
+        "CARGO_TARGET_WASM32_UNKNOWN_UNKNOWN_RUNNER",
+        "wasm-bindgen-test-runner",
+    ),
+    ("RUSTFLAGS", "--cfg getrandom_backend=\"wasm_js\""),
+];